@@ -0,0 +1,240 @@
+//! Which enum variants are boxed: the ones larger than [`MAX_VARIANT_SIZE`],
+//! by the estimated layout of the generated types on 64-bit targets.
+//!
+//! The estimate models the layout rustc picks for these types, niches
+//! included. It is written to the generated `sizes` module next to the actual
+//! size, and the `sizes` tests of the crate fail if they differ, so a change
+//! in rustc's layout can't silently box the wrong variants.
+//!
+//! The model only knows what the generated code is made of: the sizes of
+//! `String`, `Vec`, `Box` and `serde_json::Map` on 64-bit targets, fields
+//! reordered so that there is no padding between them, and the tag of an enum
+//! either in a niche of its largest variant or after it. Every feature is
+//! assumed to be enabled, and the boxed variants are the same on every target
+//! and with any features.
+
+use crate::generator::{is_concrete, upper_first, RESPONSE, UPDATE};
+use std::collections::{HashMap, HashSet};
+use tl_parser::{Class, Constructor, Field, Schema, TlType};
+
+/// Variants larger than this many bytes are boxed, so that moving an enum
+/// around never copies much more than that.
+pub const MAX_VARIANT_SIZE: usize = 128;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Layout {
+    size: usize,
+    align: usize,
+    /// Whether the type has invalid bit patterns, that `Option` uses instead
+    /// of a separate tag.
+    niche: bool,
+}
+
+impl Layout {
+    const fn new(size: usize, align: usize, niche: bool) -> Self {
+        Layout { size, align, niche }
+    }
+
+    fn option(self) -> Self {
+        if self.niche {
+            self
+        } else {
+            Layout::new(self.size + self.align, self.align, true)
+        }
+    }
+}
+
+const BOOL: Layout = Layout::new(1, 1, true);
+const INT32: Layout = Layout::new(4, 4, false);
+const INT64: Layout = Layout::new(8, 8, false);
+/// `Box`.
+const POINTER: Layout = Layout::new(8, 8, true);
+/// `String`, `Vec` and `Bytes`.
+const BUFFER: Layout = Layout::new(24, 8, true);
+/// `Unknown`, a `String` and a `serde_json::Map`.
+const UNKNOWN: Layout = Layout::new(48, 8, true);
+
+fn round_up(size: usize, align: usize) -> usize {
+    size.div_ceil(align) * align
+}
+
+/// The boxed variants of every generated enum, and the estimated size of
+/// every type.
+pub struct Boxing {
+    boxed: HashSet<(String, String)>,
+    sizes: HashMap<String, usize>,
+}
+
+impl Boxing {
+    pub fn new(schema: &Schema) -> Self {
+        let mut layouts = Layouts {
+            schema,
+            constructors: schema
+                .constructors()
+                .iter()
+                .map(|constructor| (upper_first(&constructor.name), constructor))
+                .collect(),
+            layouts: HashMap::new(),
+            boxed: HashSet::new(),
+        };
+        // Every class is a variant of Response, except that updates are
+        // variants one by one. The enum of an abstract class can still be a
+        // tag larger than its largest variant.
+        let mut variants = Vec::new();
+        for class in schema.classes() {
+            let layout = layouts.class(&class.name);
+            if class.name != UPDATE {
+                variants.push((class.name.clone(), layout));
+            } else {
+                for constructor in &class.constructors {
+                    let variant = upper_first(constructor);
+                    let layout = layouts.class(&variant);
+                    variants.push((variant, layout));
+                }
+            }
+        }
+        let payloads = layouts.box_large(RESPONSE, variants);
+        let response = enum_layout(payloads);
+        layouts.layouts.insert(RESPONSE.to_string(), Some(response));
+        Boxing {
+            boxed: layouts.boxed,
+            sizes: layouts
+                .layouts
+                .into_iter()
+                .map(|(name, layout)| (name, layout.expect("computed").size))
+                .collect(),
+        }
+    }
+
+    /// The estimated size of the struct or enum generated for a class or a
+    /// constructor, or of `Response`, by Rust name.
+    pub fn size(&self, name: &str) -> usize {
+        self.sizes[name]
+    }
+
+    pub fn is_boxed(&self, enum_: &str, variant: &str) -> bool {
+        self.boxed
+            .contains(&(enum_.to_string(), variant.to_string()))
+    }
+}
+
+struct Layouts<'a> {
+    schema: &'a Schema,
+    /// By Rust name.
+    constructors: HashMap<String, &'a Constructor>,
+    /// Of the structs and enums, by Rust name; `None` while being computed.
+    layouts: HashMap<String, Option<Layout>>,
+    boxed: HashSet<(String, String)>,
+}
+
+impl<'a> Layouts<'a> {
+    /// The layout of the struct or enum generated for a class, or for a
+    /// constructor used in place of its class.
+    fn class(&mut self, name: &str) -> Layout {
+        match self.layouts.get(name) {
+            Some(Some(layout)) => return *layout,
+            Some(None) => panic!("{} contains itself", name),
+            None => {}
+        }
+        self.layouts.insert(name.to_string(), None);
+        let layout = match self.schema.class(name) {
+            Some(class) if !is_concrete(class) => self.enum_(class),
+            _ => {
+                let constructor = self.constructors[name];
+                self.struct_(constructor)
+            }
+        };
+        self.layouts.insert(name.to_string(), Some(layout));
+        layout
+    }
+
+    fn struct_(&mut self, constructor: &Constructor) -> Layout {
+        let class = constructor.class.clone();
+        let mut size = 0;
+        let mut align = 1;
+        let mut niche = false;
+        // Fields are reordered by alignment, so there is no padding between
+        // them.
+        for field in &constructor.fields {
+            let field = self.field(&class, field);
+            size += field.size;
+            align = align.max(field.align);
+            niche |= field.niche;
+        }
+        Layout::new(round_up(size, align), align, niche)
+    }
+
+    fn field(&mut self, class: &str, field: &Field) -> Layout {
+        let layout = match &field.type_ {
+            TlType::Object(name) if upper_first(name) == class => POINTER,
+            type_ => self.type_(type_),
+        };
+        if field.nullable {
+            layout.option()
+        } else {
+            layout
+        }
+    }
+
+    fn type_(&mut self, type_: &TlType) -> Layout {
+        match type_ {
+            TlType::Bool => BOOL,
+            TlType::Int32 => INT32,
+            TlType::Int53 | TlType::Int64 | TlType::Double => INT64,
+            TlType::String | TlType::Bytes | TlType::Vector(_) => BUFFER,
+            TlType::Object(name) => self.class(&upper_first(name)),
+        }
+    }
+
+    fn enum_(&mut self, class: &Class) -> Layout {
+        let variants = class
+            .constructors
+            .iter()
+            .map(|constructor| {
+                let variant = upper_first(constructor);
+                let layout = self.class(&variant);
+                (variant, layout)
+            })
+            .collect();
+        let payloads = self.box_large(&class.name, variants);
+        enum_layout(payloads)
+    }
+
+    /// Boxes the variants of `enum_` larger than [`MAX_VARIANT_SIZE`], and
+    /// returns the layouts of the payloads of every variant as stored in the
+    /// enum, `Unknown` included.
+    fn box_large(&mut self, enum_: &str, variants: Vec<(String, Layout)>) -> Vec<Layout> {
+        let mut payloads = vec![UNKNOWN];
+        for (variant, layout) in variants {
+            if layout.size > MAX_VARIANT_SIZE {
+                self.boxed.insert((enum_.to_string(), variant));
+                payloads.push(POINTER);
+            } else {
+                payloads.push(layout);
+            }
+        }
+        payloads
+    }
+}
+
+/// The layout of an enum whose variants have the given payloads.
+///
+/// rustc stores the tag in the invalid values of a field of the largest
+/// variant when the other variants fit beside that field, which here is the
+/// 8-byte capacity of a `String` or `Vec`, or a `Box`. Otherwise the tag
+/// comes after the largest payload, rounded up to the alignment.
+fn enum_layout(mut payloads: Vec<Layout>) -> Layout {
+    let align = payloads
+        .iter()
+        .map(|payload| payload.align)
+        .max()
+        .unwrap_or(1);
+    payloads.sort_by_key(|payload| payload.size);
+    let largest = payloads.pop().expect("Unknown is a variant of every enum");
+    let others = payloads.last().map_or(0, |payload| payload.size);
+    if largest.niche && others + POINTER.size <= largest.size {
+        Layout::new(largest.size, align, true)
+    } else {
+        Layout::new(round_up(largest.size + 1, align), align, true)
+    }
+}
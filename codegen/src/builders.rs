@@ -0,0 +1,134 @@
+//! Which structs get `Default` and a builder, and which of their fields must
+//! be passed to `new` because no value fits them by default.
+//!
+//! Builders are generated for methods and for the types of their arguments,
+//! found by following the fields of the arguments, down to every constructor
+//! of the abstract classes along the way.
+
+use crate::constraints;
+use crate::generator::{is_concrete, upper_first};
+use crate::ids;
+use std::collections::HashSet;
+use tl_parser::{Constructor, Field, Schema, TlType};
+
+/// Fields that are required although a default would pass for them: the
+/// credentials of the application and what the methods of the authorization
+/// flow send, by Rust name of the struct and field name. Without them the
+/// request is pointless, e.g. `checkAuthenticationCode` with an empty code.
+const REQUIRED: &[(&str, &str)] = &[
+    ("TdlibParameters", "api_id"),
+    ("TdlibParameters", "api_hash"),
+    ("SetAuthenticationPhoneNumber", "phone_number"),
+    ("CheckAuthenticationCode", "code"),
+    ("CheckAuthenticationPassword", "password"),
+    ("RecoverAuthenticationPassword", "recovery_code"),
+    ("CheckAuthenticationBotToken", "token"),
+];
+
+pub struct Builders<'a> {
+    schema: &'a Schema,
+    /// Rust names of the structs that are arguments of methods.
+    inputs: HashSet<String>,
+}
+
+impl<'a> Builders<'a> {
+    pub fn new(schema: &'a Schema) -> Self {
+        let mut builders = Builders {
+            schema,
+            inputs: HashSet::new(),
+        };
+        let mut pending: Vec<&TlType> = schema
+            .functions()
+            .iter()
+            .flat_map(|function| &function.fields)
+            .map(|field| &field.type_)
+            .collect();
+        while let Some(type_) = pending.pop() {
+            let name = match type_ {
+                TlType::Vector(element) => {
+                    pending.push(element);
+                    continue;
+                }
+                TlType::Object(name) => upper_first(name),
+                _ => continue,
+            };
+            for constructor in builders.constructors(&name) {
+                if builders.inputs.insert(upper_first(&constructor.name)) {
+                    pending.extend(constructor.fields.iter().map(|field| &field.type_));
+                }
+            }
+        }
+        builders
+    }
+
+    /// The constructors of a class, or the constructor named in place of its
+    /// class.
+    fn constructors(&self, name: &str) -> Vec<&'a Constructor> {
+        let names = match self.schema.class(name) {
+            Some(class) => class.constructors.clone(),
+            None => vec![lower_first(name)],
+        };
+        names
+            .iter()
+            .map(|name| self.schema.constructor(name).expect("known constructor"))
+            .collect()
+    }
+
+    /// Whether the struct is an argument of a method, or part of one.
+    pub fn is_input(&self, name: &str) -> bool {
+        self.inputs.contains(name)
+    }
+
+    /// Whether a field of the struct `owner`, of `class`, has no default
+    /// value: objects of abstract classes, structs with such fields,
+    /// identifiers that are not documented to accept 0, fields whose
+    /// documented limits reject 0 or an empty value, and the ones in
+    /// [`REQUIRED`].
+    pub fn is_required(&self, owner: &str, class: &str, field: &Field) -> bool {
+        if field.nullable {
+            return false;
+        }
+        if constraints::reject_default(field) || REQUIRED.contains(&(owner, field.name.as_str())) {
+            return true;
+        }
+        match &field.type_ {
+            TlType::Object(name) => {
+                let name = upper_first(name);
+                match self.schema.class(&name) {
+                    Some(class) if !is_concrete(class) => true,
+                    _ if name == class => true,
+                    _ => self.constructors(&name).into_iter().any(|constructor| {
+                        let owner = upper_first(&constructor.name);
+                        self.has_required(&owner, &constructor.class, &constructor.fields)
+                    }),
+                }
+            }
+            TlType::Int32 => is_id(owner, field, "i32"),
+            TlType::Int53 | TlType::Int64 => is_id(owner, field, "i64"),
+            _ => false,
+        }
+    }
+
+    /// Whether a struct has required fields, and so no `Default`.
+    pub fn has_required(&self, owner: &str, class: &str, fields: &[Field]) -> bool {
+        fields
+            .iter()
+            .any(|field| self.is_required(owner, class, field))
+    }
+}
+
+fn is_id(owner: &str, field: &Field, base: &str) -> bool {
+    let zero = field
+        .description
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .any(|word| word == "0" || word.eq_ignore_ascii_case("zero"));
+    !zero && ids::newtype_of(owner, &field.name, base).is_some()
+}
+
+fn lower_first(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
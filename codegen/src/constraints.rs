@@ -0,0 +1,228 @@
+//! The limits documented on the fields of methods and of the types of their
+//! arguments, read from the prose of their descriptions.
+//!
+//! Only the phrasings `td_api.tl` uses for limits that can be checked from
+//! the request are recognized: limits on a single field, and a limit tied to
+//! an offset ("the limit must be greater or equal to -offset"). Limits that
+//! depend on options or on the contents of files are left to TDLib.
+
+use tl_parser::{Field, TlType};
+
+/// Inclusive bounds; `None` when unbounded on that side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bound {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+}
+
+impl Bound {
+    const fn new(min: Option<i64>, max: Option<i64>) -> Self {
+        Bound { min, max }
+    }
+
+    const NON_EMPTY: Bound = Bound::new(Some(1), None);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Constraint {
+    /// The number of characters of a string.
+    Characters(Bound),
+    /// The length in bytes of a string.
+    Bytes(Bound),
+    /// The number of elements of a vector.
+    Elements(Bound),
+    /// The number of characters of each string of a vector.
+    EachCharacters(Bound),
+    /// The value of an integer.
+    Value(Bound),
+    /// The values an integer can take.
+    OneOf(Vec<i64>),
+    /// The value of an integer compared to the opposite of another integer
+    /// field of the same object, as in "must be greater or equal to
+    /// -offset".
+    AboveNegated { field: String, or_equal: bool },
+}
+
+/// The limits documented on a field.
+pub fn of(field: &Field) -> Vec<Constraint> {
+    let doc = field.description.as_str();
+    let mut constraints = Vec::new();
+    match &field.type_ {
+        TlType::String => {
+            if let Some(bound) = range_before(doc, " characters") {
+                constraints.push(Constraint::Characters(bound));
+            } else if let Some(bound) = range_before(doc, " bytes") {
+                constraints.push(Constraint::Bytes(bound));
+            } else if doc.contains("must be non-empty") {
+                constraints.push(Constraint::Characters(Bound::NON_EMPTY));
+            }
+        }
+        TlType::Vector(element) => {
+            if let Some(bound) = range_before(doc, " strings") {
+                constraints.push(Constraint::Elements(bound));
+            } else if doc.contains("must be non-empty") {
+                constraints.push(Constraint::Elements(Bound::NON_EMPTY));
+            } else if let Some(max) = number_after(doc, "must not exceed ") {
+                constraints.push(Constraint::Elements(Bound::new(None, Some(max))));
+            }
+            if **element == TlType::String {
+                if let Some(bound) = range_before(doc, " characters each") {
+                    constraints.push(Constraint::EachCharacters(bound));
+                }
+            }
+        }
+        TlType::Int32 | TlType::Int53 | TlType::Int64 => {
+            if let Some(values) = one_of(doc) {
+                constraints.push(Constraint::OneOf(values));
+            } else if let Some(bound) = value_bound(doc) {
+                constraints.push(Constraint::Value(bound));
+            }
+            if let Some(constraint) = above_negated(doc) {
+                constraints.push(constraint);
+            }
+        }
+        _ => {}
+    }
+    constraints
+}
+
+/// Whether the limits of a field reject its default value: 0, or an empty
+/// string or vector.
+pub fn reject_default(field: &Field) -> bool {
+    of(field).iter().any(|constraint| match constraint {
+        Constraint::Characters(bound)
+        | Constraint::Bytes(bound)
+        | Constraint::Elements(bound)
+        | Constraint::Value(bound) => {
+            bound.min.is_some_and(|min| min > 0) || bound.max.is_some_and(|max| max < 0)
+        }
+        Constraint::OneOf(values) => !values.contains(&0),
+        Constraint::EachCharacters(_) | Constraint::AboveNegated { .. } => false,
+    })
+}
+
+/// The bounds of an integer: "1-32", "between 60 and 86400", "must be
+/// positive", "can't be greater than 100", "up to 100", "0 or a negative
+/// offset up to 99".
+fn value_bound(doc: &str) -> Option<Bound> {
+    if let Some(bound) = ranges(doc).map(|(bound, _)| bound).next() {
+        return Some(bound);
+    }
+    if doc.contains("Specify 0 ") && doc.contains(" or a negative offset") {
+        let min = number_after(doc, "a negative offset up to ").map(|max| -max);
+        return Some(Bound::new(min, Some(0)));
+    }
+    if let Some(bound) = between(doc) {
+        return Some(bound);
+    }
+    let min = if doc.contains("must be positive") {
+        Some(1)
+    } else if doc.contains("must be non-negative") {
+        Some(0)
+    } else {
+        None
+    };
+    // "a negative offset up to 99" bounds the absolute value, so "up to" is
+    // only taken when it starts a clause.
+    let max = number_after(doc, "can't be greater than ")
+        .or_else(|| number_after(doc, "not greater than "))
+        .or_else(|| number_after(doc, "; up to "))
+        .or_else(|| number_after(doc, ", up to "));
+    if min.is_none() && max.is_none() {
+        None
+    } else {
+        Some(Bound::new(min, max))
+    }
+}
+
+/// "must be greater than -offset" or "must be greater or equal to -offset".
+fn above_negated(doc: &str) -> Option<Constraint> {
+    let (start, or_equal) = match doc.find("must be greater or equal to -") {
+        Some(i) => (i + "must be greater or equal to -".len(), true),
+        None => (
+            doc.find("must be greater than -")? + "must be greater than -".len(),
+            false,
+        ),
+    };
+    let rest = &doc[start..];
+    let end = rest
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .unwrap_or(rest.len());
+    if end == 0 || rest.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(Constraint::AboveNegated {
+        field: rest[..end].to_string(),
+        or_equal,
+    })
+}
+
+/// "between 60 and 86400", unless it is followed by the case it applies to.
+fn between(doc: &str) -> Option<Bound> {
+    let start = doc.find("between ")? + "between ".len();
+    let (min, rest) = number(&doc[start..])?;
+    let rest = rest.strip_prefix(" and ")?;
+    let (max, rest) = number(rest)?;
+    if rest.starts_with(" for ") {
+        None
+    } else {
+        Some(Bound::new(Some(min), Some(max)))
+    }
+}
+
+/// "must be one of 0, 10, 30".
+fn one_of(doc: &str) -> Option<Vec<i64>> {
+    let start = doc.find("must be one of ")? + "must be one of ".len();
+    let mut values = Vec::new();
+    let mut rest = &doc[start..];
+    loop {
+        let (value, after) = number(rest)?;
+        values.push(value);
+        match after.strip_prefix(", ") {
+            Some(after) => rest = after,
+            None => return Some(values),
+        }
+    }
+}
+
+/// The first range immediately followed by `unit`, as in "1-64 characters".
+fn range_before(doc: &str, unit: &str) -> Option<Bound> {
+    ranges(doc)
+        .find(|(_, rest)| rest.starts_with(unit))
+        .map(|(bound, _)| bound)
+}
+
+/// The ranges of the form "N-M" in a text, with the text that follows each.
+fn ranges(doc: &str) -> impl Iterator<Item = (Bound, &str)> {
+    doc.char_indices().filter_map(move |(i, c)| {
+        let before = doc[..i].chars().next_back();
+        if !c.is_ascii_digit() || before.is_some_and(|c| c.is_alphanumeric() || c == '-') {
+            return None;
+        }
+        let (min, rest) = number(&doc[i..])?;
+        let (max, rest) = number(rest.strip_prefix('-')?)?;
+        if rest.starts_with(|c: char| c.is_alphanumeric() || c == '-') {
+            return None;
+        }
+        Some((Bound::new(Some(min), Some(max)), rest))
+    })
+}
+
+/// The number right after `prefix`, unless it is followed by a unit such as
+/// "MB".
+fn number_after(doc: &str, prefix: &str) -> Option<i64> {
+    let start = doc.find(prefix)? + prefix.len();
+    let (value, rest) = number(&doc[start..])?;
+    if rest.starts_with(|c: char| c.is_alphanumeric()) {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+/// A decimal number at the start of `s`, and the rest of `s`.
+fn number(s: &str) -> Option<(i64, &str)> {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let value = s[..end].parse().ok()?;
+    Some((value, &s[end..]))
+}
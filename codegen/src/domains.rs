@@ -0,0 +1,161 @@
+//! Assignment of constructors and functions to the cargo features of the
+//! crate, by the words of their names.
+
+/// The domain features, in the order their modules are declared.
+pub const DOMAINS: &[&str] = &[
+    "auth", "chats", "messages", "files", "calls", "passport", "payments", "stickers", "bots",
+    "proxy", "logging", "tests",
+];
+
+/// Splits a camel case name into its lowercase words.
+fn words(name: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    for c in name.chars() {
+        match words.last_mut() {
+            Some(word) if !c.is_uppercase() => word.push(c),
+            _ => words.push(c.to_lowercase().collect()),
+        }
+    }
+    words
+}
+
+/// What every client needs to start TDLib, follow its authorization state
+/// and stop it, which is in the core whatever its words say.
+const LIFECYCLE: &[&str] = &[
+    "TdlibParameters",
+    "setTdlibParameters",
+    "checkDatabaseEncryptionKey",
+    "AuthorizationState",
+    "updateAuthorizationState",
+    "getAuthorizationState",
+    "close",
+    "destroy",
+    "logOut",
+];
+
+/// The domain a class, constructor or function belongs to by its own name,
+/// or `None` for the core of the API, that is always available.
+pub fn domain(name: &str) -> Option<&'static str> {
+    if LIFECYCLE.contains(&name) {
+        return None;
+    }
+    let words = words(name);
+    let has = |word: &str| words.iter().any(|w| w == word);
+    let has_any = |list: &[&str]| list.iter().any(|word| has(word));
+    let starts_with = |word: &str| words.first().map(String::as_str) == Some(word);
+
+    if starts_with("test") && !has("proxy") {
+        return Some("tests");
+    }
+    if has("log") && (has_any(&["stream", "verbosity", "tag", "tags"]) || name == "addLogMessage") {
+        return Some("logging");
+    }
+    if has_any(&["proxy", "proxies"]) {
+        return Some("proxy");
+    }
+    if has_any(&[
+        "passport",
+        "personal",
+        "identity",
+        "dated",
+        "encrypted",
+        "verification",
+        "preferred",
+    ]) || matches!(name, "date" | "Date" | "address" | "Address")
+    {
+        return Some("passport");
+    }
+    if has_any(&[
+        "invoice",
+        "payment",
+        "payments",
+        "shipping",
+        "credentials",
+        "labeled",
+        "bank",
+        "checkout",
+    ]) || (has("order") && has("info"))
+    {
+        return Some("payments");
+    }
+    if has_any(&["sticker", "stickers", "mask", "emoji", "emojis"])
+        || (has("saved") && has_any(&["animation", "animations"]))
+    {
+        return Some("stickers");
+    }
+    if has_any(&[
+        "authentication",
+        "authorization",
+        "password",
+        "session",
+        "sessions",
+        "website",
+        "websites",
+        "account",
+        "qr",
+        "recovery",
+        "terms",
+        "confirmation",
+    ]) || (has("database") && has("encryption"))
+        || (has("change") && has("phone"))
+        || name == "registerUser"
+    {
+        return Some("auth");
+    }
+    if has("call") {
+        return Some("calls");
+    }
+    if has_any(&[
+        "bot", "bots", "inline", "callback", "game", "keyboard", "markup",
+    ]) || (has("custom") && has_any(&["query", "request", "event"]))
+        || (has("login") && has("url"))
+        || (has("high") && has_any(&["score", "scores"]))
+        || starts_with("answer")
+    {
+        return Some("bots");
+    }
+    if !has("supergroup")
+        && has_any(&[
+            "message",
+            "messages",
+            "history",
+            "text",
+            "entity",
+            "entities",
+            "poll",
+            "draft",
+            "scheduling",
+            "forward",
+            "animation",
+            "audio",
+            "document",
+            "video",
+            "voice",
+            "venue",
+            "markdown",
+        ])
+        || (has("web") && has("page"))
+        || (has("page") && has("block"))
+    {
+        return Some("messages");
+    }
+    if has_any(&["file", "files", "storage", "download", "upload"])
+        || (has("statistics") && has_any(&["network", "database"]))
+    {
+        return Some("files");
+    }
+    if has_any(&[
+        "chat",
+        "chats",
+        "basic",
+        "groups",
+        "supergroup",
+        "supergroups",
+        "channel",
+        "invite",
+        "secret",
+    ]) {
+        return Some("chats");
+    }
+    None
+}
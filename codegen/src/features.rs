@@ -0,0 +1,190 @@
+//! Which features each generated item is compiled with.
+
+use crate::domains::domain;
+use crate::generator::upper_first;
+use std::collections::{BTreeSet, HashMap};
+use tl_parser::{Field, Schema, TlType};
+
+/// Classes whose constructors are assigned to domains one by one, instead of
+/// following the class, and are compiled only with their own features: an
+/// update or notification of a domain that is not compiled is decoded as
+/// `Unknown`. Compiling all of them with the core would compile
+/// `notificationTypeNewMessage`, and with it every type of `messages`.
+const PER_CONSTRUCTOR: &[&str] = &["Update", "NotificationType"];
+
+/// The features an item is compiled with; `None` if it is always compiled.
+pub type Gate = Option<BTreeSet<&'static str>>;
+
+pub struct Features {
+    /// By Rust name of the struct or enum.
+    types: HashMap<String, Gate>,
+    /// By Rust name of the method.
+    methods: HashMap<String, Gate>,
+    /// The domain of each struct and enum by its own name, whose module it
+    /// is written to if it is gated.
+    domains: HashMap<String, Option<&'static str>>,
+}
+
+fn referenced(fields: &[Field]) -> Vec<String> {
+    fn walk(type_: &TlType, out: &mut Vec<String>) {
+        match type_ {
+            TlType::Vector(element) => walk(element, out),
+            TlType::Object(name) => out.push(upper_first(name)),
+            _ => {}
+        }
+    }
+    let mut out = Vec::new();
+    for field in fields {
+        walk(&field.type_, &mut out);
+    }
+    out
+}
+
+fn single(domain: Option<&'static str>) -> Gate {
+    domain.map(|domain| std::iter::once(domain).collect())
+}
+
+/// Whether an item compiled with `outer` is always compiled with `inner`.
+pub fn implies(outer: &Gate, inner: &Gate) -> bool {
+    match (outer, inner) {
+        (_, None) => true,
+        (None, Some(_)) => false,
+        (Some(outer), Some(inner)) => outer.is_subset(inner),
+    }
+}
+
+/// The condition of the `#[cfg]` of a gate, `None` for items that are always
+/// compiled.
+fn condition(gate: &Gate) -> Option<String> {
+    let predicates: Vec<_> = gate
+        .as_ref()?
+        .iter()
+        .map(|feature| format!("feature = {:?}", feature))
+        .collect();
+    Some(match predicates.as_slice() {
+        [predicate] => predicate.clone(),
+        _ => format!("any({})", predicates.join(", ")),
+    })
+}
+
+/// The `#[cfg]` attribute of a gate, empty for items that are always
+/// compiled.
+pub fn cfg(gate: &Gate) -> String {
+    match condition(gate) {
+        Some(condition) => format!("#[cfg({})]\n", condition),
+        None => String::new(),
+    }
+}
+
+/// The `#[cfg]` attribute of an item compiled only when both `outer` and
+/// `inner` are, as a single condition.
+pub fn cfg_both(outer: &Gate, inner: &Gate) -> String {
+    match (condition(outer), condition(inner)) {
+        _ if implies(outer, inner) => cfg(outer),
+        _ if implies(inner, outer) => cfg(inner),
+        (Some(outer), Some(inner)) => format!("#[cfg(all({}, {}))]\n", outer, inner),
+        _ => unreachable!("a gate of None is implied by the other"),
+    }
+}
+
+/// Adds `gate` to `target`, returning whether it changed.
+fn widen(target: &mut Gate, gate: &Gate) -> bool {
+    match (target.as_mut(), gate) {
+        (None, _) => false,
+        (Some(_), None) => {
+            *target = None;
+            true
+        }
+        (Some(target), Some(gate)) => {
+            let before = target.len();
+            target.extend(gate.iter().copied());
+            target.len() != before
+        }
+    }
+}
+
+impl Features {
+    pub fn new(schema: &Schema) -> Self {
+        let mut domains = HashMap::new();
+        for class in schema.classes() {
+            domains.insert(class.name.clone(), domain(&class.name));
+        }
+        for constructor in schema.constructors() {
+            let own = if PER_CONSTRUCTOR.contains(&constructor.class.as_str()) {
+                domain(&constructor.name)
+            } else {
+                domain(&constructor.class)
+            };
+            domains.insert(upper_first(&constructor.name), own);
+        }
+        let mut types: HashMap<String, Gate> = domains
+            .iter()
+            .map(|(name, &domain)| (name.clone(), single(domain)))
+            .collect();
+        // Arguments and results of compiled methods must be compiled too.
+        let mut methods = HashMap::new();
+        for function in schema.functions() {
+            let name = upper_first(&function.name);
+            let gate = single(domain(&function.name));
+            for type_ in referenced(&function.fields)
+                .into_iter()
+                .chain(std::iter::once(function.result.clone()))
+            {
+                widen(types.get_mut(&type_).expect("known type"), &gate);
+            }
+            methods.insert(name, gate);
+        }
+        // Fields of compiled structs must be compiled too, and so must the
+        // constructors of compiled classes, or the objects the enum holds
+        // would be decoded as `Unknown`.
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for constructor in schema.constructors() {
+                let name = upper_first(&constructor.name);
+                if !PER_CONSTRUCTOR.contains(&constructor.class.as_str()) {
+                    let class = types[&constructor.class].clone();
+                    if widen(types.get_mut(&name).expect("known type"), &class) {
+                        changed = true;
+                    }
+                }
+                let gate = types[&name].clone();
+                for name in referenced(&constructor.fields) {
+                    if widen(types.get_mut(&name).expect("known type"), &gate) {
+                        changed = true;
+                    }
+                }
+            }
+        }
+        Features {
+            types,
+            methods,
+            domains,
+        }
+    }
+
+    pub fn type_(&self, name: &str) -> &Gate {
+        &self.types[name]
+    }
+
+    pub fn method(&self, name: &str) -> &Gate {
+        &self.methods[name]
+    }
+
+    /// The module a struct or enum is written to: its domain if it is
+    /// gated, or `None` for the core.
+    pub fn type_home(&self, name: &str) -> Option<&'static str> {
+        match self.types[name] {
+            Some(_) => self.domains[name],
+            None => None,
+        }
+    }
+
+    /// The module a method is written to, which is the only feature it is
+    /// compiled with.
+    pub fn method_home(&self, name: &str) -> Option<&'static str> {
+        self.methods[name]
+            .as_ref()
+            .map(|gate| *gate.iter().next().expect("gates are not empty"))
+    }
+}
@@ -0,0 +1,1149 @@
+use crate::boxing::{self, Boxing};
+use crate::builders::Builders;
+use crate::constraints::{self, Bound, Constraint};
+use crate::domains::DOMAINS;
+use crate::features::{self, Features, Gate};
+use crate::ids;
+use crate::metadata;
+use crate::releases::Release;
+use std::fmt::Write;
+use std::path::PathBuf;
+use tl_parser::{Class, Constructor, Field, Function, Schema, TlType};
+
+const TYPES_PRELUDE: &str = include_str!("../templates/types_prelude.rs.in");
+const TYPES_EPILOGUE: &str = include_str!("../templates/types_epilogue.rs.in");
+const METHODS_PRELUDE: &str = include_str!("../templates/methods_prelude.rs.in");
+const SIZES_PRELUDE: &str = include_str!("../templates/sizes_prelude.rs.in");
+
+/// The enum of every object a function can return.
+pub const RESPONSE: &str = "Response";
+/// The class of updates, which are variants of `Response` one by one.
+pub const UPDATE: &str = "Update";
+
+/// Generates the module of a release, as paths relative to its directory
+/// and their unformatted code.
+pub fn generate(schema: &Schema, release: &Release) -> Vec<(PathBuf, String)> {
+    let generator = Generator {
+        schema,
+        release,
+        features: Features::new(schema),
+        boxing: Boxing::new(schema),
+        builders: Builders::new(schema),
+    };
+    generator.files()
+}
+
+/// The items of the `types` or `methods` module, split by the file they are
+/// written to.
+struct Module {
+    name: &'static str,
+    core: String,
+    /// The domains with gated items, in the order of [`DOMAINS`], with the
+    /// gate of their module and their code.
+    domains: Vec<(&'static str, Gate, String)>,
+}
+
+impl Module {
+    /// `items` are the home and gate of every item that will be written.
+    fn new<'g>(
+        name: &'static str,
+        items: impl Iterator<Item = (Option<&'static str>, &'g Gate)>,
+    ) -> Self {
+        let mut gates: Vec<Gate> = vec![Some(Default::default()); DOMAINS.len()];
+        for (home, gate) in items {
+            if let (Some(home), Some(gate)) = (home, gate) {
+                let i = DOMAINS.iter().position(|domain| *domain == home);
+                let module = gates[i.expect("known domain")].as_mut().unwrap();
+                module.extend(gate.iter().copied());
+            }
+        }
+        let domains = DOMAINS
+            .iter()
+            .zip(gates)
+            .filter(|(_, gate)| gate.as_ref().is_some_and(|gate| !gate.is_empty()))
+            .map(|(&domain, gate)| (domain, gate, String::new()))
+            .collect();
+        Module {
+            name,
+            core: String::new(),
+            domains,
+        }
+    }
+
+    /// Starts an item in its home module, with the `#[cfg]` it needs there.
+    fn item(&mut self, home: Option<&'static str>, gate: &Gate) -> &mut String {
+        let (module, out) = match home {
+            None => (&None, &mut self.core),
+            Some(home) => {
+                let (_, module, out) = self
+                    .domains
+                    .iter_mut()
+                    .find(|(domain, _, _)| *domain == home)
+                    .expect("domain with items");
+                (&*module, out)
+            }
+        };
+        if !features::implies(module, gate) {
+            out.push_str(&features::cfg(gate));
+        }
+        out
+    }
+
+    /// Declares and re-exports the domain modules.
+    fn write_mods(&self, out: &mut String) {
+        for (domain, gate, _) in &self.domains {
+            let cfg = features::cfg(gate);
+            writeln!(
+                out,
+                "{cfg}mod {domain};\n{cfg}pub use self::{domain}::*;",
+                cfg = cfg,
+                domain = domain
+            )
+            .unwrap();
+        }
+    }
+
+    /// The files of the domain modules.
+    fn files(self) -> impl Iterator<Item = (PathBuf, String)> {
+        let name = self.name;
+        self.domains.into_iter().map(move |(domain, _, code)| {
+            let path = PathBuf::from(name).join(format!("{}.rs", domain));
+            let code = format!(
+                "//! The {} of the `{}` feature.\n\nuse super::*;\n{}",
+                name, domain, code
+            );
+            (path, code)
+        })
+    }
+}
+
+struct Generator<'a> {
+    schema: &'a Schema,
+    release: &'a Release,
+    features: Features,
+    boxing: Boxing,
+    builders: Builders<'a>,
+}
+
+impl<'a> Generator<'a> {
+    fn files(&self) -> Vec<(PathBuf, String)> {
+        let mut types = self.types();
+        let mut methods = self.methods();
+
+        let mut lib = String::new();
+        let name = self.release.name();
+        writeln!(
+            lib,
+            "//! The types and methods of TDLib {name}, generated from \
+             `schema/{name}/td_api.tl`.\n\n\
+             #[cfg(feature = \"sizes\")]\n\
+             pub mod sizes;\n\n\
+             /// The `td_api.tl` of the release.\n\
+             pub(crate) const TD_API: &str = include_str!(\"../../schema/{name}/td_api.tl\");\n\
+             /// The version of the release.\n\
+             pub(crate) const VERSION: crate::version::Version = \
+             crate::version::Version::new({major}, {minor}, {patch});",
+            name = name,
+            major = self.release.version.0,
+            minor = self.release.version.1,
+            patch = self.release.version.2,
+        )
+        .unwrap();
+        lib.push_str("pub mod types {\n");
+        lib.push_str(TYPES_PRELUDE);
+        types.write_mods(&mut lib);
+        lib.push_str(&std::mem::take(&mut types.core));
+        self.write_response(&mut lib);
+        lib.push_str(TYPES_EPILOGUE);
+        lib.push_str("}\n");
+        lib.push_str("pub mod methods {\n");
+        lib.push_str(METHODS_PRELUDE);
+        methods.write_mods(&mut lib);
+        lib.push_str(&std::mem::take(&mut methods.core));
+        lib.push_str("}\n");
+
+        let mut files = vec![
+            (PathBuf::from("mod.rs"), lib),
+            (PathBuf::from("sizes.rs"), self.sizes()),
+        ];
+        files.extend(types.files());
+        files.extend(methods.files());
+        files
+    }
+
+    fn types(&self) -> Module {
+        let constructors = self.schema.constructors().iter().map(|constructor| {
+            let name = upper_first(&constructor.name);
+            (self.features.type_home(&name), self.features.type_(&name))
+        });
+        let classes = self.schema.classes().iter().map(|class| {
+            (
+                self.features.type_home(&class.name),
+                self.features.type_(&class.name),
+            )
+        });
+        let mut module = Module::new("types", constructors.chain(classes));
+        for constructor in self.schema.constructors() {
+            self.write_struct(&mut module, constructor);
+            let class = self.class(&constructor.class);
+            if !is_concrete(class) && class.constructors.last() == Some(&constructor.name) {
+                self.write_enum(&mut module, class);
+            }
+        }
+        module
+    }
+
+    fn methods(&self) -> Module {
+        let functions = self.schema.functions().iter().map(|function| {
+            let name = upper_first(&function.name);
+            (
+                self.features.method_home(&name),
+                self.features.method(&name),
+            )
+        });
+        let mut module = Module::new("methods", functions);
+        for function in self.schema.functions() {
+            self.write_method(&mut module, function);
+        }
+        module
+    }
+
+    /// The `sizes` module, with every enum and its variants.
+    fn sizes(&self) -> String {
+        let mut out = String::new();
+        out.push_str(SIZES_PRELUDE);
+        write_doc(&mut out, "Variants larger than this are boxed");
+        writeln!(
+            out,
+            "pub const MAX_VARIANT_SIZE: usize = {};",
+            boxing::MAX_VARIANT_SIZE
+        )
+        .unwrap();
+        write_doc(
+            &mut out,
+            "Every enum of `types`, in the order they are defined, then Response",
+        );
+        out.push_str("pub static ENUMS: &[EnumSize] = &[\n");
+        let classes = self
+            .schema
+            .classes()
+            .iter()
+            .filter(|class| !is_concrete(class));
+        for class in classes {
+            let gate = self.features.type_(&class.name);
+            let variants = class.constructors.iter().map(|constructor| {
+                let variant = upper_first(constructor);
+                (self.variant_cfg(gate, &variant), variant)
+            });
+            self.write_enum_size(&mut out, &features::cfg(gate), &class.name, variants);
+        }
+        let variants = self
+            .response_variants()
+            .into_iter()
+            .map(|variant| (self.response_cfg(&variant), variant.name()));
+        self.write_enum_size(&mut out, "", RESPONSE, variants);
+        out.push_str("];\n");
+        out
+    }
+
+    /// Writes the `EnumSize` of an enum whose variants, with their `#[cfg]`,
+    /// are named like their types.
+    fn write_enum_size(
+        &self,
+        out: &mut String,
+        cfg: &str,
+        enum_: &str,
+        variants: impl Iterator<Item = (String, String)>,
+    ) {
+        writeln!(
+            out,
+            "{}EnumSize {{ name: {:?}, size: size_of::<{}>(), estimated: {}, variants: &[",
+            cfg,
+            enum_,
+            enum_,
+            self.boxing.size(enum_)
+        )
+        .unwrap();
+        for (cfg, variant) in variants {
+            writeln!(
+                out,
+                "{}VariantSize {{ name: {:?}, size: size_of::<{}>(), estimated: {}, boxed: {} }},",
+                cfg,
+                variant,
+                variant,
+                self.boxing.size(&variant),
+                self.boxing.is_boxed(enum_, &variant)
+            )
+            .unwrap();
+        }
+        out.push_str("] },\n");
+    }
+
+    fn class(&self, name: &str) -> &'a Class {
+        self.schema
+            .class(name)
+            .unwrap_or_else(|| panic!("class {} has no constructors", name))
+    }
+
+    fn write_struct(&self, module: &mut Module, constructor: &Constructor) {
+        let name = upper_first(&constructor.name);
+        let home = self.features.type_home(&name);
+        let gate = self.features.type_(&name);
+
+        let input = self.builders.is_input(&name);
+        let default = input
+            && !self
+                .builders
+                .has_required(&name, &constructor.class, &constructor.fields);
+
+        let out = module.item(home, gate);
+        if default {
+            out.push_str("#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]\n");
+        } else {
+            out.push_str("#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]\n");
+        }
+        write_doc(out, &constructor.description);
+        self.write_fields(out, &name, &constructor.class, &constructor.fields);
+        writeln!(
+            module.item(home, gate),
+            "impl TlObject for {name} {{ const CLASS: &'static str = {class:?}; \
+             fn type_name(&self) -> &str {{ Self::TYPE }} }}",
+            name = name,
+            class = constructor.class,
+        )
+        .unwrap();
+        writeln!(
+            module.item(home, gate),
+            "impl TlConstructor for {} {{ const TYPE: &'static str = {:?}; }}",
+            name,
+            constructor.name
+        )
+        .unwrap();
+        if input {
+            let out = module.item(home, gate);
+            self.write_builder(out, &name, &constructor.class, &constructor.fields);
+            let out = module.item(home, gate);
+            self.write_validate(out, &name, &constructor.fields);
+        }
+    }
+
+    fn write_method(&self, module: &mut Module, function: &Function) {
+        let name = upper_first(&function.name);
+        let home = self.features.method_home(&name);
+        let gate = self.features.method(&name);
+
+        let out = module.item(home, gate);
+        if self.builders.has_required(&name, "", &function.fields) {
+            out.push_str("#[derive(Serialize, Deserialize, Debug, Clone)]\n");
+        } else {
+            out.push_str("#[derive(Serialize, Deserialize, Debug, Clone, Default)]\n");
+        }
+        write_doc(out, &function.description);
+        self.write_fields(out, &name, "", &function.fields);
+        let flags = metadata::flags(function);
+        let out = module.item(home, gate);
+        write!(
+            out,
+            "impl Method for {} {{ const TYPE: &'static str = {:?}; type Response = {}; ",
+            name, function.name, function.result
+        )
+        .unwrap();
+        for flag in &flags {
+            write!(out, "const {}: bool = true; ", flag).unwrap();
+        }
+        match metadata::authorization_states(function) {
+            None => {
+                out.push_str("const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None; ")
+            }
+            Some(states) if states != ["authorizationStateReady"] => write!(
+                out,
+                "const AUTHORIZATION_STATES: Option<&'static [&'static str]> = Some(&{:?}); ",
+                states
+            )
+            .unwrap(),
+            Some(_) => {}
+        }
+        out.push_str("}\n");
+        if flags.contains(&"SYNCHRONOUS") {
+            writeln!(module.item(home, gate), "impl SyncMethod for {} {{}}", name).unwrap();
+        }
+        self.write_builder(module.item(home, gate), &name, "", &function.fields);
+        self.write_validate(module.item(home, gate), &name, &function.fields);
+    }
+
+    /// Writes `new`, which takes the required fields, and a `with_` setter
+    /// for each of the other fields.
+    fn write_builder(&self, out: &mut String, name: &str, class: &str, fields: &[Field]) {
+        let (required, optional): (Vec<&Field>, Vec<&Field>) = fields
+            .iter()
+            .partition(|field| self.builders.is_required(name, class, field));
+
+        writeln!(out, "impl {} {{", name).unwrap();
+        if required.is_empty() {
+            write_doc(
+                out,
+                "Returns the object with every field set to its default value",
+            );
+            out.push_str("pub fn new() -> Self { Self::default() }\n");
+        } else {
+            write_doc(
+                out,
+                "Returns the object with the given fields, that have no default value, and the others set to their default value",
+            );
+            if required.len() > 7 {
+                out.push_str("#[allow(clippy::too_many_arguments)]\n");
+            }
+            out.push_str("pub fn new(");
+            for field in &required {
+                write!(
+                    out,
+                    "{}: impl Into<{}>, ",
+                    field_name(&field.name),
+                    self.field_type(name, class, field)
+                )
+                .unwrap();
+            }
+            out.push_str(") -> Self { Self {\n");
+            for field in fields {
+                let name = field_name(&field.name);
+                if required.contains(&field) {
+                    writeln!(out, "{}: {}.into(),", name, name).unwrap();
+                } else {
+                    writeln!(out, "{}: Default::default(),", name).unwrap();
+                }
+            }
+            out.push_str("} }\n");
+        }
+        for field in optional {
+            let field_type = self.field_type(name, class, field);
+            let (type_, value) = match field_type.strip_prefix("Option<") {
+                Some(type_) if field.nullable => (&type_[..type_.len() - 1], "Some(value.into())"),
+                _ => (field_type.as_str(), "value.into()"),
+            };
+            write_doc(out, &field.description);
+            writeln!(
+                out,
+                "pub fn with_{}(mut self, value: impl Into<{}>) -> Self {{ self.{} = {}; self }}",
+                field.name,
+                type_,
+                field_name(&field.name),
+                value
+            )
+            .unwrap();
+        }
+        out.push_str("}\n");
+    }
+
+    /// Writes `impl Validate`, which checks the limits documented on the
+    /// fields and validates the objects they hold.
+    fn write_validate(&self, out: &mut String, name: &str, fields: &[Field]) {
+        let checked: Vec<_> = fields
+            .iter()
+            .map(|field| (field, constraints::of(field)))
+            .filter(|(field, constraints)| !constraints.is_empty() || holds_objects(&field.type_))
+            .collect();
+        // Objects without limits keep the default, which accepts them.
+        if checked.is_empty() {
+            writeln!(out, "impl Validate for {} {{}}", name).unwrap();
+            return;
+        }
+        writeln!(out, "impl Validate for {} {{", name).unwrap();
+        out.push_str("fn validate(&self) -> Result<(), ValidationError> {\n");
+        for (field, constraints) in checked {
+            let objects = holds_objects(&field.type_);
+            let constraints: Vec<_> = constraints
+                .into_iter()
+                .filter(|constraint| match constraint {
+                    Constraint::AboveNegated { field: other, .. } => fields
+                        .iter()
+                        .any(|f| f.name == *other && !f.nullable && is_integer(&f.type_)),
+                    _ => true,
+                })
+                .collect();
+            let value = if field.nullable {
+                writeln!(
+                    out,
+                    "if let Some(value) = &self.{} {{",
+                    field_name(&field.name)
+                )
+                .unwrap();
+                "value".to_string()
+            } else {
+                format!("self.{}", field_name(&field.name))
+            };
+            for constraint in &constraints {
+                write_check(out, &value, field, constraint);
+            }
+            if objects {
+                write_nested(out, &value, &field.type_, &field.name, "");
+            }
+            if field.nullable {
+                out.push_str("}\n");
+            }
+        }
+        out.push_str("Ok(()) } }\n");
+    }
+
+    /// Writes `pub struct {name} { .. }`; `class` is the class the struct
+    /// belongs to, to box recursive fields.
+    fn write_fields(&self, out: &mut String, name: &str, class: &str, fields: &[Field]) {
+        writeln!(out, "pub struct {} {{", name).unwrap();
+        for field in fields {
+            if field.name == "type" {
+                out.push_str("#[serde(rename = \"type\")]\n");
+            }
+            match &field.type_ {
+                TlType::Int64 => out.push_str(
+                    "#[serde(deserialize_with = \"crate::utils::from_str_to_t\", \
+                     serialize_with = \"crate::utils::t_to_str\")]\n",
+                ),
+                TlType::Vector(inner) if **inner == TlType::Int64 => out.push_str(
+                    "#[serde(deserialize_with = \"crate::utils::from_str_to_vec_t\", \
+                     serialize_with = \"crate::utils::vec_t_to_str\")]\n",
+                ),
+                _ => {}
+            }
+            if field.nullable {
+                out.push_str("#[serde(default)]\n");
+            }
+            write_doc(out, &field.description);
+            writeln!(
+                out,
+                "pub {}: {},",
+                field_name(&field.name),
+                self.field_type(name, class, field)
+            )
+            .unwrap();
+        }
+        out.push_str("}\n");
+    }
+
+    fn field_type(&self, owner: &str, class: &str, field: &Field) -> String {
+        let type_ = match &field.type_ {
+            TlType::Vector(element) if field.nullable_elements => {
+                format!("Vec<Option<{}>>", self.rust_type(element))
+            }
+            // A class can't contain itself without indirection.
+            TlType::Object(name) if upper_first(name) == class => {
+                format!("Box<{}>", class)
+            }
+            type_ => self.rust_type(type_),
+        };
+        if field.nullable {
+            return format!("Option<{}>", type_);
+        }
+        match ids::newtype_of(owner, &field.name, &type_) {
+            Some(newtype) => newtype,
+            None => type_,
+        }
+    }
+
+    fn rust_type(&self, type_: &TlType) -> String {
+        match type_ {
+            TlType::Bool => "bool".to_string(),
+            TlType::Int32 => "i32".to_string(),
+            TlType::Int53 | TlType::Int64 => "i64".to_string(),
+            TlType::Double => "f64".to_string(),
+            TlType::String => "String".to_string(),
+            TlType::Bytes => "Bytes".to_string(),
+            TlType::Vector(element) => format!("Vec<{}>", self.rust_type(element)),
+            TlType::Object(name) => upper_first(name),
+        }
+    }
+
+    /// The `#[cfg]` a variant of an enum compiled with `gate` needs, for the
+    /// constructor `name`.
+    fn variant_cfg(&self, gate: &Gate, name: &str) -> String {
+        let variant = self.features.type_(name);
+        if features::implies(gate, variant) {
+            String::new()
+        } else {
+            features::cfg(variant)
+        }
+    }
+
+    fn write_enum(&self, module: &mut Module, class: &Class) {
+        let gate = self.features.type_(&class.name);
+        let out = module.item(self.features.type_home(&class.name), gate);
+        out.push_str("#[derive(Serialize, Debug, Clone, PartialEq)]\n");
+        out.push_str("#[serde(rename_all = \"camelCase\")]\n");
+        out.push_str("#[serde(tag = \"@type\")]\n");
+        write_doc(out, &class.description);
+        writeln!(out, "pub enum {} {{", class.name).unwrap();
+        for constructor in &class.constructors {
+            let variant = upper_first(constructor);
+            out.push_str(&self.variant_cfg(gate, &variant));
+            writeln!(out, "{}({}),", variant, self.boxed(&class.name, &variant)).unwrap();
+        }
+        out.push_str("#[serde(untagged)]\nUnknown(Unknown),\n}\n");
+
+        // Deserialized by hand, so that only objects whose @type matches no
+        // variant end up in Unknown: an untagged variant of a derived
+        // Deserialize would also take the known ones whose fields are wrong.
+        let out = module.item(self.features.type_home(&class.name), gate);
+        writeln!(
+            out,
+            "impl<'de> Deserialize<'de> for {} {{
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {{
+                    let (type_, value) = crate::utils::tagged(deserializer)?;
+                    match type_.as_str() {{",
+            class.name
+        )
+        .unwrap();
+        for constructor in &class.constructors {
+            let variant = upper_first(constructor);
+            writeln!(
+                out,
+                "{}{:?} => Deserialize::deserialize(value).map({}::{}),",
+                self.variant_cfg(gate, &variant),
+                constructor,
+                class.name,
+                variant
+            )
+            .unwrap();
+        }
+        writeln!(
+            out,
+            "_ => Unknown::deserialize(value).map({}::Unknown),
+                    }}
+                    .map_err(serde::de::Error::custom)
+                }}
+            }}",
+            class.name
+        )
+        .unwrap();
+
+        let out = module.item(self.features.type_home(&class.name), gate);
+        // Indented, because rustfmt leaves alone the matches it can't fit in
+        // its maximum width.
+        writeln!(out, "    impl TlObject for {} {{", class.name).unwrap();
+        writeln!(out, "        const CLASS: &'static str = {:?};", class.name).unwrap();
+        out.push_str("        fn type_name(&self) -> &str {\n");
+        out.push_str("            match self {\n");
+        for constructor in &class.constructors {
+            let variant = upper_first(constructor);
+            let cfg = self.variant_cfg(gate, &variant);
+            if !cfg.is_empty() {
+                write!(out, "                {}", cfg).unwrap();
+            }
+            writeln!(
+                out,
+                "                {}::{}(_) => {:?},",
+                class.name, variant, constructor
+            )
+            .unwrap();
+        }
+        writeln!(
+            out,
+            "                {}::Unknown(object) => &object.type_,",
+            class.name
+        )
+        .unwrap();
+        out.push_str("            }\n        }\n    }\n");
+
+        if !class
+            .constructors
+            .iter()
+            .any(|constructor| self.builders.is_input(&upper_first(constructor)))
+        {
+            return;
+        }
+
+        let out = module.item(self.features.type_home(&class.name), gate);
+        writeln!(out, "    impl Validate for {} {{", class.name).unwrap();
+        out.push_str("        fn validate(&self) -> Result<(), ValidationError> {\n");
+        out.push_str("            match self {\n");
+        for constructor in &class.constructors {
+            let variant = upper_first(constructor);
+            let cfg = self.variant_cfg(gate, &variant);
+            if !cfg.is_empty() {
+                write!(out, "                {}", cfg).unwrap();
+            }
+            writeln!(
+                out,
+                "                {}::{}(value) => value.validate(),",
+                class.name, variant
+            )
+            .unwrap();
+        }
+        writeln!(out, "                {}::Unknown(_) => Ok(()),", class.name).unwrap();
+        out.push_str("            }\n        }\n    }\n");
+
+        // Lets builders take the structs of the variants in place of the
+        // enum.
+        for constructor in &class.constructors {
+            let variant = upper_first(constructor);
+            if !self.builders.is_input(&variant) {
+                continue;
+            }
+            let value = if self.boxing.is_boxed(&class.name, &variant) {
+                "Box::new(value)"
+            } else {
+                "value"
+            };
+            let cfg = self.variant_cfg(gate, &variant);
+            let out = module.item(self.features.type_home(&class.name), gate);
+            writeln!(
+                out,
+                "{cfg}impl From<{variant}> for {class} {{ \
+                 fn from(value: {variant}) -> Self {{ {class}::{variant}({value}) }} }}",
+                cfg = cfg,
+                variant = variant,
+                class = class.name,
+                value = value
+            )
+            .unwrap();
+        }
+    }
+
+    fn boxed(&self, enum_: &str, variant: &str) -> String {
+        if self.boxing.is_boxed(enum_, variant) {
+            format!("Box<{}>", variant)
+        } else {
+            variant.to_string()
+        }
+    }
+
+    /// The variants of `Response`: the classes with a single constructor of
+    /// the same name, the constructors of `Update`, and the other classes,
+    /// whose variants are untagged and so come last.
+    fn response_variants(&self) -> Vec<ResponseVariant<'a>> {
+        let classes = self.schema.classes();
+        let concrete = classes
+            .iter()
+            .filter(|class| is_concrete(class))
+            .map(ResponseVariant::Class);
+        let updates = self
+            .class(UPDATE)
+            .constructors
+            .iter()
+            .map(|constructor| ResponseVariant::Update(constructor));
+        let abstract_ = classes
+            .iter()
+            .filter(|class| !is_concrete(class) && class.name != UPDATE)
+            .map(ResponseVariant::Class);
+        concrete.chain(updates).chain(abstract_).collect()
+    }
+
+    /// The `#[cfg]` of a variant of `Response`.
+    fn response_cfg(&self, variant: &ResponseVariant) -> String {
+        features::cfg(self.features.type_(&variant.name()))
+    }
+
+    /// The `@type` patterns of the objects held by a variant of `Response`,
+    /// with the `#[cfg]`s they need.
+    fn response_arms(&self, variant: &ResponseVariant) -> Vec<(String, String)> {
+        match variant {
+            ResponseVariant::Class(class) => self.arms(class),
+            ResponseVariant::Update(constructor) => {
+                vec![(self.response_cfg(variant), format!("{:?}", constructor))]
+            }
+        }
+    }
+
+    /// The `@type` patterns of the constructors of a class, with the
+    /// `#[cfg]`s they need in a match on a `Response`: the constructors
+    /// compiled with the class share an arm, the others have one each.
+    fn arms(&self, class: &Class) -> Vec<(String, String)> {
+        let gate = self.features.type_(&class.name);
+        let class_cfg = features::cfg(gate);
+        let mut shared = Vec::new();
+        let mut arms = Vec::new();
+        for constructor in &class.constructors {
+            let variant = self.features.type_(&upper_first(constructor));
+            if features::implies(gate, variant) {
+                shared.push(format!("{:?}", constructor));
+            } else {
+                arms.push((
+                    features::cfg_both(gate, variant),
+                    format!("{:?}", constructor),
+                ));
+            }
+        }
+        if !shared.is_empty() {
+            arms.insert(0, (class_cfg, shared.join(" | ")));
+        }
+        arms
+    }
+
+    fn write_response(&self, out: &mut String) {
+        let variants = self.response_variants();
+
+        out.push_str("#[derive(Serialize, Debug, Clone, PartialEq)]\n");
+        out.push_str("#[serde(rename_all = \"camelCase\")]\n");
+        out.push_str("#[serde(tag = \"@type\")]\n");
+        write_doc(
+            out,
+            "Any object that can be returned by a function call. Abstract classes are matched by the @type of any of their constructors, and every update has a variant of its own",
+        );
+        out.push_str("pub enum Response {\n");
+        for variant in &variants {
+            out.push_str(&self.response_cfg(variant));
+            if let ResponseVariant::Class(class) = variant {
+                if !is_concrete(class) {
+                    out.push_str("#[serde(untagged)]\n");
+                }
+            }
+            let name = variant.name();
+            writeln!(out, "{}({}),", name, self.boxed(RESPONSE, &name)).unwrap();
+        }
+        out.push_str("#[serde(untagged)]\nUnknown(Unknown),\n}\n");
+
+        out.push_str("impl Response {\n");
+        write_doc(out, "Returns the @type of the object");
+        out.push_str("pub fn type_name(&self) -> &str { match self {\n");
+        for variant in &variants {
+            writeln!(
+                out,
+                "{}Response::{}(object) => object.type_name(),",
+                self.response_cfg(variant),
+                variant.name()
+            )
+            .unwrap();
+        }
+        out.push_str("Response::Unknown(object) => &object.type_,\n} }\n");
+        write_doc(
+            out,
+            "Returns the name of the variant that holds objects with the given @type, or None if such objects are kept as Unknown",
+        );
+        out.push_str(
+            "pub fn variant_of(type_: &str) -> Option<&'static str> { Some(match type_ {\n",
+        );
+        for variant in &variants {
+            for (cfg, pattern) in self.response_arms(variant) {
+                writeln!(out, "{}{} => {:?},", cfg, pattern, variant.name()).unwrap();
+            }
+        }
+        out.push_str("_ => return None, }) } }\n");
+
+        // Like the enums of abstract classes, only objects whose @type
+        // matches no variant are Unknown.
+        out.push_str(
+            "impl<'de> Deserialize<'de> for Response {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    let (type_, value) = crate::utils::tagged(deserializer)?;
+                    match type_.as_str() {\n",
+        );
+        for variant in &variants {
+            for (cfg, pattern) in self.response_arms(variant) {
+                writeln!(
+                    out,
+                    "{}{} => Deserialize::deserialize(value).map(Response::{}),",
+                    cfg,
+                    pattern,
+                    variant.name()
+                )
+                .unwrap();
+            }
+        }
+        out.push_str(
+            "_ => Unknown::deserialize(value).map(Response::Unknown),
+                    }
+                    .map_err(serde::de::Error::custom)
+                }
+            }\n",
+        );
+
+        for variant in &variants {
+            let name = variant.name();
+            let value = if self.boxing.is_boxed(RESPONSE, &name) {
+                "*x"
+            } else {
+                "x"
+            };
+            writeln!(
+                out,
+                "{cfg}impl std::convert::TryFrom<Response> for {name} {{
+                    type Error = Response;
+                    fn try_from(response: Response) -> Result<Self, Self::Error> {{
+                        match response {{
+                            Response::{name}(x) => Ok({value}),
+                            response => Err(response),
+                        }}
+                    }}
+                }}",
+                cfg = self.response_cfg(variant),
+                name = name,
+                value = value
+            )
+            .unwrap();
+        }
+        self.write_update_conversions(out);
+    }
+
+    /// Converts between the updates held by `Response` and `Update`, for
+    /// the methods that return an `Update`.
+    fn write_update_conversions(&self, out: &mut String) {
+        let class = self.class(UPDATE);
+        let gate = self.features.type_(UPDATE);
+        // Both enums box the variants above the same size.
+        let arms: Vec<_> = class
+            .constructors
+            .iter()
+            .map(|constructor| {
+                let variant = upper_first(constructor);
+                let into_update = match (
+                    self.boxing.is_boxed(RESPONSE, &variant),
+                    self.boxing.is_boxed(UPDATE, &variant),
+                ) {
+                    (true, false) => "*x",
+                    (false, true) => "Box::new(x)",
+                    _ => "x",
+                };
+                let into_response = match into_update {
+                    "*x" => "Box::new(x)",
+                    "Box::new(x)" => "*x",
+                    _ => "x",
+                };
+                (
+                    self.variant_cfg(gate, &variant),
+                    variant,
+                    into_update,
+                    into_response,
+                )
+            })
+            .collect();
+
+        out.push_str(&features::cfg(gate));
+        out.push_str(
+            "impl std::convert::TryFrom<Response> for Update {
+                type Error = Response;
+                fn try_from(response: Response) -> Result<Self, Self::Error> {
+                    match response {\n",
+        );
+        for (cfg, variant, into_update, _) in &arms {
+            writeln!(
+                out,
+                "{}Response::{variant}(x) => Ok(Update::{variant}({})),",
+                cfg,
+                into_update,
+                variant = variant
+            )
+            .unwrap();
+        }
+        out.push_str("response => Err(response), } } }\n");
+
+        out.push_str(&features::cfg(gate));
+        out.push_str(
+            "impl From<Update> for Response {
+                fn from(update: Update) -> Self {
+                    match update {\n",
+        );
+        for (cfg, variant, _, into_response) in &arms {
+            writeln!(
+                out,
+                "{}Update::{variant}(x) => Response::{variant}({}),",
+                cfg,
+                into_response,
+                variant = variant
+            )
+            .unwrap();
+        }
+        out.push_str("Update::Unknown(x) => Response::Unknown(x), } } }\n");
+    }
+}
+
+/// A variant of `Response`, named like the type it holds.
+enum ResponseVariant<'a> {
+    Class(&'a Class),
+    /// A constructor of `Update`.
+    Update(&'a str),
+}
+
+impl ResponseVariant<'_> {
+    fn name(&self) -> String {
+        match self {
+            ResponseVariant::Class(class) => class.name.clone(),
+            ResponseVariant::Update(constructor) => upper_first(constructor),
+        }
+    }
+}
+
+fn is_integer(type_: &TlType) -> bool {
+    matches!(type_, TlType::Int32 | TlType::Int53 | TlType::Int64)
+}
+
+/// Whether values of the type are or contain objects, which are validated
+/// in turn.
+fn holds_objects(type_: &TlType) -> bool {
+    match type_ {
+        TlType::Object(_) => true,
+        TlType::Vector(element) => holds_objects(element),
+        _ => false,
+    }
+}
+
+/// Writes the check of a limit of a field, whose value is `value`.
+fn write_check(out: &mut String, value: &str, field: &Field, constraint: &Constraint) {
+    let (measure, actual, bound) = match constraint {
+        Constraint::Characters(bound) => (
+            "Characters",
+            format!("{}.chars().count() as i64", value),
+            bound,
+        ),
+        Constraint::Bytes(bound) => ("Bytes", format!("{}.len() as i64", value), bound),
+        Constraint::Elements(bound) => ("Elements", format!("{}.len() as i64", value), bound),
+        Constraint::Value(bound) => (
+            "Value",
+            format!("i64::from({}{})", deref(value), value),
+            bound,
+        ),
+        Constraint::EachCharacters(bound) => {
+            writeln!(
+                out,
+                "for (i, value) in {}.iter().enumerate() {{ validate::check(Self::TYPE, &format!(\"{}[{{}}]\", i), \
+                 Measure::Characters, value.chars().count() as i64, {}, {})?; }}",
+                value,
+                field.name,
+                option(bound.min),
+                option(bound.max)
+            )
+            .unwrap();
+            return;
+        }
+        Constraint::AboveNegated {
+            field: other,
+            or_equal,
+        } => {
+            // Only binding when the other field is negative, as its own
+            // bounds apply otherwise.
+            writeln!(
+                out,
+                "validate::check(Self::TYPE, {:?}, Measure::Value, i64::from({}{}), \
+                 Some({}-i64::from(self.{})), None)?;",
+                field.name,
+                deref(value),
+                value,
+                if *or_equal { "" } else { "1 " },
+                field_name(other)
+            )
+            .unwrap();
+            return;
+        }
+        Constraint::OneOf(values) => {
+            writeln!(
+                out,
+                "validate::check_one_of(Self::TYPE, {:?}, i64::from({}{}), &{:?})?;",
+                field.name,
+                deref(value),
+                value,
+                values
+            )
+            .unwrap();
+            return;
+        }
+    };
+    let Bound { min, max } = bound;
+    writeln!(
+        out,
+        "validate::check(Self::TYPE, {:?}, Measure::{}, {}, {}, {})?;",
+        field.name,
+        measure,
+        actual,
+        option(*min),
+        option(*max)
+    )
+    .unwrap();
+}
+
+/// Writes the validation of the objects held by `value`, of type `type_`, at
+/// the path `field` followed by the indexes formatted by `indexes`.
+fn write_nested(out: &mut String, value: &str, type_: &TlType, field: &str, indexes: &str) {
+    match type_ {
+        TlType::Vector(element) => {
+            let depth = indexes.len() / 4;
+            writeln!(
+                out,
+                "for ({}, value) in {}.iter().enumerate() {{",
+                INDEXES[depth], value
+            )
+            .unwrap();
+            let indexes = format!("{}[{{}}]", indexes);
+            write_nested(out, "value", element, field, &indexes);
+            out.push_str("}\n");
+        }
+        _ if indexes.is_empty() => {
+            writeln!(
+                out,
+                "{}.validate().map_err(|err| err.within(Self::TYPE, {:?}))?;",
+                value, field
+            )
+            .unwrap();
+        }
+        _ => {
+            let depth = indexes.len() / 4;
+            writeln!(
+                out,
+                "{}.validate().map_err(|err| err.within(Self::TYPE, &format!(\"{}{}\", {})))?;",
+                value,
+                field,
+                indexes,
+                INDEXES[..depth].join(", ")
+            )
+            .unwrap();
+        }
+    }
+}
+
+/// The names of the indexes of nested vectors.
+const INDEXES: &[&str] = &["i", "j"];
+
+/// `*` if `value` is a reference bound by a pattern rather than a field.
+fn deref(value: &str) -> &'static str {
+    if value.starts_with("self.") {
+        ""
+    } else {
+        "*"
+    }
+}
+
+fn option(value: Option<i64>) -> String {
+    match value {
+        Some(value) => format!("Some({})", value),
+        None => "None".to_string(),
+    }
+}
+
+/// Whether the class is a single constructor of the same name, generated as
+/// a struct only.
+pub fn is_concrete(class: &Class) -> bool {
+    match class.constructors.as_slice() {
+        [constructor] => upper_first(constructor) == class.name,
+        _ => false,
+    }
+}
+
+fn field_name(name: &str) -> &str {
+    match name {
+        "type" => "type_",
+        name => name,
+    }
+}
+
+fn write_doc(out: &mut String, doc: &str) {
+    out.push_str("#[doc = \"");
+    for c in doc.chars() {
+        match c {
+            '"' | '\\' => {
+                out.push('\\');
+                out.push(c);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push_str("\"]\n");
+}
+
+pub fn upper_first(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
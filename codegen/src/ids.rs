@@ -0,0 +1,83 @@
+//! Identifier newtypes of `int32`/`int53` fields.
+//!
+//! They are chosen by the name of the field and, for `id`, by the object it
+//! identifies, as the schema doesn't say which integers are identifiers (see
+//! [`tl_parser`]).
+
+/// The newtype of the `id` field of these constructors.
+const OWN_IDS: &[(&str, &str)] = &[
+    ("Chat", "ChatId"),
+    ("User", "UserId"),
+    ("Message", "MessageId"),
+    ("File", "FileId"),
+    ("InputFileId", "FileId"),
+    ("BasicGroup", "BasicGroupId"),
+    ("Supergroup", "SupergroupId"),
+    ("SecretChat", "SecretChatId"),
+    ("NotificationGroup", "NotificationGroupId"),
+    ("Notification", "NotificationId"),
+];
+
+/// Field name suffixes, the Rust type they must have and their newtype.
+/// `secret_chat_id` comes before `chat_id` so it is not taken for a chat.
+const SUFFIXES: &[(&str, &str, &str)] = &[
+    ("secret_chat_id", "i32", "SecretChatId"),
+    ("chat_id", "i64", "ChatId"),
+    ("user_id", "i32", "UserId"),
+    ("message_id", "i64", "MessageId"),
+    ("file_id", "i32", "FileId"),
+    ("basic_group_id", "i32", "BasicGroupId"),
+    ("supergroup_id", "i32", "SupergroupId"),
+    ("notification_group_id", "i32", "NotificationGroupId"),
+    ("notification_id", "i32", "NotificationId"),
+];
+
+/// The newtype of a field of `owner` named `field`, whose TL type maps to
+/// `base` (or to a vector of `base`).
+pub fn newtype(owner: &str, field: &str, base: &str) -> Option<&'static str> {
+    // The "supergroup" of a t.me link is the chat of the supergroup.
+    if owner == "TMeUrlTypeSupergroup" && field == "supergroup_id" {
+        return Some("ChatId");
+    }
+    if field == "id" {
+        return OWN_IDS
+            .iter()
+            .find(|(name, _)| *name == owner)
+            .map(|(_, newtype)| *newtype);
+    }
+    if matches!(field, "sticker_ids" | "animation_ids")
+        && matches!(
+            owner,
+            "UpdateRecentStickers" | "UpdateFavoriteStickers" | "UpdateSavedAnimations"
+        )
+    {
+        return Some("FileId");
+    }
+    let singular = field.strip_suffix('s').unwrap_or(field);
+    SUFFIXES
+        .iter()
+        .find(|(suffix, rust, _)| {
+            *rust == base
+                && singular.ends_with(suffix)
+                && (singular.len() == suffix.len()
+                    || singular.as_bytes()[singular.len() - suffix.len() - 1] == b'_')
+        })
+        .map(|(_, _, newtype)| *newtype)
+}
+
+/// `type_` with its integers replaced by their newtype, if the field has one.
+pub fn newtype_of(owner: &str, field: &str, type_: &str) -> Option<String> {
+    let (element, vector) = match type_.strip_prefix("Vec<") {
+        Some(element) => (element.strip_suffix('>')?, true),
+        None => (type_, false),
+    };
+    if !matches!(element, "i32" | "i64") {
+        return None;
+    }
+    let newtype = newtype(owner, field, element)?;
+    Some(if vector {
+        format!("Vec<{}>", newtype)
+    } else {
+        newtype.to_string()
+    })
+}
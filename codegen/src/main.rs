@@ -0,0 +1,127 @@
+//! Generates the `types` and `methods` modules of tdlib-types from the
+//! `td_api.tl` of each TDLib release.
+//!
+//! ```text
+//! tl-codegen [SCHEMA_DIR [SRC_DIR]]
+//! ```
+//!
+//! Reads `schema/<version>/td_api.tl` of every release and writes the module
+//! of each to `src/tdlib_<version>/`, and `src/lib.rs` selecting one of them
+//! with the `tdlib-<version>` feature, then formats the output with
+//! `rustfmt`. Run it with `cargo run -p tl-codegen` after changing a schema
+//! or the generator. A release added to `schema/` also needs its feature in
+//! `Cargo.toml`.
+//!
+//! The committed code is formatted by rustfmt [`RUSTFMT_VERSION`], and other
+//! versions are refused, since they may format it differently.
+//! `rust-toolchain.toml` pins the toolchain that ships it. `$RUSTFMT` names
+//! the rustfmt to run instead of the one on the `PATH`.
+
+mod boxing;
+mod builders;
+mod constraints;
+mod domains;
+mod features;
+mod generator;
+mod ids;
+mod metadata;
+mod releases;
+
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use tl_parser::Schema;
+
+const DEFAULT_SCHEMA_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../schema");
+const DEFAULT_SRC_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../src");
+/// The rustfmt the generated code is formatted with, the one of the
+/// toolchain in `rust-toolchain.toml`.
+const RUSTFMT_VERSION: &str = "1.9.0";
+
+fn fail(message: String) -> ! {
+    eprintln!("tl-codegen: {}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let schema_dir = PathBuf::from(
+        args.next()
+            .unwrap_or_else(|| DEFAULT_SCHEMA_DIR.to_string()),
+    );
+    let src_dir = PathBuf::from(args.next().unwrap_or_else(|| DEFAULT_SRC_DIR.to_string()));
+
+    check_rustfmt().unwrap_or_else(|err| fail(format!("rustfmt: {}", err)));
+    let releases = releases::find(&schema_dir)
+        .unwrap_or_else(|err| fail(format!("{}: {}", schema_dir.display(), err)));
+    if releases.is_empty() {
+        fail(format!(
+            "{}: no schema/<version>/td_api.tl",
+            schema_dir.display()
+        ));
+    }
+    write(&src_dir.join("lib.rs"), &releases::lib(&releases));
+    for release in &releases {
+        let path = release.schema.display();
+        let source = fs::read_to_string(&release.schema)
+            .unwrap_or_else(|err| fail(format!("{}: {}", path, err)));
+        let schema =
+            Schema::parse(&source).unwrap_or_else(|err| fail(format!("{}: {}", path, err)));
+        let dir = src_dir.join(release.module());
+        for (path, code) in generator::generate(&schema, release) {
+            write(&dir.join(path), &code);
+        }
+    }
+}
+
+/// Formats `code` and writes it to `path`, creating its directory.
+fn write(path: &Path, code: &str) {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).unwrap_or_else(|err| fail(format!("{}: {}", dir.display(), err)));
+    }
+    let code = rustfmt(code).unwrap_or_else(|err| fail(format!("rustfmt: {}", err)));
+    fs::write(path, code).unwrap_or_else(|err| fail(format!("{}: {}", path.display(), err)));
+}
+
+fn rustfmt_command() -> Command {
+    Command::new(env::var_os("RUSTFMT").unwrap_or_else(|| "rustfmt".into()))
+}
+
+/// Checks that rustfmt is the pinned version, as in
+/// `rustfmt 1.9.0-stable (59807616e1 2026-04-14)`.
+fn check_rustfmt() -> io::Result<()> {
+    let output = rustfmt_command().arg("--version").output()?;
+    let version = String::from_utf8_lossy(&output.stdout);
+    let number = version.split_whitespace().nth(1).unwrap_or_default();
+    match number.split('-').next() {
+        Some(RUSTFMT_VERSION) => Ok(()),
+        _ => Err(io::Error::other(format!(
+            "found {:?}, but the code is formatted with rustfmt {}; \
+             set RUSTFMT to the path of that version",
+            version.trim(),
+            RUSTFMT_VERSION
+        ))),
+    }
+}
+
+/// Formats a file through rustfmt's standard input, which leaves the
+/// out-of-line modules it declares alone.
+fn rustfmt(code: &str) -> io::Result<String> {
+    let mut child = rustfmt_command()
+        .args(["--edition", "2018", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    child
+        .stdin
+        .take()
+        .expect("stdin is piped")
+        .write_all(code.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        return Err(io::Error::other(format!("exited with {}", output.status)));
+    }
+    String::from_utf8(output.stdout).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
@@ -0,0 +1,112 @@
+//! What the description of a method says about how it can be called, read
+//! from the sentences `td_api.tl` uses for it, and the authorization states
+//! it works in.
+//!
+//! Like the rest of what [`tl_parser`] reads from the documentation, a method
+//! whose description lacks the phrase gets `false`. No description of TDLib
+//! 1.6.0 says a method "can't be used by bots", so `USERS_ONLY` is false for
+//! all of its methods.
+
+use tl_parser::Function;
+
+/// The associated constants of `Method` that are `true` for a method.
+pub fn flags(function: &Function) -> Vec<&'static str> {
+    let doc = function.description.as_str();
+    let says = |phrase: &str| doc.to_lowercase().contains(&phrase.to_lowercase());
+    let flags = [
+        ("OFFLINE", is_offline(doc)),
+        ("SYNCHRONOUS", says("Can be called synchronously")),
+        ("BEFORE_AUTHORIZATION", works_before_authorization(function)),
+        ("BOTS_ONLY", says("for bots only")),
+        ("USERS_ONLY", says("can't be used by bots")),
+    ];
+    flags
+        .iter()
+        .filter(|(_, set)| *set)
+        .map(|(flag, _)| *flag)
+        .collect()
+}
+
+/// Whether the method works in some state before authorization: in every
+/// state, or in one of [`authorization_states`] other than
+/// `authorizationStateReady`, like `setTdlibParameters` or
+/// `checkAuthenticationCode`.
+fn works_before_authorization(function: &Function) -> bool {
+    match authorization_states(function) {
+        None => true,
+        Some(states) => states.iter().any(|&state| state != READY),
+    }
+}
+
+/// Whether the method is "an offline method" or "an offline request", but
+/// not only under a condition, as in "an offline request if only_local is
+/// true".
+fn is_offline(doc: &str) -> bool {
+    ["offline method", "offline request"].iter().any(|phrase| {
+        doc.match_indices(phrase)
+            .any(|(i, _)| !doc[i + phrase.len()..].starts_with(" if "))
+    })
+}
+
+/// The @type of the state the client is in once authorized, the only one
+/// most methods work in.
+const READY: &str = "authorizationStateReady";
+
+/// Methods that work in every authorization state although their
+/// descriptions don't say they can be called before authorization.
+const ANY_STATE: &[&str] = &[
+    // Closes the instance whatever its state, e.g. to shut down a client
+    // still waiting for a phone number.
+    "close",
+    // Destroys the local data whatever the state, e.g. of a client that can't
+    // get past authorization.
+    "destroy",
+    // Logs out in any state; before authorization it just destroys the
+    // local data. The flow sends it in authorizationStateWaitRegistration
+    // when the terms of service are declined.
+    "logOut",
+    // Returns the state, so it has to work in each of them.
+    "getAuthorizationState",
+];
+
+/// The @types of the authorization states a method works in, or `None` if
+/// it works in every state.
+///
+/// Methods that "can be called before authorization" work in every state,
+/// the ones that work "only when the current authorization state is" some
+/// states in those, and the others once authorized.
+pub fn authorization_states(function: &Function) -> Option<Vec<&str>> {
+    let doc = function.description.as_str();
+    if ANY_STATE.contains(&function.name.as_str()) {
+        return None;
+    }
+    let mut states = Vec::new();
+    if let Some(i) = doc.find("Can be called before authorization") {
+        let sentence = sentence(&doc[i..]);
+        if !sentence.contains("authorizationState") {
+            return None;
+        }
+        // Only in some of the states before authorization.
+        states.push(READY);
+        states.extend(mentioned_states(sentence));
+    } else if let Some(i) = doc.find("Works only when the current authorization state is") {
+        states.extend(mentioned_states(sentence(&doc[i..])));
+    } else {
+        states.push(READY);
+    }
+    Some(states)
+}
+
+/// The text up to the end of the first sentence.
+fn sentence(text: &str) -> &str {
+    match text.find(". ") {
+        Some(end) => &text[..end],
+        None => text.trim_end_matches('.'),
+    }
+}
+
+/// The @types of the authorization states named in a text.
+fn mentioned_states(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| word.starts_with("authorizationState"))
+}
@@ -0,0 +1,113 @@
+//! The TDLib releases the crate is generated for, one per directory of
+//! `schema/` holding the `td_api.tl` of that release, and the part of
+//! `lib.rs` that selects one of them with a cargo feature.
+
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const HEADER: &str = include_str!("../templates/header.rs.in");
+
+/// A TDLib release with a vendored `td_api.tl`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Release {
+    pub version: (u32, u32, u32),
+    /// The `td_api.tl` of the release.
+    pub schema: PathBuf,
+}
+
+impl Release {
+    /// The version, as in `1.6.0`.
+    pub fn name(&self) -> String {
+        let (major, minor, patch) = self.version;
+        format!("{}.{}.{}", major, minor, patch)
+    }
+
+    /// The cargo feature selecting the release, as in `tdlib-1-6-0`.
+    pub fn feature(&self) -> String {
+        format!("tdlib-{}", self.name().replace('.', "-"))
+    }
+
+    /// The module the types and methods of the release are generated in,
+    /// relative to `src`, as in `tdlib_1_6_0`.
+    pub fn module(&self) -> String {
+        self.feature().replace('-', "_")
+    }
+}
+
+/// The releases in `schema_dir`, from the oldest: every directory named
+/// after a version with a `td_api.tl` in it.
+pub fn find(schema_dir: &Path) -> io::Result<Vec<Release>> {
+    let mut releases = Vec::new();
+    for entry in fs::read_dir(schema_dir)? {
+        let path = entry?.path();
+        let schema = path.join("td_api.tl");
+        let version = path
+            .file_name()
+            .and_then(|name| parse_version(name.to_str()?));
+        if let (Some(version), true) = (version, schema.is_file()) {
+            releases.push(Release { version, schema });
+        }
+    }
+    releases.sort();
+    Ok(releases)
+}
+
+fn parse_version(name: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = name.split('.').map(|part| part.parse().ok());
+    match (parts.next()?, parts.next()?, parts.next()?, parts.next()) {
+        (Some(major), Some(minor), Some(patch), None) => Some((major, minor, patch)),
+        _ => None,
+    }
+}
+
+/// `lib.rs`: the hand-written modules of the crate, and the module of the
+/// release whose feature is enabled, re-exported at the root.
+pub fn lib(releases: &[Release]) -> String {
+    let mut out = String::new();
+    let features: Vec<String> = releases
+        .iter()
+        .map(|release| format!("feature = \"{}\"", release.feature()))
+        .collect();
+    writeln!(
+        out,
+        "#[cfg(not(any({})))]\n\
+         compile_error!(\"enable the feature of the TDLib release to build for, e.g. `{}`\");",
+        features.join(", "),
+        releases.last().map_or(String::new(), Release::feature),
+    )
+    .unwrap();
+    for (i, a) in releases.iter().enumerate() {
+        for b in &releases[i + 1..] {
+            writeln!(
+                out,
+                "#[cfg(all(feature = \"{a}\", feature = \"{b}\"))]\n\
+                 compile_error!(\"enable the feature of one TDLib release only, not both `{a}` and `{b}`\");",
+                a = a.feature(),
+                b = b.feature(),
+            )
+            .unwrap();
+        }
+    }
+    out.push('\n');
+    out.push_str(HEADER);
+    for release in releases {
+        writeln!(
+            out,
+            "#[cfg(feature = \"{feature}\")]\n\
+             mod {module};\n\
+             #[cfg(feature = \"{feature}\")]\n\
+             use {module} as release;",
+            feature = release.feature(),
+            module = release.module(),
+        )
+        .unwrap();
+    }
+    out.push_str(
+        "pub use release::{methods, types};\n\
+         #[cfg(feature = \"sizes\")]\n\
+         pub use release::sizes;\n",
+    );
+    out
+}
@@ -0,0 +1,186 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn crate_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn rust_files(dir: &Path, prefix: &Path, out: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        let name = prefix.join(path.file_name().unwrap());
+        if path.is_dir() {
+            rust_files(&path, &name, out);
+        } else {
+            out.push(name);
+        }
+    }
+}
+
+/// Runs tl-codegen on `schema_dir` and returns the directory it wrote to.
+fn generate(schema_dir: &Path, name: &str) -> PathBuf {
+    let out = std::env::temp_dir().join(format!("tl-codegen-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&out);
+    let status = Command::new(env!("CARGO_BIN_EXE_tl-codegen"))
+        .arg(schema_dir)
+        .arg(&out)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    out
+}
+
+#[test]
+fn generated_code_is_up_to_date() {
+    let out = generate(&crate_dir().join("schema"), "src");
+
+    let mut files = Vec::new();
+    rust_files(&out, Path::new(""), &mut files);
+    assert!(files.contains(&PathBuf::from("lib.rs")));
+    assert!(files.contains(&PathBuf::from("tdlib_1_6_0/mod.rs")));
+    for file in &files {
+        let generated = fs::read_to_string(out.join(file)).unwrap();
+        let committed = fs::read_to_string(crate_dir().join("src").join(file)).unwrap();
+        assert!(
+            generated == committed,
+            "src/{} differs from the output of tl-codegen",
+            file.display()
+        );
+    }
+    for entry in fs::read_dir(crate_dir().join("src")).unwrap() {
+        let name = entry.unwrap().file_name();
+        if !name.to_str().unwrap().starts_with("tdlib_") {
+            continue;
+        }
+        let mut committed = Vec::new();
+        rust_files(
+            &crate_dir().join("src").join(&name),
+            Path::new(&name),
+            &mut committed,
+        );
+        for file in committed {
+            assert!(
+                files.contains(&file),
+                "src/{} is not generated by tl-codegen",
+                file.display()
+            );
+        }
+    }
+    fs::remove_dir_all(&out).unwrap();
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let path = entry.unwrap().path();
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_dir(&path, &target);
+        } else {
+            fs::copy(&path, &target).unwrap();
+        }
+    }
+}
+
+/// `cargo check` of the library of the crate in `dir`, with the given
+/// features only.
+fn check(dir: &Path, features: &str) -> (bool, String) {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .current_dir(dir)
+        .args([
+            "check",
+            "--quiet",
+            "--offline",
+            "--lib",
+            "--no-default-features",
+        ])
+        .args(["--features", features])
+        .env(
+            "CARGO_TARGET_DIR",
+            crate_dir().join("target").join("releases"),
+        )
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+#[test]
+fn other_rustfmt_versions_are_refused() {
+    let out = std::env::temp_dir().join(format!("tl-codegen-rustfmt-{}", std::process::id()));
+    let output = Command::new(env!("CARGO_BIN_EXE_tl-codegen"))
+        .arg(crate_dir().join("schema"))
+        .arg(&out)
+        // Not rustfmt, but prints a version like it.
+        .env("RUSTFMT", env!("CARGO"))
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("but the code is formatted with rustfmt"),
+        "{}",
+        stderr
+    );
+    assert!(!out.exists());
+}
+
+#[test]
+fn one_release_is_selected() {
+    // A copy of the crate with a second release, whose schema is the same;
+    // only the version matters here.
+    let dir = std::env::temp_dir().join(format!("tl-releases-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    copy_dir(&crate_dir().join("src"), &dir.join("src"));
+    // Cargo.lock isn't committed, but the versions resolved for the
+    // workspace are the ones available offline.
+    if crate_dir().join("Cargo.lock").is_file() {
+        fs::copy(crate_dir().join("Cargo.lock"), dir.join("Cargo.lock")).unwrap();
+    }
+    let manifest = fs::read_to_string(crate_dir().join("Cargo.toml"))
+        .unwrap()
+        .replace("members = [\"codegen\", \"parser\"]", "members = []")
+        .replace(
+            "path = \"parser\"",
+            &format!("path = {:?}", crate_dir().join("parser")),
+        )
+        .replace("tdlib-1-6-0 = []", "tdlib-1-6-0 = []\ntdlib-1-10-0 = []");
+    fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    for version in &["1.6.0", "1.10.0"] {
+        fs::create_dir_all(dir.join("schema").join(version)).unwrap();
+        fs::copy(
+            crate_dir().join("schema/1.6.0/td_api.tl"),
+            dir.join("schema").join(version).join("td_api.tl"),
+        )
+        .unwrap();
+    }
+    // Directories that aren't named after a version are not releases.
+    fs::create_dir_all(dir.join("schema/notes")).unwrap();
+    let status = Command::new(env!("CARGO_BIN_EXE_tl-codegen"))
+        .arg(dir.join("schema"))
+        .arg(dir.join("src"))
+        .status()
+        .unwrap();
+    assert!(status.success());
+    assert!(dir.join("src/tdlib_1_10_0/mod.rs").is_file());
+    assert!(!dir.join("src/notes").exists());
+
+    let (ok, stderr) = check(&dir, "tdlib-1-10-0");
+    assert!(ok, "{}", stderr);
+    let (ok, stderr) = check(&dir, "tdlib-1-6-0 tdlib-1-10-0");
+    assert!(!ok);
+    assert!(
+        stderr.contains("enable the feature of one TDLib release only, not both `tdlib-1-6-0` and `tdlib-1-10-0`"),
+        "{}",
+        stderr
+    );
+    let (ok, stderr) = check(&dir, "");
+    assert!(!ok);
+    assert!(stderr.contains("e.g. `tdlib-1-10-0`"), "{}", stderr);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
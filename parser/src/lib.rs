@@ -0,0 +1,389 @@
+//! A parser of the TL schema of TDLib, `td_api.tl`, shared by tdlib-types,
+//! which embeds the schema it was generated from, and `tl-codegen`, which
+//! generates it.
+//!
+//! [`Schema::parse`] reads every class, constructor and function, their
+//! fields, TL types, nullability and documentation.
+//!
+//! # What is read from the documentation
+//!
+//! TL has no syntax for much of what TDLib guarantees: which fields may be
+//! null, which integers identify a chat or a user, or how a method can be
+//! called. `td_api.tl` says it in the `//@` comments instead, so this crate
+//! and `tl-codegen` read it from their wording ("may be null", "Can be called
+//! synchronously"). That is only as good as the wording: a description that
+//! lacks the phrase reads as if the property didn't hold, and a release that
+//! rewords a phrase needs the new wording added.
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// The type of a field or the result of a function.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TlType {
+    Bool,
+    Int32,
+    /// A 53-bit integer, sent as a JSON number.
+    Int53,
+    /// A 64-bit integer, sent as a JSON string.
+    Int64,
+    Double,
+    String,
+    /// Binary data, sent as a base64 string.
+    Bytes,
+    Vector(Box<TlType>),
+    /// A class, or a constructor named in place of its class, as written in
+    /// the schema (e.g. `MessageContent` or `formattedText`).
+    Object(String),
+}
+
+impl TlType {
+    fn parse(s: &str) -> Option<Self> {
+        Some(match s {
+            "Bool" => TlType::Bool,
+            "int32" => TlType::Int32,
+            "int53" => TlType::Int53,
+            "int64" => TlType::Int64,
+            "double" => TlType::Double,
+            "string" => TlType::String,
+            "bytes" => TlType::Bytes,
+            _ => match s.strip_prefix("vector<") {
+                Some(inner) => TlType::Vector(Box::new(TlType::parse(inner.strip_suffix('>')?)?)),
+                None if !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric()) => {
+                    TlType::Object(s.to_string())
+                }
+                None => return None,
+            },
+        })
+    }
+
+    /// The name of the class values of this type belong to, for objects.
+    pub fn class(&self) -> Option<String> {
+        match self {
+            TlType::Object(name) => Some(upper_first(name)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for TlType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TlType::Bool => f.write_str("Bool"),
+            TlType::Int32 => f.write_str("int32"),
+            TlType::Int53 => f.write_str("int53"),
+            TlType::Int64 => f.write_str("int64"),
+            TlType::Double => f.write_str("double"),
+            TlType::String => f.write_str("string"),
+            TlType::Bytes => f.write_str("bytes"),
+            TlType::Vector(inner) => write!(f, "vector<{}>", inner),
+            TlType::Object(name) => f.write_str(name),
+        }
+    }
+}
+
+/// A field of a constructor or an argument of a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: String,
+    pub type_: TlType,
+    pub description: String,
+    /// The field may be null (or omitted).
+    pub nullable: bool,
+    /// The elements of this vector field may be null.
+    pub nullable_elements: bool,
+}
+
+/// An abstract class and the constructors that build it. Classes with a
+/// single constructor of the same name are listed too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Class {
+    pub name: String,
+    /// Empty for classes that are not documented on their own.
+    pub description: String,
+    pub constructors: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Constructor {
+    pub name: String,
+    pub description: String,
+    pub fields: Vec<Field>,
+    pub class: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function {
+    pub name: String,
+    pub description: String,
+    pub fields: Vec<Field>,
+    /// The class of the result.
+    pub result: String,
+}
+
+/// A constructor or a function, as looked up by `@type`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Definition<'a> {
+    Constructor(&'a Constructor),
+    Function(&'a Function),
+}
+
+impl<'a> Definition<'a> {
+    pub fn name(self) -> &'a str {
+        match self {
+            Definition::Constructor(c) => &c.name,
+            Definition::Function(f) => &f.name,
+        }
+    }
+
+    pub fn description(self) -> &'a str {
+        match self {
+            Definition::Constructor(c) => &c.description,
+            Definition::Function(f) => &f.description,
+        }
+    }
+
+    pub fn fields(self) -> &'a [Field] {
+        match self {
+            Definition::Constructor(c) => &c.fields,
+            Definition::Function(f) => &f.fields,
+        }
+    }
+}
+
+/// A parsed `td_api.tl`.
+#[derive(Debug, Clone, Default)]
+pub struct Schema {
+    classes: Vec<Class>,
+    constructors: Vec<Constructor>,
+    functions: Vec<Function>,
+    class_index: HashMap<String, usize>,
+    constructor_index: HashMap<String, usize>,
+    function_index: HashMap<String, usize>,
+}
+
+impl Schema {
+    /// Classes, in the order they first appear in the schema.
+    pub fn classes(&self) -> &[Class] {
+        &self.classes
+    }
+
+    /// Constructors, in schema order.
+    pub fn constructors(&self) -> &[Constructor] {
+        &self.constructors
+    }
+
+    /// Functions, in schema order.
+    pub fn functions(&self) -> &[Function] {
+        &self.functions
+    }
+
+    pub fn class(&self, name: &str) -> Option<&Class> {
+        self.class_index.get(name).map(|&i| &self.classes[i])
+    }
+
+    pub fn constructor(&self, name: &str) -> Option<&Constructor> {
+        self.constructor_index
+            .get(name)
+            .map(|&i| &self.constructors[i])
+    }
+
+    pub fn function(&self, name: &str) -> Option<&Function> {
+        self.function_index.get(name).map(|&i| &self.functions[i])
+    }
+
+    /// Looks up the constructor or function with the given `@type`.
+    pub fn get(&self, type_: &str) -> Option<Definition<'_>> {
+        self.constructor(type_)
+            .map(Definition::Constructor)
+            .or_else(|| self.function(type_).map(Definition::Function))
+    }
+
+    /// Parses the text of a `td_api.tl` file.
+    pub fn parse(source: &str) -> Result<Schema, ParseError> {
+        let mut schema = Schema::default();
+        let mut functions = false;
+        let mut comment = String::new();
+        for (i, line) in source.lines().enumerate() {
+            let line = line.trim();
+            let error = |message: &str| ParseError {
+                line: i + 1,
+                message: message.to_string(),
+            };
+            if line.is_empty() {
+                continue;
+            }
+            if line == "---functions---" {
+                functions = true;
+                comment.clear();
+                continue;
+            }
+            if line == "---types---" {
+                functions = false;
+                comment.clear();
+                continue;
+            }
+            if let Some(text) = line.strip_prefix("//") {
+                let text = text.strip_prefix('-').unwrap_or(text);
+                if text.trim_start().starts_with("@class ") {
+                    let tags = parse_tags(text);
+                    let name = tags
+                        .get("class")
+                        .ok_or_else(|| error("class without a name"))?;
+                    let description = tags.get("description").cloned().unwrap_or_default();
+                    schema.class_mut(name).description = description;
+                } else {
+                    comment.push(' ');
+                    comment.push_str(text.trim());
+                }
+                continue;
+            }
+            let declaration = line
+                .strip_suffix(';')
+                .ok_or_else(|| error("expected a declaration ending with ';'"))?;
+            let comment = std::mem::take(&mut comment);
+            let tags = parse_tags(&comment);
+            let mut sides = declaration.splitn(2, '=');
+            let mut left = sides.next().unwrap_or_default().split_whitespace();
+            let result = sides
+                .next()
+                .ok_or_else(|| error("expected '='"))?
+                .trim()
+                .to_string();
+            let name = left
+                .next()
+                .ok_or_else(|| error("missing name"))?
+                .to_string();
+            if is_builtin(&name, &result) {
+                continue;
+            }
+            let mut fields = Vec::new();
+            for arg in left {
+                let mut parts = arg.splitn(2, ':');
+                let field = parts.next().unwrap_or_default().to_string();
+                let type_ = parts
+                    .next()
+                    .and_then(TlType::parse)
+                    .ok_or_else(|| error(&format!("invalid argument {}", arg)))?;
+                let description = tags.get(field.as_str()).cloned().unwrap_or_default();
+                let (mut nullable, nullable_elements) = nullability(&field, &type_, &description);
+                nullable |= UNDOCUMENTED_NULLABLE.contains(&(name.as_str(), field.as_str()));
+                fields.push(Field {
+                    name: field,
+                    type_,
+                    description,
+                    nullable,
+                    nullable_elements,
+                });
+            }
+            let description = tags.get("description").cloned().unwrap_or_default();
+            if functions {
+                schema
+                    .function_index
+                    .insert(name.clone(), schema.functions.len());
+                schema.functions.push(Function {
+                    name,
+                    description,
+                    fields,
+                    result,
+                });
+            } else {
+                schema.class_mut(&result).constructors.push(name.clone());
+                schema
+                    .constructor_index
+                    .insert(name.clone(), schema.constructors.len());
+                schema.constructors.push(Constructor {
+                    name,
+                    description,
+                    fields,
+                    class: result,
+                });
+            }
+        }
+        Ok(schema)
+    }
+
+    fn class_mut(&mut self, name: &str) -> &mut Class {
+        let classes = &mut self.classes;
+        let i = *self.class_index.entry(name.to_string()).or_insert_with(|| {
+            classes.push(Class {
+                name: name.to_string(),
+                description: String::new(),
+                constructors: Vec::new(),
+            });
+            classes.len() - 1
+        });
+        &mut self.classes[i]
+    }
+}
+
+/// An error in the syntax of a `td_api.tl` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The 1-based line the error was found on.
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn is_builtin(name: &str, result: &str) -> bool {
+    match result {
+        "Double" | "String" | "Int32" | "Int53" | "Int64" | "Bytes" | "Bool" => true,
+        _ => name == "vector",
+    }
+}
+
+/// Splits a documentation comment into its `@tag text` pairs.
+fn parse_tags(comment: &str) -> HashMap<&str, String> {
+    let mut tags = HashMap::new();
+    for part in comment.split(" @") {
+        let part = part.trim().trim_start_matches('@');
+        let mut words = part.splitn(2, char::is_whitespace);
+        if let Some(tag) = words.next().filter(|tag| !tag.is_empty()) {
+            tags.insert(tag, words.next().unwrap_or_default().trim().to_string());
+        }
+    }
+    tags
+}
+
+/// Fields that may be null although their documentation doesn't say so, by
+/// constructor or function and field name.
+const UNDOCUMENTED_NULLABLE: &[(&str, &str)] = &[
+    // Null sends the message immediately, as in editMessageSchedulingState.
+    ("sendMessageOptions", "scheduling_state"),
+];
+
+/// Whether a field may be null, and whether the elements of a vector may be,
+/// read from the documentation of the field (see the [crate docs](crate)).
+fn nullability(name: &str, type_: &TlType, description: &str) -> (bool, bool) {
+    let description = description.to_lowercase();
+    if let TlType::Vector(_) = type_ {
+        if description.contains(&format!("; {} may be null", name)) {
+            return (false, true);
+        }
+    }
+    if description.contains("not null") {
+        return (false, false);
+    }
+    let nullable = description
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .any(|word| word == "null")
+        || description.contains("for bots only")
+        || description.contains("only available to bots");
+    (nullable, false)
+}
+
+fn upper_first(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,158 @@
+use tl_parser::{Definition, ParseError, Schema, TlType};
+
+/// A schema in the layout of upstream `td_api.tl`: several tags on a line,
+/// `//-` continuations and a documented class.
+const SOURCE: &str = "double ? = Double;
+string ? = String;
+
+int32 = Int32;
+int53 = Int53;
+int64 = Int64;
+bytes = Bytes;
+
+boolFalse = Bool;
+boolTrue = Bool;
+
+vector {t:Type} # [ t ] = Vector t;
+
+//@description An object of this type is returned on a successful function call for certain functions
+ok = Ok;
+
+//@class ChatType @description Describes the type of a chat
+
+//@description An ordinary chat with a user @user_id User identifier
+chatTypePrivate user_id:int32 = ChatType;
+
+//@description A secret chat with a user @secret_chat_id Secret chat identifier @user_id User identifier of the secret chat peer
+chatTypeSecret secret_chat_id:int32 user_id:int32 = ChatType;
+
+//@description Contains a part of a file @data File bytes
+filePart data:bytes = FilePart;
+
+//@description Contains a list of chats @chat_ids List of chat identifiers
+//-@next_offset Offset for the next request; may be null
+chats chat_ids:vector<int53> next_offset:string = Chats;
+
+---functions---
+
+//@description Returns a chat @chat_id Chat identifier
+getChat chat_id:int53 = Chat;
+
+//@description Returns parts of a file @file_id Identifier of the file
+//@offset The offset from which to read the file @count Number of bytes to read
+readFilePart file_id:int32 offset:int32 count:int32 = FilePart;
+";
+
+#[test]
+fn parses_classes_constructors_and_functions() {
+    let schema = Schema::parse(SOURCE).unwrap();
+    let names: Vec<_> = schema.constructors().iter().map(|c| &c.name).collect();
+    assert_eq!(
+        names,
+        [
+            "ok",
+            "chatTypePrivate",
+            "chatTypeSecret",
+            "filePart",
+            "chats"
+        ]
+    );
+    let names: Vec<_> = schema.functions().iter().map(|f| &f.name).collect();
+    assert_eq!(names, ["getChat", "readFilePart"]);
+    let names: Vec<_> = schema.classes().iter().map(|c| &c.name).collect();
+    assert_eq!(names, ["Ok", "ChatType", "FilePart", "Chats"]);
+}
+
+#[test]
+fn tags_on_one_line_and_continuations() {
+    let schema = Schema::parse(SOURCE).unwrap();
+    let secret = schema.constructor("chatTypeSecret").unwrap();
+    assert_eq!(secret.description, "A secret chat with a user");
+    assert_eq!(secret.fields[0].description, "Secret chat identifier");
+    assert_eq!(
+        secret.fields[1].description,
+        "User identifier of the secret chat peer"
+    );
+
+    let chats = schema.constructor("chats").unwrap();
+    assert_eq!(chats.fields[0].type_.to_string(), "vector<int53>");
+    assert_eq!(
+        chats.fields[1].description,
+        "Offset for the next request; may be null"
+    );
+    assert!(chats.fields[1].nullable);
+    assert!(!chats.fields[0].nullable);
+
+    let read = schema.function("readFilePart").unwrap();
+    assert_eq!(read.result, "FilePart");
+    let docs: Vec<_> = read.fields.iter().map(|f| &f.description).collect();
+    assert_eq!(
+        docs,
+        [
+            "Identifier of the file",
+            "The offset from which to read the file",
+            "Number of bytes to read"
+        ]
+    );
+}
+
+#[test]
+fn lookup_of_classes_and_constructors() {
+    let schema = Schema::parse(SOURCE).unwrap();
+    let class = schema.class("ChatType").unwrap();
+    assert_eq!(class.description, "Describes the type of a chat");
+    assert_eq!(class.constructors, ["chatTypePrivate", "chatTypeSecret"]);
+    // Classes with a single constructor of the same name are listed too.
+    assert_eq!(schema.class("FilePart").unwrap().constructors, ["filePart"]);
+    assert_eq!(schema.class("FilePart").unwrap().description, "");
+
+    let constructor = schema.constructor("filePart").unwrap();
+    assert_eq!(constructor.class, "FilePart");
+    assert_eq!(constructor.fields[0].type_, TlType::Bytes);
+
+    assert!(schema.class("chatType").is_none());
+    assert!(schema.constructor("ChatType").is_none());
+    assert!(schema.constructor("getChat").is_none());
+    assert!(schema.function("chats").is_none());
+}
+
+#[test]
+fn get_by_type() {
+    let schema = Schema::parse(SOURCE).unwrap();
+    match schema.get("chatTypePrivate") {
+        Some(Definition::Constructor(c)) => assert_eq!(c.class, "ChatType"),
+        other => panic!("expected a constructor, got {:?}", other),
+    }
+    match schema.get("getChat") {
+        Some(Definition::Function(f)) => assert_eq!(f.result, "Chat"),
+        other => panic!("expected a function, got {:?}", other),
+    }
+    let definition = schema.get("getChat").unwrap();
+    assert_eq!(definition.name(), "getChat");
+    assert_eq!(definition.description(), "Returns a chat");
+    assert_eq!(definition.fields()[0].type_, TlType::Int53);
+    // Builtins and classes are not @types.
+    assert!(schema.get("int32").is_none());
+    assert!(schema.get("ChatType").is_none());
+    assert!(schema.get("somethingNew").is_none());
+}
+
+#[test]
+fn parse_errors_point_at_their_line() {
+    let error = |source: &str| Schema::parse(source).unwrap_err();
+
+    assert_eq!(
+        error("ok = Ok;\n\n//@description A chat\nchat id:int53 = Chat"),
+        ParseError {
+            line: 4,
+            message: "expected a declaration ending with ';'".to_string(),
+        }
+    );
+    assert_eq!(error("ok = Ok;\nchat id:int53;").line, 2);
+    assert_eq!(error("ok = Ok;\nchat id:int53;").message, "expected '='");
+    let invalid = error("ok = Ok;\n---functions---\n\ngetChat chat_id:int 53 = Chat;");
+    assert_eq!(invalid.line, 4);
+    assert_eq!(invalid.message, "invalid argument 53");
+    assert_eq!(invalid.to_string(), "line 4: invalid argument 53");
+    assert_eq!(error("ok = Ok;\nchat id:vector<int53 = Chat;").line, 2);
+}
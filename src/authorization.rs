@@ -0,0 +1,300 @@
+//! Checks of requests against the current authorization state, and the
+//! flow of requests that authorizes a client.
+//!
+//! Every method lists the authorization states it works in as
+//! [`Method::AUTHORIZATION_STATES`]: most work only once authorized, the
+//! authentication methods in the state that asks for their argument, and
+//! the ones that "can be called before authorization" in every state.
+//! [`check`] compares them with the state of the last
+//! `updateAuthorizationState`, so that a request TDLib would reject is not
+//! sent at all.
+//!
+//! [`AuthorizationFlow`] answers each authorization state with the request
+//! that moves past it, asking an [`Authenticator`] for what only the user
+//! knows. It doesn't send anything itself, so it works with any way of
+//! talking to TDLib. Its requests carry an @extra, so that the answers to
+//! them can be told apart from the others and handed back to it:
+//!
+//! ```no_run
+//! # use tdlib_types::authorization::{Authenticator, AuthorizationFlow, Step};
+//! # use tdlib_types::types::{Envelope, Response};
+//! # fn run(authenticator: impl Authenticator, send: impl Fn(String), received: Vec<String>) {
+//! let mut flow = AuthorizationFlow::new(authenticator);
+//! for json in &received {
+//!     let received: Envelope<Response> = serde_json::from_str(json).unwrap();
+//!     let step = match &received.payload {
+//!         Response::UpdateAuthorizationState(update) => flow.next(update),
+//!         _ => match flow.reply(&received) {
+//!             Some(step) => step,
+//!             None => continue,
+//!         },
+//!     };
+//!     match step {
+//!         Step::Send(request) => send(serde_json::to_string(&request).unwrap()),
+//!         Step::Wait => {}
+//!         Step::Ready => break,
+//!         Step::Closed => return,
+//!     }
+//! }
+//! # }
+//! ```
+
+use crate::methods::{
+    CheckAuthenticationBotToken, CheckAuthenticationCode, CheckAuthenticationPassword,
+    CheckDatabaseEncryptionKey, LogOut, Method, RegisterUser, SetAuthenticationPhoneNumber,
+    SetTdlibParameters,
+};
+use crate::types::{
+    AuthenticationCodeInfo, AuthorizationState, AuthorizationStateWaitPassword, Bytes, Envelope,
+    Response, TdlibParameters, TermsOfService, TlObject, UpdateAuthorizationState,
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt;
+
+/// A request for a method that doesn't work in the current authorization
+/// state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrongState {
+    /// The @type of the method.
+    pub method: &'static str,
+    /// The @type of the current authorization state.
+    pub state: String,
+    /// The @types of the states the method works in.
+    pub expected: &'static [&'static str],
+}
+
+impl fmt::Display for WrongState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} doesn't work in {}, only in {}",
+            self.method,
+            self.state,
+            self.expected.join(" or ")
+        )
+    }
+}
+
+impl std::error::Error for WrongState {}
+
+/// Checks that a request can be sent in the state announced by the last
+/// `updateAuthorizationState` received.
+///
+/// `request` is only there so the method is inferred, as in
+/// `check(&request, &last)`; its fields don't matter. States are compared by
+/// @type because that is how [`Method::AUTHORIZATION_STATES`] lists them,
+/// being read from the descriptions of methods in `td_api.tl`, and because it
+/// also covers states this crate doesn't know, which decode as
+/// [`AuthorizationState::Unknown`] and work only with methods that work in
+/// every state.
+pub fn check<M: Method>(_request: &M, last: &UpdateAuthorizationState) -> Result<(), WrongState> {
+    let state = last.authorization_state.type_name();
+    match M::AUTHORIZATION_STATES {
+        Some(expected) if !M::works_in(state) => Err(WrongState {
+            method: M::TYPE,
+            state: state.to_string(),
+            expected,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// What the user provides to authorize a client, asked for by
+/// [`AuthorizationFlow`] when TDLib needs it.
+pub trait Authenticator {
+    /// The parameters TDLib is initialized with.
+    fn tdlib_parameters(&mut self) -> TdlibParameters;
+
+    /// The key of the local database; `is_encrypted` tells whether the
+    /// database already has one. The default is no key.
+    fn encryption_key(&mut self, is_encrypted: bool) -> Bytes {
+        let _ = is_encrypted;
+        Bytes::new()
+    }
+
+    /// The token of the bot to log in as, or `None` to log in as a user with
+    /// a phone number. The default is `None`.
+    fn bot_token(&mut self) -> Option<String> {
+        None
+    }
+
+    /// The phone number of the user.
+    fn phone_number(&mut self) -> String;
+
+    /// The authentication code sent as `info` describes.
+    fn code(&mut self, info: &AuthenticationCodeInfo) -> String;
+
+    /// The password of the account, which has 2-step verification enabled.
+    fn password(&mut self, state: &AuthorizationStateWaitPassword) -> String;
+
+    /// Whether the user accepts the terms of service, before a new account
+    /// is registered.
+    fn accept_terms_of_service(&mut self, terms_of_service: &TermsOfService) -> bool;
+
+    /// The first and last name of the new account.
+    fn name(&mut self) -> (String, String);
+
+    /// Called when the login has to be confirmed on another device, by
+    /// opening `link` there. The default does nothing.
+    fn confirm_on_other_device(&mut self, link: &str) {
+        let _ = link;
+    }
+}
+
+/// A request sent to authorize a client; serializes with its @type, as a
+/// tagged method. The parameters of TDLib are boxed, as the generated enums
+/// box their large variants.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+pub enum AuthorizationRequest {
+    SetTdlibParameters(Box<SetTdlibParameters>),
+    CheckDatabaseEncryptionKey(CheckDatabaseEncryptionKey),
+    SetAuthenticationPhoneNumber(SetAuthenticationPhoneNumber),
+    CheckAuthenticationBotToken(CheckAuthenticationBotToken),
+    CheckAuthenticationCode(CheckAuthenticationCode),
+    RegisterUser(RegisterUser),
+    CheckAuthenticationPassword(CheckAuthenticationPassword),
+    LogOut(LogOut),
+}
+
+/// What to do after an authorization state.
+#[derive(Debug, Clone)]
+pub enum Step {
+    /// Send the request to TDLib, with the @extra its answer is recognized
+    /// by in [`AuthorizationFlow::reply`].
+    Send(Envelope<AuthorizationRequest>),
+    /// Wait for the next state.
+    Wait,
+    /// The client is authorized.
+    Ready,
+    /// The client is closed and can't be used anymore.
+    Closed,
+}
+
+/// The requests that take a client from its first authorization state to
+/// being authorized.
+///
+/// Feed it every `updateAuthorizationState` with [`next`](Self::next). TDLib
+/// answers a wrong code or password with an error and stays in the same
+/// state, so after an error call [`retry`](Self::retry) to ask again, or hand
+/// every answer to [`reply`](Self::reply), which does it for the answers to
+/// the flow's last request. If the terms of service are declined, the flow
+/// logs out.
+#[derive(Debug)]
+pub struct AuthorizationFlow<A> {
+    authenticator: A,
+    state: Option<AuthorizationState>,
+    /// How many requests were sent; the @extra of the last one.
+    sent: u64,
+}
+
+impl<A: Authenticator> AuthorizationFlow<A> {
+    /// A flow that hasn't seen any authorization state yet.
+    pub fn new(authenticator: A) -> Self {
+        AuthorizationFlow {
+            authenticator,
+            state: None,
+            sent: 0,
+        }
+    }
+
+    /// The authenticator the flow asks, e.g. to read what it was told.
+    pub fn authenticator(&mut self) -> &mut A {
+        &mut self.authenticator
+    }
+
+    /// The state of the last update, if any.
+    pub fn state(&self) -> Option<&AuthorizationState> {
+        self.state.as_ref()
+    }
+
+    /// Returns what to do in the state announced by `update`.
+    pub fn next(&mut self, update: &UpdateAuthorizationState) -> Step {
+        self.state = Some(update.authorization_state.clone());
+        self.retry()
+    }
+
+    /// Returns what to do after an answer received from TDLib, or `None` if
+    /// it doesn't answer the last request of the flow: an error asks again as
+    /// [`retry`](Self::retry) does, anything else waits for the next state.
+    pub fn reply(&mut self, answer: &Envelope<Response>) -> Option<Step> {
+        if self.sent == 0 || answer.extra != Some(self.extra()) {
+            return None;
+        }
+        Some(match answer.payload {
+            Response::Error(_) => self.retry(),
+            _ => Step::Wait,
+        })
+    }
+
+    /// Returns what to do in the state of the last update again, e.g. after
+    /// the request sent for it failed. Waits if there was no update yet.
+    pub fn retry(&mut self) -> Step {
+        let authenticator = &mut self.authenticator;
+        let request = match &self.state {
+            None => return Step::Wait,
+            Some(AuthorizationState::AuthorizationStateWaitTdlibParameters(_)) => {
+                let parameters = authenticator.tdlib_parameters();
+                AuthorizationRequest::SetTdlibParameters(Box::new(SetTdlibParameters::new(
+                    parameters,
+                )))
+            }
+            Some(AuthorizationState::AuthorizationStateWaitEncryptionKey(state)) => {
+                let key = authenticator.encryption_key(state.is_encrypted);
+                AuthorizationRequest::CheckDatabaseEncryptionKey(
+                    CheckDatabaseEncryptionKey::new().with_encryption_key(key),
+                )
+            }
+            Some(AuthorizationState::AuthorizationStateWaitPhoneNumber(_)) => {
+                match authenticator.bot_token() {
+                    Some(token) => AuthorizationRequest::CheckAuthenticationBotToken(
+                        CheckAuthenticationBotToken::new(token),
+                    ),
+                    None => AuthorizationRequest::SetAuthenticationPhoneNumber(
+                        SetAuthenticationPhoneNumber::new(authenticator.phone_number()),
+                    ),
+                }
+            }
+            Some(AuthorizationState::AuthorizationStateWaitCode(state)) => {
+                let code = authenticator.code(&state.code_info);
+                AuthorizationRequest::CheckAuthenticationCode(CheckAuthenticationCode::new(code))
+            }
+            Some(AuthorizationState::AuthorizationStateWaitRegistration(state)) => {
+                if authenticator.accept_terms_of_service(&state.terms_of_service) {
+                    let (first_name, last_name) = authenticator.name();
+                    AuthorizationRequest::RegisterUser(
+                        RegisterUser::new(first_name).with_last_name(last_name),
+                    )
+                } else {
+                    AuthorizationRequest::LogOut(LogOut::new())
+                }
+            }
+            Some(AuthorizationState::AuthorizationStateWaitPassword(state)) => {
+                let password = authenticator.password(state);
+                AuthorizationRequest::CheckAuthenticationPassword(CheckAuthenticationPassword::new(
+                    password,
+                ))
+            }
+            Some(AuthorizationState::AuthorizationStateWaitOtherDeviceConfirmation(state)) => {
+                authenticator.confirm_on_other_device(&state.link);
+                return Step::Wait;
+            }
+            Some(AuthorizationState::AuthorizationStateReady(_)) => return Step::Ready,
+            Some(AuthorizationState::AuthorizationStateClosed(_)) => return Step::Closed,
+            Some(_) => return Step::Wait,
+        };
+        self.sent += 1;
+        Step::Send(Envelope {
+            extra: Some(self.extra()),
+            client_id: None,
+            payload: request,
+        })
+    }
+
+    /// The @extra of the last request.
+    fn extra(&self) -> Value {
+        Value::from(format!("authorization {}", self.sent))
+    }
+}
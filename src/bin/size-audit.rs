@@ -0,0 +1,50 @@
+//! Prints the size of every generated enum, from the largest.
+//!
+//! ```text
+//! size-audit [--variants]
+//! ```
+//!
+//! With `--variants`, the size of the type of each variant is printed under
+//! its enum, with the boxed ones marked.
+
+use std::env;
+use std::process;
+use tdlib_types::sizes::{self, EnumSize};
+
+const USAGE: &str = "usage: size-audit [--variants]";
+
+fn main() {
+    let mut variants = false;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--variants" => variants = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ => {
+                eprintln!("{}", USAGE);
+                process::exit(2);
+            }
+        }
+    }
+
+    let mut enums: Vec<&EnumSize> = sizes::ENUMS.iter().collect();
+    enums.sort_by(|a, b| b.size.cmp(&a.size).then(a.name.cmp(b.name)));
+    for enum_ in enums {
+        println!("{:>6}  {}", enum_.size, enum_.name);
+        if !variants {
+            continue;
+        }
+        let mut variants: Vec<_> = enum_.variants.iter().collect();
+        variants.sort_by(|a, b| b.size.cmp(&a.size).then(a.name.cmp(b.name)));
+        for variant in variants {
+            let boxed = if variant.boxed { " (boxed)" } else { "" };
+            println!("{:>14}  {}{}", variant.size, variant.name, boxed);
+        }
+    }
+    println!(
+        "variants larger than {} bytes are boxed",
+        sizes::MAX_VARIANT_SIZE
+    );
+}
@@ -0,0 +1,60 @@
+//! Compares two revisions of `td_api.tl`.
+//!
+//! ```text
+//! tl-diff [--json] OLD NEW
+//! ```
+//!
+//! Prints one line per change, `+` for additions, `-` for removals and `~`
+//! for changes, or the changes as JSON with `--json`. Exits with status 1 if
+//! the schemas differ.
+
+use std::env;
+use std::fs;
+use std::process;
+use tdlib_types::schema::diff::Diff;
+use tdlib_types::schema::Schema;
+
+const USAGE: &str = "usage: tl-diff [--json] OLD NEW";
+
+fn load(path: &str) -> Schema {
+    let source = fs::read_to_string(path).unwrap_or_else(|err| {
+        eprintln!("tl-diff: {}: {}", path, err);
+        process::exit(2);
+    });
+    Schema::parse(&source).unwrap_or_else(|err| {
+        eprintln!("tl-diff: {}: {}", path, err);
+        process::exit(2);
+    })
+}
+
+fn main() {
+    let mut json = false;
+    let mut paths = Vec::new();
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--json" => json = true,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ => paths.push(arg),
+        }
+    }
+    if paths.len() != 2 {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    }
+
+    let diff = Diff::new(&load(&paths[0]), &load(&paths[1]));
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&diff).expect("diffs serialize to JSON")
+        );
+    } else {
+        print!("{}", diff);
+    }
+    if !diff.is_empty() {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,107 @@
+//! Offline decoding of chat identifiers.
+//!
+//! TDLib derives the identifier of every chat from the identifier of the
+//! underlying user, basic group, supergroup or secret chat:
+//!
+//! - private chats use the user identifier, in `1..=2^31-1`;
+//! - basic groups use the negated basic group identifier, down to
+//!   `-999_999_999_999`;
+//! - supergroups and channels use `-1_000_000_000_000 - supergroup_id`;
+//! - secret chats use `-2_000_000_000_000 + secret_chat_id`.
+
+use crate::types::{BasicGroupId, ChatId, SecretChatId, SupergroupId, UserId};
+use std::convert::TryFrom;
+
+const MAX_USER_ID: i64 = (1 << 31) - 1;
+const MIN_BASIC_GROUP_CHAT_ID: i64 = -999_999_999_999;
+const ZERO_SUPERGROUP_CHAT_ID: i64 = -1_000_000_000_000;
+const ZERO_SECRET_CHAT_ID: i64 = -2_000_000_000_000;
+
+/// The user, basic group, supergroup or secret chat a chat is built on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Peer {
+    /// A private chat with the user, as created by `createPrivateChat`.
+    Private(UserId),
+    /// A basic group chat, as created by `createBasicGroupChat`.
+    BasicGroup(BasicGroupId),
+    /// A supergroup or channel chat, as created by `createSupergroupChat`.
+    Supergroup(SupergroupId),
+    /// A secret chat, as created by `createSecretChat`.
+    Secret(SecretChatId),
+}
+
+impl ChatId {
+    /// Decodes the kind of chat and the identifier of its peer, without
+    /// calling `getChat`. Returns `None` for identifiers outside of the ranges
+    /// TDLib uses.
+    pub fn peer(self) -> Option<Peer> {
+        let id = self.0;
+        if (1..=MAX_USER_ID).contains(&id) {
+            return Some(Peer::Private(UserId(id as i32)));
+        }
+        if (MIN_BASIC_GROUP_CHAT_ID..0).contains(&id) {
+            return positive_i32(-id).map(|id| Peer::BasicGroup(BasicGroupId(id)));
+        }
+        if let Some(id) = ZERO_SUPERGROUP_CHAT_ID
+            .checked_sub(id)
+            .and_then(positive_i32)
+        {
+            return Some(Peer::Supergroup(SupergroupId(id)));
+        }
+        match id.checked_sub(ZERO_SECRET_CHAT_ID).map(i32::try_from) {
+            Some(Ok(id)) if id != 0 => Some(Peer::Secret(SecretChatId(id))),
+            _ => None,
+        }
+    }
+
+    /// The identifier of the private chat with a user.
+    pub fn private(user_id: UserId) -> Self {
+        ChatId(i64::from(user_id.0))
+    }
+
+    /// The identifier of a basic group chat.
+    pub fn basic_group(basic_group_id: BasicGroupId) -> Self {
+        ChatId(-i64::from(basic_group_id.0))
+    }
+
+    /// The identifier of a supergroup or channel chat.
+    pub fn supergroup(supergroup_id: SupergroupId) -> Self {
+        ChatId(ZERO_SUPERGROUP_CHAT_ID - i64::from(supergroup_id.0))
+    }
+
+    /// The identifier of a secret chat.
+    pub fn secret(secret_chat_id: SecretChatId) -> Self {
+        ChatId(ZERO_SECRET_CHAT_ID + i64::from(secret_chat_id.0))
+    }
+
+    pub fn is_private(self) -> bool {
+        matches!(self.peer(), Some(Peer::Private(_)))
+    }
+
+    pub fn is_basic_group(self) -> bool {
+        matches!(self.peer(), Some(Peer::BasicGroup(_)))
+    }
+
+    pub fn is_supergroup(self) -> bool {
+        matches!(self.peer(), Some(Peer::Supergroup(_)))
+    }
+
+    pub fn is_secret(self) -> bool {
+        matches!(self.peer(), Some(Peer::Secret(_)))
+    }
+}
+
+impl From<Peer> for ChatId {
+    fn from(peer: Peer) -> Self {
+        match peer {
+            Peer::Private(user_id) => ChatId::private(user_id),
+            Peer::BasicGroup(basic_group_id) => ChatId::basic_group(basic_group_id),
+            Peer::Supergroup(supergroup_id) => ChatId::supergroup(supergroup_id),
+            Peer::Secret(secret_chat_id) => ChatId::secret(secret_chat_id),
+        }
+    }
+}
+
+fn positive_i32(id: i64) -> Option<i32> {
+    i32::try_from(id).ok().filter(|id| *id > 0)
+}
@@ -0,0 +1,228 @@
+//! Errors produced while decoding TDLib answers, and classification of the
+//! errors TDLib returns.
+
+use crate::types::{Error, Response, Unknown};
+use std::fmt;
+use std::time::Duration;
+
+/// The ways decoding the result of a [`Method`](crate::methods::Method) can
+/// fail.
+#[derive(Debug)]
+pub enum TdError {
+    /// TDLib answered with an `error` object.
+    Td(Error),
+    /// The answer is not valid JSON or has no `@type`.
+    Json(serde_json::Error),
+    /// The answer has a `@type` known to this crate but its fields, or those
+    /// of an object in it, do not match the definition of their constructor.
+    Malformed(Unknown),
+    /// The answer is a valid object, but not of the type the method returns.
+    /// Objects with a `@type` unknown to this crate end up here as
+    /// `Response::Unknown`.
+    UnexpectedType(Box<Response>),
+}
+
+impl fmt::Display for TdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TdError::Td(err) => write!(f, "TDLib error {}: {}", err.code, err.message),
+            TdError::Json(err) => write!(f, "invalid TDLib answer: {}", err),
+            TdError::Malformed(object) => {
+                write!(f, "fields of {} do not match its definition", object.type_)
+            }
+            TdError::UnexpectedType(response) => write!(f, "unexpected answer {:?}", response),
+        }
+    }
+}
+
+impl std::error::Error for TdError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TdError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for TdError {
+    fn from(err: Error) -> Self {
+        TdError::Td(err)
+    }
+}
+
+impl From<serde_json::Error> for TdError {
+    fn from(err: serde_json::Error) -> Self {
+        TdError::Json(err)
+    }
+}
+
+/// Known error messages, as returned in [`Error::message`].
+///
+/// Errors carrying a wait time (`FLOOD_WAIT_X`, `SLOWMODE_WAIT_X`,
+/// `TAKEOUT_INIT_DELAY_X` and TDLib's `Too Many Requests: retry after X`) are
+/// matched by prefix instead; see [`Error::retry_after`].
+pub const KNOWN_MESSAGES: &[(&str, ErrorKind)] = &[
+    ("PHONE_NUMBER_INVALID", ErrorKind::PhoneNumberInvalid),
+    ("PHONE_NUMBER_BANNED", ErrorKind::PhoneNumberBanned),
+    ("PHONE_NUMBER_FLOOD", ErrorKind::PhoneNumberFlood),
+    ("PHONE_NUMBER_OCCUPIED", ErrorKind::PhoneNumberOccupied),
+    ("PHONE_NUMBER_UNOCCUPIED", ErrorKind::PhoneNumberUnoccupied),
+    ("PHONE_CODE_INVALID", ErrorKind::PhoneCodeInvalid),
+    ("PHONE_CODE_EXPIRED", ErrorKind::PhoneCodeExpired),
+    ("PHONE_CODE_EMPTY", ErrorKind::PhoneCodeEmpty),
+    ("PASSWORD_HASH_INVALID", ErrorKind::PasswordHashInvalid),
+    ("SESSION_PASSWORD_NEEDED", ErrorKind::SessionPasswordNeeded),
+    ("FIRSTNAME_INVALID", ErrorKind::FirstNameInvalid),
+    ("LASTNAME_INVALID", ErrorKind::LastNameInvalid),
+    ("ACCESS_TOKEN_INVALID", ErrorKind::AccessTokenInvalid),
+    ("API_ID_INVALID", ErrorKind::ApiIdInvalid),
+    ("AUTH_KEY_UNREGISTERED", ErrorKind::AuthKeyUnregistered),
+    ("AUTH_KEY_INVALID", ErrorKind::AuthKeyInvalid),
+    ("AUTH_KEY_DUPLICATED", ErrorKind::AuthKeyDuplicated),
+    ("SESSION_REVOKED", ErrorKind::SessionRevoked),
+    ("SESSION_EXPIRED", ErrorKind::SessionExpired),
+    ("USER_DEACTIVATED", ErrorKind::UserDeactivated),
+    ("USER_DEACTIVATED_BAN", ErrorKind::UserDeactivated),
+    ("Chat not found", ErrorKind::ChatNotFound),
+    ("CHAT_WRITE_FORBIDDEN", ErrorKind::ChatWriteForbidden),
+    (
+        "Have no write access to the chat",
+        ErrorKind::ChatWriteForbidden,
+    ),
+    ("CHAT_ADMIN_REQUIRED", ErrorKind::ChatAdminRequired),
+    ("CHANNEL_PRIVATE", ErrorKind::ChannelPrivate),
+    ("USER_BANNED_IN_CHANNEL", ErrorKind::UserBannedInChannel),
+    ("USER_IS_BLOCKED", ErrorKind::UserIsBlocked),
+    ("USER_PRIVACY_RESTRICTED", ErrorKind::UserPrivacyRestricted),
+    ("PEER_ID_INVALID", ErrorKind::PeerIdInvalid),
+    ("Message not found", ErrorKind::MessageNotFound),
+    ("MESSAGE_ID_INVALID", ErrorKind::MessageIdInvalid),
+    ("MESSAGE_NOT_MODIFIED", ErrorKind::MessageNotModified),
+    ("MESSAGE_TOO_LONG", ErrorKind::MessageTooLong),
+    ("USERNAME_INVALID", ErrorKind::UsernameInvalid),
+    ("USERNAME_OCCUPIED", ErrorKind::UsernameOccupied),
+    ("USERNAME_NOT_OCCUPIED", ErrorKind::UsernameNotOccupied),
+    ("Request aborted", ErrorKind::RequestAborted),
+];
+
+/// Classification of an [`Error`] by its message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// `FLOOD_WAIT_X` or `Too Many Requests: retry after X`.
+    FloodWait,
+    /// `SLOWMODE_WAIT_X`: the chat has slow mode enabled.
+    SlowModeWait,
+    /// `TAKEOUT_INIT_DELAY_X`.
+    TakeoutInitDelay,
+    PhoneNumberInvalid,
+    PhoneNumberBanned,
+    PhoneNumberFlood,
+    PhoneNumberOccupied,
+    PhoneNumberUnoccupied,
+    PhoneCodeInvalid,
+    PhoneCodeExpired,
+    PhoneCodeEmpty,
+    PasswordHashInvalid,
+    SessionPasswordNeeded,
+    FirstNameInvalid,
+    LastNameInvalid,
+    /// The bot token is invalid.
+    AccessTokenInvalid,
+    ApiIdInvalid,
+    AuthKeyUnregistered,
+    AuthKeyInvalid,
+    AuthKeyDuplicated,
+    SessionRevoked,
+    SessionExpired,
+    /// `USER_DEACTIVATED` or `USER_DEACTIVATED_BAN`.
+    UserDeactivated,
+    ChatNotFound,
+    ChatWriteForbidden,
+    ChatAdminRequired,
+    ChannelPrivate,
+    UserBannedInChannel,
+    UserIsBlocked,
+    UserPrivacyRestricted,
+    PeerIdInvalid,
+    MessageNotFound,
+    MessageIdInvalid,
+    MessageNotModified,
+    MessageTooLong,
+    UsernameInvalid,
+    UsernameOccupied,
+    UsernameNotOccupied,
+    /// The request was cancelled, usually because TDLib is closing.
+    RequestAborted,
+    /// A message not listed in [`KNOWN_MESSAGES`].
+    Other,
+}
+
+const WAIT_PREFIXES: &[(&str, ErrorKind)] = &[
+    ("FLOOD_WAIT_", ErrorKind::FloodWait),
+    ("SLOWMODE_WAIT_", ErrorKind::SlowModeWait),
+    ("TAKEOUT_INIT_DELAY_", ErrorKind::TakeoutInitDelay),
+    ("Too Many Requests: retry after ", ErrorKind::FloodWait),
+];
+
+impl Error {
+    /// Looks up the message in [`KNOWN_MESSAGES`] and the known wait errors.
+    pub fn kind(&self) -> ErrorKind {
+        if let Some((_, kind)) = KNOWN_MESSAGES.iter().find(|(m, _)| *m == self.message) {
+            return *kind;
+        }
+        match self.wait() {
+            Some((kind, _)) => kind,
+            None => ErrorKind::Other,
+        }
+    }
+
+    /// How long to wait before sending the request again, for flood, slow
+    /// mode and takeout errors.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.wait().map(|(_, duration)| duration)
+    }
+
+    fn wait(&self) -> Option<(ErrorKind, Duration)> {
+        WAIT_PREFIXES.iter().find_map(|(prefix, kind)| {
+            let seconds = self.message.strip_prefix(prefix)?.parse().ok()?;
+            Some((*kind, Duration::from_secs(seconds)))
+        })
+    }
+
+    /// Code 406: the message must not be processed in any way and must not be
+    /// displayed to the user.
+    pub fn must_not_be_shown(&self) -> bool {
+        self.code == 406
+    }
+
+    /// Code 401, or a message saying the session is no longer authorized: the
+    /// user must log in again.
+    pub fn requires_auth(&self) -> bool {
+        self.code == 401
+            || matches!(
+                self.kind(),
+                ErrorKind::AuthKeyUnregistered
+                    | ErrorKind::AuthKeyInvalid
+                    | ErrorKind::AuthKeyDuplicated
+                    | ErrorKind::SessionRevoked
+                    | ErrorKind::SessionExpired
+                    | ErrorKind::UserDeactivated
+            )
+    }
+
+    /// Whether sending the same request again later may succeed: wait errors
+    /// (codes 420 and 429) and server-side failures (codes 500 and above),
+    /// except for requests aborted because TDLib is closing.
+    pub fn is_transient(&self) -> bool {
+        if self.kind() == ErrorKind::RequestAborted {
+            return false;
+        }
+        self.code == 420 || self.code == 429 || self.code >= 500 || self.wait().is_some()
+    }
+
+    /// Whether the same request will keep failing until something else
+    /// changes; the opposite of [`is_transient`](Error::is_transient).
+    pub fn is_permanent(&self) -> bool {
+        !self.is_transient()
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+macro_rules! id {
+    ($(#[$attr:meta])* $name:ident($inner:ty)) => {
+        $(#[$attr])*
+        #[derive(
+            Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub $inner);
+
+        impl From<$inner> for $name {
+            fn from(id: $inner) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<$name> for $inner {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+id! {
+    /// Chat identifier, as found in `Chat::id`.
+    ChatId(i64)
+}
+id! {
+    /// User identifier, as found in `User::id`.
+    UserId(i32)
+}
+id! {
+    /// Message identifier, unique for the chat the message belongs to.
+    MessageId(i64)
+}
+id! {
+    /// Identifier of a file, valid only for the current TDLib instance.
+    FileId(i32)
+}
+id! {
+    /// Basic group identifier, as found in `BasicGroup::id`.
+    BasicGroupId(i32)
+}
+id! {
+    /// Supergroup or channel identifier, as found in `Supergroup::id`.
+    SupergroupId(i32)
+}
+id! {
+    /// Secret chat identifier, as found in `SecretChat::id`.
+    SecretChatId(i32)
+}
+id! {
+    /// Notification group identifier, as found in `NotificationGroup::id`.
+    NotificationGroupId(i32)
+}
+id! {
+    /// Notification identifier, as found in `Notification::id`.
+    NotificationId(i32)
+}
@@ -0,0 +1,123 @@
+//! Conversions between TDLib message identifiers and Telegram server ones.
+//!
+//! TDLib message identifiers, as found in `Message::id`,
+//! `Message::reply_to_message_id` or `Chat::last_read_inbox_message_id`, are
+//! not the identifiers used by the Telegram servers and the Bot API. The low
+//! 20 bits describe the kind of message and the server identifier is stored
+//! above them. Scheduled messages use a different layout, that also encodes
+//! the date the message is scheduled for.
+
+use std::convert::TryFrom;
+
+use crate::types::{MessageId, SupergroupId};
+
+const SERVER_ID_SHIFT: u32 = 20;
+const SHORT_TYPE_MASK: i64 = (1 << 2) - 1;
+const FULL_TYPE_MASK: i64 = (1 << SERVER_ID_SHIFT) - 1;
+const SCHEDULED_MASK: i64 = 4;
+const TYPE_YET_UNSENT: i64 = 1;
+const TYPE_LOCAL: i64 = 2;
+const SCHEDULED_SERVER_ID_SHIFT: u32 = 3;
+const SCHEDULED_SERVER_ID_MASK: i64 = (1 << 18) - 1;
+const SCHEDULED_DATE_SHIFT: u32 = 21;
+const SCHEDULED_DATE_OFFSET: i64 = 1 << 30;
+
+impl MessageId {
+    /// The TDLib identifier of a message with the given server (and Bot API)
+    /// identifier.
+    pub fn from_server_id(server_id: i32) -> Self {
+        MessageId(i64::from(server_id) << SERVER_ID_SHIFT)
+    }
+
+    /// The TDLib identifier of a scheduled message with the given server
+    /// identifier, scheduled to be sent at `send_date` (a Unix time).
+    ///
+    /// Returns `None` if either value can't be represented.
+    pub fn from_scheduled_server_id(server_id: i32, send_date: i32) -> Option<Self> {
+        let server_id = i64::from(server_id);
+        let send_date = i64::from(send_date);
+        if server_id <= 0
+            || server_id > SCHEDULED_SERVER_ID_MASK
+            || send_date <= SCHEDULED_DATE_OFFSET
+        {
+            return None;
+        }
+        Some(MessageId(
+            ((send_date - SCHEDULED_DATE_OFFSET) << SCHEDULED_DATE_SHIFT)
+                | (server_id << SCHEDULED_SERVER_ID_SHIFT)
+                | SCHEDULED_MASK,
+        ))
+    }
+
+    /// Whether this is a scheduled message.
+    pub fn is_scheduled(self) -> bool {
+        self.0 > 0 && self.0 & SCHEDULED_MASK != 0
+    }
+
+    /// Whether this is a message that has been sent and has a server
+    /// identifier.
+    pub fn is_server(self) -> bool {
+        self.0 > 0 && self.0 & FULL_TYPE_MASK == 0
+    }
+
+    /// Whether this is a message that is being sent and hasn't got a server
+    /// identifier yet.
+    pub fn is_yet_unsent(self) -> bool {
+        self.0 > 0 && !self.is_scheduled() && self.0 & SHORT_TYPE_MASK == TYPE_YET_UNSENT
+    }
+
+    /// Whether this is a message that exists only locally, such as the ones
+    /// added with `addLocalMessage` or received in secret chats.
+    pub fn is_local(self) -> bool {
+        self.0 > 0 && !self.is_scheduled() && self.0 & SHORT_TYPE_MASK == TYPE_LOCAL
+    }
+
+    /// The server (and Bot API) identifier of a sent message.
+    ///
+    /// Returns `None` if the identifier doesn't fit in an `i32`.
+    pub fn server_id(self) -> Option<i32> {
+        if self.is_server() {
+            i32::try_from(self.0 >> SERVER_ID_SHIFT).ok()
+        } else {
+            None
+        }
+    }
+
+    /// The server identifier of a scheduled message that has already reached
+    /// the server.
+    pub fn scheduled_server_id(self) -> Option<i32> {
+        if self.is_scheduled() && self.0 & SHORT_TYPE_MASK == 0 {
+            Some(((self.0 >> SCHEDULED_SERVER_ID_SHIFT) & SCHEDULED_SERVER_ID_MASK) as i32)
+        } else {
+            None
+        }
+    }
+
+    /// The Unix time a scheduled message is scheduled to be sent at, as
+    /// encoded in its identifier.
+    ///
+    /// Returns `None` if the date doesn't fit in an `i32`.
+    pub fn scheduled_send_date(self) -> Option<i32> {
+        if self.is_scheduled() {
+            i32::try_from((self.0 >> SCHEDULED_DATE_SHIFT) + SCHEDULED_DATE_OFFSET).ok()
+        } else {
+            None
+        }
+    }
+
+    /// The `t.me` link to this message in a public chat with the given
+    /// username, without asking TDLib. Only sent, non-scheduled messages have
+    /// a link.
+    pub fn public_link(self, username: &str) -> Option<String> {
+        self.server_id()
+            .map(|server_id| format!("https://t.me/{}/{}", username, server_id))
+    }
+
+    /// The `t.me/c` link to this message, that only members of the supergroup
+    /// or channel can open, without asking TDLib. Only sent, non-scheduled
+    /// messages have a link.
+    pub fn private_link(self, supergroup_id: SupergroupId) -> Option<String> {
+        self.server_id()
+            .map(|server_id| format!("https://t.me/c/{}/{}", supergroup_id, server_id))
+    }
+}
@@ -0,0 +1,121 @@
+//! Demultiplexing of the shared `td_receive` stream.
+//!
+//! With the `td_create_client_id`/`td_send`/`td_receive` interface every
+//! client of the process shares one receive queue and TDLib tags each
+//! incoming object with `@client_id`. [`Router`] splits that queue into one
+//! channel per client.
+//!
+//! Since [`Response`](crate::types::Response) also contains every update
+//! and `Error`, `Router<Response>` is usually what you want.
+
+use crate::types::Envelope;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+/// Routes incoming objects to per-client channels based on `@client_id`.
+#[derive(Debug)]
+pub struct Router<T> {
+    clients: HashMap<i32, Sender<Envelope<T>>>,
+}
+
+impl<T> Default for Router<T> {
+    fn default() -> Self {
+        Self {
+            clients: HashMap::new(),
+        }
+    }
+}
+
+impl<T: DeserializeOwned> Router<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts routing objects tagged with `client_id`, returning the receiving
+    /// end of its stream. Registering the same id again replaces the previous
+    /// stream.
+    pub fn register(&mut self, client_id: i32) -> Receiver<Envelope<T>> {
+        let (tx, rx) = channel();
+        self.clients.insert(client_id, tx);
+        rx
+    }
+
+    /// Stops routing objects tagged with `client_id`, closing its stream.
+    pub fn unregister(&mut self, client_id: i32) {
+        self.clients.remove(&client_id);
+    }
+
+    pub fn is_registered(&self, client_id: i32) -> bool {
+        self.clients.contains_key(&client_id)
+    }
+
+    /// Parses a JSON string returned by `td_receive` and routes it, returning
+    /// the id of the client it was delivered to.
+    pub fn route_str(&mut self, json: &str) -> Result<i32, RouteError<T>> {
+        let envelope = serde_json::from_str(json).map_err(RouteError::Json)?;
+        self.route(envelope)
+    }
+
+    /// Routes an already parsed object, returning the id of the client it was
+    /// delivered to.
+    ///
+    /// A client whose receiver has been dropped is unregistered.
+    pub fn route(&mut self, envelope: Envelope<T>) -> Result<i32, RouteError<T>> {
+        let client_id = match envelope.client_id {
+            Some(client_id) => client_id,
+            None => return Err(RouteError::MissingClientId(envelope)),
+        };
+        let tx = match self.clients.get(&client_id) {
+            Some(tx) => tx,
+            None => return Err(RouteError::UnknownClient(envelope)),
+        };
+        match tx.send(envelope) {
+            Ok(()) => Ok(client_id),
+            Err(err) => {
+                self.clients.remove(&client_id);
+                Err(RouteError::Disconnected(err.0))
+            }
+        }
+    }
+}
+
+/// Reasons an incoming object could not be routed. The object is handed back
+/// whenever it could be parsed.
+#[derive(Debug)]
+pub enum RouteError<T> {
+    /// The string is not valid JSON or does not match `T`.
+    Json(serde_json::Error),
+    /// The object has no `@client_id`.
+    MissingClientId(Envelope<T>),
+    /// No stream is registered for the object's `@client_id`.
+    UnknownClient(Envelope<T>),
+    /// The stream for the object's `@client_id` has been dropped.
+    Disconnected(Envelope<T>),
+}
+
+impl<T> fmt::Display for RouteError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RouteError::Json(err) => write!(f, "invalid incoming object: {}", err),
+            RouteError::MissingClientId(_) => write!(f, "incoming object has no @client_id"),
+            RouteError::UnknownClient(e) => {
+                write!(
+                    f,
+                    "no stream registered for client {}",
+                    e.client_id.unwrap_or_default()
+                )
+            }
+            RouteError::Disconnected(e) => {
+                write!(
+                    f,
+                    "stream for client {} was dropped",
+                    e.client_id.unwrap_or_default()
+                )
+            }
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for RouteError<T> {}
@@ -0,0 +1,21 @@
+//! Reflection over the TL schema the crate was generated from.
+//!
+//! [`td_api`] returns the embedded `td_api.tl`, parsed on first use, with
+//! every class, constructor and function, their fields, TL types,
+//! nullability and documentation. [`Schema::parse`] can be used to load other
+//! revisions of the schema, and [`diff::Diff`] compares two of them.
+
+pub mod diff;
+
+use std::sync::OnceLock;
+pub use tl_parser::{Class, Constructor, Definition, Field, Function, ParseError, Schema, TlType};
+
+/// The `td_api.tl` the types in this crate were generated from, that of the
+/// release selected by the `tdlib-*` feature.
+pub const TD_API: &str = crate::release::TD_API;
+
+/// The embedded `td_api.tl`, parsed on first use.
+pub fn td_api() -> &'static Schema {
+    static SCHEMA: OnceLock<Schema> = OnceLock::new();
+    SCHEMA.get_or_init(|| Schema::parse(TD_API).expect("the embedded td_api.tl is valid"))
+}
@@ -0,0 +1,314 @@
+//! Differences between two revisions of a schema, such as the `td_api.tl` of
+//! two TDLib releases.
+//!
+//! [`Diff`] serializes to JSON as a list of changes, tagged by `kind`, and
+//! displays as one line per change.
+
+use super::{Field, Schema};
+use serde::Serialize;
+use std::fmt;
+
+/// Whether a field, or the elements of a vector field, may be null.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum Nullability {
+    NotNull,
+    Nullable,
+    NullableElements,
+}
+
+impl Nullability {
+    fn of(field: &Field) -> Self {
+        if field.nullable {
+            Nullability::Nullable
+        } else if field.nullable_elements {
+            Nullability::NullableElements
+        } else {
+            Nullability::NotNull
+        }
+    }
+}
+
+impl fmt::Display for Nullability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Nullability::NotNull => "not null",
+            Nullability::Nullable => "nullable",
+            Nullability::NullableElements => "nullable elements",
+        })
+    }
+}
+
+/// A single difference between two schemas. Fields are reported on the
+/// constructor or function (`definition`) they belong to; a renamed field
+/// shows up as removed and added.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Change {
+    ClassAdded {
+        class: String,
+    },
+    ClassRemoved {
+        class: String,
+    },
+    ConstructorAdded {
+        constructor: String,
+        class: String,
+    },
+    ConstructorRemoved {
+        constructor: String,
+        class: String,
+    },
+    /// The constructor now builds another class.
+    ClassChanged {
+        constructor: String,
+        old: String,
+        new: String,
+    },
+    FunctionAdded {
+        function: String,
+        result: String,
+    },
+    FunctionRemoved {
+        function: String,
+        result: String,
+    },
+    ResultChanged {
+        function: String,
+        old: String,
+        new: String,
+    },
+    FieldAdded {
+        definition: String,
+        field: String,
+        #[serde(rename = "type")]
+        type_: String,
+        nullability: Nullability,
+    },
+    FieldRemoved {
+        definition: String,
+        field: String,
+        #[serde(rename = "type")]
+        type_: String,
+    },
+    FieldTypeChanged {
+        definition: String,
+        field: String,
+        old: String,
+        new: String,
+    },
+    NullabilityChanged {
+        definition: String,
+        field: String,
+        old: Nullability,
+        new: Nullability,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::ClassAdded { class } => write!(f, "+ class {}", class),
+            Change::ClassRemoved { class } => write!(f, "- class {}", class),
+            Change::ConstructorAdded { constructor, class } => {
+                write!(f, "+ constructor {} = {}", constructor, class)
+            }
+            Change::ConstructorRemoved { constructor, class } => {
+                write!(f, "- constructor {} = {}", constructor, class)
+            }
+            Change::ClassChanged {
+                constructor,
+                old,
+                new,
+            } => write!(f, "~ constructor {} = {} (was {})", constructor, new, old),
+            Change::FunctionAdded { function, result } => {
+                write!(f, "+ function {} = {}", function, result)
+            }
+            Change::FunctionRemoved { function, result } => {
+                write!(f, "- function {} = {}", function, result)
+            }
+            Change::ResultChanged { function, old, new } => {
+                write!(f, "~ function {} = {} (was {})", function, new, old)
+            }
+            Change::FieldAdded {
+                definition,
+                field,
+                type_,
+                nullability,
+            } => write!(
+                f,
+                "+ field {}.{}:{} ({})",
+                definition, field, type_, nullability
+            ),
+            Change::FieldRemoved {
+                definition,
+                field,
+                type_,
+            } => write!(f, "- field {}.{}:{}", definition, field, type_),
+            Change::FieldTypeChanged {
+                definition,
+                field,
+                old,
+                new,
+            } => write!(f, "~ field {}.{}:{} (was {})", definition, field, new, old),
+            Change::NullabilityChanged {
+                definition,
+                field,
+                old,
+                new,
+            } => write!(
+                f,
+                "~ field {}.{} is {} (was {})",
+                definition, field, new, old
+            ),
+        }
+    }
+}
+
+/// The changes from one schema to another.
+#[derive(Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct Diff {
+    pub changes: Vec<Change>,
+}
+
+impl Diff {
+    /// Compares `old` with `new`. Removed and changed definitions are listed
+    /// in the order of `old`, added ones in the order of `new`.
+    pub fn new(old: &Schema, new: &Schema) -> Self {
+        let mut changes = Vec::new();
+        for class in old.classes() {
+            if new.class(&class.name).is_none() {
+                changes.push(Change::ClassRemoved {
+                    class: class.name.clone(),
+                });
+            }
+        }
+        for class in new.classes() {
+            if old.class(&class.name).is_none() {
+                changes.push(Change::ClassAdded {
+                    class: class.name.clone(),
+                });
+            }
+        }
+
+        for constructor in old.constructors() {
+            match new.constructor(&constructor.name) {
+                None => changes.push(Change::ConstructorRemoved {
+                    constructor: constructor.name.clone(),
+                    class: constructor.class.clone(),
+                }),
+                Some(other) => {
+                    if constructor.class != other.class {
+                        changes.push(Change::ClassChanged {
+                            constructor: constructor.name.clone(),
+                            old: constructor.class.clone(),
+                            new: other.class.clone(),
+                        });
+                    }
+                    diff_fields(
+                        &mut changes,
+                        &constructor.name,
+                        &constructor.fields,
+                        &other.fields,
+                    );
+                }
+            }
+        }
+        for constructor in new.constructors() {
+            if old.constructor(&constructor.name).is_none() {
+                changes.push(Change::ConstructorAdded {
+                    constructor: constructor.name.clone(),
+                    class: constructor.class.clone(),
+                });
+            }
+        }
+
+        for function in old.functions() {
+            match new.function(&function.name) {
+                None => changes.push(Change::FunctionRemoved {
+                    function: function.name.clone(),
+                    result: function.result.clone(),
+                }),
+                Some(other) => {
+                    if function.result != other.result {
+                        changes.push(Change::ResultChanged {
+                            function: function.name.clone(),
+                            old: function.result.clone(),
+                            new: other.result.clone(),
+                        });
+                    }
+                    diff_fields(
+                        &mut changes,
+                        &function.name,
+                        &function.fields,
+                        &other.fields,
+                    );
+                }
+            }
+        }
+        for function in new.functions() {
+            if old.function(&function.name).is_none() {
+                changes.push(Change::FunctionAdded {
+                    function: function.name.clone(),
+                    result: function.result.clone(),
+                });
+            }
+        }
+
+        Diff { changes }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.changes {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+fn diff_fields(changes: &mut Vec<Change>, definition: &str, old: &[Field], new: &[Field]) {
+    for field in old {
+        match new.iter().find(|other| other.name == field.name) {
+            None => changes.push(Change::FieldRemoved {
+                definition: definition.to_string(),
+                field: field.name.clone(),
+                type_: field.type_.to_string(),
+            }),
+            Some(other) => {
+                if field.type_ != other.type_ {
+                    changes.push(Change::FieldTypeChanged {
+                        definition: definition.to_string(),
+                        field: field.name.clone(),
+                        old: field.type_.to_string(),
+                        new: other.type_.to_string(),
+                    });
+                }
+                let (old, new) = (Nullability::of(field), Nullability::of(other));
+                if old != new {
+                    changes.push(Change::NullabilityChanged {
+                        definition: definition.to_string(),
+                        field: field.name.clone(),
+                        old,
+                        new,
+                    });
+                }
+            }
+        }
+    }
+    for field in new {
+        if !old.iter().any(|other| other.name == field.name) {
+            changes.push(Change::FieldAdded {
+                definition: definition.to_string(),
+                field: field.name.clone(),
+                type_: field.type_.to_string(),
+                nullability: Nullability::of(field),
+            });
+        }
+    }
+}
@@ -0,0 +1,867 @@
+//! The methods of the `auth` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sets the phone number of the user and sends an authentication code to the user. Works only when the current authorization state is authorizationStateWaitPhoneNumber, or if there is no pending authentication query and the current authorization state is authorizationStateWaitCode, authorizationStateWaitRegistration, or authorizationStateWaitPassword"]
+pub struct SetAuthenticationPhoneNumber {
+    #[doc = "The phone number of the user, in international format"]
+    pub phone_number: String,
+    #[doc = "Settings for the authentication of the user's phone number"]
+    pub settings: PhoneNumberAuthenticationSettings,
+}
+impl Method for SetAuthenticationPhoneNumber {
+    const TYPE: &'static str = "setAuthenticationPhoneNumber";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = Some(&[
+        "authorizationStateWaitPhoneNumber",
+        "authorizationStateWaitCode",
+        "authorizationStateWaitRegistration",
+        "authorizationStateWaitPassword",
+    ]);
+}
+impl SetAuthenticationPhoneNumber {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(phone_number: impl Into<String>) -> Self {
+        Self {
+            phone_number: phone_number.into(),
+            settings: Default::default(),
+        }
+    }
+    #[doc = "Settings for the authentication of the user's phone number"]
+    pub fn with_settings(mut self, value: impl Into<PhoneNumberAuthenticationSettings>) -> Self {
+        self.settings = value.into();
+        self
+    }
+}
+impl Validate for SetAuthenticationPhoneNumber {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.settings
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "settings"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Re-sends an authentication code to the user. Works only when the current authorization state is authorizationStateWaitCode and the next_code_type of the result is not null"]
+pub struct ResendAuthenticationCode {}
+impl Method for ResendAuthenticationCode {
+    const TYPE: &'static str = "resendAuthenticationCode";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> =
+        Some(&["authorizationStateWaitCode"]);
+}
+impl ResendAuthenticationCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for ResendAuthenticationCode {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Checks the authentication code. Works only when the current authorization state is authorizationStateWaitCode"]
+pub struct CheckAuthenticationCode {
+    #[doc = "The verification code received via SMS, Telegram message, phone call, or flash call"]
+    pub code: String,
+}
+impl Method for CheckAuthenticationCode {
+    const TYPE: &'static str = "checkAuthenticationCode";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> =
+        Some(&["authorizationStateWaitCode"]);
+}
+impl CheckAuthenticationCode {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(code: impl Into<String>) -> Self {
+        Self { code: code.into() }
+    }
+}
+impl Validate for CheckAuthenticationCode {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Requests QR code authentication by scanning a QR code on another logged in device. Works only when the current authorization state is authorizationStateWaitPhoneNumber"]
+pub struct RequestQrCodeAuthentication {
+    #[doc = "List of user identifiers of other users currently using the client"]
+    pub other_user_ids: Vec<UserId>,
+}
+impl Method for RequestQrCodeAuthentication {
+    const TYPE: &'static str = "requestQrCodeAuthentication";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> =
+        Some(&["authorizationStateWaitPhoneNumber"]);
+}
+impl RequestQrCodeAuthentication {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "List of user identifiers of other users currently using the client"]
+    pub fn with_other_user_ids(mut self, value: impl Into<Vec<UserId>>) -> Self {
+        self.other_user_ids = value.into();
+        self
+    }
+}
+impl Validate for RequestQrCodeAuthentication {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Finishes user registration. Works only when the current authorization state is authorizationStateWaitRegistration"]
+pub struct RegisterUser {
+    #[doc = "The first name of the user; 1-64 characters"]
+    pub first_name: String,
+    #[doc = "The last name of the user; 0-64 characters"]
+    pub last_name: String,
+}
+impl Method for RegisterUser {
+    const TYPE: &'static str = "registerUser";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> =
+        Some(&["authorizationStateWaitRegistration"]);
+}
+impl RegisterUser {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(first_name: impl Into<String>) -> Self {
+        Self {
+            first_name: first_name.into(),
+            last_name: Default::default(),
+        }
+    }
+    #[doc = "The last name of the user; 0-64 characters"]
+    pub fn with_last_name(mut self, value: impl Into<String>) -> Self {
+        self.last_name = value.into();
+        self
+    }
+}
+impl Validate for RegisterUser {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "first_name",
+            Measure::Characters,
+            self.first_name.chars().count() as i64,
+            Some(1),
+            Some(64),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "last_name",
+            Measure::Characters,
+            self.last_name.chars().count() as i64,
+            Some(0),
+            Some(64),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Checks the authentication password for correctness. Works only when the current authorization state is authorizationStateWaitPassword"]
+pub struct CheckAuthenticationPassword {
+    #[doc = "The password to check"]
+    pub password: String,
+}
+impl Method for CheckAuthenticationPassword {
+    const TYPE: &'static str = "checkAuthenticationPassword";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> =
+        Some(&["authorizationStateWaitPassword"]);
+}
+impl CheckAuthenticationPassword {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(password: impl Into<String>) -> Self {
+        Self {
+            password: password.into(),
+        }
+    }
+}
+impl Validate for CheckAuthenticationPassword {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Requests to send a password recovery code to an email address that was previously set up. Works only when the current authorization state is authorizationStateWaitPassword"]
+pub struct RequestAuthenticationPasswordRecovery {}
+impl Method for RequestAuthenticationPasswordRecovery {
+    const TYPE: &'static str = "requestAuthenticationPasswordRecovery";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> =
+        Some(&["authorizationStateWaitPassword"]);
+}
+impl RequestAuthenticationPasswordRecovery {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for RequestAuthenticationPasswordRecovery {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Recovers the password with a password recovery code sent to an email address that was previously set up. Works only when the current authorization state is authorizationStateWaitPassword"]
+pub struct RecoverAuthenticationPassword {
+    #[doc = "Recovery code to check"]
+    pub recovery_code: String,
+}
+impl Method for RecoverAuthenticationPassword {
+    const TYPE: &'static str = "recoverAuthenticationPassword";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> =
+        Some(&["authorizationStateWaitPassword"]);
+}
+impl RecoverAuthenticationPassword {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(recovery_code: impl Into<String>) -> Self {
+        Self {
+            recovery_code: recovery_code.into(),
+        }
+    }
+}
+impl Validate for RecoverAuthenticationPassword {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Checks the authentication token of a bot; to log in as a bot. Works only when the current authorization state is authorizationStateWaitPhoneNumber. Can be used instead of setAuthenticationPhoneNumber and checkAuthenticationCode to log in"]
+pub struct CheckAuthenticationBotToken {
+    #[doc = "The bot token"]
+    pub token: String,
+}
+impl Method for CheckAuthenticationBotToken {
+    const TYPE: &'static str = "checkAuthenticationBotToken";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> =
+        Some(&["authorizationStateWaitPhoneNumber"]);
+}
+impl CheckAuthenticationBotToken {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+        }
+    }
+}
+impl Validate for CheckAuthenticationBotToken {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Confirms QR code authentication on another device. Returns created session on success"]
+pub struct ConfirmQrCodeAuthentication {
+    #[doc = "A link from a QR code. The link must be scanned by the in-app camera"]
+    pub link: String,
+}
+impl Method for ConfirmQrCodeAuthentication {
+    const TYPE: &'static str = "confirmQrCodeAuthentication";
+    type Response = Session;
+}
+impl ConfirmQrCodeAuthentication {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "A link from a QR code. The link must be scanned by the in-app camera"]
+    pub fn with_link(mut self, value: impl Into<String>) -> Self {
+        self.link = value.into();
+        self
+    }
+}
+impl Validate for ConfirmQrCodeAuthentication {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Changes the database encryption key. Usually the encryption key is never changed and is stored in some OS keychain"]
+pub struct SetDatabaseEncryptionKey {
+    #[doc = "New encryption key"]
+    pub new_encryption_key: Bytes,
+}
+impl Method for SetDatabaseEncryptionKey {
+    const TYPE: &'static str = "setDatabaseEncryptionKey";
+    type Response = Ok;
+}
+impl SetDatabaseEncryptionKey {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "New encryption key"]
+    pub fn with_new_encryption_key(mut self, value: impl Into<Bytes>) -> Self {
+        self.new_encryption_key = value.into();
+        self
+    }
+}
+impl Validate for SetDatabaseEncryptionKey {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the current state of 2-step verification"]
+pub struct GetPasswordState {}
+impl Method for GetPasswordState {
+    const TYPE: &'static str = "getPasswordState";
+    type Response = PasswordState;
+}
+impl GetPasswordState {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetPasswordState {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Changes the password for the user. If a new recovery email address is specified, then the change will not be applied until the new recovery email address is confirmed"]
+pub struct SetPassword {
+    #[doc = "Previous password of the user"]
+    pub old_password: String,
+    #[doc = "New password of the user; may be empty to remove the password"]
+    pub new_password: String,
+    #[doc = "New password hint; may be empty"]
+    pub new_hint: String,
+    #[doc = "Pass true if the recovery email address should be changed"]
+    pub set_recovery_email_address: bool,
+    #[doc = "New recovery email address; may be empty"]
+    pub new_recovery_email_address: String,
+}
+impl Method for SetPassword {
+    const TYPE: &'static str = "setPassword";
+    type Response = PasswordState;
+}
+impl SetPassword {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Previous password of the user"]
+    pub fn with_old_password(mut self, value: impl Into<String>) -> Self {
+        self.old_password = value.into();
+        self
+    }
+    #[doc = "New password of the user; may be empty to remove the password"]
+    pub fn with_new_password(mut self, value: impl Into<String>) -> Self {
+        self.new_password = value.into();
+        self
+    }
+    #[doc = "New password hint; may be empty"]
+    pub fn with_new_hint(mut self, value: impl Into<String>) -> Self {
+        self.new_hint = value.into();
+        self
+    }
+    #[doc = "Pass true if the recovery email address should be changed"]
+    pub fn with_set_recovery_email_address(mut self, value: impl Into<bool>) -> Self {
+        self.set_recovery_email_address = value.into();
+        self
+    }
+    #[doc = "New recovery email address; may be empty"]
+    pub fn with_new_recovery_email_address(mut self, value: impl Into<String>) -> Self {
+        self.new_recovery_email_address = value.into();
+        self
+    }
+}
+impl Validate for SetPassword {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns a 2-step verification recovery email address that was previously set up. This method can be used to verify a password provided by the user"]
+pub struct GetRecoveryEmailAddress {
+    #[doc = "The password for the current user"]
+    pub password: String,
+}
+impl Method for GetRecoveryEmailAddress {
+    const TYPE: &'static str = "getRecoveryEmailAddress";
+    type Response = RecoveryEmailAddress;
+}
+impl GetRecoveryEmailAddress {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The password for the current user"]
+    pub fn with_password(mut self, value: impl Into<String>) -> Self {
+        self.password = value.into();
+        self
+    }
+}
+impl Validate for GetRecoveryEmailAddress {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Changes the 2-step verification recovery email address of the user. If a new recovery email address is specified, then the change will not be applied until the new recovery email address is confirmed. If new_recovery_email_address is the same as the email address that is currently set up, this call succeeds immediately and aborts all other requests waiting for an email confirmation"]
+pub struct SetRecoveryEmailAddress {
+    #[doc = "Password of the current user"]
+    pub password: String,
+    #[doc = "New recovery email address"]
+    pub new_recovery_email_address: String,
+}
+impl Method for SetRecoveryEmailAddress {
+    const TYPE: &'static str = "setRecoveryEmailAddress";
+    type Response = PasswordState;
+}
+impl SetRecoveryEmailAddress {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Password of the current user"]
+    pub fn with_password(mut self, value: impl Into<String>) -> Self {
+        self.password = value.into();
+        self
+    }
+    #[doc = "New recovery email address"]
+    pub fn with_new_recovery_email_address(mut self, value: impl Into<String>) -> Self {
+        self.new_recovery_email_address = value.into();
+        self
+    }
+}
+impl Validate for SetRecoveryEmailAddress {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Checks the 2-step verification recovery email address verification code"]
+pub struct CheckRecoveryEmailAddressCode {
+    #[doc = "Verification code"]
+    pub code: String,
+}
+impl Method for CheckRecoveryEmailAddressCode {
+    const TYPE: &'static str = "checkRecoveryEmailAddressCode";
+    type Response = PasswordState;
+}
+impl CheckRecoveryEmailAddressCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Verification code"]
+    pub fn with_code(mut self, value: impl Into<String>) -> Self {
+        self.code = value.into();
+        self
+    }
+}
+impl Validate for CheckRecoveryEmailAddressCode {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Resends the 2-step verification recovery email address verification code"]
+pub struct ResendRecoveryEmailAddressCode {}
+impl Method for ResendRecoveryEmailAddressCode {
+    const TYPE: &'static str = "resendRecoveryEmailAddressCode";
+    type Response = PasswordState;
+}
+impl ResendRecoveryEmailAddressCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for ResendRecoveryEmailAddressCode {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Requests to send a password recovery code to an email address that was previously set up"]
+pub struct RequestPasswordRecovery {}
+impl Method for RequestPasswordRecovery {
+    const TYPE: &'static str = "requestPasswordRecovery";
+    type Response = EmailAddressAuthenticationCodeInfo;
+}
+impl RequestPasswordRecovery {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for RequestPasswordRecovery {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Recovers the password using a recovery code sent to an email address that was previously set up"]
+pub struct RecoverPassword {
+    #[doc = "Recovery code to check"]
+    pub recovery_code: String,
+}
+impl Method for RecoverPassword {
+    const TYPE: &'static str = "recoverPassword";
+    type Response = PasswordState;
+}
+impl RecoverPassword {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Recovery code to check"]
+    pub fn with_recovery_code(mut self, value: impl Into<String>) -> Self {
+        self.recovery_code = value.into();
+        self
+    }
+}
+impl Validate for RecoverPassword {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Creates a new temporary password for processing payments"]
+pub struct CreateTemporaryPassword {
+    #[doc = "Persistent user password"]
+    pub password: String,
+    #[doc = "Time during which the temporary password will be valid, in seconds; should be between 60 and 86400"]
+    pub valid_for: i32,
+}
+impl Method for CreateTemporaryPassword {
+    const TYPE: &'static str = "createTemporaryPassword";
+    type Response = TemporaryPasswordState;
+}
+impl CreateTemporaryPassword {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(valid_for: impl Into<i32>) -> Self {
+        Self {
+            password: Default::default(),
+            valid_for: valid_for.into(),
+        }
+    }
+    #[doc = "Persistent user password"]
+    pub fn with_password(mut self, value: impl Into<String>) -> Self {
+        self.password = value.into();
+        self
+    }
+}
+impl Validate for CreateTemporaryPassword {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "valid_for",
+            Measure::Value,
+            i64::from(self.valid_for),
+            Some(60),
+            Some(86400),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns information about the current temporary password"]
+pub struct GetTemporaryPasswordState {}
+impl Method for GetTemporaryPasswordState {
+    const TYPE: &'static str = "getTemporaryPasswordState";
+    type Response = TemporaryPasswordState;
+}
+impl GetTemporaryPasswordState {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetTemporaryPasswordState {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Changes the phone number of the user and sends an authentication code to the user's new phone number. On success, returns information about the sent code"]
+pub struct ChangePhoneNumber {
+    #[doc = "The new phone number of the user in international format"]
+    pub phone_number: String,
+    #[doc = "Settings for the authentication of the user's phone number"]
+    pub settings: PhoneNumberAuthenticationSettings,
+}
+impl Method for ChangePhoneNumber {
+    const TYPE: &'static str = "changePhoneNumber";
+    type Response = AuthenticationCodeInfo;
+}
+impl ChangePhoneNumber {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The new phone number of the user in international format"]
+    pub fn with_phone_number(mut self, value: impl Into<String>) -> Self {
+        self.phone_number = value.into();
+        self
+    }
+    #[doc = "Settings for the authentication of the user's phone number"]
+    pub fn with_settings(mut self, value: impl Into<PhoneNumberAuthenticationSettings>) -> Self {
+        self.settings = value.into();
+        self
+    }
+}
+impl Validate for ChangePhoneNumber {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.settings
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "settings"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Re-sends the authentication code sent to confirm a new phone number for the user. Works only if the previously received authenticationCodeInfo next_code_type was not null"]
+pub struct ResendChangePhoneNumberCode {}
+impl Method for ResendChangePhoneNumberCode {
+    const TYPE: &'static str = "resendChangePhoneNumberCode";
+    type Response = AuthenticationCodeInfo;
+}
+impl ResendChangePhoneNumberCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for ResendChangePhoneNumberCode {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Checks the authentication code sent to confirm a new phone number of the user"]
+pub struct CheckChangePhoneNumberCode {
+    #[doc = "Verification code received by SMS, phone call or flash call"]
+    pub code: String,
+}
+impl Method for CheckChangePhoneNumberCode {
+    const TYPE: &'static str = "checkChangePhoneNumberCode";
+    type Response = Ok;
+}
+impl CheckChangePhoneNumberCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Verification code received by SMS, phone call or flash call"]
+    pub fn with_code(mut self, value: impl Into<String>) -> Self {
+        self.code = value.into();
+        self
+    }
+}
+impl Validate for CheckChangePhoneNumberCode {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns all active sessions of the current user"]
+pub struct GetActiveSessions {}
+impl Method for GetActiveSessions {
+    const TYPE: &'static str = "getActiveSessions";
+    type Response = Sessions;
+}
+impl GetActiveSessions {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetActiveSessions {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Terminates a session of the current user"]
+pub struct TerminateSession {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Session identifier"]
+    pub session_id: i64,
+}
+impl Method for TerminateSession {
+    const TYPE: &'static str = "terminateSession";
+    type Response = Ok;
+}
+impl TerminateSession {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Session identifier"]
+    pub fn with_session_id(mut self, value: impl Into<i64>) -> Self {
+        self.session_id = value.into();
+        self
+    }
+}
+impl Validate for TerminateSession {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Terminates all other sessions of the current user"]
+pub struct TerminateAllOtherSessions {}
+impl Method for TerminateAllOtherSessions {
+    const TYPE: &'static str = "terminateAllOtherSessions";
+    type Response = Ok;
+}
+impl TerminateAllOtherSessions {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for TerminateAllOtherSessions {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns all website where the current user used Telegram to log in"]
+pub struct GetConnectedWebsites {}
+impl Method for GetConnectedWebsites {
+    const TYPE: &'static str = "getConnectedWebsites";
+    type Response = ConnectedWebsites;
+}
+impl GetConnectedWebsites {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetConnectedWebsites {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Disconnects website from the current user's Telegram account"]
+pub struct DisconnectWebsite {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Website identifier"]
+    pub website_id: i64,
+}
+impl Method for DisconnectWebsite {
+    const TYPE: &'static str = "disconnectWebsite";
+    type Response = Ok;
+}
+impl DisconnectWebsite {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Website identifier"]
+    pub fn with_website_id(mut self, value: impl Into<i64>) -> Self {
+        self.website_id = value.into();
+        self
+    }
+}
+impl Validate for DisconnectWebsite {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Disconnects all websites from the current user's Telegram account"]
+pub struct DisconnectAllWebsites {}
+impl Method for DisconnectAllWebsites {
+    const TYPE: &'static str = "disconnectAllWebsites";
+    type Response = Ok;
+}
+impl DisconnectAllWebsites {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for DisconnectAllWebsites {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the period of inactivity after which the account of the current user will automatically be deleted"]
+pub struct SetAccountTtl {
+    #[doc = "New account TTL"]
+    pub ttl: AccountTtl,
+}
+impl Method for SetAccountTtl {
+    const TYPE: &'static str = "setAccountTtl";
+    type Response = Ok;
+}
+impl SetAccountTtl {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(ttl: impl Into<AccountTtl>) -> Self {
+        Self { ttl: ttl.into() }
+    }
+}
+impl Validate for SetAccountTtl {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.ttl
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "ttl"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the period of inactivity after which the account of the current user will automatically be deleted"]
+pub struct GetAccountTtl {}
+impl Method for GetAccountTtl {
+    const TYPE: &'static str = "getAccountTtl";
+    type Response = AccountTtl;
+}
+impl GetAccountTtl {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetAccountTtl {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Deletes the account of the current user, deleting all information associated with the user from the server. The phone number of the account can be used to create a new account. Can be called before authorization when the current authorization state is authorizationStateWaitPassword"]
+pub struct DeleteAccount {
+    #[doc = "The reason why the account was deleted; optional"]
+    pub reason: String,
+}
+impl Method for DeleteAccount {
+    const TYPE: &'static str = "deleteAccount";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> =
+        Some(&["authorizationStateReady", "authorizationStateWaitPassword"]);
+}
+impl DeleteAccount {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The reason why the account was deleted; optional"]
+    pub fn with_reason(mut self, value: impl Into<String>) -> Self {
+        self.reason = value.into();
+        self
+    }
+}
+impl Validate for DeleteAccount {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Sends phone number confirmation code. Should be called when user presses \"https://t.me/confirmphone?phone=*******&hash=**********\" or \"tg://confirmphone?phone=*******&hash=**********\" link"]
+pub struct SendPhoneNumberConfirmationCode {
+    #[doc = "Value of the \"hash\" parameter from the link"]
+    pub hash: String,
+    #[doc = "Value of the \"phone\" parameter from the link"]
+    pub phone_number: String,
+    #[doc = "Settings for the authentication of the user's phone number"]
+    pub settings: PhoneNumberAuthenticationSettings,
+}
+impl Method for SendPhoneNumberConfirmationCode {
+    const TYPE: &'static str = "sendPhoneNumberConfirmationCode";
+    type Response = AuthenticationCodeInfo;
+}
+impl SendPhoneNumberConfirmationCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Value of the \"hash\" parameter from the link"]
+    pub fn with_hash(mut self, value: impl Into<String>) -> Self {
+        self.hash = value.into();
+        self
+    }
+    #[doc = "Value of the \"phone\" parameter from the link"]
+    pub fn with_phone_number(mut self, value: impl Into<String>) -> Self {
+        self.phone_number = value.into();
+        self
+    }
+    #[doc = "Settings for the authentication of the user's phone number"]
+    pub fn with_settings(mut self, value: impl Into<PhoneNumberAuthenticationSettings>) -> Self {
+        self.settings = value.into();
+        self
+    }
+}
+impl Validate for SendPhoneNumberConfirmationCode {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.settings
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "settings"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Resends phone number confirmation code"]
+pub struct ResendPhoneNumberConfirmationCode {}
+impl Method for ResendPhoneNumberConfirmationCode {
+    const TYPE: &'static str = "resendPhoneNumberConfirmationCode";
+    type Response = AuthenticationCodeInfo;
+}
+impl ResendPhoneNumberConfirmationCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for ResendPhoneNumberConfirmationCode {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Checks phone number confirmation code"]
+pub struct CheckPhoneNumberConfirmationCode {
+    #[doc = "The phone number confirmation code"]
+    pub code: String,
+}
+impl Method for CheckPhoneNumberConfirmationCode {
+    const TYPE: &'static str = "checkPhoneNumberConfirmationCode";
+    type Response = Ok;
+}
+impl CheckPhoneNumberConfirmationCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The phone number confirmation code"]
+    pub fn with_code(mut self, value: impl Into<String>) -> Self {
+        self.code = value.into();
+        self
+    }
+}
+impl Validate for CheckPhoneNumberConfirmationCode {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Accepts Telegram terms of services"]
+pub struct AcceptTermsOfService {
+    #[doc = "Terms of service identifier"]
+    pub terms_of_service_id: String,
+}
+impl Method for AcceptTermsOfService {
+    const TYPE: &'static str = "acceptTermsOfService";
+    type Response = Ok;
+}
+impl AcceptTermsOfService {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Terms of service identifier"]
+    pub fn with_terms_of_service_id(mut self, value: impl Into<String>) -> Self {
+        self.terms_of_service_id = value.into();
+        self
+    }
+}
+impl Validate for AcceptTermsOfService {}
@@ -0,0 +1,833 @@
+//! The methods of the `files` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a file; this is an offline request"]
+pub struct GetFile {
+    #[doc = "Identifier of the file to get"]
+    pub file_id: FileId,
+}
+impl Method for GetFile {
+    const TYPE: &'static str = "getFile";
+    type Response = File;
+    const OFFLINE: bool = true;
+}
+impl GetFile {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(file_id: impl Into<FileId>) -> Self {
+        Self {
+            file_id: file_id.into(),
+        }
+    }
+}
+impl Validate for GetFile {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a file by its remote ID; this is an offline request. Can be used to register a URL as a file for further uploading, or sending as a message. Even the request succeeds, the file can be used only if it is still accessible to the user. For example, if the file is from a message, then the message must be not deleted and accessible to the user. If the file database is disabled, then the corresponding object with the file must be preloaded by the client"]
+pub struct GetRemoteFile {
+    #[doc = "Remote identifier of the file to get"]
+    pub remote_file_id: String,
+    #[doc = "File type, if known"]
+    pub file_type: FileType,
+}
+impl Method for GetRemoteFile {
+    const TYPE: &'static str = "getRemoteFile";
+    type Response = File;
+    const OFFLINE: bool = true;
+}
+impl GetRemoteFile {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(file_type: impl Into<FileType>) -> Self {
+        Self {
+            remote_file_id: Default::default(),
+            file_type: file_type.into(),
+        }
+    }
+    #[doc = "Remote identifier of the file to get"]
+    pub fn with_remote_file_id(mut self, value: impl Into<String>) -> Self {
+        self.remote_file_id = value.into();
+        self
+    }
+}
+impl Validate for GetRemoteFile {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.file_type
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "file_type"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the MIME type of a file, guessed by its extension. Returns an empty string on failure. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct GetFileMimeType {
+    #[doc = "The name of the file or path to the file"]
+    pub file_name: String,
+}
+impl Method for GetFileMimeType {
+    const TYPE: &'static str = "getFileMimeType";
+    type Response = Text;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for GetFileMimeType {}
+impl GetFileMimeType {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The name of the file or path to the file"]
+    pub fn with_file_name(mut self, value: impl Into<String>) -> Self {
+        self.file_name = value.into();
+        self
+    }
+}
+impl Validate for GetFileMimeType {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the extension of a file, guessed by its MIME type. Returns an empty string on failure. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct GetFileExtension {
+    #[doc = "The MIME type of the file"]
+    pub mime_type: String,
+}
+impl Method for GetFileExtension {
+    const TYPE: &'static str = "getFileExtension";
+    type Response = Text;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for GetFileExtension {}
+impl GetFileExtension {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The MIME type of the file"]
+    pub fn with_mime_type(mut self, value: impl Into<String>) -> Self {
+        self.mime_type = value.into();
+        self
+    }
+}
+impl Validate for GetFileExtension {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Removes potentially dangerous characters from the name of a file. The encoding of the file name is supposed to be UTF-8. Returns an empty string on failure. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct CleanFileName {
+    #[doc = "File name or path to the file"]
+    pub file_name: String,
+}
+impl Method for CleanFileName {
+    const TYPE: &'static str = "cleanFileName";
+    type Response = Text;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for CleanFileName {}
+impl CleanFileName {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "File name or path to the file"]
+    pub fn with_file_name(mut self, value: impl Into<String>) -> Self {
+        self.file_name = value.into();
+        self
+    }
+}
+impl Validate for CleanFileName {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Downloads a file from the cloud. Download progress and completion of the download will be notified through updateFile updates"]
+pub struct DownloadFile {
+    #[doc = "Identifier of the file to download"]
+    pub file_id: FileId,
+    #[doc = "Priority of the download (1-32). The higher the priority, the earlier the file will be downloaded. If the priorities of two files are equal, then the last one for which downloadFile was called will be downloaded first"]
+    pub priority: i32,
+    #[doc = "The starting position from which the file should be downloaded"]
+    pub offset: i32,
+    #[doc = "Number of bytes which should be downloaded starting from the \"offset\" position before the download will be automatically cancelled; use 0 to download without a limit"]
+    pub limit: i32,
+    #[doc = "If false, this request returns file state just after the download has been started. If true, this request returns file state only after the download has succeeded, has failed, has been cancelled or a new downloadFile request with different offset/limit parameters was sent"]
+    pub synchronous: bool,
+}
+impl Method for DownloadFile {
+    const TYPE: &'static str = "downloadFile";
+    type Response = File;
+}
+impl DownloadFile {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(file_id: impl Into<FileId>, priority: impl Into<i32>) -> Self {
+        Self {
+            file_id: file_id.into(),
+            priority: priority.into(),
+            offset: Default::default(),
+            limit: Default::default(),
+            synchronous: Default::default(),
+        }
+    }
+    #[doc = "The starting position from which the file should be downloaded"]
+    pub fn with_offset(mut self, value: impl Into<i32>) -> Self {
+        self.offset = value.into();
+        self
+    }
+    #[doc = "Number of bytes which should be downloaded starting from the \"offset\" position before the download will be automatically cancelled; use 0 to download without a limit"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+    #[doc = "If false, this request returns file state just after the download has been started. If true, this request returns file state only after the download has succeeded, has failed, has been cancelled or a new downloadFile request with different offset/limit parameters was sent"]
+    pub fn with_synchronous(mut self, value: impl Into<bool>) -> Self {
+        self.synchronous = value.into();
+        self
+    }
+}
+impl Validate for DownloadFile {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "priority",
+            Measure::Value,
+            i64::from(self.priority),
+            Some(1),
+            Some(32),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns file downloaded prefix size from a given offset"]
+pub struct GetFileDownloadedPrefixSize {
+    #[doc = "Identifier of the file"]
+    pub file_id: FileId,
+    #[doc = "Offset from which downloaded prefix size should be calculated"]
+    pub offset: i32,
+}
+impl Method for GetFileDownloadedPrefixSize {
+    const TYPE: &'static str = "getFileDownloadedPrefixSize";
+    type Response = Count;
+}
+impl GetFileDownloadedPrefixSize {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(file_id: impl Into<FileId>) -> Self {
+        Self {
+            file_id: file_id.into(),
+            offset: Default::default(),
+        }
+    }
+    #[doc = "Offset from which downloaded prefix size should be calculated"]
+    pub fn with_offset(mut self, value: impl Into<i32>) -> Self {
+        self.offset = value.into();
+        self
+    }
+}
+impl Validate for GetFileDownloadedPrefixSize {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Stops the downloading of a file. If a file has already been downloaded, does nothing"]
+pub struct CancelDownloadFile {
+    #[doc = "Identifier of a file to stop downloading"]
+    pub file_id: FileId,
+    #[doc = "Pass true to stop downloading only if it hasn't been started, i.e. request hasn't been sent to server"]
+    pub only_if_pending: bool,
+}
+impl Method for CancelDownloadFile {
+    const TYPE: &'static str = "cancelDownloadFile";
+    type Response = Ok;
+}
+impl CancelDownloadFile {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(file_id: impl Into<FileId>) -> Self {
+        Self {
+            file_id: file_id.into(),
+            only_if_pending: Default::default(),
+        }
+    }
+    #[doc = "Pass true to stop downloading only if it hasn't been started, i.e. request hasn't been sent to server"]
+    pub fn with_only_if_pending(mut self, value: impl Into<bool>) -> Self {
+        self.only_if_pending = value.into();
+        self
+    }
+}
+impl Validate for CancelDownloadFile {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Asynchronously uploads a file to the cloud without sending it in a message. updateFile will be used to notify about upload progress and successful completion of the upload. The file will not have a persistent remote identifier until it will be sent in a message"]
+pub struct UploadFile {
+    #[doc = "File to upload"]
+    pub file: InputFile,
+    #[doc = "File type"]
+    pub file_type: FileType,
+    #[doc = "Priority of the upload (1-32). The higher the priority, the earlier the file will be uploaded. If the priorities of two files are equal, then the first one for which uploadFile was called will be uploaded first"]
+    pub priority: i32,
+}
+impl Method for UploadFile {
+    const TYPE: &'static str = "uploadFile";
+    type Response = File;
+}
+impl UploadFile {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        file: impl Into<InputFile>,
+        file_type: impl Into<FileType>,
+        priority: impl Into<i32>,
+    ) -> Self {
+        Self {
+            file: file.into(),
+            file_type: file_type.into(),
+            priority: priority.into(),
+        }
+    }
+}
+impl Validate for UploadFile {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.file
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "file"))?;
+        self.file_type
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "file_type"))?;
+        validate::check(
+            Self::TYPE,
+            "priority",
+            Measure::Value,
+            i64::from(self.priority),
+            Some(1),
+            Some(32),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Stops the uploading of a file. Supported only for files uploaded by using uploadFile. For other files the behavior is undefined"]
+pub struct CancelUploadFile {
+    #[doc = "Identifier of the file to stop uploading"]
+    pub file_id: FileId,
+}
+impl Method for CancelUploadFile {
+    const TYPE: &'static str = "cancelUploadFile";
+    type Response = Ok;
+}
+impl CancelUploadFile {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(file_id: impl Into<FileId>) -> Self {
+        Self {
+            file_id: file_id.into(),
+        }
+    }
+}
+impl Validate for CancelUploadFile {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Writes a part of a generated file. This method is intended to be used only if the client has no direct access to TDLib's file system, because it is usually slower than a direct write to the destination file"]
+pub struct WriteGeneratedFilePart {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "The identifier of the generation process"]
+    pub generation_id: i64,
+    #[doc = "The offset from which to write the data to the file"]
+    pub offset: i32,
+    #[doc = "The data to write"]
+    pub data: Bytes,
+}
+impl Method for WriteGeneratedFilePart {
+    const TYPE: &'static str = "writeGeneratedFilePart";
+    type Response = Ok;
+}
+impl WriteGeneratedFilePart {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The identifier of the generation process"]
+    pub fn with_generation_id(mut self, value: impl Into<i64>) -> Self {
+        self.generation_id = value.into();
+        self
+    }
+    #[doc = "The offset from which to write the data to the file"]
+    pub fn with_offset(mut self, value: impl Into<i32>) -> Self {
+        self.offset = value.into();
+        self
+    }
+    #[doc = "The data to write"]
+    pub fn with_data(mut self, value: impl Into<Bytes>) -> Self {
+        self.data = value.into();
+        self
+    }
+}
+impl Validate for WriteGeneratedFilePart {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Informs TDLib on a file generation progress"]
+pub struct SetFileGenerationProgress {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "The identifier of the generation process"]
+    pub generation_id: i64,
+    #[doc = "Expected size of the generated file, in bytes; 0 if unknown"]
+    pub expected_size: i32,
+    #[doc = "The number of bytes already generated"]
+    pub local_prefix_size: i32,
+}
+impl Method for SetFileGenerationProgress {
+    const TYPE: &'static str = "setFileGenerationProgress";
+    type Response = Ok;
+}
+impl SetFileGenerationProgress {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The identifier of the generation process"]
+    pub fn with_generation_id(mut self, value: impl Into<i64>) -> Self {
+        self.generation_id = value.into();
+        self
+    }
+    #[doc = "Expected size of the generated file, in bytes; 0 if unknown"]
+    pub fn with_expected_size(mut self, value: impl Into<i32>) -> Self {
+        self.expected_size = value.into();
+        self
+    }
+    #[doc = "The number of bytes already generated"]
+    pub fn with_local_prefix_size(mut self, value: impl Into<i32>) -> Self {
+        self.local_prefix_size = value.into();
+        self
+    }
+}
+impl Validate for SetFileGenerationProgress {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Finishes the file generation"]
+pub struct FinishFileGeneration {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "The identifier of the generation process"]
+    pub generation_id: i64,
+    #[doc = "If set, means that file generation has failed and should be terminated"]
+    pub error: Error,
+}
+impl Method for FinishFileGeneration {
+    const TYPE: &'static str = "finishFileGeneration";
+    type Response = Ok;
+}
+impl FinishFileGeneration {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The identifier of the generation process"]
+    pub fn with_generation_id(mut self, value: impl Into<i64>) -> Self {
+        self.generation_id = value.into();
+        self
+    }
+    #[doc = "If set, means that file generation has failed and should be terminated"]
+    pub fn with_error(mut self, value: impl Into<Error>) -> Self {
+        self.error = value.into();
+        self
+    }
+}
+impl Validate for FinishFileGeneration {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.error
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "error"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Reads a part of a file from the TDLib file cache and returns read bytes. This method is intended to be used only if the client has no direct access to TDLib's file system, because it is usually slower than a direct read from the file"]
+pub struct ReadFilePart {
+    #[doc = "Identifier of the file. The file must be located in the TDLib file cache"]
+    pub file_id: FileId,
+    #[doc = "The offset from which to read the file"]
+    pub offset: i32,
+    #[doc = "Number of bytes to read. An error will be returned if there are not enough bytes available in the file from the specified position. Pass 0 to read all available data from the specified position"]
+    pub count: i32,
+}
+impl Method for ReadFilePart {
+    const TYPE: &'static str = "readFilePart";
+    type Response = FilePart;
+}
+impl ReadFilePart {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(file_id: impl Into<FileId>) -> Self {
+        Self {
+            file_id: file_id.into(),
+            offset: Default::default(),
+            count: Default::default(),
+        }
+    }
+    #[doc = "The offset from which to read the file"]
+    pub fn with_offset(mut self, value: impl Into<i32>) -> Self {
+        self.offset = value.into();
+        self
+    }
+    #[doc = "Number of bytes to read. An error will be returned if there are not enough bytes available in the file from the specified position. Pass 0 to read all available data from the specified position"]
+    pub fn with_count(mut self, value: impl Into<i32>) -> Self {
+        self.count = value.into();
+        self
+    }
+}
+impl Validate for ReadFilePart {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Deletes a file from the TDLib file cache"]
+pub struct DeleteFile {
+    #[doc = "Identifier of the file to delete"]
+    pub file_id: FileId,
+}
+impl Method for DeleteFile {
+    const TYPE: &'static str = "deleteFile";
+    type Response = Ok;
+}
+impl DeleteFile {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(file_id: impl Into<FileId>) -> Self {
+        Self {
+            file_id: file_id.into(),
+        }
+    }
+}
+impl Validate for DeleteFile {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns storage usage statistics. Can be called before authorization"]
+pub struct GetStorageStatistics {
+    #[doc = "The maximum number of chats with the largest storage usage for which separate statistics should be returned. All other chats will be grouped in entries with chat_id == 0. If the chat info database is not used, the chat_limit is ignored and is always set to 0"]
+    pub chat_limit: i32,
+}
+impl Method for GetStorageStatistics {
+    const TYPE: &'static str = "getStorageStatistics";
+    type Response = StorageStatistics;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl GetStorageStatistics {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The maximum number of chats with the largest storage usage for which separate statistics should be returned. All other chats will be grouped in entries with chat_id == 0. If the chat info database is not used, the chat_limit is ignored and is always set to 0"]
+    pub fn with_chat_limit(mut self, value: impl Into<i32>) -> Self {
+        self.chat_limit = value.into();
+        self
+    }
+}
+impl Validate for GetStorageStatistics {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Quickly returns approximate storage usage statistics. Can be called before authorization"]
+pub struct GetStorageStatisticsFast {}
+impl Method for GetStorageStatisticsFast {
+    const TYPE: &'static str = "getStorageStatisticsFast";
+    type Response = StorageStatisticsFast;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl GetStorageStatisticsFast {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetStorageStatisticsFast {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns database statistics"]
+pub struct GetDatabaseStatistics {}
+impl Method for GetDatabaseStatistics {
+    const TYPE: &'static str = "getDatabaseStatistics";
+    type Response = DatabaseStatistics;
+}
+impl GetDatabaseStatistics {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetDatabaseStatistics {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Optimizes storage usage, i.e. deletes some files and returns new storage usage statistics. Secret thumbnails can't be deleted"]
+pub struct OptimizeStorage {
+    #[doc = "Limit on the total size of files after deletion. Pass -1 to use the default limit"]
+    pub size: i64,
+    #[doc = "Limit on the time that has passed since the last time a file was accessed (or creation time for some filesystems). Pass -1 to use the default limit"]
+    pub ttl: i32,
+    #[doc = "Limit on the total count of files after deletion. Pass -1 to use the default limit"]
+    pub count: i32,
+    #[doc = "The amount of time after the creation of a file during which it can't be deleted, in seconds. Pass -1 to use the default value"]
+    pub immunity_delay: i32,
+    #[doc = "If not empty, only files with the given type(s) are considered. By default, all types except thumbnails, profile photos, stickers and wallpapers are deleted"]
+    pub file_types: Vec<FileType>,
+    #[doc = "If not empty, only files from the given chats are considered. Use 0 as chat identifier to delete files not belonging to any chat (e.g., profile photos)"]
+    pub chat_ids: Vec<ChatId>,
+    #[doc = "If not empty, files from the given chats are excluded. Use 0 as chat identifier to exclude all files not belonging to any chat (e.g., profile photos)"]
+    pub exclude_chat_ids: Vec<ChatId>,
+    #[doc = "Same as in getStorageStatistics. Affects only returned statistics"]
+    pub chat_limit: i32,
+}
+impl Method for OptimizeStorage {
+    const TYPE: &'static str = "optimizeStorage";
+    type Response = StorageStatistics;
+}
+impl OptimizeStorage {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Limit on the total size of files after deletion. Pass -1 to use the default limit"]
+    pub fn with_size(mut self, value: impl Into<i64>) -> Self {
+        self.size = value.into();
+        self
+    }
+    #[doc = "Limit on the time that has passed since the last time a file was accessed (or creation time for some filesystems). Pass -1 to use the default limit"]
+    pub fn with_ttl(mut self, value: impl Into<i32>) -> Self {
+        self.ttl = value.into();
+        self
+    }
+    #[doc = "Limit on the total count of files after deletion. Pass -1 to use the default limit"]
+    pub fn with_count(mut self, value: impl Into<i32>) -> Self {
+        self.count = value.into();
+        self
+    }
+    #[doc = "The amount of time after the creation of a file during which it can't be deleted, in seconds. Pass -1 to use the default value"]
+    pub fn with_immunity_delay(mut self, value: impl Into<i32>) -> Self {
+        self.immunity_delay = value.into();
+        self
+    }
+    #[doc = "If not empty, only files with the given type(s) are considered. By default, all types except thumbnails, profile photos, stickers and wallpapers are deleted"]
+    pub fn with_file_types(mut self, value: impl Into<Vec<FileType>>) -> Self {
+        self.file_types = value.into();
+        self
+    }
+    #[doc = "If not empty, only files from the given chats are considered. Use 0 as chat identifier to delete files not belonging to any chat (e.g., profile photos)"]
+    pub fn with_chat_ids(mut self, value: impl Into<Vec<ChatId>>) -> Self {
+        self.chat_ids = value.into();
+        self
+    }
+    #[doc = "If not empty, files from the given chats are excluded. Use 0 as chat identifier to exclude all files not belonging to any chat (e.g., profile photos)"]
+    pub fn with_exclude_chat_ids(mut self, value: impl Into<Vec<ChatId>>) -> Self {
+        self.exclude_chat_ids = value.into();
+        self
+    }
+    #[doc = "Same as in getStorageStatistics. Affects only returned statistics"]
+    pub fn with_chat_limit(mut self, value: impl Into<i32>) -> Self {
+        self.chat_limit = value.into();
+        self
+    }
+}
+impl Validate for OptimizeStorage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, value) in self.file_types.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("file_types[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns network data usage statistics. Can be called before authorization"]
+pub struct GetNetworkStatistics {
+    #[doc = "If true, returns only data for the current library launch"]
+    pub only_current: bool,
+}
+impl Method for GetNetworkStatistics {
+    const TYPE: &'static str = "getNetworkStatistics";
+    type Response = NetworkStatistics;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl GetNetworkStatistics {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "If true, returns only data for the current library launch"]
+    pub fn with_only_current(mut self, value: impl Into<bool>) -> Self {
+        self.only_current = value.into();
+        self
+    }
+}
+impl Validate for GetNetworkStatistics {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Adds the specified data to data usage statistics. Can be called before authorization"]
+pub struct AddNetworkStatistics {
+    #[doc = "The network statistics entry with the data to be added to statistics"]
+    pub entry: NetworkStatisticsEntry,
+}
+impl Method for AddNetworkStatistics {
+    const TYPE: &'static str = "addNetworkStatistics";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl AddNetworkStatistics {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(entry: impl Into<NetworkStatisticsEntry>) -> Self {
+        Self {
+            entry: entry.into(),
+        }
+    }
+}
+impl Validate for AddNetworkStatistics {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.entry
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "entry"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Resets all network data usage statistics to zero. Can be called before authorization"]
+pub struct ResetNetworkStatistics {}
+impl Method for ResetNetworkStatistics {
+    const TYPE: &'static str = "resetNetworkStatistics";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl ResetNetworkStatistics {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for ResetNetworkStatistics {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns auto-download settings presets for the currently logged in user"]
+pub struct GetAutoDownloadSettingsPresets {}
+impl Method for GetAutoDownloadSettingsPresets {
+    const TYPE: &'static str = "getAutoDownloadSettingsPresets";
+    type Response = AutoDownloadSettingsPresets;
+}
+impl GetAutoDownloadSettingsPresets {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetAutoDownloadSettingsPresets {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sets auto-download settings"]
+pub struct SetAutoDownloadSettings {
+    #[doc = "New user auto-download settings"]
+    pub settings: AutoDownloadSettings,
+    #[serde(rename = "type")]
+    #[doc = "Type of the network for which the new settings are applied"]
+    pub type_: NetworkType,
+}
+impl Method for SetAutoDownloadSettings {
+    const TYPE: &'static str = "setAutoDownloadSettings";
+    type Response = Ok;
+}
+impl SetAutoDownloadSettings {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(type_: impl Into<NetworkType>) -> Self {
+        Self {
+            settings: Default::default(),
+            type_: type_.into(),
+        }
+    }
+    #[doc = "New user auto-download settings"]
+    pub fn with_settings(mut self, value: impl Into<AutoDownloadSettings>) -> Self {
+        self.settings = value.into();
+        self
+    }
+}
+impl Validate for SetAutoDownloadSettings {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.settings
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "settings"))?;
+        self.type_
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "type"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a file with a map thumbnail in PNG format. Only map thumbnail files with size less than 1MB can be downloaded"]
+pub struct GetMapThumbnailFile {
+    #[doc = "Location of the map center"]
+    pub location: Location,
+    #[doc = "Map zoom level; 13-20"]
+    pub zoom: i32,
+    #[doc = "Map width in pixels before applying scale; 16-1024"]
+    pub width: i32,
+    #[doc = "Map height in pixels before applying scale; 16-1024"]
+    pub height: i32,
+    #[doc = "Map scale; 1-3"]
+    pub scale: i32,
+    #[doc = "Identifier of a chat, in which the thumbnail will be shown. Use 0 if unknown"]
+    pub chat_id: ChatId,
+}
+impl Method for GetMapThumbnailFile {
+    const TYPE: &'static str = "getMapThumbnailFile";
+    type Response = File;
+}
+impl GetMapThumbnailFile {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        zoom: impl Into<i32>,
+        width: impl Into<i32>,
+        height: impl Into<i32>,
+        scale: impl Into<i32>,
+    ) -> Self {
+        Self {
+            location: Default::default(),
+            zoom: zoom.into(),
+            width: width.into(),
+            height: height.into(),
+            scale: scale.into(),
+            chat_id: Default::default(),
+        }
+    }
+    #[doc = "Location of the map center"]
+    pub fn with_location(mut self, value: impl Into<Location>) -> Self {
+        self.location = value.into();
+        self
+    }
+    #[doc = "Identifier of a chat, in which the thumbnail will be shown. Use 0 if unknown"]
+    pub fn with_chat_id(mut self, value: impl Into<ChatId>) -> Self {
+        self.chat_id = value.into();
+        self
+    }
+}
+impl Validate for GetMapThumbnailFile {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.location
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "location"))?;
+        validate::check(
+            Self::TYPE,
+            "zoom",
+            Measure::Value,
+            i64::from(self.zoom),
+            Some(13),
+            Some(20),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "width",
+            Measure::Value,
+            i64::from(self.width),
+            Some(16),
+            Some(1024),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "height",
+            Measure::Value,
+            i64::from(self.height),
+            Some(16),
+            Some(1024),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "scale",
+            Measure::Value,
+            i64::from(self.scale),
+            Some(1),
+            Some(3),
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,235 @@
+//! The methods of the `logging` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sets new log stream for internal logging of TDLib. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct SetLogStream {
+    #[doc = "New log stream"]
+    pub log_stream: LogStream,
+}
+impl Method for SetLogStream {
+    const TYPE: &'static str = "setLogStream";
+    type Response = Ok;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for SetLogStream {}
+impl SetLogStream {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(log_stream: impl Into<LogStream>) -> Self {
+        Self {
+            log_stream: log_stream.into(),
+        }
+    }
+}
+impl Validate for SetLogStream {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.log_stream
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "log_stream"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns information about currently used log stream for internal logging of TDLib. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct GetLogStream {}
+impl Method for GetLogStream {
+    const TYPE: &'static str = "getLogStream";
+    type Response = LogStream;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for GetLogStream {}
+impl GetLogStream {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetLogStream {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Sets the verbosity level of the internal logging of TDLib. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct SetLogVerbosityLevel {
+    #[doc = "New value of the verbosity level for logging. Value 0 corresponds to fatal errors, value 1 corresponds to errors, value 2 corresponds to warnings and debug warnings, value 3 corresponds to informational, value 4 corresponds to debug, value 5 corresponds to verbose debug, value greater than 5 and up to 1023 can be used to enable even more logging"]
+    pub new_verbosity_level: i32,
+}
+impl Method for SetLogVerbosityLevel {
+    const TYPE: &'static str = "setLogVerbosityLevel";
+    type Response = Ok;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for SetLogVerbosityLevel {}
+impl SetLogVerbosityLevel {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "New value of the verbosity level for logging. Value 0 corresponds to fatal errors, value 1 corresponds to errors, value 2 corresponds to warnings and debug warnings, value 3 corresponds to informational, value 4 corresponds to debug, value 5 corresponds to verbose debug, value greater than 5 and up to 1023 can be used to enable even more logging"]
+    pub fn with_new_verbosity_level(mut self, value: impl Into<i32>) -> Self {
+        self.new_verbosity_level = value.into();
+        self
+    }
+}
+impl Validate for SetLogVerbosityLevel {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns current verbosity level of the internal logging of TDLib. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct GetLogVerbosityLevel {}
+impl Method for GetLogVerbosityLevel {
+    const TYPE: &'static str = "getLogVerbosityLevel";
+    type Response = LogVerbosityLevel;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for GetLogVerbosityLevel {}
+impl GetLogVerbosityLevel {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetLogVerbosityLevel {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns list of available TDLib internal log tags, for example, [\"actor\", \"binlog\", \"connections\", \"notifications\", \"proxy\"]. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct GetLogTags {}
+impl Method for GetLogTags {
+    const TYPE: &'static str = "getLogTags";
+    type Response = LogTags;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for GetLogTags {}
+impl GetLogTags {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetLogTags {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sets the verbosity level for a specified TDLib internal log tag. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct SetLogTagVerbosityLevel {
+    #[doc = "Logging tag to change verbosity level"]
+    pub tag: String,
+    #[doc = "New verbosity level; 1-1024"]
+    pub new_verbosity_level: i32,
+}
+impl Method for SetLogTagVerbosityLevel {
+    const TYPE: &'static str = "setLogTagVerbosityLevel";
+    type Response = Ok;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for SetLogTagVerbosityLevel {}
+impl SetLogTagVerbosityLevel {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(new_verbosity_level: impl Into<i32>) -> Self {
+        Self {
+            tag: Default::default(),
+            new_verbosity_level: new_verbosity_level.into(),
+        }
+    }
+    #[doc = "Logging tag to change verbosity level"]
+    pub fn with_tag(mut self, value: impl Into<String>) -> Self {
+        self.tag = value.into();
+        self
+    }
+}
+impl Validate for SetLogTagVerbosityLevel {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "new_verbosity_level",
+            Measure::Value,
+            i64::from(self.new_verbosity_level),
+            Some(1),
+            Some(1024),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns current verbosity level for a specified TDLib internal log tag. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct GetLogTagVerbosityLevel {
+    #[doc = "Logging tag to change verbosity level"]
+    pub tag: String,
+}
+impl Method for GetLogTagVerbosityLevel {
+    const TYPE: &'static str = "getLogTagVerbosityLevel";
+    type Response = LogVerbosityLevel;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for GetLogTagVerbosityLevel {}
+impl GetLogTagVerbosityLevel {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Logging tag to change verbosity level"]
+    pub fn with_tag(mut self, value: impl Into<String>) -> Self {
+        self.tag = value.into();
+        self
+    }
+}
+impl Validate for GetLogTagVerbosityLevel {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Adds a message to TDLib internal log. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct AddLogMessage {
+    #[doc = "The minimum verbosity level needed for the message to be logged, 0-1023"]
+    pub verbosity_level: i32,
+    #[doc = "Text of a message to log"]
+    pub text: String,
+}
+impl Method for AddLogMessage {
+    const TYPE: &'static str = "addLogMessage";
+    type Response = Ok;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for AddLogMessage {}
+impl AddLogMessage {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The minimum verbosity level needed for the message to be logged, 0-1023"]
+    pub fn with_verbosity_level(mut self, value: impl Into<i32>) -> Self {
+        self.verbosity_level = value.into();
+        self
+    }
+    #[doc = "Text of a message to log"]
+    pub fn with_text(mut self, value: impl Into<String>) -> Self {
+        self.text = value.into();
+        self
+    }
+}
+impl Validate for AddLogMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "verbosity_level",
+            Measure::Value,
+            i64::from(self.verbosity_level),
+            Some(0),
+            Some(1023),
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,420 @@
+//! The methods of the `passport` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns one of the available Telegram Passport elements"]
+pub struct GetPassportElement {
+    #[serde(rename = "type")]
+    #[doc = "Telegram Passport element type"]
+    pub type_: PassportElementType,
+    #[doc = "Password of the current user"]
+    pub password: String,
+}
+impl Method for GetPassportElement {
+    const TYPE: &'static str = "getPassportElement";
+    type Response = PassportElement;
+}
+impl GetPassportElement {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(type_: impl Into<PassportElementType>) -> Self {
+        Self {
+            type_: type_.into(),
+            password: Default::default(),
+        }
+    }
+    #[doc = "Password of the current user"]
+    pub fn with_password(mut self, value: impl Into<String>) -> Self {
+        self.password = value.into();
+        self
+    }
+}
+impl Validate for GetPassportElement {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.type_
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "type"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns all available Telegram Passport elements"]
+pub struct GetAllPassportElements {
+    #[doc = "Password of the current user"]
+    pub password: String,
+}
+impl Method for GetAllPassportElements {
+    const TYPE: &'static str = "getAllPassportElements";
+    type Response = PassportElements;
+}
+impl GetAllPassportElements {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Password of the current user"]
+    pub fn with_password(mut self, value: impl Into<String>) -> Self {
+        self.password = value.into();
+        self
+    }
+}
+impl Validate for GetAllPassportElements {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Adds an element to the user's Telegram Passport. May return an error with a message \"PHONE_VERIFICATION_NEEDED\" or \"EMAIL_VERIFICATION_NEEDED\" if the chosen phone number or the chosen email address must be verified first"]
+pub struct SetPassportElement {
+    #[doc = "Input Telegram Passport element"]
+    pub element: InputPassportElement,
+    #[doc = "Password of the current user"]
+    pub password: String,
+}
+impl Method for SetPassportElement {
+    const TYPE: &'static str = "setPassportElement";
+    type Response = PassportElement;
+}
+impl SetPassportElement {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(element: impl Into<InputPassportElement>) -> Self {
+        Self {
+            element: element.into(),
+            password: Default::default(),
+        }
+    }
+    #[doc = "Password of the current user"]
+    pub fn with_password(mut self, value: impl Into<String>) -> Self {
+        self.password = value.into();
+        self
+    }
+}
+impl Validate for SetPassportElement {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.element
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "element"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Deletes a Telegram Passport element"]
+pub struct DeletePassportElement {
+    #[serde(rename = "type")]
+    #[doc = "Element type"]
+    pub type_: PassportElementType,
+}
+impl Method for DeletePassportElement {
+    const TYPE: &'static str = "deletePassportElement";
+    type Response = Ok;
+}
+impl DeletePassportElement {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(type_: impl Into<PassportElementType>) -> Self {
+        Self {
+            type_: type_.into(),
+        }
+    }
+}
+impl Validate for DeletePassportElement {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.type_
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "type"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Informs the user that some of the elements in their Telegram Passport contain errors; for bots only. The user will not be able to resend the elements, until the errors are fixed"]
+pub struct SetPassportElementErrors {
+    #[doc = "User identifier"]
+    pub user_id: UserId,
+    #[doc = "The errors"]
+    pub errors: Vec<InputPassportElementError>,
+}
+impl Method for SetPassportElementErrors {
+    const TYPE: &'static str = "setPassportElementErrors";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl SetPassportElementErrors {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(user_id: impl Into<UserId>) -> Self {
+        Self {
+            user_id: user_id.into(),
+            errors: Default::default(),
+        }
+    }
+    #[doc = "The errors"]
+    pub fn with_errors(mut self, value: impl Into<Vec<InputPassportElementError>>) -> Self {
+        self.errors = value.into();
+        self
+    }
+}
+impl Validate for SetPassportElementErrors {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, value) in self.errors.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("errors[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns an IETF language tag of the language preferred in the country, which should be used to fill native fields in Telegram Passport personal details. Returns a 404 error if unknown"]
+pub struct GetPreferredCountryLanguage {
+    #[doc = "A two-letter ISO 3166-1 alpha-2 country code"]
+    pub country_code: String,
+}
+impl Method for GetPreferredCountryLanguage {
+    const TYPE: &'static str = "getPreferredCountryLanguage";
+    type Response = Text;
+}
+impl GetPreferredCountryLanguage {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "A two-letter ISO 3166-1 alpha-2 country code"]
+    pub fn with_country_code(mut self, value: impl Into<String>) -> Self {
+        self.country_code = value.into();
+        self
+    }
+}
+impl Validate for GetPreferredCountryLanguage {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Sends a code to verify a phone number to be added to a user's Telegram Passport"]
+pub struct SendPhoneNumberVerificationCode {
+    #[doc = "The phone number of the user, in international format"]
+    pub phone_number: String,
+    #[doc = "Settings for the authentication of the user's phone number"]
+    pub settings: PhoneNumberAuthenticationSettings,
+}
+impl Method for SendPhoneNumberVerificationCode {
+    const TYPE: &'static str = "sendPhoneNumberVerificationCode";
+    type Response = AuthenticationCodeInfo;
+}
+impl SendPhoneNumberVerificationCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The phone number of the user, in international format"]
+    pub fn with_phone_number(mut self, value: impl Into<String>) -> Self {
+        self.phone_number = value.into();
+        self
+    }
+    #[doc = "Settings for the authentication of the user's phone number"]
+    pub fn with_settings(mut self, value: impl Into<PhoneNumberAuthenticationSettings>) -> Self {
+        self.settings = value.into();
+        self
+    }
+}
+impl Validate for SendPhoneNumberVerificationCode {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.settings
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "settings"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Re-sends the code to verify a phone number to be added to a user's Telegram Passport"]
+pub struct ResendPhoneNumberVerificationCode {}
+impl Method for ResendPhoneNumberVerificationCode {
+    const TYPE: &'static str = "resendPhoneNumberVerificationCode";
+    type Response = AuthenticationCodeInfo;
+}
+impl ResendPhoneNumberVerificationCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for ResendPhoneNumberVerificationCode {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Checks the phone number verification code for Telegram Passport"]
+pub struct CheckPhoneNumberVerificationCode {
+    #[doc = "Verification code"]
+    pub code: String,
+}
+impl Method for CheckPhoneNumberVerificationCode {
+    const TYPE: &'static str = "checkPhoneNumberVerificationCode";
+    type Response = Ok;
+}
+impl CheckPhoneNumberVerificationCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Verification code"]
+    pub fn with_code(mut self, value: impl Into<String>) -> Self {
+        self.code = value.into();
+        self
+    }
+}
+impl Validate for CheckPhoneNumberVerificationCode {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Sends a code to verify an email address to be added to a user's Telegram Passport"]
+pub struct SendEmailAddressVerificationCode {
+    #[doc = "Email address"]
+    pub email_address: String,
+}
+impl Method for SendEmailAddressVerificationCode {
+    const TYPE: &'static str = "sendEmailAddressVerificationCode";
+    type Response = EmailAddressAuthenticationCodeInfo;
+}
+impl SendEmailAddressVerificationCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Email address"]
+    pub fn with_email_address(mut self, value: impl Into<String>) -> Self {
+        self.email_address = value.into();
+        self
+    }
+}
+impl Validate for SendEmailAddressVerificationCode {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Re-sends the code to verify an email address to be added to a user's Telegram Passport"]
+pub struct ResendEmailAddressVerificationCode {}
+impl Method for ResendEmailAddressVerificationCode {
+    const TYPE: &'static str = "resendEmailAddressVerificationCode";
+    type Response = EmailAddressAuthenticationCodeInfo;
+}
+impl ResendEmailAddressVerificationCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for ResendEmailAddressVerificationCode {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Checks the email address verification code for Telegram Passport"]
+pub struct CheckEmailAddressVerificationCode {
+    #[doc = "Verification code"]
+    pub code: String,
+}
+impl Method for CheckEmailAddressVerificationCode {
+    const TYPE: &'static str = "checkEmailAddressVerificationCode";
+    type Response = Ok;
+}
+impl CheckEmailAddressVerificationCode {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Verification code"]
+    pub fn with_code(mut self, value: impl Into<String>) -> Self {
+        self.code = value.into();
+        self
+    }
+}
+impl Validate for CheckEmailAddressVerificationCode {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns a Telegram Passport authorization form for sharing data with a service"]
+pub struct GetPassportAuthorizationForm {
+    #[doc = "User identifier of the service's bot"]
+    pub bot_user_id: UserId,
+    #[doc = "Telegram Passport element types requested by the service"]
+    pub scope: String,
+    #[doc = "Service's public_key"]
+    pub public_key: String,
+    #[doc = "Authorization form nonce provided by the service"]
+    pub nonce: String,
+}
+impl Method for GetPassportAuthorizationForm {
+    const TYPE: &'static str = "getPassportAuthorizationForm";
+    type Response = PassportAuthorizationForm;
+}
+impl GetPassportAuthorizationForm {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(bot_user_id: impl Into<UserId>) -> Self {
+        Self {
+            bot_user_id: bot_user_id.into(),
+            scope: Default::default(),
+            public_key: Default::default(),
+            nonce: Default::default(),
+        }
+    }
+    #[doc = "Telegram Passport element types requested by the service"]
+    pub fn with_scope(mut self, value: impl Into<String>) -> Self {
+        self.scope = value.into();
+        self
+    }
+    #[doc = "Service's public_key"]
+    pub fn with_public_key(mut self, value: impl Into<String>) -> Self {
+        self.public_key = value.into();
+        self
+    }
+    #[doc = "Authorization form nonce provided by the service"]
+    pub fn with_nonce(mut self, value: impl Into<String>) -> Self {
+        self.nonce = value.into();
+        self
+    }
+}
+impl Validate for GetPassportAuthorizationForm {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns already available Telegram Passport elements suitable for completing a Telegram Passport authorization form. Result can be received only once for each authorization form"]
+pub struct GetPassportAuthorizationFormAvailableElements {
+    #[doc = "Authorization form identifier"]
+    pub autorization_form_id: i32,
+    #[doc = "Password of the current user"]
+    pub password: String,
+}
+impl Method for GetPassportAuthorizationFormAvailableElements {
+    const TYPE: &'static str = "getPassportAuthorizationFormAvailableElements";
+    type Response = PassportElementsWithErrors;
+}
+impl GetPassportAuthorizationFormAvailableElements {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Authorization form identifier"]
+    pub fn with_autorization_form_id(mut self, value: impl Into<i32>) -> Self {
+        self.autorization_form_id = value.into();
+        self
+    }
+    #[doc = "Password of the current user"]
+    pub fn with_password(mut self, value: impl Into<String>) -> Self {
+        self.password = value.into();
+        self
+    }
+}
+impl Validate for GetPassportAuthorizationFormAvailableElements {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Sends a Telegram Passport authorization form, effectively sharing data with the service. This method must be called after getPassportAuthorizationFormAvailableElements if some previously available elements need to be used"]
+pub struct SendPassportAuthorizationForm {
+    #[doc = "Authorization form identifier"]
+    pub autorization_form_id: i32,
+    #[doc = "Types of Telegram Passport elements chosen by user to complete the authorization form"]
+    pub types: Vec<PassportElementType>,
+}
+impl Method for SendPassportAuthorizationForm {
+    const TYPE: &'static str = "sendPassportAuthorizationForm";
+    type Response = Ok;
+}
+impl SendPassportAuthorizationForm {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Authorization form identifier"]
+    pub fn with_autorization_form_id(mut self, value: impl Into<i32>) -> Self {
+        self.autorization_form_id = value.into();
+        self
+    }
+    #[doc = "Types of Telegram Passport elements chosen by user to complete the authorization form"]
+    pub fn with_types(mut self, value: impl Into<Vec<PassportElementType>>) -> Self {
+        self.types = value.into();
+        self
+    }
+}
+impl Validate for SendPassportAuthorizationForm {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, value) in self.types.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("types[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,270 @@
+//! The methods of the `payments` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Sets the result of a shipping query; for bots only"]
+pub struct AnswerShippingQuery {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of the shipping query"]
+    pub shipping_query_id: i64,
+    #[doc = "Available shipping options"]
+    pub shipping_options: Vec<ShippingOption>,
+    #[doc = "An error message, empty on success"]
+    pub error_message: String,
+}
+impl Method for AnswerShippingQuery {
+    const TYPE: &'static str = "answerShippingQuery";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl AnswerShippingQuery {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Identifier of the shipping query"]
+    pub fn with_shipping_query_id(mut self, value: impl Into<i64>) -> Self {
+        self.shipping_query_id = value.into();
+        self
+    }
+    #[doc = "Available shipping options"]
+    pub fn with_shipping_options(mut self, value: impl Into<Vec<ShippingOption>>) -> Self {
+        self.shipping_options = value.into();
+        self
+    }
+    #[doc = "An error message, empty on success"]
+    pub fn with_error_message(mut self, value: impl Into<String>) -> Self {
+        self.error_message = value.into();
+        self
+    }
+}
+impl Validate for AnswerShippingQuery {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, value) in self.shipping_options.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("shipping_options[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Sets the result of a pre-checkout query; for bots only"]
+pub struct AnswerPreCheckoutQuery {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of the pre-checkout query"]
+    pub pre_checkout_query_id: i64,
+    #[doc = "An error message, empty on success"]
+    pub error_message: String,
+}
+impl Method for AnswerPreCheckoutQuery {
+    const TYPE: &'static str = "answerPreCheckoutQuery";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl AnswerPreCheckoutQuery {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Identifier of the pre-checkout query"]
+    pub fn with_pre_checkout_query_id(mut self, value: impl Into<i64>) -> Self {
+        self.pre_checkout_query_id = value.into();
+        self
+    }
+    #[doc = "An error message, empty on success"]
+    pub fn with_error_message(mut self, value: impl Into<String>) -> Self {
+        self.error_message = value.into();
+        self
+    }
+}
+impl Validate for AnswerPreCheckoutQuery {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns an invoice payment form. This method should be called when the user presses inlineKeyboardButtonBuy"]
+pub struct GetPaymentForm {
+    #[doc = "Chat identifier of the Invoice message"]
+    pub chat_id: ChatId,
+    #[doc = "Message identifier"]
+    pub message_id: MessageId,
+}
+impl Method for GetPaymentForm {
+    const TYPE: &'static str = "getPaymentForm";
+    type Response = PaymentForm;
+}
+impl GetPaymentForm {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+        }
+    }
+}
+impl Validate for GetPaymentForm {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Validates the order information provided by a user and returns the available shipping options for a flexible invoice"]
+pub struct ValidateOrderInfo {
+    #[doc = "Chat identifier of the Invoice message"]
+    pub chat_id: ChatId,
+    #[doc = "Message identifier"]
+    pub message_id: MessageId,
+    #[doc = "The order information, provided by the user"]
+    pub order_info: OrderInfo,
+    #[doc = "True, if the order information can be saved"]
+    pub allow_save: bool,
+}
+impl Method for ValidateOrderInfo {
+    const TYPE: &'static str = "validateOrderInfo";
+    type Response = ValidatedOrderInfo;
+}
+impl ValidateOrderInfo {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            order_info: Default::default(),
+            allow_save: Default::default(),
+        }
+    }
+    #[doc = "The order information, provided by the user"]
+    pub fn with_order_info(mut self, value: impl Into<OrderInfo>) -> Self {
+        self.order_info = value.into();
+        self
+    }
+    #[doc = "True, if the order information can be saved"]
+    pub fn with_allow_save(mut self, value: impl Into<bool>) -> Self {
+        self.allow_save = value.into();
+        self
+    }
+}
+impl Validate for ValidateOrderInfo {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.order_info
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "order_info"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sends a filled-out payment form to the bot for final verification"]
+pub struct SendPaymentForm {
+    #[doc = "Chat identifier of the Invoice message"]
+    pub chat_id: ChatId,
+    #[doc = "Message identifier"]
+    pub message_id: MessageId,
+    #[doc = "Identifier returned by ValidateOrderInfo, or an empty string"]
+    pub order_info_id: String,
+    #[doc = "Identifier of a chosen shipping option, if applicable"]
+    pub shipping_option_id: String,
+    #[doc = "The credentials chosen by user for payment"]
+    pub credentials: InputCredentials,
+}
+impl Method for SendPaymentForm {
+    const TYPE: &'static str = "sendPaymentForm";
+    type Response = PaymentResult;
+}
+impl SendPaymentForm {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        chat_id: impl Into<ChatId>,
+        message_id: impl Into<MessageId>,
+        credentials: impl Into<InputCredentials>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            order_info_id: Default::default(),
+            shipping_option_id: Default::default(),
+            credentials: credentials.into(),
+        }
+    }
+    #[doc = "Identifier returned by ValidateOrderInfo, or an empty string"]
+    pub fn with_order_info_id(mut self, value: impl Into<String>) -> Self {
+        self.order_info_id = value.into();
+        self
+    }
+    #[doc = "Identifier of a chosen shipping option, if applicable"]
+    pub fn with_shipping_option_id(mut self, value: impl Into<String>) -> Self {
+        self.shipping_option_id = value.into();
+        self
+    }
+}
+impl Validate for SendPaymentForm {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.credentials
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "credentials"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a successful payment"]
+pub struct GetPaymentReceipt {
+    #[doc = "Chat identifier of the PaymentSuccessful message"]
+    pub chat_id: ChatId,
+    #[doc = "Message identifier"]
+    pub message_id: MessageId,
+}
+impl Method for GetPaymentReceipt {
+    const TYPE: &'static str = "getPaymentReceipt";
+    type Response = PaymentReceipt;
+}
+impl GetPaymentReceipt {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+        }
+    }
+}
+impl Validate for GetPaymentReceipt {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns saved order info, if any"]
+pub struct GetSavedOrderInfo {}
+impl Method for GetSavedOrderInfo {
+    const TYPE: &'static str = "getSavedOrderInfo";
+    type Response = OrderInfo;
+}
+impl GetSavedOrderInfo {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetSavedOrderInfo {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Deletes saved order info"]
+pub struct DeleteSavedOrderInfo {}
+impl Method for DeleteSavedOrderInfo {
+    const TYPE: &'static str = "deleteSavedOrderInfo";
+    type Response = Ok;
+}
+impl DeleteSavedOrderInfo {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for DeleteSavedOrderInfo {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Deletes saved credentials for all payment provider bots"]
+pub struct DeleteSavedCredentials {}
+impl Method for DeleteSavedCredentials {
+    const TYPE: &'static str = "deleteSavedCredentials";
+    type Response = Ok;
+}
+impl DeleteSavedCredentials {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for DeleteSavedCredentials {}
@@ -0,0 +1,306 @@
+//! The methods of the `proxy` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Adds a proxy server for network requests. Can be called before authorization"]
+pub struct AddProxy {
+    #[doc = "Proxy server IP address"]
+    pub server: String,
+    #[doc = "Proxy server port"]
+    pub port: i32,
+    #[doc = "True, if the proxy should be enabled"]
+    pub enable: bool,
+    #[serde(rename = "type")]
+    #[doc = "Proxy type"]
+    pub type_: ProxyType,
+}
+impl Method for AddProxy {
+    const TYPE: &'static str = "addProxy";
+    type Response = Proxy;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl AddProxy {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(type_: impl Into<ProxyType>) -> Self {
+        Self {
+            server: Default::default(),
+            port: Default::default(),
+            enable: Default::default(),
+            type_: type_.into(),
+        }
+    }
+    #[doc = "Proxy server IP address"]
+    pub fn with_server(mut self, value: impl Into<String>) -> Self {
+        self.server = value.into();
+        self
+    }
+    #[doc = "Proxy server port"]
+    pub fn with_port(mut self, value: impl Into<i32>) -> Self {
+        self.port = value.into();
+        self
+    }
+    #[doc = "True, if the proxy should be enabled"]
+    pub fn with_enable(mut self, value: impl Into<bool>) -> Self {
+        self.enable = value.into();
+        self
+    }
+}
+impl Validate for AddProxy {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.type_
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "type"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Edits an existing proxy server for network requests. Can be called before authorization"]
+pub struct EditProxy {
+    #[doc = "Proxy identifier"]
+    pub proxy_id: i32,
+    #[doc = "Proxy server IP address"]
+    pub server: String,
+    #[doc = "Proxy server port"]
+    pub port: i32,
+    #[doc = "True, if the proxy should be enabled"]
+    pub enable: bool,
+    #[serde(rename = "type")]
+    #[doc = "Proxy type"]
+    pub type_: ProxyType,
+}
+impl Method for EditProxy {
+    const TYPE: &'static str = "editProxy";
+    type Response = Proxy;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl EditProxy {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(type_: impl Into<ProxyType>) -> Self {
+        Self {
+            proxy_id: Default::default(),
+            server: Default::default(),
+            port: Default::default(),
+            enable: Default::default(),
+            type_: type_.into(),
+        }
+    }
+    #[doc = "Proxy identifier"]
+    pub fn with_proxy_id(mut self, value: impl Into<i32>) -> Self {
+        self.proxy_id = value.into();
+        self
+    }
+    #[doc = "Proxy server IP address"]
+    pub fn with_server(mut self, value: impl Into<String>) -> Self {
+        self.server = value.into();
+        self
+    }
+    #[doc = "Proxy server port"]
+    pub fn with_port(mut self, value: impl Into<i32>) -> Self {
+        self.port = value.into();
+        self
+    }
+    #[doc = "True, if the proxy should be enabled"]
+    pub fn with_enable(mut self, value: impl Into<bool>) -> Self {
+        self.enable = value.into();
+        self
+    }
+}
+impl Validate for EditProxy {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.type_
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "type"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Enables a proxy. Only one proxy can be enabled at a time. Can be called before authorization"]
+pub struct EnableProxy {
+    #[doc = "Proxy identifier"]
+    pub proxy_id: i32,
+}
+impl Method for EnableProxy {
+    const TYPE: &'static str = "enableProxy";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl EnableProxy {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Proxy identifier"]
+    pub fn with_proxy_id(mut self, value: impl Into<i32>) -> Self {
+        self.proxy_id = value.into();
+        self
+    }
+}
+impl Validate for EnableProxy {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Disables the currently enabled proxy. Can be called before authorization"]
+pub struct DisableProxy {}
+impl Method for DisableProxy {
+    const TYPE: &'static str = "disableProxy";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl DisableProxy {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for DisableProxy {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Removes a proxy server. Can be called before authorization"]
+pub struct RemoveProxy {
+    #[doc = "Proxy identifier"]
+    pub proxy_id: i32,
+}
+impl Method for RemoveProxy {
+    const TYPE: &'static str = "removeProxy";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl RemoveProxy {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Proxy identifier"]
+    pub fn with_proxy_id(mut self, value: impl Into<i32>) -> Self {
+        self.proxy_id = value.into();
+        self
+    }
+}
+impl Validate for RemoveProxy {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns list of proxies that are currently set up. Can be called before authorization"]
+pub struct GetProxies {}
+impl Method for GetProxies {
+    const TYPE: &'static str = "getProxies";
+    type Response = Proxies;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl GetProxies {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetProxies {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns an HTTPS link, which can be used to add a proxy. Available only for SOCKS5 and MTProto proxies. Can be called before authorization"]
+pub struct GetProxyLink {
+    #[doc = "Proxy identifier"]
+    pub proxy_id: i32,
+}
+impl Method for GetProxyLink {
+    const TYPE: &'static str = "getProxyLink";
+    type Response = Text;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl GetProxyLink {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Proxy identifier"]
+    pub fn with_proxy_id(mut self, value: impl Into<i32>) -> Self {
+        self.proxy_id = value.into();
+        self
+    }
+}
+impl Validate for GetProxyLink {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Computes time needed to receive a response from a Telegram server through a proxy. Can be called before authorization"]
+pub struct PingProxy {
+    #[doc = "Proxy identifier. Use 0 to ping a Telegram server without a proxy"]
+    pub proxy_id: i32,
+}
+impl Method for PingProxy {
+    const TYPE: &'static str = "pingProxy";
+    type Response = Seconds;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl PingProxy {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Proxy identifier. Use 0 to ping a Telegram server without a proxy"]
+    pub fn with_proxy_id(mut self, value: impl Into<i32>) -> Self {
+        self.proxy_id = value.into();
+        self
+    }
+}
+impl Validate for PingProxy {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sends a simple network request to the Telegram servers via proxy; for testing only. Can be called before authorization"]
+pub struct TestProxy {
+    #[doc = "Proxy server IP address"]
+    pub server: String,
+    #[doc = "Proxy server port"]
+    pub port: i32,
+    #[serde(rename = "type")]
+    #[doc = "Proxy type"]
+    pub type_: ProxyType,
+    #[doc = "Identifier of a datacenter, with which to test connection"]
+    pub dc_id: i32,
+    #[doc = "The maximum overall timeout for the request"]
+    pub timeout: f64,
+}
+impl Method for TestProxy {
+    const TYPE: &'static str = "testProxy";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl TestProxy {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(type_: impl Into<ProxyType>) -> Self {
+        Self {
+            server: Default::default(),
+            port: Default::default(),
+            type_: type_.into(),
+            dc_id: Default::default(),
+            timeout: Default::default(),
+        }
+    }
+    #[doc = "Proxy server IP address"]
+    pub fn with_server(mut self, value: impl Into<String>) -> Self {
+        self.server = value.into();
+        self
+    }
+    #[doc = "Proxy server port"]
+    pub fn with_port(mut self, value: impl Into<i32>) -> Self {
+        self.port = value.into();
+        self
+    }
+    #[doc = "Identifier of a datacenter, with which to test connection"]
+    pub fn with_dc_id(mut self, value: impl Into<i32>) -> Self {
+        self.dc_id = value.into();
+        self
+    }
+    #[doc = "The maximum overall timeout for the request"]
+    pub fn with_timeout(mut self, value: impl Into<f64>) -> Self {
+        self.timeout = value.into();
+        self
+    }
+}
+impl Validate for TestProxy {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.type_
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "type"))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,931 @@
+//! The methods of the `stickers` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns stickers from the installed sticker sets that correspond to a given emoji. If the emoji is not empty, favorite and recently used stickers may also be returned"]
+pub struct GetStickers {
+    #[doc = "String representation of emoji. If empty, returns all known installed stickers"]
+    pub emoji: String,
+    #[doc = "The maximum number of stickers to be returned"]
+    pub limit: i32,
+}
+impl Method for GetStickers {
+    const TYPE: &'static str = "getStickers";
+    type Response = Stickers;
+}
+impl GetStickers {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "String representation of emoji. If empty, returns all known installed stickers"]
+    pub fn with_emoji(mut self, value: impl Into<String>) -> Self {
+        self.emoji = value.into();
+        self
+    }
+    #[doc = "The maximum number of stickers to be returned"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for GetStickers {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Searches for stickers from public sticker sets that correspond to a given emoji"]
+pub struct SearchStickers {
+    #[doc = "String representation of emoji; must be non-empty"]
+    pub emoji: String,
+    #[doc = "The maximum number of stickers to be returned"]
+    pub limit: i32,
+}
+impl Method for SearchStickers {
+    const TYPE: &'static str = "searchStickers";
+    type Response = Stickers;
+}
+impl SearchStickers {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(emoji: impl Into<String>) -> Self {
+        Self {
+            emoji: emoji.into(),
+            limit: Default::default(),
+        }
+    }
+    #[doc = "The maximum number of stickers to be returned"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for SearchStickers {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "emoji",
+            Measure::Characters,
+            self.emoji.chars().count() as i64,
+            Some(1),
+            None,
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns a list of installed sticker sets"]
+pub struct GetInstalledStickerSets {
+    #[doc = "Pass true to return mask sticker sets; pass false to return ordinary sticker sets"]
+    pub is_masks: bool,
+}
+impl Method for GetInstalledStickerSets {
+    const TYPE: &'static str = "getInstalledStickerSets";
+    type Response = StickerSets;
+}
+impl GetInstalledStickerSets {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Pass true to return mask sticker sets; pass false to return ordinary sticker sets"]
+    pub fn with_is_masks(mut self, value: impl Into<bool>) -> Self {
+        self.is_masks = value.into();
+        self
+    }
+}
+impl Validate for GetInstalledStickerSets {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns a list of archived sticker sets"]
+pub struct GetArchivedStickerSets {
+    #[doc = "Pass true to return mask stickers sets; pass false to return ordinary sticker sets"]
+    pub is_masks: bool,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of the sticker set from which to return the result"]
+    pub offset_sticker_set_id: i64,
+    #[doc = "The maximum number of sticker sets to return"]
+    pub limit: i32,
+}
+impl Method for GetArchivedStickerSets {
+    const TYPE: &'static str = "getArchivedStickerSets";
+    type Response = StickerSets;
+}
+impl GetArchivedStickerSets {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Pass true to return mask stickers sets; pass false to return ordinary sticker sets"]
+    pub fn with_is_masks(mut self, value: impl Into<bool>) -> Self {
+        self.is_masks = value.into();
+        self
+    }
+    #[doc = "Identifier of the sticker set from which to return the result"]
+    pub fn with_offset_sticker_set_id(mut self, value: impl Into<i64>) -> Self {
+        self.offset_sticker_set_id = value.into();
+        self
+    }
+    #[doc = "The maximum number of sticker sets to return"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for GetArchivedStickerSets {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns a list of trending sticker sets"]
+pub struct GetTrendingStickerSets {}
+impl Method for GetTrendingStickerSets {
+    const TYPE: &'static str = "getTrendingStickerSets";
+    type Response = StickerSets;
+}
+impl GetTrendingStickerSets {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetTrendingStickerSets {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns a list of sticker sets attached to a file. Currently only photos and videos can have attached sticker sets"]
+pub struct GetAttachedStickerSets {
+    #[doc = "File identifier"]
+    pub file_id: FileId,
+}
+impl Method for GetAttachedStickerSets {
+    const TYPE: &'static str = "getAttachedStickerSets";
+    type Response = StickerSets;
+}
+impl GetAttachedStickerSets {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(file_id: impl Into<FileId>) -> Self {
+        Self {
+            file_id: file_id.into(),
+        }
+    }
+}
+impl Validate for GetAttachedStickerSets {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns information about a sticker set by its identifier"]
+pub struct GetStickerSet {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of the sticker set"]
+    pub set_id: i64,
+}
+impl Method for GetStickerSet {
+    const TYPE: &'static str = "getStickerSet";
+    type Response = StickerSet;
+}
+impl GetStickerSet {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Identifier of the sticker set"]
+    pub fn with_set_id(mut self, value: impl Into<i64>) -> Self {
+        self.set_id = value.into();
+        self
+    }
+}
+impl Validate for GetStickerSet {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Searches for a sticker set by its name"]
+pub struct SearchStickerSet {
+    #[doc = "Name of the sticker set"]
+    pub name: String,
+}
+impl Method for SearchStickerSet {
+    const TYPE: &'static str = "searchStickerSet";
+    type Response = StickerSet;
+}
+impl SearchStickerSet {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Name of the sticker set"]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+}
+impl Validate for SearchStickerSet {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Searches for installed sticker sets by looking for specified query in their title and name"]
+pub struct SearchInstalledStickerSets {
+    #[doc = "Pass true to return mask sticker sets; pass false to return ordinary sticker sets"]
+    pub is_masks: bool,
+    #[doc = "Query to search for"]
+    pub query: String,
+    #[doc = "The maximum number of sticker sets to return"]
+    pub limit: i32,
+}
+impl Method for SearchInstalledStickerSets {
+    const TYPE: &'static str = "searchInstalledStickerSets";
+    type Response = StickerSets;
+}
+impl SearchInstalledStickerSets {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Pass true to return mask sticker sets; pass false to return ordinary sticker sets"]
+    pub fn with_is_masks(mut self, value: impl Into<bool>) -> Self {
+        self.is_masks = value.into();
+        self
+    }
+    #[doc = "Query to search for"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+    #[doc = "The maximum number of sticker sets to return"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for SearchInstalledStickerSets {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Searches for ordinary sticker sets by looking for specified query in their title and name. Excludes installed sticker sets from the results"]
+pub struct SearchStickerSets {
+    #[doc = "Query to search for"]
+    pub query: String,
+}
+impl Method for SearchStickerSets {
+    const TYPE: &'static str = "searchStickerSets";
+    type Response = StickerSets;
+}
+impl SearchStickerSets {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Query to search for"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+}
+impl Validate for SearchStickerSets {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Installs/uninstalls or activates/archives a sticker set"]
+pub struct ChangeStickerSet {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of the sticker set"]
+    pub set_id: i64,
+    #[doc = "The new value of is_installed"]
+    pub is_installed: bool,
+    #[doc = "The new value of is_archived. A sticker set can't be installed and archived simultaneously"]
+    pub is_archived: bool,
+}
+impl Method for ChangeStickerSet {
+    const TYPE: &'static str = "changeStickerSet";
+    type Response = Ok;
+}
+impl ChangeStickerSet {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Identifier of the sticker set"]
+    pub fn with_set_id(mut self, value: impl Into<i64>) -> Self {
+        self.set_id = value.into();
+        self
+    }
+    #[doc = "The new value of is_installed"]
+    pub fn with_is_installed(mut self, value: impl Into<bool>) -> Self {
+        self.is_installed = value.into();
+        self
+    }
+    #[doc = "The new value of is_archived. A sticker set can't be installed and archived simultaneously"]
+    pub fn with_is_archived(mut self, value: impl Into<bool>) -> Self {
+        self.is_archived = value.into();
+        self
+    }
+}
+impl Validate for ChangeStickerSet {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Informs the server that some trending sticker sets have been viewed by the user"]
+pub struct ViewTrendingStickerSets {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_vec_t",
+        serialize_with = "crate::utils::vec_t_to_str"
+    )]
+    #[doc = "Identifiers of viewed trending sticker sets"]
+    pub sticker_set_ids: Vec<i64>,
+}
+impl Method for ViewTrendingStickerSets {
+    const TYPE: &'static str = "viewTrendingStickerSets";
+    type Response = Ok;
+}
+impl ViewTrendingStickerSets {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Identifiers of viewed trending sticker sets"]
+    pub fn with_sticker_set_ids(mut self, value: impl Into<Vec<i64>>) -> Self {
+        self.sticker_set_ids = value.into();
+        self
+    }
+}
+impl Validate for ViewTrendingStickerSets {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Changes the order of installed sticker sets"]
+pub struct ReorderInstalledStickerSets {
+    #[doc = "Pass true to change the order of mask sticker sets; pass false to change the order of ordinary sticker sets"]
+    pub is_masks: bool,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_vec_t",
+        serialize_with = "crate::utils::vec_t_to_str"
+    )]
+    #[doc = "Identifiers of installed sticker sets in the new correct order"]
+    pub sticker_set_ids: Vec<i64>,
+}
+impl Method for ReorderInstalledStickerSets {
+    const TYPE: &'static str = "reorderInstalledStickerSets";
+    type Response = Ok;
+}
+impl ReorderInstalledStickerSets {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Pass true to change the order of mask sticker sets; pass false to change the order of ordinary sticker sets"]
+    pub fn with_is_masks(mut self, value: impl Into<bool>) -> Self {
+        self.is_masks = value.into();
+        self
+    }
+    #[doc = "Identifiers of installed sticker sets in the new correct order"]
+    pub fn with_sticker_set_ids(mut self, value: impl Into<Vec<i64>>) -> Self {
+        self.sticker_set_ids = value.into();
+        self
+    }
+}
+impl Validate for ReorderInstalledStickerSets {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns a list of recently used stickers"]
+pub struct GetRecentStickers {
+    #[doc = "Pass true to return stickers and masks that were recently attached to photos or video files; pass false to return recently sent stickers"]
+    pub is_attached: bool,
+}
+impl Method for GetRecentStickers {
+    const TYPE: &'static str = "getRecentStickers";
+    type Response = Stickers;
+}
+impl GetRecentStickers {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Pass true to return stickers and masks that were recently attached to photos or video files; pass false to return recently sent stickers"]
+    pub fn with_is_attached(mut self, value: impl Into<bool>) -> Self {
+        self.is_attached = value.into();
+        self
+    }
+}
+impl Validate for GetRecentStickers {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Manually adds a new sticker to the list of recently used stickers. The new sticker is added to the top of the list. If the sticker was already in the list, it is removed from the list first. Only stickers belonging to a sticker set can be added to this list"]
+pub struct AddRecentSticker {
+    #[doc = "Pass true to add the sticker to the list of stickers recently attached to photo or video files; pass false to add the sticker to the list of recently sent stickers"]
+    pub is_attached: bool,
+    #[doc = "Sticker file to add"]
+    pub sticker: InputFile,
+}
+impl Method for AddRecentSticker {
+    const TYPE: &'static str = "addRecentSticker";
+    type Response = Stickers;
+}
+impl AddRecentSticker {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(sticker: impl Into<InputFile>) -> Self {
+        Self {
+            is_attached: Default::default(),
+            sticker: sticker.into(),
+        }
+    }
+    #[doc = "Pass true to add the sticker to the list of stickers recently attached to photo or video files; pass false to add the sticker to the list of recently sent stickers"]
+    pub fn with_is_attached(mut self, value: impl Into<bool>) -> Self {
+        self.is_attached = value.into();
+        self
+    }
+}
+impl Validate for AddRecentSticker {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.sticker
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "sticker"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Removes a sticker from the list of recently used stickers"]
+pub struct RemoveRecentSticker {
+    #[doc = "Pass true to remove the sticker from the list of stickers recently attached to photo or video files; pass false to remove the sticker from the list of recently sent stickers"]
+    pub is_attached: bool,
+    #[doc = "Sticker file to delete"]
+    pub sticker: InputFile,
+}
+impl Method for RemoveRecentSticker {
+    const TYPE: &'static str = "removeRecentSticker";
+    type Response = Ok;
+}
+impl RemoveRecentSticker {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(sticker: impl Into<InputFile>) -> Self {
+        Self {
+            is_attached: Default::default(),
+            sticker: sticker.into(),
+        }
+    }
+    #[doc = "Pass true to remove the sticker from the list of stickers recently attached to photo or video files; pass false to remove the sticker from the list of recently sent stickers"]
+    pub fn with_is_attached(mut self, value: impl Into<bool>) -> Self {
+        self.is_attached = value.into();
+        self
+    }
+}
+impl Validate for RemoveRecentSticker {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.sticker
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "sticker"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Clears the list of recently used stickers"]
+pub struct ClearRecentStickers {
+    #[doc = "Pass true to clear the list of stickers recently attached to photo or video files; pass false to clear the list of recently sent stickers"]
+    pub is_attached: bool,
+}
+impl Method for ClearRecentStickers {
+    const TYPE: &'static str = "clearRecentStickers";
+    type Response = Ok;
+}
+impl ClearRecentStickers {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Pass true to clear the list of stickers recently attached to photo or video files; pass false to clear the list of recently sent stickers"]
+    pub fn with_is_attached(mut self, value: impl Into<bool>) -> Self {
+        self.is_attached = value.into();
+        self
+    }
+}
+impl Validate for ClearRecentStickers {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns favorite stickers"]
+pub struct GetFavoriteStickers {}
+impl Method for GetFavoriteStickers {
+    const TYPE: &'static str = "getFavoriteStickers";
+    type Response = Stickers;
+}
+impl GetFavoriteStickers {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetFavoriteStickers {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Adds a new sticker to the list of favorite stickers. The new sticker is added to the top of the list. If the sticker was already in the list, it is removed from the list first. Only stickers belonging to a sticker set can be added to this list"]
+pub struct AddFavoriteSticker {
+    #[doc = "Sticker file to add"]
+    pub sticker: InputFile,
+}
+impl Method for AddFavoriteSticker {
+    const TYPE: &'static str = "addFavoriteSticker";
+    type Response = Ok;
+}
+impl AddFavoriteSticker {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(sticker: impl Into<InputFile>) -> Self {
+        Self {
+            sticker: sticker.into(),
+        }
+    }
+}
+impl Validate for AddFavoriteSticker {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.sticker
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "sticker"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Removes a sticker from the list of favorite stickers"]
+pub struct RemoveFavoriteSticker {
+    #[doc = "Sticker file to delete from the list"]
+    pub sticker: InputFile,
+}
+impl Method for RemoveFavoriteSticker {
+    const TYPE: &'static str = "removeFavoriteSticker";
+    type Response = Ok;
+}
+impl RemoveFavoriteSticker {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(sticker: impl Into<InputFile>) -> Self {
+        Self {
+            sticker: sticker.into(),
+        }
+    }
+}
+impl Validate for RemoveFavoriteSticker {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.sticker
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "sticker"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns emoji corresponding to a sticker. The list is only for informational purposes, because a sticker is always sent with a fixed emoji from the corresponding Sticker object"]
+pub struct GetStickerEmojis {
+    #[doc = "Sticker file identifier"]
+    pub sticker: InputFile,
+}
+impl Method for GetStickerEmojis {
+    const TYPE: &'static str = "getStickerEmojis";
+    type Response = Emojis;
+}
+impl GetStickerEmojis {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(sticker: impl Into<InputFile>) -> Self {
+        Self {
+            sticker: sticker.into(),
+        }
+    }
+}
+impl Validate for GetStickerEmojis {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.sticker
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "sticker"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Searches for emojis by keywords. Supported only if the file database is enabled"]
+pub struct SearchEmojis {
+    #[doc = "Text to search for"]
+    pub text: String,
+    #[doc = "True, if only emojis, which exactly match text needs to be returned"]
+    pub exact_match: bool,
+    #[doc = "IETF language tag of the user's input language; may be empty if unknown"]
+    pub input_language_code: String,
+}
+impl Method for SearchEmojis {
+    const TYPE: &'static str = "searchEmojis";
+    type Response = Emojis;
+}
+impl SearchEmojis {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Text to search for"]
+    pub fn with_text(mut self, value: impl Into<String>) -> Self {
+        self.text = value.into();
+        self
+    }
+    #[doc = "True, if only emojis, which exactly match text needs to be returned"]
+    pub fn with_exact_match(mut self, value: impl Into<bool>) -> Self {
+        self.exact_match = value.into();
+        self
+    }
+    #[doc = "IETF language tag of the user's input language; may be empty if unknown"]
+    pub fn with_input_language_code(mut self, value: impl Into<String>) -> Self {
+        self.input_language_code = value.into();
+        self
+    }
+}
+impl Validate for SearchEmojis {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns an HTTP URL which can be used to automatically log in to the translation platform and suggest new emoji replacements. The URL will be valid for 30 seconds after generation"]
+pub struct GetEmojiSuggestionsUrl {
+    #[doc = "Language code for which the emoji replacements will be suggested"]
+    pub language_code: String,
+}
+impl Method for GetEmojiSuggestionsUrl {
+    const TYPE: &'static str = "getEmojiSuggestionsUrl";
+    type Response = HttpUrl;
+}
+impl GetEmojiSuggestionsUrl {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Language code for which the emoji replacements will be suggested"]
+    pub fn with_language_code(mut self, value: impl Into<String>) -> Self {
+        self.language_code = value.into();
+        self
+    }
+}
+impl Validate for GetEmojiSuggestionsUrl {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns saved animations"]
+pub struct GetSavedAnimations {}
+impl Method for GetSavedAnimations {
+    const TYPE: &'static str = "getSavedAnimations";
+    type Response = Animations;
+}
+impl GetSavedAnimations {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetSavedAnimations {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Manually adds a new animation to the list of saved animations. The new animation is added to the beginning of the list. If the animation was already in the list, it is removed first. Only non-secret video animations with MIME type \"video/mp4\" can be added to the list"]
+pub struct AddSavedAnimation {
+    #[doc = "The animation file to be added. Only animations known to the server (i.e. successfully sent via a message) can be added to the list"]
+    pub animation: InputFile,
+}
+impl Method for AddSavedAnimation {
+    const TYPE: &'static str = "addSavedAnimation";
+    type Response = Ok;
+}
+impl AddSavedAnimation {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(animation: impl Into<InputFile>) -> Self {
+        Self {
+            animation: animation.into(),
+        }
+    }
+}
+impl Validate for AddSavedAnimation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.animation
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "animation"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Removes an animation from the list of saved animations"]
+pub struct RemoveSavedAnimation {
+    #[doc = "Animation file to be removed"]
+    pub animation: InputFile,
+}
+impl Method for RemoveSavedAnimation {
+    const TYPE: &'static str = "removeSavedAnimation";
+    type Response = Ok;
+}
+impl RemoveSavedAnimation {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(animation: impl Into<InputFile>) -> Self {
+        Self {
+            animation: animation.into(),
+        }
+    }
+}
+impl Validate for RemoveSavedAnimation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.animation
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "animation"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the sticker set of a supergroup; requires can_change_info rights"]
+pub struct SetSupergroupStickerSet {
+    #[doc = "Identifier of the supergroup"]
+    pub supergroup_id: SupergroupId,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "New value of the supergroup sticker set identifier. Use 0 to remove the supergroup sticker set"]
+    pub sticker_set_id: i64,
+}
+impl Method for SetSupergroupStickerSet {
+    const TYPE: &'static str = "setSupergroupStickerSet";
+    type Response = Ok;
+}
+impl SetSupergroupStickerSet {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(supergroup_id: impl Into<SupergroupId>) -> Self {
+        Self {
+            supergroup_id: supergroup_id.into(),
+            sticker_set_id: Default::default(),
+        }
+    }
+    #[doc = "New value of the supergroup sticker set identifier. Use 0 to remove the supergroup sticker set"]
+    pub fn with_sticker_set_id(mut self, value: impl Into<i64>) -> Self {
+        self.sticker_set_id = value.into();
+        self
+    }
+}
+impl Validate for SetSupergroupStickerSet {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Uploads a PNG image with a sticker; for bots only; returns the uploaded file"]
+pub struct UploadStickerFile {
+    #[doc = "Sticker file owner"]
+    pub user_id: UserId,
+    #[doc = "PNG image with the sticker; must be up to 512 kB in size and fit in 512x512 square"]
+    pub png_sticker: InputFile,
+}
+impl Method for UploadStickerFile {
+    const TYPE: &'static str = "uploadStickerFile";
+    type Response = File;
+    const BOTS_ONLY: bool = true;
+}
+impl UploadStickerFile {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(user_id: impl Into<UserId>, png_sticker: impl Into<InputFile>) -> Self {
+        Self {
+            user_id: user_id.into(),
+            png_sticker: png_sticker.into(),
+        }
+    }
+}
+impl Validate for UploadStickerFile {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.png_sticker
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "png_sticker"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Creates a new sticker set; for bots only. Returns the newly created sticker set"]
+pub struct CreateNewStickerSet {
+    #[doc = "Sticker set owner"]
+    pub user_id: UserId,
+    #[doc = "Sticker set title; 1-64 characters"]
+    pub title: String,
+    #[doc = "Sticker set name. Can contain only English letters, digits and underscores. Must end with *\"_by_<bot username>\"* (*<bot_username>* is case insensitive); 1-64 characters"]
+    pub name: String,
+    #[doc = "True, if stickers are masks"]
+    pub is_masks: bool,
+    #[doc = "List of stickers to be added to the set"]
+    pub stickers: Vec<InputSticker>,
+}
+impl Method for CreateNewStickerSet {
+    const TYPE: &'static str = "createNewStickerSet";
+    type Response = StickerSet;
+    const BOTS_ONLY: bool = true;
+}
+impl CreateNewStickerSet {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        user_id: impl Into<UserId>,
+        title: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        Self {
+            user_id: user_id.into(),
+            title: title.into(),
+            name: name.into(),
+            is_masks: Default::default(),
+            stickers: Default::default(),
+        }
+    }
+    #[doc = "True, if stickers are masks"]
+    pub fn with_is_masks(mut self, value: impl Into<bool>) -> Self {
+        self.is_masks = value.into();
+        self
+    }
+    #[doc = "List of stickers to be added to the set"]
+    pub fn with_stickers(mut self, value: impl Into<Vec<InputSticker>>) -> Self {
+        self.stickers = value.into();
+        self
+    }
+}
+impl Validate for CreateNewStickerSet {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "title",
+            Measure::Characters,
+            self.title.chars().count() as i64,
+            Some(1),
+            Some(64),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "name",
+            Measure::Characters,
+            self.name.chars().count() as i64,
+            Some(1),
+            Some(64),
+        )?;
+        for (i, value) in self.stickers.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("stickers[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Adds a new sticker to a set; for bots only. Returns the sticker set"]
+pub struct AddStickerToSet {
+    #[doc = "Sticker set owner"]
+    pub user_id: UserId,
+    #[doc = "Sticker set name"]
+    pub name: String,
+    #[doc = "Sticker to add to the set"]
+    pub sticker: InputSticker,
+}
+impl Method for AddStickerToSet {
+    const TYPE: &'static str = "addStickerToSet";
+    type Response = StickerSet;
+    const BOTS_ONLY: bool = true;
+}
+impl AddStickerToSet {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(user_id: impl Into<UserId>, sticker: impl Into<InputSticker>) -> Self {
+        Self {
+            user_id: user_id.into(),
+            name: Default::default(),
+            sticker: sticker.into(),
+        }
+    }
+    #[doc = "Sticker set name"]
+    pub fn with_name(mut self, value: impl Into<String>) -> Self {
+        self.name = value.into();
+        self
+    }
+}
+impl Validate for AddStickerToSet {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.sticker
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "sticker"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the position of a sticker in the set to which it belongs; for bots only. The sticker set must have been created by the bot"]
+pub struct SetStickerPositionInSet {
+    #[doc = "Sticker"]
+    pub sticker: InputFile,
+    #[doc = "New position of the sticker in the set, zero-based"]
+    pub position: i32,
+}
+impl Method for SetStickerPositionInSet {
+    const TYPE: &'static str = "setStickerPositionInSet";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl SetStickerPositionInSet {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(sticker: impl Into<InputFile>) -> Self {
+        Self {
+            sticker: sticker.into(),
+            position: Default::default(),
+        }
+    }
+    #[doc = "New position of the sticker in the set, zero-based"]
+    pub fn with_position(mut self, value: impl Into<i32>) -> Self {
+        self.position = value.into();
+        self
+    }
+}
+impl Validate for SetStickerPositionInSet {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.sticker
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "sticker"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Removes a sticker from the set to which it belongs; for bots only. The sticker set must have been created by the bot"]
+pub struct RemoveStickerFromSet {
+    #[doc = "Sticker"]
+    pub sticker: InputFile,
+}
+impl Method for RemoveStickerFromSet {
+    const TYPE: &'static str = "removeStickerFromSet";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl RemoveStickerFromSet {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(sticker: impl Into<InputFile>) -> Self {
+        Self {
+            sticker: sticker.into(),
+        }
+    }
+}
+impl Validate for RemoveStickerFromSet {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.sticker
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "sticker"))?;
+        Ok(())
+    }
+}
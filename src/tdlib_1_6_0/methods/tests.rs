@@ -0,0 +1,294 @@
+//! The methods of the `tests` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Does nothing; for testing only. This is an offline method. Can be called before authorization"]
+pub struct TestCallEmpty {}
+impl Method for TestCallEmpty {
+    const TYPE: &'static str = "testCallEmpty";
+    type Response = Ok;
+    const OFFLINE: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl TestCallEmpty {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for TestCallEmpty {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the received string; for testing only. This is an offline method. Can be called before authorization"]
+pub struct TestCallString {
+    #[doc = "String to return"]
+    pub x: String,
+}
+impl Method for TestCallString {
+    const TYPE: &'static str = "testCallString";
+    type Response = TestString;
+    const OFFLINE: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl TestCallString {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "String to return"]
+    pub fn with_x(mut self, value: impl Into<String>) -> Self {
+        self.x = value.into();
+        self
+    }
+}
+impl Validate for TestCallString {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the received bytes; for testing only. This is an offline method. Can be called before authorization"]
+pub struct TestCallBytes {
+    #[doc = "Bytes to return"]
+    pub x: Bytes,
+}
+impl Method for TestCallBytes {
+    const TYPE: &'static str = "testCallBytes";
+    type Response = TestBytes;
+    const OFFLINE: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl TestCallBytes {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Bytes to return"]
+    pub fn with_x(mut self, value: impl Into<Bytes>) -> Self {
+        self.x = value.into();
+        self
+    }
+}
+impl Validate for TestCallBytes {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the received vector of numbers; for testing only. This is an offline method. Can be called before authorization"]
+pub struct TestCallVectorInt {
+    #[doc = "Vector of numbers to return"]
+    pub x: Vec<i32>,
+}
+impl Method for TestCallVectorInt {
+    const TYPE: &'static str = "testCallVectorInt";
+    type Response = TestVectorInt;
+    const OFFLINE: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl TestCallVectorInt {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Vector of numbers to return"]
+    pub fn with_x(mut self, value: impl Into<Vec<i32>>) -> Self {
+        self.x = value.into();
+        self
+    }
+}
+impl Validate for TestCallVectorInt {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the received vector of objects containing a number; for testing only. This is an offline method. Can be called before authorization"]
+pub struct TestCallVectorIntObject {
+    #[doc = "Vector of objects to return"]
+    pub x: Vec<TestInt>,
+}
+impl Method for TestCallVectorIntObject {
+    const TYPE: &'static str = "testCallVectorIntObject";
+    type Response = TestVectorIntObject;
+    const OFFLINE: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl TestCallVectorIntObject {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Vector of objects to return"]
+    pub fn with_x(mut self, value: impl Into<Vec<TestInt>>) -> Self {
+        self.x = value.into();
+        self
+    }
+}
+impl Validate for TestCallVectorIntObject {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, value) in self.x.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("x[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the received vector of strings; for testing only. This is an offline method. Can be called before authorization"]
+pub struct TestCallVectorString {
+    #[doc = "Vector of strings to return"]
+    pub x: Vec<String>,
+}
+impl Method for TestCallVectorString {
+    const TYPE: &'static str = "testCallVectorString";
+    type Response = TestVectorString;
+    const OFFLINE: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl TestCallVectorString {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Vector of strings to return"]
+    pub fn with_x(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.x = value.into();
+        self
+    }
+}
+impl Validate for TestCallVectorString {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the received vector of objects containing a string; for testing only. This is an offline method. Can be called before authorization"]
+pub struct TestCallVectorStringObject {
+    #[doc = "Vector of objects to return"]
+    pub x: Vec<TestString>,
+}
+impl Method for TestCallVectorStringObject {
+    const TYPE: &'static str = "testCallVectorStringObject";
+    type Response = TestVectorStringObject;
+    const OFFLINE: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl TestCallVectorStringObject {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Vector of objects to return"]
+    pub fn with_x(mut self, value: impl Into<Vec<TestString>>) -> Self {
+        self.x = value.into();
+        self
+    }
+}
+impl Validate for TestCallVectorStringObject {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, value) in self.x.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("x[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the squared received number; for testing only. This is an offline method. Can be called before authorization"]
+pub struct TestSquareInt {
+    #[doc = "Number to square"]
+    pub x: i32,
+}
+impl Method for TestSquareInt {
+    const TYPE: &'static str = "testSquareInt";
+    type Response = TestInt;
+    const OFFLINE: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl TestSquareInt {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Number to square"]
+    pub fn with_x(mut self, value: impl Into<i32>) -> Self {
+        self.x = value.into();
+        self
+    }
+}
+impl Validate for TestSquareInt {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Sends a simple network request to the Telegram servers; for testing only. Can be called before authorization"]
+pub struct TestNetwork {}
+impl Method for TestNetwork {
+    const TYPE: &'static str = "testNetwork";
+    type Response = Ok;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl TestNetwork {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for TestNetwork {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Forces an updates.getDifference call to the Telegram servers; for testing only"]
+pub struct TestGetDifference {}
+impl Method for TestGetDifference {
+    const TYPE: &'static str = "testGetDifference";
+    type Response = Ok;
+}
+impl TestGetDifference {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for TestGetDifference {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Does nothing and ensures that the Update object is used; for testing only. This is an offline method. Can be called before authorization"]
+pub struct TestUseUpdate {}
+impl Method for TestUseUpdate {
+    const TYPE: &'static str = "testUseUpdate";
+    type Response = Update;
+    const OFFLINE: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl TestUseUpdate {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for TestUseUpdate {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the specified error and ensures that the Error object is used; for testing only. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct TestReturnError {
+    #[doc = "The error to be returned"]
+    pub error: Error,
+}
+impl Method for TestReturnError {
+    const TYPE: &'static str = "testReturnError";
+    type Response = Error;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for TestReturnError {}
+impl TestReturnError {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The error to be returned"]
+    pub fn with_error(mut self, value: impl Into<Error>) -> Self {
+        self.error = value.into();
+        self
+    }
+}
+impl Validate for TestReturnError {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.error
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "error"))?;
+        Ok(())
+    }
+}
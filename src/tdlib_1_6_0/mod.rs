@@ -0,0 +1,18313 @@
+//! The types and methods of TDLib 1.6.0, generated from `schema/1.6.0/td_api.tl`.
+
+#[cfg(feature = "sizes")]
+pub mod sizes;
+
+/// The `td_api.tl` of the release.
+pub(crate) const TD_API: &str = include_str!("../../schema/1.6.0/td_api.tl");
+/// The version of the release.
+pub(crate) const VERSION: crate::version::Version = crate::version::Version::new(1, 6, 0);
+pub mod types {
+    pub use crate::bytes::Bytes;
+    pub use crate::chat_id::Peer;
+    pub use crate::ids::*;
+    use crate::validate::{self, Measure, Validate, ValidationError};
+    use serde::{Deserialize, Serialize};
+    #[doc = "An object of a TL class"]
+    pub trait TlObject {
+        #[doc = "Name of the TL class the object belongs to"]
+        const CLASS: &'static str;
+        #[doc = "Returns the TL constructor name of the object, used as its @type"]
+        fn type_name(&self) -> &str;
+    }
+    #[doc = "A TL constructor, i.e. an object whose @type is known statically"]
+    pub trait TlConstructor: TlObject {
+        #[doc = "Name of the TL constructor"]
+        const TYPE: &'static str;
+    }
+    #[cfg(any(feature = "auth", feature = "passport"))]
+    mod auth;
+    #[cfg(any(feature = "auth", feature = "passport"))]
+    pub use self::auth::*;
+    #[cfg(any(feature = "chats", feature = "messages"))]
+    mod chats;
+    #[cfg(any(feature = "chats", feature = "messages"))]
+    pub use self::chats::*;
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    mod messages;
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    pub use self::messages::*;
+    #[cfg(feature = "files")]
+    mod files;
+    #[cfg(feature = "files")]
+    pub use self::files::*;
+    #[cfg(feature = "calls")]
+    mod calls;
+    #[cfg(feature = "calls")]
+    pub use self::calls::*;
+    #[cfg(feature = "passport")]
+    mod passport;
+    #[cfg(feature = "passport")]
+    pub use self::passport::*;
+    #[cfg(any(
+        feature = "bots",
+        feature = "chats",
+        feature = "messages",
+        feature = "payments"
+    ))]
+    mod payments;
+    #[cfg(any(
+        feature = "bots",
+        feature = "chats",
+        feature = "messages",
+        feature = "payments"
+    ))]
+    pub use self::payments::*;
+    #[cfg(feature = "stickers")]
+    mod stickers;
+    #[cfg(feature = "stickers")]
+    pub use self::stickers::*;
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    mod bots;
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    pub use self::bots::*;
+    #[cfg(feature = "proxy")]
+    mod proxy;
+    #[cfg(feature = "proxy")]
+    pub use self::proxy::*;
+    #[cfg(feature = "logging")]
+    mod logging;
+    #[cfg(feature = "logging")]
+    pub use self::logging::*;
+    #[cfg(feature = "tests")]
+    mod tests;
+    #[cfg(feature = "tests")]
+    pub use self::tests::*;
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "An object of this type can be returned on every function call, in case of an error"]
+    pub struct Error {
+        #[doc = "Error code; subject to future changes. If the error code is 406, the error message must not be processed in any way and must not be displayed to the user"]
+        pub code: i32,
+        #[doc = "Error message; subject to future changes"]
+        pub message: String,
+    }
+    impl TlObject for Error {
+        const CLASS: &'static str = "Error";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Error {
+        const TYPE: &'static str = "error";
+    }
+    impl Error {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Error code; subject to future changes. If the error code is 406, the error message must not be processed in any way and must not be displayed to the user"]
+        pub fn with_code(mut self, value: impl Into<i32>) -> Self {
+            self.code = value.into();
+            self
+        }
+        #[doc = "Error message; subject to future changes"]
+        pub fn with_message(mut self, value: impl Into<String>) -> Self {
+            self.message = value.into();
+            self
+        }
+    }
+    impl Validate for Error {}
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An object of this type is returned on a successful function call for certain functions"]
+    pub struct Ok {}
+    impl TlObject for Ok {
+        const CLASS: &'static str = "Ok";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Ok {
+        const TYPE: &'static str = "ok";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains parameters for TDLib initialization"]
+    pub struct TdlibParameters {
+        #[doc = "If set to true, the Telegram test environment will be used instead of the production environment"]
+        pub use_test_dc: bool,
+        #[doc = "The path to the directory for the persistent database; if empty, the current working directory will be used"]
+        pub database_directory: String,
+        #[doc = "The path to the directory for storing files; if empty, database_directory will be used"]
+        pub files_directory: String,
+        #[doc = "If set to true, information about downloaded and uploaded files will be saved between application restarts"]
+        pub use_file_database: bool,
+        #[doc = "If set to true, the library will maintain a cache of users, basic groups, supergroups, channels and secret chats. Implies use_file_database"]
+        pub use_chat_info_database: bool,
+        #[doc = "If set to true, the library will maintain a cache of chats and messages. Implies use_chat_info_database"]
+        pub use_message_database: bool,
+        #[doc = "If set to true, support for secret chats will be enabled"]
+        pub use_secret_chats: bool,
+        #[doc = "Application identifier for Telegram API access, which can be obtained at https://my.telegram.org"]
+        pub api_id: i32,
+        #[doc = "Application identifier hash for Telegram API access, which can be obtained at https://my.telegram.org"]
+        pub api_hash: String,
+        #[doc = "IETF language tag of the user's operating system language; must be non-empty"]
+        pub system_language_code: String,
+        #[doc = "Model of the device the application is being run on; must be non-empty"]
+        pub device_model: String,
+        #[doc = "Version of the operating system the application is being run on; must be non-empty"]
+        pub system_version: String,
+        #[doc = "Application version; must be non-empty"]
+        pub application_version: String,
+        #[doc = "If set to true, old files will automatically be deleted"]
+        pub enable_storage_optimizer: bool,
+        #[doc = "If set to true, original file names will be ignored. Otherwise, downloaded files will be saved under names as close as possible to the original name"]
+        pub ignore_file_names: bool,
+    }
+    impl TlObject for TdlibParameters {
+        const CLASS: &'static str = "TdlibParameters";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TdlibParameters {
+        const TYPE: &'static str = "tdlibParameters";
+    }
+    impl TdlibParameters {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(
+            api_id: impl Into<i32>,
+            api_hash: impl Into<String>,
+            system_language_code: impl Into<String>,
+            device_model: impl Into<String>,
+            system_version: impl Into<String>,
+            application_version: impl Into<String>,
+        ) -> Self {
+            Self {
+                use_test_dc: Default::default(),
+                database_directory: Default::default(),
+                files_directory: Default::default(),
+                use_file_database: Default::default(),
+                use_chat_info_database: Default::default(),
+                use_message_database: Default::default(),
+                use_secret_chats: Default::default(),
+                api_id: api_id.into(),
+                api_hash: api_hash.into(),
+                system_language_code: system_language_code.into(),
+                device_model: device_model.into(),
+                system_version: system_version.into(),
+                application_version: application_version.into(),
+                enable_storage_optimizer: Default::default(),
+                ignore_file_names: Default::default(),
+            }
+        }
+        #[doc = "If set to true, the Telegram test environment will be used instead of the production environment"]
+        pub fn with_use_test_dc(mut self, value: impl Into<bool>) -> Self {
+            self.use_test_dc = value.into();
+            self
+        }
+        #[doc = "The path to the directory for the persistent database; if empty, the current working directory will be used"]
+        pub fn with_database_directory(mut self, value: impl Into<String>) -> Self {
+            self.database_directory = value.into();
+            self
+        }
+        #[doc = "The path to the directory for storing files; if empty, database_directory will be used"]
+        pub fn with_files_directory(mut self, value: impl Into<String>) -> Self {
+            self.files_directory = value.into();
+            self
+        }
+        #[doc = "If set to true, information about downloaded and uploaded files will be saved between application restarts"]
+        pub fn with_use_file_database(mut self, value: impl Into<bool>) -> Self {
+            self.use_file_database = value.into();
+            self
+        }
+        #[doc = "If set to true, the library will maintain a cache of users, basic groups, supergroups, channels and secret chats. Implies use_file_database"]
+        pub fn with_use_chat_info_database(mut self, value: impl Into<bool>) -> Self {
+            self.use_chat_info_database = value.into();
+            self
+        }
+        #[doc = "If set to true, the library will maintain a cache of chats and messages. Implies use_chat_info_database"]
+        pub fn with_use_message_database(mut self, value: impl Into<bool>) -> Self {
+            self.use_message_database = value.into();
+            self
+        }
+        #[doc = "If set to true, support for secret chats will be enabled"]
+        pub fn with_use_secret_chats(mut self, value: impl Into<bool>) -> Self {
+            self.use_secret_chats = value.into();
+            self
+        }
+        #[doc = "If set to true, old files will automatically be deleted"]
+        pub fn with_enable_storage_optimizer(mut self, value: impl Into<bool>) -> Self {
+            self.enable_storage_optimizer = value.into();
+            self
+        }
+        #[doc = "If set to true, original file names will be ignored. Otherwise, downloaded files will be saved under names as close as possible to the original name"]
+        pub fn with_ignore_file_names(mut self, value: impl Into<bool>) -> Self {
+            self.ignore_file_names = value.into();
+            self
+        }
+    }
+    impl Validate for TdlibParameters {
+        fn validate(&self) -> Result<(), ValidationError> {
+            validate::check(
+                Self::TYPE,
+                "system_language_code",
+                Measure::Characters,
+                self.system_language_code.chars().count() as i64,
+                Some(1),
+                None,
+            )?;
+            validate::check(
+                Self::TYPE,
+                "device_model",
+                Measure::Characters,
+                self.device_model.chars().count() as i64,
+                Some(1),
+                None,
+            )?;
+            validate::check(
+                Self::TYPE,
+                "system_version",
+                Measure::Characters,
+                self.system_version.chars().count() as i64,
+                Some(1),
+                None,
+            )?;
+            validate::check(
+                Self::TYPE,
+                "application_version",
+                Measure::Characters,
+                self.application_version.chars().count() as i64,
+                Some(1),
+                None,
+            )?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An authentication code is delivered via a private Telegram message, which can be viewed in another client"]
+    pub struct AuthenticationCodeTypeTelegramMessage {
+        #[doc = "Length of the code"]
+        pub length: i32,
+    }
+    impl TlObject for AuthenticationCodeTypeTelegramMessage {
+        const CLASS: &'static str = "AuthenticationCodeType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthenticationCodeTypeTelegramMessage {
+        const TYPE: &'static str = "authenticationCodeTypeTelegramMessage";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An authentication code is delivered via an SMS message to the specified phone number"]
+    pub struct AuthenticationCodeTypeSms {
+        #[doc = "Length of the code"]
+        pub length: i32,
+    }
+    impl TlObject for AuthenticationCodeTypeSms {
+        const CLASS: &'static str = "AuthenticationCodeType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthenticationCodeTypeSms {
+        const TYPE: &'static str = "authenticationCodeTypeSms";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An authentication code is delivered via a phone call to the specified phone number"]
+    pub struct AuthenticationCodeTypeCall {
+        #[doc = "Length of the code"]
+        pub length: i32,
+    }
+    impl TlObject for AuthenticationCodeTypeCall {
+        const CLASS: &'static str = "AuthenticationCodeType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthenticationCodeTypeCall {
+        const TYPE: &'static str = "authenticationCodeTypeCall";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An authentication code is delivered by an immediately cancelled call to the specified phone number. The number from which the call was made is the code"]
+    pub struct AuthenticationCodeTypeFlashCall {
+        #[doc = "Pattern of the phone number from which the call will be made"]
+        pub pattern: String,
+    }
+    impl TlObject for AuthenticationCodeTypeFlashCall {
+        const CLASS: &'static str = "AuthenticationCodeType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthenticationCodeTypeFlashCall {
+        const TYPE: &'static str = "authenticationCodeTypeFlashCall";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Provides information about the method by which an authentication code is delivered to the user"]
+    pub enum AuthenticationCodeType {
+        AuthenticationCodeTypeTelegramMessage(AuthenticationCodeTypeTelegramMessage),
+        AuthenticationCodeTypeSms(AuthenticationCodeTypeSms),
+        AuthenticationCodeTypeCall(AuthenticationCodeTypeCall),
+        AuthenticationCodeTypeFlashCall(AuthenticationCodeTypeFlashCall),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for AuthenticationCodeType {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "authenticationCodeTypeTelegramMessage" => Deserialize::deserialize(value)
+                    .map(AuthenticationCodeType::AuthenticationCodeTypeTelegramMessage),
+                "authenticationCodeTypeSms" => Deserialize::deserialize(value)
+                    .map(AuthenticationCodeType::AuthenticationCodeTypeSms),
+                "authenticationCodeTypeCall" => Deserialize::deserialize(value)
+                    .map(AuthenticationCodeType::AuthenticationCodeTypeCall),
+                "authenticationCodeTypeFlashCall" => Deserialize::deserialize(value)
+                    .map(AuthenticationCodeType::AuthenticationCodeTypeFlashCall),
+                _ => Unknown::deserialize(value).map(AuthenticationCodeType::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for AuthenticationCodeType {
+        const CLASS: &'static str = "AuthenticationCodeType";
+        fn type_name(&self) -> &str {
+            match self {
+                AuthenticationCodeType::AuthenticationCodeTypeTelegramMessage(_) => {
+                    "authenticationCodeTypeTelegramMessage"
+                }
+                AuthenticationCodeType::AuthenticationCodeTypeSms(_) => "authenticationCodeTypeSms",
+                AuthenticationCodeType::AuthenticationCodeTypeCall(_) => {
+                    "authenticationCodeTypeCall"
+                }
+                AuthenticationCodeType::AuthenticationCodeTypeFlashCall(_) => {
+                    "authenticationCodeTypeFlashCall"
+                }
+                AuthenticationCodeType::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Information about the authentication code that was sent"]
+    pub struct AuthenticationCodeInfo {
+        #[doc = "A phone number that is being authenticated"]
+        pub phone_number: String,
+        #[serde(rename = "type")]
+        #[doc = "Describes the way the code was sent to the user"]
+        pub type_: AuthenticationCodeType,
+        #[serde(default)]
+        #[doc = "Describes the way the next code will be sent to the user; may be null"]
+        pub next_type: Option<AuthenticationCodeType>,
+        #[doc = "Timeout before the code should be re-sent, in seconds"]
+        pub timeout: i32,
+    }
+    impl TlObject for AuthenticationCodeInfo {
+        const CLASS: &'static str = "AuthenticationCodeInfo";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthenticationCodeInfo {
+        const TYPE: &'static str = "authenticationCodeInfo";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Represents a part of the text that needs to be formatted in some unusual way"]
+    pub struct TextEntity {
+        #[doc = "Offset of the entity in UTF-16 code units"]
+        pub offset: i32,
+        #[doc = "Length of the entity, in UTF-16 code units"]
+        pub length: i32,
+        #[serde(rename = "type")]
+        #[doc = "Type of the entity"]
+        pub type_: TextEntityType,
+    }
+    impl TlObject for TextEntity {
+        const CLASS: &'static str = "TextEntity";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntity {
+        const TYPE: &'static str = "textEntity";
+    }
+    impl TextEntity {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(type_: impl Into<TextEntityType>) -> Self {
+            Self {
+                offset: Default::default(),
+                length: Default::default(),
+                type_: type_.into(),
+            }
+        }
+        #[doc = "Offset of the entity in UTF-16 code units"]
+        pub fn with_offset(mut self, value: impl Into<i32>) -> Self {
+            self.offset = value.into();
+            self
+        }
+        #[doc = "Length of the entity, in UTF-16 code units"]
+        pub fn with_length(mut self, value: impl Into<i32>) -> Self {
+            self.length = value.into();
+            self
+        }
+    }
+    impl Validate for TextEntity {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.type_
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "type"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A text with some entities"]
+    pub struct FormattedText {
+        #[doc = "The text"]
+        pub text: String,
+        #[doc = "Entities contained in the text. Entities can be nested, but must not mutually intersect with each other. Pre, Code and PreCode entities can't contain other entities. Bold, Italic, Underline and Strikethrough entities can contain and to be contained in all other entities. All other entities can't contain each other"]
+        pub entities: Vec<TextEntity>,
+    }
+    impl TlObject for FormattedText {
+        const CLASS: &'static str = "FormattedText";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for FormattedText {
+        const TYPE: &'static str = "formattedText";
+    }
+    impl FormattedText {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The text"]
+        pub fn with_text(mut self, value: impl Into<String>) -> Self {
+            self.text = value.into();
+            self
+        }
+        #[doc = "Entities contained in the text. Entities can be nested, but must not mutually intersect with each other. Pre, Code and PreCode entities can't contain other entities. Bold, Italic, Underline and Strikethrough entities can contain and to be contained in all other entities. All other entities can't contain each other"]
+        pub fn with_entities(mut self, value: impl Into<Vec<TextEntity>>) -> Self {
+            self.entities = value.into();
+            self
+        }
+    }
+    impl Validate for FormattedText {
+        fn validate(&self) -> Result<(), ValidationError> {
+            for (i, value) in self.entities.iter().enumerate() {
+                value
+                    .validate()
+                    .map_err(|err| err.within(Self::TYPE, &format!("entities[{}]", i)))?;
+            }
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains Telegram terms of service"]
+    pub struct TermsOfService {
+        #[doc = "Text of the terms of service"]
+        pub text: FormattedText,
+        #[doc = "The minimum age of a user to be able to accept the terms; 0 if any"]
+        pub min_user_age: i32,
+        #[doc = "True, if a blocking popup with terms of service must be shown to the user"]
+        pub show_popup: bool,
+    }
+    impl TlObject for TermsOfService {
+        const CLASS: &'static str = "TermsOfService";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TermsOfService {
+        const TYPE: &'static str = "termsOfService";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "TDLib needs TdlibParameters for initialization"]
+    pub struct AuthorizationStateWaitTdlibParameters {}
+    impl TlObject for AuthorizationStateWaitTdlibParameters {
+        const CLASS: &'static str = "AuthorizationState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthorizationStateWaitTdlibParameters {
+        const TYPE: &'static str = "authorizationStateWaitTdlibParameters";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "TDLib needs an encryption key to decrypt the local database"]
+    pub struct AuthorizationStateWaitEncryptionKey {
+        #[doc = "True, if the database is currently encrypted"]
+        pub is_encrypted: bool,
+    }
+    impl TlObject for AuthorizationStateWaitEncryptionKey {
+        const CLASS: &'static str = "AuthorizationState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthorizationStateWaitEncryptionKey {
+        const TYPE: &'static str = "authorizationStateWaitEncryptionKey";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "TDLib needs the user's phone number to authorize. Call `setAuthenticationPhoneNumber` to provide the phone number, or use `requestQrCodeAuthentication`, or `checkAuthenticationBotToken` for other authentication options"]
+    pub struct AuthorizationStateWaitPhoneNumber {}
+    impl TlObject for AuthorizationStateWaitPhoneNumber {
+        const CLASS: &'static str = "AuthorizationState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthorizationStateWaitPhoneNumber {
+        const TYPE: &'static str = "authorizationStateWaitPhoneNumber";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "TDLib needs the user's authentication code to authorize"]
+    pub struct AuthorizationStateWaitCode {
+        #[doc = "Information about the authorization code that was sent"]
+        pub code_info: AuthenticationCodeInfo,
+    }
+    impl TlObject for AuthorizationStateWaitCode {
+        const CLASS: &'static str = "AuthorizationState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthorizationStateWaitCode {
+        const TYPE: &'static str = "authorizationStateWaitCode";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user needs to confirm authorization on another logged in device by scanning a QR code with the provided link"]
+    pub struct AuthorizationStateWaitOtherDeviceConfirmation {
+        #[doc = "A tg:// URL for the QR code. The link will be updated frequently"]
+        pub link: String,
+    }
+    impl TlObject for AuthorizationStateWaitOtherDeviceConfirmation {
+        const CLASS: &'static str = "AuthorizationState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthorizationStateWaitOtherDeviceConfirmation {
+        const TYPE: &'static str = "authorizationStateWaitOtherDeviceConfirmation";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user is unregistered and need to accept terms of service and enter their first name and last name to finish registration"]
+    pub struct AuthorizationStateWaitRegistration {
+        #[doc = "Telegram terms of service"]
+        pub terms_of_service: TermsOfService,
+    }
+    impl TlObject for AuthorizationStateWaitRegistration {
+        const CLASS: &'static str = "AuthorizationState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthorizationStateWaitRegistration {
+        const TYPE: &'static str = "authorizationStateWaitRegistration";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user has been authorized, but needs to enter a password to start using the application"]
+    pub struct AuthorizationStateWaitPassword {
+        #[doc = "Hint for the password; may be empty"]
+        pub password_hint: String,
+        #[doc = "True, if a recovery email address has been set up"]
+        pub has_recovery_email_address: bool,
+        #[doc = "Pattern of the email address to which the recovery email was sent; empty until a recovery email has been sent"]
+        pub recovery_email_address_pattern: String,
+    }
+    impl TlObject for AuthorizationStateWaitPassword {
+        const CLASS: &'static str = "AuthorizationState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthorizationStateWaitPassword {
+        const TYPE: &'static str = "authorizationStateWaitPassword";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user has been successfully authorized. TDLib is now ready to answer queries"]
+    pub struct AuthorizationStateReady {}
+    impl TlObject for AuthorizationStateReady {
+        const CLASS: &'static str = "AuthorizationState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthorizationStateReady {
+        const TYPE: &'static str = "authorizationStateReady";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user is currently logging out"]
+    pub struct AuthorizationStateLoggingOut {}
+    impl TlObject for AuthorizationStateLoggingOut {
+        const CLASS: &'static str = "AuthorizationState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthorizationStateLoggingOut {
+        const TYPE: &'static str = "authorizationStateLoggingOut";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "TDLib is closing, all subsequent queries will be answered with the error 500. Note that closing TDLib can take a while. All resources will be freed only after authorizationStateClosed has been received"]
+    pub struct AuthorizationStateClosing {}
+    impl TlObject for AuthorizationStateClosing {
+        const CLASS: &'static str = "AuthorizationState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthorizationStateClosing {
+        const TYPE: &'static str = "authorizationStateClosing";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "TDLib client is in its final state. All databases are closed and all resources are released. No other updates will be received after this. All queries will be responded to with error code 500. To continue working, one should create a new instance of the TDLib client"]
+    pub struct AuthorizationStateClosed {}
+    impl TlObject for AuthorizationStateClosed {
+        const CLASS: &'static str = "AuthorizationState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for AuthorizationStateClosed {
+        const TYPE: &'static str = "authorizationStateClosed";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Represents the current authorization state of the client"]
+    pub enum AuthorizationState {
+        AuthorizationStateWaitTdlibParameters(AuthorizationStateWaitTdlibParameters),
+        AuthorizationStateWaitEncryptionKey(AuthorizationStateWaitEncryptionKey),
+        AuthorizationStateWaitPhoneNumber(AuthorizationStateWaitPhoneNumber),
+        AuthorizationStateWaitCode(AuthorizationStateWaitCode),
+        AuthorizationStateWaitOtherDeviceConfirmation(
+            AuthorizationStateWaitOtherDeviceConfirmation,
+        ),
+        AuthorizationStateWaitRegistration(AuthorizationStateWaitRegistration),
+        AuthorizationStateWaitPassword(AuthorizationStateWaitPassword),
+        AuthorizationStateReady(AuthorizationStateReady),
+        AuthorizationStateLoggingOut(AuthorizationStateLoggingOut),
+        AuthorizationStateClosing(AuthorizationStateClosing),
+        AuthorizationStateClosed(AuthorizationStateClosed),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for AuthorizationState {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "authorizationStateWaitTdlibParameters" => Deserialize::deserialize(value)
+                    .map(AuthorizationState::AuthorizationStateWaitTdlibParameters),
+                "authorizationStateWaitEncryptionKey" => Deserialize::deserialize(value)
+                    .map(AuthorizationState::AuthorizationStateWaitEncryptionKey),
+                "authorizationStateWaitPhoneNumber" => Deserialize::deserialize(value)
+                    .map(AuthorizationState::AuthorizationStateWaitPhoneNumber),
+                "authorizationStateWaitCode" => Deserialize::deserialize(value)
+                    .map(AuthorizationState::AuthorizationStateWaitCode),
+                "authorizationStateWaitOtherDeviceConfirmation" => Deserialize::deserialize(value)
+                    .map(AuthorizationState::AuthorizationStateWaitOtherDeviceConfirmation),
+                "authorizationStateWaitRegistration" => Deserialize::deserialize(value)
+                    .map(AuthorizationState::AuthorizationStateWaitRegistration),
+                "authorizationStateWaitPassword" => Deserialize::deserialize(value)
+                    .map(AuthorizationState::AuthorizationStateWaitPassword),
+                "authorizationStateReady" => {
+                    Deserialize::deserialize(value).map(AuthorizationState::AuthorizationStateReady)
+                }
+                "authorizationStateLoggingOut" => Deserialize::deserialize(value)
+                    .map(AuthorizationState::AuthorizationStateLoggingOut),
+                "authorizationStateClosing" => Deserialize::deserialize(value)
+                    .map(AuthorizationState::AuthorizationStateClosing),
+                "authorizationStateClosed" => Deserialize::deserialize(value)
+                    .map(AuthorizationState::AuthorizationStateClosed),
+                _ => Unknown::deserialize(value).map(AuthorizationState::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for AuthorizationState {
+        const CLASS: &'static str = "AuthorizationState";
+        fn type_name(&self) -> &str {
+            match self {
+                AuthorizationState::AuthorizationStateWaitTdlibParameters(_) => {
+                    "authorizationStateWaitTdlibParameters"
+                }
+                AuthorizationState::AuthorizationStateWaitEncryptionKey(_) => {
+                    "authorizationStateWaitEncryptionKey"
+                }
+                AuthorizationState::AuthorizationStateWaitPhoneNumber(_) => {
+                    "authorizationStateWaitPhoneNumber"
+                }
+                AuthorizationState::AuthorizationStateWaitCode(_) => "authorizationStateWaitCode",
+                AuthorizationState::AuthorizationStateWaitOtherDeviceConfirmation(_) => {
+                    "authorizationStateWaitOtherDeviceConfirmation"
+                }
+                AuthorizationState::AuthorizationStateWaitRegistration(_) => {
+                    "authorizationStateWaitRegistration"
+                }
+                AuthorizationState::AuthorizationStateWaitPassword(_) => {
+                    "authorizationStateWaitPassword"
+                }
+                AuthorizationState::AuthorizationStateReady(_) => "authorizationStateReady",
+                AuthorizationState::AuthorizationStateLoggingOut(_) => {
+                    "authorizationStateLoggingOut"
+                }
+                AuthorizationState::AuthorizationStateClosing(_) => "authorizationStateClosing",
+                AuthorizationState::AuthorizationStateClosed(_) => "authorizationStateClosed",
+                AuthorizationState::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Represents a local file"]
+    pub struct LocalFile {
+        #[doc = "Local path to the locally available file part; may be empty"]
+        pub path: String,
+        #[doc = "True, if it is possible to try to download or generate the file"]
+        pub can_be_downloaded: bool,
+        #[doc = "True, if the file can be deleted"]
+        pub can_be_deleted: bool,
+        #[doc = "True, if the file is currently being downloaded (or a local copy is being generated by some other means)"]
+        pub is_downloading_active: bool,
+        #[doc = "True, if the local copy is fully available"]
+        pub is_downloading_completed: bool,
+        #[doc = "Download will be started from this offset. downloaded_prefix_size is calculated from this offset"]
+        pub download_offset: i32,
+        #[doc = "If is_downloading_completed is false, then only some prefix of the file starting from download_offset is ready to be read. downloaded_prefix_size is the size of that prefix"]
+        pub downloaded_prefix_size: i32,
+        #[doc = "Total downloaded file bytes. Should be used only for calculating download progress. The actual file size may be bigger, and some parts of it may contain garbage"]
+        pub downloaded_size: i32,
+    }
+    impl TlObject for LocalFile {
+        const CLASS: &'static str = "LocalFile";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for LocalFile {
+        const TYPE: &'static str = "localFile";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Represents a remote file"]
+    pub struct RemoteFile {
+        #[doc = "Remote file identifier; may be empty. Can be used across application restarts or even from other devices for the current user. Uniquely identifies a file, but a file can have a lot of different valid identifiers. If the ID starts with \"http://\" or \"https://\", it represents the HTTP URL of the file. TDLib is currently unable to download files if only their URL is known. If downloadFile is called on such a file or if it is sent to a secret chat, TDLib starts a file generation process by sending updateFileGenerationStart to the client with the HTTP URL in the original_path and \"#url#\" as the conversion string. Clients should generate the file by downloading it to the specified location"]
+        pub id: String,
+        #[doc = "Unique file identifier; may be empty if unknown. The unique file identifier which is the same for the same file even for different users and is persistent over time"]
+        pub unique_id: String,
+        #[doc = "True, if the file is currently being uploaded (or a remote copy is being generated by some other means)"]
+        pub is_uploading_active: bool,
+        #[doc = "True, if a remote copy is fully available"]
+        pub is_uploading_completed: bool,
+        #[doc = "Size of the remote available part of the file; 0 if unknown"]
+        pub uploaded_size: i32,
+    }
+    impl TlObject for RemoteFile {
+        const CLASS: &'static str = "RemoteFile";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for RemoteFile {
+        const TYPE: &'static str = "remoteFile";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Represents a file"]
+    pub struct File {
+        #[doc = "Unique file identifier"]
+        pub id: FileId,
+        #[doc = "File size; 0 if unknown"]
+        pub size: i32,
+        #[doc = "Expected file size in case the exact file size is unknown, but an approximate size is known. Can be used to show download/upload progress"]
+        pub expected_size: i32,
+        #[doc = "Information about the local copy of the file"]
+        pub local: LocalFile,
+        #[doc = "Information about the remote copy of the file"]
+        pub remote: RemoteFile,
+    }
+    impl TlObject for File {
+        const CLASS: &'static str = "File";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for File {
+        const TYPE: &'static str = "file";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A file defined by its unique ID"]
+    pub struct InputFileId {
+        #[doc = "Unique file identifier"]
+        pub id: FileId,
+    }
+    impl TlObject for InputFileId {
+        const CLASS: &'static str = "InputFile";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for InputFileId {
+        const TYPE: &'static str = "inputFileId";
+    }
+    impl InputFileId {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(id: impl Into<FileId>) -> Self {
+            Self { id: id.into() }
+        }
+    }
+    impl Validate for InputFileId {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A file defined by its remote ID. The remote ID is guaranteed to be usable only if the corresponding file is still accessible to the user and known to TDLib. For example, if the file is from a message, then the message must be not deleted and accessible to the user. If the file database is disabled, then the corresponding object with the file must be preloaded by the client"]
+    pub struct InputFileRemote {
+        #[doc = "Remote file identifier"]
+        pub id: String,
+    }
+    impl TlObject for InputFileRemote {
+        const CLASS: &'static str = "InputFile";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for InputFileRemote {
+        const TYPE: &'static str = "inputFileRemote";
+    }
+    impl InputFileRemote {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Remote file identifier"]
+        pub fn with_id(mut self, value: impl Into<String>) -> Self {
+            self.id = value.into();
+            self
+        }
+    }
+    impl Validate for InputFileRemote {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A file defined by a local path"]
+    pub struct InputFileLocal {
+        #[doc = "Local path to the file"]
+        pub path: String,
+    }
+    impl TlObject for InputFileLocal {
+        const CLASS: &'static str = "InputFile";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for InputFileLocal {
+        const TYPE: &'static str = "inputFileLocal";
+    }
+    impl InputFileLocal {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Local path to the file"]
+        pub fn with_path(mut self, value: impl Into<String>) -> Self {
+            self.path = value.into();
+            self
+        }
+    }
+    impl Validate for InputFileLocal {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A file generated by the client"]
+    pub struct InputFileGenerated {
+        #[doc = "Local path to a file from which the file is generated; may be empty if there is no such file"]
+        pub original_path: String,
+        #[doc = "String specifying the conversion applied to the original file; should be persistent across application restarts. Conversions beginning with '#' are reserved for internal TDLib usage"]
+        pub conversion: String,
+        #[doc = "Expected size of the generated file; 0 if unknown"]
+        pub expected_size: i32,
+    }
+    impl TlObject for InputFileGenerated {
+        const CLASS: &'static str = "InputFile";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for InputFileGenerated {
+        const TYPE: &'static str = "inputFileGenerated";
+    }
+    impl InputFileGenerated {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Local path to a file from which the file is generated; may be empty if there is no such file"]
+        pub fn with_original_path(mut self, value: impl Into<String>) -> Self {
+            self.original_path = value.into();
+            self
+        }
+        #[doc = "String specifying the conversion applied to the original file; should be persistent across application restarts. Conversions beginning with '#' are reserved for internal TDLib usage"]
+        pub fn with_conversion(mut self, value: impl Into<String>) -> Self {
+            self.conversion = value.into();
+            self
+        }
+        #[doc = "Expected size of the generated file; 0 if unknown"]
+        pub fn with_expected_size(mut self, value: impl Into<i32>) -> Self {
+            self.expected_size = value.into();
+            self
+        }
+    }
+    impl Validate for InputFileGenerated {}
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Points to a file"]
+    pub enum InputFile {
+        InputFileId(InputFileId),
+        InputFileRemote(InputFileRemote),
+        InputFileLocal(InputFileLocal),
+        InputFileGenerated(InputFileGenerated),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for InputFile {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "inputFileId" => Deserialize::deserialize(value).map(InputFile::InputFileId),
+                "inputFileRemote" => {
+                    Deserialize::deserialize(value).map(InputFile::InputFileRemote)
+                }
+                "inputFileLocal" => Deserialize::deserialize(value).map(InputFile::InputFileLocal),
+                "inputFileGenerated" => {
+                    Deserialize::deserialize(value).map(InputFile::InputFileGenerated)
+                }
+                _ => Unknown::deserialize(value).map(InputFile::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for InputFile {
+        const CLASS: &'static str = "InputFile";
+        fn type_name(&self) -> &str {
+            match self {
+                InputFile::InputFileId(_) => "inputFileId",
+                InputFile::InputFileRemote(_) => "inputFileRemote",
+                InputFile::InputFileLocal(_) => "inputFileLocal",
+                InputFile::InputFileGenerated(_) => "inputFileGenerated",
+                InputFile::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for InputFile {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                InputFile::InputFileId(value) => value.validate(),
+                InputFile::InputFileRemote(value) => value.validate(),
+                InputFile::InputFileLocal(value) => value.validate(),
+                InputFile::InputFileGenerated(value) => value.validate(),
+                InputFile::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<InputFileId> for InputFile {
+        fn from(value: InputFileId) -> Self {
+            InputFile::InputFileId(value)
+        }
+    }
+    impl From<InputFileRemote> for InputFile {
+        fn from(value: InputFileRemote) -> Self {
+            InputFile::InputFileRemote(value)
+        }
+    }
+    impl From<InputFileLocal> for InputFile {
+        fn from(value: InputFileLocal) -> Self {
+            InputFile::InputFileLocal(value)
+        }
+    }
+    impl From<InputFileGenerated> for InputFile {
+        fn from(value: InputFileGenerated) -> Self {
+            InputFile::InputFileGenerated(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Photo description"]
+    pub struct PhotoSize {
+        #[serde(rename = "type")]
+        #[doc = "Thumbnail type (see https://core.telegram.org/constructor/photoSize)"]
+        pub type_: String,
+        #[doc = "Information about the photo file"]
+        pub photo: File,
+        #[doc = "Photo width"]
+        pub width: i32,
+        #[doc = "Photo height"]
+        pub height: i32,
+    }
+    impl TlObject for PhotoSize {
+        const CLASS: &'static str = "PhotoSize";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PhotoSize {
+        const TYPE: &'static str = "photoSize";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Thumbnail image of a very poor quality and low resolution"]
+    pub struct Minithumbnail {
+        #[doc = "Thumbnail width, usually doesn't exceed 40"]
+        pub width: i32,
+        #[doc = "Thumbnail height, usually doesn't exceed 40"]
+        pub height: i32,
+        #[doc = "The thumbnail in JPEG format"]
+        pub data: Bytes,
+    }
+    impl TlObject for Minithumbnail {
+        const CLASS: &'static str = "Minithumbnail";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Minithumbnail {
+        const TYPE: &'static str = "minithumbnail";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A mask should be placed relatively to the forehead"]
+    pub struct MaskPointForehead {}
+    impl TlObject for MaskPointForehead {
+        const CLASS: &'static str = "MaskPoint";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MaskPointForehead {
+        const TYPE: &'static str = "maskPointForehead";
+    }
+    impl MaskPointForehead {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for MaskPointForehead {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A mask should be placed relatively to the eyes"]
+    pub struct MaskPointEyes {}
+    impl TlObject for MaskPointEyes {
+        const CLASS: &'static str = "MaskPoint";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MaskPointEyes {
+        const TYPE: &'static str = "maskPointEyes";
+    }
+    impl MaskPointEyes {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for MaskPointEyes {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A mask should be placed relatively to the mouth"]
+    pub struct MaskPointMouth {}
+    impl TlObject for MaskPointMouth {
+        const CLASS: &'static str = "MaskPoint";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MaskPointMouth {
+        const TYPE: &'static str = "maskPointMouth";
+    }
+    impl MaskPointMouth {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for MaskPointMouth {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A mask should be placed relatively to the chin"]
+    pub struct MaskPointChin {}
+    impl TlObject for MaskPointChin {
+        const CLASS: &'static str = "MaskPoint";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MaskPointChin {
+        const TYPE: &'static str = "maskPointChin";
+    }
+    impl MaskPointChin {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for MaskPointChin {}
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Part of the face, relative to which a mask should be placed"]
+    pub enum MaskPoint {
+        MaskPointForehead(MaskPointForehead),
+        MaskPointEyes(MaskPointEyes),
+        MaskPointMouth(MaskPointMouth),
+        MaskPointChin(MaskPointChin),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for MaskPoint {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "maskPointForehead" => {
+                    Deserialize::deserialize(value).map(MaskPoint::MaskPointForehead)
+                }
+                "maskPointEyes" => Deserialize::deserialize(value).map(MaskPoint::MaskPointEyes),
+                "maskPointMouth" => Deserialize::deserialize(value).map(MaskPoint::MaskPointMouth),
+                "maskPointChin" => Deserialize::deserialize(value).map(MaskPoint::MaskPointChin),
+                _ => Unknown::deserialize(value).map(MaskPoint::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for MaskPoint {
+        const CLASS: &'static str = "MaskPoint";
+        fn type_name(&self) -> &str {
+            match self {
+                MaskPoint::MaskPointForehead(_) => "maskPointForehead",
+                MaskPoint::MaskPointEyes(_) => "maskPointEyes",
+                MaskPoint::MaskPointMouth(_) => "maskPointMouth",
+                MaskPoint::MaskPointChin(_) => "maskPointChin",
+                MaskPoint::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for MaskPoint {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                MaskPoint::MaskPointForehead(value) => value.validate(),
+                MaskPoint::MaskPointEyes(value) => value.validate(),
+                MaskPoint::MaskPointMouth(value) => value.validate(),
+                MaskPoint::MaskPointChin(value) => value.validate(),
+                MaskPoint::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<MaskPointForehead> for MaskPoint {
+        fn from(value: MaskPointForehead) -> Self {
+            MaskPoint::MaskPointForehead(value)
+        }
+    }
+    impl From<MaskPointEyes> for MaskPoint {
+        fn from(value: MaskPointEyes) -> Self {
+            MaskPoint::MaskPointEyes(value)
+        }
+    }
+    impl From<MaskPointMouth> for MaskPoint {
+        fn from(value: MaskPointMouth) -> Self {
+            MaskPoint::MaskPointMouth(value)
+        }
+    }
+    impl From<MaskPointChin> for MaskPoint {
+        fn from(value: MaskPointChin) -> Self {
+            MaskPoint::MaskPointChin(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Position on a photo where a mask should be placed"]
+    pub struct MaskPosition {
+        #[doc = "Part of the face, relative to which the mask should be placed"]
+        pub point: MaskPoint,
+        #[doc = "Shift by X-axis measured in widths of the mask scaled to the face size, from left to right. (For example, -1.0 will place the mask just to the left of the default mask position)"]
+        pub x_shift: f64,
+        #[doc = "Shift by Y-axis measured in heights of the mask scaled to the face size, from top to bottom. (For example, 1.0 will place the mask just below the default mask position)"]
+        pub y_shift: f64,
+        #[doc = "Mask scaling coefficient. (For example, 2.0 means a doubled size)"]
+        pub scale: f64,
+    }
+    impl TlObject for MaskPosition {
+        const CLASS: &'static str = "MaskPosition";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MaskPosition {
+        const TYPE: &'static str = "maskPosition";
+    }
+    impl MaskPosition {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(point: impl Into<MaskPoint>) -> Self {
+            Self {
+                point: point.into(),
+                x_shift: Default::default(),
+                y_shift: Default::default(),
+                scale: Default::default(),
+            }
+        }
+        #[doc = "Shift by X-axis measured in widths of the mask scaled to the face size, from left to right. (For example, -1.0 will place the mask just to the left of the default mask position)"]
+        pub fn with_x_shift(mut self, value: impl Into<f64>) -> Self {
+            self.x_shift = value.into();
+            self
+        }
+        #[doc = "Shift by Y-axis measured in heights of the mask scaled to the face size, from top to bottom. (For example, 1.0 will place the mask just below the default mask position)"]
+        pub fn with_y_shift(mut self, value: impl Into<f64>) -> Self {
+            self.y_shift = value.into();
+            self
+        }
+        #[doc = "Mask scaling coefficient. (For example, 2.0 means a doubled size)"]
+        pub fn with_scale(mut self, value: impl Into<f64>) -> Self {
+            self.scale = value.into();
+            self
+        }
+    }
+    impl Validate for MaskPosition {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.point
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "point"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes one answer option of a poll"]
+    pub struct PollOption {
+        #[doc = "Option text, 1-100 characters"]
+        pub text: String,
+        #[doc = "Number of voters for this option, available only for closed or voted polls"]
+        pub voter_count: i32,
+        #[doc = "The percentage of votes for this option, 0-100"]
+        pub vote_percentage: i32,
+        #[doc = "True, if the option was chosen by the user"]
+        pub is_chosen: bool,
+        #[doc = "True, if the option is being chosen by a pending setPollAnswer request"]
+        pub is_being_chosen: bool,
+    }
+    impl TlObject for PollOption {
+        const CLASS: &'static str = "PollOption";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PollOption {
+        const TYPE: &'static str = "pollOption";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A regular poll"]
+    pub struct PollTypeRegular {
+        #[doc = "True, if multiple answer options can be chosen simultaneously"]
+        pub allow_multiple_answers: bool,
+    }
+    impl TlObject for PollTypeRegular {
+        const CLASS: &'static str = "PollType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PollTypeRegular {
+        const TYPE: &'static str = "pollTypeRegular";
+    }
+    impl PollTypeRegular {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "True, if multiple answer options can be chosen simultaneously"]
+        pub fn with_allow_multiple_answers(mut self, value: impl Into<bool>) -> Self {
+            self.allow_multiple_answers = value.into();
+            self
+        }
+    }
+    impl Validate for PollTypeRegular {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A poll in quiz mode, which has exactly one correct answer option and can be answered only once"]
+    pub struct PollTypeQuiz {
+        #[doc = "0-based identifier of the correct answer option; -1 for a yet unanswered poll"]
+        pub correct_option_id: i32,
+    }
+    impl TlObject for PollTypeQuiz {
+        const CLASS: &'static str = "PollType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PollTypeQuiz {
+        const TYPE: &'static str = "pollTypeQuiz";
+    }
+    impl PollTypeQuiz {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "0-based identifier of the correct answer option; -1 for a yet unanswered poll"]
+        pub fn with_correct_option_id(mut self, value: impl Into<i32>) -> Self {
+            self.correct_option_id = value.into();
+            self
+        }
+    }
+    impl Validate for PollTypeQuiz {}
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Describes the type of a poll"]
+    pub enum PollType {
+        PollTypeRegular(PollTypeRegular),
+        PollTypeQuiz(PollTypeQuiz),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for PollType {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "pollTypeRegular" => Deserialize::deserialize(value).map(PollType::PollTypeRegular),
+                "pollTypeQuiz" => Deserialize::deserialize(value).map(PollType::PollTypeQuiz),
+                _ => Unknown::deserialize(value).map(PollType::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for PollType {
+        const CLASS: &'static str = "PollType";
+        fn type_name(&self) -> &str {
+            match self {
+                PollType::PollTypeRegular(_) => "pollTypeRegular",
+                PollType::PollTypeQuiz(_) => "pollTypeQuiz",
+                PollType::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for PollType {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                PollType::PollTypeRegular(value) => value.validate(),
+                PollType::PollTypeQuiz(value) => value.validate(),
+                PollType::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<PollTypeRegular> for PollType {
+        fn from(value: PollTypeRegular) -> Self {
+            PollType::PollTypeRegular(value)
+        }
+    }
+    impl From<PollTypeQuiz> for PollType {
+        fn from(value: PollTypeQuiz) -> Self {
+            PollType::PollTypeQuiz(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes an animation file. The animation must be encoded in GIF or MPEG4 format"]
+    pub struct Animation {
+        #[doc = "Duration of the animation, in seconds; as defined by the sender"]
+        pub duration: i32,
+        #[doc = "Width of the animation"]
+        pub width: i32,
+        #[doc = "Height of the animation"]
+        pub height: i32,
+        #[doc = "Original name of the file; as defined by the sender"]
+        pub file_name: String,
+        #[doc = "MIME type of the file, usually \"image/gif\" or \"video/mp4\""]
+        pub mime_type: String,
+        #[serde(default)]
+        #[doc = "Animation minithumbnail; may be null"]
+        pub minithumbnail: Option<Minithumbnail>,
+        #[serde(default)]
+        #[doc = "Animation thumbnail; may be null"]
+        pub thumbnail: Option<PhotoSize>,
+        #[doc = "File containing the animation"]
+        pub animation: File,
+    }
+    impl TlObject for Animation {
+        const CLASS: &'static str = "Animation";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Animation {
+        const TYPE: &'static str = "animation";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes an audio file. Audio is usually in MP3 or M4A format"]
+    pub struct Audio {
+        #[doc = "Duration of the audio, in seconds; as defined by the sender"]
+        pub duration: i32,
+        #[doc = "Title of the audio; as defined by the sender"]
+        pub title: String,
+        #[doc = "Performer of the audio; as defined by the sender"]
+        pub performer: String,
+        #[doc = "Original name of the file; as defined by the sender"]
+        pub file_name: String,
+        #[doc = "The MIME type of the file; as defined by the sender"]
+        pub mime_type: String,
+        #[serde(default)]
+        #[doc = "The minithumbnail of the album cover; may be null"]
+        pub album_cover_minithumbnail: Option<Minithumbnail>,
+        #[serde(default)]
+        #[doc = "The thumbnail of the album cover; as defined by the sender. The full size thumbnail should be extracted from the downloaded file; may be null"]
+        pub album_cover_thumbnail: Option<PhotoSize>,
+        #[doc = "File containing the audio"]
+        pub audio: File,
+    }
+    impl TlObject for Audio {
+        const CLASS: &'static str = "Audio";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Audio {
+        const TYPE: &'static str = "audio";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a document of any type"]
+    pub struct Document {
+        #[doc = "Original name of the file; as defined by the sender"]
+        pub file_name: String,
+        #[doc = "MIME type of the file; as defined by the sender"]
+        pub mime_type: String,
+        #[serde(default)]
+        #[doc = "Document minithumbnail; may be null"]
+        pub minithumbnail: Option<Minithumbnail>,
+        #[serde(default)]
+        #[doc = "Document thumbnail in JPEG or PNG format (PNG will be used only for background patterns); as defined by the sender; may be null"]
+        pub thumbnail: Option<PhotoSize>,
+        #[doc = "File containing the document"]
+        pub document: File,
+    }
+    impl TlObject for Document {
+        const CLASS: &'static str = "Document";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Document {
+        const TYPE: &'static str = "document";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a photo"]
+    pub struct Photo {
+        #[doc = "True, if stickers were added to the photo"]
+        pub has_stickers: bool,
+        #[serde(default)]
+        #[doc = "Photo minithumbnail; may be null"]
+        pub minithumbnail: Option<Minithumbnail>,
+        #[doc = "Available variants of the photo, in different sizes"]
+        pub sizes: Vec<PhotoSize>,
+    }
+    impl TlObject for Photo {
+        const CLASS: &'static str = "Photo";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Photo {
+        const TYPE: &'static str = "photo";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a sticker"]
+    pub struct Sticker {
+        #[serde(
+            deserialize_with = "crate::utils::from_str_to_t",
+            serialize_with = "crate::utils::t_to_str"
+        )]
+        #[doc = "The identifier of the sticker set to which the sticker belongs; 0 if none"]
+        pub set_id: i64,
+        #[doc = "Sticker width; as defined by the sender"]
+        pub width: i32,
+        #[doc = "Sticker height; as defined by the sender"]
+        pub height: i32,
+        #[doc = "Emoji corresponding to the sticker"]
+        pub emoji: String,
+        #[doc = "True, if the sticker is an animated sticker in TGS format"]
+        pub is_animated: bool,
+        #[doc = "True, if the sticker is a mask"]
+        pub is_mask: bool,
+        #[serde(default)]
+        #[doc = "Position where the mask should be placed; may be null"]
+        pub mask_position: Option<MaskPosition>,
+        #[serde(default)]
+        #[doc = "Sticker thumbnail in WEBP or JPEG format; may be null"]
+        pub thumbnail: Option<PhotoSize>,
+        #[doc = "File containing the sticker"]
+        pub sticker: File,
+    }
+    impl TlObject for Sticker {
+        const CLASS: &'static str = "Sticker";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Sticker {
+        const TYPE: &'static str = "sticker";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a video file"]
+    pub struct Video {
+        #[doc = "Duration of the video, in seconds; as defined by the sender"]
+        pub duration: i32,
+        #[doc = "Video width; as defined by the sender"]
+        pub width: i32,
+        #[doc = "Video height; as defined by the sender"]
+        pub height: i32,
+        #[doc = "Original name of the file; as defined by the sender"]
+        pub file_name: String,
+        #[doc = "MIME type of the file; as defined by the sender"]
+        pub mime_type: String,
+        #[doc = "True, if stickers were added to the video"]
+        pub has_stickers: bool,
+        #[doc = "True, if the video should be tried to be streamed"]
+        pub supports_streaming: bool,
+        #[serde(default)]
+        #[doc = "Video minithumbnail; may be null"]
+        pub minithumbnail: Option<Minithumbnail>,
+        #[serde(default)]
+        #[doc = "Video thumbnail; as defined by the sender; may be null"]
+        pub thumbnail: Option<PhotoSize>,
+        #[doc = "File containing the video"]
+        pub video: File,
+    }
+    impl TlObject for Video {
+        const CLASS: &'static str = "Video";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Video {
+        const TYPE: &'static str = "video";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a video note. The video must be equal in width and height, cropped to a circle, and stored in MPEG4 format"]
+    pub struct VideoNote {
+        #[doc = "Duration of the video, in seconds; as defined by the sender"]
+        pub duration: i32,
+        #[doc = "Video width and height; as defined by the sender"]
+        pub length: i32,
+        #[serde(default)]
+        #[doc = "Video minithumbnail; may be null"]
+        pub minithumbnail: Option<Minithumbnail>,
+        #[serde(default)]
+        #[doc = "Video thumbnail; as defined by the sender; may be null"]
+        pub thumbnail: Option<PhotoSize>,
+        #[doc = "File containing the video"]
+        pub video: File,
+    }
+    impl TlObject for VideoNote {
+        const CLASS: &'static str = "VideoNote";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for VideoNote {
+        const TYPE: &'static str = "videoNote";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a voice note. The voice note must be encoded with the Opus codec, and stored inside an OGG container. Voice notes can have only a single audio channel"]
+    pub struct VoiceNote {
+        #[doc = "Duration of the voice note, in seconds; as defined by the sender"]
+        pub duration: i32,
+        #[doc = "A waveform representation of the voice note in 5-bit format"]
+        pub waveform: String,
+        #[doc = "MIME type of the file; as defined by the sender"]
+        pub mime_type: String,
+        #[doc = "File containing the voice note"]
+        pub voice: File,
+    }
+    impl TlObject for VoiceNote {
+        const CLASS: &'static str = "VoiceNote";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for VoiceNote {
+        const TYPE: &'static str = "voiceNote";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a user contact"]
+    pub struct Contact {
+        #[doc = "Phone number of the user"]
+        pub phone_number: String,
+        #[doc = "First name of the user; 1-255 characters in length"]
+        pub first_name: String,
+        #[doc = "Last name of the user"]
+        pub last_name: String,
+        #[doc = "Additional data about the user in a form of vCard; 0-2048 bytes in length"]
+        pub vcard: String,
+        #[doc = "Identifier of the user, if known; otherwise 0"]
+        pub user_id: UserId,
+    }
+    impl TlObject for Contact {
+        const CLASS: &'static str = "Contact";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Contact {
+        const TYPE: &'static str = "contact";
+    }
+    impl Contact {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(first_name: impl Into<String>) -> Self {
+            Self {
+                phone_number: Default::default(),
+                first_name: first_name.into(),
+                last_name: Default::default(),
+                vcard: Default::default(),
+                user_id: Default::default(),
+            }
+        }
+        #[doc = "Phone number of the user"]
+        pub fn with_phone_number(mut self, value: impl Into<String>) -> Self {
+            self.phone_number = value.into();
+            self
+        }
+        #[doc = "Last name of the user"]
+        pub fn with_last_name(mut self, value: impl Into<String>) -> Self {
+            self.last_name = value.into();
+            self
+        }
+        #[doc = "Additional data about the user in a form of vCard; 0-2048 bytes in length"]
+        pub fn with_vcard(mut self, value: impl Into<String>) -> Self {
+            self.vcard = value.into();
+            self
+        }
+        #[doc = "Identifier of the user, if known; otherwise 0"]
+        pub fn with_user_id(mut self, value: impl Into<UserId>) -> Self {
+            self.user_id = value.into();
+            self
+        }
+    }
+    impl Validate for Contact {
+        fn validate(&self) -> Result<(), ValidationError> {
+            validate::check(
+                Self::TYPE,
+                "first_name",
+                Measure::Characters,
+                self.first_name.chars().count() as i64,
+                Some(1),
+                Some(255),
+            )?;
+            validate::check(
+                Self::TYPE,
+                "vcard",
+                Measure::Bytes,
+                self.vcard.len() as i64,
+                Some(0),
+                Some(2048),
+            )?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Describes a location on planet Earth"]
+    pub struct Location {
+        #[doc = "Latitude of the location in degrees; as defined by the sender"]
+        pub latitude: f64,
+        #[doc = "Longitude of the location, in degrees; as defined by the sender"]
+        pub longitude: f64,
+    }
+    impl TlObject for Location {
+        const CLASS: &'static str = "Location";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Location {
+        const TYPE: &'static str = "location";
+    }
+    impl Location {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Latitude of the location in degrees; as defined by the sender"]
+        pub fn with_latitude(mut self, value: impl Into<f64>) -> Self {
+            self.latitude = value.into();
+            self
+        }
+        #[doc = "Longitude of the location, in degrees; as defined by the sender"]
+        pub fn with_longitude(mut self, value: impl Into<f64>) -> Self {
+            self.longitude = value.into();
+            self
+        }
+    }
+    impl Validate for Location {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Describes a venue"]
+    pub struct Venue {
+        #[doc = "Venue location; as defined by the sender"]
+        pub location: Location,
+        #[doc = "Venue name; as defined by the sender"]
+        pub title: String,
+        #[doc = "Venue address; as defined by the sender"]
+        pub address: String,
+        #[doc = "Provider of the venue database; as defined by the sender. Currently only \"foursquare\" needs to be supported"]
+        pub provider: String,
+        #[doc = "Identifier of the venue in the provider database; as defined by the sender"]
+        pub id: String,
+        #[serde(rename = "type")]
+        #[doc = "Type of the venue in the provider database; as defined by the sender"]
+        pub type_: String,
+    }
+    impl TlObject for Venue {
+        const CLASS: &'static str = "Venue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Venue {
+        const TYPE: &'static str = "venue";
+    }
+    impl Venue {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Venue location; as defined by the sender"]
+        pub fn with_location(mut self, value: impl Into<Location>) -> Self {
+            self.location = value.into();
+            self
+        }
+        #[doc = "Venue name; as defined by the sender"]
+        pub fn with_title(mut self, value: impl Into<String>) -> Self {
+            self.title = value.into();
+            self
+        }
+        #[doc = "Venue address; as defined by the sender"]
+        pub fn with_address(mut self, value: impl Into<String>) -> Self {
+            self.address = value.into();
+            self
+        }
+        #[doc = "Provider of the venue database; as defined by the sender. Currently only \"foursquare\" needs to be supported"]
+        pub fn with_provider(mut self, value: impl Into<String>) -> Self {
+            self.provider = value.into();
+            self
+        }
+        #[doc = "Identifier of the venue in the provider database; as defined by the sender"]
+        pub fn with_id(mut self, value: impl Into<String>) -> Self {
+            self.id = value.into();
+            self
+        }
+        #[doc = "Type of the venue in the provider database; as defined by the sender"]
+        pub fn with_type(mut self, value: impl Into<String>) -> Self {
+            self.type_ = value.into();
+            self
+        }
+    }
+    impl Validate for Venue {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.location
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "location"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a game"]
+    pub struct Game {
+        #[serde(
+            deserialize_with = "crate::utils::from_str_to_t",
+            serialize_with = "crate::utils::t_to_str"
+        )]
+        #[doc = "Game ID"]
+        pub id: i64,
+        #[doc = "Game short name. To share a game use the URL https://t.me/{bot_username}?game={game_short_name}"]
+        pub short_name: String,
+        #[doc = "Game title"]
+        pub title: String,
+        #[doc = "Game text, usually containing scoreboards for a game"]
+        pub text: FormattedText,
+        #[doc = "Describes a game"]
+        pub description: String,
+        #[doc = "Game photo"]
+        pub photo: Photo,
+        #[serde(default)]
+        #[doc = "Game animation; may be null"]
+        pub animation: Option<Animation>,
+    }
+    impl TlObject for Game {
+        const CLASS: &'static str = "Game";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Game {
+        const TYPE: &'static str = "game";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a poll"]
+    pub struct Poll {
+        #[serde(
+            deserialize_with = "crate::utils::from_str_to_t",
+            serialize_with = "crate::utils::t_to_str"
+        )]
+        #[doc = "Unique poll identifier"]
+        pub id: i64,
+        #[doc = "Poll question, 1-255 characters"]
+        pub question: String,
+        #[doc = "List of poll answer options"]
+        pub options: Vec<PollOption>,
+        #[doc = "Total number of voters, participating in the poll"]
+        pub total_voter_count: i32,
+        #[doc = "User identifiers of recent voters, if the poll is non-anonymous"]
+        pub recent_voter_user_ids: Vec<UserId>,
+        #[doc = "True, if the poll is anonymous"]
+        pub is_anonymous: bool,
+        #[serde(rename = "type")]
+        #[doc = "Type of the poll"]
+        pub type_: PollType,
+        #[doc = "True, if the poll is closed"]
+        pub is_closed: bool,
+    }
+    impl TlObject for Poll {
+        const CLASS: &'static str = "Poll";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Poll {
+        const TYPE: &'static str = "poll";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a user profile photo"]
+    pub struct ProfilePhoto {
+        #[serde(
+            deserialize_with = "crate::utils::from_str_to_t",
+            serialize_with = "crate::utils::t_to_str"
+        )]
+        #[doc = "Photo identifier; 0 for an empty photo. Can be used to find a photo in a list of userProfilePhotos"]
+        pub id: i64,
+        #[doc = "A small (160x160) user profile photo. The file can be downloaded only before the photo is changed"]
+        pub small: File,
+        #[doc = "A big (640x640) user profile photo. The file can be downloaded only before the photo is changed"]
+        pub big: File,
+    }
+    impl TlObject for ProfilePhoto {
+        const CLASS: &'static str = "ProfilePhoto";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ProfilePhoto {
+        const TYPE: &'static str = "profilePhoto";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes the photo of a chat"]
+    pub struct ChatPhoto {
+        #[doc = "A small (160x160) chat photo. The file can be downloaded only before the photo is changed"]
+        pub small: File,
+        #[doc = "A big (640x640) chat photo. The file can be downloaded only before the photo is changed"]
+        pub big: File,
+    }
+    impl TlObject for ChatPhoto {
+        const CLASS: &'static str = "ChatPhoto";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ChatPhoto {
+        const TYPE: &'static str = "chatPhoto";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A regular user"]
+    pub struct UserTypeRegular {}
+    impl TlObject for UserTypeRegular {
+        const CLASS: &'static str = "UserType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserTypeRegular {
+        const TYPE: &'static str = "userTypeRegular";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A deleted user or deleted bot. No information on the user besides the user identifier is available. It is not possible to perform any active actions on this type of user"]
+    pub struct UserTypeDeleted {}
+    impl TlObject for UserTypeDeleted {
+        const CLASS: &'static str = "UserType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserTypeDeleted {
+        const TYPE: &'static str = "userTypeDeleted";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A bot (see https://core.telegram.org/bots)"]
+    pub struct UserTypeBot {
+        #[doc = "True, if the bot can be invited to basic group and supergroup chats"]
+        pub can_join_groups: bool,
+        #[doc = "True, if the bot can read all messages in basic group or supergroup chats and not just those addressed to the bot. In private and channel chats a bot can always read all messages"]
+        pub can_read_all_group_messages: bool,
+        #[doc = "True, if the bot supports inline queries"]
+        pub is_inline: bool,
+        #[doc = "Placeholder for inline queries (displayed on the client input field)"]
+        pub inline_query_placeholder: String,
+        #[doc = "True, if the location of the user should be sent with every inline query to this bot"]
+        pub need_location: bool,
+    }
+    impl TlObject for UserTypeBot {
+        const CLASS: &'static str = "UserType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserTypeBot {
+        const TYPE: &'static str = "userTypeBot";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "No information on the user besides the user identifier is available, yet this user has not been deleted. This object is extremely rare and must be handled like a deleted user. It is not possible to perform any actions on users of this type"]
+    pub struct UserTypeUnknown {}
+    impl TlObject for UserTypeUnknown {
+        const CLASS: &'static str = "UserType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserTypeUnknown {
+        const TYPE: &'static str = "userTypeUnknown";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Represents the type of a user. The following types are possible: regular users, deleted users and bots"]
+    pub enum UserType {
+        UserTypeRegular(UserTypeRegular),
+        UserTypeDeleted(UserTypeDeleted),
+        UserTypeBot(UserTypeBot),
+        UserTypeUnknown(UserTypeUnknown),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for UserType {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "userTypeRegular" => Deserialize::deserialize(value).map(UserType::UserTypeRegular),
+                "userTypeDeleted" => Deserialize::deserialize(value).map(UserType::UserTypeDeleted),
+                "userTypeBot" => Deserialize::deserialize(value).map(UserType::UserTypeBot),
+                "userTypeUnknown" => Deserialize::deserialize(value).map(UserType::UserTypeUnknown),
+                _ => Unknown::deserialize(value).map(UserType::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for UserType {
+        const CLASS: &'static str = "UserType";
+        fn type_name(&self) -> &str {
+            match self {
+                UserType::UserTypeRegular(_) => "userTypeRegular",
+                UserType::UserTypeDeleted(_) => "userTypeDeleted",
+                UserType::UserTypeBot(_) => "userTypeBot",
+                UserType::UserTypeUnknown(_) => "userTypeUnknown",
+                UserType::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Represents commands supported by a bot"]
+    pub struct BotCommand {
+        #[doc = "Text of the bot command"]
+        pub command: String,
+        #[doc = "Represents commands supported by a bot"]
+        pub description: String,
+    }
+    impl TlObject for BotCommand {
+        const CLASS: &'static str = "BotCommand";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for BotCommand {
+        const TYPE: &'static str = "botCommand";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Provides information about a bot and its supported commands"]
+    pub struct BotInfo {
+        #[doc = "Provides information about a bot and its supported commands"]
+        pub description: String,
+        #[doc = "A list of commands supported by the bot"]
+        pub commands: Vec<BotCommand>,
+    }
+    impl TlObject for BotInfo {
+        const CLASS: &'static str = "BotInfo";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for BotInfo {
+        const TYPE: &'static str = "botInfo";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Represents a user"]
+    pub struct User {
+        #[doc = "User identifier"]
+        pub id: UserId,
+        #[doc = "First name of the user"]
+        pub first_name: String,
+        #[doc = "Last name of the user"]
+        pub last_name: String,
+        #[doc = "Username of the user"]
+        pub username: String,
+        #[doc = "Phone number of the user"]
+        pub phone_number: String,
+        #[doc = "Current online status of the user"]
+        pub status: UserStatus,
+        #[serde(default)]
+        #[doc = "Profile photo of the user; may be null"]
+        pub profile_photo: Option<ProfilePhoto>,
+        #[doc = "The user is a contact of the current user"]
+        pub is_contact: bool,
+        #[doc = "The user is a contact of the current user and the current user is a contact of the user"]
+        pub is_mutual_contact: bool,
+        #[doc = "True, if the user is verified"]
+        pub is_verified: bool,
+        #[doc = "True, if the user is Telegram support account"]
+        pub is_support: bool,
+        #[doc = "If non-empty, it contains a human-readable description of the reason why access to this user must be restricted"]
+        pub restriction_reason: String,
+        #[doc = "True, if many users reported this user as a scam"]
+        pub is_scam: bool,
+        #[doc = "If false, the user is inaccessible, and the only information known about the user is inside this class. It can't be passed to any method except GetUser"]
+        pub have_access: bool,
+        #[serde(rename = "type")]
+        #[doc = "Type of the user"]
+        pub type_: UserType,
+        #[serde(default)]
+        #[doc = "IETF language tag of the user's language; only available to bots"]
+        pub language_code: Option<String>,
+    }
+    impl TlObject for User {
+        const CLASS: &'static str = "User";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for User {
+        const TYPE: &'static str = "user";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains full information about a user (except the full list of profile photos)"]
+    pub struct UserFullInfo {
+        #[doc = "True, if the user is blacklisted by the current user"]
+        pub is_blocked: bool,
+        #[doc = "True, if the user can be called"]
+        pub can_be_called: bool,
+        #[doc = "True, if the user can't be called due to their privacy settings"]
+        pub has_private_calls: bool,
+        #[doc = "True, if the current user needs to explicitly allow to share their phone number with the user when the method addContact is used"]
+        pub need_phone_number_privacy_exception: bool,
+        #[doc = "A short user bio"]
+        pub bio: String,
+        #[doc = "For bots, the text that is included with the link when users share the bot"]
+        pub share_text: String,
+        #[doc = "Number of group chats where both the other user and the current user are a member; 0 for the current user"]
+        pub group_in_common_count: i32,
+        #[serde(default)]
+        #[doc = "If the user is a bot, information about the bot; may be null"]
+        pub bot_info: Option<BotInfo>,
+    }
+    impl TlObject for UserFullInfo {
+        const CLASS: &'static str = "UserFullInfo";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserFullInfo {
+        const TYPE: &'static str = "userFullInfo";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains full information about a user profile photo"]
+    pub struct UserProfilePhoto {
+        #[serde(
+            deserialize_with = "crate::utils::from_str_to_t",
+            serialize_with = "crate::utils::t_to_str"
+        )]
+        #[doc = "Unique user profile photo identifier"]
+        pub id: i64,
+        #[doc = "Point in time (Unix timestamp) when the photo has been added"]
+        pub added_date: i32,
+        #[doc = "Available variants of the user photo, in different sizes"]
+        pub sizes: Vec<PhotoSize>,
+    }
+    impl TlObject for UserProfilePhoto {
+        const CLASS: &'static str = "UserProfilePhoto";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserProfilePhoto {
+        const TYPE: &'static str = "userProfilePhoto";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains part of the list of user photos"]
+    pub struct UserProfilePhotos {
+        #[doc = "Total number of user profile photos"]
+        pub total_count: i32,
+        #[doc = "A list of photos"]
+        pub photos: Vec<UserProfilePhoto>,
+    }
+    impl TlObject for UserProfilePhotos {
+        const CLASS: &'static str = "UserProfilePhotos";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserProfilePhotos {
+        const TYPE: &'static str = "userProfilePhotos";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Represents a list of users"]
+    pub struct Users {
+        #[doc = "Approximate total count of users found"]
+        pub total_count: i32,
+        #[doc = "A list of user identifiers"]
+        pub user_ids: Vec<UserId>,
+    }
+    impl TlObject for Users {
+        const CLASS: &'static str = "Users";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Users {
+        const TYPE: &'static str = "users";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Notification settings applied to all private and secret chats when the corresponding chat setting has a default value"]
+    pub struct NotificationSettingsScopePrivateChats {}
+    impl TlObject for NotificationSettingsScopePrivateChats {
+        const CLASS: &'static str = "NotificationSettingsScope";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NotificationSettingsScopePrivateChats {
+        const TYPE: &'static str = "notificationSettingsScopePrivateChats";
+    }
+    impl NotificationSettingsScopePrivateChats {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for NotificationSettingsScopePrivateChats {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Notification settings applied to all basic groups and supergroups when the corresponding chat setting has a default value"]
+    pub struct NotificationSettingsScopeGroupChats {}
+    impl TlObject for NotificationSettingsScopeGroupChats {
+        const CLASS: &'static str = "NotificationSettingsScope";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NotificationSettingsScopeGroupChats {
+        const TYPE: &'static str = "notificationSettingsScopeGroupChats";
+    }
+    impl NotificationSettingsScopeGroupChats {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for NotificationSettingsScopeGroupChats {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Notification settings applied to all channels when the corresponding chat setting has a default value"]
+    pub struct NotificationSettingsScopeChannelChats {}
+    impl TlObject for NotificationSettingsScopeChannelChats {
+        const CLASS: &'static str = "NotificationSettingsScope";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NotificationSettingsScopeChannelChats {
+        const TYPE: &'static str = "notificationSettingsScopeChannelChats";
+    }
+    impl NotificationSettingsScopeChannelChats {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for NotificationSettingsScopeChannelChats {}
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Describes the types of chats to which notification settings are applied"]
+    pub enum NotificationSettingsScope {
+        NotificationSettingsScopePrivateChats(NotificationSettingsScopePrivateChats),
+        NotificationSettingsScopeGroupChats(NotificationSettingsScopeGroupChats),
+        NotificationSettingsScopeChannelChats(NotificationSettingsScopeChannelChats),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for NotificationSettingsScope {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "notificationSettingsScopePrivateChats" => Deserialize::deserialize(value)
+                    .map(NotificationSettingsScope::NotificationSettingsScopePrivateChats),
+                "notificationSettingsScopeGroupChats" => Deserialize::deserialize(value)
+                    .map(NotificationSettingsScope::NotificationSettingsScopeGroupChats),
+                "notificationSettingsScopeChannelChats" => Deserialize::deserialize(value)
+                    .map(NotificationSettingsScope::NotificationSettingsScopeChannelChats),
+                _ => Unknown::deserialize(value).map(NotificationSettingsScope::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for NotificationSettingsScope {
+        const CLASS: &'static str = "NotificationSettingsScope";
+        fn type_name(&self) -> &str {
+            match self {
+                NotificationSettingsScope::NotificationSettingsScopePrivateChats(_) => {
+                    "notificationSettingsScopePrivateChats"
+                }
+                NotificationSettingsScope::NotificationSettingsScopeGroupChats(_) => {
+                    "notificationSettingsScopeGroupChats"
+                }
+                NotificationSettingsScope::NotificationSettingsScopeChannelChats(_) => {
+                    "notificationSettingsScopeChannelChats"
+                }
+                NotificationSettingsScope::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for NotificationSettingsScope {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                NotificationSettingsScope::NotificationSettingsScopePrivateChats(value) => {
+                    value.validate()
+                }
+                NotificationSettingsScope::NotificationSettingsScopeGroupChats(value) => {
+                    value.validate()
+                }
+                NotificationSettingsScope::NotificationSettingsScopeChannelChats(value) => {
+                    value.validate()
+                }
+                NotificationSettingsScope::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<NotificationSettingsScopePrivateChats> for NotificationSettingsScope {
+        fn from(value: NotificationSettingsScopePrivateChats) -> Self {
+            NotificationSettingsScope::NotificationSettingsScopePrivateChats(value)
+        }
+    }
+    impl From<NotificationSettingsScopeGroupChats> for NotificationSettingsScope {
+        fn from(value: NotificationSettingsScopeGroupChats) -> Self {
+            NotificationSettingsScope::NotificationSettingsScopeGroupChats(value)
+        }
+    }
+    impl From<NotificationSettingsScopeChannelChats> for NotificationSettingsScope {
+        fn from(value: NotificationSettingsScopeChannelChats) -> Self {
+            NotificationSettingsScope::NotificationSettingsScopeChannelChats(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Contains information about notification settings for several chats"]
+    pub struct ScopeNotificationSettings {
+        #[doc = "Time left before notifications will be unmuted, in seconds"]
+        pub mute_for: i32,
+        #[doc = "The name of an audio file to be used for notification sounds; only applies to iOS applications"]
+        pub sound: String,
+        #[doc = "True, if message content should be displayed in notifications"]
+        pub show_preview: bool,
+        #[doc = "True, if notifications for incoming pinned messages will be created as for an ordinary unread message"]
+        pub disable_pinned_message_notifications: bool,
+        #[doc = "True, if notifications for messages with mentions will be created as for an ordinary unread message"]
+        pub disable_mention_notifications: bool,
+    }
+    impl TlObject for ScopeNotificationSettings {
+        const CLASS: &'static str = "ScopeNotificationSettings";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ScopeNotificationSettings {
+        const TYPE: &'static str = "scopeNotificationSettings";
+    }
+    impl ScopeNotificationSettings {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Time left before notifications will be unmuted, in seconds"]
+        pub fn with_mute_for(mut self, value: impl Into<i32>) -> Self {
+            self.mute_for = value.into();
+            self
+        }
+        #[doc = "The name of an audio file to be used for notification sounds; only applies to iOS applications"]
+        pub fn with_sound(mut self, value: impl Into<String>) -> Self {
+            self.sound = value.into();
+            self
+        }
+        #[doc = "True, if message content should be displayed in notifications"]
+        pub fn with_show_preview(mut self, value: impl Into<bool>) -> Self {
+            self.show_preview = value.into();
+            self
+        }
+        #[doc = "True, if notifications for incoming pinned messages will be created as for an ordinary unread message"]
+        pub fn with_disable_pinned_message_notifications(mut self, value: impl Into<bool>) -> Self {
+            self.disable_pinned_message_notifications = value.into();
+            self
+        }
+        #[doc = "True, if notifications for messages with mentions will be created as for an ordinary unread message"]
+        pub fn with_disable_mention_notifications(mut self, value: impl Into<bool>) -> Self {
+            self.disable_mention_notifications = value.into();
+            self
+        }
+    }
+    impl Validate for ScopeNotificationSettings {}
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An ordinary chat with a user"]
+    pub struct ChatTypePrivate {
+        #[doc = "User identifier"]
+        pub user_id: UserId,
+    }
+    impl TlObject for ChatTypePrivate {
+        const CLASS: &'static str = "ChatType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ChatTypePrivate {
+        const TYPE: &'static str = "chatTypePrivate";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A basic group (i.e., a chat with 0-200 other users)"]
+    pub struct ChatTypeBasicGroup {
+        #[doc = "Basic group identifier"]
+        pub basic_group_id: BasicGroupId,
+    }
+    impl TlObject for ChatTypeBasicGroup {
+        const CLASS: &'static str = "ChatType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ChatTypeBasicGroup {
+        const TYPE: &'static str = "chatTypeBasicGroup";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A supergroup (i.e. a chat with up to GetOption(\"supergroup_max_size\") other users), or channel (with unlimited members)"]
+    pub struct ChatTypeSupergroup {
+        #[doc = "Supergroup or channel identifier"]
+        pub supergroup_id: SupergroupId,
+        #[doc = "True, if the supergroup is a channel"]
+        pub is_channel: bool,
+    }
+    impl TlObject for ChatTypeSupergroup {
+        const CLASS: &'static str = "ChatType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ChatTypeSupergroup {
+        const TYPE: &'static str = "chatTypeSupergroup";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A secret chat with a user"]
+    pub struct ChatTypeSecret {
+        #[doc = "Secret chat identifier"]
+        pub secret_chat_id: SecretChatId,
+        #[doc = "User identifier of the secret chat peer"]
+        pub user_id: UserId,
+    }
+    impl TlObject for ChatTypeSecret {
+        const CLASS: &'static str = "ChatType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ChatTypeSecret {
+        const TYPE: &'static str = "chatTypeSecret";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Describes the type of a chat"]
+    pub enum ChatType {
+        ChatTypePrivate(ChatTypePrivate),
+        ChatTypeBasicGroup(ChatTypeBasicGroup),
+        ChatTypeSupergroup(ChatTypeSupergroup),
+        ChatTypeSecret(ChatTypeSecret),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for ChatType {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "chatTypePrivate" => Deserialize::deserialize(value).map(ChatType::ChatTypePrivate),
+                "chatTypeBasicGroup" => {
+                    Deserialize::deserialize(value).map(ChatType::ChatTypeBasicGroup)
+                }
+                "chatTypeSupergroup" => {
+                    Deserialize::deserialize(value).map(ChatType::ChatTypeSupergroup)
+                }
+                "chatTypeSecret" => Deserialize::deserialize(value).map(ChatType::ChatTypeSecret),
+                _ => Unknown::deserialize(value).map(ChatType::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for ChatType {
+        const CLASS: &'static str = "ChatType";
+        fn type_name(&self) -> &str {
+            match self {
+                ChatType::ChatTypePrivate(_) => "chatTypePrivate",
+                ChatType::ChatTypeBasicGroup(_) => "chatTypeBasicGroup",
+                ChatType::ChatTypeSupergroup(_) => "chatTypeSupergroup",
+                ChatType::ChatTypeSecret(_) => "chatTypeSecret",
+                ChatType::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a chat located nearby"]
+    pub struct ChatNearby {
+        #[doc = "Chat identifier"]
+        pub chat_id: ChatId,
+        #[doc = "Distance to the chat location in meters"]
+        pub distance: i32,
+    }
+    impl TlObject for ChatNearby {
+        const CLASS: &'static str = "ChatNearby";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ChatNearby {
+        const TYPE: &'static str = "chatNearby";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains information about a chat invite link"]
+    pub struct ChatInviteLinkInfo {
+        #[doc = "Chat identifier of the invite link; 0 if the user is not a member of this chat"]
+        pub chat_id: ChatId,
+        #[serde(rename = "type")]
+        #[doc = "Contains information about the type of the chat"]
+        pub type_: ChatType,
+        #[doc = "Title of the chat"]
+        pub title: String,
+        #[serde(default)]
+        #[doc = "Chat photo; may be null"]
+        pub photo: Option<ChatPhoto>,
+        #[doc = "Number of members"]
+        pub member_count: i32,
+        #[doc = "User identifiers of some chat members that may be known to the current user"]
+        pub member_user_ids: Vec<UserId>,
+        #[doc = "True, if the chat is a public supergroup or channel, i.e. it has a username or it is a location-based supergroup"]
+        pub is_public: bool,
+    }
+    impl TlObject for ChatInviteLinkInfo {
+        const CLASS: &'static str = "ChatInviteLinkInfo";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ChatInviteLinkInfo {
+        const TYPE: &'static str = "chatInviteLinkInfo";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a web page preview"]
+    pub struct WebPage {
+        #[doc = "Original URL of the link"]
+        pub url: String,
+        #[doc = "URL to display"]
+        pub display_url: String,
+        #[serde(rename = "type")]
+        #[doc = "Type of the web page. Can be: article, photo, audio, video, document, profile, app, or something else"]
+        pub type_: String,
+        #[doc = "Short name of the site (e.g., Google Docs, App Store)"]
+        pub site_name: String,
+        #[doc = "Title of the content"]
+        pub title: String,
+        #[doc = "Describes a web page preview"]
+        pub description: String,
+        #[serde(default)]
+        #[doc = "Image representing the content; may be null"]
+        pub photo: Option<Photo>,
+        #[doc = "URL to show in the embedded preview"]
+        pub embed_url: String,
+        #[doc = "MIME type of the embedded preview, (e.g., text/html or video/mp4)"]
+        pub embed_type: String,
+        #[doc = "Width of the embedded preview"]
+        pub embed_width: i32,
+        #[doc = "Height of the embedded preview"]
+        pub embed_height: i32,
+        #[doc = "Duration of the content, in seconds"]
+        pub duration: i32,
+        #[doc = "Author of the content"]
+        pub author: String,
+        #[serde(default)]
+        #[doc = "Preview of the content as an animation, if available; may be null"]
+        pub animation: Option<Animation>,
+        #[serde(default)]
+        #[doc = "Preview of the content as an audio file, if available; may be null"]
+        pub audio: Option<Audio>,
+        #[serde(default)]
+        #[doc = "Preview of the content as a document, if available (currently only available for small PDF files and ZIP archives); may be null"]
+        pub document: Option<Document>,
+        #[serde(default)]
+        #[doc = "Preview of the content as a sticker for small WEBP files, if available; may be null"]
+        pub sticker: Option<Sticker>,
+        #[serde(default)]
+        #[doc = "Preview of the content as a video, if available; may be null"]
+        pub video: Option<Video>,
+        #[serde(default)]
+        #[doc = "Preview of the content as a video note, if available; may be null"]
+        pub video_note: Option<VideoNote>,
+        #[serde(default)]
+        #[doc = "Preview of the content as a voice note, if available; may be null"]
+        pub voice_note: Option<VoiceNote>,
+        #[doc = "Version of instant view, available for the web page (currently can be 1 or 2), 0 if none"]
+        pub instant_view_version: i32,
+    }
+    impl TlObject for WebPage {
+        const CLASS: &'static str = "WebPage";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for WebPage {
+        const TYPE: &'static str = "webPage";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Describes an address"]
+    pub struct Address {
+        #[doc = "A two-letter ISO 3166-1 alpha-2 country code"]
+        pub country_code: String,
+        #[doc = "State, if applicable"]
+        pub state: String,
+        #[doc = "City"]
+        pub city: String,
+        #[doc = "First line of the address"]
+        pub street_line1: String,
+        #[doc = "Second line of the address"]
+        pub street_line2: String,
+        #[doc = "Address postal code"]
+        pub postal_code: String,
+    }
+    impl TlObject for Address {
+        const CLASS: &'static str = "Address";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Address {
+        const TYPE: &'static str = "address";
+    }
+    impl Address {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "A two-letter ISO 3166-1 alpha-2 country code"]
+        pub fn with_country_code(mut self, value: impl Into<String>) -> Self {
+            self.country_code = value.into();
+            self
+        }
+        #[doc = "State, if applicable"]
+        pub fn with_state(mut self, value: impl Into<String>) -> Self {
+            self.state = value.into();
+            self
+        }
+        #[doc = "City"]
+        pub fn with_city(mut self, value: impl Into<String>) -> Self {
+            self.city = value.into();
+            self
+        }
+        #[doc = "First line of the address"]
+        pub fn with_street_line1(mut self, value: impl Into<String>) -> Self {
+            self.street_line1 = value.into();
+            self
+        }
+        #[doc = "Second line of the address"]
+        pub fn with_street_line2(mut self, value: impl Into<String>) -> Self {
+            self.street_line2 = value.into();
+            self
+        }
+        #[doc = "Address postal code"]
+        pub fn with_postal_code(mut self, value: impl Into<String>) -> Self {
+            self.postal_code = value.into();
+            self
+        }
+    }
+    impl Validate for Address {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Order information"]
+    pub struct OrderInfo {
+        #[doc = "Name of the user"]
+        pub name: String,
+        #[doc = "Phone number of the user"]
+        pub phone_number: String,
+        #[doc = "Email address of the user"]
+        pub email_address: String,
+        #[serde(default)]
+        #[doc = "Shipping address for this order; may be null"]
+        pub shipping_address: Option<Address>,
+    }
+    impl TlObject for OrderInfo {
+        const CLASS: &'static str = "OrderInfo";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for OrderInfo {
+        const TYPE: &'static str = "orderInfo";
+    }
+    impl OrderInfo {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Name of the user"]
+        pub fn with_name(mut self, value: impl Into<String>) -> Self {
+            self.name = value.into();
+            self
+        }
+        #[doc = "Phone number of the user"]
+        pub fn with_phone_number(mut self, value: impl Into<String>) -> Self {
+            self.phone_number = value.into();
+            self
+        }
+        #[doc = "Email address of the user"]
+        pub fn with_email_address(mut self, value: impl Into<String>) -> Self {
+            self.email_address = value.into();
+            self
+        }
+        #[doc = "Shipping address for this order; may be null"]
+        pub fn with_shipping_address(mut self, value: impl Into<Address>) -> Self {
+            self.shipping_address = Some(value.into());
+            self
+        }
+    }
+    impl Validate for OrderInfo {
+        fn validate(&self) -> Result<(), ValidationError> {
+            if let Some(value) = &self.shipping_address {
+                value
+                    .validate()
+                    .map_err(|err| err.within(Self::TYPE, "shipping_address"))?;
+            }
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "File with the date it was uploaded"]
+    pub struct DatedFile {
+        #[doc = "The file"]
+        pub file: File,
+        #[doc = "Point in time (Unix timestamp) when the file was uploaded"]
+        pub date: i32,
+    }
+    impl TlObject for DatedFile {
+        const CLASS: &'static str = "DatedFile";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DatedFile {
+        const TYPE: &'static str = "datedFile";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the user's personal details"]
+    pub struct PassportElementTypePersonalDetails {}
+    impl TlObject for PassportElementTypePersonalDetails {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypePersonalDetails {
+        const TYPE: &'static str = "passportElementTypePersonalDetails";
+    }
+    impl PassportElementTypePersonalDetails {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypePersonalDetails {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the user's passport"]
+    pub struct PassportElementTypePassport {}
+    impl TlObject for PassportElementTypePassport {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypePassport {
+        const TYPE: &'static str = "passportElementTypePassport";
+    }
+    impl PassportElementTypePassport {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypePassport {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the user's driver license"]
+    pub struct PassportElementTypeDriverLicense {}
+    impl TlObject for PassportElementTypeDriverLicense {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypeDriverLicense {
+        const TYPE: &'static str = "passportElementTypeDriverLicense";
+    }
+    impl PassportElementTypeDriverLicense {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypeDriverLicense {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the user's identity card"]
+    pub struct PassportElementTypeIdentityCard {}
+    impl TlObject for PassportElementTypeIdentityCard {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypeIdentityCard {
+        const TYPE: &'static str = "passportElementTypeIdentityCard";
+    }
+    impl PassportElementTypeIdentityCard {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypeIdentityCard {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the user's internal passport"]
+    pub struct PassportElementTypeInternalPassport {}
+    impl TlObject for PassportElementTypeInternalPassport {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypeInternalPassport {
+        const TYPE: &'static str = "passportElementTypeInternalPassport";
+    }
+    impl PassportElementTypeInternalPassport {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypeInternalPassport {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the user's address"]
+    pub struct PassportElementTypeAddress {}
+    impl TlObject for PassportElementTypeAddress {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypeAddress {
+        const TYPE: &'static str = "passportElementTypeAddress";
+    }
+    impl PassportElementTypeAddress {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypeAddress {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the user's utility bill"]
+    pub struct PassportElementTypeUtilityBill {}
+    impl TlObject for PassportElementTypeUtilityBill {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypeUtilityBill {
+        const TYPE: &'static str = "passportElementTypeUtilityBill";
+    }
+    impl PassportElementTypeUtilityBill {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypeUtilityBill {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the user's bank statement"]
+    pub struct PassportElementTypeBankStatement {}
+    impl TlObject for PassportElementTypeBankStatement {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypeBankStatement {
+        const TYPE: &'static str = "passportElementTypeBankStatement";
+    }
+    impl PassportElementTypeBankStatement {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypeBankStatement {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the user's rental agreement"]
+    pub struct PassportElementTypeRentalAgreement {}
+    impl TlObject for PassportElementTypeRentalAgreement {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypeRentalAgreement {
+        const TYPE: &'static str = "passportElementTypeRentalAgreement";
+    }
+    impl PassportElementTypeRentalAgreement {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypeRentalAgreement {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the registration page of the user's passport"]
+    pub struct PassportElementTypePassportRegistration {}
+    impl TlObject for PassportElementTypePassportRegistration {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypePassportRegistration {
+        const TYPE: &'static str = "passportElementTypePassportRegistration";
+    }
+    impl PassportElementTypePassportRegistration {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypePassportRegistration {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the user's temporary registration"]
+    pub struct PassportElementTypeTemporaryRegistration {}
+    impl TlObject for PassportElementTypeTemporaryRegistration {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypeTemporaryRegistration {
+        const TYPE: &'static str = "passportElementTypeTemporaryRegistration";
+    }
+    impl PassportElementTypeTemporaryRegistration {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypeTemporaryRegistration {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the user's phone number"]
+    pub struct PassportElementTypePhoneNumber {}
+    impl TlObject for PassportElementTypePhoneNumber {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypePhoneNumber {
+        const TYPE: &'static str = "passportElementTypePhoneNumber";
+    }
+    impl PassportElementTypePhoneNumber {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypePhoneNumber {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Telegram Passport element containing the user's email address"]
+    pub struct PassportElementTypeEmailAddress {}
+    impl TlObject for PassportElementTypeEmailAddress {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PassportElementTypeEmailAddress {
+        const TYPE: &'static str = "passportElementTypeEmailAddress";
+    }
+    impl PassportElementTypeEmailAddress {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for PassportElementTypeEmailAddress {}
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Contains the type of a Telegram Passport element"]
+    pub enum PassportElementType {
+        PassportElementTypePersonalDetails(PassportElementTypePersonalDetails),
+        PassportElementTypePassport(PassportElementTypePassport),
+        PassportElementTypeDriverLicense(PassportElementTypeDriverLicense),
+        PassportElementTypeIdentityCard(PassportElementTypeIdentityCard),
+        PassportElementTypeInternalPassport(PassportElementTypeInternalPassport),
+        PassportElementTypeAddress(PassportElementTypeAddress),
+        PassportElementTypeUtilityBill(PassportElementTypeUtilityBill),
+        PassportElementTypeBankStatement(PassportElementTypeBankStatement),
+        PassportElementTypeRentalAgreement(PassportElementTypeRentalAgreement),
+        PassportElementTypePassportRegistration(PassportElementTypePassportRegistration),
+        PassportElementTypeTemporaryRegistration(PassportElementTypeTemporaryRegistration),
+        PassportElementTypePhoneNumber(PassportElementTypePhoneNumber),
+        PassportElementTypeEmailAddress(PassportElementTypeEmailAddress),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for PassportElementType {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "passportElementTypePersonalDetails" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypePersonalDetails),
+                "passportElementTypePassport" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypePassport),
+                "passportElementTypeDriverLicense" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypeDriverLicense),
+                "passportElementTypeIdentityCard" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypeIdentityCard),
+                "passportElementTypeInternalPassport" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypeInternalPassport),
+                "passportElementTypeAddress" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypeAddress),
+                "passportElementTypeUtilityBill" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypeUtilityBill),
+                "passportElementTypeBankStatement" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypeBankStatement),
+                "passportElementTypeRentalAgreement" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypeRentalAgreement),
+                "passportElementTypePassportRegistration" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypePassportRegistration),
+                "passportElementTypeTemporaryRegistration" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypeTemporaryRegistration),
+                "passportElementTypePhoneNumber" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypePhoneNumber),
+                "passportElementTypeEmailAddress" => Deserialize::deserialize(value)
+                    .map(PassportElementType::PassportElementTypeEmailAddress),
+                _ => Unknown::deserialize(value).map(PassportElementType::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for PassportElementType {
+        const CLASS: &'static str = "PassportElementType";
+        fn type_name(&self) -> &str {
+            match self {
+                PassportElementType::PassportElementTypePersonalDetails(_) => {
+                    "passportElementTypePersonalDetails"
+                }
+                PassportElementType::PassportElementTypePassport(_) => {
+                    "passportElementTypePassport"
+                }
+                PassportElementType::PassportElementTypeDriverLicense(_) => {
+                    "passportElementTypeDriverLicense"
+                }
+                PassportElementType::PassportElementTypeIdentityCard(_) => {
+                    "passportElementTypeIdentityCard"
+                }
+                PassportElementType::PassportElementTypeInternalPassport(_) => {
+                    "passportElementTypeInternalPassport"
+                }
+                PassportElementType::PassportElementTypeAddress(_) => "passportElementTypeAddress",
+                PassportElementType::PassportElementTypeUtilityBill(_) => {
+                    "passportElementTypeUtilityBill"
+                }
+                PassportElementType::PassportElementTypeBankStatement(_) => {
+                    "passportElementTypeBankStatement"
+                }
+                PassportElementType::PassportElementTypeRentalAgreement(_) => {
+                    "passportElementTypeRentalAgreement"
+                }
+                PassportElementType::PassportElementTypePassportRegistration(_) => {
+                    "passportElementTypePassportRegistration"
+                }
+                PassportElementType::PassportElementTypeTemporaryRegistration(_) => {
+                    "passportElementTypeTemporaryRegistration"
+                }
+                PassportElementType::PassportElementTypePhoneNumber(_) => {
+                    "passportElementTypePhoneNumber"
+                }
+                PassportElementType::PassportElementTypeEmailAddress(_) => {
+                    "passportElementTypeEmailAddress"
+                }
+                PassportElementType::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for PassportElementType {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                PassportElementType::PassportElementTypePersonalDetails(value) => value.validate(),
+                PassportElementType::PassportElementTypePassport(value) => value.validate(),
+                PassportElementType::PassportElementTypeDriverLicense(value) => value.validate(),
+                PassportElementType::PassportElementTypeIdentityCard(value) => value.validate(),
+                PassportElementType::PassportElementTypeInternalPassport(value) => value.validate(),
+                PassportElementType::PassportElementTypeAddress(value) => value.validate(),
+                PassportElementType::PassportElementTypeUtilityBill(value) => value.validate(),
+                PassportElementType::PassportElementTypeBankStatement(value) => value.validate(),
+                PassportElementType::PassportElementTypeRentalAgreement(value) => value.validate(),
+                PassportElementType::PassportElementTypePassportRegistration(value) => {
+                    value.validate()
+                }
+                PassportElementType::PassportElementTypeTemporaryRegistration(value) => {
+                    value.validate()
+                }
+                PassportElementType::PassportElementTypePhoneNumber(value) => value.validate(),
+                PassportElementType::PassportElementTypeEmailAddress(value) => value.validate(),
+                PassportElementType::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<PassportElementTypePersonalDetails> for PassportElementType {
+        fn from(value: PassportElementTypePersonalDetails) -> Self {
+            PassportElementType::PassportElementTypePersonalDetails(value)
+        }
+    }
+    impl From<PassportElementTypePassport> for PassportElementType {
+        fn from(value: PassportElementTypePassport) -> Self {
+            PassportElementType::PassportElementTypePassport(value)
+        }
+    }
+    impl From<PassportElementTypeDriverLicense> for PassportElementType {
+        fn from(value: PassportElementTypeDriverLicense) -> Self {
+            PassportElementType::PassportElementTypeDriverLicense(value)
+        }
+    }
+    impl From<PassportElementTypeIdentityCard> for PassportElementType {
+        fn from(value: PassportElementTypeIdentityCard) -> Self {
+            PassportElementType::PassportElementTypeIdentityCard(value)
+        }
+    }
+    impl From<PassportElementTypeInternalPassport> for PassportElementType {
+        fn from(value: PassportElementTypeInternalPassport) -> Self {
+            PassportElementType::PassportElementTypeInternalPassport(value)
+        }
+    }
+    impl From<PassportElementTypeAddress> for PassportElementType {
+        fn from(value: PassportElementTypeAddress) -> Self {
+            PassportElementType::PassportElementTypeAddress(value)
+        }
+    }
+    impl From<PassportElementTypeUtilityBill> for PassportElementType {
+        fn from(value: PassportElementTypeUtilityBill) -> Self {
+            PassportElementType::PassportElementTypeUtilityBill(value)
+        }
+    }
+    impl From<PassportElementTypeBankStatement> for PassportElementType {
+        fn from(value: PassportElementTypeBankStatement) -> Self {
+            PassportElementType::PassportElementTypeBankStatement(value)
+        }
+    }
+    impl From<PassportElementTypeRentalAgreement> for PassportElementType {
+        fn from(value: PassportElementTypeRentalAgreement) -> Self {
+            PassportElementType::PassportElementTypeRentalAgreement(value)
+        }
+    }
+    impl From<PassportElementTypePassportRegistration> for PassportElementType {
+        fn from(value: PassportElementTypePassportRegistration) -> Self {
+            PassportElementType::PassportElementTypePassportRegistration(value)
+        }
+    }
+    impl From<PassportElementTypeTemporaryRegistration> for PassportElementType {
+        fn from(value: PassportElementTypeTemporaryRegistration) -> Self {
+            PassportElementType::PassportElementTypeTemporaryRegistration(value)
+        }
+    }
+    impl From<PassportElementTypePhoneNumber> for PassportElementType {
+        fn from(value: PassportElementTypePhoneNumber) -> Self {
+            PassportElementType::PassportElementTypePhoneNumber(value)
+        }
+    }
+    impl From<PassportElementTypeEmailAddress> for PassportElementType {
+        fn from(value: PassportElementTypeEmailAddress) -> Self {
+            PassportElementType::PassportElementTypeEmailAddress(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains encrypted Telegram Passport data credentials"]
+    pub struct EncryptedCredentials {
+        #[doc = "The encrypted credentials"]
+        pub data: Bytes,
+        #[doc = "The decrypted data hash"]
+        pub hash: Bytes,
+        #[doc = "Secret for data decryption, encrypted with the service's public key"]
+        pub secret: Bytes,
+    }
+    impl TlObject for EncryptedCredentials {
+        const CLASS: &'static str = "EncryptedCredentials";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for EncryptedCredentials {
+        const TYPE: &'static str = "encryptedCredentials";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains information about an encrypted Telegram Passport element; for bots only"]
+    pub struct EncryptedPassportElement {
+        #[serde(rename = "type")]
+        #[doc = "Type of Telegram Passport element"]
+        pub type_: PassportElementType,
+        #[doc = "Encrypted JSON-encoded data about the user"]
+        pub data: Bytes,
+        #[doc = "The front side of an identity document"]
+        pub front_side: DatedFile,
+        #[serde(default)]
+        #[doc = "The reverse side of an identity document; may be null"]
+        pub reverse_side: Option<DatedFile>,
+        #[serde(default)]
+        #[doc = "Selfie with the document; may be null"]
+        pub selfie: Option<DatedFile>,
+        #[doc = "List of files containing a certified English translation of the document"]
+        pub translation: Vec<DatedFile>,
+        #[doc = "List of attached files"]
+        pub files: Vec<DatedFile>,
+        #[doc = "Unencrypted data, phone number or email address"]
+        pub value: String,
+        #[doc = "Hash of the entire element"]
+        pub hash: String,
+    }
+    impl TlObject for EncryptedPassportElement {
+        const CLASS: &'static str = "EncryptedPassportElement";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for EncryptedPassportElement {
+        const TYPE: &'static str = "encryptedPassportElement";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A text message"]
+    pub struct MessageText {
+        #[doc = "Text of the message"]
+        pub text: FormattedText,
+        #[serde(default)]
+        #[doc = "A preview of the web page that's mentioned in the text; may be null"]
+        pub web_page: Option<WebPage>,
+    }
+    impl TlObject for MessageText {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageText {
+        const TYPE: &'static str = "messageText";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An animation message (GIF-style)."]
+    pub struct MessageAnimation {
+        #[doc = "The animation description"]
+        pub animation: Animation,
+        #[doc = "Animation caption"]
+        pub caption: FormattedText,
+        #[doc = "True, if the animation thumbnail must be blurred and the animation must be shown only while tapped"]
+        pub is_secret: bool,
+    }
+    impl TlObject for MessageAnimation {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageAnimation {
+        const TYPE: &'static str = "messageAnimation";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An audio message"]
+    pub struct MessageAudio {
+        #[doc = "The audio description"]
+        pub audio: Audio,
+        #[doc = "Audio caption"]
+        pub caption: FormattedText,
+    }
+    impl TlObject for MessageAudio {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageAudio {
+        const TYPE: &'static str = "messageAudio";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A document message (general file)"]
+    pub struct MessageDocument {
+        #[doc = "The document description"]
+        pub document: Document,
+        #[doc = "Document caption"]
+        pub caption: FormattedText,
+    }
+    impl TlObject for MessageDocument {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageDocument {
+        const TYPE: &'static str = "messageDocument";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A photo message"]
+    pub struct MessagePhoto {
+        #[doc = "The photo description"]
+        pub photo: Photo,
+        #[doc = "Photo caption"]
+        pub caption: FormattedText,
+        #[doc = "True, if the photo must be blurred and must be shown only while tapped"]
+        pub is_secret: bool,
+    }
+    impl TlObject for MessagePhoto {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessagePhoto {
+        const TYPE: &'static str = "messagePhoto";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An expired photo message (self-destructed after TTL has elapsed)"]
+    pub struct MessageExpiredPhoto {}
+    impl TlObject for MessageExpiredPhoto {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageExpiredPhoto {
+        const TYPE: &'static str = "messageExpiredPhoto";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A sticker message"]
+    pub struct MessageSticker {
+        #[doc = "The sticker description"]
+        pub sticker: Sticker,
+    }
+    impl TlObject for MessageSticker {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageSticker {
+        const TYPE: &'static str = "messageSticker";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A video message"]
+    pub struct MessageVideo {
+        #[doc = "The video description"]
+        pub video: Video,
+        #[doc = "Video caption"]
+        pub caption: FormattedText,
+        #[doc = "True, if the video thumbnail must be blurred and the video must be shown only while tapped"]
+        pub is_secret: bool,
+    }
+    impl TlObject for MessageVideo {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageVideo {
+        const TYPE: &'static str = "messageVideo";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An expired video message (self-destructed after TTL has elapsed)"]
+    pub struct MessageExpiredVideo {}
+    impl TlObject for MessageExpiredVideo {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageExpiredVideo {
+        const TYPE: &'static str = "messageExpiredVideo";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A video note message"]
+    pub struct MessageVideoNote {
+        #[doc = "The video note description"]
+        pub video_note: VideoNote,
+        #[doc = "True, if at least one of the recipients has viewed the video note"]
+        pub is_viewed: bool,
+        #[doc = "True, if the video note thumbnail must be blurred and the video note must be shown only while tapped"]
+        pub is_secret: bool,
+    }
+    impl TlObject for MessageVideoNote {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageVideoNote {
+        const TYPE: &'static str = "messageVideoNote";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A voice note message"]
+    pub struct MessageVoiceNote {
+        #[doc = "The voice note description"]
+        pub voice_note: VoiceNote,
+        #[doc = "Voice note caption"]
+        pub caption: FormattedText,
+        #[doc = "True, if at least one of the recipients has listened to the voice note"]
+        pub is_listened: bool,
+    }
+    impl TlObject for MessageVoiceNote {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageVoiceNote {
+        const TYPE: &'static str = "messageVoiceNote";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A message with a location"]
+    pub struct MessageLocation {
+        #[doc = "The location description"]
+        pub location: Location,
+        #[doc = "Time relative to the message sent date until which the location can be updated, in seconds"]
+        pub live_period: i32,
+        #[doc = "Left time for which the location can be updated, in seconds. updateMessageContent is not sent when this field changes"]
+        pub expires_in: i32,
+    }
+    impl TlObject for MessageLocation {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageLocation {
+        const TYPE: &'static str = "messageLocation";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A message with information about a venue"]
+    pub struct MessageVenue {
+        #[doc = "The venue description"]
+        pub venue: Venue,
+    }
+    impl TlObject for MessageVenue {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageVenue {
+        const TYPE: &'static str = "messageVenue";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A message with a user contact"]
+    pub struct MessageContact {
+        #[doc = "The contact description"]
+        pub contact: Contact,
+    }
+    impl TlObject for MessageContact {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageContact {
+        const TYPE: &'static str = "messageContact";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A message with a game"]
+    pub struct MessageGame {
+        #[doc = "The game description"]
+        pub game: Game,
+    }
+    impl TlObject for MessageGame {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageGame {
+        const TYPE: &'static str = "messageGame";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A message with a poll"]
+    pub struct MessagePoll {
+        #[doc = "The poll description"]
+        pub poll: Poll,
+    }
+    impl TlObject for MessagePoll {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessagePoll {
+        const TYPE: &'static str = "messagePoll";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A message with an invoice from a bot"]
+    pub struct MessageInvoice {
+        #[doc = "Product title"]
+        pub title: String,
+        #[doc = "A message with an invoice from a bot"]
+        pub description: String,
+        #[serde(default)]
+        #[doc = "Product photo; may be null"]
+        pub photo: Option<Photo>,
+        #[doc = "Currency for the product price"]
+        pub currency: String,
+        #[doc = "Product total price in the minimal quantity of the currency"]
+        pub total_amount: i64,
+        #[doc = "Unique invoice bot start_parameter. To share an invoice use the URL https://t.me/{bot_username}?start={start_parameter}"]
+        pub start_parameter: String,
+        #[doc = "True, if the invoice is a test invoice"]
+        pub is_test: bool,
+        #[doc = "True, if the shipping address should be specified"]
+        pub need_shipping_address: bool,
+        #[doc = "The identifier of the message with the receipt, after the product has been purchased"]
+        pub receipt_message_id: MessageId,
+    }
+    impl TlObject for MessageInvoice {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageInvoice {
+        const TYPE: &'static str = "messageInvoice";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A message with information about an ended call"]
+    pub struct MessageCall {
+        #[doc = "Reason why the call was discarded"]
+        pub discard_reason: CallDiscardReason,
+        #[doc = "Call duration, in seconds"]
+        pub duration: i32,
+    }
+    impl TlObject for MessageCall {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageCall {
+        const TYPE: &'static str = "messageCall";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A newly created basic group"]
+    pub struct MessageBasicGroupChatCreate {
+        #[doc = "Title of the basic group"]
+        pub title: String,
+        #[doc = "User identifiers of members in the basic group"]
+        pub member_user_ids: Vec<UserId>,
+    }
+    impl TlObject for MessageBasicGroupChatCreate {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageBasicGroupChatCreate {
+        const TYPE: &'static str = "messageBasicGroupChatCreate";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A newly created supergroup or channel"]
+    pub struct MessageSupergroupChatCreate {
+        #[doc = "Title of the supergroup or channel"]
+        pub title: String,
+    }
+    impl TlObject for MessageSupergroupChatCreate {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageSupergroupChatCreate {
+        const TYPE: &'static str = "messageSupergroupChatCreate";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An updated chat title"]
+    pub struct MessageChatChangeTitle {
+        #[doc = "New chat title"]
+        pub title: String,
+    }
+    impl TlObject for MessageChatChangeTitle {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageChatChangeTitle {
+        const TYPE: &'static str = "messageChatChangeTitle";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An updated chat photo"]
+    pub struct MessageChatChangePhoto {
+        #[doc = "New chat photo"]
+        pub photo: Photo,
+    }
+    impl TlObject for MessageChatChangePhoto {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageChatChangePhoto {
+        const TYPE: &'static str = "messageChatChangePhoto";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A deleted chat photo"]
+    pub struct MessageChatDeletePhoto {}
+    impl TlObject for MessageChatDeletePhoto {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageChatDeletePhoto {
+        const TYPE: &'static str = "messageChatDeletePhoto";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "New chat members were added"]
+    pub struct MessageChatAddMembers {
+        #[doc = "User identifiers of the new members"]
+        pub member_user_ids: Vec<UserId>,
+    }
+    impl TlObject for MessageChatAddMembers {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageChatAddMembers {
+        const TYPE: &'static str = "messageChatAddMembers";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A new member joined the chat by invite link"]
+    pub struct MessageChatJoinByLink {}
+    impl TlObject for MessageChatJoinByLink {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageChatJoinByLink {
+        const TYPE: &'static str = "messageChatJoinByLink";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A chat member was deleted"]
+    pub struct MessageChatDeleteMember {
+        #[doc = "User identifier of the deleted chat member"]
+        pub user_id: UserId,
+    }
+    impl TlObject for MessageChatDeleteMember {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageChatDeleteMember {
+        const TYPE: &'static str = "messageChatDeleteMember";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A basic group was upgraded to a supergroup and was deactivated as the result"]
+    pub struct MessageChatUpgradeTo {
+        #[doc = "Identifier of the supergroup to which the basic group was upgraded"]
+        pub supergroup_id: SupergroupId,
+    }
+    impl TlObject for MessageChatUpgradeTo {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageChatUpgradeTo {
+        const TYPE: &'static str = "messageChatUpgradeTo";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A supergroup has been created from a basic group"]
+    pub struct MessageChatUpgradeFrom {
+        #[doc = "Title of the newly created supergroup"]
+        pub title: String,
+        #[doc = "The identifier of the original basic group"]
+        pub basic_group_id: BasicGroupId,
+    }
+    impl TlObject for MessageChatUpgradeFrom {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageChatUpgradeFrom {
+        const TYPE: &'static str = "messageChatUpgradeFrom";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A message has been pinned"]
+    pub struct MessagePinMessage {
+        #[doc = "Identifier of the pinned message, can be an identifier of a deleted message or 0"]
+        pub message_id: MessageId,
+    }
+    impl TlObject for MessagePinMessage {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessagePinMessage {
+        const TYPE: &'static str = "messagePinMessage";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A screenshot of a message in the chat has been taken"]
+    pub struct MessageScreenshotTaken {}
+    impl TlObject for MessageScreenshotTaken {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageScreenshotTaken {
+        const TYPE: &'static str = "messageScreenshotTaken";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The TTL (Time To Live) setting messages in a secret chat has been changed"]
+    pub struct MessageChatSetTtl {
+        #[doc = "New TTL"]
+        pub ttl: i32,
+    }
+    impl TlObject for MessageChatSetTtl {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageChatSetTtl {
+        const TYPE: &'static str = "messageChatSetTtl";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A non-standard action has happened in the chat"]
+    pub struct MessageCustomServiceAction {
+        #[doc = "Message text to be shown in the chat"]
+        pub text: String,
+    }
+    impl TlObject for MessageCustomServiceAction {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageCustomServiceAction {
+        const TYPE: &'static str = "messageCustomServiceAction";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A new high score was achieved in a game"]
+    pub struct MessageGameScore {
+        #[doc = "Identifier of the message with the game, can be an identifier of a deleted message"]
+        pub game_message_id: MessageId,
+        #[serde(
+            deserialize_with = "crate::utils::from_str_to_t",
+            serialize_with = "crate::utils::t_to_str"
+        )]
+        #[doc = "Identifier of the game; may be different from the games presented in the message with the game"]
+        pub game_id: i64,
+        #[doc = "New score"]
+        pub score: i32,
+    }
+    impl TlObject for MessageGameScore {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageGameScore {
+        const TYPE: &'static str = "messageGameScore";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A payment has been completed"]
+    pub struct MessagePaymentSuccessful {
+        #[doc = "Identifier of the message with the corresponding invoice; can be an identifier of a deleted message"]
+        pub invoice_message_id: MessageId,
+        #[doc = "Currency for the price of the product"]
+        pub currency: String,
+        #[doc = "Total price for the product, in the minimal quantity of the currency"]
+        pub total_amount: i64,
+    }
+    impl TlObject for MessagePaymentSuccessful {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessagePaymentSuccessful {
+        const TYPE: &'static str = "messagePaymentSuccessful";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A payment has been completed; for bots only"]
+    pub struct MessagePaymentSuccessfulBot {
+        #[doc = "Identifier of the message with the corresponding invoice; can be an identifier of a deleted message"]
+        pub invoice_message_id: MessageId,
+        #[doc = "Currency for price of the product"]
+        pub currency: String,
+        #[doc = "Total price for the product, in the minimal quantity of the currency"]
+        pub total_amount: i64,
+        #[doc = "Invoice payload"]
+        pub invoice_payload: Bytes,
+        #[doc = "Identifier of the shipping option chosen by the user; may be empty if not applicable"]
+        pub shipping_option_id: String,
+        #[serde(default)]
+        #[doc = "Information about the order; may be null"]
+        pub order_info: Option<OrderInfo>,
+        #[doc = "Telegram payment identifier"]
+        pub telegram_payment_charge_id: String,
+        #[doc = "Provider payment identifier"]
+        pub provider_payment_charge_id: String,
+    }
+    impl TlObject for MessagePaymentSuccessfulBot {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessagePaymentSuccessfulBot {
+        const TYPE: &'static str = "messagePaymentSuccessfulBot";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A contact has registered with Telegram"]
+    pub struct MessageContactRegistered {}
+    impl TlObject for MessageContactRegistered {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageContactRegistered {
+        const TYPE: &'static str = "messageContactRegistered";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The current user has connected a website by logging in using Telegram Login Widget on it"]
+    pub struct MessageWebsiteConnected {
+        #[doc = "Domain name of the connected website"]
+        pub domain_name: String,
+    }
+    impl TlObject for MessageWebsiteConnected {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageWebsiteConnected {
+        const TYPE: &'static str = "messageWebsiteConnected";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Telegram Passport data has been sent"]
+    pub struct MessagePassportDataSent {
+        #[doc = "List of Telegram Passport element types sent"]
+        pub types: Vec<PassportElementType>,
+    }
+    impl TlObject for MessagePassportDataSent {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessagePassportDataSent {
+        const TYPE: &'static str = "messagePassportDataSent";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Telegram Passport data has been received; for bots only"]
+    pub struct MessagePassportDataReceived {
+        #[doc = "List of received Telegram Passport elements"]
+        pub elements: Vec<EncryptedPassportElement>,
+        #[doc = "Encrypted data credentials"]
+        pub credentials: EncryptedCredentials,
+    }
+    impl TlObject for MessagePassportDataReceived {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessagePassportDataReceived {
+        const TYPE: &'static str = "messagePassportDataReceived";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Message content that is not supported by the client"]
+    pub struct MessageUnsupported {}
+    impl TlObject for MessageUnsupported {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for MessageUnsupported {
+        const TYPE: &'static str = "messageUnsupported";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Contains the content of a message"]
+    pub enum MessageContent {
+        MessageText(Box<MessageText>),
+        MessageAnimation(Box<MessageAnimation>),
+        MessageAudio(Box<MessageAudio>),
+        MessageDocument(Box<MessageDocument>),
+        MessagePhoto(MessagePhoto),
+        MessageExpiredPhoto(MessageExpiredPhoto),
+        MessageSticker(Box<MessageSticker>),
+        MessageVideo(Box<MessageVideo>),
+        MessageExpiredVideo(MessageExpiredVideo),
+        MessageVideoNote(Box<MessageVideoNote>),
+        MessageVoiceNote(Box<MessageVoiceNote>),
+        MessageLocation(MessageLocation),
+        MessageVenue(Box<MessageVenue>),
+        MessageContact(MessageContact),
+        MessageGame(Box<MessageGame>),
+        MessagePoll(Box<MessagePoll>),
+        MessageInvoice(Box<MessageInvoice>),
+        MessageCall(MessageCall),
+        MessageBasicGroupChatCreate(MessageBasicGroupChatCreate),
+        MessageSupergroupChatCreate(MessageSupergroupChatCreate),
+        MessageChatChangeTitle(MessageChatChangeTitle),
+        MessageChatChangePhoto(MessageChatChangePhoto),
+        MessageChatDeletePhoto(MessageChatDeletePhoto),
+        MessageChatAddMembers(MessageChatAddMembers),
+        MessageChatJoinByLink(MessageChatJoinByLink),
+        MessageChatDeleteMember(MessageChatDeleteMember),
+        MessageChatUpgradeTo(MessageChatUpgradeTo),
+        MessageChatUpgradeFrom(MessageChatUpgradeFrom),
+        MessagePinMessage(MessagePinMessage),
+        MessageScreenshotTaken(MessageScreenshotTaken),
+        MessageChatSetTtl(MessageChatSetTtl),
+        MessageCustomServiceAction(MessageCustomServiceAction),
+        MessageGameScore(MessageGameScore),
+        MessagePaymentSuccessful(MessagePaymentSuccessful),
+        MessagePaymentSuccessfulBot(Box<MessagePaymentSuccessfulBot>),
+        MessageContactRegistered(MessageContactRegistered),
+        MessageWebsiteConnected(MessageWebsiteConnected),
+        MessagePassportDataSent(MessagePassportDataSent),
+        MessagePassportDataReceived(MessagePassportDataReceived),
+        MessageUnsupported(MessageUnsupported),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for MessageContent {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "messageText" => Deserialize::deserialize(value).map(MessageContent::MessageText),
+                "messageAnimation" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageAnimation)
+                }
+                "messageAudio" => Deserialize::deserialize(value).map(MessageContent::MessageAudio),
+                "messageDocument" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageDocument)
+                }
+                "messagePhoto" => Deserialize::deserialize(value).map(MessageContent::MessagePhoto),
+                "messageExpiredPhoto" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageExpiredPhoto)
+                }
+                "messageSticker" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageSticker)
+                }
+                "messageVideo" => Deserialize::deserialize(value).map(MessageContent::MessageVideo),
+                "messageExpiredVideo" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageExpiredVideo)
+                }
+                "messageVideoNote" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageVideoNote)
+                }
+                "messageVoiceNote" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageVoiceNote)
+                }
+                "messageLocation" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageLocation)
+                }
+                "messageVenue" => Deserialize::deserialize(value).map(MessageContent::MessageVenue),
+                "messageContact" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageContact)
+                }
+                "messageGame" => Deserialize::deserialize(value).map(MessageContent::MessageGame),
+                "messagePoll" => Deserialize::deserialize(value).map(MessageContent::MessagePoll),
+                "messageInvoice" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageInvoice)
+                }
+                "messageCall" => Deserialize::deserialize(value).map(MessageContent::MessageCall),
+                "messageBasicGroupChatCreate" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageBasicGroupChatCreate)
+                }
+                "messageSupergroupChatCreate" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageSupergroupChatCreate)
+                }
+                "messageChatChangeTitle" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageChatChangeTitle)
+                }
+                "messageChatChangePhoto" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageChatChangePhoto)
+                }
+                "messageChatDeletePhoto" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageChatDeletePhoto)
+                }
+                "messageChatAddMembers" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageChatAddMembers)
+                }
+                "messageChatJoinByLink" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageChatJoinByLink)
+                }
+                "messageChatDeleteMember" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageChatDeleteMember)
+                }
+                "messageChatUpgradeTo" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageChatUpgradeTo)
+                }
+                "messageChatUpgradeFrom" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageChatUpgradeFrom)
+                }
+                "messagePinMessage" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessagePinMessage)
+                }
+                "messageScreenshotTaken" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageScreenshotTaken)
+                }
+                "messageChatSetTtl" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageChatSetTtl)
+                }
+                "messageCustomServiceAction" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageCustomServiceAction)
+                }
+                "messageGameScore" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageGameScore)
+                }
+                "messagePaymentSuccessful" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessagePaymentSuccessful)
+                }
+                "messagePaymentSuccessfulBot" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessagePaymentSuccessfulBot)
+                }
+                "messageContactRegistered" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageContactRegistered)
+                }
+                "messageWebsiteConnected" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageWebsiteConnected)
+                }
+                "messagePassportDataSent" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessagePassportDataSent)
+                }
+                "messagePassportDataReceived" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessagePassportDataReceived)
+                }
+                "messageUnsupported" => {
+                    Deserialize::deserialize(value).map(MessageContent::MessageUnsupported)
+                }
+                _ => Unknown::deserialize(value).map(MessageContent::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for MessageContent {
+        const CLASS: &'static str = "MessageContent";
+        fn type_name(&self) -> &str {
+            match self {
+                MessageContent::MessageText(_) => "messageText",
+                MessageContent::MessageAnimation(_) => "messageAnimation",
+                MessageContent::MessageAudio(_) => "messageAudio",
+                MessageContent::MessageDocument(_) => "messageDocument",
+                MessageContent::MessagePhoto(_) => "messagePhoto",
+                MessageContent::MessageExpiredPhoto(_) => "messageExpiredPhoto",
+                MessageContent::MessageSticker(_) => "messageSticker",
+                MessageContent::MessageVideo(_) => "messageVideo",
+                MessageContent::MessageExpiredVideo(_) => "messageExpiredVideo",
+                MessageContent::MessageVideoNote(_) => "messageVideoNote",
+                MessageContent::MessageVoiceNote(_) => "messageVoiceNote",
+                MessageContent::MessageLocation(_) => "messageLocation",
+                MessageContent::MessageVenue(_) => "messageVenue",
+                MessageContent::MessageContact(_) => "messageContact",
+                MessageContent::MessageGame(_) => "messageGame",
+                MessageContent::MessagePoll(_) => "messagePoll",
+                MessageContent::MessageInvoice(_) => "messageInvoice",
+                MessageContent::MessageCall(_) => "messageCall",
+                MessageContent::MessageBasicGroupChatCreate(_) => "messageBasicGroupChatCreate",
+                MessageContent::MessageSupergroupChatCreate(_) => "messageSupergroupChatCreate",
+                MessageContent::MessageChatChangeTitle(_) => "messageChatChangeTitle",
+                MessageContent::MessageChatChangePhoto(_) => "messageChatChangePhoto",
+                MessageContent::MessageChatDeletePhoto(_) => "messageChatDeletePhoto",
+                MessageContent::MessageChatAddMembers(_) => "messageChatAddMembers",
+                MessageContent::MessageChatJoinByLink(_) => "messageChatJoinByLink",
+                MessageContent::MessageChatDeleteMember(_) => "messageChatDeleteMember",
+                MessageContent::MessageChatUpgradeTo(_) => "messageChatUpgradeTo",
+                MessageContent::MessageChatUpgradeFrom(_) => "messageChatUpgradeFrom",
+                MessageContent::MessagePinMessage(_) => "messagePinMessage",
+                MessageContent::MessageScreenshotTaken(_) => "messageScreenshotTaken",
+                MessageContent::MessageChatSetTtl(_) => "messageChatSetTtl",
+                MessageContent::MessageCustomServiceAction(_) => "messageCustomServiceAction",
+                MessageContent::MessageGameScore(_) => "messageGameScore",
+                MessageContent::MessagePaymentSuccessful(_) => "messagePaymentSuccessful",
+                MessageContent::MessagePaymentSuccessfulBot(_) => "messagePaymentSuccessfulBot",
+                MessageContent::MessageContactRegistered(_) => "messageContactRegistered",
+                MessageContent::MessageWebsiteConnected(_) => "messageWebsiteConnected",
+                MessageContent::MessagePassportDataSent(_) => "messagePassportDataSent",
+                MessageContent::MessagePassportDataReceived(_) => "messagePassportDataReceived",
+                MessageContent::MessageUnsupported(_) => "messageUnsupported",
+                MessageContent::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A mention of a user by their username"]
+    pub struct TextEntityTypeMention {}
+    impl TlObject for TextEntityTypeMention {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeMention {
+        const TYPE: &'static str = "textEntityTypeMention";
+    }
+    impl TextEntityTypeMention {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypeMention {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A hashtag text, beginning with \"#\""]
+    pub struct TextEntityTypeHashtag {}
+    impl TlObject for TextEntityTypeHashtag {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeHashtag {
+        const TYPE: &'static str = "textEntityTypeHashtag";
+    }
+    impl TextEntityTypeHashtag {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypeHashtag {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A cashtag text, beginning with \"$\" and consisting of capital english letters (i.e. \"$USD\")"]
+    pub struct TextEntityTypeCashtag {}
+    impl TlObject for TextEntityTypeCashtag {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeCashtag {
+        const TYPE: &'static str = "textEntityTypeCashtag";
+    }
+    impl TextEntityTypeCashtag {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypeCashtag {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A bot command, beginning with \"/\". This shouldn't be highlighted if there are no bots in the chat"]
+    pub struct TextEntityTypeBotCommand {}
+    impl TlObject for TextEntityTypeBotCommand {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeBotCommand {
+        const TYPE: &'static str = "textEntityTypeBotCommand";
+    }
+    impl TextEntityTypeBotCommand {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypeBotCommand {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "An HTTP URL"]
+    pub struct TextEntityTypeUrl {}
+    impl TlObject for TextEntityTypeUrl {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeUrl {
+        const TYPE: &'static str = "textEntityTypeUrl";
+    }
+    impl TextEntityTypeUrl {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypeUrl {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "An email address"]
+    pub struct TextEntityTypeEmailAddress {}
+    impl TlObject for TextEntityTypeEmailAddress {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeEmailAddress {
+        const TYPE: &'static str = "textEntityTypeEmailAddress";
+    }
+    impl TextEntityTypeEmailAddress {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypeEmailAddress {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A phone number"]
+    pub struct TextEntityTypePhoneNumber {}
+    impl TlObject for TextEntityTypePhoneNumber {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypePhoneNumber {
+        const TYPE: &'static str = "textEntityTypePhoneNumber";
+    }
+    impl TextEntityTypePhoneNumber {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypePhoneNumber {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A bold text"]
+    pub struct TextEntityTypeBold {}
+    impl TlObject for TextEntityTypeBold {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeBold {
+        const TYPE: &'static str = "textEntityTypeBold";
+    }
+    impl TextEntityTypeBold {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypeBold {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "An italic text"]
+    pub struct TextEntityTypeItalic {}
+    impl TlObject for TextEntityTypeItalic {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeItalic {
+        const TYPE: &'static str = "textEntityTypeItalic";
+    }
+    impl TextEntityTypeItalic {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypeItalic {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "An underlined text"]
+    pub struct TextEntityTypeUnderline {}
+    impl TlObject for TextEntityTypeUnderline {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeUnderline {
+        const TYPE: &'static str = "textEntityTypeUnderline";
+    }
+    impl TextEntityTypeUnderline {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypeUnderline {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A strikethrough text"]
+    pub struct TextEntityTypeStrikethrough {}
+    impl TlObject for TextEntityTypeStrikethrough {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeStrikethrough {
+        const TYPE: &'static str = "textEntityTypeStrikethrough";
+    }
+    impl TextEntityTypeStrikethrough {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypeStrikethrough {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Text that must be formatted as if inside a code HTML tag"]
+    pub struct TextEntityTypeCode {}
+    impl TlObject for TextEntityTypeCode {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeCode {
+        const TYPE: &'static str = "textEntityTypeCode";
+    }
+    impl TextEntityTypeCode {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypeCode {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Text that must be formatted as if inside a pre HTML tag"]
+    pub struct TextEntityTypePre {}
+    impl TlObject for TextEntityTypePre {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypePre {
+        const TYPE: &'static str = "textEntityTypePre";
+    }
+    impl TextEntityTypePre {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for TextEntityTypePre {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Text that must be formatted as if inside pre, and code HTML tags"]
+    pub struct TextEntityTypePreCode {
+        #[doc = "Programming language of the code; as defined by the sender"]
+        pub language: String,
+    }
+    impl TlObject for TextEntityTypePreCode {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypePreCode {
+        const TYPE: &'static str = "textEntityTypePreCode";
+    }
+    impl TextEntityTypePreCode {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Programming language of the code; as defined by the sender"]
+        pub fn with_language(mut self, value: impl Into<String>) -> Self {
+            self.language = value.into();
+            self
+        }
+    }
+    impl Validate for TextEntityTypePreCode {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A text description shown instead of a raw URL"]
+    pub struct TextEntityTypeTextUrl {
+        #[doc = "HTTP or tg:// URL to be opened when the link is clicked"]
+        pub url: String,
+    }
+    impl TlObject for TextEntityTypeTextUrl {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeTextUrl {
+        const TYPE: &'static str = "textEntityTypeTextUrl";
+    }
+    impl TextEntityTypeTextUrl {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "HTTP or tg:// URL to be opened when the link is clicked"]
+        pub fn with_url(mut self, value: impl Into<String>) -> Self {
+            self.url = value.into();
+            self
+        }
+    }
+    impl Validate for TextEntityTypeTextUrl {}
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A text shows instead of a raw mention of the user (e.g., when the user has no username)"]
+    pub struct TextEntityTypeMentionName {
+        #[doc = "Identifier of the mentioned user"]
+        pub user_id: UserId,
+    }
+    impl TlObject for TextEntityTypeMentionName {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TextEntityTypeMentionName {
+        const TYPE: &'static str = "textEntityTypeMentionName";
+    }
+    impl TextEntityTypeMentionName {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(user_id: impl Into<UserId>) -> Self {
+            Self {
+                user_id: user_id.into(),
+            }
+        }
+    }
+    impl Validate for TextEntityTypeMentionName {}
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Represents a part of the text which must be formatted differently"]
+    pub enum TextEntityType {
+        TextEntityTypeMention(TextEntityTypeMention),
+        TextEntityTypeHashtag(TextEntityTypeHashtag),
+        TextEntityTypeCashtag(TextEntityTypeCashtag),
+        TextEntityTypeBotCommand(TextEntityTypeBotCommand),
+        TextEntityTypeUrl(TextEntityTypeUrl),
+        TextEntityTypeEmailAddress(TextEntityTypeEmailAddress),
+        TextEntityTypePhoneNumber(TextEntityTypePhoneNumber),
+        TextEntityTypeBold(TextEntityTypeBold),
+        TextEntityTypeItalic(TextEntityTypeItalic),
+        TextEntityTypeUnderline(TextEntityTypeUnderline),
+        TextEntityTypeStrikethrough(TextEntityTypeStrikethrough),
+        TextEntityTypeCode(TextEntityTypeCode),
+        TextEntityTypePre(TextEntityTypePre),
+        TextEntityTypePreCode(TextEntityTypePreCode),
+        TextEntityTypeTextUrl(TextEntityTypeTextUrl),
+        TextEntityTypeMentionName(TextEntityTypeMentionName),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for TextEntityType {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "textEntityTypeMention" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeMention)
+                }
+                "textEntityTypeHashtag" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeHashtag)
+                }
+                "textEntityTypeCashtag" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeCashtag)
+                }
+                "textEntityTypeBotCommand" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeBotCommand)
+                }
+                "textEntityTypeUrl" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeUrl)
+                }
+                "textEntityTypeEmailAddress" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeEmailAddress)
+                }
+                "textEntityTypePhoneNumber" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypePhoneNumber)
+                }
+                "textEntityTypeBold" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeBold)
+                }
+                "textEntityTypeItalic" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeItalic)
+                }
+                "textEntityTypeUnderline" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeUnderline)
+                }
+                "textEntityTypeStrikethrough" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeStrikethrough)
+                }
+                "textEntityTypeCode" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeCode)
+                }
+                "textEntityTypePre" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypePre)
+                }
+                "textEntityTypePreCode" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypePreCode)
+                }
+                "textEntityTypeTextUrl" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeTextUrl)
+                }
+                "textEntityTypeMentionName" => {
+                    Deserialize::deserialize(value).map(TextEntityType::TextEntityTypeMentionName)
+                }
+                _ => Unknown::deserialize(value).map(TextEntityType::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for TextEntityType {
+        const CLASS: &'static str = "TextEntityType";
+        fn type_name(&self) -> &str {
+            match self {
+                TextEntityType::TextEntityTypeMention(_) => "textEntityTypeMention",
+                TextEntityType::TextEntityTypeHashtag(_) => "textEntityTypeHashtag",
+                TextEntityType::TextEntityTypeCashtag(_) => "textEntityTypeCashtag",
+                TextEntityType::TextEntityTypeBotCommand(_) => "textEntityTypeBotCommand",
+                TextEntityType::TextEntityTypeUrl(_) => "textEntityTypeUrl",
+                TextEntityType::TextEntityTypeEmailAddress(_) => "textEntityTypeEmailAddress",
+                TextEntityType::TextEntityTypePhoneNumber(_) => "textEntityTypePhoneNumber",
+                TextEntityType::TextEntityTypeBold(_) => "textEntityTypeBold",
+                TextEntityType::TextEntityTypeItalic(_) => "textEntityTypeItalic",
+                TextEntityType::TextEntityTypeUnderline(_) => "textEntityTypeUnderline",
+                TextEntityType::TextEntityTypeStrikethrough(_) => "textEntityTypeStrikethrough",
+                TextEntityType::TextEntityTypeCode(_) => "textEntityTypeCode",
+                TextEntityType::TextEntityTypePre(_) => "textEntityTypePre",
+                TextEntityType::TextEntityTypePreCode(_) => "textEntityTypePreCode",
+                TextEntityType::TextEntityTypeTextUrl(_) => "textEntityTypeTextUrl",
+                TextEntityType::TextEntityTypeMentionName(_) => "textEntityTypeMentionName",
+                TextEntityType::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for TextEntityType {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                TextEntityType::TextEntityTypeMention(value) => value.validate(),
+                TextEntityType::TextEntityTypeHashtag(value) => value.validate(),
+                TextEntityType::TextEntityTypeCashtag(value) => value.validate(),
+                TextEntityType::TextEntityTypeBotCommand(value) => value.validate(),
+                TextEntityType::TextEntityTypeUrl(value) => value.validate(),
+                TextEntityType::TextEntityTypeEmailAddress(value) => value.validate(),
+                TextEntityType::TextEntityTypePhoneNumber(value) => value.validate(),
+                TextEntityType::TextEntityTypeBold(value) => value.validate(),
+                TextEntityType::TextEntityTypeItalic(value) => value.validate(),
+                TextEntityType::TextEntityTypeUnderline(value) => value.validate(),
+                TextEntityType::TextEntityTypeStrikethrough(value) => value.validate(),
+                TextEntityType::TextEntityTypeCode(value) => value.validate(),
+                TextEntityType::TextEntityTypePre(value) => value.validate(),
+                TextEntityType::TextEntityTypePreCode(value) => value.validate(),
+                TextEntityType::TextEntityTypeTextUrl(value) => value.validate(),
+                TextEntityType::TextEntityTypeMentionName(value) => value.validate(),
+                TextEntityType::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<TextEntityTypeMention> for TextEntityType {
+        fn from(value: TextEntityTypeMention) -> Self {
+            TextEntityType::TextEntityTypeMention(value)
+        }
+    }
+    impl From<TextEntityTypeHashtag> for TextEntityType {
+        fn from(value: TextEntityTypeHashtag) -> Self {
+            TextEntityType::TextEntityTypeHashtag(value)
+        }
+    }
+    impl From<TextEntityTypeCashtag> for TextEntityType {
+        fn from(value: TextEntityTypeCashtag) -> Self {
+            TextEntityType::TextEntityTypeCashtag(value)
+        }
+    }
+    impl From<TextEntityTypeBotCommand> for TextEntityType {
+        fn from(value: TextEntityTypeBotCommand) -> Self {
+            TextEntityType::TextEntityTypeBotCommand(value)
+        }
+    }
+    impl From<TextEntityTypeUrl> for TextEntityType {
+        fn from(value: TextEntityTypeUrl) -> Self {
+            TextEntityType::TextEntityTypeUrl(value)
+        }
+    }
+    impl From<TextEntityTypeEmailAddress> for TextEntityType {
+        fn from(value: TextEntityTypeEmailAddress) -> Self {
+            TextEntityType::TextEntityTypeEmailAddress(value)
+        }
+    }
+    impl From<TextEntityTypePhoneNumber> for TextEntityType {
+        fn from(value: TextEntityTypePhoneNumber) -> Self {
+            TextEntityType::TextEntityTypePhoneNumber(value)
+        }
+    }
+    impl From<TextEntityTypeBold> for TextEntityType {
+        fn from(value: TextEntityTypeBold) -> Self {
+            TextEntityType::TextEntityTypeBold(value)
+        }
+    }
+    impl From<TextEntityTypeItalic> for TextEntityType {
+        fn from(value: TextEntityTypeItalic) -> Self {
+            TextEntityType::TextEntityTypeItalic(value)
+        }
+    }
+    impl From<TextEntityTypeUnderline> for TextEntityType {
+        fn from(value: TextEntityTypeUnderline) -> Self {
+            TextEntityType::TextEntityTypeUnderline(value)
+        }
+    }
+    impl From<TextEntityTypeStrikethrough> for TextEntityType {
+        fn from(value: TextEntityTypeStrikethrough) -> Self {
+            TextEntityType::TextEntityTypeStrikethrough(value)
+        }
+    }
+    impl From<TextEntityTypeCode> for TextEntityType {
+        fn from(value: TextEntityTypeCode) -> Self {
+            TextEntityType::TextEntityTypeCode(value)
+        }
+    }
+    impl From<TextEntityTypePre> for TextEntityType {
+        fn from(value: TextEntityTypePre) -> Self {
+            TextEntityType::TextEntityTypePre(value)
+        }
+    }
+    impl From<TextEntityTypePreCode> for TextEntityType {
+        fn from(value: TextEntityTypePreCode) -> Self {
+            TextEntityType::TextEntityTypePreCode(value)
+        }
+    }
+    impl From<TextEntityTypeTextUrl> for TextEntityType {
+        fn from(value: TextEntityTypeTextUrl) -> Self {
+            TextEntityType::TextEntityTypeTextUrl(value)
+        }
+    }
+    impl From<TextEntityTypeMentionName> for TextEntityType {
+        fn from(value: TextEntityTypeMentionName) -> Self {
+            TextEntityType::TextEntityTypeMentionName(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A thumbnail to be sent along with a file; should be in JPEG or WEBP format for stickers, and less than 200 kB in size"]
+    pub struct InputThumbnail {
+        #[doc = "Thumbnail file to send. Sending thumbnails by file_id is currently not supported"]
+        pub thumbnail: InputFile,
+        #[doc = "Thumbnail width, usually shouldn't exceed 320. Use 0 if unknown"]
+        pub width: i32,
+        #[doc = "Thumbnail height, usually shouldn't exceed 320. Use 0 if unknown"]
+        pub height: i32,
+    }
+    impl TlObject for InputThumbnail {
+        const CLASS: &'static str = "InputThumbnail";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for InputThumbnail {
+        const TYPE: &'static str = "inputThumbnail";
+    }
+    impl InputThumbnail {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(thumbnail: impl Into<InputFile>) -> Self {
+            Self {
+                thumbnail: thumbnail.into(),
+                width: Default::default(),
+                height: Default::default(),
+            }
+        }
+        #[doc = "Thumbnail width, usually shouldn't exceed 320. Use 0 if unknown"]
+        pub fn with_width(mut self, value: impl Into<i32>) -> Self {
+            self.width = value.into();
+            self
+        }
+        #[doc = "Thumbnail height, usually shouldn't exceed 320. Use 0 if unknown"]
+        pub fn with_height(mut self, value: impl Into<i32>) -> Self {
+            self.height = value.into();
+            self
+        }
+    }
+    impl Validate for InputThumbnail {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.thumbnail
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "thumbnail"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user status was never changed"]
+    pub struct UserStatusEmpty {}
+    impl TlObject for UserStatusEmpty {
+        const CLASS: &'static str = "UserStatus";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserStatusEmpty {
+        const TYPE: &'static str = "userStatusEmpty";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user is online"]
+    pub struct UserStatusOnline {
+        #[doc = "Point in time (Unix timestamp) when the user's online status will expire"]
+        pub expires: i32,
+    }
+    impl TlObject for UserStatusOnline {
+        const CLASS: &'static str = "UserStatus";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserStatusOnline {
+        const TYPE: &'static str = "userStatusOnline";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user is offline"]
+    pub struct UserStatusOffline {
+        #[doc = "Point in time (Unix timestamp) when the user was last online"]
+        pub was_online: i32,
+    }
+    impl TlObject for UserStatusOffline {
+        const CLASS: &'static str = "UserStatus";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserStatusOffline {
+        const TYPE: &'static str = "userStatusOffline";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user was online recently"]
+    pub struct UserStatusRecently {}
+    impl TlObject for UserStatusRecently {
+        const CLASS: &'static str = "UserStatus";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserStatusRecently {
+        const TYPE: &'static str = "userStatusRecently";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user is offline, but was online last week"]
+    pub struct UserStatusLastWeek {}
+    impl TlObject for UserStatusLastWeek {
+        const CLASS: &'static str = "UserStatus";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserStatusLastWeek {
+        const TYPE: &'static str = "userStatusLastWeek";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user is offline, but was online last month"]
+    pub struct UserStatusLastMonth {}
+    impl TlObject for UserStatusLastMonth {
+        const CLASS: &'static str = "UserStatus";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserStatusLastMonth {
+        const TYPE: &'static str = "userStatusLastMonth";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Describes the last time the user was online"]
+    pub enum UserStatus {
+        UserStatusEmpty(UserStatusEmpty),
+        UserStatusOnline(UserStatusOnline),
+        UserStatusOffline(UserStatusOffline),
+        UserStatusRecently(UserStatusRecently),
+        UserStatusLastWeek(UserStatusLastWeek),
+        UserStatusLastMonth(UserStatusLastMonth),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for UserStatus {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "userStatusEmpty" => {
+                    Deserialize::deserialize(value).map(UserStatus::UserStatusEmpty)
+                }
+                "userStatusOnline" => {
+                    Deserialize::deserialize(value).map(UserStatus::UserStatusOnline)
+                }
+                "userStatusOffline" => {
+                    Deserialize::deserialize(value).map(UserStatus::UserStatusOffline)
+                }
+                "userStatusRecently" => {
+                    Deserialize::deserialize(value).map(UserStatus::UserStatusRecently)
+                }
+                "userStatusLastWeek" => {
+                    Deserialize::deserialize(value).map(UserStatus::UserStatusLastWeek)
+                }
+                "userStatusLastMonth" => {
+                    Deserialize::deserialize(value).map(UserStatus::UserStatusLastMonth)
+                }
+                _ => Unknown::deserialize(value).map(UserStatus::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for UserStatus {
+        const CLASS: &'static str = "UserStatus";
+        fn type_name(&self) -> &str {
+            match self {
+                UserStatus::UserStatusEmpty(_) => "userStatusEmpty",
+                UserStatus::UserStatusOnline(_) => "userStatusOnline",
+                UserStatus::UserStatusOffline(_) => "userStatusOffline",
+                UserStatus::UserStatusRecently(_) => "userStatusRecently",
+                UserStatus::UserStatusLastWeek(_) => "userStatusLastWeek",
+                UserStatus::UserStatusLastMonth(_) => "userStatusLastMonth",
+                UserStatus::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The call wasn't discarded, or the reason is unknown"]
+    pub struct CallDiscardReasonEmpty {}
+    impl TlObject for CallDiscardReasonEmpty {
+        const CLASS: &'static str = "CallDiscardReason";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for CallDiscardReasonEmpty {
+        const TYPE: &'static str = "callDiscardReasonEmpty";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The call was ended before the conversation started. It was cancelled by the caller or missed by the other party"]
+    pub struct CallDiscardReasonMissed {}
+    impl TlObject for CallDiscardReasonMissed {
+        const CLASS: &'static str = "CallDiscardReason";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for CallDiscardReasonMissed {
+        const TYPE: &'static str = "callDiscardReasonMissed";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The call was ended before the conversation started. It was declined by the other party"]
+    pub struct CallDiscardReasonDeclined {}
+    impl TlObject for CallDiscardReasonDeclined {
+        const CLASS: &'static str = "CallDiscardReason";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for CallDiscardReasonDeclined {
+        const TYPE: &'static str = "callDiscardReasonDeclined";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The call was ended during the conversation because the users were disconnected"]
+    pub struct CallDiscardReasonDisconnected {}
+    impl TlObject for CallDiscardReasonDisconnected {
+        const CLASS: &'static str = "CallDiscardReason";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for CallDiscardReasonDisconnected {
+        const TYPE: &'static str = "callDiscardReasonDisconnected";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The call was ended because one of the parties hung up"]
+    pub struct CallDiscardReasonHungUp {}
+    impl TlObject for CallDiscardReasonHungUp {
+        const CLASS: &'static str = "CallDiscardReason";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for CallDiscardReasonHungUp {
+        const TYPE: &'static str = "callDiscardReasonHungUp";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Describes the reason why a call was discarded"]
+    pub enum CallDiscardReason {
+        CallDiscardReasonEmpty(CallDiscardReasonEmpty),
+        CallDiscardReasonMissed(CallDiscardReasonMissed),
+        CallDiscardReasonDeclined(CallDiscardReasonDeclined),
+        CallDiscardReasonDisconnected(CallDiscardReasonDisconnected),
+        CallDiscardReasonHungUp(CallDiscardReasonHungUp),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for CallDiscardReason {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "callDiscardReasonEmpty" => {
+                    Deserialize::deserialize(value).map(CallDiscardReason::CallDiscardReasonEmpty)
+                }
+                "callDiscardReasonMissed" => {
+                    Deserialize::deserialize(value).map(CallDiscardReason::CallDiscardReasonMissed)
+                }
+                "callDiscardReasonDeclined" => Deserialize::deserialize(value)
+                    .map(CallDiscardReason::CallDiscardReasonDeclined),
+                "callDiscardReasonDisconnected" => Deserialize::deserialize(value)
+                    .map(CallDiscardReason::CallDiscardReasonDisconnected),
+                "callDiscardReasonHungUp" => {
+                    Deserialize::deserialize(value).map(CallDiscardReason::CallDiscardReasonHungUp)
+                }
+                _ => Unknown::deserialize(value).map(CallDiscardReason::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for CallDiscardReason {
+        const CLASS: &'static str = "CallDiscardReason";
+        fn type_name(&self) -> &str {
+            match self {
+                CallDiscardReason::CallDiscardReasonEmpty(_) => "callDiscardReasonEmpty",
+                CallDiscardReason::CallDiscardReasonMissed(_) => "callDiscardReasonMissed",
+                CallDiscardReason::CallDiscardReasonDeclined(_) => "callDiscardReasonDeclined",
+                CallDiscardReason::CallDiscardReasonDisconnected(_) => {
+                    "callDiscardReasonDisconnected"
+                }
+                CallDiscardReason::CallDiscardReasonHungUp(_) => "callDiscardReasonHungUp",
+                CallDiscardReason::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Represents a list of animations"]
+    pub struct Animations {
+        #[doc = "List of animations"]
+        pub animations: Vec<Animation>,
+    }
+    impl TlObject for Animations {
+        const CLASS: &'static str = "Animations";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Animations {
+        const TYPE: &'static str = "animations";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Represents the result of an ImportContacts request"]
+    pub struct ImportedContacts {
+        #[doc = "User identifiers of the imported contacts in the same order as they were specified in the request; 0 if the contact is not yet a registered user"]
+        pub user_ids: Vec<UserId>,
+        #[doc = "The number of users that imported the corresponding contact; 0 for already registered users or if unavailable"]
+        pub importer_count: Vec<i32>,
+    }
+    impl TlObject for ImportedContacts {
+        const CLASS: &'static str = "ImportedContacts";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ImportedContacts {
+        const TYPE: &'static str = "importedContacts";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains an HTTP URL"]
+    pub struct HttpUrl {
+        #[doc = "The URL"]
+        pub url: String,
+    }
+    impl TlObject for HttpUrl {
+        const CLASS: &'static str = "HttpUrl";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for HttpUrl {
+        const TYPE: &'static str = "httpUrl";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "An ordinary language pack string"]
+    pub struct LanguagePackStringValueOrdinary {
+        #[doc = "String value"]
+        pub value: String,
+    }
+    impl TlObject for LanguagePackStringValueOrdinary {
+        const CLASS: &'static str = "LanguagePackStringValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for LanguagePackStringValueOrdinary {
+        const TYPE: &'static str = "languagePackStringValueOrdinary";
+    }
+    impl LanguagePackStringValueOrdinary {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "String value"]
+        pub fn with_value(mut self, value: impl Into<String>) -> Self {
+            self.value = value.into();
+            self
+        }
+    }
+    impl Validate for LanguagePackStringValueOrdinary {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A language pack string which has different forms based on the number of some object it mentions. See https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html for more info"]
+    pub struct LanguagePackStringValuePluralized {
+        #[doc = "Value for zero objects"]
+        pub zero_value: String,
+        #[doc = "Value for one object"]
+        pub one_value: String,
+        #[doc = "Value for two objects"]
+        pub two_value: String,
+        #[doc = "Value for few objects"]
+        pub few_value: String,
+        #[doc = "Value for many objects"]
+        pub many_value: String,
+        #[doc = "Default value"]
+        pub other_value: String,
+    }
+    impl TlObject for LanguagePackStringValuePluralized {
+        const CLASS: &'static str = "LanguagePackStringValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for LanguagePackStringValuePluralized {
+        const TYPE: &'static str = "languagePackStringValuePluralized";
+    }
+    impl LanguagePackStringValuePluralized {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Value for zero objects"]
+        pub fn with_zero_value(mut self, value: impl Into<String>) -> Self {
+            self.zero_value = value.into();
+            self
+        }
+        #[doc = "Value for one object"]
+        pub fn with_one_value(mut self, value: impl Into<String>) -> Self {
+            self.one_value = value.into();
+            self
+        }
+        #[doc = "Value for two objects"]
+        pub fn with_two_value(mut self, value: impl Into<String>) -> Self {
+            self.two_value = value.into();
+            self
+        }
+        #[doc = "Value for few objects"]
+        pub fn with_few_value(mut self, value: impl Into<String>) -> Self {
+            self.few_value = value.into();
+            self
+        }
+        #[doc = "Value for many objects"]
+        pub fn with_many_value(mut self, value: impl Into<String>) -> Self {
+            self.many_value = value.into();
+            self
+        }
+        #[doc = "Default value"]
+        pub fn with_other_value(mut self, value: impl Into<String>) -> Self {
+            self.other_value = value.into();
+            self
+        }
+    }
+    impl Validate for LanguagePackStringValuePluralized {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A deleted language pack string, the value should be taken from the built-in english language pack"]
+    pub struct LanguagePackStringValueDeleted {}
+    impl TlObject for LanguagePackStringValueDeleted {
+        const CLASS: &'static str = "LanguagePackStringValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for LanguagePackStringValueDeleted {
+        const TYPE: &'static str = "languagePackStringValueDeleted";
+    }
+    impl LanguagePackStringValueDeleted {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for LanguagePackStringValueDeleted {}
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Represents the value of a string in a language pack"]
+    pub enum LanguagePackStringValue {
+        LanguagePackStringValueOrdinary(LanguagePackStringValueOrdinary),
+        LanguagePackStringValuePluralized(Box<LanguagePackStringValuePluralized>),
+        LanguagePackStringValueDeleted(LanguagePackStringValueDeleted),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for LanguagePackStringValue {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "languagePackStringValueOrdinary" => Deserialize::deserialize(value)
+                    .map(LanguagePackStringValue::LanguagePackStringValueOrdinary),
+                "languagePackStringValuePluralized" => Deserialize::deserialize(value)
+                    .map(LanguagePackStringValue::LanguagePackStringValuePluralized),
+                "languagePackStringValueDeleted" => Deserialize::deserialize(value)
+                    .map(LanguagePackStringValue::LanguagePackStringValueDeleted),
+                _ => Unknown::deserialize(value).map(LanguagePackStringValue::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for LanguagePackStringValue {
+        const CLASS: &'static str = "LanguagePackStringValue";
+        fn type_name(&self) -> &str {
+            match self {
+                LanguagePackStringValue::LanguagePackStringValueOrdinary(_) => {
+                    "languagePackStringValueOrdinary"
+                }
+                LanguagePackStringValue::LanguagePackStringValuePluralized(_) => {
+                    "languagePackStringValuePluralized"
+                }
+                LanguagePackStringValue::LanguagePackStringValueDeleted(_) => {
+                    "languagePackStringValueDeleted"
+                }
+                LanguagePackStringValue::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for LanguagePackStringValue {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                LanguagePackStringValue::LanguagePackStringValueOrdinary(value) => value.validate(),
+                LanguagePackStringValue::LanguagePackStringValuePluralized(value) => {
+                    value.validate()
+                }
+                LanguagePackStringValue::LanguagePackStringValueDeleted(value) => value.validate(),
+                LanguagePackStringValue::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<LanguagePackStringValueOrdinary> for LanguagePackStringValue {
+        fn from(value: LanguagePackStringValueOrdinary) -> Self {
+            LanguagePackStringValue::LanguagePackStringValueOrdinary(value)
+        }
+    }
+    impl From<LanguagePackStringValuePluralized> for LanguagePackStringValue {
+        fn from(value: LanguagePackStringValuePluralized) -> Self {
+            LanguagePackStringValue::LanguagePackStringValuePluralized(Box::new(value))
+        }
+    }
+    impl From<LanguagePackStringValueDeleted> for LanguagePackStringValue {
+        fn from(value: LanguagePackStringValueDeleted) -> Self {
+            LanguagePackStringValue::LanguagePackStringValueDeleted(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Represents one language pack string"]
+    pub struct LanguagePackString {
+        #[doc = "String key"]
+        pub key: String,
+        #[doc = "String value"]
+        pub value: LanguagePackStringValue,
+    }
+    impl TlObject for LanguagePackString {
+        const CLASS: &'static str = "LanguagePackString";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for LanguagePackString {
+        const TYPE: &'static str = "languagePackString";
+    }
+    impl LanguagePackString {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(value: impl Into<LanguagePackStringValue>) -> Self {
+            Self {
+                key: Default::default(),
+                value: value.into(),
+            }
+        }
+        #[doc = "String key"]
+        pub fn with_key(mut self, value: impl Into<String>) -> Self {
+            self.key = value.into();
+            self
+        }
+    }
+    impl Validate for LanguagePackString {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "value"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains a list of language pack strings"]
+    pub struct LanguagePackStrings {
+        #[doc = "A list of language pack strings"]
+        pub strings: Vec<LanguagePackString>,
+    }
+    impl TlObject for LanguagePackStrings {
+        const CLASS: &'static str = "LanguagePackStrings";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for LanguagePackStrings {
+        const TYPE: &'static str = "languagePackStrings";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Contains information about a language pack"]
+    pub struct LanguagePackInfo {
+        #[doc = "Unique language pack identifier"]
+        pub id: String,
+        #[doc = "Identifier of a base language pack; may be empty. If a string is missed in the language pack, then it should be fetched from base language pack. Unsupported in custom language packs"]
+        pub base_language_pack_id: String,
+        #[doc = "Language name"]
+        pub name: String,
+        #[doc = "Name of the language in that language"]
+        pub native_name: String,
+        #[doc = "A language code to be used to apply plural forms. See https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html for more info"]
+        pub plural_code: String,
+        #[doc = "True, if the language pack is official"]
+        pub is_official: bool,
+        #[doc = "True, if the language pack strings are RTL"]
+        pub is_rtl: bool,
+        #[doc = "True, if the language pack is a beta language pack"]
+        pub is_beta: bool,
+        #[doc = "True, if the language pack is installed by the current user"]
+        pub is_installed: bool,
+        #[doc = "Total number of non-deleted strings from the language pack"]
+        pub total_string_count: i32,
+        #[doc = "Total number of translated strings from the language pack"]
+        pub translated_string_count: i32,
+        #[doc = "Total number of non-deleted strings from the language pack available locally"]
+        pub local_string_count: i32,
+        #[doc = "Link to language translation interface; empty for custom local language packs"]
+        pub translation_url: String,
+    }
+    impl TlObject for LanguagePackInfo {
+        const CLASS: &'static str = "LanguagePackInfo";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for LanguagePackInfo {
+        const TYPE: &'static str = "languagePackInfo";
+    }
+    impl LanguagePackInfo {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Unique language pack identifier"]
+        pub fn with_id(mut self, value: impl Into<String>) -> Self {
+            self.id = value.into();
+            self
+        }
+        #[doc = "Identifier of a base language pack; may be empty. If a string is missed in the language pack, then it should be fetched from base language pack. Unsupported in custom language packs"]
+        pub fn with_base_language_pack_id(mut self, value: impl Into<String>) -> Self {
+            self.base_language_pack_id = value.into();
+            self
+        }
+        #[doc = "Language name"]
+        pub fn with_name(mut self, value: impl Into<String>) -> Self {
+            self.name = value.into();
+            self
+        }
+        #[doc = "Name of the language in that language"]
+        pub fn with_native_name(mut self, value: impl Into<String>) -> Self {
+            self.native_name = value.into();
+            self
+        }
+        #[doc = "A language code to be used to apply plural forms. See https://www.unicode.org/cldr/charts/latest/supplemental/language_plural_rules.html for more info"]
+        pub fn with_plural_code(mut self, value: impl Into<String>) -> Self {
+            self.plural_code = value.into();
+            self
+        }
+        #[doc = "True, if the language pack is official"]
+        pub fn with_is_official(mut self, value: impl Into<bool>) -> Self {
+            self.is_official = value.into();
+            self
+        }
+        #[doc = "True, if the language pack strings are RTL"]
+        pub fn with_is_rtl(mut self, value: impl Into<bool>) -> Self {
+            self.is_rtl = value.into();
+            self
+        }
+        #[doc = "True, if the language pack is a beta language pack"]
+        pub fn with_is_beta(mut self, value: impl Into<bool>) -> Self {
+            self.is_beta = value.into();
+            self
+        }
+        #[doc = "True, if the language pack is installed by the current user"]
+        pub fn with_is_installed(mut self, value: impl Into<bool>) -> Self {
+            self.is_installed = value.into();
+            self
+        }
+        #[doc = "Total number of non-deleted strings from the language pack"]
+        pub fn with_total_string_count(mut self, value: impl Into<i32>) -> Self {
+            self.total_string_count = value.into();
+            self
+        }
+        #[doc = "Total number of translated strings from the language pack"]
+        pub fn with_translated_string_count(mut self, value: impl Into<i32>) -> Self {
+            self.translated_string_count = value.into();
+            self
+        }
+        #[doc = "Total number of non-deleted strings from the language pack available locally"]
+        pub fn with_local_string_count(mut self, value: impl Into<i32>) -> Self {
+            self.local_string_count = value.into();
+            self
+        }
+        #[doc = "Link to language translation interface; empty for custom local language packs"]
+        pub fn with_translation_url(mut self, value: impl Into<String>) -> Self {
+            self.translation_url = value.into();
+            self
+        }
+    }
+    impl Validate for LanguagePackInfo {}
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains information about the current localization target"]
+    pub struct LocalizationTargetInfo {
+        #[doc = "List of available language packs for this application"]
+        pub language_packs: Vec<LanguagePackInfo>,
+    }
+    impl TlObject for LocalizationTargetInfo {
+        const CLASS: &'static str = "LocalizationTargetInfo";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for LocalizationTargetInfo {
+        const TYPE: &'static str = "localizationTargetInfo";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A token for Firebase Cloud Messaging"]
+    pub struct DeviceTokenFirebaseCloudMessaging {
+        #[doc = "Device registration token; may be empty to de-register a device"]
+        pub token: String,
+        #[doc = "True, if push notifications should be additionally encrypted"]
+        pub encrypt: bool,
+    }
+    impl TlObject for DeviceTokenFirebaseCloudMessaging {
+        const CLASS: &'static str = "DeviceToken";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DeviceTokenFirebaseCloudMessaging {
+        const TYPE: &'static str = "deviceTokenFirebaseCloudMessaging";
+    }
+    impl DeviceTokenFirebaseCloudMessaging {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Device registration token; may be empty to de-register a device"]
+        pub fn with_token(mut self, value: impl Into<String>) -> Self {
+            self.token = value.into();
+            self
+        }
+        #[doc = "True, if push notifications should be additionally encrypted"]
+        pub fn with_encrypt(mut self, value: impl Into<bool>) -> Self {
+            self.encrypt = value.into();
+            self
+        }
+    }
+    impl Validate for DeviceTokenFirebaseCloudMessaging {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A token for Apple Push Notification service"]
+    pub struct DeviceTokenApplePush {
+        #[doc = "Device token; may be empty to de-register a device"]
+        pub device_token: String,
+        #[doc = "True, if App Sandbox is enabled"]
+        pub is_app_sandbox: bool,
+    }
+    impl TlObject for DeviceTokenApplePush {
+        const CLASS: &'static str = "DeviceToken";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DeviceTokenApplePush {
+        const TYPE: &'static str = "deviceTokenApplePush";
+    }
+    impl DeviceTokenApplePush {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Device token; may be empty to de-register a device"]
+        pub fn with_device_token(mut self, value: impl Into<String>) -> Self {
+            self.device_token = value.into();
+            self
+        }
+        #[doc = "True, if App Sandbox is enabled"]
+        pub fn with_is_app_sandbox(mut self, value: impl Into<bool>) -> Self {
+            self.is_app_sandbox = value.into();
+            self
+        }
+    }
+    impl Validate for DeviceTokenApplePush {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A token for Apple Push Notification service VoIP notifications"]
+    pub struct DeviceTokenApplePushVoIP {
+        #[doc = "Device token; may be empty to de-register a device"]
+        pub device_token: String,
+        #[doc = "True, if App Sandbox is enabled"]
+        pub is_app_sandbox: bool,
+        #[doc = "True, if push notifications should be additionally encrypted"]
+        pub encrypt: bool,
+    }
+    impl TlObject for DeviceTokenApplePushVoIP {
+        const CLASS: &'static str = "DeviceToken";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DeviceTokenApplePushVoIP {
+        const TYPE: &'static str = "deviceTokenApplePushVoIP";
+    }
+    impl DeviceTokenApplePushVoIP {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Device token; may be empty to de-register a device"]
+        pub fn with_device_token(mut self, value: impl Into<String>) -> Self {
+            self.device_token = value.into();
+            self
+        }
+        #[doc = "True, if App Sandbox is enabled"]
+        pub fn with_is_app_sandbox(mut self, value: impl Into<bool>) -> Self {
+            self.is_app_sandbox = value.into();
+            self
+        }
+        #[doc = "True, if push notifications should be additionally encrypted"]
+        pub fn with_encrypt(mut self, value: impl Into<bool>) -> Self {
+            self.encrypt = value.into();
+            self
+        }
+    }
+    impl Validate for DeviceTokenApplePushVoIP {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A token for Windows Push Notification Services"]
+    pub struct DeviceTokenWindowsPush {
+        #[doc = "The access token that will be used to send notifications; may be empty to de-register a device"]
+        pub access_token: String,
+    }
+    impl TlObject for DeviceTokenWindowsPush {
+        const CLASS: &'static str = "DeviceToken";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DeviceTokenWindowsPush {
+        const TYPE: &'static str = "deviceTokenWindowsPush";
+    }
+    impl DeviceTokenWindowsPush {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The access token that will be used to send notifications; may be empty to de-register a device"]
+        pub fn with_access_token(mut self, value: impl Into<String>) -> Self {
+            self.access_token = value.into();
+            self
+        }
+    }
+    impl Validate for DeviceTokenWindowsPush {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A token for Microsoft Push Notification Service"]
+    pub struct DeviceTokenMicrosoftPush {
+        #[doc = "Push notification channel URI; may be empty to de-register a device"]
+        pub channel_uri: String,
+    }
+    impl TlObject for DeviceTokenMicrosoftPush {
+        const CLASS: &'static str = "DeviceToken";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DeviceTokenMicrosoftPush {
+        const TYPE: &'static str = "deviceTokenMicrosoftPush";
+    }
+    impl DeviceTokenMicrosoftPush {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Push notification channel URI; may be empty to de-register a device"]
+        pub fn with_channel_uri(mut self, value: impl Into<String>) -> Self {
+            self.channel_uri = value.into();
+            self
+        }
+    }
+    impl Validate for DeviceTokenMicrosoftPush {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A token for Microsoft Push Notification Service VoIP channel"]
+    pub struct DeviceTokenMicrosoftPushVoIP {
+        #[doc = "Push notification channel URI; may be empty to de-register a device"]
+        pub channel_uri: String,
+    }
+    impl TlObject for DeviceTokenMicrosoftPushVoIP {
+        const CLASS: &'static str = "DeviceToken";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DeviceTokenMicrosoftPushVoIP {
+        const TYPE: &'static str = "deviceTokenMicrosoftPushVoIP";
+    }
+    impl DeviceTokenMicrosoftPushVoIP {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Push notification channel URI; may be empty to de-register a device"]
+        pub fn with_channel_uri(mut self, value: impl Into<String>) -> Self {
+            self.channel_uri = value.into();
+            self
+        }
+    }
+    impl Validate for DeviceTokenMicrosoftPushVoIP {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A token for web Push API"]
+    pub struct DeviceTokenWebPush {
+        #[doc = "Absolute URL exposed by the push service where the application server can send push messages; may be empty to de-register a device"]
+        pub endpoint: String,
+        #[doc = "Base64url-encoded P-256 elliptic curve Diffie-Hellman public key"]
+        pub p256dh_base64url: String,
+        #[doc = "Base64url-encoded authentication secret"]
+        pub auth_base64url: String,
+    }
+    impl TlObject for DeviceTokenWebPush {
+        const CLASS: &'static str = "DeviceToken";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DeviceTokenWebPush {
+        const TYPE: &'static str = "deviceTokenWebPush";
+    }
+    impl DeviceTokenWebPush {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Absolute URL exposed by the push service where the application server can send push messages; may be empty to de-register a device"]
+        pub fn with_endpoint(mut self, value: impl Into<String>) -> Self {
+            self.endpoint = value.into();
+            self
+        }
+        #[doc = "Base64url-encoded P-256 elliptic curve Diffie-Hellman public key"]
+        pub fn with_p256dh_base64url(mut self, value: impl Into<String>) -> Self {
+            self.p256dh_base64url = value.into();
+            self
+        }
+        #[doc = "Base64url-encoded authentication secret"]
+        pub fn with_auth_base64url(mut self, value: impl Into<String>) -> Self {
+            self.auth_base64url = value.into();
+            self
+        }
+    }
+    impl Validate for DeviceTokenWebPush {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A token for Simple Push API for Firefox OS"]
+    pub struct DeviceTokenSimplePush {
+        #[doc = "Absolute URL exposed by the push service where the application server can send push messages; may be empty to de-register a device"]
+        pub endpoint: String,
+    }
+    impl TlObject for DeviceTokenSimplePush {
+        const CLASS: &'static str = "DeviceToken";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DeviceTokenSimplePush {
+        const TYPE: &'static str = "deviceTokenSimplePush";
+    }
+    impl DeviceTokenSimplePush {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Absolute URL exposed by the push service where the application server can send push messages; may be empty to de-register a device"]
+        pub fn with_endpoint(mut self, value: impl Into<String>) -> Self {
+            self.endpoint = value.into();
+            self
+        }
+    }
+    impl Validate for DeviceTokenSimplePush {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A token for Ubuntu Push Client service"]
+    pub struct DeviceTokenUbuntuPush {
+        #[doc = "Token; may be empty to de-register a device"]
+        pub token: String,
+    }
+    impl TlObject for DeviceTokenUbuntuPush {
+        const CLASS: &'static str = "DeviceToken";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DeviceTokenUbuntuPush {
+        const TYPE: &'static str = "deviceTokenUbuntuPush";
+    }
+    impl DeviceTokenUbuntuPush {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Token; may be empty to de-register a device"]
+        pub fn with_token(mut self, value: impl Into<String>) -> Self {
+            self.token = value.into();
+            self
+        }
+    }
+    impl Validate for DeviceTokenUbuntuPush {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A token for BlackBerry Push Service"]
+    pub struct DeviceTokenBlackBerryPush {
+        #[doc = "Token; may be empty to de-register a device"]
+        pub token: String,
+    }
+    impl TlObject for DeviceTokenBlackBerryPush {
+        const CLASS: &'static str = "DeviceToken";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DeviceTokenBlackBerryPush {
+        const TYPE: &'static str = "deviceTokenBlackBerryPush";
+    }
+    impl DeviceTokenBlackBerryPush {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Token; may be empty to de-register a device"]
+        pub fn with_token(mut self, value: impl Into<String>) -> Self {
+            self.token = value.into();
+            self
+        }
+    }
+    impl Validate for DeviceTokenBlackBerryPush {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A token for Tizen Push Service"]
+    pub struct DeviceTokenTizenPush {
+        #[doc = "Push service registration identifier; may be empty to de-register a device"]
+        pub reg_id: String,
+    }
+    impl TlObject for DeviceTokenTizenPush {
+        const CLASS: &'static str = "DeviceToken";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DeviceTokenTizenPush {
+        const TYPE: &'static str = "deviceTokenTizenPush";
+    }
+    impl DeviceTokenTizenPush {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Push service registration identifier; may be empty to de-register a device"]
+        pub fn with_reg_id(mut self, value: impl Into<String>) -> Self {
+            self.reg_id = value.into();
+            self
+        }
+    }
+    impl Validate for DeviceTokenTizenPush {}
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Represents a data needed to subscribe for push notifications through registerDevice method. To use specific push notification service, you must specify the correct application platform and upload valid server authentication data at https://my.telegram.org"]
+    pub enum DeviceToken {
+        DeviceTokenFirebaseCloudMessaging(DeviceTokenFirebaseCloudMessaging),
+        DeviceTokenApplePush(DeviceTokenApplePush),
+        DeviceTokenApplePushVoIP(DeviceTokenApplePushVoIP),
+        DeviceTokenWindowsPush(DeviceTokenWindowsPush),
+        DeviceTokenMicrosoftPush(DeviceTokenMicrosoftPush),
+        DeviceTokenMicrosoftPushVoIP(DeviceTokenMicrosoftPushVoIP),
+        DeviceTokenWebPush(DeviceTokenWebPush),
+        DeviceTokenSimplePush(DeviceTokenSimplePush),
+        DeviceTokenUbuntuPush(DeviceTokenUbuntuPush),
+        DeviceTokenBlackBerryPush(DeviceTokenBlackBerryPush),
+        DeviceTokenTizenPush(DeviceTokenTizenPush),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for DeviceToken {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "deviceTokenFirebaseCloudMessaging" => Deserialize::deserialize(value)
+                    .map(DeviceToken::DeviceTokenFirebaseCloudMessaging),
+                "deviceTokenApplePush" => {
+                    Deserialize::deserialize(value).map(DeviceToken::DeviceTokenApplePush)
+                }
+                "deviceTokenApplePushVoIP" => {
+                    Deserialize::deserialize(value).map(DeviceToken::DeviceTokenApplePushVoIP)
+                }
+                "deviceTokenWindowsPush" => {
+                    Deserialize::deserialize(value).map(DeviceToken::DeviceTokenWindowsPush)
+                }
+                "deviceTokenMicrosoftPush" => {
+                    Deserialize::deserialize(value).map(DeviceToken::DeviceTokenMicrosoftPush)
+                }
+                "deviceTokenMicrosoftPushVoIP" => {
+                    Deserialize::deserialize(value).map(DeviceToken::DeviceTokenMicrosoftPushVoIP)
+                }
+                "deviceTokenWebPush" => {
+                    Deserialize::deserialize(value).map(DeviceToken::DeviceTokenWebPush)
+                }
+                "deviceTokenSimplePush" => {
+                    Deserialize::deserialize(value).map(DeviceToken::DeviceTokenSimplePush)
+                }
+                "deviceTokenUbuntuPush" => {
+                    Deserialize::deserialize(value).map(DeviceToken::DeviceTokenUbuntuPush)
+                }
+                "deviceTokenBlackBerryPush" => {
+                    Deserialize::deserialize(value).map(DeviceToken::DeviceTokenBlackBerryPush)
+                }
+                "deviceTokenTizenPush" => {
+                    Deserialize::deserialize(value).map(DeviceToken::DeviceTokenTizenPush)
+                }
+                _ => Unknown::deserialize(value).map(DeviceToken::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for DeviceToken {
+        const CLASS: &'static str = "DeviceToken";
+        fn type_name(&self) -> &str {
+            match self {
+                DeviceToken::DeviceTokenFirebaseCloudMessaging(_) => {
+                    "deviceTokenFirebaseCloudMessaging"
+                }
+                DeviceToken::DeviceTokenApplePush(_) => "deviceTokenApplePush",
+                DeviceToken::DeviceTokenApplePushVoIP(_) => "deviceTokenApplePushVoIP",
+                DeviceToken::DeviceTokenWindowsPush(_) => "deviceTokenWindowsPush",
+                DeviceToken::DeviceTokenMicrosoftPush(_) => "deviceTokenMicrosoftPush",
+                DeviceToken::DeviceTokenMicrosoftPushVoIP(_) => "deviceTokenMicrosoftPushVoIP",
+                DeviceToken::DeviceTokenWebPush(_) => "deviceTokenWebPush",
+                DeviceToken::DeviceTokenSimplePush(_) => "deviceTokenSimplePush",
+                DeviceToken::DeviceTokenUbuntuPush(_) => "deviceTokenUbuntuPush",
+                DeviceToken::DeviceTokenBlackBerryPush(_) => "deviceTokenBlackBerryPush",
+                DeviceToken::DeviceTokenTizenPush(_) => "deviceTokenTizenPush",
+                DeviceToken::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for DeviceToken {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                DeviceToken::DeviceTokenFirebaseCloudMessaging(value) => value.validate(),
+                DeviceToken::DeviceTokenApplePush(value) => value.validate(),
+                DeviceToken::DeviceTokenApplePushVoIP(value) => value.validate(),
+                DeviceToken::DeviceTokenWindowsPush(value) => value.validate(),
+                DeviceToken::DeviceTokenMicrosoftPush(value) => value.validate(),
+                DeviceToken::DeviceTokenMicrosoftPushVoIP(value) => value.validate(),
+                DeviceToken::DeviceTokenWebPush(value) => value.validate(),
+                DeviceToken::DeviceTokenSimplePush(value) => value.validate(),
+                DeviceToken::DeviceTokenUbuntuPush(value) => value.validate(),
+                DeviceToken::DeviceTokenBlackBerryPush(value) => value.validate(),
+                DeviceToken::DeviceTokenTizenPush(value) => value.validate(),
+                DeviceToken::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<DeviceTokenFirebaseCloudMessaging> for DeviceToken {
+        fn from(value: DeviceTokenFirebaseCloudMessaging) -> Self {
+            DeviceToken::DeviceTokenFirebaseCloudMessaging(value)
+        }
+    }
+    impl From<DeviceTokenApplePush> for DeviceToken {
+        fn from(value: DeviceTokenApplePush) -> Self {
+            DeviceToken::DeviceTokenApplePush(value)
+        }
+    }
+    impl From<DeviceTokenApplePushVoIP> for DeviceToken {
+        fn from(value: DeviceTokenApplePushVoIP) -> Self {
+            DeviceToken::DeviceTokenApplePushVoIP(value)
+        }
+    }
+    impl From<DeviceTokenWindowsPush> for DeviceToken {
+        fn from(value: DeviceTokenWindowsPush) -> Self {
+            DeviceToken::DeviceTokenWindowsPush(value)
+        }
+    }
+    impl From<DeviceTokenMicrosoftPush> for DeviceToken {
+        fn from(value: DeviceTokenMicrosoftPush) -> Self {
+            DeviceToken::DeviceTokenMicrosoftPush(value)
+        }
+    }
+    impl From<DeviceTokenMicrosoftPushVoIP> for DeviceToken {
+        fn from(value: DeviceTokenMicrosoftPushVoIP) -> Self {
+            DeviceToken::DeviceTokenMicrosoftPushVoIP(value)
+        }
+    }
+    impl From<DeviceTokenWebPush> for DeviceToken {
+        fn from(value: DeviceTokenWebPush) -> Self {
+            DeviceToken::DeviceTokenWebPush(value)
+        }
+    }
+    impl From<DeviceTokenSimplePush> for DeviceToken {
+        fn from(value: DeviceTokenSimplePush) -> Self {
+            DeviceToken::DeviceTokenSimplePush(value)
+        }
+    }
+    impl From<DeviceTokenUbuntuPush> for DeviceToken {
+        fn from(value: DeviceTokenUbuntuPush) -> Self {
+            DeviceToken::DeviceTokenUbuntuPush(value)
+        }
+    }
+    impl From<DeviceTokenBlackBerryPush> for DeviceToken {
+        fn from(value: DeviceTokenBlackBerryPush) -> Self {
+            DeviceToken::DeviceTokenBlackBerryPush(value)
+        }
+    }
+    impl From<DeviceTokenTizenPush> for DeviceToken {
+        fn from(value: DeviceTokenTizenPush) -> Self {
+            DeviceToken::DeviceTokenTizenPush(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains a globally unique push receiver identifier, which can be used to identify which account has received a push notification"]
+    pub struct PushReceiverId {
+        #[serde(
+            deserialize_with = "crate::utils::from_str_to_t",
+            serialize_with = "crate::utils::t_to_str"
+        )]
+        #[doc = "The globally unique identifier of push notification subscription"]
+        pub id: i64,
+    }
+    impl TlObject for PushReceiverId {
+        const CLASS: &'static str = "PushReceiverId";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for PushReceiverId {
+        const TYPE: &'static str = "pushReceiverId";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Describes a solid fill of a background"]
+    pub struct BackgroundFillSolid {
+        #[doc = "A color of the background in the RGB24 format"]
+        pub color: i32,
+    }
+    impl TlObject for BackgroundFillSolid {
+        const CLASS: &'static str = "BackgroundFill";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for BackgroundFillSolid {
+        const TYPE: &'static str = "backgroundFillSolid";
+    }
+    impl BackgroundFillSolid {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "A color of the background in the RGB24 format"]
+        pub fn with_color(mut self, value: impl Into<i32>) -> Self {
+            self.color = value.into();
+            self
+        }
+    }
+    impl Validate for BackgroundFillSolid {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Describes a gradient fill of a background"]
+    pub struct BackgroundFillGradient {
+        #[doc = "A top color of the background in the RGB24 format"]
+        pub top_color: i32,
+        #[doc = "A bottom color of the background in the RGB24 format"]
+        pub bottom_color: i32,
+        #[doc = "Clockwise rotation angle of the gradient, in degrees; 0-359. Should be always divisible by 45"]
+        pub rotation_angle: i32,
+    }
+    impl TlObject for BackgroundFillGradient {
+        const CLASS: &'static str = "BackgroundFill";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for BackgroundFillGradient {
+        const TYPE: &'static str = "backgroundFillGradient";
+    }
+    impl BackgroundFillGradient {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "A top color of the background in the RGB24 format"]
+        pub fn with_top_color(mut self, value: impl Into<i32>) -> Self {
+            self.top_color = value.into();
+            self
+        }
+        #[doc = "A bottom color of the background in the RGB24 format"]
+        pub fn with_bottom_color(mut self, value: impl Into<i32>) -> Self {
+            self.bottom_color = value.into();
+            self
+        }
+        #[doc = "Clockwise rotation angle of the gradient, in degrees; 0-359. Should be always divisible by 45"]
+        pub fn with_rotation_angle(mut self, value: impl Into<i32>) -> Self {
+            self.rotation_angle = value.into();
+            self
+        }
+    }
+    impl Validate for BackgroundFillGradient {
+        fn validate(&self) -> Result<(), ValidationError> {
+            validate::check(
+                Self::TYPE,
+                "rotation_angle",
+                Measure::Value,
+                i64::from(self.rotation_angle),
+                Some(0),
+                Some(359),
+            )?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Describes a fill of a background"]
+    pub enum BackgroundFill {
+        BackgroundFillSolid(BackgroundFillSolid),
+        BackgroundFillGradient(BackgroundFillGradient),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for BackgroundFill {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "backgroundFillSolid" => {
+                    Deserialize::deserialize(value).map(BackgroundFill::BackgroundFillSolid)
+                }
+                "backgroundFillGradient" => {
+                    Deserialize::deserialize(value).map(BackgroundFill::BackgroundFillGradient)
+                }
+                _ => Unknown::deserialize(value).map(BackgroundFill::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for BackgroundFill {
+        const CLASS: &'static str = "BackgroundFill";
+        fn type_name(&self) -> &str {
+            match self {
+                BackgroundFill::BackgroundFillSolid(_) => "backgroundFillSolid",
+                BackgroundFill::BackgroundFillGradient(_) => "backgroundFillGradient",
+                BackgroundFill::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for BackgroundFill {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                BackgroundFill::BackgroundFillSolid(value) => value.validate(),
+                BackgroundFill::BackgroundFillGradient(value) => value.validate(),
+                BackgroundFill::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<BackgroundFillSolid> for BackgroundFill {
+        fn from(value: BackgroundFillSolid) -> Self {
+            BackgroundFill::BackgroundFillSolid(value)
+        }
+    }
+    impl From<BackgroundFillGradient> for BackgroundFill {
+        fn from(value: BackgroundFillGradient) -> Self {
+            BackgroundFill::BackgroundFillGradient(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A wallpaper in JPEG format"]
+    pub struct BackgroundTypeWallpaper {
+        #[doc = "True, if the wallpaper must be downscaled to fit in 450x450 square and then box-blurred with radius 12"]
+        pub is_blurred: bool,
+        #[doc = "True, if the background needs to be slightly moved when device is tilted"]
+        pub is_moving: bool,
+    }
+    impl TlObject for BackgroundTypeWallpaper {
+        const CLASS: &'static str = "BackgroundType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for BackgroundTypeWallpaper {
+        const TYPE: &'static str = "backgroundTypeWallpaper";
+    }
+    impl BackgroundTypeWallpaper {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "True, if the wallpaper must be downscaled to fit in 450x450 square and then box-blurred with radius 12"]
+        pub fn with_is_blurred(mut self, value: impl Into<bool>) -> Self {
+            self.is_blurred = value.into();
+            self
+        }
+        #[doc = "True, if the background needs to be slightly moved when device is tilted"]
+        pub fn with_is_moving(mut self, value: impl Into<bool>) -> Self {
+            self.is_moving = value.into();
+            self
+        }
+    }
+    impl Validate for BackgroundTypeWallpaper {}
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A PNG or TGV (gzipped subset of SVG with MIME type \"application/x-tgwallpattern\") pattern to be combined with the background fill chosen by the user"]
+    pub struct BackgroundTypePattern {
+        #[doc = "Description of the background fill"]
+        pub fill: BackgroundFill,
+        #[doc = "Intensity of the pattern when it is shown above the filled background, 0-100"]
+        pub intensity: i32,
+        #[doc = "True, if the background needs to be slightly moved when device is tilted"]
+        pub is_moving: bool,
+    }
+    impl TlObject for BackgroundTypePattern {
+        const CLASS: &'static str = "BackgroundType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for BackgroundTypePattern {
+        const TYPE: &'static str = "backgroundTypePattern";
+    }
+    impl BackgroundTypePattern {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(fill: impl Into<BackgroundFill>) -> Self {
+            Self {
+                fill: fill.into(),
+                intensity: Default::default(),
+                is_moving: Default::default(),
+            }
+        }
+        #[doc = "Intensity of the pattern when it is shown above the filled background, 0-100"]
+        pub fn with_intensity(mut self, value: impl Into<i32>) -> Self {
+            self.intensity = value.into();
+            self
+        }
+        #[doc = "True, if the background needs to be slightly moved when device is tilted"]
+        pub fn with_is_moving(mut self, value: impl Into<bool>) -> Self {
+            self.is_moving = value.into();
+            self
+        }
+    }
+    impl Validate for BackgroundTypePattern {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.fill
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "fill"))?;
+            validate::check(
+                Self::TYPE,
+                "intensity",
+                Measure::Value,
+                i64::from(self.intensity),
+                Some(0),
+                Some(100),
+            )?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A filled background"]
+    pub struct BackgroundTypeFill {
+        #[doc = "Description of the background fill"]
+        pub fill: BackgroundFill,
+    }
+    impl TlObject for BackgroundTypeFill {
+        const CLASS: &'static str = "BackgroundType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for BackgroundTypeFill {
+        const TYPE: &'static str = "backgroundTypeFill";
+    }
+    impl BackgroundTypeFill {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(fill: impl Into<BackgroundFill>) -> Self {
+            Self { fill: fill.into() }
+        }
+    }
+    impl Validate for BackgroundTypeFill {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.fill
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "fill"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Describes the type of a background"]
+    pub enum BackgroundType {
+        BackgroundTypeWallpaper(BackgroundTypeWallpaper),
+        BackgroundTypePattern(BackgroundTypePattern),
+        BackgroundTypeFill(BackgroundTypeFill),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for BackgroundType {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "backgroundTypeWallpaper" => {
+                    Deserialize::deserialize(value).map(BackgroundType::BackgroundTypeWallpaper)
+                }
+                "backgroundTypePattern" => {
+                    Deserialize::deserialize(value).map(BackgroundType::BackgroundTypePattern)
+                }
+                "backgroundTypeFill" => {
+                    Deserialize::deserialize(value).map(BackgroundType::BackgroundTypeFill)
+                }
+                _ => Unknown::deserialize(value).map(BackgroundType::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for BackgroundType {
+        const CLASS: &'static str = "BackgroundType";
+        fn type_name(&self) -> &str {
+            match self {
+                BackgroundType::BackgroundTypeWallpaper(_) => "backgroundTypeWallpaper",
+                BackgroundType::BackgroundTypePattern(_) => "backgroundTypePattern",
+                BackgroundType::BackgroundTypeFill(_) => "backgroundTypeFill",
+                BackgroundType::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for BackgroundType {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                BackgroundType::BackgroundTypeWallpaper(value) => value.validate(),
+                BackgroundType::BackgroundTypePattern(value) => value.validate(),
+                BackgroundType::BackgroundTypeFill(value) => value.validate(),
+                BackgroundType::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<BackgroundTypeWallpaper> for BackgroundType {
+        fn from(value: BackgroundTypeWallpaper) -> Self {
+            BackgroundType::BackgroundTypeWallpaper(value)
+        }
+    }
+    impl From<BackgroundTypePattern> for BackgroundType {
+        fn from(value: BackgroundTypePattern) -> Self {
+            BackgroundType::BackgroundTypePattern(value)
+        }
+    }
+    impl From<BackgroundTypeFill> for BackgroundType {
+        fn from(value: BackgroundTypeFill) -> Self {
+            BackgroundType::BackgroundTypeFill(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a chat background"]
+    pub struct Background {
+        #[serde(
+            deserialize_with = "crate::utils::from_str_to_t",
+            serialize_with = "crate::utils::t_to_str"
+        )]
+        #[doc = "Unique background identifier"]
+        pub id: i64,
+        #[doc = "True, if this is one of default backgrounds"]
+        pub is_default: bool,
+        #[doc = "True, if the background is dark and is recommended to be used with dark theme"]
+        pub is_dark: bool,
+        #[doc = "Unique background name"]
+        pub name: String,
+        #[serde(default)]
+        #[doc = "Document with the background; may be null. Null only for filled backgrounds"]
+        pub document: Option<Document>,
+        #[serde(rename = "type")]
+        #[doc = "Type of the background"]
+        pub type_: BackgroundType,
+    }
+    impl TlObject for Background {
+        const CLASS: &'static str = "Background";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Background {
+        const TYPE: &'static str = "background";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains a list of backgrounds"]
+    pub struct Backgrounds {
+        #[doc = "A list of backgrounds"]
+        pub backgrounds: Vec<Background>,
+    }
+    impl TlObject for Backgrounds {
+        const CLASS: &'static str = "Backgrounds";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Backgrounds {
+        const TYPE: &'static str = "backgrounds";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A background from a local file"]
+    pub struct InputBackgroundLocal {
+        #[doc = "Background file to use. Only inputFileLocal and inputFileGenerated are supported. The file must be in JPEG format for wallpapers and in PNG format for patterns"]
+        pub background: InputFile,
+    }
+    impl TlObject for InputBackgroundLocal {
+        const CLASS: &'static str = "InputBackground";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for InputBackgroundLocal {
+        const TYPE: &'static str = "inputBackgroundLocal";
+    }
+    impl InputBackgroundLocal {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(background: impl Into<InputFile>) -> Self {
+            Self {
+                background: background.into(),
+            }
+        }
+    }
+    impl Validate for InputBackgroundLocal {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.background
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "background"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A background from the server"]
+    pub struct InputBackgroundRemote {
+        #[serde(
+            deserialize_with = "crate::utils::from_str_to_t",
+            serialize_with = "crate::utils::t_to_str"
+        )]
+        #[doc = "The background identifier"]
+        pub background_id: i64,
+    }
+    impl TlObject for InputBackgroundRemote {
+        const CLASS: &'static str = "InputBackground";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for InputBackgroundRemote {
+        const TYPE: &'static str = "inputBackgroundRemote";
+    }
+    impl InputBackgroundRemote {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The background identifier"]
+        pub fn with_background_id(mut self, value: impl Into<i64>) -> Self {
+            self.background_id = value.into();
+            self
+        }
+    }
+    impl Validate for InputBackgroundRemote {}
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Contains information about background to set"]
+    pub enum InputBackground {
+        InputBackgroundLocal(InputBackgroundLocal),
+        InputBackgroundRemote(InputBackgroundRemote),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for InputBackground {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "inputBackgroundLocal" => {
+                    Deserialize::deserialize(value).map(InputBackground::InputBackgroundLocal)
+                }
+                "inputBackgroundRemote" => {
+                    Deserialize::deserialize(value).map(InputBackground::InputBackgroundRemote)
+                }
+                _ => Unknown::deserialize(value).map(InputBackground::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for InputBackground {
+        const CLASS: &'static str = "InputBackground";
+        fn type_name(&self) -> &str {
+            match self {
+                InputBackground::InputBackgroundLocal(_) => "inputBackgroundLocal",
+                InputBackground::InputBackgroundRemote(_) => "inputBackgroundRemote",
+                InputBackground::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for InputBackground {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                InputBackground::InputBackgroundLocal(value) => value.validate(),
+                InputBackground::InputBackgroundRemote(value) => value.validate(),
+                InputBackground::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<InputBackgroundLocal> for InputBackground {
+        fn from(value: InputBackgroundLocal) -> Self {
+            InputBackground::InputBackgroundLocal(value)
+        }
+    }
+    impl From<InputBackgroundRemote> for InputBackground {
+        fn from(value: InputBackgroundRemote) -> Self {
+            InputBackground::InputBackgroundRemote(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains a list of hashtags"]
+    pub struct Hashtags {
+        #[doc = "A list of hashtags"]
+        pub hashtags: Vec<String>,
+    }
+    impl TlObject for Hashtags {
+        const CLASS: &'static str = "Hashtags";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Hashtags {
+        const TYPE: &'static str = "hashtags";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The session can be used"]
+    pub struct CanTransferOwnershipResultOk {}
+    impl TlObject for CanTransferOwnershipResultOk {
+        const CLASS: &'static str = "CanTransferOwnershipResult";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for CanTransferOwnershipResultOk {
+        const TYPE: &'static str = "canTransferOwnershipResultOk";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The 2-step verification needs to be enabled first"]
+    pub struct CanTransferOwnershipResultPasswordNeeded {}
+    impl TlObject for CanTransferOwnershipResultPasswordNeeded {
+        const CLASS: &'static str = "CanTransferOwnershipResult";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for CanTransferOwnershipResultPasswordNeeded {
+        const TYPE: &'static str = "canTransferOwnershipResultPasswordNeeded";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The 2-step verification was enabled recently, user needs to wait"]
+    pub struct CanTransferOwnershipResultPasswordTooFresh {
+        #[doc = "Time left before the session can be used to transfer ownership of a chat, in seconds"]
+        pub retry_after: i32,
+    }
+    impl TlObject for CanTransferOwnershipResultPasswordTooFresh {
+        const CLASS: &'static str = "CanTransferOwnershipResult";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for CanTransferOwnershipResultPasswordTooFresh {
+        const TYPE: &'static str = "canTransferOwnershipResultPasswordTooFresh";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The session was created recently, user needs to wait"]
+    pub struct CanTransferOwnershipResultSessionTooFresh {
+        #[doc = "Time left before the session can be used to transfer ownership of a chat, in seconds"]
+        pub retry_after: i32,
+    }
+    impl TlObject for CanTransferOwnershipResultSessionTooFresh {
+        const CLASS: &'static str = "CanTransferOwnershipResult";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for CanTransferOwnershipResultSessionTooFresh {
+        const TYPE: &'static str = "canTransferOwnershipResultSessionTooFresh";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Represents result of checking whether the current session can be used to transfer a chat ownership to another user"]
+    pub enum CanTransferOwnershipResult {
+        CanTransferOwnershipResultOk(CanTransferOwnershipResultOk),
+        CanTransferOwnershipResultPasswordNeeded(CanTransferOwnershipResultPasswordNeeded),
+        CanTransferOwnershipResultPasswordTooFresh(CanTransferOwnershipResultPasswordTooFresh),
+        CanTransferOwnershipResultSessionTooFresh(CanTransferOwnershipResultSessionTooFresh),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for CanTransferOwnershipResult {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "canTransferOwnershipResultOk" => Deserialize::deserialize(value)
+                    .map(CanTransferOwnershipResult::CanTransferOwnershipResultOk),
+                "canTransferOwnershipResultPasswordNeeded" => Deserialize::deserialize(value)
+                    .map(CanTransferOwnershipResult::CanTransferOwnershipResultPasswordNeeded),
+                "canTransferOwnershipResultPasswordTooFresh" => Deserialize::deserialize(value)
+                    .map(CanTransferOwnershipResult::CanTransferOwnershipResultPasswordTooFresh),
+                "canTransferOwnershipResultSessionTooFresh" => Deserialize::deserialize(value)
+                    .map(CanTransferOwnershipResult::CanTransferOwnershipResultSessionTooFresh),
+                _ => Unknown::deserialize(value).map(CanTransferOwnershipResult::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for CanTransferOwnershipResult {
+        const CLASS: &'static str = "CanTransferOwnershipResult";
+        fn type_name(&self) -> &str {
+            match self {
+                CanTransferOwnershipResult::CanTransferOwnershipResultOk(_) => {
+                    "canTransferOwnershipResultOk"
+                }
+                CanTransferOwnershipResult::CanTransferOwnershipResultPasswordNeeded(_) => {
+                    "canTransferOwnershipResultPasswordNeeded"
+                }
+                CanTransferOwnershipResult::CanTransferOwnershipResultPasswordTooFresh(_) => {
+                    "canTransferOwnershipResultPasswordTooFresh"
+                }
+                CanTransferOwnershipResult::CanTransferOwnershipResultSessionTooFresh(_) => {
+                    "canTransferOwnershipResultSessionTooFresh"
+                }
+                CanTransferOwnershipResult::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Contains detailed information about a notification"]
+    pub enum NotificationType {
+        #[cfg(feature = "messages")]
+        NotificationTypeNewMessage(Box<NotificationTypeNewMessage>),
+        #[cfg(feature = "chats")]
+        NotificationTypeNewSecretChat(NotificationTypeNewSecretChat),
+        #[cfg(feature = "calls")]
+        NotificationTypeNewCall(NotificationTypeNewCall),
+        #[cfg(feature = "messages")]
+        NotificationTypeNewPushMessage(NotificationTypeNewPushMessage),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for NotificationType {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                #[cfg(feature = "messages")]
+                "notificationTypeNewMessage" => Deserialize::deserialize(value)
+                    .map(NotificationType::NotificationTypeNewMessage),
+                #[cfg(feature = "chats")]
+                "notificationTypeNewSecretChat" => Deserialize::deserialize(value)
+                    .map(NotificationType::NotificationTypeNewSecretChat),
+                #[cfg(feature = "calls")]
+                "notificationTypeNewCall" => {
+                    Deserialize::deserialize(value).map(NotificationType::NotificationTypeNewCall)
+                }
+                #[cfg(feature = "messages")]
+                "notificationTypeNewPushMessage" => Deserialize::deserialize(value)
+                    .map(NotificationType::NotificationTypeNewPushMessage),
+                _ => Unknown::deserialize(value).map(NotificationType::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for NotificationType {
+        const CLASS: &'static str = "NotificationType";
+        fn type_name(&self) -> &str {
+            match self {
+                #[cfg(feature = "messages")]
+                NotificationType::NotificationTypeNewMessage(_) => "notificationTypeNewMessage",
+                #[cfg(feature = "chats")]
+                NotificationType::NotificationTypeNewSecretChat(_) => {
+                    "notificationTypeNewSecretChat"
+                }
+                #[cfg(feature = "calls")]
+                NotificationType::NotificationTypeNewCall(_) => "notificationTypeNewCall",
+                #[cfg(feature = "messages")]
+                NotificationType::NotificationTypeNewPushMessage(_) => {
+                    "notificationTypeNewPushMessage"
+                }
+                NotificationType::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with ordinary unread messages"]
+    pub struct NotificationGroupTypeMessages {}
+    impl TlObject for NotificationGroupTypeMessages {
+        const CLASS: &'static str = "NotificationGroupType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NotificationGroupTypeMessages {
+        const TYPE: &'static str = "notificationGroupTypeMessages";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A group containing notifications of type notificationTypeNewMessage and notificationTypeNewPushMessage with unread mentions of the current user, replies to their messages, or a pinned message"]
+    pub struct NotificationGroupTypeMentions {}
+    impl TlObject for NotificationGroupTypeMentions {
+        const CLASS: &'static str = "NotificationGroupType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NotificationGroupTypeMentions {
+        const TYPE: &'static str = "notificationGroupTypeMentions";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A group containing a notification of type notificationTypeNewSecretChat"]
+    pub struct NotificationGroupTypeSecretChat {}
+    impl TlObject for NotificationGroupTypeSecretChat {
+        const CLASS: &'static str = "NotificationGroupType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NotificationGroupTypeSecretChat {
+        const TYPE: &'static str = "notificationGroupTypeSecretChat";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A group containing notifications of type notificationTypeNewCall"]
+    pub struct NotificationGroupTypeCalls {}
+    impl TlObject for NotificationGroupTypeCalls {
+        const CLASS: &'static str = "NotificationGroupType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NotificationGroupTypeCalls {
+        const TYPE: &'static str = "notificationGroupTypeCalls";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Describes the type of notifications in a notification group"]
+    pub enum NotificationGroupType {
+        NotificationGroupTypeMessages(NotificationGroupTypeMessages),
+        NotificationGroupTypeMentions(NotificationGroupTypeMentions),
+        NotificationGroupTypeSecretChat(NotificationGroupTypeSecretChat),
+        NotificationGroupTypeCalls(NotificationGroupTypeCalls),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for NotificationGroupType {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "notificationGroupTypeMessages" => Deserialize::deserialize(value)
+                    .map(NotificationGroupType::NotificationGroupTypeMessages),
+                "notificationGroupTypeMentions" => Deserialize::deserialize(value)
+                    .map(NotificationGroupType::NotificationGroupTypeMentions),
+                "notificationGroupTypeSecretChat" => Deserialize::deserialize(value)
+                    .map(NotificationGroupType::NotificationGroupTypeSecretChat),
+                "notificationGroupTypeCalls" => Deserialize::deserialize(value)
+                    .map(NotificationGroupType::NotificationGroupTypeCalls),
+                _ => Unknown::deserialize(value).map(NotificationGroupType::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for NotificationGroupType {
+        const CLASS: &'static str = "NotificationGroupType";
+        fn type_name(&self) -> &str {
+            match self {
+                NotificationGroupType::NotificationGroupTypeMessages(_) => {
+                    "notificationGroupTypeMessages"
+                }
+                NotificationGroupType::NotificationGroupTypeMentions(_) => {
+                    "notificationGroupTypeMentions"
+                }
+                NotificationGroupType::NotificationGroupTypeSecretChat(_) => {
+                    "notificationGroupTypeSecretChat"
+                }
+                NotificationGroupType::NotificationGroupTypeCalls(_) => {
+                    "notificationGroupTypeCalls"
+                }
+                NotificationGroupType::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains information about a notification"]
+    pub struct Notification {
+        #[doc = "Unique persistent identifier of this notification"]
+        pub id: NotificationId,
+        #[doc = "Notification date"]
+        pub date: i32,
+        #[doc = "True, if the notification was initially silent"]
+        pub is_silent: bool,
+        #[serde(rename = "type")]
+        #[doc = "Notification type"]
+        pub type_: NotificationType,
+    }
+    impl TlObject for Notification {
+        const CLASS: &'static str = "Notification";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Notification {
+        const TYPE: &'static str = "notification";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes a group of notifications"]
+    pub struct NotificationGroup {
+        #[doc = "Unique persistent auto-incremented from 1 identifier of the notification group"]
+        pub id: NotificationGroupId,
+        #[serde(rename = "type")]
+        #[doc = "Type of the group"]
+        pub type_: NotificationGroupType,
+        #[doc = "Identifier of a chat to which all notifications in the group belong"]
+        pub chat_id: ChatId,
+        #[doc = "Total number of active notifications in the group"]
+        pub total_count: i32,
+        #[doc = "The list of active notifications"]
+        pub notifications: Vec<Notification>,
+    }
+    impl TlObject for NotificationGroup {
+        const CLASS: &'static str = "NotificationGroup";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NotificationGroup {
+        const TYPE: &'static str = "notificationGroup";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Represents a boolean option"]
+    pub struct OptionValueBoolean {
+        #[doc = "The value of the option"]
+        pub value: bool,
+    }
+    impl TlObject for OptionValueBoolean {
+        const CLASS: &'static str = "OptionValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for OptionValueBoolean {
+        const TYPE: &'static str = "optionValueBoolean";
+    }
+    impl OptionValueBoolean {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The value of the option"]
+        pub fn with_value(mut self, value: impl Into<bool>) -> Self {
+            self.value = value.into();
+            self
+        }
+    }
+    impl Validate for OptionValueBoolean {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Represents an unknown option or an option which has a default value"]
+    pub struct OptionValueEmpty {}
+    impl TlObject for OptionValueEmpty {
+        const CLASS: &'static str = "OptionValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for OptionValueEmpty {
+        const TYPE: &'static str = "optionValueEmpty";
+    }
+    impl OptionValueEmpty {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for OptionValueEmpty {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Represents an integer option"]
+    pub struct OptionValueInteger {
+        #[doc = "The value of the option"]
+        pub value: i32,
+    }
+    impl TlObject for OptionValueInteger {
+        const CLASS: &'static str = "OptionValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for OptionValueInteger {
+        const TYPE: &'static str = "optionValueInteger";
+    }
+    impl OptionValueInteger {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The value of the option"]
+        pub fn with_value(mut self, value: impl Into<i32>) -> Self {
+            self.value = value.into();
+            self
+        }
+    }
+    impl Validate for OptionValueInteger {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Represents a string option"]
+    pub struct OptionValueString {
+        #[doc = "The value of the option"]
+        pub value: String,
+    }
+    impl TlObject for OptionValueString {
+        const CLASS: &'static str = "OptionValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for OptionValueString {
+        const TYPE: &'static str = "optionValueString";
+    }
+    impl OptionValueString {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The value of the option"]
+        pub fn with_value(mut self, value: impl Into<String>) -> Self {
+            self.value = value.into();
+            self
+        }
+    }
+    impl Validate for OptionValueString {}
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Represents the value of an option"]
+    pub enum OptionValue {
+        OptionValueBoolean(OptionValueBoolean),
+        OptionValueEmpty(OptionValueEmpty),
+        OptionValueInteger(OptionValueInteger),
+        OptionValueString(OptionValueString),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for OptionValue {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "optionValueBoolean" => {
+                    Deserialize::deserialize(value).map(OptionValue::OptionValueBoolean)
+                }
+                "optionValueEmpty" => {
+                    Deserialize::deserialize(value).map(OptionValue::OptionValueEmpty)
+                }
+                "optionValueInteger" => {
+                    Deserialize::deserialize(value).map(OptionValue::OptionValueInteger)
+                }
+                "optionValueString" => {
+                    Deserialize::deserialize(value).map(OptionValue::OptionValueString)
+                }
+                _ => Unknown::deserialize(value).map(OptionValue::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for OptionValue {
+        const CLASS: &'static str = "OptionValue";
+        fn type_name(&self) -> &str {
+            match self {
+                OptionValue::OptionValueBoolean(_) => "optionValueBoolean",
+                OptionValue::OptionValueEmpty(_) => "optionValueEmpty",
+                OptionValue::OptionValueInteger(_) => "optionValueInteger",
+                OptionValue::OptionValueString(_) => "optionValueString",
+                OptionValue::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for OptionValue {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                OptionValue::OptionValueBoolean(value) => value.validate(),
+                OptionValue::OptionValueEmpty(value) => value.validate(),
+                OptionValue::OptionValueInteger(value) => value.validate(),
+                OptionValue::OptionValueString(value) => value.validate(),
+                OptionValue::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<OptionValueBoolean> for OptionValue {
+        fn from(value: OptionValueBoolean) -> Self {
+            OptionValue::OptionValueBoolean(value)
+        }
+    }
+    impl From<OptionValueEmpty> for OptionValue {
+        fn from(value: OptionValueEmpty) -> Self {
+            OptionValue::OptionValueEmpty(value)
+        }
+    }
+    impl From<OptionValueInteger> for OptionValue {
+        fn from(value: OptionValueInteger) -> Self {
+            OptionValue::OptionValueInteger(value)
+        }
+    }
+    impl From<OptionValueString> for OptionValue {
+        fn from(value: OptionValueString) -> Self {
+            OptionValue::OptionValueString(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Represents one member of a JSON object"]
+    pub struct JsonObjectMember {
+        #[doc = "Member's key"]
+        pub key: String,
+        #[doc = "Member's value"]
+        pub value: JsonValue,
+    }
+    impl TlObject for JsonObjectMember {
+        const CLASS: &'static str = "JsonObjectMember";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for JsonObjectMember {
+        const TYPE: &'static str = "jsonObjectMember";
+    }
+    impl JsonObjectMember {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(value: impl Into<JsonValue>) -> Self {
+            Self {
+                key: Default::default(),
+                value: value.into(),
+            }
+        }
+        #[doc = "Member's key"]
+        pub fn with_key(mut self, value: impl Into<String>) -> Self {
+            self.key = value.into();
+            self
+        }
+    }
+    impl Validate for JsonObjectMember {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "value"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Represents a null JSON value"]
+    pub struct JsonValueNull {}
+    impl TlObject for JsonValueNull {
+        const CLASS: &'static str = "JsonValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for JsonValueNull {
+        const TYPE: &'static str = "jsonValueNull";
+    }
+    impl JsonValueNull {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for JsonValueNull {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Represents a boolean JSON value"]
+    pub struct JsonValueBoolean {
+        #[doc = "The value"]
+        pub value: bool,
+    }
+    impl TlObject for JsonValueBoolean {
+        const CLASS: &'static str = "JsonValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for JsonValueBoolean {
+        const TYPE: &'static str = "jsonValueBoolean";
+    }
+    impl JsonValueBoolean {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The value"]
+        pub fn with_value(mut self, value: impl Into<bool>) -> Self {
+            self.value = value.into();
+            self
+        }
+    }
+    impl Validate for JsonValueBoolean {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Represents a numeric JSON value"]
+    pub struct JsonValueNumber {
+        #[doc = "The value"]
+        pub value: f64,
+    }
+    impl TlObject for JsonValueNumber {
+        const CLASS: &'static str = "JsonValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for JsonValueNumber {
+        const TYPE: &'static str = "jsonValueNumber";
+    }
+    impl JsonValueNumber {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The value"]
+        pub fn with_value(mut self, value: impl Into<f64>) -> Self {
+            self.value = value.into();
+            self
+        }
+    }
+    impl Validate for JsonValueNumber {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Represents a string JSON value"]
+    pub struct JsonValueString {
+        #[doc = "The value"]
+        pub value: String,
+    }
+    impl TlObject for JsonValueString {
+        const CLASS: &'static str = "JsonValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for JsonValueString {
+        const TYPE: &'static str = "jsonValueString";
+    }
+    impl JsonValueString {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The value"]
+        pub fn with_value(mut self, value: impl Into<String>) -> Self {
+            self.value = value.into();
+            self
+        }
+    }
+    impl Validate for JsonValueString {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Represents a JSON array"]
+    pub struct JsonValueArray {
+        #[doc = "The list of array elements"]
+        pub values: Vec<JsonValue>,
+    }
+    impl TlObject for JsonValueArray {
+        const CLASS: &'static str = "JsonValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for JsonValueArray {
+        const TYPE: &'static str = "jsonValueArray";
+    }
+    impl JsonValueArray {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The list of array elements"]
+        pub fn with_values(mut self, value: impl Into<Vec<JsonValue>>) -> Self {
+            self.values = value.into();
+            self
+        }
+    }
+    impl Validate for JsonValueArray {
+        fn validate(&self) -> Result<(), ValidationError> {
+            for (i, value) in self.values.iter().enumerate() {
+                value
+                    .validate()
+                    .map_err(|err| err.within(Self::TYPE, &format!("values[{}]", i)))?;
+            }
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "Represents a JSON object"]
+    pub struct JsonValueObject {
+        #[doc = "The list of object members"]
+        pub members: Vec<JsonObjectMember>,
+    }
+    impl TlObject for JsonValueObject {
+        const CLASS: &'static str = "JsonValue";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for JsonValueObject {
+        const TYPE: &'static str = "jsonValueObject";
+    }
+    impl JsonValueObject {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The list of object members"]
+        pub fn with_members(mut self, value: impl Into<Vec<JsonObjectMember>>) -> Self {
+            self.members = value.into();
+            self
+        }
+    }
+    impl Validate for JsonValueObject {
+        fn validate(&self) -> Result<(), ValidationError> {
+            for (i, value) in self.members.iter().enumerate() {
+                value
+                    .validate()
+                    .map_err(|err| err.within(Self::TYPE, &format!("members[{}]", i)))?;
+            }
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Represents a JSON value"]
+    pub enum JsonValue {
+        JsonValueNull(JsonValueNull),
+        JsonValueBoolean(JsonValueBoolean),
+        JsonValueNumber(JsonValueNumber),
+        JsonValueString(JsonValueString),
+        JsonValueArray(JsonValueArray),
+        JsonValueObject(JsonValueObject),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for JsonValue {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "jsonValueNull" => Deserialize::deserialize(value).map(JsonValue::JsonValueNull),
+                "jsonValueBoolean" => {
+                    Deserialize::deserialize(value).map(JsonValue::JsonValueBoolean)
+                }
+                "jsonValueNumber" => {
+                    Deserialize::deserialize(value).map(JsonValue::JsonValueNumber)
+                }
+                "jsonValueString" => {
+                    Deserialize::deserialize(value).map(JsonValue::JsonValueString)
+                }
+                "jsonValueArray" => Deserialize::deserialize(value).map(JsonValue::JsonValueArray),
+                "jsonValueObject" => {
+                    Deserialize::deserialize(value).map(JsonValue::JsonValueObject)
+                }
+                _ => Unknown::deserialize(value).map(JsonValue::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for JsonValue {
+        const CLASS: &'static str = "JsonValue";
+        fn type_name(&self) -> &str {
+            match self {
+                JsonValue::JsonValueNull(_) => "jsonValueNull",
+                JsonValue::JsonValueBoolean(_) => "jsonValueBoolean",
+                JsonValue::JsonValueNumber(_) => "jsonValueNumber",
+                JsonValue::JsonValueString(_) => "jsonValueString",
+                JsonValue::JsonValueArray(_) => "jsonValueArray",
+                JsonValue::JsonValueObject(_) => "jsonValueObject",
+                JsonValue::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for JsonValue {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                JsonValue::JsonValueNull(value) => value.validate(),
+                JsonValue::JsonValueBoolean(value) => value.validate(),
+                JsonValue::JsonValueNumber(value) => value.validate(),
+                JsonValue::JsonValueString(value) => value.validate(),
+                JsonValue::JsonValueArray(value) => value.validate(),
+                JsonValue::JsonValueObject(value) => value.validate(),
+                JsonValue::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<JsonValueNull> for JsonValue {
+        fn from(value: JsonValueNull) -> Self {
+            JsonValue::JsonValueNull(value)
+        }
+    }
+    impl From<JsonValueBoolean> for JsonValue {
+        fn from(value: JsonValueBoolean) -> Self {
+            JsonValue::JsonValueBoolean(value)
+        }
+    }
+    impl From<JsonValueNumber> for JsonValue {
+        fn from(value: JsonValueNumber) -> Self {
+            JsonValue::JsonValueNumber(value)
+        }
+    }
+    impl From<JsonValueString> for JsonValue {
+        fn from(value: JsonValueString) -> Self {
+            JsonValue::JsonValueString(value)
+        }
+    }
+    impl From<JsonValueArray> for JsonValue {
+        fn from(value: JsonValueArray) -> Self {
+            JsonValue::JsonValueArray(value)
+        }
+    }
+    impl From<JsonValueObject> for JsonValue {
+        fn from(value: JsonValueObject) -> Self {
+            JsonValue::JsonValueObject(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A rule to allow all users to do something"]
+    pub struct UserPrivacySettingRuleAllowAll {}
+    impl TlObject for UserPrivacySettingRuleAllowAll {
+        const CLASS: &'static str = "UserPrivacySettingRule";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingRuleAllowAll {
+        const TYPE: &'static str = "userPrivacySettingRuleAllowAll";
+    }
+    impl UserPrivacySettingRuleAllowAll {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for UserPrivacySettingRuleAllowAll {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A rule to allow all of a user's contacts to do something"]
+    pub struct UserPrivacySettingRuleAllowContacts {}
+    impl TlObject for UserPrivacySettingRuleAllowContacts {
+        const CLASS: &'static str = "UserPrivacySettingRule";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingRuleAllowContacts {
+        const TYPE: &'static str = "userPrivacySettingRuleAllowContacts";
+    }
+    impl UserPrivacySettingRuleAllowContacts {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for UserPrivacySettingRuleAllowContacts {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A rule to allow certain specified users to do something"]
+    pub struct UserPrivacySettingRuleAllowUsers {
+        #[doc = "The user identifiers, total number of users in all rules must not exceed 1000"]
+        pub user_ids: Vec<UserId>,
+    }
+    impl TlObject for UserPrivacySettingRuleAllowUsers {
+        const CLASS: &'static str = "UserPrivacySettingRule";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingRuleAllowUsers {
+        const TYPE: &'static str = "userPrivacySettingRuleAllowUsers";
+    }
+    impl UserPrivacySettingRuleAllowUsers {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The user identifiers, total number of users in all rules must not exceed 1000"]
+        pub fn with_user_ids(mut self, value: impl Into<Vec<UserId>>) -> Self {
+            self.user_ids = value.into();
+            self
+        }
+    }
+    impl Validate for UserPrivacySettingRuleAllowUsers {
+        fn validate(&self) -> Result<(), ValidationError> {
+            validate::check(
+                Self::TYPE,
+                "user_ids",
+                Measure::Elements,
+                self.user_ids.len() as i64,
+                None,
+                Some(1000),
+            )?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A rule to allow all members of certain specified basic groups and supergroups to doing something"]
+    pub struct UserPrivacySettingRuleAllowChatMembers {
+        #[doc = "The chat identifiers, total number of chats in all rules must not exceed 20"]
+        pub chat_ids: Vec<ChatId>,
+    }
+    impl TlObject for UserPrivacySettingRuleAllowChatMembers {
+        const CLASS: &'static str = "UserPrivacySettingRule";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingRuleAllowChatMembers {
+        const TYPE: &'static str = "userPrivacySettingRuleAllowChatMembers";
+    }
+    impl UserPrivacySettingRuleAllowChatMembers {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The chat identifiers, total number of chats in all rules must not exceed 20"]
+        pub fn with_chat_ids(mut self, value: impl Into<Vec<ChatId>>) -> Self {
+            self.chat_ids = value.into();
+            self
+        }
+    }
+    impl Validate for UserPrivacySettingRuleAllowChatMembers {
+        fn validate(&self) -> Result<(), ValidationError> {
+            validate::check(
+                Self::TYPE,
+                "chat_ids",
+                Measure::Elements,
+                self.chat_ids.len() as i64,
+                None,
+                Some(20),
+            )?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A rule to restrict all users from doing something"]
+    pub struct UserPrivacySettingRuleRestrictAll {}
+    impl TlObject for UserPrivacySettingRuleRestrictAll {
+        const CLASS: &'static str = "UserPrivacySettingRule";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingRuleRestrictAll {
+        const TYPE: &'static str = "userPrivacySettingRuleRestrictAll";
+    }
+    impl UserPrivacySettingRuleRestrictAll {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for UserPrivacySettingRuleRestrictAll {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A rule to restrict all contacts of a user from doing something"]
+    pub struct UserPrivacySettingRuleRestrictContacts {}
+    impl TlObject for UserPrivacySettingRuleRestrictContacts {
+        const CLASS: &'static str = "UserPrivacySettingRule";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingRuleRestrictContacts {
+        const TYPE: &'static str = "userPrivacySettingRuleRestrictContacts";
+    }
+    impl UserPrivacySettingRuleRestrictContacts {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for UserPrivacySettingRuleRestrictContacts {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A rule to restrict all specified users from doing something"]
+    pub struct UserPrivacySettingRuleRestrictUsers {
+        #[doc = "The user identifiers, total number of users in all rules must not exceed 1000"]
+        pub user_ids: Vec<UserId>,
+    }
+    impl TlObject for UserPrivacySettingRuleRestrictUsers {
+        const CLASS: &'static str = "UserPrivacySettingRule";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingRuleRestrictUsers {
+        const TYPE: &'static str = "userPrivacySettingRuleRestrictUsers";
+    }
+    impl UserPrivacySettingRuleRestrictUsers {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The user identifiers, total number of users in all rules must not exceed 1000"]
+        pub fn with_user_ids(mut self, value: impl Into<Vec<UserId>>) -> Self {
+            self.user_ids = value.into();
+            self
+        }
+    }
+    impl Validate for UserPrivacySettingRuleRestrictUsers {
+        fn validate(&self) -> Result<(), ValidationError> {
+            validate::check(
+                Self::TYPE,
+                "user_ids",
+                Measure::Elements,
+                self.user_ids.len() as i64,
+                None,
+                Some(1000),
+            )?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A rule to restrict all members of specified basic groups and supergroups from doing something"]
+    pub struct UserPrivacySettingRuleRestrictChatMembers {
+        #[doc = "The chat identifiers, total number of chats in all rules must not exceed 20"]
+        pub chat_ids: Vec<ChatId>,
+    }
+    impl TlObject for UserPrivacySettingRuleRestrictChatMembers {
+        const CLASS: &'static str = "UserPrivacySettingRule";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingRuleRestrictChatMembers {
+        const TYPE: &'static str = "userPrivacySettingRuleRestrictChatMembers";
+    }
+    impl UserPrivacySettingRuleRestrictChatMembers {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The chat identifiers, total number of chats in all rules must not exceed 20"]
+        pub fn with_chat_ids(mut self, value: impl Into<Vec<ChatId>>) -> Self {
+            self.chat_ids = value.into();
+            self
+        }
+    }
+    impl Validate for UserPrivacySettingRuleRestrictChatMembers {
+        fn validate(&self) -> Result<(), ValidationError> {
+            validate::check(
+                Self::TYPE,
+                "chat_ids",
+                Measure::Elements,
+                self.chat_ids.len() as i64,
+                None,
+                Some(20),
+            )?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Represents a single rule for managing privacy settings"]
+    pub enum UserPrivacySettingRule {
+        UserPrivacySettingRuleAllowAll(UserPrivacySettingRuleAllowAll),
+        UserPrivacySettingRuleAllowContacts(UserPrivacySettingRuleAllowContacts),
+        UserPrivacySettingRuleAllowUsers(UserPrivacySettingRuleAllowUsers),
+        UserPrivacySettingRuleAllowChatMembers(UserPrivacySettingRuleAllowChatMembers),
+        UserPrivacySettingRuleRestrictAll(UserPrivacySettingRuleRestrictAll),
+        UserPrivacySettingRuleRestrictContacts(UserPrivacySettingRuleRestrictContacts),
+        UserPrivacySettingRuleRestrictUsers(UserPrivacySettingRuleRestrictUsers),
+        UserPrivacySettingRuleRestrictChatMembers(UserPrivacySettingRuleRestrictChatMembers),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for UserPrivacySettingRule {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "userPrivacySettingRuleAllowAll" => Deserialize::deserialize(value)
+                    .map(UserPrivacySettingRule::UserPrivacySettingRuleAllowAll),
+                "userPrivacySettingRuleAllowContacts" => Deserialize::deserialize(value)
+                    .map(UserPrivacySettingRule::UserPrivacySettingRuleAllowContacts),
+                "userPrivacySettingRuleAllowUsers" => Deserialize::deserialize(value)
+                    .map(UserPrivacySettingRule::UserPrivacySettingRuleAllowUsers),
+                "userPrivacySettingRuleAllowChatMembers" => Deserialize::deserialize(value)
+                    .map(UserPrivacySettingRule::UserPrivacySettingRuleAllowChatMembers),
+                "userPrivacySettingRuleRestrictAll" => Deserialize::deserialize(value)
+                    .map(UserPrivacySettingRule::UserPrivacySettingRuleRestrictAll),
+                "userPrivacySettingRuleRestrictContacts" => Deserialize::deserialize(value)
+                    .map(UserPrivacySettingRule::UserPrivacySettingRuleRestrictContacts),
+                "userPrivacySettingRuleRestrictUsers" => Deserialize::deserialize(value)
+                    .map(UserPrivacySettingRule::UserPrivacySettingRuleRestrictUsers),
+                "userPrivacySettingRuleRestrictChatMembers" => Deserialize::deserialize(value)
+                    .map(UserPrivacySettingRule::UserPrivacySettingRuleRestrictChatMembers),
+                _ => Unknown::deserialize(value).map(UserPrivacySettingRule::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for UserPrivacySettingRule {
+        const CLASS: &'static str = "UserPrivacySettingRule";
+        fn type_name(&self) -> &str {
+            match self {
+                UserPrivacySettingRule::UserPrivacySettingRuleAllowAll(_) => {
+                    "userPrivacySettingRuleAllowAll"
+                }
+                UserPrivacySettingRule::UserPrivacySettingRuleAllowContacts(_) => {
+                    "userPrivacySettingRuleAllowContacts"
+                }
+                UserPrivacySettingRule::UserPrivacySettingRuleAllowUsers(_) => {
+                    "userPrivacySettingRuleAllowUsers"
+                }
+                UserPrivacySettingRule::UserPrivacySettingRuleAllowChatMembers(_) => {
+                    "userPrivacySettingRuleAllowChatMembers"
+                }
+                UserPrivacySettingRule::UserPrivacySettingRuleRestrictAll(_) => {
+                    "userPrivacySettingRuleRestrictAll"
+                }
+                UserPrivacySettingRule::UserPrivacySettingRuleRestrictContacts(_) => {
+                    "userPrivacySettingRuleRestrictContacts"
+                }
+                UserPrivacySettingRule::UserPrivacySettingRuleRestrictUsers(_) => {
+                    "userPrivacySettingRuleRestrictUsers"
+                }
+                UserPrivacySettingRule::UserPrivacySettingRuleRestrictChatMembers(_) => {
+                    "userPrivacySettingRuleRestrictChatMembers"
+                }
+                UserPrivacySettingRule::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for UserPrivacySettingRule {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                UserPrivacySettingRule::UserPrivacySettingRuleAllowAll(value) => value.validate(),
+                UserPrivacySettingRule::UserPrivacySettingRuleAllowContacts(value) => {
+                    value.validate()
+                }
+                UserPrivacySettingRule::UserPrivacySettingRuleAllowUsers(value) => value.validate(),
+                UserPrivacySettingRule::UserPrivacySettingRuleAllowChatMembers(value) => {
+                    value.validate()
+                }
+                UserPrivacySettingRule::UserPrivacySettingRuleRestrictAll(value) => {
+                    value.validate()
+                }
+                UserPrivacySettingRule::UserPrivacySettingRuleRestrictContacts(value) => {
+                    value.validate()
+                }
+                UserPrivacySettingRule::UserPrivacySettingRuleRestrictUsers(value) => {
+                    value.validate()
+                }
+                UserPrivacySettingRule::UserPrivacySettingRuleRestrictChatMembers(value) => {
+                    value.validate()
+                }
+                UserPrivacySettingRule::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<UserPrivacySettingRuleAllowAll> for UserPrivacySettingRule {
+        fn from(value: UserPrivacySettingRuleAllowAll) -> Self {
+            UserPrivacySettingRule::UserPrivacySettingRuleAllowAll(value)
+        }
+    }
+    impl From<UserPrivacySettingRuleAllowContacts> for UserPrivacySettingRule {
+        fn from(value: UserPrivacySettingRuleAllowContacts) -> Self {
+            UserPrivacySettingRule::UserPrivacySettingRuleAllowContacts(value)
+        }
+    }
+    impl From<UserPrivacySettingRuleAllowUsers> for UserPrivacySettingRule {
+        fn from(value: UserPrivacySettingRuleAllowUsers) -> Self {
+            UserPrivacySettingRule::UserPrivacySettingRuleAllowUsers(value)
+        }
+    }
+    impl From<UserPrivacySettingRuleAllowChatMembers> for UserPrivacySettingRule {
+        fn from(value: UserPrivacySettingRuleAllowChatMembers) -> Self {
+            UserPrivacySettingRule::UserPrivacySettingRuleAllowChatMembers(value)
+        }
+    }
+    impl From<UserPrivacySettingRuleRestrictAll> for UserPrivacySettingRule {
+        fn from(value: UserPrivacySettingRuleRestrictAll) -> Self {
+            UserPrivacySettingRule::UserPrivacySettingRuleRestrictAll(value)
+        }
+    }
+    impl From<UserPrivacySettingRuleRestrictContacts> for UserPrivacySettingRule {
+        fn from(value: UserPrivacySettingRuleRestrictContacts) -> Self {
+            UserPrivacySettingRule::UserPrivacySettingRuleRestrictContacts(value)
+        }
+    }
+    impl From<UserPrivacySettingRuleRestrictUsers> for UserPrivacySettingRule {
+        fn from(value: UserPrivacySettingRuleRestrictUsers) -> Self {
+            UserPrivacySettingRule::UserPrivacySettingRuleRestrictUsers(value)
+        }
+    }
+    impl From<UserPrivacySettingRuleRestrictChatMembers> for UserPrivacySettingRule {
+        fn from(value: UserPrivacySettingRuleRestrictChatMembers) -> Self {
+            UserPrivacySettingRule::UserPrivacySettingRuleRestrictChatMembers(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A list of privacy rules. Rules are matched in the specified order. The first matched rule defines the privacy setting for a given user. If no rule matches, the action is not allowed"]
+    pub struct UserPrivacySettingRules {
+        #[doc = "A list of rules"]
+        pub rules: Vec<UserPrivacySettingRule>,
+    }
+    impl TlObject for UserPrivacySettingRules {
+        const CLASS: &'static str = "UserPrivacySettingRules";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingRules {
+        const TYPE: &'static str = "userPrivacySettingRules";
+    }
+    impl UserPrivacySettingRules {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "A list of rules"]
+        pub fn with_rules(mut self, value: impl Into<Vec<UserPrivacySettingRule>>) -> Self {
+            self.rules = value.into();
+            self
+        }
+    }
+    impl Validate for UserPrivacySettingRules {
+        fn validate(&self) -> Result<(), ValidationError> {
+            for (i, value) in self.rules.iter().enumerate() {
+                value
+                    .validate()
+                    .map_err(|err| err.within(Self::TYPE, &format!("rules[{}]", i)))?;
+            }
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A privacy setting for managing whether the user's online status is visible"]
+    pub struct UserPrivacySettingShowStatus {}
+    impl TlObject for UserPrivacySettingShowStatus {
+        const CLASS: &'static str = "UserPrivacySetting";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingShowStatus {
+        const TYPE: &'static str = "userPrivacySettingShowStatus";
+    }
+    impl UserPrivacySettingShowStatus {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for UserPrivacySettingShowStatus {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A privacy setting for managing whether the user's profile photo is visible"]
+    pub struct UserPrivacySettingShowProfilePhoto {}
+    impl TlObject for UserPrivacySettingShowProfilePhoto {
+        const CLASS: &'static str = "UserPrivacySetting";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingShowProfilePhoto {
+        const TYPE: &'static str = "userPrivacySettingShowProfilePhoto";
+    }
+    impl UserPrivacySettingShowProfilePhoto {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for UserPrivacySettingShowProfilePhoto {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A privacy setting for managing whether a link to the user's account is included in forwarded messages"]
+    pub struct UserPrivacySettingShowLinkInForwardedMessages {}
+    impl TlObject for UserPrivacySettingShowLinkInForwardedMessages {
+        const CLASS: &'static str = "UserPrivacySetting";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingShowLinkInForwardedMessages {
+        const TYPE: &'static str = "userPrivacySettingShowLinkInForwardedMessages";
+    }
+    impl UserPrivacySettingShowLinkInForwardedMessages {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for UserPrivacySettingShowLinkInForwardedMessages {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A privacy setting for managing whether the user's phone number is visible"]
+    pub struct UserPrivacySettingShowPhoneNumber {}
+    impl TlObject for UserPrivacySettingShowPhoneNumber {
+        const CLASS: &'static str = "UserPrivacySetting";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingShowPhoneNumber {
+        const TYPE: &'static str = "userPrivacySettingShowPhoneNumber";
+    }
+    impl UserPrivacySettingShowPhoneNumber {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for UserPrivacySettingShowPhoneNumber {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A privacy setting for managing whether the user can be invited to chats"]
+    pub struct UserPrivacySettingAllowChatInvites {}
+    impl TlObject for UserPrivacySettingAllowChatInvites {
+        const CLASS: &'static str = "UserPrivacySetting";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingAllowChatInvites {
+        const TYPE: &'static str = "userPrivacySettingAllowChatInvites";
+    }
+    impl UserPrivacySettingAllowChatInvites {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for UserPrivacySettingAllowChatInvites {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A privacy setting for managing whether the user can be called"]
+    pub struct UserPrivacySettingAllowCalls {}
+    impl TlObject for UserPrivacySettingAllowCalls {
+        const CLASS: &'static str = "UserPrivacySetting";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingAllowCalls {
+        const TYPE: &'static str = "userPrivacySettingAllowCalls";
+    }
+    impl UserPrivacySettingAllowCalls {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for UserPrivacySettingAllowCalls {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A privacy setting for managing whether peer-to-peer connections can be used for calls"]
+    pub struct UserPrivacySettingAllowPeerToPeerCalls {}
+    impl TlObject for UserPrivacySettingAllowPeerToPeerCalls {
+        const CLASS: &'static str = "UserPrivacySetting";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingAllowPeerToPeerCalls {
+        const TYPE: &'static str = "userPrivacySettingAllowPeerToPeerCalls";
+    }
+    impl UserPrivacySettingAllowPeerToPeerCalls {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for UserPrivacySettingAllowPeerToPeerCalls {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A privacy setting for managing whether the user can be found by their phone number. Checked only if the phone number is not known to the other user. Can be set only to \"Allow contacts\" or \"Allow all\""]
+    pub struct UserPrivacySettingAllowFindingByPhoneNumber {}
+    impl TlObject for UserPrivacySettingAllowFindingByPhoneNumber {
+        const CLASS: &'static str = "UserPrivacySetting";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UserPrivacySettingAllowFindingByPhoneNumber {
+        const TYPE: &'static str = "userPrivacySettingAllowFindingByPhoneNumber";
+    }
+    impl UserPrivacySettingAllowFindingByPhoneNumber {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for UserPrivacySettingAllowFindingByPhoneNumber {}
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Describes available user privacy settings"]
+    pub enum UserPrivacySetting {
+        UserPrivacySettingShowStatus(UserPrivacySettingShowStatus),
+        UserPrivacySettingShowProfilePhoto(UserPrivacySettingShowProfilePhoto),
+        UserPrivacySettingShowLinkInForwardedMessages(
+            UserPrivacySettingShowLinkInForwardedMessages,
+        ),
+        UserPrivacySettingShowPhoneNumber(UserPrivacySettingShowPhoneNumber),
+        UserPrivacySettingAllowChatInvites(UserPrivacySettingAllowChatInvites),
+        UserPrivacySettingAllowCalls(UserPrivacySettingAllowCalls),
+        UserPrivacySettingAllowPeerToPeerCalls(UserPrivacySettingAllowPeerToPeerCalls),
+        UserPrivacySettingAllowFindingByPhoneNumber(UserPrivacySettingAllowFindingByPhoneNumber),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for UserPrivacySetting {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "userPrivacySettingShowStatus" => Deserialize::deserialize(value)
+                    .map(UserPrivacySetting::UserPrivacySettingShowStatus),
+                "userPrivacySettingShowProfilePhoto" => Deserialize::deserialize(value)
+                    .map(UserPrivacySetting::UserPrivacySettingShowProfilePhoto),
+                "userPrivacySettingShowLinkInForwardedMessages" => Deserialize::deserialize(value)
+                    .map(UserPrivacySetting::UserPrivacySettingShowLinkInForwardedMessages),
+                "userPrivacySettingShowPhoneNumber" => Deserialize::deserialize(value)
+                    .map(UserPrivacySetting::UserPrivacySettingShowPhoneNumber),
+                "userPrivacySettingAllowChatInvites" => Deserialize::deserialize(value)
+                    .map(UserPrivacySetting::UserPrivacySettingAllowChatInvites),
+                "userPrivacySettingAllowCalls" => Deserialize::deserialize(value)
+                    .map(UserPrivacySetting::UserPrivacySettingAllowCalls),
+                "userPrivacySettingAllowPeerToPeerCalls" => Deserialize::deserialize(value)
+                    .map(UserPrivacySetting::UserPrivacySettingAllowPeerToPeerCalls),
+                "userPrivacySettingAllowFindingByPhoneNumber" => Deserialize::deserialize(value)
+                    .map(UserPrivacySetting::UserPrivacySettingAllowFindingByPhoneNumber),
+                _ => Unknown::deserialize(value).map(UserPrivacySetting::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for UserPrivacySetting {
+        const CLASS: &'static str = "UserPrivacySetting";
+        fn type_name(&self) -> &str {
+            match self {
+                UserPrivacySetting::UserPrivacySettingShowStatus(_) => {
+                    "userPrivacySettingShowStatus"
+                }
+                UserPrivacySetting::UserPrivacySettingShowProfilePhoto(_) => {
+                    "userPrivacySettingShowProfilePhoto"
+                }
+                UserPrivacySetting::UserPrivacySettingShowLinkInForwardedMessages(_) => {
+                    "userPrivacySettingShowLinkInForwardedMessages"
+                }
+                UserPrivacySetting::UserPrivacySettingShowPhoneNumber(_) => {
+                    "userPrivacySettingShowPhoneNumber"
+                }
+                UserPrivacySetting::UserPrivacySettingAllowChatInvites(_) => {
+                    "userPrivacySettingAllowChatInvites"
+                }
+                UserPrivacySetting::UserPrivacySettingAllowCalls(_) => {
+                    "userPrivacySettingAllowCalls"
+                }
+                UserPrivacySetting::UserPrivacySettingAllowPeerToPeerCalls(_) => {
+                    "userPrivacySettingAllowPeerToPeerCalls"
+                }
+                UserPrivacySetting::UserPrivacySettingAllowFindingByPhoneNumber(_) => {
+                    "userPrivacySettingAllowFindingByPhoneNumber"
+                }
+                UserPrivacySetting::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for UserPrivacySetting {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                UserPrivacySetting::UserPrivacySettingShowStatus(value) => value.validate(),
+                UserPrivacySetting::UserPrivacySettingShowProfilePhoto(value) => value.validate(),
+                UserPrivacySetting::UserPrivacySettingShowLinkInForwardedMessages(value) => {
+                    value.validate()
+                }
+                UserPrivacySetting::UserPrivacySettingShowPhoneNumber(value) => value.validate(),
+                UserPrivacySetting::UserPrivacySettingAllowChatInvites(value) => value.validate(),
+                UserPrivacySetting::UserPrivacySettingAllowCalls(value) => value.validate(),
+                UserPrivacySetting::UserPrivacySettingAllowPeerToPeerCalls(value) => {
+                    value.validate()
+                }
+                UserPrivacySetting::UserPrivacySettingAllowFindingByPhoneNumber(value) => {
+                    value.validate()
+                }
+                UserPrivacySetting::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<UserPrivacySettingShowStatus> for UserPrivacySetting {
+        fn from(value: UserPrivacySettingShowStatus) -> Self {
+            UserPrivacySetting::UserPrivacySettingShowStatus(value)
+        }
+    }
+    impl From<UserPrivacySettingShowProfilePhoto> for UserPrivacySetting {
+        fn from(value: UserPrivacySettingShowProfilePhoto) -> Self {
+            UserPrivacySetting::UserPrivacySettingShowProfilePhoto(value)
+        }
+    }
+    impl From<UserPrivacySettingShowLinkInForwardedMessages> for UserPrivacySetting {
+        fn from(value: UserPrivacySettingShowLinkInForwardedMessages) -> Self {
+            UserPrivacySetting::UserPrivacySettingShowLinkInForwardedMessages(value)
+        }
+    }
+    impl From<UserPrivacySettingShowPhoneNumber> for UserPrivacySetting {
+        fn from(value: UserPrivacySettingShowPhoneNumber) -> Self {
+            UserPrivacySetting::UserPrivacySettingShowPhoneNumber(value)
+        }
+    }
+    impl From<UserPrivacySettingAllowChatInvites> for UserPrivacySetting {
+        fn from(value: UserPrivacySettingAllowChatInvites) -> Self {
+            UserPrivacySetting::UserPrivacySettingAllowChatInvites(value)
+        }
+    }
+    impl From<UserPrivacySettingAllowCalls> for UserPrivacySetting {
+        fn from(value: UserPrivacySettingAllowCalls) -> Self {
+            UserPrivacySetting::UserPrivacySettingAllowCalls(value)
+        }
+    }
+    impl From<UserPrivacySettingAllowPeerToPeerCalls> for UserPrivacySetting {
+        fn from(value: UserPrivacySettingAllowPeerToPeerCalls) -> Self {
+            UserPrivacySetting::UserPrivacySettingAllowPeerToPeerCalls(value)
+        }
+    }
+    impl From<UserPrivacySettingAllowFindingByPhoneNumber> for UserPrivacySetting {
+        fn from(value: UserPrivacySettingAllowFindingByPhoneNumber) -> Self {
+            UserPrivacySetting::UserPrivacySettingAllowFindingByPhoneNumber(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "The network is not available"]
+    pub struct NetworkTypeNone {}
+    impl TlObject for NetworkTypeNone {
+        const CLASS: &'static str = "NetworkType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NetworkTypeNone {
+        const TYPE: &'static str = "networkTypeNone";
+    }
+    impl NetworkTypeNone {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for NetworkTypeNone {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A mobile network"]
+    pub struct NetworkTypeMobile {}
+    impl TlObject for NetworkTypeMobile {
+        const CLASS: &'static str = "NetworkType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NetworkTypeMobile {
+        const TYPE: &'static str = "networkTypeMobile";
+    }
+    impl NetworkTypeMobile {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for NetworkTypeMobile {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A mobile roaming network"]
+    pub struct NetworkTypeMobileRoaming {}
+    impl TlObject for NetworkTypeMobileRoaming {
+        const CLASS: &'static str = "NetworkType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NetworkTypeMobileRoaming {
+        const TYPE: &'static str = "networkTypeMobileRoaming";
+    }
+    impl NetworkTypeMobileRoaming {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for NetworkTypeMobileRoaming {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A Wi-Fi network"]
+    pub struct NetworkTypeWiFi {}
+    impl TlObject for NetworkTypeWiFi {
+        const CLASS: &'static str = "NetworkType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NetworkTypeWiFi {
+        const TYPE: &'static str = "networkTypeWiFi";
+    }
+    impl NetworkTypeWiFi {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for NetworkTypeWiFi {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+    #[doc = "A different network type (e.g., Ethernet network)"]
+    pub struct NetworkTypeOther {}
+    impl TlObject for NetworkTypeOther {
+        const CLASS: &'static str = "NetworkType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for NetworkTypeOther {
+        const TYPE: &'static str = "networkTypeOther";
+    }
+    impl NetworkTypeOther {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for NetworkTypeOther {}
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Represents the type of a network"]
+    pub enum NetworkType {
+        NetworkTypeNone(NetworkTypeNone),
+        NetworkTypeMobile(NetworkTypeMobile),
+        NetworkTypeMobileRoaming(NetworkTypeMobileRoaming),
+        NetworkTypeWiFi(NetworkTypeWiFi),
+        NetworkTypeOther(NetworkTypeOther),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for NetworkType {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "networkTypeNone" => {
+                    Deserialize::deserialize(value).map(NetworkType::NetworkTypeNone)
+                }
+                "networkTypeMobile" => {
+                    Deserialize::deserialize(value).map(NetworkType::NetworkTypeMobile)
+                }
+                "networkTypeMobileRoaming" => {
+                    Deserialize::deserialize(value).map(NetworkType::NetworkTypeMobileRoaming)
+                }
+                "networkTypeWiFi" => {
+                    Deserialize::deserialize(value).map(NetworkType::NetworkTypeWiFi)
+                }
+                "networkTypeOther" => {
+                    Deserialize::deserialize(value).map(NetworkType::NetworkTypeOther)
+                }
+                _ => Unknown::deserialize(value).map(NetworkType::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for NetworkType {
+        const CLASS: &'static str = "NetworkType";
+        fn type_name(&self) -> &str {
+            match self {
+                NetworkType::NetworkTypeNone(_) => "networkTypeNone",
+                NetworkType::NetworkTypeMobile(_) => "networkTypeMobile",
+                NetworkType::NetworkTypeMobileRoaming(_) => "networkTypeMobileRoaming",
+                NetworkType::NetworkTypeWiFi(_) => "networkTypeWiFi",
+                NetworkType::NetworkTypeOther(_) => "networkTypeOther",
+                NetworkType::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    impl Validate for NetworkType {
+        fn validate(&self) -> Result<(), ValidationError> {
+            match self {
+                NetworkType::NetworkTypeNone(value) => value.validate(),
+                NetworkType::NetworkTypeMobile(value) => value.validate(),
+                NetworkType::NetworkTypeMobileRoaming(value) => value.validate(),
+                NetworkType::NetworkTypeWiFi(value) => value.validate(),
+                NetworkType::NetworkTypeOther(value) => value.validate(),
+                NetworkType::Unknown(_) => Ok(()),
+            }
+        }
+    }
+    impl From<NetworkTypeNone> for NetworkType {
+        fn from(value: NetworkTypeNone) -> Self {
+            NetworkType::NetworkTypeNone(value)
+        }
+    }
+    impl From<NetworkTypeMobile> for NetworkType {
+        fn from(value: NetworkTypeMobile) -> Self {
+            NetworkType::NetworkTypeMobile(value)
+        }
+    }
+    impl From<NetworkTypeMobileRoaming> for NetworkType {
+        fn from(value: NetworkTypeMobileRoaming) -> Self {
+            NetworkType::NetworkTypeMobileRoaming(value)
+        }
+    }
+    impl From<NetworkTypeWiFi> for NetworkType {
+        fn from(value: NetworkTypeWiFi) -> Self {
+            NetworkType::NetworkTypeWiFi(value)
+        }
+    }
+    impl From<NetworkTypeOther> for NetworkType {
+        fn from(value: NetworkTypeOther) -> Self {
+            NetworkType::NetworkTypeOther(value)
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Currently waiting for the network to become available. Use setNetworkType to change the available network type"]
+    pub struct ConnectionStateWaitingForNetwork {}
+    impl TlObject for ConnectionStateWaitingForNetwork {
+        const CLASS: &'static str = "ConnectionState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ConnectionStateWaitingForNetwork {
+        const TYPE: &'static str = "connectionStateWaitingForNetwork";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Currently establishing a connection with a proxy server"]
+    pub struct ConnectionStateConnectingToProxy {}
+    impl TlObject for ConnectionStateConnectingToProxy {
+        const CLASS: &'static str = "ConnectionState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ConnectionStateConnectingToProxy {
+        const TYPE: &'static str = "connectionStateConnectingToProxy";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Currently establishing a connection to the Telegram servers"]
+    pub struct ConnectionStateConnecting {}
+    impl TlObject for ConnectionStateConnecting {
+        const CLASS: &'static str = "ConnectionState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ConnectionStateConnecting {
+        const TYPE: &'static str = "connectionStateConnecting";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Downloading data received while the client was offline"]
+    pub struct ConnectionStateUpdating {}
+    impl TlObject for ConnectionStateUpdating {
+        const CLASS: &'static str = "ConnectionState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ConnectionStateUpdating {
+        const TYPE: &'static str = "connectionStateUpdating";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "There is a working connection to the Telegram servers"]
+    pub struct ConnectionStateReady {}
+    impl TlObject for ConnectionStateReady {
+        const CLASS: &'static str = "ConnectionState";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for ConnectionStateReady {
+        const TYPE: &'static str = "connectionStateReady";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Describes the current state of the connection to Telegram servers"]
+    pub enum ConnectionState {
+        ConnectionStateWaitingForNetwork(ConnectionStateWaitingForNetwork),
+        ConnectionStateConnectingToProxy(ConnectionStateConnectingToProxy),
+        ConnectionStateConnecting(ConnectionStateConnecting),
+        ConnectionStateUpdating(ConnectionStateUpdating),
+        ConnectionStateReady(ConnectionStateReady),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for ConnectionState {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "connectionStateWaitingForNetwork" => Deserialize::deserialize(value)
+                    .map(ConnectionState::ConnectionStateWaitingForNetwork),
+                "connectionStateConnectingToProxy" => Deserialize::deserialize(value)
+                    .map(ConnectionState::ConnectionStateConnectingToProxy),
+                "connectionStateConnecting" => {
+                    Deserialize::deserialize(value).map(ConnectionState::ConnectionStateConnecting)
+                }
+                "connectionStateUpdating" => {
+                    Deserialize::deserialize(value).map(ConnectionState::ConnectionStateUpdating)
+                }
+                "connectionStateReady" => {
+                    Deserialize::deserialize(value).map(ConnectionState::ConnectionStateReady)
+                }
+                _ => Unknown::deserialize(value).map(ConnectionState::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for ConnectionState {
+        const CLASS: &'static str = "ConnectionState";
+        fn type_name(&self) -> &str {
+            match self {
+                ConnectionState::ConnectionStateWaitingForNetwork(_) => {
+                    "connectionStateWaitingForNetwork"
+                }
+                ConnectionState::ConnectionStateConnectingToProxy(_) => {
+                    "connectionStateConnectingToProxy"
+                }
+                ConnectionState::ConnectionStateConnecting(_) => "connectionStateConnecting",
+                ConnectionState::ConnectionStateUpdating(_) => "connectionStateUpdating",
+                ConnectionState::ConnectionStateReady(_) => "connectionStateReady",
+                ConnectionState::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A URL linking to a user"]
+    pub struct TMeUrlTypeUser {
+        #[doc = "Identifier of the user"]
+        pub user_id: UserId,
+    }
+    impl TlObject for TMeUrlTypeUser {
+        const CLASS: &'static str = "TMeUrlType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TMeUrlTypeUser {
+        const TYPE: &'static str = "tMeUrlTypeUser";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A URL linking to a public supergroup or channel"]
+    pub struct TMeUrlTypeSupergroup {
+        #[doc = "Identifier of the supergroup or channel"]
+        pub supergroup_id: ChatId,
+    }
+    impl TlObject for TMeUrlTypeSupergroup {
+        const CLASS: &'static str = "TMeUrlType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TMeUrlTypeSupergroup {
+        const TYPE: &'static str = "tMeUrlTypeSupergroup";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A chat invite link"]
+    pub struct TMeUrlTypeChatInvite {
+        #[doc = "Chat invite link info"]
+        pub info: ChatInviteLinkInfo,
+    }
+    impl TlObject for TMeUrlTypeChatInvite {
+        const CLASS: &'static str = "TMeUrlType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TMeUrlTypeChatInvite {
+        const TYPE: &'static str = "tMeUrlTypeChatInvite";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A URL linking to a sticker set"]
+    pub struct TMeUrlTypeStickerSet {
+        #[serde(
+            deserialize_with = "crate::utils::from_str_to_t",
+            serialize_with = "crate::utils::t_to_str"
+        )]
+        #[doc = "Identifier of the sticker set"]
+        pub sticker_set_id: i64,
+    }
+    impl TlObject for TMeUrlTypeStickerSet {
+        const CLASS: &'static str = "TMeUrlType";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TMeUrlTypeStickerSet {
+        const TYPE: &'static str = "tMeUrlTypeStickerSet";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Describes the type of a URL linking to an internal Telegram entity"]
+    pub enum TMeUrlType {
+        TMeUrlTypeUser(TMeUrlTypeUser),
+        TMeUrlTypeSupergroup(TMeUrlTypeSupergroup),
+        TMeUrlTypeChatInvite(Box<TMeUrlTypeChatInvite>),
+        TMeUrlTypeStickerSet(TMeUrlTypeStickerSet),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for TMeUrlType {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "tMeUrlTypeUser" => Deserialize::deserialize(value).map(TMeUrlType::TMeUrlTypeUser),
+                "tMeUrlTypeSupergroup" => {
+                    Deserialize::deserialize(value).map(TMeUrlType::TMeUrlTypeSupergroup)
+                }
+                "tMeUrlTypeChatInvite" => {
+                    Deserialize::deserialize(value).map(TMeUrlType::TMeUrlTypeChatInvite)
+                }
+                "tMeUrlTypeStickerSet" => {
+                    Deserialize::deserialize(value).map(TMeUrlType::TMeUrlTypeStickerSet)
+                }
+                _ => Unknown::deserialize(value).map(TMeUrlType::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for TMeUrlType {
+        const CLASS: &'static str = "TMeUrlType";
+        fn type_name(&self) -> &str {
+            match self {
+                TMeUrlType::TMeUrlTypeUser(_) => "tMeUrlTypeUser",
+                TMeUrlType::TMeUrlTypeSupergroup(_) => "tMeUrlTypeSupergroup",
+                TMeUrlType::TMeUrlTypeChatInvite(_) => "tMeUrlTypeChatInvite",
+                TMeUrlType::TMeUrlTypeStickerSet(_) => "tMeUrlTypeStickerSet",
+                TMeUrlType::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Represents a URL linking to an internal Telegram entity"]
+    pub struct TMeUrl {
+        #[doc = "URL"]
+        pub url: String,
+        #[serde(rename = "type")]
+        #[doc = "Type of the URL"]
+        pub type_: TMeUrlType,
+    }
+    impl TlObject for TMeUrl {
+        const CLASS: &'static str = "TMeUrl";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TMeUrl {
+        const TYPE: &'static str = "tMeUrl";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains a list of t.me URLs"]
+    pub struct TMeUrls {
+        #[doc = "List of URLs"]
+        pub urls: Vec<TMeUrl>,
+    }
+    impl TlObject for TMeUrls {
+        const CLASS: &'static str = "TMeUrls";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for TMeUrls {
+        const TYPE: &'static str = "tMeUrls";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains a counter"]
+    pub struct Count {
+        #[doc = "Count"]
+        pub count: i32,
+    }
+    impl TlObject for Count {
+        const CLASS: &'static str = "Count";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Count {
+        const TYPE: &'static str = "count";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains some text"]
+    pub struct Text {
+        #[doc = "Text"]
+        pub text: String,
+    }
+    impl TlObject for Text {
+        const CLASS: &'static str = "Text";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Text {
+        const TYPE: &'static str = "text";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains a value representing a number of seconds"]
+    pub struct Seconds {
+        #[doc = "Number of seconds"]
+        pub seconds: f64,
+    }
+    impl TlObject for Seconds {
+        const CLASS: &'static str = "Seconds";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Seconds {
+        const TYPE: &'static str = "seconds";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains information about a tg:// deep link"]
+    pub struct DeepLinkInfo {
+        #[doc = "Text to be shown to the user"]
+        pub text: FormattedText,
+        #[doc = "True, if user should be asked to update the application"]
+        pub need_update_application: bool,
+    }
+    impl TlObject for DeepLinkInfo {
+        const CLASS: &'static str = "DeepLinkInfo";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for DeepLinkInfo {
+        const TYPE: &'static str = "deepLinkInfo";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user authorization state has changed"]
+    pub struct UpdateAuthorizationState {
+        #[doc = "New authorization state"]
+        pub authorization_state: AuthorizationState,
+    }
+    impl TlObject for UpdateAuthorizationState {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateAuthorizationState {
+        const TYPE: &'static str = "updateAuthorizationState";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Notification settings for some type of chats were updated"]
+    pub struct UpdateScopeNotificationSettings {
+        #[doc = "Types of chats for which notification settings were updated"]
+        pub scope: NotificationSettingsScope,
+        #[doc = "The new notification settings"]
+        pub notification_settings: ScopeNotificationSettings,
+    }
+    impl TlObject for UpdateScopeNotificationSettings {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateScopeNotificationSettings {
+        const TYPE: &'static str = "updateScopeNotificationSettings";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A notification was changed"]
+    pub struct UpdateNotification {
+        #[doc = "Unique notification group identifier"]
+        pub notification_group_id: NotificationGroupId,
+        #[doc = "Changed notification"]
+        pub notification: Notification,
+    }
+    impl TlObject for UpdateNotification {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateNotification {
+        const TYPE: &'static str = "updateNotification";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "A list of active notifications in a notification group has changed"]
+    pub struct UpdateNotificationGroup {
+        #[doc = "Unique notification group identifier"]
+        pub notification_group_id: NotificationGroupId,
+        #[serde(rename = "type")]
+        #[doc = "New type of the notification group"]
+        pub type_: NotificationGroupType,
+        #[doc = "Identifier of a chat to which all notifications in the group belong"]
+        pub chat_id: ChatId,
+        #[doc = "Chat identifier, which notification settings must be applied to the added notifications"]
+        pub notification_settings_chat_id: ChatId,
+        #[doc = "True, if the notifications should be shown without sound"]
+        pub is_silent: bool,
+        #[doc = "Total number of unread notifications in the group, can be bigger than number of active notifications"]
+        pub total_count: i32,
+        #[doc = "List of added group notifications, sorted by notification ID"]
+        pub added_notifications: Vec<Notification>,
+        #[doc = "Identifiers of removed group notifications, sorted by notification ID"]
+        pub removed_notification_ids: Vec<NotificationId>,
+    }
+    impl TlObject for UpdateNotificationGroup {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateNotificationGroup {
+        const TYPE: &'static str = "updateNotificationGroup";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains active notifications that was shown on previous application launches. This update is sent only if the message database is used. In that case it comes once before any updateNotification and updateNotificationGroup update"]
+    pub struct UpdateActiveNotifications {
+        #[doc = "Lists of active notification groups"]
+        pub groups: Vec<NotificationGroup>,
+    }
+    impl TlObject for UpdateActiveNotifications {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateActiveNotifications {
+        const TYPE: &'static str = "updateActiveNotifications";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Describes whether there are some pending notification updates. Can be used to prevent application from killing, while there are some pending notifications"]
+    pub struct UpdateHavePendingNotifications {
+        #[doc = "True, if there are some delayed notification updates, which will be sent soon"]
+        pub have_delayed_notifications: bool,
+        #[doc = "True, if there can be some yet unreceived notifications, which are being fetched from the server"]
+        pub have_unreceived_notifications: bool,
+    }
+    impl TlObject for UpdateHavePendingNotifications {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateHavePendingNotifications {
+        const TYPE: &'static str = "updateHavePendingNotifications";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The user went online or offline"]
+    pub struct UpdateUserStatus {
+        #[doc = "User identifier"]
+        pub user_id: UserId,
+        #[doc = "New status of the user"]
+        pub status: UserStatus,
+    }
+    impl TlObject for UpdateUserStatus {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateUserStatus {
+        const TYPE: &'static str = "updateUserStatus";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Some data of a user has changed. This update is guaranteed to come before the user identifier is returned to the client"]
+    pub struct UpdateUser {
+        #[doc = "New data about the user"]
+        pub user: User,
+    }
+    impl TlObject for UpdateUser {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateUser {
+        const TYPE: &'static str = "updateUser";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Some data from userFullInfo has been changed"]
+    pub struct UpdateUserFullInfo {
+        #[doc = "User identifier"]
+        pub user_id: UserId,
+        #[doc = "New full information about the user"]
+        pub user_full_info: UserFullInfo,
+    }
+    impl TlObject for UpdateUserFullInfo {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateUserFullInfo {
+        const TYPE: &'static str = "updateUserFullInfo";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Service notification from the server. Upon receiving this the client must show a popup with the content of the notification"]
+    pub struct UpdateServiceNotification {
+        #[serde(rename = "type")]
+        #[doc = "Notification type. If type begins with \"AUTH_KEY_DROP_\", then two buttons \"Cancel\" and \"Log out\" should be shown under notification; if user presses the second, all local data should be destroyed using Destroy method"]
+        pub type_: String,
+        #[doc = "Notification content"]
+        pub content: MessageContent,
+    }
+    impl TlObject for UpdateServiceNotification {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateServiceNotification {
+        const TYPE: &'static str = "updateServiceNotification";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Some privacy setting rules have been changed"]
+    pub struct UpdateUserPrivacySettingRules {
+        #[doc = "The privacy setting"]
+        pub setting: UserPrivacySetting,
+        #[doc = "New privacy rules"]
+        pub rules: UserPrivacySettingRules,
+    }
+    impl TlObject for UpdateUserPrivacySettingRules {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateUserPrivacySettingRules {
+        const TYPE: &'static str = "updateUserPrivacySettingRules";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An option changed its value"]
+    pub struct UpdateOption {
+        #[doc = "The option name"]
+        pub name: String,
+        #[doc = "The new option value"]
+        pub value: OptionValue,
+    }
+    impl TlObject for UpdateOption {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateOption {
+        const TYPE: &'static str = "updateOption";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The selected background has changed"]
+    pub struct UpdateSelectedBackground {
+        #[doc = "True, if background for dark theme has changed"]
+        pub for_dark_theme: bool,
+        #[serde(default)]
+        #[doc = "The new selected background; may be null"]
+        pub background: Option<Background>,
+    }
+    impl TlObject for UpdateSelectedBackground {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateSelectedBackground {
+        const TYPE: &'static str = "updateSelectedBackground";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Some language pack strings have been updated"]
+    pub struct UpdateLanguagePackStrings {
+        #[doc = "Localization target to which the language pack belongs"]
+        pub localization_target: String,
+        #[doc = "Identifier of the updated language pack"]
+        pub language_pack_id: String,
+        #[doc = "List of changed language pack strings"]
+        pub strings: Vec<LanguagePackString>,
+    }
+    impl TlObject for UpdateLanguagePackStrings {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateLanguagePackStrings {
+        const TYPE: &'static str = "updateLanguagePackStrings";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "The connection state has changed"]
+    pub struct UpdateConnectionState {
+        #[doc = "The new connection state"]
+        pub state: ConnectionState,
+    }
+    impl TlObject for UpdateConnectionState {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateConnectionState {
+        const TYPE: &'static str = "updateConnectionState";
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "List of users nearby has changed. The update is sent only 60 seconds after a successful searchChatsNearby request"]
+    pub struct UpdateUsersNearby {
+        #[doc = "The new list of users nearby"]
+        pub users_nearby: Vec<ChatNearby>,
+    }
+    impl TlObject for UpdateUsersNearby {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for UpdateUsersNearby {
+        const TYPE: &'static str = "updateUsersNearby";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Contains notifications about data changes"]
+    pub enum Update {
+        UpdateAuthorizationState(UpdateAuthorizationState),
+        #[cfg(feature = "messages")]
+        UpdateNewMessage(Box<UpdateNewMessage>),
+        #[cfg(feature = "messages")]
+        UpdateMessageSendAcknowledged(UpdateMessageSendAcknowledged),
+        #[cfg(feature = "messages")]
+        UpdateMessageSendSucceeded(Box<UpdateMessageSendSucceeded>),
+        #[cfg(feature = "messages")]
+        UpdateMessageSendFailed(Box<UpdateMessageSendFailed>),
+        #[cfg(feature = "messages")]
+        UpdateMessageContent(Box<UpdateMessageContent>),
+        #[cfg(feature = "messages")]
+        UpdateMessageEdited(UpdateMessageEdited),
+        #[cfg(feature = "messages")]
+        UpdateMessageViews(UpdateMessageViews),
+        #[cfg(feature = "messages")]
+        UpdateMessageContentOpened(UpdateMessageContentOpened),
+        #[cfg(feature = "messages")]
+        UpdateMessageMentionRead(UpdateMessageMentionRead),
+        #[cfg(feature = "messages")]
+        UpdateMessageLiveLocationViewed(UpdateMessageLiveLocationViewed),
+        #[cfg(feature = "chats")]
+        UpdateNewChat(Box<UpdateNewChat>),
+        #[cfg(feature = "chats")]
+        UpdateChatChatList(UpdateChatChatList),
+        #[cfg(feature = "chats")]
+        UpdateChatTitle(UpdateChatTitle),
+        #[cfg(feature = "chats")]
+        UpdateChatPhoto(Box<UpdateChatPhoto>),
+        #[cfg(feature = "chats")]
+        UpdateChatPermissions(UpdateChatPermissions),
+        #[cfg(feature = "messages")]
+        UpdateChatLastMessage(Box<UpdateChatLastMessage>),
+        #[cfg(feature = "chats")]
+        UpdateChatOrder(UpdateChatOrder),
+        #[cfg(feature = "chats")]
+        UpdateChatIsPinned(UpdateChatIsPinned),
+        #[cfg(feature = "chats")]
+        UpdateChatIsMarkedAsUnread(UpdateChatIsMarkedAsUnread),
+        #[cfg(feature = "chats")]
+        UpdateChatIsSponsored(UpdateChatIsSponsored),
+        #[cfg(feature = "messages")]
+        UpdateChatHasScheduledMessages(UpdateChatHasScheduledMessages),
+        #[cfg(feature = "chats")]
+        UpdateChatDefaultDisableNotification(UpdateChatDefaultDisableNotification),
+        #[cfg(feature = "chats")]
+        UpdateChatReadInbox(UpdateChatReadInbox),
+        #[cfg(feature = "chats")]
+        UpdateChatReadOutbox(UpdateChatReadOutbox),
+        #[cfg(feature = "chats")]
+        UpdateChatUnreadMentionCount(UpdateChatUnreadMentionCount),
+        #[cfg(feature = "chats")]
+        UpdateChatNotificationSettings(UpdateChatNotificationSettings),
+        UpdateScopeNotificationSettings(UpdateScopeNotificationSettings),
+        #[cfg(feature = "chats")]
+        UpdateChatActionBar(UpdateChatActionBar),
+        #[cfg(feature = "messages")]
+        UpdateChatPinnedMessage(UpdateChatPinnedMessage),
+        #[cfg(feature = "bots")]
+        UpdateChatReplyMarkup(UpdateChatReplyMarkup),
+        #[cfg(feature = "messages")]
+        UpdateChatDraftMessage(Box<UpdateChatDraftMessage>),
+        #[cfg(feature = "chats")]
+        UpdateChatOnlineMemberCount(UpdateChatOnlineMemberCount),
+        UpdateNotification(Box<UpdateNotification>),
+        UpdateNotificationGroup(UpdateNotificationGroup),
+        UpdateActiveNotifications(UpdateActiveNotifications),
+        UpdateHavePendingNotifications(UpdateHavePendingNotifications),
+        #[cfg(feature = "messages")]
+        UpdateDeleteMessages(UpdateDeleteMessages),
+        #[cfg(feature = "chats")]
+        UpdateUserChatAction(UpdateUserChatAction),
+        UpdateUserStatus(UpdateUserStatus),
+        UpdateUser(Box<UpdateUser>),
+        #[cfg(feature = "chats")]
+        UpdateBasicGroup(UpdateBasicGroup),
+        #[cfg(feature = "chats")]
+        UpdateSupergroup(UpdateSupergroup),
+        #[cfg(feature = "chats")]
+        UpdateSecretChat(UpdateSecretChat),
+        UpdateUserFullInfo(UpdateUserFullInfo),
+        #[cfg(feature = "chats")]
+        UpdateBasicGroupFullInfo(UpdateBasicGroupFullInfo),
+        #[cfg(feature = "chats")]
+        UpdateSupergroupFullInfo(Box<UpdateSupergroupFullInfo>),
+        UpdateServiceNotification(Box<UpdateServiceNotification>),
+        #[cfg(feature = "files")]
+        UpdateFile(UpdateFile),
+        #[cfg(feature = "files")]
+        UpdateFileGenerationStart(UpdateFileGenerationStart),
+        #[cfg(feature = "files")]
+        UpdateFileGenerationStop(UpdateFileGenerationStop),
+        #[cfg(feature = "calls")]
+        UpdateCall(UpdateCall),
+        UpdateUserPrivacySettingRules(UpdateUserPrivacySettingRules),
+        #[cfg(feature = "messages")]
+        UpdateUnreadMessageCount(UpdateUnreadMessageCount),
+        #[cfg(feature = "chats")]
+        UpdateUnreadChatCount(UpdateUnreadChatCount),
+        UpdateOption(UpdateOption),
+        #[cfg(feature = "stickers")]
+        UpdateInstalledStickerSets(UpdateInstalledStickerSets),
+        #[cfg(feature = "stickers")]
+        UpdateTrendingStickerSets(UpdateTrendingStickerSets),
+        #[cfg(feature = "stickers")]
+        UpdateRecentStickers(UpdateRecentStickers),
+        #[cfg(feature = "stickers")]
+        UpdateFavoriteStickers(UpdateFavoriteStickers),
+        #[cfg(feature = "stickers")]
+        UpdateSavedAnimations(UpdateSavedAnimations),
+        UpdateSelectedBackground(Box<UpdateSelectedBackground>),
+        UpdateLanguagePackStrings(UpdateLanguagePackStrings),
+        UpdateConnectionState(UpdateConnectionState),
+        #[cfg(feature = "auth")]
+        UpdateTermsOfService(UpdateTermsOfService),
+        UpdateUsersNearby(UpdateUsersNearby),
+        #[cfg(feature = "bots")]
+        UpdateNewInlineQuery(UpdateNewInlineQuery),
+        #[cfg(feature = "bots")]
+        UpdateNewChosenInlineResult(UpdateNewChosenInlineResult),
+        #[cfg(feature = "bots")]
+        UpdateNewCallbackQuery(UpdateNewCallbackQuery),
+        #[cfg(feature = "bots")]
+        UpdateNewInlineCallbackQuery(UpdateNewInlineCallbackQuery),
+        #[cfg(feature = "payments")]
+        UpdateNewShippingQuery(Box<UpdateNewShippingQuery>),
+        #[cfg(feature = "payments")]
+        UpdateNewPreCheckoutQuery(Box<UpdateNewPreCheckoutQuery>),
+        #[cfg(feature = "bots")]
+        UpdateNewCustomEvent(UpdateNewCustomEvent),
+        #[cfg(feature = "bots")]
+        UpdateNewCustomQuery(UpdateNewCustomQuery),
+        #[cfg(feature = "messages")]
+        UpdatePoll(Box<UpdatePoll>),
+        #[cfg(feature = "messages")]
+        UpdatePollAnswer(UpdatePollAnswer),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl<'de> Deserialize<'de> for Update {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "updateAuthorizationState" => {
+                    Deserialize::deserialize(value).map(Update::UpdateAuthorizationState)
+                }
+                #[cfg(feature = "messages")]
+                "updateNewMessage" => Deserialize::deserialize(value).map(Update::UpdateNewMessage),
+                #[cfg(feature = "messages")]
+                "updateMessageSendAcknowledged" => {
+                    Deserialize::deserialize(value).map(Update::UpdateMessageSendAcknowledged)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageSendSucceeded" => {
+                    Deserialize::deserialize(value).map(Update::UpdateMessageSendSucceeded)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageSendFailed" => {
+                    Deserialize::deserialize(value).map(Update::UpdateMessageSendFailed)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageContent" => {
+                    Deserialize::deserialize(value).map(Update::UpdateMessageContent)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageEdited" => {
+                    Deserialize::deserialize(value).map(Update::UpdateMessageEdited)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageViews" => {
+                    Deserialize::deserialize(value).map(Update::UpdateMessageViews)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageContentOpened" => {
+                    Deserialize::deserialize(value).map(Update::UpdateMessageContentOpened)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageMentionRead" => {
+                    Deserialize::deserialize(value).map(Update::UpdateMessageMentionRead)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageLiveLocationViewed" => {
+                    Deserialize::deserialize(value).map(Update::UpdateMessageLiveLocationViewed)
+                }
+                #[cfg(feature = "chats")]
+                "updateNewChat" => Deserialize::deserialize(value).map(Update::UpdateNewChat),
+                #[cfg(feature = "chats")]
+                "updateChatChatList" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatChatList)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatTitle" => Deserialize::deserialize(value).map(Update::UpdateChatTitle),
+                #[cfg(feature = "chats")]
+                "updateChatPhoto" => Deserialize::deserialize(value).map(Update::UpdateChatPhoto),
+                #[cfg(feature = "chats")]
+                "updateChatPermissions" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatPermissions)
+                }
+                #[cfg(feature = "messages")]
+                "updateChatLastMessage" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatLastMessage)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatOrder" => Deserialize::deserialize(value).map(Update::UpdateChatOrder),
+                #[cfg(feature = "chats")]
+                "updateChatIsPinned" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatIsPinned)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatIsMarkedAsUnread" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatIsMarkedAsUnread)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatIsSponsored" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatIsSponsored)
+                }
+                #[cfg(feature = "messages")]
+                "updateChatHasScheduledMessages" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatHasScheduledMessages)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatDefaultDisableNotification" => Deserialize::deserialize(value)
+                    .map(Update::UpdateChatDefaultDisableNotification),
+                #[cfg(feature = "chats")]
+                "updateChatReadInbox" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatReadInbox)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatReadOutbox" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatReadOutbox)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatUnreadMentionCount" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatUnreadMentionCount)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatNotificationSettings" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatNotificationSettings)
+                }
+                "updateScopeNotificationSettings" => {
+                    Deserialize::deserialize(value).map(Update::UpdateScopeNotificationSettings)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatActionBar" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatActionBar)
+                }
+                #[cfg(feature = "messages")]
+                "updateChatPinnedMessage" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatPinnedMessage)
+                }
+                #[cfg(feature = "bots")]
+                "updateChatReplyMarkup" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatReplyMarkup)
+                }
+                #[cfg(feature = "messages")]
+                "updateChatDraftMessage" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatDraftMessage)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatOnlineMemberCount" => {
+                    Deserialize::deserialize(value).map(Update::UpdateChatOnlineMemberCount)
+                }
+                "updateNotification" => {
+                    Deserialize::deserialize(value).map(Update::UpdateNotification)
+                }
+                "updateNotificationGroup" => {
+                    Deserialize::deserialize(value).map(Update::UpdateNotificationGroup)
+                }
+                "updateActiveNotifications" => {
+                    Deserialize::deserialize(value).map(Update::UpdateActiveNotifications)
+                }
+                "updateHavePendingNotifications" => {
+                    Deserialize::deserialize(value).map(Update::UpdateHavePendingNotifications)
+                }
+                #[cfg(feature = "messages")]
+                "updateDeleteMessages" => {
+                    Deserialize::deserialize(value).map(Update::UpdateDeleteMessages)
+                }
+                #[cfg(feature = "chats")]
+                "updateUserChatAction" => {
+                    Deserialize::deserialize(value).map(Update::UpdateUserChatAction)
+                }
+                "updateUserStatus" => Deserialize::deserialize(value).map(Update::UpdateUserStatus),
+                "updateUser" => Deserialize::deserialize(value).map(Update::UpdateUser),
+                #[cfg(feature = "chats")]
+                "updateBasicGroup" => Deserialize::deserialize(value).map(Update::UpdateBasicGroup),
+                #[cfg(feature = "chats")]
+                "updateSupergroup" => Deserialize::deserialize(value).map(Update::UpdateSupergroup),
+                #[cfg(feature = "chats")]
+                "updateSecretChat" => Deserialize::deserialize(value).map(Update::UpdateSecretChat),
+                "updateUserFullInfo" => {
+                    Deserialize::deserialize(value).map(Update::UpdateUserFullInfo)
+                }
+                #[cfg(feature = "chats")]
+                "updateBasicGroupFullInfo" => {
+                    Deserialize::deserialize(value).map(Update::UpdateBasicGroupFullInfo)
+                }
+                #[cfg(feature = "chats")]
+                "updateSupergroupFullInfo" => {
+                    Deserialize::deserialize(value).map(Update::UpdateSupergroupFullInfo)
+                }
+                "updateServiceNotification" => {
+                    Deserialize::deserialize(value).map(Update::UpdateServiceNotification)
+                }
+                #[cfg(feature = "files")]
+                "updateFile" => Deserialize::deserialize(value).map(Update::UpdateFile),
+                #[cfg(feature = "files")]
+                "updateFileGenerationStart" => {
+                    Deserialize::deserialize(value).map(Update::UpdateFileGenerationStart)
+                }
+                #[cfg(feature = "files")]
+                "updateFileGenerationStop" => {
+                    Deserialize::deserialize(value).map(Update::UpdateFileGenerationStop)
+                }
+                #[cfg(feature = "calls")]
+                "updateCall" => Deserialize::deserialize(value).map(Update::UpdateCall),
+                "updateUserPrivacySettingRules" => {
+                    Deserialize::deserialize(value).map(Update::UpdateUserPrivacySettingRules)
+                }
+                #[cfg(feature = "messages")]
+                "updateUnreadMessageCount" => {
+                    Deserialize::deserialize(value).map(Update::UpdateUnreadMessageCount)
+                }
+                #[cfg(feature = "chats")]
+                "updateUnreadChatCount" => {
+                    Deserialize::deserialize(value).map(Update::UpdateUnreadChatCount)
+                }
+                "updateOption" => Deserialize::deserialize(value).map(Update::UpdateOption),
+                #[cfg(feature = "stickers")]
+                "updateInstalledStickerSets" => {
+                    Deserialize::deserialize(value).map(Update::UpdateInstalledStickerSets)
+                }
+                #[cfg(feature = "stickers")]
+                "updateTrendingStickerSets" => {
+                    Deserialize::deserialize(value).map(Update::UpdateTrendingStickerSets)
+                }
+                #[cfg(feature = "stickers")]
+                "updateRecentStickers" => {
+                    Deserialize::deserialize(value).map(Update::UpdateRecentStickers)
+                }
+                #[cfg(feature = "stickers")]
+                "updateFavoriteStickers" => {
+                    Deserialize::deserialize(value).map(Update::UpdateFavoriteStickers)
+                }
+                #[cfg(feature = "stickers")]
+                "updateSavedAnimations" => {
+                    Deserialize::deserialize(value).map(Update::UpdateSavedAnimations)
+                }
+                "updateSelectedBackground" => {
+                    Deserialize::deserialize(value).map(Update::UpdateSelectedBackground)
+                }
+                "updateLanguagePackStrings" => {
+                    Deserialize::deserialize(value).map(Update::UpdateLanguagePackStrings)
+                }
+                "updateConnectionState" => {
+                    Deserialize::deserialize(value).map(Update::UpdateConnectionState)
+                }
+                #[cfg(feature = "auth")]
+                "updateTermsOfService" => {
+                    Deserialize::deserialize(value).map(Update::UpdateTermsOfService)
+                }
+                "updateUsersNearby" => {
+                    Deserialize::deserialize(value).map(Update::UpdateUsersNearby)
+                }
+                #[cfg(feature = "bots")]
+                "updateNewInlineQuery" => {
+                    Deserialize::deserialize(value).map(Update::UpdateNewInlineQuery)
+                }
+                #[cfg(feature = "bots")]
+                "updateNewChosenInlineResult" => {
+                    Deserialize::deserialize(value).map(Update::UpdateNewChosenInlineResult)
+                }
+                #[cfg(feature = "bots")]
+                "updateNewCallbackQuery" => {
+                    Deserialize::deserialize(value).map(Update::UpdateNewCallbackQuery)
+                }
+                #[cfg(feature = "bots")]
+                "updateNewInlineCallbackQuery" => {
+                    Deserialize::deserialize(value).map(Update::UpdateNewInlineCallbackQuery)
+                }
+                #[cfg(feature = "payments")]
+                "updateNewShippingQuery" => {
+                    Deserialize::deserialize(value).map(Update::UpdateNewShippingQuery)
+                }
+                #[cfg(feature = "payments")]
+                "updateNewPreCheckoutQuery" => {
+                    Deserialize::deserialize(value).map(Update::UpdateNewPreCheckoutQuery)
+                }
+                #[cfg(feature = "bots")]
+                "updateNewCustomEvent" => {
+                    Deserialize::deserialize(value).map(Update::UpdateNewCustomEvent)
+                }
+                #[cfg(feature = "bots")]
+                "updateNewCustomQuery" => {
+                    Deserialize::deserialize(value).map(Update::UpdateNewCustomQuery)
+                }
+                #[cfg(feature = "messages")]
+                "updatePoll" => Deserialize::deserialize(value).map(Update::UpdatePoll),
+                #[cfg(feature = "messages")]
+                "updatePollAnswer" => Deserialize::deserialize(value).map(Update::UpdatePollAnswer),
+                _ => Unknown::deserialize(value).map(Update::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl TlObject for Update {
+        const CLASS: &'static str = "Update";
+        fn type_name(&self) -> &str {
+            match self {
+                Update::UpdateAuthorizationState(_) => "updateAuthorizationState",
+                #[cfg(feature = "messages")]
+                Update::UpdateNewMessage(_) => "updateNewMessage",
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageSendAcknowledged(_) => "updateMessageSendAcknowledged",
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageSendSucceeded(_) => "updateMessageSendSucceeded",
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageSendFailed(_) => "updateMessageSendFailed",
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageContent(_) => "updateMessageContent",
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageEdited(_) => "updateMessageEdited",
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageViews(_) => "updateMessageViews",
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageContentOpened(_) => "updateMessageContentOpened",
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageMentionRead(_) => "updateMessageMentionRead",
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageLiveLocationViewed(_) => "updateMessageLiveLocationViewed",
+                #[cfg(feature = "chats")]
+                Update::UpdateNewChat(_) => "updateNewChat",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatChatList(_) => "updateChatChatList",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatTitle(_) => "updateChatTitle",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatPhoto(_) => "updateChatPhoto",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatPermissions(_) => "updateChatPermissions",
+                #[cfg(feature = "messages")]
+                Update::UpdateChatLastMessage(_) => "updateChatLastMessage",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatOrder(_) => "updateChatOrder",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatIsPinned(_) => "updateChatIsPinned",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatIsMarkedAsUnread(_) => "updateChatIsMarkedAsUnread",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatIsSponsored(_) => "updateChatIsSponsored",
+                #[cfg(feature = "messages")]
+                Update::UpdateChatHasScheduledMessages(_) => "updateChatHasScheduledMessages",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatDefaultDisableNotification(_) => {
+                    "updateChatDefaultDisableNotification"
+                }
+                #[cfg(feature = "chats")]
+                Update::UpdateChatReadInbox(_) => "updateChatReadInbox",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatReadOutbox(_) => "updateChatReadOutbox",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatUnreadMentionCount(_) => "updateChatUnreadMentionCount",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatNotificationSettings(_) => "updateChatNotificationSettings",
+                Update::UpdateScopeNotificationSettings(_) => "updateScopeNotificationSettings",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatActionBar(_) => "updateChatActionBar",
+                #[cfg(feature = "messages")]
+                Update::UpdateChatPinnedMessage(_) => "updateChatPinnedMessage",
+                #[cfg(feature = "bots")]
+                Update::UpdateChatReplyMarkup(_) => "updateChatReplyMarkup",
+                #[cfg(feature = "messages")]
+                Update::UpdateChatDraftMessage(_) => "updateChatDraftMessage",
+                #[cfg(feature = "chats")]
+                Update::UpdateChatOnlineMemberCount(_) => "updateChatOnlineMemberCount",
+                Update::UpdateNotification(_) => "updateNotification",
+                Update::UpdateNotificationGroup(_) => "updateNotificationGroup",
+                Update::UpdateActiveNotifications(_) => "updateActiveNotifications",
+                Update::UpdateHavePendingNotifications(_) => "updateHavePendingNotifications",
+                #[cfg(feature = "messages")]
+                Update::UpdateDeleteMessages(_) => "updateDeleteMessages",
+                #[cfg(feature = "chats")]
+                Update::UpdateUserChatAction(_) => "updateUserChatAction",
+                Update::UpdateUserStatus(_) => "updateUserStatus",
+                Update::UpdateUser(_) => "updateUser",
+                #[cfg(feature = "chats")]
+                Update::UpdateBasicGroup(_) => "updateBasicGroup",
+                #[cfg(feature = "chats")]
+                Update::UpdateSupergroup(_) => "updateSupergroup",
+                #[cfg(feature = "chats")]
+                Update::UpdateSecretChat(_) => "updateSecretChat",
+                Update::UpdateUserFullInfo(_) => "updateUserFullInfo",
+                #[cfg(feature = "chats")]
+                Update::UpdateBasicGroupFullInfo(_) => "updateBasicGroupFullInfo",
+                #[cfg(feature = "chats")]
+                Update::UpdateSupergroupFullInfo(_) => "updateSupergroupFullInfo",
+                Update::UpdateServiceNotification(_) => "updateServiceNotification",
+                #[cfg(feature = "files")]
+                Update::UpdateFile(_) => "updateFile",
+                #[cfg(feature = "files")]
+                Update::UpdateFileGenerationStart(_) => "updateFileGenerationStart",
+                #[cfg(feature = "files")]
+                Update::UpdateFileGenerationStop(_) => "updateFileGenerationStop",
+                #[cfg(feature = "calls")]
+                Update::UpdateCall(_) => "updateCall",
+                Update::UpdateUserPrivacySettingRules(_) => "updateUserPrivacySettingRules",
+                #[cfg(feature = "messages")]
+                Update::UpdateUnreadMessageCount(_) => "updateUnreadMessageCount",
+                #[cfg(feature = "chats")]
+                Update::UpdateUnreadChatCount(_) => "updateUnreadChatCount",
+                Update::UpdateOption(_) => "updateOption",
+                #[cfg(feature = "stickers")]
+                Update::UpdateInstalledStickerSets(_) => "updateInstalledStickerSets",
+                #[cfg(feature = "stickers")]
+                Update::UpdateTrendingStickerSets(_) => "updateTrendingStickerSets",
+                #[cfg(feature = "stickers")]
+                Update::UpdateRecentStickers(_) => "updateRecentStickers",
+                #[cfg(feature = "stickers")]
+                Update::UpdateFavoriteStickers(_) => "updateFavoriteStickers",
+                #[cfg(feature = "stickers")]
+                Update::UpdateSavedAnimations(_) => "updateSavedAnimations",
+                Update::UpdateSelectedBackground(_) => "updateSelectedBackground",
+                Update::UpdateLanguagePackStrings(_) => "updateLanguagePackStrings",
+                Update::UpdateConnectionState(_) => "updateConnectionState",
+                #[cfg(feature = "auth")]
+                Update::UpdateTermsOfService(_) => "updateTermsOfService",
+                Update::UpdateUsersNearby(_) => "updateUsersNearby",
+                #[cfg(feature = "bots")]
+                Update::UpdateNewInlineQuery(_) => "updateNewInlineQuery",
+                #[cfg(feature = "bots")]
+                Update::UpdateNewChosenInlineResult(_) => "updateNewChosenInlineResult",
+                #[cfg(feature = "bots")]
+                Update::UpdateNewCallbackQuery(_) => "updateNewCallbackQuery",
+                #[cfg(feature = "bots")]
+                Update::UpdateNewInlineCallbackQuery(_) => "updateNewInlineCallbackQuery",
+                #[cfg(feature = "payments")]
+                Update::UpdateNewShippingQuery(_) => "updateNewShippingQuery",
+                #[cfg(feature = "payments")]
+                Update::UpdateNewPreCheckoutQuery(_) => "updateNewPreCheckoutQuery",
+                #[cfg(feature = "bots")]
+                Update::UpdateNewCustomEvent(_) => "updateNewCustomEvent",
+                #[cfg(feature = "bots")]
+                Update::UpdateNewCustomQuery(_) => "updateNewCustomQuery",
+                #[cfg(feature = "messages")]
+                Update::UpdatePoll(_) => "updatePoll",
+                #[cfg(feature = "messages")]
+                Update::UpdatePollAnswer(_) => "updatePollAnswer",
+                Update::Unknown(object) => &object.type_,
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "Contains a list of updates"]
+    pub struct Updates {
+        #[doc = "List of updates"]
+        pub updates: Vec<Update>,
+    }
+    impl TlObject for Updates {
+        const CLASS: &'static str = "Updates";
+        fn type_name(&self) -> &str {
+            Self::TYPE
+        }
+    }
+    impl TlConstructor for Updates {
+        const TYPE: &'static str = "updates";
+    }
+    #[derive(Serialize, Debug, Clone, PartialEq)]
+    #[serde(rename_all = "camelCase")]
+    #[serde(tag = "@type")]
+    #[doc = "Any object that can be returned by a function call. Abstract classes are matched by the @type of any of their constructors, and every update has a variant of its own"]
+    pub enum Response {
+        Error(Error),
+        Ok(Ok),
+        TdlibParameters(Box<TdlibParameters>),
+        AuthenticationCodeInfo(AuthenticationCodeInfo),
+        #[cfg(any(feature = "auth", feature = "passport"))]
+        EmailAddressAuthenticationCodeInfo(EmailAddressAuthenticationCodeInfo),
+        TextEntity(TextEntity),
+        #[cfg(feature = "messages")]
+        TextEntities(TextEntities),
+        FormattedText(FormattedText),
+        TermsOfService(TermsOfService),
+        #[cfg(feature = "auth")]
+        PasswordState(PasswordState),
+        #[cfg(feature = "auth")]
+        RecoveryEmailAddress(RecoveryEmailAddress),
+        #[cfg(feature = "auth")]
+        TemporaryPasswordState(TemporaryPasswordState),
+        LocalFile(LocalFile),
+        RemoteFile(RemoteFile),
+        File(File),
+        PhotoSize(Box<PhotoSize>),
+        Minithumbnail(Minithumbnail),
+        MaskPosition(MaskPosition),
+        PollOption(PollOption),
+        Animation(Box<Animation>),
+        Audio(Box<Audio>),
+        Document(Box<Document>),
+        Photo(Photo),
+        Sticker(Box<Sticker>),
+        Video(Box<Video>),
+        VideoNote(Box<VideoNote>),
+        VoiceNote(Box<VoiceNote>),
+        Contact(Contact),
+        Location(Location),
+        Venue(Box<Venue>),
+        Game(Box<Game>),
+        Poll(Box<Poll>),
+        ProfilePhoto(Box<ProfilePhoto>),
+        ChatPhoto(Box<ChatPhoto>),
+        BotCommand(BotCommand),
+        BotInfo(BotInfo),
+        #[cfg(feature = "chats")]
+        ChatLocation(ChatLocation),
+        User(Box<User>),
+        UserFullInfo(UserFullInfo),
+        UserProfilePhoto(UserProfilePhoto),
+        UserProfilePhotos(UserProfilePhotos),
+        Users(Users),
+        #[cfg(feature = "chats")]
+        ChatAdministrator(ChatAdministrator),
+        #[cfg(feature = "chats")]
+        ChatAdministrators(ChatAdministrators),
+        #[cfg(feature = "chats")]
+        ChatPermissions(ChatPermissions),
+        #[cfg(feature = "chats")]
+        ChatMember(ChatMember),
+        #[cfg(feature = "chats")]
+        ChatMembers(ChatMembers),
+        #[cfg(feature = "chats")]
+        BasicGroup(BasicGroup),
+        #[cfg(feature = "chats")]
+        BasicGroupFullInfo(BasicGroupFullInfo),
+        #[cfg(feature = "chats")]
+        Supergroup(Supergroup),
+        #[cfg(feature = "chats")]
+        SupergroupFullInfo(Box<SupergroupFullInfo>),
+        #[cfg(feature = "chats")]
+        SecretChat(SecretChat),
+        #[cfg(any(
+            feature = "bots",
+            feature = "calls",
+            feature = "chats",
+            feature = "messages"
+        ))]
+        MessageForwardInfo(MessageForwardInfo),
+        #[cfg(any(
+            feature = "bots",
+            feature = "calls",
+            feature = "chats",
+            feature = "messages"
+        ))]
+        Message(Box<Message>),
+        #[cfg(any(feature = "calls", feature = "messages"))]
+        Messages(Messages),
+        #[cfg(feature = "messages")]
+        FoundMessages(FoundMessages),
+        #[cfg(feature = "chats")]
+        ChatNotificationSettings(ChatNotificationSettings),
+        ScopeNotificationSettings(ScopeNotificationSettings),
+        #[cfg(any(feature = "chats", feature = "messages"))]
+        DraftMessage(Box<DraftMessage>),
+        #[cfg(feature = "chats")]
+        Chat(Box<Chat>),
+        #[cfg(feature = "chats")]
+        Chats(Chats),
+        ChatNearby(ChatNearby),
+        #[cfg(feature = "chats")]
+        ChatsNearby(ChatsNearby),
+        #[cfg(feature = "chats")]
+        ChatInviteLink(ChatInviteLink),
+        ChatInviteLinkInfo(Box<ChatInviteLinkInfo>),
+        #[cfg(any(
+            feature = "bots",
+            feature = "calls",
+            feature = "chats",
+            feature = "messages"
+        ))]
+        KeyboardButton(KeyboardButton),
+        #[cfg(any(
+            feature = "bots",
+            feature = "calls",
+            feature = "chats",
+            feature = "messages"
+        ))]
+        InlineKeyboardButton(InlineKeyboardButton),
+        #[cfg(feature = "messages")]
+        PageBlockCaption(PageBlockCaption),
+        #[cfg(feature = "messages")]
+        PageBlockListItem(PageBlockListItem),
+        #[cfg(feature = "messages")]
+        PageBlockTableCell(Box<PageBlockTableCell>),
+        #[cfg(feature = "messages")]
+        PageBlockRelatedArticle(Box<PageBlockRelatedArticle>),
+        #[cfg(feature = "messages")]
+        WebPageInstantView(WebPageInstantView),
+        WebPage(Box<WebPage>),
+        Address(Box<Address>),
+        #[cfg(any(
+            feature = "bots",
+            feature = "chats",
+            feature = "messages",
+            feature = "payments"
+        ))]
+        LabeledPricePart(LabeledPricePart),
+        #[cfg(any(
+            feature = "bots",
+            feature = "chats",
+            feature = "messages",
+            feature = "payments"
+        ))]
+        Invoice(Invoice),
+        OrderInfo(Box<OrderInfo>),
+        #[cfg(feature = "payments")]
+        ShippingOption(ShippingOption),
+        #[cfg(feature = "payments")]
+        SavedCredentials(SavedCredentials),
+        #[cfg(feature = "payments")]
+        PaymentsProviderStripe(PaymentsProviderStripe),
+        #[cfg(feature = "payments")]
+        PaymentForm(Box<PaymentForm>),
+        #[cfg(feature = "payments")]
+        ValidatedOrderInfo(ValidatedOrderInfo),
+        #[cfg(feature = "payments")]
+        PaymentResult(PaymentResult),
+        #[cfg(feature = "payments")]
+        PaymentReceipt(Box<PaymentReceipt>),
+        DatedFile(DatedFile),
+        #[cfg(feature = "passport")]
+        Date(Date),
+        #[cfg(feature = "passport")]
+        PersonalDetails(Box<PersonalDetails>),
+        #[cfg(feature = "passport")]
+        IdentityDocument(Box<IdentityDocument>),
+        #[cfg(feature = "passport")]
+        InputIdentityDocument(Box<InputIdentityDocument>),
+        #[cfg(feature = "passport")]
+        PersonalDocument(PersonalDocument),
+        #[cfg(feature = "passport")]
+        InputPersonalDocument(InputPersonalDocument),
+        #[cfg(feature = "passport")]
+        PassportElements(PassportElements),
+        #[cfg(feature = "passport")]
+        PassportElementError(PassportElementError),
+        #[cfg(feature = "passport")]
+        PassportSuitableElement(PassportSuitableElement),
+        #[cfg(feature = "passport")]
+        PassportRequiredElement(PassportRequiredElement),
+        #[cfg(feature = "passport")]
+        PassportAuthorizationForm(PassportAuthorizationForm),
+        #[cfg(feature = "passport")]
+        PassportElementsWithErrors(PassportElementsWithErrors),
+        EncryptedCredentials(EncryptedCredentials),
+        EncryptedPassportElement(Box<EncryptedPassportElement>),
+        #[cfg(feature = "passport")]
+        InputPassportElementError(InputPassportElementError),
+        InputThumbnail(InputThumbnail),
+        #[cfg(any(feature = "bots", feature = "messages"))]
+        SendMessageOptions(SendMessageOptions),
+        #[cfg(feature = "stickers")]
+        Stickers(Stickers),
+        #[cfg(feature = "stickers")]
+        Emojis(Emojis),
+        #[cfg(feature = "stickers")]
+        StickerSet(Box<StickerSet>),
+        #[cfg(feature = "stickers")]
+        StickerSetInfo(Box<StickerSetInfo>),
+        #[cfg(feature = "stickers")]
+        StickerSets(StickerSets),
+        #[cfg(feature = "calls")]
+        CallProtocol(CallProtocol),
+        #[cfg(feature = "calls")]
+        CallConnection(CallConnection),
+        #[cfg(feature = "calls")]
+        CallId(CallId),
+        #[cfg(feature = "calls")]
+        Call(Call),
+        #[cfg(any(feature = "auth", feature = "passport"))]
+        PhoneNumberAuthenticationSettings(PhoneNumberAuthenticationSettings),
+        Animations(Animations),
+        ImportedContacts(ImportedContacts),
+        HttpUrl(HttpUrl),
+        #[cfg(feature = "bots")]
+        InlineQueryResults(InlineQueryResults),
+        #[cfg(feature = "bots")]
+        CallbackQueryAnswer(CallbackQueryAnswer),
+        #[cfg(feature = "bots")]
+        CustomRequestResult(CustomRequestResult),
+        #[cfg(feature = "bots")]
+        GameHighScore(GameHighScore),
+        #[cfg(feature = "bots")]
+        GameHighScores(GameHighScores),
+        #[cfg(feature = "chats")]
+        ChatEvent(Box<ChatEvent>),
+        #[cfg(feature = "chats")]
+        ChatEvents(ChatEvents),
+        #[cfg(feature = "chats")]
+        ChatEventLogFilters(ChatEventLogFilters),
+        LanguagePackString(LanguagePackString),
+        LanguagePackStrings(LanguagePackStrings),
+        LanguagePackInfo(Box<LanguagePackInfo>),
+        LocalizationTargetInfo(LocalizationTargetInfo),
+        PushReceiverId(PushReceiverId),
+        Background(Box<Background>),
+        Backgrounds(Backgrounds),
+        Hashtags(Hashtags),
+        Notification(Notification),
+        NotificationGroup(NotificationGroup),
+        JsonObjectMember(JsonObjectMember),
+        UserPrivacySettingRules(UserPrivacySettingRules),
+        #[cfg(feature = "auth")]
+        AccountTtl(AccountTtl),
+        #[cfg(feature = "auth")]
+        Session(Box<Session>),
+        #[cfg(feature = "auth")]
+        Sessions(Sessions),
+        #[cfg(feature = "auth")]
+        ConnectedWebsite(Box<ConnectedWebsite>),
+        #[cfg(feature = "auth")]
+        ConnectedWebsites(ConnectedWebsites),
+        #[cfg(feature = "messages")]
+        PublicMessageLink(PublicMessageLink),
+        #[cfg(feature = "messages")]
+        MessageLinkInfo(Box<MessageLinkInfo>),
+        #[cfg(feature = "files")]
+        FilePart(FilePart),
+        #[cfg(feature = "files")]
+        StorageStatisticsByFileType(StorageStatisticsByFileType),
+        #[cfg(feature = "files")]
+        StorageStatisticsByChat(StorageStatisticsByChat),
+        #[cfg(feature = "files")]
+        StorageStatistics(StorageStatistics),
+        #[cfg(feature = "files")]
+        StorageStatisticsFast(StorageStatisticsFast),
+        #[cfg(feature = "files")]
+        DatabaseStatistics(DatabaseStatistics),
+        #[cfg(feature = "files")]
+        NetworkStatistics(NetworkStatistics),
+        #[cfg(feature = "files")]
+        AutoDownloadSettings(AutoDownloadSettings),
+        #[cfg(feature = "files")]
+        AutoDownloadSettingsPresets(AutoDownloadSettingsPresets),
+        TMeUrl(TMeUrl),
+        TMeUrls(TMeUrls),
+        Count(Count),
+        Text(Text),
+        Seconds(Seconds),
+        DeepLinkInfo(DeepLinkInfo),
+        #[cfg(feature = "proxy")]
+        Proxy(Proxy),
+        #[cfg(feature = "proxy")]
+        Proxies(Proxies),
+        #[cfg(feature = "stickers")]
+        InputSticker(Box<InputSticker>),
+        Updates(Updates),
+        #[cfg(feature = "logging")]
+        LogVerbosityLevel(LogVerbosityLevel),
+        #[cfg(feature = "logging")]
+        LogTags(LogTags),
+        #[cfg(feature = "tests")]
+        TestInt(TestInt),
+        #[cfg(feature = "tests")]
+        TestString(TestString),
+        #[cfg(feature = "tests")]
+        TestBytes(TestBytes),
+        #[cfg(feature = "tests")]
+        TestVectorInt(TestVectorInt),
+        #[cfg(feature = "tests")]
+        TestVectorIntObject(TestVectorIntObject),
+        #[cfg(feature = "tests")]
+        TestVectorString(TestVectorString),
+        #[cfg(feature = "tests")]
+        TestVectorStringObject(TestVectorStringObject),
+        UpdateAuthorizationState(UpdateAuthorizationState),
+        #[cfg(feature = "messages")]
+        UpdateNewMessage(Box<UpdateNewMessage>),
+        #[cfg(feature = "messages")]
+        UpdateMessageSendAcknowledged(UpdateMessageSendAcknowledged),
+        #[cfg(feature = "messages")]
+        UpdateMessageSendSucceeded(Box<UpdateMessageSendSucceeded>),
+        #[cfg(feature = "messages")]
+        UpdateMessageSendFailed(Box<UpdateMessageSendFailed>),
+        #[cfg(feature = "messages")]
+        UpdateMessageContent(Box<UpdateMessageContent>),
+        #[cfg(feature = "messages")]
+        UpdateMessageEdited(UpdateMessageEdited),
+        #[cfg(feature = "messages")]
+        UpdateMessageViews(UpdateMessageViews),
+        #[cfg(feature = "messages")]
+        UpdateMessageContentOpened(UpdateMessageContentOpened),
+        #[cfg(feature = "messages")]
+        UpdateMessageMentionRead(UpdateMessageMentionRead),
+        #[cfg(feature = "messages")]
+        UpdateMessageLiveLocationViewed(UpdateMessageLiveLocationViewed),
+        #[cfg(feature = "chats")]
+        UpdateNewChat(Box<UpdateNewChat>),
+        #[cfg(feature = "chats")]
+        UpdateChatChatList(UpdateChatChatList),
+        #[cfg(feature = "chats")]
+        UpdateChatTitle(UpdateChatTitle),
+        #[cfg(feature = "chats")]
+        UpdateChatPhoto(Box<UpdateChatPhoto>),
+        #[cfg(feature = "chats")]
+        UpdateChatPermissions(UpdateChatPermissions),
+        #[cfg(feature = "messages")]
+        UpdateChatLastMessage(Box<UpdateChatLastMessage>),
+        #[cfg(feature = "chats")]
+        UpdateChatOrder(UpdateChatOrder),
+        #[cfg(feature = "chats")]
+        UpdateChatIsPinned(UpdateChatIsPinned),
+        #[cfg(feature = "chats")]
+        UpdateChatIsMarkedAsUnread(UpdateChatIsMarkedAsUnread),
+        #[cfg(feature = "chats")]
+        UpdateChatIsSponsored(UpdateChatIsSponsored),
+        #[cfg(feature = "messages")]
+        UpdateChatHasScheduledMessages(UpdateChatHasScheduledMessages),
+        #[cfg(feature = "chats")]
+        UpdateChatDefaultDisableNotification(UpdateChatDefaultDisableNotification),
+        #[cfg(feature = "chats")]
+        UpdateChatReadInbox(UpdateChatReadInbox),
+        #[cfg(feature = "chats")]
+        UpdateChatReadOutbox(UpdateChatReadOutbox),
+        #[cfg(feature = "chats")]
+        UpdateChatUnreadMentionCount(UpdateChatUnreadMentionCount),
+        #[cfg(feature = "chats")]
+        UpdateChatNotificationSettings(UpdateChatNotificationSettings),
+        UpdateScopeNotificationSettings(UpdateScopeNotificationSettings),
+        #[cfg(feature = "chats")]
+        UpdateChatActionBar(UpdateChatActionBar),
+        #[cfg(feature = "messages")]
+        UpdateChatPinnedMessage(UpdateChatPinnedMessage),
+        #[cfg(feature = "bots")]
+        UpdateChatReplyMarkup(UpdateChatReplyMarkup),
+        #[cfg(feature = "messages")]
+        UpdateChatDraftMessage(Box<UpdateChatDraftMessage>),
+        #[cfg(feature = "chats")]
+        UpdateChatOnlineMemberCount(UpdateChatOnlineMemberCount),
+        UpdateNotification(Box<UpdateNotification>),
+        UpdateNotificationGroup(UpdateNotificationGroup),
+        UpdateActiveNotifications(UpdateActiveNotifications),
+        UpdateHavePendingNotifications(UpdateHavePendingNotifications),
+        #[cfg(feature = "messages")]
+        UpdateDeleteMessages(UpdateDeleteMessages),
+        #[cfg(feature = "chats")]
+        UpdateUserChatAction(UpdateUserChatAction),
+        UpdateUserStatus(UpdateUserStatus),
+        UpdateUser(Box<UpdateUser>),
+        #[cfg(feature = "chats")]
+        UpdateBasicGroup(UpdateBasicGroup),
+        #[cfg(feature = "chats")]
+        UpdateSupergroup(UpdateSupergroup),
+        #[cfg(feature = "chats")]
+        UpdateSecretChat(UpdateSecretChat),
+        UpdateUserFullInfo(UpdateUserFullInfo),
+        #[cfg(feature = "chats")]
+        UpdateBasicGroupFullInfo(UpdateBasicGroupFullInfo),
+        #[cfg(feature = "chats")]
+        UpdateSupergroupFullInfo(Box<UpdateSupergroupFullInfo>),
+        UpdateServiceNotification(Box<UpdateServiceNotification>),
+        #[cfg(feature = "files")]
+        UpdateFile(UpdateFile),
+        #[cfg(feature = "files")]
+        UpdateFileGenerationStart(UpdateFileGenerationStart),
+        #[cfg(feature = "files")]
+        UpdateFileGenerationStop(UpdateFileGenerationStop),
+        #[cfg(feature = "calls")]
+        UpdateCall(UpdateCall),
+        UpdateUserPrivacySettingRules(UpdateUserPrivacySettingRules),
+        #[cfg(feature = "messages")]
+        UpdateUnreadMessageCount(UpdateUnreadMessageCount),
+        #[cfg(feature = "chats")]
+        UpdateUnreadChatCount(UpdateUnreadChatCount),
+        UpdateOption(UpdateOption),
+        #[cfg(feature = "stickers")]
+        UpdateInstalledStickerSets(UpdateInstalledStickerSets),
+        #[cfg(feature = "stickers")]
+        UpdateTrendingStickerSets(UpdateTrendingStickerSets),
+        #[cfg(feature = "stickers")]
+        UpdateRecentStickers(UpdateRecentStickers),
+        #[cfg(feature = "stickers")]
+        UpdateFavoriteStickers(UpdateFavoriteStickers),
+        #[cfg(feature = "stickers")]
+        UpdateSavedAnimations(UpdateSavedAnimations),
+        UpdateSelectedBackground(Box<UpdateSelectedBackground>),
+        UpdateLanguagePackStrings(UpdateLanguagePackStrings),
+        UpdateConnectionState(UpdateConnectionState),
+        #[cfg(feature = "auth")]
+        UpdateTermsOfService(UpdateTermsOfService),
+        UpdateUsersNearby(UpdateUsersNearby),
+        #[cfg(feature = "bots")]
+        UpdateNewInlineQuery(UpdateNewInlineQuery),
+        #[cfg(feature = "bots")]
+        UpdateNewChosenInlineResult(UpdateNewChosenInlineResult),
+        #[cfg(feature = "bots")]
+        UpdateNewCallbackQuery(UpdateNewCallbackQuery),
+        #[cfg(feature = "bots")]
+        UpdateNewInlineCallbackQuery(UpdateNewInlineCallbackQuery),
+        #[cfg(feature = "payments")]
+        UpdateNewShippingQuery(Box<UpdateNewShippingQuery>),
+        #[cfg(feature = "payments")]
+        UpdateNewPreCheckoutQuery(Box<UpdateNewPreCheckoutQuery>),
+        #[cfg(feature = "bots")]
+        UpdateNewCustomEvent(UpdateNewCustomEvent),
+        #[cfg(feature = "bots")]
+        UpdateNewCustomQuery(UpdateNewCustomQuery),
+        #[cfg(feature = "messages")]
+        UpdatePoll(Box<UpdatePoll>),
+        #[cfg(feature = "messages")]
+        UpdatePollAnswer(UpdatePollAnswer),
+        #[serde(untagged)]
+        AuthenticationCodeType(AuthenticationCodeType),
+        #[serde(untagged)]
+        AuthorizationState(AuthorizationState),
+        #[serde(untagged)]
+        InputFile(InputFile),
+        #[serde(untagged)]
+        MaskPoint(MaskPoint),
+        #[serde(untagged)]
+        PollType(PollType),
+        #[serde(untagged)]
+        UserType(UserType),
+        #[cfg(feature = "chats")]
+        #[serde(untagged)]
+        ChatMemberStatus(ChatMemberStatus),
+        #[cfg(feature = "chats")]
+        #[serde(untagged)]
+        ChatMembersFilter(ChatMembersFilter),
+        #[cfg(feature = "chats")]
+        #[serde(untagged)]
+        SupergroupMembersFilter(SupergroupMembersFilter),
+        #[cfg(feature = "chats")]
+        #[serde(untagged)]
+        SecretChatState(SecretChatState),
+        #[cfg(any(
+            feature = "bots",
+            feature = "calls",
+            feature = "chats",
+            feature = "messages"
+        ))]
+        #[serde(untagged)]
+        MessageForwardOrigin(MessageForwardOrigin),
+        #[cfg(any(
+            feature = "bots",
+            feature = "calls",
+            feature = "chats",
+            feature = "messages"
+        ))]
+        #[serde(untagged)]
+        MessageSendingState(MessageSendingState),
+        #[serde(untagged)]
+        NotificationSettingsScope(NotificationSettingsScope),
+        #[serde(untagged)]
+        ChatType(ChatType),
+        #[cfg(any(feature = "chats", feature = "messages"))]
+        #[serde(untagged)]
+        ChatList(ChatList),
+        #[cfg(feature = "chats")]
+        #[serde(untagged)]
+        PublicChatType(PublicChatType),
+        #[cfg(feature = "chats")]
+        #[serde(untagged)]
+        ChatActionBar(ChatActionBar),
+        #[cfg(any(
+            feature = "bots",
+            feature = "calls",
+            feature = "chats",
+            feature = "messages"
+        ))]
+        #[serde(untagged)]
+        KeyboardButtonType(KeyboardButtonType),
+        #[cfg(any(
+            feature = "bots",
+            feature = "calls",
+            feature = "chats",
+            feature = "messages"
+        ))]
+        #[serde(untagged)]
+        InlineKeyboardButtonType(InlineKeyboardButtonType),
+        #[cfg(any(
+            feature = "bots",
+            feature = "calls",
+            feature = "chats",
+            feature = "messages"
+        ))]
+        #[serde(untagged)]
+        ReplyMarkup(ReplyMarkup),
+        #[cfg(feature = "bots")]
+        #[serde(untagged)]
+        LoginUrlInfo(LoginUrlInfo),
+        #[cfg(feature = "messages")]
+        #[serde(untagged)]
+        RichText(RichText),
+        #[cfg(feature = "messages")]
+        #[serde(untagged)]
+        PageBlockHorizontalAlignment(PageBlockHorizontalAlignment),
+        #[cfg(feature = "messages")]
+        #[serde(untagged)]
+        PageBlockVerticalAlignment(PageBlockVerticalAlignment),
+        #[cfg(feature = "messages")]
+        #[serde(untagged)]
+        PageBlock(PageBlock),
+        #[cfg(feature = "payments")]
+        #[serde(untagged)]
+        InputCredentials(InputCredentials),
+        #[serde(untagged)]
+        PassportElementType(PassportElementType),
+        #[cfg(feature = "passport")]
+        #[serde(untagged)]
+        PassportElement(PassportElement),
+        #[cfg(feature = "passport")]
+        #[serde(untagged)]
+        InputPassportElement(InputPassportElement),
+        #[cfg(feature = "passport")]
+        #[serde(untagged)]
+        PassportElementErrorSource(PassportElementErrorSource),
+        #[cfg(feature = "passport")]
+        #[serde(untagged)]
+        InputPassportElementErrorSource(InputPassportElementErrorSource),
+        #[serde(untagged)]
+        MessageContent(MessageContent),
+        #[serde(untagged)]
+        TextEntityType(TextEntityType),
+        #[cfg(any(
+            feature = "bots",
+            feature = "calls",
+            feature = "chats",
+            feature = "messages"
+        ))]
+        #[serde(untagged)]
+        MessageSchedulingState(MessageSchedulingState),
+        #[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+        #[serde(untagged)]
+        InputMessageContent(Box<InputMessageContent>),
+        #[cfg(feature = "messages")]
+        #[serde(untagged)]
+        SearchMessagesFilter(SearchMessagesFilter),
+        #[cfg(feature = "chats")]
+        #[serde(untagged)]
+        ChatAction(ChatAction),
+        #[serde(untagged)]
+        UserStatus(UserStatus),
+        #[serde(untagged)]
+        CallDiscardReason(CallDiscardReason),
+        #[cfg(feature = "calls")]
+        #[serde(untagged)]
+        CallState(CallState),
+        #[cfg(feature = "calls")]
+        #[serde(untagged)]
+        CallProblem(CallProblem),
+        #[cfg(feature = "bots")]
+        #[serde(untagged)]
+        InputInlineQueryResult(InputInlineQueryResult),
+        #[cfg(feature = "bots")]
+        #[serde(untagged)]
+        InlineQueryResult(InlineQueryResult),
+        #[cfg(feature = "bots")]
+        #[serde(untagged)]
+        CallbackQueryPayload(CallbackQueryPayload),
+        #[cfg(feature = "chats")]
+        #[serde(untagged)]
+        ChatEventAction(ChatEventAction),
+        #[serde(untagged)]
+        LanguagePackStringValue(LanguagePackStringValue),
+        #[serde(untagged)]
+        DeviceToken(DeviceToken),
+        #[serde(untagged)]
+        BackgroundFill(BackgroundFill),
+        #[serde(untagged)]
+        BackgroundType(BackgroundType),
+        #[serde(untagged)]
+        InputBackground(InputBackground),
+        #[serde(untagged)]
+        CanTransferOwnershipResult(CanTransferOwnershipResult),
+        #[cfg(feature = "chats")]
+        #[serde(untagged)]
+        CheckChatUsernameResult(CheckChatUsernameResult),
+        #[cfg(feature = "messages")]
+        #[serde(untagged)]
+        PushMessageContent(PushMessageContent),
+        #[serde(untagged)]
+        NotificationType(NotificationType),
+        #[serde(untagged)]
+        NotificationGroupType(NotificationGroupType),
+        #[serde(untagged)]
+        OptionValue(OptionValue),
+        #[serde(untagged)]
+        JsonValue(JsonValue),
+        #[serde(untagged)]
+        UserPrivacySettingRule(UserPrivacySettingRule),
+        #[serde(untagged)]
+        UserPrivacySetting(UserPrivacySetting),
+        #[cfg(feature = "chats")]
+        #[serde(untagged)]
+        ChatReportReason(ChatReportReason),
+        #[cfg(feature = "files")]
+        #[serde(untagged)]
+        FileType(FileType),
+        #[serde(untagged)]
+        NetworkType(NetworkType),
+        #[cfg(feature = "files")]
+        #[serde(untagged)]
+        NetworkStatisticsEntry(NetworkStatisticsEntry),
+        #[serde(untagged)]
+        ConnectionState(ConnectionState),
+        #[cfg(feature = "chats")]
+        #[serde(untagged)]
+        TopChatCategory(TopChatCategory),
+        #[serde(untagged)]
+        TMeUrlType(TMeUrlType),
+        #[cfg(feature = "messages")]
+        #[serde(untagged)]
+        TextParseMode(TextParseMode),
+        #[cfg(feature = "proxy")]
+        #[serde(untagged)]
+        ProxyType(ProxyType),
+        #[cfg(feature = "logging")]
+        #[serde(untagged)]
+        LogStream(LogStream),
+        #[serde(untagged)]
+        Unknown(Unknown),
+    }
+    impl Response {
+        #[doc = "Returns the @type of the object"]
+        pub fn type_name(&self) -> &str {
+            match self {
+                Response::Error(object) => object.type_name(),
+                Response::Ok(object) => object.type_name(),
+                Response::TdlibParameters(object) => object.type_name(),
+                Response::AuthenticationCodeInfo(object) => object.type_name(),
+                #[cfg(any(feature = "auth", feature = "passport"))]
+                Response::EmailAddressAuthenticationCodeInfo(object) => object.type_name(),
+                Response::TextEntity(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::TextEntities(object) => object.type_name(),
+                Response::FormattedText(object) => object.type_name(),
+                Response::TermsOfService(object) => object.type_name(),
+                #[cfg(feature = "auth")]
+                Response::PasswordState(object) => object.type_name(),
+                #[cfg(feature = "auth")]
+                Response::RecoveryEmailAddress(object) => object.type_name(),
+                #[cfg(feature = "auth")]
+                Response::TemporaryPasswordState(object) => object.type_name(),
+                Response::LocalFile(object) => object.type_name(),
+                Response::RemoteFile(object) => object.type_name(),
+                Response::File(object) => object.type_name(),
+                Response::PhotoSize(object) => object.type_name(),
+                Response::Minithumbnail(object) => object.type_name(),
+                Response::MaskPosition(object) => object.type_name(),
+                Response::PollOption(object) => object.type_name(),
+                Response::Animation(object) => object.type_name(),
+                Response::Audio(object) => object.type_name(),
+                Response::Document(object) => object.type_name(),
+                Response::Photo(object) => object.type_name(),
+                Response::Sticker(object) => object.type_name(),
+                Response::Video(object) => object.type_name(),
+                Response::VideoNote(object) => object.type_name(),
+                Response::VoiceNote(object) => object.type_name(),
+                Response::Contact(object) => object.type_name(),
+                Response::Location(object) => object.type_name(),
+                Response::Venue(object) => object.type_name(),
+                Response::Game(object) => object.type_name(),
+                Response::Poll(object) => object.type_name(),
+                Response::ProfilePhoto(object) => object.type_name(),
+                Response::ChatPhoto(object) => object.type_name(),
+                Response::BotCommand(object) => object.type_name(),
+                Response::BotInfo(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatLocation(object) => object.type_name(),
+                Response::User(object) => object.type_name(),
+                Response::UserFullInfo(object) => object.type_name(),
+                Response::UserProfilePhoto(object) => object.type_name(),
+                Response::UserProfilePhotos(object) => object.type_name(),
+                Response::Users(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatAdministrator(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatAdministrators(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatPermissions(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatMember(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatMembers(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::BasicGroup(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::BasicGroupFullInfo(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::Supergroup(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::SupergroupFullInfo(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::SecretChat(object) => object.type_name(),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                Response::MessageForwardInfo(object) => object.type_name(),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                Response::Message(object) => object.type_name(),
+                #[cfg(any(feature = "calls", feature = "messages"))]
+                Response::Messages(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::FoundMessages(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatNotificationSettings(object) => object.type_name(),
+                Response::ScopeNotificationSettings(object) => object.type_name(),
+                #[cfg(any(feature = "chats", feature = "messages"))]
+                Response::DraftMessage(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::Chat(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::Chats(object) => object.type_name(),
+                Response::ChatNearby(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatsNearby(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatInviteLink(object) => object.type_name(),
+                Response::ChatInviteLinkInfo(object) => object.type_name(),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                Response::KeyboardButton(object) => object.type_name(),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                Response::InlineKeyboardButton(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::PageBlockCaption(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::PageBlockListItem(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::PageBlockTableCell(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::PageBlockRelatedArticle(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::WebPageInstantView(object) => object.type_name(),
+                Response::WebPage(object) => object.type_name(),
+                Response::Address(object) => object.type_name(),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "chats",
+                    feature = "messages",
+                    feature = "payments"
+                ))]
+                Response::LabeledPricePart(object) => object.type_name(),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "chats",
+                    feature = "messages",
+                    feature = "payments"
+                ))]
+                Response::Invoice(object) => object.type_name(),
+                Response::OrderInfo(object) => object.type_name(),
+                #[cfg(feature = "payments")]
+                Response::ShippingOption(object) => object.type_name(),
+                #[cfg(feature = "payments")]
+                Response::SavedCredentials(object) => object.type_name(),
+                #[cfg(feature = "payments")]
+                Response::PaymentsProviderStripe(object) => object.type_name(),
+                #[cfg(feature = "payments")]
+                Response::PaymentForm(object) => object.type_name(),
+                #[cfg(feature = "payments")]
+                Response::ValidatedOrderInfo(object) => object.type_name(),
+                #[cfg(feature = "payments")]
+                Response::PaymentResult(object) => object.type_name(),
+                #[cfg(feature = "payments")]
+                Response::PaymentReceipt(object) => object.type_name(),
+                Response::DatedFile(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::Date(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::PersonalDetails(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::IdentityDocument(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::InputIdentityDocument(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::PersonalDocument(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::InputPersonalDocument(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::PassportElements(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::PassportElementError(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::PassportSuitableElement(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::PassportRequiredElement(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::PassportAuthorizationForm(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::PassportElementsWithErrors(object) => object.type_name(),
+                Response::EncryptedCredentials(object) => object.type_name(),
+                Response::EncryptedPassportElement(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::InputPassportElementError(object) => object.type_name(),
+                Response::InputThumbnail(object) => object.type_name(),
+                #[cfg(any(feature = "bots", feature = "messages"))]
+                Response::SendMessageOptions(object) => object.type_name(),
+                #[cfg(feature = "stickers")]
+                Response::Stickers(object) => object.type_name(),
+                #[cfg(feature = "stickers")]
+                Response::Emojis(object) => object.type_name(),
+                #[cfg(feature = "stickers")]
+                Response::StickerSet(object) => object.type_name(),
+                #[cfg(feature = "stickers")]
+                Response::StickerSetInfo(object) => object.type_name(),
+                #[cfg(feature = "stickers")]
+                Response::StickerSets(object) => object.type_name(),
+                #[cfg(feature = "calls")]
+                Response::CallProtocol(object) => object.type_name(),
+                #[cfg(feature = "calls")]
+                Response::CallConnection(object) => object.type_name(),
+                #[cfg(feature = "calls")]
+                Response::CallId(object) => object.type_name(),
+                #[cfg(feature = "calls")]
+                Response::Call(object) => object.type_name(),
+                #[cfg(any(feature = "auth", feature = "passport"))]
+                Response::PhoneNumberAuthenticationSettings(object) => object.type_name(),
+                Response::Animations(object) => object.type_name(),
+                Response::ImportedContacts(object) => object.type_name(),
+                Response::HttpUrl(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::InlineQueryResults(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::CallbackQueryAnswer(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::CustomRequestResult(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::GameHighScore(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::GameHighScores(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatEvent(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatEvents(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatEventLogFilters(object) => object.type_name(),
+                Response::LanguagePackString(object) => object.type_name(),
+                Response::LanguagePackStrings(object) => object.type_name(),
+                Response::LanguagePackInfo(object) => object.type_name(),
+                Response::LocalizationTargetInfo(object) => object.type_name(),
+                Response::PushReceiverId(object) => object.type_name(),
+                Response::Background(object) => object.type_name(),
+                Response::Backgrounds(object) => object.type_name(),
+                Response::Hashtags(object) => object.type_name(),
+                Response::Notification(object) => object.type_name(),
+                Response::NotificationGroup(object) => object.type_name(),
+                Response::JsonObjectMember(object) => object.type_name(),
+                Response::UserPrivacySettingRules(object) => object.type_name(),
+                #[cfg(feature = "auth")]
+                Response::AccountTtl(object) => object.type_name(),
+                #[cfg(feature = "auth")]
+                Response::Session(object) => object.type_name(),
+                #[cfg(feature = "auth")]
+                Response::Sessions(object) => object.type_name(),
+                #[cfg(feature = "auth")]
+                Response::ConnectedWebsite(object) => object.type_name(),
+                #[cfg(feature = "auth")]
+                Response::ConnectedWebsites(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::PublicMessageLink(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::MessageLinkInfo(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::FilePart(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::StorageStatisticsByFileType(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::StorageStatisticsByChat(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::StorageStatistics(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::StorageStatisticsFast(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::DatabaseStatistics(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::NetworkStatistics(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::AutoDownloadSettings(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::AutoDownloadSettingsPresets(object) => object.type_name(),
+                Response::TMeUrl(object) => object.type_name(),
+                Response::TMeUrls(object) => object.type_name(),
+                Response::Count(object) => object.type_name(),
+                Response::Text(object) => object.type_name(),
+                Response::Seconds(object) => object.type_name(),
+                Response::DeepLinkInfo(object) => object.type_name(),
+                #[cfg(feature = "proxy")]
+                Response::Proxy(object) => object.type_name(),
+                #[cfg(feature = "proxy")]
+                Response::Proxies(object) => object.type_name(),
+                #[cfg(feature = "stickers")]
+                Response::InputSticker(object) => object.type_name(),
+                Response::Updates(object) => object.type_name(),
+                #[cfg(feature = "logging")]
+                Response::LogVerbosityLevel(object) => object.type_name(),
+                #[cfg(feature = "logging")]
+                Response::LogTags(object) => object.type_name(),
+                #[cfg(feature = "tests")]
+                Response::TestInt(object) => object.type_name(),
+                #[cfg(feature = "tests")]
+                Response::TestString(object) => object.type_name(),
+                #[cfg(feature = "tests")]
+                Response::TestBytes(object) => object.type_name(),
+                #[cfg(feature = "tests")]
+                Response::TestVectorInt(object) => object.type_name(),
+                #[cfg(feature = "tests")]
+                Response::TestVectorIntObject(object) => object.type_name(),
+                #[cfg(feature = "tests")]
+                Response::TestVectorString(object) => object.type_name(),
+                #[cfg(feature = "tests")]
+                Response::TestVectorStringObject(object) => object.type_name(),
+                Response::UpdateAuthorizationState(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateNewMessage(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageSendAcknowledged(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageSendSucceeded(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageSendFailed(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageContent(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageEdited(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageViews(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageContentOpened(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageMentionRead(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageLiveLocationViewed(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateNewChat(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatChatList(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatTitle(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatPhoto(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatPermissions(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateChatLastMessage(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatOrder(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatIsPinned(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatIsMarkedAsUnread(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatIsSponsored(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateChatHasScheduledMessages(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatDefaultDisableNotification(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatReadInbox(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatReadOutbox(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatUnreadMentionCount(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatNotificationSettings(object) => object.type_name(),
+                Response::UpdateScopeNotificationSettings(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatActionBar(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateChatPinnedMessage(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::UpdateChatReplyMarkup(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateChatDraftMessage(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatOnlineMemberCount(object) => object.type_name(),
+                Response::UpdateNotification(object) => object.type_name(),
+                Response::UpdateNotificationGroup(object) => object.type_name(),
+                Response::UpdateActiveNotifications(object) => object.type_name(),
+                Response::UpdateHavePendingNotifications(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateDeleteMessages(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateUserChatAction(object) => object.type_name(),
+                Response::UpdateUserStatus(object) => object.type_name(),
+                Response::UpdateUser(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateBasicGroup(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateSupergroup(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateSecretChat(object) => object.type_name(),
+                Response::UpdateUserFullInfo(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateBasicGroupFullInfo(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateSupergroupFullInfo(object) => object.type_name(),
+                Response::UpdateServiceNotification(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::UpdateFile(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::UpdateFileGenerationStart(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::UpdateFileGenerationStop(object) => object.type_name(),
+                #[cfg(feature = "calls")]
+                Response::UpdateCall(object) => object.type_name(),
+                Response::UpdateUserPrivacySettingRules(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdateUnreadMessageCount(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::UpdateUnreadChatCount(object) => object.type_name(),
+                Response::UpdateOption(object) => object.type_name(),
+                #[cfg(feature = "stickers")]
+                Response::UpdateInstalledStickerSets(object) => object.type_name(),
+                #[cfg(feature = "stickers")]
+                Response::UpdateTrendingStickerSets(object) => object.type_name(),
+                #[cfg(feature = "stickers")]
+                Response::UpdateRecentStickers(object) => object.type_name(),
+                #[cfg(feature = "stickers")]
+                Response::UpdateFavoriteStickers(object) => object.type_name(),
+                #[cfg(feature = "stickers")]
+                Response::UpdateSavedAnimations(object) => object.type_name(),
+                Response::UpdateSelectedBackground(object) => object.type_name(),
+                Response::UpdateLanguagePackStrings(object) => object.type_name(),
+                Response::UpdateConnectionState(object) => object.type_name(),
+                #[cfg(feature = "auth")]
+                Response::UpdateTermsOfService(object) => object.type_name(),
+                Response::UpdateUsersNearby(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::UpdateNewInlineQuery(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::UpdateNewChosenInlineResult(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::UpdateNewCallbackQuery(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::UpdateNewInlineCallbackQuery(object) => object.type_name(),
+                #[cfg(feature = "payments")]
+                Response::UpdateNewShippingQuery(object) => object.type_name(),
+                #[cfg(feature = "payments")]
+                Response::UpdateNewPreCheckoutQuery(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::UpdateNewCustomEvent(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::UpdateNewCustomQuery(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdatePoll(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::UpdatePollAnswer(object) => object.type_name(),
+                Response::AuthenticationCodeType(object) => object.type_name(),
+                Response::AuthorizationState(object) => object.type_name(),
+                Response::InputFile(object) => object.type_name(),
+                Response::MaskPoint(object) => object.type_name(),
+                Response::PollType(object) => object.type_name(),
+                Response::UserType(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatMemberStatus(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatMembersFilter(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::SupergroupMembersFilter(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::SecretChatState(object) => object.type_name(),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                Response::MessageForwardOrigin(object) => object.type_name(),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                Response::MessageSendingState(object) => object.type_name(),
+                Response::NotificationSettingsScope(object) => object.type_name(),
+                Response::ChatType(object) => object.type_name(),
+                #[cfg(any(feature = "chats", feature = "messages"))]
+                Response::ChatList(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::PublicChatType(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatActionBar(object) => object.type_name(),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                Response::KeyboardButtonType(object) => object.type_name(),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                Response::InlineKeyboardButtonType(object) => object.type_name(),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                Response::ReplyMarkup(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::LoginUrlInfo(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::RichText(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::PageBlockHorizontalAlignment(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::PageBlockVerticalAlignment(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::PageBlock(object) => object.type_name(),
+                #[cfg(feature = "payments")]
+                Response::InputCredentials(object) => object.type_name(),
+                Response::PassportElementType(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::PassportElement(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::InputPassportElement(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::PassportElementErrorSource(object) => object.type_name(),
+                #[cfg(feature = "passport")]
+                Response::InputPassportElementErrorSource(object) => object.type_name(),
+                Response::MessageContent(object) => object.type_name(),
+                Response::TextEntityType(object) => object.type_name(),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                Response::MessageSchedulingState(object) => object.type_name(),
+                #[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+                Response::InputMessageContent(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::SearchMessagesFilter(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatAction(object) => object.type_name(),
+                Response::UserStatus(object) => object.type_name(),
+                Response::CallDiscardReason(object) => object.type_name(),
+                #[cfg(feature = "calls")]
+                Response::CallState(object) => object.type_name(),
+                #[cfg(feature = "calls")]
+                Response::CallProblem(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::InputInlineQueryResult(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::InlineQueryResult(object) => object.type_name(),
+                #[cfg(feature = "bots")]
+                Response::CallbackQueryPayload(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatEventAction(object) => object.type_name(),
+                Response::LanguagePackStringValue(object) => object.type_name(),
+                Response::DeviceToken(object) => object.type_name(),
+                Response::BackgroundFill(object) => object.type_name(),
+                Response::BackgroundType(object) => object.type_name(),
+                Response::InputBackground(object) => object.type_name(),
+                Response::CanTransferOwnershipResult(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::CheckChatUsernameResult(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::PushMessageContent(object) => object.type_name(),
+                Response::NotificationType(object) => object.type_name(),
+                Response::NotificationGroupType(object) => object.type_name(),
+                Response::OptionValue(object) => object.type_name(),
+                Response::JsonValue(object) => object.type_name(),
+                Response::UserPrivacySettingRule(object) => object.type_name(),
+                Response::UserPrivacySetting(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::ChatReportReason(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::FileType(object) => object.type_name(),
+                Response::NetworkType(object) => object.type_name(),
+                #[cfg(feature = "files")]
+                Response::NetworkStatisticsEntry(object) => object.type_name(),
+                Response::ConnectionState(object) => object.type_name(),
+                #[cfg(feature = "chats")]
+                Response::TopChatCategory(object) => object.type_name(),
+                Response::TMeUrlType(object) => object.type_name(),
+                #[cfg(feature = "messages")]
+                Response::TextParseMode(object) => object.type_name(),
+                #[cfg(feature = "proxy")]
+                Response::ProxyType(object) => object.type_name(),
+                #[cfg(feature = "logging")]
+                Response::LogStream(object) => object.type_name(),
+                Response::Unknown(object) => &object.type_,
+            }
+        }
+        #[doc = "Returns the name of the variant that holds objects with the given @type, or None if such objects are kept as Unknown"]
+        pub fn variant_of(type_: &str) -> Option<&'static str> {
+            Some(match type_ {
+                "error" => "Error",
+                "ok" => "Ok",
+                "tdlibParameters" => "TdlibParameters",
+                "authenticationCodeInfo" => "AuthenticationCodeInfo",
+                #[cfg(any(feature = "auth", feature = "passport"))]
+                "emailAddressAuthenticationCodeInfo" => "EmailAddressAuthenticationCodeInfo",
+                "textEntity" => "TextEntity",
+                #[cfg(feature = "messages")]
+                "textEntities" => "TextEntities",
+                "formattedText" => "FormattedText",
+                "termsOfService" => "TermsOfService",
+                #[cfg(feature = "auth")]
+                "passwordState" => "PasswordState",
+                #[cfg(feature = "auth")]
+                "recoveryEmailAddress" => "RecoveryEmailAddress",
+                #[cfg(feature = "auth")]
+                "temporaryPasswordState" => "TemporaryPasswordState",
+                "localFile" => "LocalFile",
+                "remoteFile" => "RemoteFile",
+                "file" => "File",
+                "photoSize" => "PhotoSize",
+                "minithumbnail" => "Minithumbnail",
+                "maskPosition" => "MaskPosition",
+                "pollOption" => "PollOption",
+                "animation" => "Animation",
+                "audio" => "Audio",
+                "document" => "Document",
+                "photo" => "Photo",
+                "sticker" => "Sticker",
+                "video" => "Video",
+                "videoNote" => "VideoNote",
+                "voiceNote" => "VoiceNote",
+                "contact" => "Contact",
+                "location" => "Location",
+                "venue" => "Venue",
+                "game" => "Game",
+                "poll" => "Poll",
+                "profilePhoto" => "ProfilePhoto",
+                "chatPhoto" => "ChatPhoto",
+                "botCommand" => "BotCommand",
+                "botInfo" => "BotInfo",
+                #[cfg(feature = "chats")]
+                "chatLocation" => "ChatLocation",
+                "user" => "User",
+                "userFullInfo" => "UserFullInfo",
+                "userProfilePhoto" => "UserProfilePhoto",
+                "userProfilePhotos" => "UserProfilePhotos",
+                "users" => "Users",
+                #[cfg(feature = "chats")]
+                "chatAdministrator" => "ChatAdministrator",
+                #[cfg(feature = "chats")]
+                "chatAdministrators" => "ChatAdministrators",
+                #[cfg(feature = "chats")]
+                "chatPermissions" => "ChatPermissions",
+                #[cfg(feature = "chats")]
+                "chatMember" => "ChatMember",
+                #[cfg(feature = "chats")]
+                "chatMembers" => "ChatMembers",
+                #[cfg(feature = "chats")]
+                "basicGroup" => "BasicGroup",
+                #[cfg(feature = "chats")]
+                "basicGroupFullInfo" => "BasicGroupFullInfo",
+                #[cfg(feature = "chats")]
+                "supergroup" => "Supergroup",
+                #[cfg(feature = "chats")]
+                "supergroupFullInfo" => "SupergroupFullInfo",
+                #[cfg(feature = "chats")]
+                "secretChat" => "SecretChat",
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "messageForwardInfo" => "MessageForwardInfo",
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "message" => "Message",
+                #[cfg(any(feature = "calls", feature = "messages"))]
+                "messages" => "Messages",
+                #[cfg(feature = "messages")]
+                "foundMessages" => "FoundMessages",
+                #[cfg(feature = "chats")]
+                "chatNotificationSettings" => "ChatNotificationSettings",
+                "scopeNotificationSettings" => "ScopeNotificationSettings",
+                #[cfg(any(feature = "chats", feature = "messages"))]
+                "draftMessage" => "DraftMessage",
+                #[cfg(feature = "chats")]
+                "chat" => "Chat",
+                #[cfg(feature = "chats")]
+                "chats" => "Chats",
+                "chatNearby" => "ChatNearby",
+                #[cfg(feature = "chats")]
+                "chatsNearby" => "ChatsNearby",
+                #[cfg(feature = "chats")]
+                "chatInviteLink" => "ChatInviteLink",
+                "chatInviteLinkInfo" => "ChatInviteLinkInfo",
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "keyboardButton" => "KeyboardButton",
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "inlineKeyboardButton" => "InlineKeyboardButton",
+                #[cfg(feature = "messages")]
+                "pageBlockCaption" => "PageBlockCaption",
+                #[cfg(feature = "messages")]
+                "pageBlockListItem" => "PageBlockListItem",
+                #[cfg(feature = "messages")]
+                "pageBlockTableCell" => "PageBlockTableCell",
+                #[cfg(feature = "messages")]
+                "pageBlockRelatedArticle" => "PageBlockRelatedArticle",
+                #[cfg(feature = "messages")]
+                "webPageInstantView" => "WebPageInstantView",
+                "webPage" => "WebPage",
+                "address" => "Address",
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "chats",
+                    feature = "messages",
+                    feature = "payments"
+                ))]
+                "labeledPricePart" => "LabeledPricePart",
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "chats",
+                    feature = "messages",
+                    feature = "payments"
+                ))]
+                "invoice" => "Invoice",
+                "orderInfo" => "OrderInfo",
+                #[cfg(feature = "payments")]
+                "shippingOption" => "ShippingOption",
+                #[cfg(feature = "payments")]
+                "savedCredentials" => "SavedCredentials",
+                #[cfg(feature = "payments")]
+                "paymentsProviderStripe" => "PaymentsProviderStripe",
+                #[cfg(feature = "payments")]
+                "paymentForm" => "PaymentForm",
+                #[cfg(feature = "payments")]
+                "validatedOrderInfo" => "ValidatedOrderInfo",
+                #[cfg(feature = "payments")]
+                "paymentResult" => "PaymentResult",
+                #[cfg(feature = "payments")]
+                "paymentReceipt" => "PaymentReceipt",
+                "datedFile" => "DatedFile",
+                #[cfg(feature = "passport")]
+                "date" => "Date",
+                #[cfg(feature = "passport")]
+                "personalDetails" => "PersonalDetails",
+                #[cfg(feature = "passport")]
+                "identityDocument" => "IdentityDocument",
+                #[cfg(feature = "passport")]
+                "inputIdentityDocument" => "InputIdentityDocument",
+                #[cfg(feature = "passport")]
+                "personalDocument" => "PersonalDocument",
+                #[cfg(feature = "passport")]
+                "inputPersonalDocument" => "InputPersonalDocument",
+                #[cfg(feature = "passport")]
+                "passportElements" => "PassportElements",
+                #[cfg(feature = "passport")]
+                "passportElementError" => "PassportElementError",
+                #[cfg(feature = "passport")]
+                "passportSuitableElement" => "PassportSuitableElement",
+                #[cfg(feature = "passport")]
+                "passportRequiredElement" => "PassportRequiredElement",
+                #[cfg(feature = "passport")]
+                "passportAuthorizationForm" => "PassportAuthorizationForm",
+                #[cfg(feature = "passport")]
+                "passportElementsWithErrors" => "PassportElementsWithErrors",
+                "encryptedCredentials" => "EncryptedCredentials",
+                "encryptedPassportElement" => "EncryptedPassportElement",
+                #[cfg(feature = "passport")]
+                "inputPassportElementError" => "InputPassportElementError",
+                "inputThumbnail" => "InputThumbnail",
+                #[cfg(any(feature = "bots", feature = "messages"))]
+                "sendMessageOptions" => "SendMessageOptions",
+                #[cfg(feature = "stickers")]
+                "stickers" => "Stickers",
+                #[cfg(feature = "stickers")]
+                "emojis" => "Emojis",
+                #[cfg(feature = "stickers")]
+                "stickerSet" => "StickerSet",
+                #[cfg(feature = "stickers")]
+                "stickerSetInfo" => "StickerSetInfo",
+                #[cfg(feature = "stickers")]
+                "stickerSets" => "StickerSets",
+                #[cfg(feature = "calls")]
+                "callProtocol" => "CallProtocol",
+                #[cfg(feature = "calls")]
+                "callConnection" => "CallConnection",
+                #[cfg(feature = "calls")]
+                "callId" => "CallId",
+                #[cfg(feature = "calls")]
+                "call" => "Call",
+                #[cfg(any(feature = "auth", feature = "passport"))]
+                "phoneNumberAuthenticationSettings" => "PhoneNumberAuthenticationSettings",
+                "animations" => "Animations",
+                "importedContacts" => "ImportedContacts",
+                "httpUrl" => "HttpUrl",
+                #[cfg(feature = "bots")]
+                "inlineQueryResults" => "InlineQueryResults",
+                #[cfg(feature = "bots")]
+                "callbackQueryAnswer" => "CallbackQueryAnswer",
+                #[cfg(feature = "bots")]
+                "customRequestResult" => "CustomRequestResult",
+                #[cfg(feature = "bots")]
+                "gameHighScore" => "GameHighScore",
+                #[cfg(feature = "bots")]
+                "gameHighScores" => "GameHighScores",
+                #[cfg(feature = "chats")]
+                "chatEvent" => "ChatEvent",
+                #[cfg(feature = "chats")]
+                "chatEvents" => "ChatEvents",
+                #[cfg(feature = "chats")]
+                "chatEventLogFilters" => "ChatEventLogFilters",
+                "languagePackString" => "LanguagePackString",
+                "languagePackStrings" => "LanguagePackStrings",
+                "languagePackInfo" => "LanguagePackInfo",
+                "localizationTargetInfo" => "LocalizationTargetInfo",
+                "pushReceiverId" => "PushReceiverId",
+                "background" => "Background",
+                "backgrounds" => "Backgrounds",
+                "hashtags" => "Hashtags",
+                "notification" => "Notification",
+                "notificationGroup" => "NotificationGroup",
+                "jsonObjectMember" => "JsonObjectMember",
+                "userPrivacySettingRules" => "UserPrivacySettingRules",
+                #[cfg(feature = "auth")]
+                "accountTtl" => "AccountTtl",
+                #[cfg(feature = "auth")]
+                "session" => "Session",
+                #[cfg(feature = "auth")]
+                "sessions" => "Sessions",
+                #[cfg(feature = "auth")]
+                "connectedWebsite" => "ConnectedWebsite",
+                #[cfg(feature = "auth")]
+                "connectedWebsites" => "ConnectedWebsites",
+                #[cfg(feature = "messages")]
+                "publicMessageLink" => "PublicMessageLink",
+                #[cfg(feature = "messages")]
+                "messageLinkInfo" => "MessageLinkInfo",
+                #[cfg(feature = "files")]
+                "filePart" => "FilePart",
+                #[cfg(feature = "files")]
+                "storageStatisticsByFileType" => "StorageStatisticsByFileType",
+                #[cfg(feature = "files")]
+                "storageStatisticsByChat" => "StorageStatisticsByChat",
+                #[cfg(feature = "files")]
+                "storageStatistics" => "StorageStatistics",
+                #[cfg(feature = "files")]
+                "storageStatisticsFast" => "StorageStatisticsFast",
+                #[cfg(feature = "files")]
+                "databaseStatistics" => "DatabaseStatistics",
+                #[cfg(feature = "files")]
+                "networkStatistics" => "NetworkStatistics",
+                #[cfg(feature = "files")]
+                "autoDownloadSettings" => "AutoDownloadSettings",
+                #[cfg(feature = "files")]
+                "autoDownloadSettingsPresets" => "AutoDownloadSettingsPresets",
+                "tMeUrl" => "TMeUrl",
+                "tMeUrls" => "TMeUrls",
+                "count" => "Count",
+                "text" => "Text",
+                "seconds" => "Seconds",
+                "deepLinkInfo" => "DeepLinkInfo",
+                #[cfg(feature = "proxy")]
+                "proxy" => "Proxy",
+                #[cfg(feature = "proxy")]
+                "proxies" => "Proxies",
+                #[cfg(feature = "stickers")]
+                "inputSticker" => "InputSticker",
+                "updates" => "Updates",
+                #[cfg(feature = "logging")]
+                "logVerbosityLevel" => "LogVerbosityLevel",
+                #[cfg(feature = "logging")]
+                "logTags" => "LogTags",
+                #[cfg(feature = "tests")]
+                "testInt" => "TestInt",
+                #[cfg(feature = "tests")]
+                "testString" => "TestString",
+                #[cfg(feature = "tests")]
+                "testBytes" => "TestBytes",
+                #[cfg(feature = "tests")]
+                "testVectorInt" => "TestVectorInt",
+                #[cfg(feature = "tests")]
+                "testVectorIntObject" => "TestVectorIntObject",
+                #[cfg(feature = "tests")]
+                "testVectorString" => "TestVectorString",
+                #[cfg(feature = "tests")]
+                "testVectorStringObject" => "TestVectorStringObject",
+                "updateAuthorizationState" => "UpdateAuthorizationState",
+                #[cfg(feature = "messages")]
+                "updateNewMessage" => "UpdateNewMessage",
+                #[cfg(feature = "messages")]
+                "updateMessageSendAcknowledged" => "UpdateMessageSendAcknowledged",
+                #[cfg(feature = "messages")]
+                "updateMessageSendSucceeded" => "UpdateMessageSendSucceeded",
+                #[cfg(feature = "messages")]
+                "updateMessageSendFailed" => "UpdateMessageSendFailed",
+                #[cfg(feature = "messages")]
+                "updateMessageContent" => "UpdateMessageContent",
+                #[cfg(feature = "messages")]
+                "updateMessageEdited" => "UpdateMessageEdited",
+                #[cfg(feature = "messages")]
+                "updateMessageViews" => "UpdateMessageViews",
+                #[cfg(feature = "messages")]
+                "updateMessageContentOpened" => "UpdateMessageContentOpened",
+                #[cfg(feature = "messages")]
+                "updateMessageMentionRead" => "UpdateMessageMentionRead",
+                #[cfg(feature = "messages")]
+                "updateMessageLiveLocationViewed" => "UpdateMessageLiveLocationViewed",
+                #[cfg(feature = "chats")]
+                "updateNewChat" => "UpdateNewChat",
+                #[cfg(feature = "chats")]
+                "updateChatChatList" => "UpdateChatChatList",
+                #[cfg(feature = "chats")]
+                "updateChatTitle" => "UpdateChatTitle",
+                #[cfg(feature = "chats")]
+                "updateChatPhoto" => "UpdateChatPhoto",
+                #[cfg(feature = "chats")]
+                "updateChatPermissions" => "UpdateChatPermissions",
+                #[cfg(feature = "messages")]
+                "updateChatLastMessage" => "UpdateChatLastMessage",
+                #[cfg(feature = "chats")]
+                "updateChatOrder" => "UpdateChatOrder",
+                #[cfg(feature = "chats")]
+                "updateChatIsPinned" => "UpdateChatIsPinned",
+                #[cfg(feature = "chats")]
+                "updateChatIsMarkedAsUnread" => "UpdateChatIsMarkedAsUnread",
+                #[cfg(feature = "chats")]
+                "updateChatIsSponsored" => "UpdateChatIsSponsored",
+                #[cfg(feature = "messages")]
+                "updateChatHasScheduledMessages" => "UpdateChatHasScheduledMessages",
+                #[cfg(feature = "chats")]
+                "updateChatDefaultDisableNotification" => "UpdateChatDefaultDisableNotification",
+                #[cfg(feature = "chats")]
+                "updateChatReadInbox" => "UpdateChatReadInbox",
+                #[cfg(feature = "chats")]
+                "updateChatReadOutbox" => "UpdateChatReadOutbox",
+                #[cfg(feature = "chats")]
+                "updateChatUnreadMentionCount" => "UpdateChatUnreadMentionCount",
+                #[cfg(feature = "chats")]
+                "updateChatNotificationSettings" => "UpdateChatNotificationSettings",
+                "updateScopeNotificationSettings" => "UpdateScopeNotificationSettings",
+                #[cfg(feature = "chats")]
+                "updateChatActionBar" => "UpdateChatActionBar",
+                #[cfg(feature = "messages")]
+                "updateChatPinnedMessage" => "UpdateChatPinnedMessage",
+                #[cfg(feature = "bots")]
+                "updateChatReplyMarkup" => "UpdateChatReplyMarkup",
+                #[cfg(feature = "messages")]
+                "updateChatDraftMessage" => "UpdateChatDraftMessage",
+                #[cfg(feature = "chats")]
+                "updateChatOnlineMemberCount" => "UpdateChatOnlineMemberCount",
+                "updateNotification" => "UpdateNotification",
+                "updateNotificationGroup" => "UpdateNotificationGroup",
+                "updateActiveNotifications" => "UpdateActiveNotifications",
+                "updateHavePendingNotifications" => "UpdateHavePendingNotifications",
+                #[cfg(feature = "messages")]
+                "updateDeleteMessages" => "UpdateDeleteMessages",
+                #[cfg(feature = "chats")]
+                "updateUserChatAction" => "UpdateUserChatAction",
+                "updateUserStatus" => "UpdateUserStatus",
+                "updateUser" => "UpdateUser",
+                #[cfg(feature = "chats")]
+                "updateBasicGroup" => "UpdateBasicGroup",
+                #[cfg(feature = "chats")]
+                "updateSupergroup" => "UpdateSupergroup",
+                #[cfg(feature = "chats")]
+                "updateSecretChat" => "UpdateSecretChat",
+                "updateUserFullInfo" => "UpdateUserFullInfo",
+                #[cfg(feature = "chats")]
+                "updateBasicGroupFullInfo" => "UpdateBasicGroupFullInfo",
+                #[cfg(feature = "chats")]
+                "updateSupergroupFullInfo" => "UpdateSupergroupFullInfo",
+                "updateServiceNotification" => "UpdateServiceNotification",
+                #[cfg(feature = "files")]
+                "updateFile" => "UpdateFile",
+                #[cfg(feature = "files")]
+                "updateFileGenerationStart" => "UpdateFileGenerationStart",
+                #[cfg(feature = "files")]
+                "updateFileGenerationStop" => "UpdateFileGenerationStop",
+                #[cfg(feature = "calls")]
+                "updateCall" => "UpdateCall",
+                "updateUserPrivacySettingRules" => "UpdateUserPrivacySettingRules",
+                #[cfg(feature = "messages")]
+                "updateUnreadMessageCount" => "UpdateUnreadMessageCount",
+                #[cfg(feature = "chats")]
+                "updateUnreadChatCount" => "UpdateUnreadChatCount",
+                "updateOption" => "UpdateOption",
+                #[cfg(feature = "stickers")]
+                "updateInstalledStickerSets" => "UpdateInstalledStickerSets",
+                #[cfg(feature = "stickers")]
+                "updateTrendingStickerSets" => "UpdateTrendingStickerSets",
+                #[cfg(feature = "stickers")]
+                "updateRecentStickers" => "UpdateRecentStickers",
+                #[cfg(feature = "stickers")]
+                "updateFavoriteStickers" => "UpdateFavoriteStickers",
+                #[cfg(feature = "stickers")]
+                "updateSavedAnimations" => "UpdateSavedAnimations",
+                "updateSelectedBackground" => "UpdateSelectedBackground",
+                "updateLanguagePackStrings" => "UpdateLanguagePackStrings",
+                "updateConnectionState" => "UpdateConnectionState",
+                #[cfg(feature = "auth")]
+                "updateTermsOfService" => "UpdateTermsOfService",
+                "updateUsersNearby" => "UpdateUsersNearby",
+                #[cfg(feature = "bots")]
+                "updateNewInlineQuery" => "UpdateNewInlineQuery",
+                #[cfg(feature = "bots")]
+                "updateNewChosenInlineResult" => "UpdateNewChosenInlineResult",
+                #[cfg(feature = "bots")]
+                "updateNewCallbackQuery" => "UpdateNewCallbackQuery",
+                #[cfg(feature = "bots")]
+                "updateNewInlineCallbackQuery" => "UpdateNewInlineCallbackQuery",
+                #[cfg(feature = "payments")]
+                "updateNewShippingQuery" => "UpdateNewShippingQuery",
+                #[cfg(feature = "payments")]
+                "updateNewPreCheckoutQuery" => "UpdateNewPreCheckoutQuery",
+                #[cfg(feature = "bots")]
+                "updateNewCustomEvent" => "UpdateNewCustomEvent",
+                #[cfg(feature = "bots")]
+                "updateNewCustomQuery" => "UpdateNewCustomQuery",
+                #[cfg(feature = "messages")]
+                "updatePoll" => "UpdatePoll",
+                #[cfg(feature = "messages")]
+                "updatePollAnswer" => "UpdatePollAnswer",
+                "authenticationCodeTypeTelegramMessage"
+                | "authenticationCodeTypeSms"
+                | "authenticationCodeTypeCall"
+                | "authenticationCodeTypeFlashCall" => "AuthenticationCodeType",
+                "authorizationStateWaitTdlibParameters"
+                | "authorizationStateWaitEncryptionKey"
+                | "authorizationStateWaitPhoneNumber"
+                | "authorizationStateWaitCode"
+                | "authorizationStateWaitOtherDeviceConfirmation"
+                | "authorizationStateWaitRegistration"
+                | "authorizationStateWaitPassword"
+                | "authorizationStateReady"
+                | "authorizationStateLoggingOut"
+                | "authorizationStateClosing"
+                | "authorizationStateClosed" => "AuthorizationState",
+                "inputFileId" | "inputFileRemote" | "inputFileLocal" | "inputFileGenerated" => {
+                    "InputFile"
+                }
+                "maskPointForehead" | "maskPointEyes" | "maskPointMouth" | "maskPointChin" => {
+                    "MaskPoint"
+                }
+                "pollTypeRegular" | "pollTypeQuiz" => "PollType",
+                "userTypeRegular" | "userTypeDeleted" | "userTypeBot" | "userTypeUnknown" => {
+                    "UserType"
+                }
+                #[cfg(feature = "chats")]
+                "chatMemberStatusCreator"
+                | "chatMemberStatusAdministrator"
+                | "chatMemberStatusMember"
+                | "chatMemberStatusRestricted"
+                | "chatMemberStatusLeft"
+                | "chatMemberStatusBanned" => "ChatMemberStatus",
+                #[cfg(feature = "chats")]
+                "chatMembersFilterContacts"
+                | "chatMembersFilterAdministrators"
+                | "chatMembersFilterMembers"
+                | "chatMembersFilterRestricted"
+                | "chatMembersFilterBanned"
+                | "chatMembersFilterBots" => "ChatMembersFilter",
+                #[cfg(feature = "chats")]
+                "supergroupMembersFilterRecent"
+                | "supergroupMembersFilterContacts"
+                | "supergroupMembersFilterAdministrators"
+                | "supergroupMembersFilterSearch"
+                | "supergroupMembersFilterRestricted"
+                | "supergroupMembersFilterBanned"
+                | "supergroupMembersFilterBots" => "SupergroupMembersFilter",
+                #[cfg(feature = "chats")]
+                "secretChatStatePending" | "secretChatStateReady" | "secretChatStateClosed" => {
+                    "SecretChatState"
+                }
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "messageForwardOriginUser"
+                | "messageForwardOriginHiddenUser"
+                | "messageForwardOriginChannel" => "MessageForwardOrigin",
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "messageSendingStatePending" | "messageSendingStateFailed" => "MessageSendingState",
+                "notificationSettingsScopePrivateChats"
+                | "notificationSettingsScopeGroupChats"
+                | "notificationSettingsScopeChannelChats" => "NotificationSettingsScope",
+                "chatTypePrivate" | "chatTypeBasicGroup" | "chatTypeSupergroup"
+                | "chatTypeSecret" => "ChatType",
+                #[cfg(any(feature = "chats", feature = "messages"))]
+                "chatListMain" | "chatListArchive" => "ChatList",
+                #[cfg(feature = "chats")]
+                "publicChatTypeHasUsername" | "publicChatTypeIsLocationBased" => "PublicChatType",
+                #[cfg(feature = "chats")]
+                "chatActionBarReportSpam"
+                | "chatActionBarReportUnrelatedLocation"
+                | "chatActionBarReportAddBlock"
+                | "chatActionBarAddContact"
+                | "chatActionBarSharePhoneNumber" => "ChatActionBar",
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "keyboardButtonTypeText"
+                | "keyboardButtonTypeRequestPhoneNumber"
+                | "keyboardButtonTypeRequestLocation"
+                | "keyboardButtonTypeRequestPoll" => "KeyboardButtonType",
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "inlineKeyboardButtonTypeUrl"
+                | "inlineKeyboardButtonTypeLoginUrl"
+                | "inlineKeyboardButtonTypeCallback"
+                | "inlineKeyboardButtonTypeCallbackGame"
+                | "inlineKeyboardButtonTypeSwitchInline"
+                | "inlineKeyboardButtonTypeBuy" => "InlineKeyboardButtonType",
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "replyMarkupRemoveKeyboard"
+                | "replyMarkupForceReply"
+                | "replyMarkupShowKeyboard"
+                | "replyMarkupInlineKeyboard" => "ReplyMarkup",
+                #[cfg(feature = "bots")]
+                "loginUrlInfoOpen" | "loginUrlInfoRequestConfirmation" => "LoginUrlInfo",
+                #[cfg(feature = "messages")]
+                "richTextPlain"
+                | "richTextBold"
+                | "richTextItalic"
+                | "richTextUnderline"
+                | "richTextStrikethrough"
+                | "richTextFixed"
+                | "richTextUrl"
+                | "richTextEmailAddress"
+                | "richTextSubscript"
+                | "richTextSuperscript"
+                | "richTextMarked"
+                | "richTextPhoneNumber"
+                | "richTextIcon"
+                | "richTextAnchor"
+                | "richTexts" => "RichText",
+                #[cfg(feature = "messages")]
+                "pageBlockHorizontalAlignmentLeft"
+                | "pageBlockHorizontalAlignmentCenter"
+                | "pageBlockHorizontalAlignmentRight" => "PageBlockHorizontalAlignment",
+                #[cfg(feature = "messages")]
+                "pageBlockVerticalAlignmentTop"
+                | "pageBlockVerticalAlignmentMiddle"
+                | "pageBlockVerticalAlignmentBottom" => "PageBlockVerticalAlignment",
+                #[cfg(feature = "messages")]
+                "pageBlockTitle"
+                | "pageBlockSubtitle"
+                | "pageBlockAuthorDate"
+                | "pageBlockHeader"
+                | "pageBlockSubheader"
+                | "pageBlockKicker"
+                | "pageBlockParagraph"
+                | "pageBlockPreformatted"
+                | "pageBlockFooter"
+                | "pageBlockDivider"
+                | "pageBlockAnchor"
+                | "pageBlockList"
+                | "pageBlockBlockQuote"
+                | "pageBlockPullQuote"
+                | "pageBlockAnimation"
+                | "pageBlockAudio"
+                | "pageBlockPhoto"
+                | "pageBlockVideo"
+                | "pageBlockVoiceNote"
+                | "pageBlockCover"
+                | "pageBlockEmbedded"
+                | "pageBlockEmbeddedPost"
+                | "pageBlockCollage"
+                | "pageBlockSlideshow"
+                | "pageBlockChatLink"
+                | "pageBlockTable"
+                | "pageBlockDetails"
+                | "pageBlockRelatedArticles"
+                | "pageBlockMap" => "PageBlock",
+                #[cfg(feature = "payments")]
+                "inputCredentialsSaved"
+                | "inputCredentialsNew"
+                | "inputCredentialsAndroidPay"
+                | "inputCredentialsApplePay" => "InputCredentials",
+                "passportElementTypePersonalDetails"
+                | "passportElementTypePassport"
+                | "passportElementTypeDriverLicense"
+                | "passportElementTypeIdentityCard"
+                | "passportElementTypeInternalPassport"
+                | "passportElementTypeAddress"
+                | "passportElementTypeUtilityBill"
+                | "passportElementTypeBankStatement"
+                | "passportElementTypeRentalAgreement"
+                | "passportElementTypePassportRegistration"
+                | "passportElementTypeTemporaryRegistration"
+                | "passportElementTypePhoneNumber"
+                | "passportElementTypeEmailAddress" => "PassportElementType",
+                #[cfg(feature = "passport")]
+                "passportElementPersonalDetails"
+                | "passportElementPassport"
+                | "passportElementDriverLicense"
+                | "passportElementIdentityCard"
+                | "passportElementInternalPassport"
+                | "passportElementAddress"
+                | "passportElementUtilityBill"
+                | "passportElementBankStatement"
+                | "passportElementRentalAgreement"
+                | "passportElementPassportRegistration"
+                | "passportElementTemporaryRegistration"
+                | "passportElementPhoneNumber"
+                | "passportElementEmailAddress" => "PassportElement",
+                #[cfg(feature = "passport")]
+                "inputPassportElementPersonalDetails"
+                | "inputPassportElementPassport"
+                | "inputPassportElementDriverLicense"
+                | "inputPassportElementIdentityCard"
+                | "inputPassportElementInternalPassport"
+                | "inputPassportElementAddress"
+                | "inputPassportElementUtilityBill"
+                | "inputPassportElementBankStatement"
+                | "inputPassportElementRentalAgreement"
+                | "inputPassportElementPassportRegistration"
+                | "inputPassportElementTemporaryRegistration"
+                | "inputPassportElementPhoneNumber"
+                | "inputPassportElementEmailAddress" => "InputPassportElement",
+                #[cfg(feature = "passport")]
+                "passportElementErrorSourceUnspecified"
+                | "passportElementErrorSourceDataField"
+                | "passportElementErrorSourceFrontSide"
+                | "passportElementErrorSourceReverseSide"
+                | "passportElementErrorSourceSelfie"
+                | "passportElementErrorSourceTranslationFile"
+                | "passportElementErrorSourceTranslationFiles"
+                | "passportElementErrorSourceFile"
+                | "passportElementErrorSourceFiles" => "PassportElementErrorSource",
+                #[cfg(feature = "passport")]
+                "inputPassportElementErrorSourceUnspecified"
+                | "inputPassportElementErrorSourceDataField"
+                | "inputPassportElementErrorSourceFrontSide"
+                | "inputPassportElementErrorSourceReverseSide"
+                | "inputPassportElementErrorSourceSelfie"
+                | "inputPassportElementErrorSourceTranslationFile"
+                | "inputPassportElementErrorSourceTranslationFiles"
+                | "inputPassportElementErrorSourceFile"
+                | "inputPassportElementErrorSourceFiles" => "InputPassportElementErrorSource",
+                "messageText"
+                | "messageAnimation"
+                | "messageAudio"
+                | "messageDocument"
+                | "messagePhoto"
+                | "messageExpiredPhoto"
+                | "messageSticker"
+                | "messageVideo"
+                | "messageExpiredVideo"
+                | "messageVideoNote"
+                | "messageVoiceNote"
+                | "messageLocation"
+                | "messageVenue"
+                | "messageContact"
+                | "messageGame"
+                | "messagePoll"
+                | "messageInvoice"
+                | "messageCall"
+                | "messageBasicGroupChatCreate"
+                | "messageSupergroupChatCreate"
+                | "messageChatChangeTitle"
+                | "messageChatChangePhoto"
+                | "messageChatDeletePhoto"
+                | "messageChatAddMembers"
+                | "messageChatJoinByLink"
+                | "messageChatDeleteMember"
+                | "messageChatUpgradeTo"
+                | "messageChatUpgradeFrom"
+                | "messagePinMessage"
+                | "messageScreenshotTaken"
+                | "messageChatSetTtl"
+                | "messageCustomServiceAction"
+                | "messageGameScore"
+                | "messagePaymentSuccessful"
+                | "messagePaymentSuccessfulBot"
+                | "messageContactRegistered"
+                | "messageWebsiteConnected"
+                | "messagePassportDataSent"
+                | "messagePassportDataReceived"
+                | "messageUnsupported" => "MessageContent",
+                "textEntityTypeMention"
+                | "textEntityTypeHashtag"
+                | "textEntityTypeCashtag"
+                | "textEntityTypeBotCommand"
+                | "textEntityTypeUrl"
+                | "textEntityTypeEmailAddress"
+                | "textEntityTypePhoneNumber"
+                | "textEntityTypeBold"
+                | "textEntityTypeItalic"
+                | "textEntityTypeUnderline"
+                | "textEntityTypeStrikethrough"
+                | "textEntityTypeCode"
+                | "textEntityTypePre"
+                | "textEntityTypePreCode"
+                | "textEntityTypeTextUrl"
+                | "textEntityTypeMentionName" => "TextEntityType",
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "messageSchedulingStateSendAtDate" | "messageSchedulingStateSendWhenOnline" => {
+                    "MessageSchedulingState"
+                }
+                #[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+                "inputMessageText"
+                | "inputMessageAnimation"
+                | "inputMessageAudio"
+                | "inputMessageDocument"
+                | "inputMessagePhoto"
+                | "inputMessageSticker"
+                | "inputMessageVideo"
+                | "inputMessageVideoNote"
+                | "inputMessageVoiceNote"
+                | "inputMessageLocation"
+                | "inputMessageVenue"
+                | "inputMessageContact"
+                | "inputMessageGame"
+                | "inputMessageInvoice"
+                | "inputMessagePoll"
+                | "inputMessageForwarded" => "InputMessageContent",
+                #[cfg(feature = "messages")]
+                "searchMessagesFilterEmpty"
+                | "searchMessagesFilterAnimation"
+                | "searchMessagesFilterAudio"
+                | "searchMessagesFilterDocument"
+                | "searchMessagesFilterPhoto"
+                | "searchMessagesFilterVideo"
+                | "searchMessagesFilterVoiceNote"
+                | "searchMessagesFilterPhotoAndVideo"
+                | "searchMessagesFilterUrl"
+                | "searchMessagesFilterChatPhoto"
+                | "searchMessagesFilterCall"
+                | "searchMessagesFilterMissedCall"
+                | "searchMessagesFilterVideoNote"
+                | "searchMessagesFilterVoiceAndVideoNote"
+                | "searchMessagesFilterMention"
+                | "searchMessagesFilterUnreadMention" => "SearchMessagesFilter",
+                #[cfg(feature = "chats")]
+                "chatActionTyping"
+                | "chatActionRecordingVideo"
+                | "chatActionUploadingVideo"
+                | "chatActionRecordingVoiceNote"
+                | "chatActionUploadingVoiceNote"
+                | "chatActionUploadingPhoto"
+                | "chatActionUploadingDocument"
+                | "chatActionChoosingLocation"
+                | "chatActionChoosingContact"
+                | "chatActionStartPlayingGame"
+                | "chatActionRecordingVideoNote"
+                | "chatActionUploadingVideoNote"
+                | "chatActionCancel" => "ChatAction",
+                "userStatusEmpty"
+                | "userStatusOnline"
+                | "userStatusOffline"
+                | "userStatusRecently"
+                | "userStatusLastWeek"
+                | "userStatusLastMonth" => "UserStatus",
+                "callDiscardReasonEmpty"
+                | "callDiscardReasonMissed"
+                | "callDiscardReasonDeclined"
+                | "callDiscardReasonDisconnected"
+                | "callDiscardReasonHungUp" => "CallDiscardReason",
+                #[cfg(feature = "calls")]
+                "callStatePending"
+                | "callStateExchangingKeys"
+                | "callStateReady"
+                | "callStateHangingUp"
+                | "callStateDiscarded"
+                | "callStateError" => "CallState",
+                #[cfg(feature = "calls")]
+                "callProblemEcho"
+                | "callProblemNoise"
+                | "callProblemInterruptions"
+                | "callProblemDistortedSpeech"
+                | "callProblemSilentLocal"
+                | "callProblemSilentRemote"
+                | "callProblemDropped" => "CallProblem",
+                #[cfg(feature = "bots")]
+                "inputInlineQueryResultAnimatedGif"
+                | "inputInlineQueryResultAnimatedMpeg4"
+                | "inputInlineQueryResultArticle"
+                | "inputInlineQueryResultAudio"
+                | "inputInlineQueryResultContact"
+                | "inputInlineQueryResultDocument"
+                | "inputInlineQueryResultGame"
+                | "inputInlineQueryResultLocation"
+                | "inputInlineQueryResultPhoto"
+                | "inputInlineQueryResultSticker"
+                | "inputInlineQueryResultVenue"
+                | "inputInlineQueryResultVideo"
+                | "inputInlineQueryResultVoiceNote" => "InputInlineQueryResult",
+                #[cfg(feature = "bots")]
+                "inlineQueryResultArticle"
+                | "inlineQueryResultContact"
+                | "inlineQueryResultLocation"
+                | "inlineQueryResultVenue"
+                | "inlineQueryResultGame"
+                | "inlineQueryResultAnimation"
+                | "inlineQueryResultAudio"
+                | "inlineQueryResultDocument"
+                | "inlineQueryResultPhoto"
+                | "inlineQueryResultSticker"
+                | "inlineQueryResultVideo"
+                | "inlineQueryResultVoiceNote" => "InlineQueryResult",
+                #[cfg(feature = "bots")]
+                "callbackQueryPayloadData" | "callbackQueryPayloadGame" => "CallbackQueryPayload",
+                #[cfg(feature = "chats")]
+                "chatEventMessageEdited"
+                | "chatEventMessageDeleted"
+                | "chatEventPollStopped"
+                | "chatEventMessagePinned"
+                | "chatEventMessageUnpinned"
+                | "chatEventMemberJoined"
+                | "chatEventMemberLeft"
+                | "chatEventMemberInvited"
+                | "chatEventMemberPromoted"
+                | "chatEventMemberRestricted"
+                | "chatEventTitleChanged"
+                | "chatEventPermissionsChanged"
+                | "chatEventDescriptionChanged"
+                | "chatEventUsernameChanged"
+                | "chatEventPhotoChanged"
+                | "chatEventInvitesToggled"
+                | "chatEventLinkedChatChanged"
+                | "chatEventSlowModeDelayChanged"
+                | "chatEventSignMessagesToggled"
+                | "chatEventStickerSetChanged"
+                | "chatEventLocationChanged"
+                | "chatEventIsAllHistoryAvailableToggled" => "ChatEventAction",
+                "languagePackStringValueOrdinary"
+                | "languagePackStringValuePluralized"
+                | "languagePackStringValueDeleted" => "LanguagePackStringValue",
+                "deviceTokenFirebaseCloudMessaging"
+                | "deviceTokenApplePush"
+                | "deviceTokenApplePushVoIP"
+                | "deviceTokenWindowsPush"
+                | "deviceTokenMicrosoftPush"
+                | "deviceTokenMicrosoftPushVoIP"
+                | "deviceTokenWebPush"
+                | "deviceTokenSimplePush"
+                | "deviceTokenUbuntuPush"
+                | "deviceTokenBlackBerryPush"
+                | "deviceTokenTizenPush" => "DeviceToken",
+                "backgroundFillSolid" | "backgroundFillGradient" => "BackgroundFill",
+                "backgroundTypeWallpaper" | "backgroundTypePattern" | "backgroundTypeFill" => {
+                    "BackgroundType"
+                }
+                "inputBackgroundLocal" | "inputBackgroundRemote" => "InputBackground",
+                "canTransferOwnershipResultOk"
+                | "canTransferOwnershipResultPasswordNeeded"
+                | "canTransferOwnershipResultPasswordTooFresh"
+                | "canTransferOwnershipResultSessionTooFresh" => "CanTransferOwnershipResult",
+                #[cfg(feature = "chats")]
+                "checkChatUsernameResultOk"
+                | "checkChatUsernameResultUsernameInvalid"
+                | "checkChatUsernameResultUsernameOccupied"
+                | "checkChatUsernameResultPublicChatsTooMuch"
+                | "checkChatUsernameResultPublicGroupsUnavailable" => "CheckChatUsernameResult",
+                #[cfg(feature = "messages")]
+                "pushMessageContentHidden"
+                | "pushMessageContentAnimation"
+                | "pushMessageContentAudio"
+                | "pushMessageContentContact"
+                | "pushMessageContentContactRegistered"
+                | "pushMessageContentDocument"
+                | "pushMessageContentGame"
+                | "pushMessageContentGameScore"
+                | "pushMessageContentInvoice"
+                | "pushMessageContentLocation"
+                | "pushMessageContentPhoto"
+                | "pushMessageContentPoll"
+                | "pushMessageContentScreenshotTaken"
+                | "pushMessageContentSticker"
+                | "pushMessageContentText"
+                | "pushMessageContentVideo"
+                | "pushMessageContentVideoNote"
+                | "pushMessageContentVoiceNote"
+                | "pushMessageContentBasicGroupChatCreate"
+                | "pushMessageContentChatAddMembers"
+                | "pushMessageContentChatChangePhoto"
+                | "pushMessageContentChatChangeTitle"
+                | "pushMessageContentChatDeleteMember"
+                | "pushMessageContentChatJoinByLink"
+                | "pushMessageContentMessageForwards"
+                | "pushMessageContentMediaAlbum" => "PushMessageContent",
+                #[cfg(feature = "messages")]
+                "notificationTypeNewMessage" => "NotificationType",
+                #[cfg(feature = "chats")]
+                "notificationTypeNewSecretChat" => "NotificationType",
+                #[cfg(feature = "calls")]
+                "notificationTypeNewCall" => "NotificationType",
+                #[cfg(feature = "messages")]
+                "notificationTypeNewPushMessage" => "NotificationType",
+                "notificationGroupTypeMessages"
+                | "notificationGroupTypeMentions"
+                | "notificationGroupTypeSecretChat"
+                | "notificationGroupTypeCalls" => "NotificationGroupType",
+                "optionValueBoolean" | "optionValueEmpty" | "optionValueInteger"
+                | "optionValueString" => "OptionValue",
+                "jsonValueNull" | "jsonValueBoolean" | "jsonValueNumber" | "jsonValueString"
+                | "jsonValueArray" | "jsonValueObject" => "JsonValue",
+                "userPrivacySettingRuleAllowAll"
+                | "userPrivacySettingRuleAllowContacts"
+                | "userPrivacySettingRuleAllowUsers"
+                | "userPrivacySettingRuleAllowChatMembers"
+                | "userPrivacySettingRuleRestrictAll"
+                | "userPrivacySettingRuleRestrictContacts"
+                | "userPrivacySettingRuleRestrictUsers"
+                | "userPrivacySettingRuleRestrictChatMembers" => "UserPrivacySettingRule",
+                "userPrivacySettingShowStatus"
+                | "userPrivacySettingShowProfilePhoto"
+                | "userPrivacySettingShowLinkInForwardedMessages"
+                | "userPrivacySettingShowPhoneNumber"
+                | "userPrivacySettingAllowChatInvites"
+                | "userPrivacySettingAllowCalls"
+                | "userPrivacySettingAllowPeerToPeerCalls"
+                | "userPrivacySettingAllowFindingByPhoneNumber" => "UserPrivacySetting",
+                #[cfg(feature = "chats")]
+                "chatReportReasonSpam"
+                | "chatReportReasonViolence"
+                | "chatReportReasonPornography"
+                | "chatReportReasonChildAbuse"
+                | "chatReportReasonCopyright"
+                | "chatReportReasonUnrelatedLocation"
+                | "chatReportReasonCustom" => "ChatReportReason",
+                #[cfg(feature = "files")]
+                "fileTypeNone"
+                | "fileTypeAnimation"
+                | "fileTypeAudio"
+                | "fileTypeDocument"
+                | "fileTypePhoto"
+                | "fileTypeProfilePhoto"
+                | "fileTypeSecret"
+                | "fileTypeSecretThumbnail"
+                | "fileTypeSecure"
+                | "fileTypeSticker"
+                | "fileTypeThumbnail"
+                | "fileTypeUnknown"
+                | "fileTypeVideo"
+                | "fileTypeVideoNote"
+                | "fileTypeVoiceNote"
+                | "fileTypeWallpaper" => "FileType",
+                "networkTypeNone"
+                | "networkTypeMobile"
+                | "networkTypeMobileRoaming"
+                | "networkTypeWiFi"
+                | "networkTypeOther" => "NetworkType",
+                #[cfg(feature = "files")]
+                "networkStatisticsEntryFile" | "networkStatisticsEntryCall" => {
+                    "NetworkStatisticsEntry"
+                }
+                "connectionStateWaitingForNetwork"
+                | "connectionStateConnectingToProxy"
+                | "connectionStateConnecting"
+                | "connectionStateUpdating"
+                | "connectionStateReady" => "ConnectionState",
+                #[cfg(feature = "chats")]
+                "topChatCategoryUsers"
+                | "topChatCategoryBots"
+                | "topChatCategoryGroups"
+                | "topChatCategoryChannels"
+                | "topChatCategoryInlineBots"
+                | "topChatCategoryCalls"
+                | "topChatCategoryForwardChats" => "TopChatCategory",
+                "tMeUrlTypeUser"
+                | "tMeUrlTypeSupergroup"
+                | "tMeUrlTypeChatInvite"
+                | "tMeUrlTypeStickerSet" => "TMeUrlType",
+                #[cfg(feature = "messages")]
+                "textParseModeMarkdown" | "textParseModeHTML" => "TextParseMode",
+                #[cfg(feature = "proxy")]
+                "proxyTypeSocks5" | "proxyTypeHttp" | "proxyTypeMtproto" => "ProxyType",
+                #[cfg(feature = "logging")]
+                "logStreamDefault" | "logStreamFile" | "logStreamEmpty" => "LogStream",
+                _ => return None,
+            })
+        }
+    }
+    impl<'de> Deserialize<'de> for Response {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            let (type_, value) = crate::utils::tagged(deserializer)?;
+            match type_.as_str() {
+                "error" => Deserialize::deserialize(value).map(Response::Error),
+                "ok" => Deserialize::deserialize(value).map(Response::Ok),
+                "tdlibParameters" => Deserialize::deserialize(value).map(Response::TdlibParameters),
+                "authenticationCodeInfo" => {
+                    Deserialize::deserialize(value).map(Response::AuthenticationCodeInfo)
+                }
+                #[cfg(any(feature = "auth", feature = "passport"))]
+                "emailAddressAuthenticationCodeInfo" => Deserialize::deserialize(value)
+                    .map(Response::EmailAddressAuthenticationCodeInfo),
+                "textEntity" => Deserialize::deserialize(value).map(Response::TextEntity),
+                #[cfg(feature = "messages")]
+                "textEntities" => Deserialize::deserialize(value).map(Response::TextEntities),
+                "formattedText" => Deserialize::deserialize(value).map(Response::FormattedText),
+                "termsOfService" => Deserialize::deserialize(value).map(Response::TermsOfService),
+                #[cfg(feature = "auth")]
+                "passwordState" => Deserialize::deserialize(value).map(Response::PasswordState),
+                #[cfg(feature = "auth")]
+                "recoveryEmailAddress" => {
+                    Deserialize::deserialize(value).map(Response::RecoveryEmailAddress)
+                }
+                #[cfg(feature = "auth")]
+                "temporaryPasswordState" => {
+                    Deserialize::deserialize(value).map(Response::TemporaryPasswordState)
+                }
+                "localFile" => Deserialize::deserialize(value).map(Response::LocalFile),
+                "remoteFile" => Deserialize::deserialize(value).map(Response::RemoteFile),
+                "file" => Deserialize::deserialize(value).map(Response::File),
+                "photoSize" => Deserialize::deserialize(value).map(Response::PhotoSize),
+                "minithumbnail" => Deserialize::deserialize(value).map(Response::Minithumbnail),
+                "maskPosition" => Deserialize::deserialize(value).map(Response::MaskPosition),
+                "pollOption" => Deserialize::deserialize(value).map(Response::PollOption),
+                "animation" => Deserialize::deserialize(value).map(Response::Animation),
+                "audio" => Deserialize::deserialize(value).map(Response::Audio),
+                "document" => Deserialize::deserialize(value).map(Response::Document),
+                "photo" => Deserialize::deserialize(value).map(Response::Photo),
+                "sticker" => Deserialize::deserialize(value).map(Response::Sticker),
+                "video" => Deserialize::deserialize(value).map(Response::Video),
+                "videoNote" => Deserialize::deserialize(value).map(Response::VideoNote),
+                "voiceNote" => Deserialize::deserialize(value).map(Response::VoiceNote),
+                "contact" => Deserialize::deserialize(value).map(Response::Contact),
+                "location" => Deserialize::deserialize(value).map(Response::Location),
+                "venue" => Deserialize::deserialize(value).map(Response::Venue),
+                "game" => Deserialize::deserialize(value).map(Response::Game),
+                "poll" => Deserialize::deserialize(value).map(Response::Poll),
+                "profilePhoto" => Deserialize::deserialize(value).map(Response::ProfilePhoto),
+                "chatPhoto" => Deserialize::deserialize(value).map(Response::ChatPhoto),
+                "botCommand" => Deserialize::deserialize(value).map(Response::BotCommand),
+                "botInfo" => Deserialize::deserialize(value).map(Response::BotInfo),
+                #[cfg(feature = "chats")]
+                "chatLocation" => Deserialize::deserialize(value).map(Response::ChatLocation),
+                "user" => Deserialize::deserialize(value).map(Response::User),
+                "userFullInfo" => Deserialize::deserialize(value).map(Response::UserFullInfo),
+                "userProfilePhoto" => {
+                    Deserialize::deserialize(value).map(Response::UserProfilePhoto)
+                }
+                "userProfilePhotos" => {
+                    Deserialize::deserialize(value).map(Response::UserProfilePhotos)
+                }
+                "users" => Deserialize::deserialize(value).map(Response::Users),
+                #[cfg(feature = "chats")]
+                "chatAdministrator" => {
+                    Deserialize::deserialize(value).map(Response::ChatAdministrator)
+                }
+                #[cfg(feature = "chats")]
+                "chatAdministrators" => {
+                    Deserialize::deserialize(value).map(Response::ChatAdministrators)
+                }
+                #[cfg(feature = "chats")]
+                "chatPermissions" => Deserialize::deserialize(value).map(Response::ChatPermissions),
+                #[cfg(feature = "chats")]
+                "chatMember" => Deserialize::deserialize(value).map(Response::ChatMember),
+                #[cfg(feature = "chats")]
+                "chatMembers" => Deserialize::deserialize(value).map(Response::ChatMembers),
+                #[cfg(feature = "chats")]
+                "basicGroup" => Deserialize::deserialize(value).map(Response::BasicGroup),
+                #[cfg(feature = "chats")]
+                "basicGroupFullInfo" => {
+                    Deserialize::deserialize(value).map(Response::BasicGroupFullInfo)
+                }
+                #[cfg(feature = "chats")]
+                "supergroup" => Deserialize::deserialize(value).map(Response::Supergroup),
+                #[cfg(feature = "chats")]
+                "supergroupFullInfo" => {
+                    Deserialize::deserialize(value).map(Response::SupergroupFullInfo)
+                }
+                #[cfg(feature = "chats")]
+                "secretChat" => Deserialize::deserialize(value).map(Response::SecretChat),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "messageForwardInfo" => {
+                    Deserialize::deserialize(value).map(Response::MessageForwardInfo)
+                }
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "message" => Deserialize::deserialize(value).map(Response::Message),
+                #[cfg(any(feature = "calls", feature = "messages"))]
+                "messages" => Deserialize::deserialize(value).map(Response::Messages),
+                #[cfg(feature = "messages")]
+                "foundMessages" => Deserialize::deserialize(value).map(Response::FoundMessages),
+                #[cfg(feature = "chats")]
+                "chatNotificationSettings" => {
+                    Deserialize::deserialize(value).map(Response::ChatNotificationSettings)
+                }
+                "scopeNotificationSettings" => {
+                    Deserialize::deserialize(value).map(Response::ScopeNotificationSettings)
+                }
+                #[cfg(any(feature = "chats", feature = "messages"))]
+                "draftMessage" => Deserialize::deserialize(value).map(Response::DraftMessage),
+                #[cfg(feature = "chats")]
+                "chat" => Deserialize::deserialize(value).map(Response::Chat),
+                #[cfg(feature = "chats")]
+                "chats" => Deserialize::deserialize(value).map(Response::Chats),
+                "chatNearby" => Deserialize::deserialize(value).map(Response::ChatNearby),
+                #[cfg(feature = "chats")]
+                "chatsNearby" => Deserialize::deserialize(value).map(Response::ChatsNearby),
+                #[cfg(feature = "chats")]
+                "chatInviteLink" => Deserialize::deserialize(value).map(Response::ChatInviteLink),
+                "chatInviteLinkInfo" => {
+                    Deserialize::deserialize(value).map(Response::ChatInviteLinkInfo)
+                }
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "keyboardButton" => Deserialize::deserialize(value).map(Response::KeyboardButton),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "inlineKeyboardButton" => {
+                    Deserialize::deserialize(value).map(Response::InlineKeyboardButton)
+                }
+                #[cfg(feature = "messages")]
+                "pageBlockCaption" => {
+                    Deserialize::deserialize(value).map(Response::PageBlockCaption)
+                }
+                #[cfg(feature = "messages")]
+                "pageBlockListItem" => {
+                    Deserialize::deserialize(value).map(Response::PageBlockListItem)
+                }
+                #[cfg(feature = "messages")]
+                "pageBlockTableCell" => {
+                    Deserialize::deserialize(value).map(Response::PageBlockTableCell)
+                }
+                #[cfg(feature = "messages")]
+                "pageBlockRelatedArticle" => {
+                    Deserialize::deserialize(value).map(Response::PageBlockRelatedArticle)
+                }
+                #[cfg(feature = "messages")]
+                "webPageInstantView" => {
+                    Deserialize::deserialize(value).map(Response::WebPageInstantView)
+                }
+                "webPage" => Deserialize::deserialize(value).map(Response::WebPage),
+                "address" => Deserialize::deserialize(value).map(Response::Address),
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "chats",
+                    feature = "messages",
+                    feature = "payments"
+                ))]
+                "labeledPricePart" => {
+                    Deserialize::deserialize(value).map(Response::LabeledPricePart)
+                }
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "chats",
+                    feature = "messages",
+                    feature = "payments"
+                ))]
+                "invoice" => Deserialize::deserialize(value).map(Response::Invoice),
+                "orderInfo" => Deserialize::deserialize(value).map(Response::OrderInfo),
+                #[cfg(feature = "payments")]
+                "shippingOption" => Deserialize::deserialize(value).map(Response::ShippingOption),
+                #[cfg(feature = "payments")]
+                "savedCredentials" => {
+                    Deserialize::deserialize(value).map(Response::SavedCredentials)
+                }
+                #[cfg(feature = "payments")]
+                "paymentsProviderStripe" => {
+                    Deserialize::deserialize(value).map(Response::PaymentsProviderStripe)
+                }
+                #[cfg(feature = "payments")]
+                "paymentForm" => Deserialize::deserialize(value).map(Response::PaymentForm),
+                #[cfg(feature = "payments")]
+                "validatedOrderInfo" => {
+                    Deserialize::deserialize(value).map(Response::ValidatedOrderInfo)
+                }
+                #[cfg(feature = "payments")]
+                "paymentResult" => Deserialize::deserialize(value).map(Response::PaymentResult),
+                #[cfg(feature = "payments")]
+                "paymentReceipt" => Deserialize::deserialize(value).map(Response::PaymentReceipt),
+                "datedFile" => Deserialize::deserialize(value).map(Response::DatedFile),
+                #[cfg(feature = "passport")]
+                "date" => Deserialize::deserialize(value).map(Response::Date),
+                #[cfg(feature = "passport")]
+                "personalDetails" => Deserialize::deserialize(value).map(Response::PersonalDetails),
+                #[cfg(feature = "passport")]
+                "identityDocument" => {
+                    Deserialize::deserialize(value).map(Response::IdentityDocument)
+                }
+                #[cfg(feature = "passport")]
+                "inputIdentityDocument" => {
+                    Deserialize::deserialize(value).map(Response::InputIdentityDocument)
+                }
+                #[cfg(feature = "passport")]
+                "personalDocument" => {
+                    Deserialize::deserialize(value).map(Response::PersonalDocument)
+                }
+                #[cfg(feature = "passport")]
+                "inputPersonalDocument" => {
+                    Deserialize::deserialize(value).map(Response::InputPersonalDocument)
+                }
+                #[cfg(feature = "passport")]
+                "passportElements" => {
+                    Deserialize::deserialize(value).map(Response::PassportElements)
+                }
+                #[cfg(feature = "passport")]
+                "passportElementError" => {
+                    Deserialize::deserialize(value).map(Response::PassportElementError)
+                }
+                #[cfg(feature = "passport")]
+                "passportSuitableElement" => {
+                    Deserialize::deserialize(value).map(Response::PassportSuitableElement)
+                }
+                #[cfg(feature = "passport")]
+                "passportRequiredElement" => {
+                    Deserialize::deserialize(value).map(Response::PassportRequiredElement)
+                }
+                #[cfg(feature = "passport")]
+                "passportAuthorizationForm" => {
+                    Deserialize::deserialize(value).map(Response::PassportAuthorizationForm)
+                }
+                #[cfg(feature = "passport")]
+                "passportElementsWithErrors" => {
+                    Deserialize::deserialize(value).map(Response::PassportElementsWithErrors)
+                }
+                "encryptedCredentials" => {
+                    Deserialize::deserialize(value).map(Response::EncryptedCredentials)
+                }
+                "encryptedPassportElement" => {
+                    Deserialize::deserialize(value).map(Response::EncryptedPassportElement)
+                }
+                #[cfg(feature = "passport")]
+                "inputPassportElementError" => {
+                    Deserialize::deserialize(value).map(Response::InputPassportElementError)
+                }
+                "inputThumbnail" => Deserialize::deserialize(value).map(Response::InputThumbnail),
+                #[cfg(any(feature = "bots", feature = "messages"))]
+                "sendMessageOptions" => {
+                    Deserialize::deserialize(value).map(Response::SendMessageOptions)
+                }
+                #[cfg(feature = "stickers")]
+                "stickers" => Deserialize::deserialize(value).map(Response::Stickers),
+                #[cfg(feature = "stickers")]
+                "emojis" => Deserialize::deserialize(value).map(Response::Emojis),
+                #[cfg(feature = "stickers")]
+                "stickerSet" => Deserialize::deserialize(value).map(Response::StickerSet),
+                #[cfg(feature = "stickers")]
+                "stickerSetInfo" => Deserialize::deserialize(value).map(Response::StickerSetInfo),
+                #[cfg(feature = "stickers")]
+                "stickerSets" => Deserialize::deserialize(value).map(Response::StickerSets),
+                #[cfg(feature = "calls")]
+                "callProtocol" => Deserialize::deserialize(value).map(Response::CallProtocol),
+                #[cfg(feature = "calls")]
+                "callConnection" => Deserialize::deserialize(value).map(Response::CallConnection),
+                #[cfg(feature = "calls")]
+                "callId" => Deserialize::deserialize(value).map(Response::CallId),
+                #[cfg(feature = "calls")]
+                "call" => Deserialize::deserialize(value).map(Response::Call),
+                #[cfg(any(feature = "auth", feature = "passport"))]
+                "phoneNumberAuthenticationSettings" => {
+                    Deserialize::deserialize(value).map(Response::PhoneNumberAuthenticationSettings)
+                }
+                "animations" => Deserialize::deserialize(value).map(Response::Animations),
+                "importedContacts" => {
+                    Deserialize::deserialize(value).map(Response::ImportedContacts)
+                }
+                "httpUrl" => Deserialize::deserialize(value).map(Response::HttpUrl),
+                #[cfg(feature = "bots")]
+                "inlineQueryResults" => {
+                    Deserialize::deserialize(value).map(Response::InlineQueryResults)
+                }
+                #[cfg(feature = "bots")]
+                "callbackQueryAnswer" => {
+                    Deserialize::deserialize(value).map(Response::CallbackQueryAnswer)
+                }
+                #[cfg(feature = "bots")]
+                "customRequestResult" => {
+                    Deserialize::deserialize(value).map(Response::CustomRequestResult)
+                }
+                #[cfg(feature = "bots")]
+                "gameHighScore" => Deserialize::deserialize(value).map(Response::GameHighScore),
+                #[cfg(feature = "bots")]
+                "gameHighScores" => Deserialize::deserialize(value).map(Response::GameHighScores),
+                #[cfg(feature = "chats")]
+                "chatEvent" => Deserialize::deserialize(value).map(Response::ChatEvent),
+                #[cfg(feature = "chats")]
+                "chatEvents" => Deserialize::deserialize(value).map(Response::ChatEvents),
+                #[cfg(feature = "chats")]
+                "chatEventLogFilters" => {
+                    Deserialize::deserialize(value).map(Response::ChatEventLogFilters)
+                }
+                "languagePackString" => {
+                    Deserialize::deserialize(value).map(Response::LanguagePackString)
+                }
+                "languagePackStrings" => {
+                    Deserialize::deserialize(value).map(Response::LanguagePackStrings)
+                }
+                "languagePackInfo" => {
+                    Deserialize::deserialize(value).map(Response::LanguagePackInfo)
+                }
+                "localizationTargetInfo" => {
+                    Deserialize::deserialize(value).map(Response::LocalizationTargetInfo)
+                }
+                "pushReceiverId" => Deserialize::deserialize(value).map(Response::PushReceiverId),
+                "background" => Deserialize::deserialize(value).map(Response::Background),
+                "backgrounds" => Deserialize::deserialize(value).map(Response::Backgrounds),
+                "hashtags" => Deserialize::deserialize(value).map(Response::Hashtags),
+                "notification" => Deserialize::deserialize(value).map(Response::Notification),
+                "notificationGroup" => {
+                    Deserialize::deserialize(value).map(Response::NotificationGroup)
+                }
+                "jsonObjectMember" => {
+                    Deserialize::deserialize(value).map(Response::JsonObjectMember)
+                }
+                "userPrivacySettingRules" => {
+                    Deserialize::deserialize(value).map(Response::UserPrivacySettingRules)
+                }
+                #[cfg(feature = "auth")]
+                "accountTtl" => Deserialize::deserialize(value).map(Response::AccountTtl),
+                #[cfg(feature = "auth")]
+                "session" => Deserialize::deserialize(value).map(Response::Session),
+                #[cfg(feature = "auth")]
+                "sessions" => Deserialize::deserialize(value).map(Response::Sessions),
+                #[cfg(feature = "auth")]
+                "connectedWebsite" => {
+                    Deserialize::deserialize(value).map(Response::ConnectedWebsite)
+                }
+                #[cfg(feature = "auth")]
+                "connectedWebsites" => {
+                    Deserialize::deserialize(value).map(Response::ConnectedWebsites)
+                }
+                #[cfg(feature = "messages")]
+                "publicMessageLink" => {
+                    Deserialize::deserialize(value).map(Response::PublicMessageLink)
+                }
+                #[cfg(feature = "messages")]
+                "messageLinkInfo" => Deserialize::deserialize(value).map(Response::MessageLinkInfo),
+                #[cfg(feature = "files")]
+                "filePart" => Deserialize::deserialize(value).map(Response::FilePart),
+                #[cfg(feature = "files")]
+                "storageStatisticsByFileType" => {
+                    Deserialize::deserialize(value).map(Response::StorageStatisticsByFileType)
+                }
+                #[cfg(feature = "files")]
+                "storageStatisticsByChat" => {
+                    Deserialize::deserialize(value).map(Response::StorageStatisticsByChat)
+                }
+                #[cfg(feature = "files")]
+                "storageStatistics" => {
+                    Deserialize::deserialize(value).map(Response::StorageStatistics)
+                }
+                #[cfg(feature = "files")]
+                "storageStatisticsFast" => {
+                    Deserialize::deserialize(value).map(Response::StorageStatisticsFast)
+                }
+                #[cfg(feature = "files")]
+                "databaseStatistics" => {
+                    Deserialize::deserialize(value).map(Response::DatabaseStatistics)
+                }
+                #[cfg(feature = "files")]
+                "networkStatistics" => {
+                    Deserialize::deserialize(value).map(Response::NetworkStatistics)
+                }
+                #[cfg(feature = "files")]
+                "autoDownloadSettings" => {
+                    Deserialize::deserialize(value).map(Response::AutoDownloadSettings)
+                }
+                #[cfg(feature = "files")]
+                "autoDownloadSettingsPresets" => {
+                    Deserialize::deserialize(value).map(Response::AutoDownloadSettingsPresets)
+                }
+                "tMeUrl" => Deserialize::deserialize(value).map(Response::TMeUrl),
+                "tMeUrls" => Deserialize::deserialize(value).map(Response::TMeUrls),
+                "count" => Deserialize::deserialize(value).map(Response::Count),
+                "text" => Deserialize::deserialize(value).map(Response::Text),
+                "seconds" => Deserialize::deserialize(value).map(Response::Seconds),
+                "deepLinkInfo" => Deserialize::deserialize(value).map(Response::DeepLinkInfo),
+                #[cfg(feature = "proxy")]
+                "proxy" => Deserialize::deserialize(value).map(Response::Proxy),
+                #[cfg(feature = "proxy")]
+                "proxies" => Deserialize::deserialize(value).map(Response::Proxies),
+                #[cfg(feature = "stickers")]
+                "inputSticker" => Deserialize::deserialize(value).map(Response::InputSticker),
+                "updates" => Deserialize::deserialize(value).map(Response::Updates),
+                #[cfg(feature = "logging")]
+                "logVerbosityLevel" => {
+                    Deserialize::deserialize(value).map(Response::LogVerbosityLevel)
+                }
+                #[cfg(feature = "logging")]
+                "logTags" => Deserialize::deserialize(value).map(Response::LogTags),
+                #[cfg(feature = "tests")]
+                "testInt" => Deserialize::deserialize(value).map(Response::TestInt),
+                #[cfg(feature = "tests")]
+                "testString" => Deserialize::deserialize(value).map(Response::TestString),
+                #[cfg(feature = "tests")]
+                "testBytes" => Deserialize::deserialize(value).map(Response::TestBytes),
+                #[cfg(feature = "tests")]
+                "testVectorInt" => Deserialize::deserialize(value).map(Response::TestVectorInt),
+                #[cfg(feature = "tests")]
+                "testVectorIntObject" => {
+                    Deserialize::deserialize(value).map(Response::TestVectorIntObject)
+                }
+                #[cfg(feature = "tests")]
+                "testVectorString" => {
+                    Deserialize::deserialize(value).map(Response::TestVectorString)
+                }
+                #[cfg(feature = "tests")]
+                "testVectorStringObject" => {
+                    Deserialize::deserialize(value).map(Response::TestVectorStringObject)
+                }
+                "updateAuthorizationState" => {
+                    Deserialize::deserialize(value).map(Response::UpdateAuthorizationState)
+                }
+                #[cfg(feature = "messages")]
+                "updateNewMessage" => {
+                    Deserialize::deserialize(value).map(Response::UpdateNewMessage)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageSendAcknowledged" => {
+                    Deserialize::deserialize(value).map(Response::UpdateMessageSendAcknowledged)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageSendSucceeded" => {
+                    Deserialize::deserialize(value).map(Response::UpdateMessageSendSucceeded)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageSendFailed" => {
+                    Deserialize::deserialize(value).map(Response::UpdateMessageSendFailed)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageContent" => {
+                    Deserialize::deserialize(value).map(Response::UpdateMessageContent)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageEdited" => {
+                    Deserialize::deserialize(value).map(Response::UpdateMessageEdited)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageViews" => {
+                    Deserialize::deserialize(value).map(Response::UpdateMessageViews)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageContentOpened" => {
+                    Deserialize::deserialize(value).map(Response::UpdateMessageContentOpened)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageMentionRead" => {
+                    Deserialize::deserialize(value).map(Response::UpdateMessageMentionRead)
+                }
+                #[cfg(feature = "messages")]
+                "updateMessageLiveLocationViewed" => {
+                    Deserialize::deserialize(value).map(Response::UpdateMessageLiveLocationViewed)
+                }
+                #[cfg(feature = "chats")]
+                "updateNewChat" => Deserialize::deserialize(value).map(Response::UpdateNewChat),
+                #[cfg(feature = "chats")]
+                "updateChatChatList" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatChatList)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatTitle" => Deserialize::deserialize(value).map(Response::UpdateChatTitle),
+                #[cfg(feature = "chats")]
+                "updateChatPhoto" => Deserialize::deserialize(value).map(Response::UpdateChatPhoto),
+                #[cfg(feature = "chats")]
+                "updateChatPermissions" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatPermissions)
+                }
+                #[cfg(feature = "messages")]
+                "updateChatLastMessage" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatLastMessage)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatOrder" => Deserialize::deserialize(value).map(Response::UpdateChatOrder),
+                #[cfg(feature = "chats")]
+                "updateChatIsPinned" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatIsPinned)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatIsMarkedAsUnread" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatIsMarkedAsUnread)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatIsSponsored" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatIsSponsored)
+                }
+                #[cfg(feature = "messages")]
+                "updateChatHasScheduledMessages" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatHasScheduledMessages)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatDefaultDisableNotification" => Deserialize::deserialize(value)
+                    .map(Response::UpdateChatDefaultDisableNotification),
+                #[cfg(feature = "chats")]
+                "updateChatReadInbox" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatReadInbox)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatReadOutbox" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatReadOutbox)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatUnreadMentionCount" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatUnreadMentionCount)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatNotificationSettings" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatNotificationSettings)
+                }
+                "updateScopeNotificationSettings" => {
+                    Deserialize::deserialize(value).map(Response::UpdateScopeNotificationSettings)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatActionBar" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatActionBar)
+                }
+                #[cfg(feature = "messages")]
+                "updateChatPinnedMessage" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatPinnedMessage)
+                }
+                #[cfg(feature = "bots")]
+                "updateChatReplyMarkup" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatReplyMarkup)
+                }
+                #[cfg(feature = "messages")]
+                "updateChatDraftMessage" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatDraftMessage)
+                }
+                #[cfg(feature = "chats")]
+                "updateChatOnlineMemberCount" => {
+                    Deserialize::deserialize(value).map(Response::UpdateChatOnlineMemberCount)
+                }
+                "updateNotification" => {
+                    Deserialize::deserialize(value).map(Response::UpdateNotification)
+                }
+                "updateNotificationGroup" => {
+                    Deserialize::deserialize(value).map(Response::UpdateNotificationGroup)
+                }
+                "updateActiveNotifications" => {
+                    Deserialize::deserialize(value).map(Response::UpdateActiveNotifications)
+                }
+                "updateHavePendingNotifications" => {
+                    Deserialize::deserialize(value).map(Response::UpdateHavePendingNotifications)
+                }
+                #[cfg(feature = "messages")]
+                "updateDeleteMessages" => {
+                    Deserialize::deserialize(value).map(Response::UpdateDeleteMessages)
+                }
+                #[cfg(feature = "chats")]
+                "updateUserChatAction" => {
+                    Deserialize::deserialize(value).map(Response::UpdateUserChatAction)
+                }
+                "updateUserStatus" => {
+                    Deserialize::deserialize(value).map(Response::UpdateUserStatus)
+                }
+                "updateUser" => Deserialize::deserialize(value).map(Response::UpdateUser),
+                #[cfg(feature = "chats")]
+                "updateBasicGroup" => {
+                    Deserialize::deserialize(value).map(Response::UpdateBasicGroup)
+                }
+                #[cfg(feature = "chats")]
+                "updateSupergroup" => {
+                    Deserialize::deserialize(value).map(Response::UpdateSupergroup)
+                }
+                #[cfg(feature = "chats")]
+                "updateSecretChat" => {
+                    Deserialize::deserialize(value).map(Response::UpdateSecretChat)
+                }
+                "updateUserFullInfo" => {
+                    Deserialize::deserialize(value).map(Response::UpdateUserFullInfo)
+                }
+                #[cfg(feature = "chats")]
+                "updateBasicGroupFullInfo" => {
+                    Deserialize::deserialize(value).map(Response::UpdateBasicGroupFullInfo)
+                }
+                #[cfg(feature = "chats")]
+                "updateSupergroupFullInfo" => {
+                    Deserialize::deserialize(value).map(Response::UpdateSupergroupFullInfo)
+                }
+                "updateServiceNotification" => {
+                    Deserialize::deserialize(value).map(Response::UpdateServiceNotification)
+                }
+                #[cfg(feature = "files")]
+                "updateFile" => Deserialize::deserialize(value).map(Response::UpdateFile),
+                #[cfg(feature = "files")]
+                "updateFileGenerationStart" => {
+                    Deserialize::deserialize(value).map(Response::UpdateFileGenerationStart)
+                }
+                #[cfg(feature = "files")]
+                "updateFileGenerationStop" => {
+                    Deserialize::deserialize(value).map(Response::UpdateFileGenerationStop)
+                }
+                #[cfg(feature = "calls")]
+                "updateCall" => Deserialize::deserialize(value).map(Response::UpdateCall),
+                "updateUserPrivacySettingRules" => {
+                    Deserialize::deserialize(value).map(Response::UpdateUserPrivacySettingRules)
+                }
+                #[cfg(feature = "messages")]
+                "updateUnreadMessageCount" => {
+                    Deserialize::deserialize(value).map(Response::UpdateUnreadMessageCount)
+                }
+                #[cfg(feature = "chats")]
+                "updateUnreadChatCount" => {
+                    Deserialize::deserialize(value).map(Response::UpdateUnreadChatCount)
+                }
+                "updateOption" => Deserialize::deserialize(value).map(Response::UpdateOption),
+                #[cfg(feature = "stickers")]
+                "updateInstalledStickerSets" => {
+                    Deserialize::deserialize(value).map(Response::UpdateInstalledStickerSets)
+                }
+                #[cfg(feature = "stickers")]
+                "updateTrendingStickerSets" => {
+                    Deserialize::deserialize(value).map(Response::UpdateTrendingStickerSets)
+                }
+                #[cfg(feature = "stickers")]
+                "updateRecentStickers" => {
+                    Deserialize::deserialize(value).map(Response::UpdateRecentStickers)
+                }
+                #[cfg(feature = "stickers")]
+                "updateFavoriteStickers" => {
+                    Deserialize::deserialize(value).map(Response::UpdateFavoriteStickers)
+                }
+                #[cfg(feature = "stickers")]
+                "updateSavedAnimations" => {
+                    Deserialize::deserialize(value).map(Response::UpdateSavedAnimations)
+                }
+                "updateSelectedBackground" => {
+                    Deserialize::deserialize(value).map(Response::UpdateSelectedBackground)
+                }
+                "updateLanguagePackStrings" => {
+                    Deserialize::deserialize(value).map(Response::UpdateLanguagePackStrings)
+                }
+                "updateConnectionState" => {
+                    Deserialize::deserialize(value).map(Response::UpdateConnectionState)
+                }
+                #[cfg(feature = "auth")]
+                "updateTermsOfService" => {
+                    Deserialize::deserialize(value).map(Response::UpdateTermsOfService)
+                }
+                "updateUsersNearby" => {
+                    Deserialize::deserialize(value).map(Response::UpdateUsersNearby)
+                }
+                #[cfg(feature = "bots")]
+                "updateNewInlineQuery" => {
+                    Deserialize::deserialize(value).map(Response::UpdateNewInlineQuery)
+                }
+                #[cfg(feature = "bots")]
+                "updateNewChosenInlineResult" => {
+                    Deserialize::deserialize(value).map(Response::UpdateNewChosenInlineResult)
+                }
+                #[cfg(feature = "bots")]
+                "updateNewCallbackQuery" => {
+                    Deserialize::deserialize(value).map(Response::UpdateNewCallbackQuery)
+                }
+                #[cfg(feature = "bots")]
+                "updateNewInlineCallbackQuery" => {
+                    Deserialize::deserialize(value).map(Response::UpdateNewInlineCallbackQuery)
+                }
+                #[cfg(feature = "payments")]
+                "updateNewShippingQuery" => {
+                    Deserialize::deserialize(value).map(Response::UpdateNewShippingQuery)
+                }
+                #[cfg(feature = "payments")]
+                "updateNewPreCheckoutQuery" => {
+                    Deserialize::deserialize(value).map(Response::UpdateNewPreCheckoutQuery)
+                }
+                #[cfg(feature = "bots")]
+                "updateNewCustomEvent" => {
+                    Deserialize::deserialize(value).map(Response::UpdateNewCustomEvent)
+                }
+                #[cfg(feature = "bots")]
+                "updateNewCustomQuery" => {
+                    Deserialize::deserialize(value).map(Response::UpdateNewCustomQuery)
+                }
+                #[cfg(feature = "messages")]
+                "updatePoll" => Deserialize::deserialize(value).map(Response::UpdatePoll),
+                #[cfg(feature = "messages")]
+                "updatePollAnswer" => {
+                    Deserialize::deserialize(value).map(Response::UpdatePollAnswer)
+                }
+                "authenticationCodeTypeTelegramMessage"
+                | "authenticationCodeTypeSms"
+                | "authenticationCodeTypeCall"
+                | "authenticationCodeTypeFlashCall" => {
+                    Deserialize::deserialize(value).map(Response::AuthenticationCodeType)
+                }
+                "authorizationStateWaitTdlibParameters"
+                | "authorizationStateWaitEncryptionKey"
+                | "authorizationStateWaitPhoneNumber"
+                | "authorizationStateWaitCode"
+                | "authorizationStateWaitOtherDeviceConfirmation"
+                | "authorizationStateWaitRegistration"
+                | "authorizationStateWaitPassword"
+                | "authorizationStateReady"
+                | "authorizationStateLoggingOut"
+                | "authorizationStateClosing"
+                | "authorizationStateClosed" => {
+                    Deserialize::deserialize(value).map(Response::AuthorizationState)
+                }
+                "inputFileId" | "inputFileRemote" | "inputFileLocal" | "inputFileGenerated" => {
+                    Deserialize::deserialize(value).map(Response::InputFile)
+                }
+                "maskPointForehead" | "maskPointEyes" | "maskPointMouth" | "maskPointChin" => {
+                    Deserialize::deserialize(value).map(Response::MaskPoint)
+                }
+                "pollTypeRegular" | "pollTypeQuiz" => {
+                    Deserialize::deserialize(value).map(Response::PollType)
+                }
+                "userTypeRegular" | "userTypeDeleted" | "userTypeBot" | "userTypeUnknown" => {
+                    Deserialize::deserialize(value).map(Response::UserType)
+                }
+                #[cfg(feature = "chats")]
+                "chatMemberStatusCreator"
+                | "chatMemberStatusAdministrator"
+                | "chatMemberStatusMember"
+                | "chatMemberStatusRestricted"
+                | "chatMemberStatusLeft"
+                | "chatMemberStatusBanned" => {
+                    Deserialize::deserialize(value).map(Response::ChatMemberStatus)
+                }
+                #[cfg(feature = "chats")]
+                "chatMembersFilterContacts"
+                | "chatMembersFilterAdministrators"
+                | "chatMembersFilterMembers"
+                | "chatMembersFilterRestricted"
+                | "chatMembersFilterBanned"
+                | "chatMembersFilterBots" => {
+                    Deserialize::deserialize(value).map(Response::ChatMembersFilter)
+                }
+                #[cfg(feature = "chats")]
+                "supergroupMembersFilterRecent"
+                | "supergroupMembersFilterContacts"
+                | "supergroupMembersFilterAdministrators"
+                | "supergroupMembersFilterSearch"
+                | "supergroupMembersFilterRestricted"
+                | "supergroupMembersFilterBanned"
+                | "supergroupMembersFilterBots" => {
+                    Deserialize::deserialize(value).map(Response::SupergroupMembersFilter)
+                }
+                #[cfg(feature = "chats")]
+                "secretChatStatePending" | "secretChatStateReady" | "secretChatStateClosed" => {
+                    Deserialize::deserialize(value).map(Response::SecretChatState)
+                }
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "messageForwardOriginUser"
+                | "messageForwardOriginHiddenUser"
+                | "messageForwardOriginChannel" => {
+                    Deserialize::deserialize(value).map(Response::MessageForwardOrigin)
+                }
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "messageSendingStatePending" | "messageSendingStateFailed" => {
+                    Deserialize::deserialize(value).map(Response::MessageSendingState)
+                }
+                "notificationSettingsScopePrivateChats"
+                | "notificationSettingsScopeGroupChats"
+                | "notificationSettingsScopeChannelChats" => {
+                    Deserialize::deserialize(value).map(Response::NotificationSettingsScope)
+                }
+                "chatTypePrivate" | "chatTypeBasicGroup" | "chatTypeSupergroup"
+                | "chatTypeSecret" => Deserialize::deserialize(value).map(Response::ChatType),
+                #[cfg(any(feature = "chats", feature = "messages"))]
+                "chatListMain" | "chatListArchive" => {
+                    Deserialize::deserialize(value).map(Response::ChatList)
+                }
+                #[cfg(feature = "chats")]
+                "publicChatTypeHasUsername" | "publicChatTypeIsLocationBased" => {
+                    Deserialize::deserialize(value).map(Response::PublicChatType)
+                }
+                #[cfg(feature = "chats")]
+                "chatActionBarReportSpam"
+                | "chatActionBarReportUnrelatedLocation"
+                | "chatActionBarReportAddBlock"
+                | "chatActionBarAddContact"
+                | "chatActionBarSharePhoneNumber" => {
+                    Deserialize::deserialize(value).map(Response::ChatActionBar)
+                }
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "keyboardButtonTypeText"
+                | "keyboardButtonTypeRequestPhoneNumber"
+                | "keyboardButtonTypeRequestLocation"
+                | "keyboardButtonTypeRequestPoll" => {
+                    Deserialize::deserialize(value).map(Response::KeyboardButtonType)
+                }
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "inlineKeyboardButtonTypeUrl"
+                | "inlineKeyboardButtonTypeLoginUrl"
+                | "inlineKeyboardButtonTypeCallback"
+                | "inlineKeyboardButtonTypeCallbackGame"
+                | "inlineKeyboardButtonTypeSwitchInline"
+                | "inlineKeyboardButtonTypeBuy" => {
+                    Deserialize::deserialize(value).map(Response::InlineKeyboardButtonType)
+                }
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "replyMarkupRemoveKeyboard"
+                | "replyMarkupForceReply"
+                | "replyMarkupShowKeyboard"
+                | "replyMarkupInlineKeyboard" => {
+                    Deserialize::deserialize(value).map(Response::ReplyMarkup)
+                }
+                #[cfg(feature = "bots")]
+                "loginUrlInfoOpen" | "loginUrlInfoRequestConfirmation" => {
+                    Deserialize::deserialize(value).map(Response::LoginUrlInfo)
+                }
+                #[cfg(feature = "messages")]
+                "richTextPlain"
+                | "richTextBold"
+                | "richTextItalic"
+                | "richTextUnderline"
+                | "richTextStrikethrough"
+                | "richTextFixed"
+                | "richTextUrl"
+                | "richTextEmailAddress"
+                | "richTextSubscript"
+                | "richTextSuperscript"
+                | "richTextMarked"
+                | "richTextPhoneNumber"
+                | "richTextIcon"
+                | "richTextAnchor"
+                | "richTexts" => Deserialize::deserialize(value).map(Response::RichText),
+                #[cfg(feature = "messages")]
+                "pageBlockHorizontalAlignmentLeft"
+                | "pageBlockHorizontalAlignmentCenter"
+                | "pageBlockHorizontalAlignmentRight" => {
+                    Deserialize::deserialize(value).map(Response::PageBlockHorizontalAlignment)
+                }
+                #[cfg(feature = "messages")]
+                "pageBlockVerticalAlignmentTop"
+                | "pageBlockVerticalAlignmentMiddle"
+                | "pageBlockVerticalAlignmentBottom" => {
+                    Deserialize::deserialize(value).map(Response::PageBlockVerticalAlignment)
+                }
+                #[cfg(feature = "messages")]
+                "pageBlockTitle"
+                | "pageBlockSubtitle"
+                | "pageBlockAuthorDate"
+                | "pageBlockHeader"
+                | "pageBlockSubheader"
+                | "pageBlockKicker"
+                | "pageBlockParagraph"
+                | "pageBlockPreformatted"
+                | "pageBlockFooter"
+                | "pageBlockDivider"
+                | "pageBlockAnchor"
+                | "pageBlockList"
+                | "pageBlockBlockQuote"
+                | "pageBlockPullQuote"
+                | "pageBlockAnimation"
+                | "pageBlockAudio"
+                | "pageBlockPhoto"
+                | "pageBlockVideo"
+                | "pageBlockVoiceNote"
+                | "pageBlockCover"
+                | "pageBlockEmbedded"
+                | "pageBlockEmbeddedPost"
+                | "pageBlockCollage"
+                | "pageBlockSlideshow"
+                | "pageBlockChatLink"
+                | "pageBlockTable"
+                | "pageBlockDetails"
+                | "pageBlockRelatedArticles"
+                | "pageBlockMap" => Deserialize::deserialize(value).map(Response::PageBlock),
+                #[cfg(feature = "payments")]
+                "inputCredentialsSaved"
+                | "inputCredentialsNew"
+                | "inputCredentialsAndroidPay"
+                | "inputCredentialsApplePay" => {
+                    Deserialize::deserialize(value).map(Response::InputCredentials)
+                }
+                "passportElementTypePersonalDetails"
+                | "passportElementTypePassport"
+                | "passportElementTypeDriverLicense"
+                | "passportElementTypeIdentityCard"
+                | "passportElementTypeInternalPassport"
+                | "passportElementTypeAddress"
+                | "passportElementTypeUtilityBill"
+                | "passportElementTypeBankStatement"
+                | "passportElementTypeRentalAgreement"
+                | "passportElementTypePassportRegistration"
+                | "passportElementTypeTemporaryRegistration"
+                | "passportElementTypePhoneNumber"
+                | "passportElementTypeEmailAddress" => {
+                    Deserialize::deserialize(value).map(Response::PassportElementType)
+                }
+                #[cfg(feature = "passport")]
+                "passportElementPersonalDetails"
+                | "passportElementPassport"
+                | "passportElementDriverLicense"
+                | "passportElementIdentityCard"
+                | "passportElementInternalPassport"
+                | "passportElementAddress"
+                | "passportElementUtilityBill"
+                | "passportElementBankStatement"
+                | "passportElementRentalAgreement"
+                | "passportElementPassportRegistration"
+                | "passportElementTemporaryRegistration"
+                | "passportElementPhoneNumber"
+                | "passportElementEmailAddress" => {
+                    Deserialize::deserialize(value).map(Response::PassportElement)
+                }
+                #[cfg(feature = "passport")]
+                "inputPassportElementPersonalDetails"
+                | "inputPassportElementPassport"
+                | "inputPassportElementDriverLicense"
+                | "inputPassportElementIdentityCard"
+                | "inputPassportElementInternalPassport"
+                | "inputPassportElementAddress"
+                | "inputPassportElementUtilityBill"
+                | "inputPassportElementBankStatement"
+                | "inputPassportElementRentalAgreement"
+                | "inputPassportElementPassportRegistration"
+                | "inputPassportElementTemporaryRegistration"
+                | "inputPassportElementPhoneNumber"
+                | "inputPassportElementEmailAddress" => {
+                    Deserialize::deserialize(value).map(Response::InputPassportElement)
+                }
+                #[cfg(feature = "passport")]
+                "passportElementErrorSourceUnspecified"
+                | "passportElementErrorSourceDataField"
+                | "passportElementErrorSourceFrontSide"
+                | "passportElementErrorSourceReverseSide"
+                | "passportElementErrorSourceSelfie"
+                | "passportElementErrorSourceTranslationFile"
+                | "passportElementErrorSourceTranslationFiles"
+                | "passportElementErrorSourceFile"
+                | "passportElementErrorSourceFiles" => {
+                    Deserialize::deserialize(value).map(Response::PassportElementErrorSource)
+                }
+                #[cfg(feature = "passport")]
+                "inputPassportElementErrorSourceUnspecified"
+                | "inputPassportElementErrorSourceDataField"
+                | "inputPassportElementErrorSourceFrontSide"
+                | "inputPassportElementErrorSourceReverseSide"
+                | "inputPassportElementErrorSourceSelfie"
+                | "inputPassportElementErrorSourceTranslationFile"
+                | "inputPassportElementErrorSourceTranslationFiles"
+                | "inputPassportElementErrorSourceFile"
+                | "inputPassportElementErrorSourceFiles" => {
+                    Deserialize::deserialize(value).map(Response::InputPassportElementErrorSource)
+                }
+                "messageText"
+                | "messageAnimation"
+                | "messageAudio"
+                | "messageDocument"
+                | "messagePhoto"
+                | "messageExpiredPhoto"
+                | "messageSticker"
+                | "messageVideo"
+                | "messageExpiredVideo"
+                | "messageVideoNote"
+                | "messageVoiceNote"
+                | "messageLocation"
+                | "messageVenue"
+                | "messageContact"
+                | "messageGame"
+                | "messagePoll"
+                | "messageInvoice"
+                | "messageCall"
+                | "messageBasicGroupChatCreate"
+                | "messageSupergroupChatCreate"
+                | "messageChatChangeTitle"
+                | "messageChatChangePhoto"
+                | "messageChatDeletePhoto"
+                | "messageChatAddMembers"
+                | "messageChatJoinByLink"
+                | "messageChatDeleteMember"
+                | "messageChatUpgradeTo"
+                | "messageChatUpgradeFrom"
+                | "messagePinMessage"
+                | "messageScreenshotTaken"
+                | "messageChatSetTtl"
+                | "messageCustomServiceAction"
+                | "messageGameScore"
+                | "messagePaymentSuccessful"
+                | "messagePaymentSuccessfulBot"
+                | "messageContactRegistered"
+                | "messageWebsiteConnected"
+                | "messagePassportDataSent"
+                | "messagePassportDataReceived"
+                | "messageUnsupported" => {
+                    Deserialize::deserialize(value).map(Response::MessageContent)
+                }
+                "textEntityTypeMention"
+                | "textEntityTypeHashtag"
+                | "textEntityTypeCashtag"
+                | "textEntityTypeBotCommand"
+                | "textEntityTypeUrl"
+                | "textEntityTypeEmailAddress"
+                | "textEntityTypePhoneNumber"
+                | "textEntityTypeBold"
+                | "textEntityTypeItalic"
+                | "textEntityTypeUnderline"
+                | "textEntityTypeStrikethrough"
+                | "textEntityTypeCode"
+                | "textEntityTypePre"
+                | "textEntityTypePreCode"
+                | "textEntityTypeTextUrl"
+                | "textEntityTypeMentionName" => {
+                    Deserialize::deserialize(value).map(Response::TextEntityType)
+                }
+                #[cfg(any(
+                    feature = "bots",
+                    feature = "calls",
+                    feature = "chats",
+                    feature = "messages"
+                ))]
+                "messageSchedulingStateSendAtDate" | "messageSchedulingStateSendWhenOnline" => {
+                    Deserialize::deserialize(value).map(Response::MessageSchedulingState)
+                }
+                #[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+                "inputMessageText"
+                | "inputMessageAnimation"
+                | "inputMessageAudio"
+                | "inputMessageDocument"
+                | "inputMessagePhoto"
+                | "inputMessageSticker"
+                | "inputMessageVideo"
+                | "inputMessageVideoNote"
+                | "inputMessageVoiceNote"
+                | "inputMessageLocation"
+                | "inputMessageVenue"
+                | "inputMessageContact"
+                | "inputMessageGame"
+                | "inputMessageInvoice"
+                | "inputMessagePoll"
+                | "inputMessageForwarded" => {
+                    Deserialize::deserialize(value).map(Response::InputMessageContent)
+                }
+                #[cfg(feature = "messages")]
+                "searchMessagesFilterEmpty"
+                | "searchMessagesFilterAnimation"
+                | "searchMessagesFilterAudio"
+                | "searchMessagesFilterDocument"
+                | "searchMessagesFilterPhoto"
+                | "searchMessagesFilterVideo"
+                | "searchMessagesFilterVoiceNote"
+                | "searchMessagesFilterPhotoAndVideo"
+                | "searchMessagesFilterUrl"
+                | "searchMessagesFilterChatPhoto"
+                | "searchMessagesFilterCall"
+                | "searchMessagesFilterMissedCall"
+                | "searchMessagesFilterVideoNote"
+                | "searchMessagesFilterVoiceAndVideoNote"
+                | "searchMessagesFilterMention"
+                | "searchMessagesFilterUnreadMention" => {
+                    Deserialize::deserialize(value).map(Response::SearchMessagesFilter)
+                }
+                #[cfg(feature = "chats")]
+                "chatActionTyping"
+                | "chatActionRecordingVideo"
+                | "chatActionUploadingVideo"
+                | "chatActionRecordingVoiceNote"
+                | "chatActionUploadingVoiceNote"
+                | "chatActionUploadingPhoto"
+                | "chatActionUploadingDocument"
+                | "chatActionChoosingLocation"
+                | "chatActionChoosingContact"
+                | "chatActionStartPlayingGame"
+                | "chatActionRecordingVideoNote"
+                | "chatActionUploadingVideoNote"
+                | "chatActionCancel" => Deserialize::deserialize(value).map(Response::ChatAction),
+                "userStatusEmpty"
+                | "userStatusOnline"
+                | "userStatusOffline"
+                | "userStatusRecently"
+                | "userStatusLastWeek"
+                | "userStatusLastMonth" => {
+                    Deserialize::deserialize(value).map(Response::UserStatus)
+                }
+                "callDiscardReasonEmpty"
+                | "callDiscardReasonMissed"
+                | "callDiscardReasonDeclined"
+                | "callDiscardReasonDisconnected"
+                | "callDiscardReasonHungUp" => {
+                    Deserialize::deserialize(value).map(Response::CallDiscardReason)
+                }
+                #[cfg(feature = "calls")]
+                "callStatePending"
+                | "callStateExchangingKeys"
+                | "callStateReady"
+                | "callStateHangingUp"
+                | "callStateDiscarded"
+                | "callStateError" => Deserialize::deserialize(value).map(Response::CallState),
+                #[cfg(feature = "calls")]
+                "callProblemEcho"
+                | "callProblemNoise"
+                | "callProblemInterruptions"
+                | "callProblemDistortedSpeech"
+                | "callProblemSilentLocal"
+                | "callProblemSilentRemote"
+                | "callProblemDropped" => {
+                    Deserialize::deserialize(value).map(Response::CallProblem)
+                }
+                #[cfg(feature = "bots")]
+                "inputInlineQueryResultAnimatedGif"
+                | "inputInlineQueryResultAnimatedMpeg4"
+                | "inputInlineQueryResultArticle"
+                | "inputInlineQueryResultAudio"
+                | "inputInlineQueryResultContact"
+                | "inputInlineQueryResultDocument"
+                | "inputInlineQueryResultGame"
+                | "inputInlineQueryResultLocation"
+                | "inputInlineQueryResultPhoto"
+                | "inputInlineQueryResultSticker"
+                | "inputInlineQueryResultVenue"
+                | "inputInlineQueryResultVideo"
+                | "inputInlineQueryResultVoiceNote" => {
+                    Deserialize::deserialize(value).map(Response::InputInlineQueryResult)
+                }
+                #[cfg(feature = "bots")]
+                "inlineQueryResultArticle"
+                | "inlineQueryResultContact"
+                | "inlineQueryResultLocation"
+                | "inlineQueryResultVenue"
+                | "inlineQueryResultGame"
+                | "inlineQueryResultAnimation"
+                | "inlineQueryResultAudio"
+                | "inlineQueryResultDocument"
+                | "inlineQueryResultPhoto"
+                | "inlineQueryResultSticker"
+                | "inlineQueryResultVideo"
+                | "inlineQueryResultVoiceNote" => {
+                    Deserialize::deserialize(value).map(Response::InlineQueryResult)
+                }
+                #[cfg(feature = "bots")]
+                "callbackQueryPayloadData" | "callbackQueryPayloadGame" => {
+                    Deserialize::deserialize(value).map(Response::CallbackQueryPayload)
+                }
+                #[cfg(feature = "chats")]
+                "chatEventMessageEdited"
+                | "chatEventMessageDeleted"
+                | "chatEventPollStopped"
+                | "chatEventMessagePinned"
+                | "chatEventMessageUnpinned"
+                | "chatEventMemberJoined"
+                | "chatEventMemberLeft"
+                | "chatEventMemberInvited"
+                | "chatEventMemberPromoted"
+                | "chatEventMemberRestricted"
+                | "chatEventTitleChanged"
+                | "chatEventPermissionsChanged"
+                | "chatEventDescriptionChanged"
+                | "chatEventUsernameChanged"
+                | "chatEventPhotoChanged"
+                | "chatEventInvitesToggled"
+                | "chatEventLinkedChatChanged"
+                | "chatEventSlowModeDelayChanged"
+                | "chatEventSignMessagesToggled"
+                | "chatEventStickerSetChanged"
+                | "chatEventLocationChanged"
+                | "chatEventIsAllHistoryAvailableToggled" => {
+                    Deserialize::deserialize(value).map(Response::ChatEventAction)
+                }
+                "languagePackStringValueOrdinary"
+                | "languagePackStringValuePluralized"
+                | "languagePackStringValueDeleted" => {
+                    Deserialize::deserialize(value).map(Response::LanguagePackStringValue)
+                }
+                "deviceTokenFirebaseCloudMessaging"
+                | "deviceTokenApplePush"
+                | "deviceTokenApplePushVoIP"
+                | "deviceTokenWindowsPush"
+                | "deviceTokenMicrosoftPush"
+                | "deviceTokenMicrosoftPushVoIP"
+                | "deviceTokenWebPush"
+                | "deviceTokenSimplePush"
+                | "deviceTokenUbuntuPush"
+                | "deviceTokenBlackBerryPush"
+                | "deviceTokenTizenPush" => {
+                    Deserialize::deserialize(value).map(Response::DeviceToken)
+                }
+                "backgroundFillSolid" | "backgroundFillGradient" => {
+                    Deserialize::deserialize(value).map(Response::BackgroundFill)
+                }
+                "backgroundTypeWallpaper" | "backgroundTypePattern" | "backgroundTypeFill" => {
+                    Deserialize::deserialize(value).map(Response::BackgroundType)
+                }
+                "inputBackgroundLocal" | "inputBackgroundRemote" => {
+                    Deserialize::deserialize(value).map(Response::InputBackground)
+                }
+                "canTransferOwnershipResultOk"
+                | "canTransferOwnershipResultPasswordNeeded"
+                | "canTransferOwnershipResultPasswordTooFresh"
+                | "canTransferOwnershipResultSessionTooFresh" => {
+                    Deserialize::deserialize(value).map(Response::CanTransferOwnershipResult)
+                }
+                #[cfg(feature = "chats")]
+                "checkChatUsernameResultOk"
+                | "checkChatUsernameResultUsernameInvalid"
+                | "checkChatUsernameResultUsernameOccupied"
+                | "checkChatUsernameResultPublicChatsTooMuch"
+                | "checkChatUsernameResultPublicGroupsUnavailable" => {
+                    Deserialize::deserialize(value).map(Response::CheckChatUsernameResult)
+                }
+                #[cfg(feature = "messages")]
+                "pushMessageContentHidden"
+                | "pushMessageContentAnimation"
+                | "pushMessageContentAudio"
+                | "pushMessageContentContact"
+                | "pushMessageContentContactRegistered"
+                | "pushMessageContentDocument"
+                | "pushMessageContentGame"
+                | "pushMessageContentGameScore"
+                | "pushMessageContentInvoice"
+                | "pushMessageContentLocation"
+                | "pushMessageContentPhoto"
+                | "pushMessageContentPoll"
+                | "pushMessageContentScreenshotTaken"
+                | "pushMessageContentSticker"
+                | "pushMessageContentText"
+                | "pushMessageContentVideo"
+                | "pushMessageContentVideoNote"
+                | "pushMessageContentVoiceNote"
+                | "pushMessageContentBasicGroupChatCreate"
+                | "pushMessageContentChatAddMembers"
+                | "pushMessageContentChatChangePhoto"
+                | "pushMessageContentChatChangeTitle"
+                | "pushMessageContentChatDeleteMember"
+                | "pushMessageContentChatJoinByLink"
+                | "pushMessageContentMessageForwards"
+                | "pushMessageContentMediaAlbum" => {
+                    Deserialize::deserialize(value).map(Response::PushMessageContent)
+                }
+                #[cfg(feature = "messages")]
+                "notificationTypeNewMessage" => {
+                    Deserialize::deserialize(value).map(Response::NotificationType)
+                }
+                #[cfg(feature = "chats")]
+                "notificationTypeNewSecretChat" => {
+                    Deserialize::deserialize(value).map(Response::NotificationType)
+                }
+                #[cfg(feature = "calls")]
+                "notificationTypeNewCall" => {
+                    Deserialize::deserialize(value).map(Response::NotificationType)
+                }
+                #[cfg(feature = "messages")]
+                "notificationTypeNewPushMessage" => {
+                    Deserialize::deserialize(value).map(Response::NotificationType)
+                }
+                "notificationGroupTypeMessages"
+                | "notificationGroupTypeMentions"
+                | "notificationGroupTypeSecretChat"
+                | "notificationGroupTypeCalls" => {
+                    Deserialize::deserialize(value).map(Response::NotificationGroupType)
+                }
+                "optionValueBoolean" | "optionValueEmpty" | "optionValueInteger"
+                | "optionValueString" => Deserialize::deserialize(value).map(Response::OptionValue),
+                "jsonValueNull" | "jsonValueBoolean" | "jsonValueNumber" | "jsonValueString"
+                | "jsonValueArray" | "jsonValueObject" => {
+                    Deserialize::deserialize(value).map(Response::JsonValue)
+                }
+                "userPrivacySettingRuleAllowAll"
+                | "userPrivacySettingRuleAllowContacts"
+                | "userPrivacySettingRuleAllowUsers"
+                | "userPrivacySettingRuleAllowChatMembers"
+                | "userPrivacySettingRuleRestrictAll"
+                | "userPrivacySettingRuleRestrictContacts"
+                | "userPrivacySettingRuleRestrictUsers"
+                | "userPrivacySettingRuleRestrictChatMembers" => {
+                    Deserialize::deserialize(value).map(Response::UserPrivacySettingRule)
+                }
+                "userPrivacySettingShowStatus"
+                | "userPrivacySettingShowProfilePhoto"
+                | "userPrivacySettingShowLinkInForwardedMessages"
+                | "userPrivacySettingShowPhoneNumber"
+                | "userPrivacySettingAllowChatInvites"
+                | "userPrivacySettingAllowCalls"
+                | "userPrivacySettingAllowPeerToPeerCalls"
+                | "userPrivacySettingAllowFindingByPhoneNumber" => {
+                    Deserialize::deserialize(value).map(Response::UserPrivacySetting)
+                }
+                #[cfg(feature = "chats")]
+                "chatReportReasonSpam"
+                | "chatReportReasonViolence"
+                | "chatReportReasonPornography"
+                | "chatReportReasonChildAbuse"
+                | "chatReportReasonCopyright"
+                | "chatReportReasonUnrelatedLocation"
+                | "chatReportReasonCustom" => {
+                    Deserialize::deserialize(value).map(Response::ChatReportReason)
+                }
+                #[cfg(feature = "files")]
+                "fileTypeNone"
+                | "fileTypeAnimation"
+                | "fileTypeAudio"
+                | "fileTypeDocument"
+                | "fileTypePhoto"
+                | "fileTypeProfilePhoto"
+                | "fileTypeSecret"
+                | "fileTypeSecretThumbnail"
+                | "fileTypeSecure"
+                | "fileTypeSticker"
+                | "fileTypeThumbnail"
+                | "fileTypeUnknown"
+                | "fileTypeVideo"
+                | "fileTypeVideoNote"
+                | "fileTypeVoiceNote"
+                | "fileTypeWallpaper" => Deserialize::deserialize(value).map(Response::FileType),
+                "networkTypeNone"
+                | "networkTypeMobile"
+                | "networkTypeMobileRoaming"
+                | "networkTypeWiFi"
+                | "networkTypeOther" => Deserialize::deserialize(value).map(Response::NetworkType),
+                #[cfg(feature = "files")]
+                "networkStatisticsEntryFile" | "networkStatisticsEntryCall" => {
+                    Deserialize::deserialize(value).map(Response::NetworkStatisticsEntry)
+                }
+                "connectionStateWaitingForNetwork"
+                | "connectionStateConnectingToProxy"
+                | "connectionStateConnecting"
+                | "connectionStateUpdating"
+                | "connectionStateReady" => {
+                    Deserialize::deserialize(value).map(Response::ConnectionState)
+                }
+                #[cfg(feature = "chats")]
+                "topChatCategoryUsers"
+                | "topChatCategoryBots"
+                | "topChatCategoryGroups"
+                | "topChatCategoryChannels"
+                | "topChatCategoryInlineBots"
+                | "topChatCategoryCalls"
+                | "topChatCategoryForwardChats" => {
+                    Deserialize::deserialize(value).map(Response::TopChatCategory)
+                }
+                "tMeUrlTypeUser"
+                | "tMeUrlTypeSupergroup"
+                | "tMeUrlTypeChatInvite"
+                | "tMeUrlTypeStickerSet" => {
+                    Deserialize::deserialize(value).map(Response::TMeUrlType)
+                }
+                #[cfg(feature = "messages")]
+                "textParseModeMarkdown" | "textParseModeHTML" => {
+                    Deserialize::deserialize(value).map(Response::TextParseMode)
+                }
+                #[cfg(feature = "proxy")]
+                "proxyTypeSocks5" | "proxyTypeHttp" | "proxyTypeMtproto" => {
+                    Deserialize::deserialize(value).map(Response::ProxyType)
+                }
+                #[cfg(feature = "logging")]
+                "logStreamDefault" | "logStreamFile" | "logStreamEmpty" => {
+                    Deserialize::deserialize(value).map(Response::LogStream)
+                }
+                _ => Unknown::deserialize(value).map(Response::Unknown),
+            }
+            .map_err(serde::de::Error::custom)
+        }
+    }
+    impl std::convert::TryFrom<Response> for Error {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Error(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Ok {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Ok(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for TdlibParameters {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TdlibParameters(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for AuthenticationCodeInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::AuthenticationCodeInfo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(feature = "auth", feature = "passport"))]
+    impl std::convert::TryFrom<Response> for EmailAddressAuthenticationCodeInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::EmailAddressAuthenticationCodeInfo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for TextEntity {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TextEntity(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for TextEntities {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TextEntities(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for FormattedText {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::FormattedText(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for TermsOfService {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TermsOfService(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "auth")]
+    impl std::convert::TryFrom<Response> for PasswordState {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PasswordState(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "auth")]
+    impl std::convert::TryFrom<Response> for RecoveryEmailAddress {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::RecoveryEmailAddress(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "auth")]
+    impl std::convert::TryFrom<Response> for TemporaryPasswordState {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TemporaryPasswordState(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for LocalFile {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::LocalFile(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for RemoteFile {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::RemoteFile(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for File {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::File(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for PhotoSize {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PhotoSize(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Minithumbnail {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Minithumbnail(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for MaskPosition {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::MaskPosition(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for PollOption {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PollOption(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Animation {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Animation(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Audio {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Audio(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Document {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Document(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Photo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Photo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Sticker {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Sticker(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Video {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Video(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for VideoNote {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::VideoNote(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for VoiceNote {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::VoiceNote(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Contact {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Contact(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Location {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Location(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Venue {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Venue(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Game {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Game(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Poll {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Poll(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for ProfilePhoto {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ProfilePhoto(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for ChatPhoto {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatPhoto(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for BotCommand {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::BotCommand(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for BotInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::BotInfo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatLocation {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatLocation(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for User {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::User(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UserFullInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UserFullInfo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UserProfilePhoto {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UserProfilePhoto(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UserProfilePhotos {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UserProfilePhotos(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Users {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Users(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatAdministrator {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatAdministrator(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatAdministrators {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatAdministrators(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatPermissions {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatPermissions(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatMember {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatMember(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatMembers {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatMembers(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for BasicGroup {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::BasicGroup(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for BasicGroupFullInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::BasicGroupFullInfo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for Supergroup {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Supergroup(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for SupergroupFullInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::SupergroupFullInfo(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for SecretChat {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::SecretChat(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    impl std::convert::TryFrom<Response> for MessageForwardInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::MessageForwardInfo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    impl std::convert::TryFrom<Response> for Message {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Message(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(feature = "calls", feature = "messages"))]
+    impl std::convert::TryFrom<Response> for Messages {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Messages(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for FoundMessages {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::FoundMessages(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatNotificationSettings {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatNotificationSettings(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for ScopeNotificationSettings {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ScopeNotificationSettings(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(feature = "chats", feature = "messages"))]
+    impl std::convert::TryFrom<Response> for DraftMessage {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::DraftMessage(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for Chat {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Chat(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for Chats {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Chats(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for ChatNearby {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatNearby(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatsNearby {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatsNearby(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatInviteLink {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatInviteLink(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for ChatInviteLinkInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatInviteLinkInfo(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    impl std::convert::TryFrom<Response> for KeyboardButton {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::KeyboardButton(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    impl std::convert::TryFrom<Response> for InlineKeyboardButton {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InlineKeyboardButton(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for PageBlockCaption {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PageBlockCaption(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for PageBlockListItem {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PageBlockListItem(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for PageBlockTableCell {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PageBlockTableCell(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for PageBlockRelatedArticle {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PageBlockRelatedArticle(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for WebPageInstantView {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::WebPageInstantView(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for WebPage {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::WebPage(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Address {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Address(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(
+        feature = "bots",
+        feature = "chats",
+        feature = "messages",
+        feature = "payments"
+    ))]
+    impl std::convert::TryFrom<Response> for LabeledPricePart {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::LabeledPricePart(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(
+        feature = "bots",
+        feature = "chats",
+        feature = "messages",
+        feature = "payments"
+    ))]
+    impl std::convert::TryFrom<Response> for Invoice {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Invoice(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for OrderInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::OrderInfo(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "payments")]
+    impl std::convert::TryFrom<Response> for ShippingOption {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ShippingOption(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "payments")]
+    impl std::convert::TryFrom<Response> for SavedCredentials {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::SavedCredentials(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "payments")]
+    impl std::convert::TryFrom<Response> for PaymentsProviderStripe {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PaymentsProviderStripe(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "payments")]
+    impl std::convert::TryFrom<Response> for PaymentForm {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PaymentForm(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "payments")]
+    impl std::convert::TryFrom<Response> for ValidatedOrderInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ValidatedOrderInfo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "payments")]
+    impl std::convert::TryFrom<Response> for PaymentResult {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PaymentResult(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "payments")]
+    impl std::convert::TryFrom<Response> for PaymentReceipt {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PaymentReceipt(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for DatedFile {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::DatedFile(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for Date {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Date(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for PersonalDetails {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PersonalDetails(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for IdentityDocument {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::IdentityDocument(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for InputIdentityDocument {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InputIdentityDocument(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for PersonalDocument {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PersonalDocument(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for InputPersonalDocument {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InputPersonalDocument(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for PassportElements {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PassportElements(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for PassportElementError {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PassportElementError(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for PassportSuitableElement {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PassportSuitableElement(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for PassportRequiredElement {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PassportRequiredElement(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for PassportAuthorizationForm {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PassportAuthorizationForm(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for PassportElementsWithErrors {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PassportElementsWithErrors(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for EncryptedCredentials {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::EncryptedCredentials(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for EncryptedPassportElement {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::EncryptedPassportElement(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for InputPassportElementError {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InputPassportElementError(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for InputThumbnail {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InputThumbnail(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(feature = "bots", feature = "messages"))]
+    impl std::convert::TryFrom<Response> for SendMessageOptions {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::SendMessageOptions(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "stickers")]
+    impl std::convert::TryFrom<Response> for Stickers {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Stickers(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "stickers")]
+    impl std::convert::TryFrom<Response> for Emojis {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Emojis(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "stickers")]
+    impl std::convert::TryFrom<Response> for StickerSet {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::StickerSet(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "stickers")]
+    impl std::convert::TryFrom<Response> for StickerSetInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::StickerSetInfo(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "stickers")]
+    impl std::convert::TryFrom<Response> for StickerSets {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::StickerSets(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "calls")]
+    impl std::convert::TryFrom<Response> for CallProtocol {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::CallProtocol(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "calls")]
+    impl std::convert::TryFrom<Response> for CallConnection {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::CallConnection(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "calls")]
+    impl std::convert::TryFrom<Response> for CallId {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::CallId(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "calls")]
+    impl std::convert::TryFrom<Response> for Call {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Call(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(feature = "auth", feature = "passport"))]
+    impl std::convert::TryFrom<Response> for PhoneNumberAuthenticationSettings {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PhoneNumberAuthenticationSettings(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Animations {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Animations(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for ImportedContacts {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ImportedContacts(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for HttpUrl {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::HttpUrl(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for InlineQueryResults {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InlineQueryResults(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for CallbackQueryAnswer {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::CallbackQueryAnswer(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for CustomRequestResult {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::CustomRequestResult(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for GameHighScore {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::GameHighScore(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for GameHighScores {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::GameHighScores(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatEvent {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatEvent(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatEvents {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatEvents(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatEventLogFilters {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatEventLogFilters(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for LanguagePackString {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::LanguagePackString(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for LanguagePackStrings {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::LanguagePackStrings(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for LanguagePackInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::LanguagePackInfo(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for LocalizationTargetInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::LocalizationTargetInfo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for PushReceiverId {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PushReceiverId(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Background {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Background(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Backgrounds {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Backgrounds(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Hashtags {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Hashtags(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Notification {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Notification(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for NotificationGroup {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::NotificationGroup(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for JsonObjectMember {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::JsonObjectMember(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UserPrivacySettingRules {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UserPrivacySettingRules(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "auth")]
+    impl std::convert::TryFrom<Response> for AccountTtl {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::AccountTtl(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "auth")]
+    impl std::convert::TryFrom<Response> for Session {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Session(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "auth")]
+    impl std::convert::TryFrom<Response> for Sessions {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Sessions(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "auth")]
+    impl std::convert::TryFrom<Response> for ConnectedWebsite {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ConnectedWebsite(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "auth")]
+    impl std::convert::TryFrom<Response> for ConnectedWebsites {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ConnectedWebsites(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for PublicMessageLink {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PublicMessageLink(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for MessageLinkInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::MessageLinkInfo(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for FilePart {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::FilePart(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for StorageStatisticsByFileType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::StorageStatisticsByFileType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for StorageStatisticsByChat {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::StorageStatisticsByChat(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for StorageStatistics {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::StorageStatistics(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for StorageStatisticsFast {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::StorageStatisticsFast(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for DatabaseStatistics {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::DatabaseStatistics(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for NetworkStatistics {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::NetworkStatistics(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for AutoDownloadSettings {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::AutoDownloadSettings(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for AutoDownloadSettingsPresets {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::AutoDownloadSettingsPresets(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for TMeUrl {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TMeUrl(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for TMeUrls {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TMeUrls(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Count {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Count(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Text {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Text(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Seconds {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Seconds(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for DeepLinkInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::DeepLinkInfo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "proxy")]
+    impl std::convert::TryFrom<Response> for Proxy {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Proxy(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "proxy")]
+    impl std::convert::TryFrom<Response> for Proxies {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Proxies(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "stickers")]
+    impl std::convert::TryFrom<Response> for InputSticker {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InputSticker(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Updates {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::Updates(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "logging")]
+    impl std::convert::TryFrom<Response> for LogVerbosityLevel {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::LogVerbosityLevel(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "logging")]
+    impl std::convert::TryFrom<Response> for LogTags {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::LogTags(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "tests")]
+    impl std::convert::TryFrom<Response> for TestInt {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TestInt(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "tests")]
+    impl std::convert::TryFrom<Response> for TestString {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TestString(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "tests")]
+    impl std::convert::TryFrom<Response> for TestBytes {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TestBytes(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "tests")]
+    impl std::convert::TryFrom<Response> for TestVectorInt {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TestVectorInt(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "tests")]
+    impl std::convert::TryFrom<Response> for TestVectorIntObject {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TestVectorIntObject(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "tests")]
+    impl std::convert::TryFrom<Response> for TestVectorString {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TestVectorString(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "tests")]
+    impl std::convert::TryFrom<Response> for TestVectorStringObject {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TestVectorStringObject(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateAuthorizationState {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateAuthorizationState(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateNewMessage {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateNewMessage(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateMessageSendAcknowledged {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateMessageSendAcknowledged(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateMessageSendSucceeded {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateMessageSendSucceeded(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateMessageSendFailed {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateMessageSendFailed(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateMessageContent {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateMessageContent(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateMessageEdited {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateMessageEdited(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateMessageViews {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateMessageViews(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateMessageContentOpened {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateMessageContentOpened(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateMessageMentionRead {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateMessageMentionRead(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateMessageLiveLocationViewed {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateMessageLiveLocationViewed(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateNewChat {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateNewChat(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatChatList {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatChatList(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatTitle {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatTitle(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatPhoto {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatPhoto(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatPermissions {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatPermissions(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateChatLastMessage {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatLastMessage(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatOrder {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatOrder(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatIsPinned {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatIsPinned(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatIsMarkedAsUnread {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatIsMarkedAsUnread(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatIsSponsored {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatIsSponsored(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateChatHasScheduledMessages {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatHasScheduledMessages(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatDefaultDisableNotification {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatDefaultDisableNotification(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatReadInbox {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatReadInbox(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatReadOutbox {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatReadOutbox(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatUnreadMentionCount {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatUnreadMentionCount(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatNotificationSettings {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatNotificationSettings(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateScopeNotificationSettings {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateScopeNotificationSettings(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatActionBar {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatActionBar(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateChatPinnedMessage {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatPinnedMessage(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for UpdateChatReplyMarkup {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatReplyMarkup(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateChatDraftMessage {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatDraftMessage(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateChatOnlineMemberCount {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateChatOnlineMemberCount(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateNotification {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateNotification(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateNotificationGroup {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateNotificationGroup(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateActiveNotifications {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateActiveNotifications(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateHavePendingNotifications {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateHavePendingNotifications(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateDeleteMessages {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateDeleteMessages(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateUserChatAction {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateUserChatAction(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateUserStatus {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateUserStatus(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateUser {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateUser(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateBasicGroup {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateBasicGroup(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateSupergroup {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateSupergroup(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateSecretChat {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateSecretChat(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateUserFullInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateUserFullInfo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateBasicGroupFullInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateBasicGroupFullInfo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateSupergroupFullInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateSupergroupFullInfo(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateServiceNotification {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateServiceNotification(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for UpdateFile {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateFile(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for UpdateFileGenerationStart {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateFileGenerationStart(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for UpdateFileGenerationStop {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateFileGenerationStop(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "calls")]
+    impl std::convert::TryFrom<Response> for UpdateCall {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateCall(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateUserPrivacySettingRules {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateUserPrivacySettingRules(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdateUnreadMessageCount {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateUnreadMessageCount(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for UpdateUnreadChatCount {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateUnreadChatCount(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateOption {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateOption(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "stickers")]
+    impl std::convert::TryFrom<Response> for UpdateInstalledStickerSets {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateInstalledStickerSets(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "stickers")]
+    impl std::convert::TryFrom<Response> for UpdateTrendingStickerSets {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateTrendingStickerSets(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "stickers")]
+    impl std::convert::TryFrom<Response> for UpdateRecentStickers {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateRecentStickers(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "stickers")]
+    impl std::convert::TryFrom<Response> for UpdateFavoriteStickers {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateFavoriteStickers(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "stickers")]
+    impl std::convert::TryFrom<Response> for UpdateSavedAnimations {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateSavedAnimations(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateSelectedBackground {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateSelectedBackground(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateLanguagePackStrings {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateLanguagePackStrings(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateConnectionState {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateConnectionState(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "auth")]
+    impl std::convert::TryFrom<Response> for UpdateTermsOfService {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateTermsOfService(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UpdateUsersNearby {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateUsersNearby(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for UpdateNewInlineQuery {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateNewInlineQuery(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for UpdateNewChosenInlineResult {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateNewChosenInlineResult(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for UpdateNewCallbackQuery {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateNewCallbackQuery(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for UpdateNewInlineCallbackQuery {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateNewInlineCallbackQuery(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "payments")]
+    impl std::convert::TryFrom<Response> for UpdateNewShippingQuery {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateNewShippingQuery(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "payments")]
+    impl std::convert::TryFrom<Response> for UpdateNewPreCheckoutQuery {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateNewPreCheckoutQuery(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for UpdateNewCustomEvent {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateNewCustomEvent(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for UpdateNewCustomQuery {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateNewCustomQuery(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdatePoll {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdatePoll(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for UpdatePollAnswer {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdatePollAnswer(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for AuthenticationCodeType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::AuthenticationCodeType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for AuthorizationState {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::AuthorizationState(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for InputFile {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InputFile(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for MaskPoint {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::MaskPoint(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for PollType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PollType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UserType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UserType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatMemberStatus {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatMemberStatus(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatMembersFilter {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatMembersFilter(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for SupergroupMembersFilter {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::SupergroupMembersFilter(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for SecretChatState {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::SecretChatState(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    impl std::convert::TryFrom<Response> for MessageForwardOrigin {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::MessageForwardOrigin(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    impl std::convert::TryFrom<Response> for MessageSendingState {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::MessageSendingState(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for NotificationSettingsScope {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::NotificationSettingsScope(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for ChatType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(feature = "chats", feature = "messages"))]
+    impl std::convert::TryFrom<Response> for ChatList {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatList(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for PublicChatType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PublicChatType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatActionBar {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatActionBar(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    impl std::convert::TryFrom<Response> for KeyboardButtonType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::KeyboardButtonType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    impl std::convert::TryFrom<Response> for InlineKeyboardButtonType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InlineKeyboardButtonType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    impl std::convert::TryFrom<Response> for ReplyMarkup {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ReplyMarkup(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for LoginUrlInfo {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::LoginUrlInfo(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for RichText {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::RichText(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for PageBlockHorizontalAlignment {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PageBlockHorizontalAlignment(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for PageBlockVerticalAlignment {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PageBlockVerticalAlignment(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for PageBlock {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PageBlock(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "payments")]
+    impl std::convert::TryFrom<Response> for InputCredentials {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InputCredentials(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for PassportElementType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PassportElementType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for PassportElement {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PassportElement(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for InputPassportElement {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InputPassportElement(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for PassportElementErrorSource {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PassportElementErrorSource(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "passport")]
+    impl std::convert::TryFrom<Response> for InputPassportElementErrorSource {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InputPassportElementErrorSource(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for MessageContent {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::MessageContent(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for TextEntityType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TextEntityType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    impl std::convert::TryFrom<Response> for MessageSchedulingState {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::MessageSchedulingState(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+    impl std::convert::TryFrom<Response> for InputMessageContent {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InputMessageContent(x) => Ok(*x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for SearchMessagesFilter {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::SearchMessagesFilter(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatAction {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatAction(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UserStatus {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UserStatus(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for CallDiscardReason {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::CallDiscardReason(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "calls")]
+    impl std::convert::TryFrom<Response> for CallState {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::CallState(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "calls")]
+    impl std::convert::TryFrom<Response> for CallProblem {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::CallProblem(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for InputInlineQueryResult {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InputInlineQueryResult(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for InlineQueryResult {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InlineQueryResult(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "bots")]
+    impl std::convert::TryFrom<Response> for CallbackQueryPayload {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::CallbackQueryPayload(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatEventAction {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatEventAction(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for LanguagePackStringValue {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::LanguagePackStringValue(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for DeviceToken {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::DeviceToken(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for BackgroundFill {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::BackgroundFill(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for BackgroundType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::BackgroundType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for InputBackground {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::InputBackground(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for CanTransferOwnershipResult {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::CanTransferOwnershipResult(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for CheckChatUsernameResult {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::CheckChatUsernameResult(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for PushMessageContent {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::PushMessageContent(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for NotificationType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::NotificationType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for NotificationGroupType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::NotificationGroupType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for OptionValue {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::OptionValue(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for JsonValue {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::JsonValue(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UserPrivacySettingRule {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UserPrivacySettingRule(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for UserPrivacySetting {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UserPrivacySetting(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for ChatReportReason {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ChatReportReason(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for FileType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::FileType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for NetworkType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::NetworkType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "files")]
+    impl std::convert::TryFrom<Response> for NetworkStatisticsEntry {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::NetworkStatisticsEntry(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for ConnectionState {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ConnectionState(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "chats")]
+    impl std::convert::TryFrom<Response> for TopChatCategory {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TopChatCategory(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for TMeUrlType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TMeUrlType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "messages")]
+    impl std::convert::TryFrom<Response> for TextParseMode {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::TextParseMode(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "proxy")]
+    impl std::convert::TryFrom<Response> for ProxyType {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::ProxyType(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    #[cfg(feature = "logging")]
+    impl std::convert::TryFrom<Response> for LogStream {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::LogStream(x) => Ok(x),
+                response => Err(response),
+            }
+        }
+    }
+    impl std::convert::TryFrom<Response> for Update {
+        type Error = Response;
+        fn try_from(response: Response) -> Result<Self, Self::Error> {
+            match response {
+                Response::UpdateAuthorizationState(x) => Ok(Update::UpdateAuthorizationState(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdateNewMessage(x) => Ok(Update::UpdateNewMessage(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageSendAcknowledged(x) => {
+                    Ok(Update::UpdateMessageSendAcknowledged(x))
+                }
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageSendSucceeded(x) => {
+                    Ok(Update::UpdateMessageSendSucceeded(x))
+                }
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageSendFailed(x) => Ok(Update::UpdateMessageSendFailed(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageContent(x) => Ok(Update::UpdateMessageContent(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageEdited(x) => Ok(Update::UpdateMessageEdited(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageViews(x) => Ok(Update::UpdateMessageViews(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageContentOpened(x) => {
+                    Ok(Update::UpdateMessageContentOpened(x))
+                }
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageMentionRead(x) => Ok(Update::UpdateMessageMentionRead(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdateMessageLiveLocationViewed(x) => {
+                    Ok(Update::UpdateMessageLiveLocationViewed(x))
+                }
+                #[cfg(feature = "chats")]
+                Response::UpdateNewChat(x) => Ok(Update::UpdateNewChat(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatChatList(x) => Ok(Update::UpdateChatChatList(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatTitle(x) => Ok(Update::UpdateChatTitle(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatPhoto(x) => Ok(Update::UpdateChatPhoto(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatPermissions(x) => Ok(Update::UpdateChatPermissions(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdateChatLastMessage(x) => Ok(Update::UpdateChatLastMessage(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatOrder(x) => Ok(Update::UpdateChatOrder(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatIsPinned(x) => Ok(Update::UpdateChatIsPinned(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatIsMarkedAsUnread(x) => {
+                    Ok(Update::UpdateChatIsMarkedAsUnread(x))
+                }
+                #[cfg(feature = "chats")]
+                Response::UpdateChatIsSponsored(x) => Ok(Update::UpdateChatIsSponsored(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdateChatHasScheduledMessages(x) => {
+                    Ok(Update::UpdateChatHasScheduledMessages(x))
+                }
+                #[cfg(feature = "chats")]
+                Response::UpdateChatDefaultDisableNotification(x) => {
+                    Ok(Update::UpdateChatDefaultDisableNotification(x))
+                }
+                #[cfg(feature = "chats")]
+                Response::UpdateChatReadInbox(x) => Ok(Update::UpdateChatReadInbox(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatReadOutbox(x) => Ok(Update::UpdateChatReadOutbox(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatUnreadMentionCount(x) => {
+                    Ok(Update::UpdateChatUnreadMentionCount(x))
+                }
+                #[cfg(feature = "chats")]
+                Response::UpdateChatNotificationSettings(x) => {
+                    Ok(Update::UpdateChatNotificationSettings(x))
+                }
+                Response::UpdateScopeNotificationSettings(x) => {
+                    Ok(Update::UpdateScopeNotificationSettings(x))
+                }
+                #[cfg(feature = "chats")]
+                Response::UpdateChatActionBar(x) => Ok(Update::UpdateChatActionBar(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdateChatPinnedMessage(x) => Ok(Update::UpdateChatPinnedMessage(x)),
+                #[cfg(feature = "bots")]
+                Response::UpdateChatReplyMarkup(x) => Ok(Update::UpdateChatReplyMarkup(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdateChatDraftMessage(x) => Ok(Update::UpdateChatDraftMessage(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateChatOnlineMemberCount(x) => {
+                    Ok(Update::UpdateChatOnlineMemberCount(x))
+                }
+                Response::UpdateNotification(x) => Ok(Update::UpdateNotification(x)),
+                Response::UpdateNotificationGroup(x) => Ok(Update::UpdateNotificationGroup(x)),
+                Response::UpdateActiveNotifications(x) => Ok(Update::UpdateActiveNotifications(x)),
+                Response::UpdateHavePendingNotifications(x) => {
+                    Ok(Update::UpdateHavePendingNotifications(x))
+                }
+                #[cfg(feature = "messages")]
+                Response::UpdateDeleteMessages(x) => Ok(Update::UpdateDeleteMessages(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateUserChatAction(x) => Ok(Update::UpdateUserChatAction(x)),
+                Response::UpdateUserStatus(x) => Ok(Update::UpdateUserStatus(x)),
+                Response::UpdateUser(x) => Ok(Update::UpdateUser(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateBasicGroup(x) => Ok(Update::UpdateBasicGroup(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateSupergroup(x) => Ok(Update::UpdateSupergroup(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateSecretChat(x) => Ok(Update::UpdateSecretChat(x)),
+                Response::UpdateUserFullInfo(x) => Ok(Update::UpdateUserFullInfo(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateBasicGroupFullInfo(x) => Ok(Update::UpdateBasicGroupFullInfo(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateSupergroupFullInfo(x) => Ok(Update::UpdateSupergroupFullInfo(x)),
+                Response::UpdateServiceNotification(x) => Ok(Update::UpdateServiceNotification(x)),
+                #[cfg(feature = "files")]
+                Response::UpdateFile(x) => Ok(Update::UpdateFile(x)),
+                #[cfg(feature = "files")]
+                Response::UpdateFileGenerationStart(x) => Ok(Update::UpdateFileGenerationStart(x)),
+                #[cfg(feature = "files")]
+                Response::UpdateFileGenerationStop(x) => Ok(Update::UpdateFileGenerationStop(x)),
+                #[cfg(feature = "calls")]
+                Response::UpdateCall(x) => Ok(Update::UpdateCall(x)),
+                Response::UpdateUserPrivacySettingRules(x) => {
+                    Ok(Update::UpdateUserPrivacySettingRules(x))
+                }
+                #[cfg(feature = "messages")]
+                Response::UpdateUnreadMessageCount(x) => Ok(Update::UpdateUnreadMessageCount(x)),
+                #[cfg(feature = "chats")]
+                Response::UpdateUnreadChatCount(x) => Ok(Update::UpdateUnreadChatCount(x)),
+                Response::UpdateOption(x) => Ok(Update::UpdateOption(x)),
+                #[cfg(feature = "stickers")]
+                Response::UpdateInstalledStickerSets(x) => {
+                    Ok(Update::UpdateInstalledStickerSets(x))
+                }
+                #[cfg(feature = "stickers")]
+                Response::UpdateTrendingStickerSets(x) => Ok(Update::UpdateTrendingStickerSets(x)),
+                #[cfg(feature = "stickers")]
+                Response::UpdateRecentStickers(x) => Ok(Update::UpdateRecentStickers(x)),
+                #[cfg(feature = "stickers")]
+                Response::UpdateFavoriteStickers(x) => Ok(Update::UpdateFavoriteStickers(x)),
+                #[cfg(feature = "stickers")]
+                Response::UpdateSavedAnimations(x) => Ok(Update::UpdateSavedAnimations(x)),
+                Response::UpdateSelectedBackground(x) => Ok(Update::UpdateSelectedBackground(x)),
+                Response::UpdateLanguagePackStrings(x) => Ok(Update::UpdateLanguagePackStrings(x)),
+                Response::UpdateConnectionState(x) => Ok(Update::UpdateConnectionState(x)),
+                #[cfg(feature = "auth")]
+                Response::UpdateTermsOfService(x) => Ok(Update::UpdateTermsOfService(x)),
+                Response::UpdateUsersNearby(x) => Ok(Update::UpdateUsersNearby(x)),
+                #[cfg(feature = "bots")]
+                Response::UpdateNewInlineQuery(x) => Ok(Update::UpdateNewInlineQuery(x)),
+                #[cfg(feature = "bots")]
+                Response::UpdateNewChosenInlineResult(x) => {
+                    Ok(Update::UpdateNewChosenInlineResult(x))
+                }
+                #[cfg(feature = "bots")]
+                Response::UpdateNewCallbackQuery(x) => Ok(Update::UpdateNewCallbackQuery(x)),
+                #[cfg(feature = "bots")]
+                Response::UpdateNewInlineCallbackQuery(x) => {
+                    Ok(Update::UpdateNewInlineCallbackQuery(x))
+                }
+                #[cfg(feature = "payments")]
+                Response::UpdateNewShippingQuery(x) => Ok(Update::UpdateNewShippingQuery(x)),
+                #[cfg(feature = "payments")]
+                Response::UpdateNewPreCheckoutQuery(x) => Ok(Update::UpdateNewPreCheckoutQuery(x)),
+                #[cfg(feature = "bots")]
+                Response::UpdateNewCustomEvent(x) => Ok(Update::UpdateNewCustomEvent(x)),
+                #[cfg(feature = "bots")]
+                Response::UpdateNewCustomQuery(x) => Ok(Update::UpdateNewCustomQuery(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdatePoll(x) => Ok(Update::UpdatePoll(x)),
+                #[cfg(feature = "messages")]
+                Response::UpdatePollAnswer(x) => Ok(Update::UpdatePollAnswer(x)),
+                response => Err(response),
+            }
+        }
+    }
+    impl From<Update> for Response {
+        fn from(update: Update) -> Self {
+            match update {
+                Update::UpdateAuthorizationState(x) => Response::UpdateAuthorizationState(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateNewMessage(x) => Response::UpdateNewMessage(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageSendAcknowledged(x) => {
+                    Response::UpdateMessageSendAcknowledged(x)
+                }
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageSendSucceeded(x) => Response::UpdateMessageSendSucceeded(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageSendFailed(x) => Response::UpdateMessageSendFailed(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageContent(x) => Response::UpdateMessageContent(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageEdited(x) => Response::UpdateMessageEdited(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageViews(x) => Response::UpdateMessageViews(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageContentOpened(x) => Response::UpdateMessageContentOpened(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageMentionRead(x) => Response::UpdateMessageMentionRead(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateMessageLiveLocationViewed(x) => {
+                    Response::UpdateMessageLiveLocationViewed(x)
+                }
+                #[cfg(feature = "chats")]
+                Update::UpdateNewChat(x) => Response::UpdateNewChat(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateChatChatList(x) => Response::UpdateChatChatList(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateChatTitle(x) => Response::UpdateChatTitle(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateChatPhoto(x) => Response::UpdateChatPhoto(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateChatPermissions(x) => Response::UpdateChatPermissions(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateChatLastMessage(x) => Response::UpdateChatLastMessage(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateChatOrder(x) => Response::UpdateChatOrder(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateChatIsPinned(x) => Response::UpdateChatIsPinned(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateChatIsMarkedAsUnread(x) => Response::UpdateChatIsMarkedAsUnread(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateChatIsSponsored(x) => Response::UpdateChatIsSponsored(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateChatHasScheduledMessages(x) => {
+                    Response::UpdateChatHasScheduledMessages(x)
+                }
+                #[cfg(feature = "chats")]
+                Update::UpdateChatDefaultDisableNotification(x) => {
+                    Response::UpdateChatDefaultDisableNotification(x)
+                }
+                #[cfg(feature = "chats")]
+                Update::UpdateChatReadInbox(x) => Response::UpdateChatReadInbox(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateChatReadOutbox(x) => Response::UpdateChatReadOutbox(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateChatUnreadMentionCount(x) => {
+                    Response::UpdateChatUnreadMentionCount(x)
+                }
+                #[cfg(feature = "chats")]
+                Update::UpdateChatNotificationSettings(x) => {
+                    Response::UpdateChatNotificationSettings(x)
+                }
+                Update::UpdateScopeNotificationSettings(x) => {
+                    Response::UpdateScopeNotificationSettings(x)
+                }
+                #[cfg(feature = "chats")]
+                Update::UpdateChatActionBar(x) => Response::UpdateChatActionBar(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateChatPinnedMessage(x) => Response::UpdateChatPinnedMessage(x),
+                #[cfg(feature = "bots")]
+                Update::UpdateChatReplyMarkup(x) => Response::UpdateChatReplyMarkup(x),
+                #[cfg(feature = "messages")]
+                Update::UpdateChatDraftMessage(x) => Response::UpdateChatDraftMessage(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateChatOnlineMemberCount(x) => Response::UpdateChatOnlineMemberCount(x),
+                Update::UpdateNotification(x) => Response::UpdateNotification(x),
+                Update::UpdateNotificationGroup(x) => Response::UpdateNotificationGroup(x),
+                Update::UpdateActiveNotifications(x) => Response::UpdateActiveNotifications(x),
+                Update::UpdateHavePendingNotifications(x) => {
+                    Response::UpdateHavePendingNotifications(x)
+                }
+                #[cfg(feature = "messages")]
+                Update::UpdateDeleteMessages(x) => Response::UpdateDeleteMessages(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateUserChatAction(x) => Response::UpdateUserChatAction(x),
+                Update::UpdateUserStatus(x) => Response::UpdateUserStatus(x),
+                Update::UpdateUser(x) => Response::UpdateUser(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateBasicGroup(x) => Response::UpdateBasicGroup(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateSupergroup(x) => Response::UpdateSupergroup(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateSecretChat(x) => Response::UpdateSecretChat(x),
+                Update::UpdateUserFullInfo(x) => Response::UpdateUserFullInfo(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateBasicGroupFullInfo(x) => Response::UpdateBasicGroupFullInfo(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateSupergroupFullInfo(x) => Response::UpdateSupergroupFullInfo(x),
+                Update::UpdateServiceNotification(x) => Response::UpdateServiceNotification(x),
+                #[cfg(feature = "files")]
+                Update::UpdateFile(x) => Response::UpdateFile(x),
+                #[cfg(feature = "files")]
+                Update::UpdateFileGenerationStart(x) => Response::UpdateFileGenerationStart(x),
+                #[cfg(feature = "files")]
+                Update::UpdateFileGenerationStop(x) => Response::UpdateFileGenerationStop(x),
+                #[cfg(feature = "calls")]
+                Update::UpdateCall(x) => Response::UpdateCall(x),
+                Update::UpdateUserPrivacySettingRules(x) => {
+                    Response::UpdateUserPrivacySettingRules(x)
+                }
+                #[cfg(feature = "messages")]
+                Update::UpdateUnreadMessageCount(x) => Response::UpdateUnreadMessageCount(x),
+                #[cfg(feature = "chats")]
+                Update::UpdateUnreadChatCount(x) => Response::UpdateUnreadChatCount(x),
+                Update::UpdateOption(x) => Response::UpdateOption(x),
+                #[cfg(feature = "stickers")]
+                Update::UpdateInstalledStickerSets(x) => Response::UpdateInstalledStickerSets(x),
+                #[cfg(feature = "stickers")]
+                Update::UpdateTrendingStickerSets(x) => Response::UpdateTrendingStickerSets(x),
+                #[cfg(feature = "stickers")]
+                Update::UpdateRecentStickers(x) => Response::UpdateRecentStickers(x),
+                #[cfg(feature = "stickers")]
+                Update::UpdateFavoriteStickers(x) => Response::UpdateFavoriteStickers(x),
+                #[cfg(feature = "stickers")]
+                Update::UpdateSavedAnimations(x) => Response::UpdateSavedAnimations(x),
+                Update::UpdateSelectedBackground(x) => Response::UpdateSelectedBackground(x),
+                Update::UpdateLanguagePackStrings(x) => Response::UpdateLanguagePackStrings(x),
+                Update::UpdateConnectionState(x) => Response::UpdateConnectionState(x),
+                #[cfg(feature = "auth")]
+                Update::UpdateTermsOfService(x) => Response::UpdateTermsOfService(x),
+                Update::UpdateUsersNearby(x) => Response::UpdateUsersNearby(x),
+                #[cfg(feature = "bots")]
+                Update::UpdateNewInlineQuery(x) => Response::UpdateNewInlineQuery(x),
+                #[cfg(feature = "bots")]
+                Update::UpdateNewChosenInlineResult(x) => Response::UpdateNewChosenInlineResult(x),
+                #[cfg(feature = "bots")]
+                Update::UpdateNewCallbackQuery(x) => Response::UpdateNewCallbackQuery(x),
+                #[cfg(feature = "bots")]
+                Update::UpdateNewInlineCallbackQuery(x) => {
+                    Response::UpdateNewInlineCallbackQuery(x)
+                }
+                #[cfg(feature = "payments")]
+                Update::UpdateNewShippingQuery(x) => Response::UpdateNewShippingQuery(x),
+                #[cfg(feature = "payments")]
+                Update::UpdateNewPreCheckoutQuery(x) => Response::UpdateNewPreCheckoutQuery(x),
+                #[cfg(feature = "bots")]
+                Update::UpdateNewCustomEvent(x) => Response::UpdateNewCustomEvent(x),
+                #[cfg(feature = "bots")]
+                Update::UpdateNewCustomQuery(x) => Response::UpdateNewCustomQuery(x),
+                #[cfg(feature = "messages")]
+                Update::UpdatePoll(x) => Response::UpdatePoll(x),
+                #[cfg(feature = "messages")]
+                Update::UpdatePollAnswer(x) => Response::UpdatePollAnswer(x),
+                Update::Unknown(x) => Response::Unknown(x),
+            }
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An object whose @type is not known to this crate, e.g. a constructor added by a newer TDLib. Kept as raw JSON so it can be logged and serialized back unchanged"]
+    pub struct Unknown {
+        #[serde(rename = "@type")]
+        #[doc = "The TL constructor name of the object"]
+        pub type_: String,
+        #[serde(flatten)]
+        #[doc = "All the other fields of the object"]
+        pub fields: serde_json::Map<String, serde_json::Value>,
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+    #[doc = "An incoming object together with the @extra value of the request that produced it and the @client_id of the client that received it, if any. Usually wraps a Response or an Update"]
+    pub struct Envelope<T> {
+        #[serde(rename = "@extra", default, skip_serializing_if = "Option::is_none")]
+        #[doc = "The @extra value sent with the request; None for updates and requests sent without one"]
+        pub extra: Option<serde_json::Value>,
+        #[serde(
+            rename = "@client_id",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        #[doc = "Identifier of the TDLib client the object belongs to; set only by the td_receive interface"]
+        pub client_id: Option<i32>,
+        #[serde(flatten)]
+        #[doc = "The incoming object"]
+        pub payload: T,
+    }
+}
+pub mod methods {
+    use crate::error::TdError;
+    use crate::types::*;
+    use crate::validate::{self, Measure, Validate, ValidationError};
+    use serde::de::DeserializeOwned;
+    use serde::{Deserialize, Serialize};
+    use std::convert::TryFrom;
+    use std::fmt::Debug;
+    pub trait Method: Serialize + Clone + Validate {
+        const TYPE: &'static str;
+        type Response: DeserializeOwned + Debug + TryFrom<Response, Error = Response>;
+        #[doc = "Whether TDLib answers the method from its local state, without a network request. Set when the description calls it an offline method or request, except only under a condition"]
+        const OFFLINE: bool = false;
+        #[doc = "Whether the method can be called synchronously, through td_execute. Such methods implement SyncMethod as well"]
+        const SYNCHRONOUS: bool = false;
+        #[doc = "Whether the method can be called before the authorization is complete, in some states at least; AUTHORIZATION_STATES lists which"]
+        const BEFORE_AUTHORIZATION: bool = false;
+        #[doc = "Whether the description of the method says it is for bots only"]
+        const BOTS_ONLY: bool = false;
+        #[doc = "Whether the description of the method says it can't be used by bots. Few releases document that, so false doesn't mean bots can call it"]
+        const USERS_ONLY: bool = false;
+        #[doc = "The @type of the authorization states in which the method works, or None if it works in every state. Most methods work only once authorized"]
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> =
+            Some(&["authorizationStateReady"]);
+        #[doc = "Whether the method works in the authorization state with the given @type"]
+        fn works_in(state: &str) -> bool {
+            match Self::AUTHORIZATION_STATES {
+                Some(states) => states.contains(&state),
+                None => true,
+            }
+        }
+        fn tag(self) -> MethodType<Self>
+        where
+            Self: ::std::marker::Sized,
+        {
+            MethodType {
+                type_: Self::TYPE,
+                extra: None,
+                payload: self,
+            }
+        }
+        fn tag_with_extra<E>(self, extra: E) -> MethodType<Self>
+        where
+            Self: ::std::marker::Sized,
+            E: Into<serde_json::Value>,
+        {
+            self.tag().with_extra(extra)
+        }
+        #[doc = "Decodes a JSON string received from TDLib as the result of this method"]
+        fn decode_response(json: &str) -> Result<Self::Response, TdError> {
+            let response: Response = match serde_json::from_str(json) {
+                Ok(response) => response,
+                Err(err) => {
+                    return Err(match serde_json::from_str::<Unknown>(json) {
+                        Ok(object) if Response::variant_of(&object.type_).is_some() => {
+                            TdError::Malformed(object)
+                        }
+                        _ => TdError::Json(err),
+                    })
+                }
+            };
+            match response {
+                Response::Error(error) => Err(TdError::Td(error)),
+                response => Self::Response::try_from(response)
+                    .map_err(|response| TdError::UnexpectedType(Box::new(response))),
+            }
+        }
+    }
+    #[doc = "A method that can be called synchronously, through td_execute, and whose answer is returned right away"]
+    pub trait SyncMethod: Method {}
+    #[derive(Serialize, Debug, Clone)]
+    pub struct MethodType<T: Method> {
+        #[serde(rename = "@type")]
+        pub type_: &'static str,
+        #[serde(rename = "@extra", skip_serializing_if = "Option::is_none")]
+        pub extra: Option<serde_json::Value>,
+        #[serde(flatten)]
+        pub payload: T,
+    }
+    impl<T: Method> MethodType<T> {
+        #[doc = "Attaches an @extra value that TDLib will echo back in the response to this request"]
+        pub fn with_extra<E: Into<serde_json::Value>>(mut self, extra: E) -> Self {
+            self.extra = Some(extra.into());
+            self
+        }
+    }
+    #[cfg(feature = "auth")]
+    mod auth;
+    #[cfg(feature = "auth")]
+    pub use self::auth::*;
+    #[cfg(feature = "chats")]
+    mod chats;
+    #[cfg(feature = "chats")]
+    pub use self::chats::*;
+    #[cfg(feature = "messages")]
+    mod messages;
+    #[cfg(feature = "messages")]
+    pub use self::messages::*;
+    #[cfg(feature = "files")]
+    mod files;
+    #[cfg(feature = "files")]
+    pub use self::files::*;
+    #[cfg(feature = "calls")]
+    mod calls;
+    #[cfg(feature = "calls")]
+    pub use self::calls::*;
+    #[cfg(feature = "passport")]
+    mod passport;
+    #[cfg(feature = "passport")]
+    pub use self::passport::*;
+    #[cfg(feature = "payments")]
+    mod payments;
+    #[cfg(feature = "payments")]
+    pub use self::payments::*;
+    #[cfg(feature = "stickers")]
+    mod stickers;
+    #[cfg(feature = "stickers")]
+    pub use self::stickers::*;
+    #[cfg(feature = "bots")]
+    mod bots;
+    #[cfg(feature = "bots")]
+    pub use self::bots::*;
+    #[cfg(feature = "proxy")]
+    mod proxy;
+    #[cfg(feature = "proxy")]
+    pub use self::proxy::*;
+    #[cfg(feature = "logging")]
+    mod logging;
+    #[cfg(feature = "logging")]
+    pub use self::logging::*;
+    #[cfg(feature = "tests")]
+    mod tests;
+    #[cfg(feature = "tests")]
+    pub use self::tests::*;
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns the current authorization state; this is an offline request. For informational purposes only. Use updateAuthorizationState instead to maintain the current authorization state"]
+    pub struct GetAuthorizationState {}
+    impl Method for GetAuthorizationState {
+        const TYPE: &'static str = "getAuthorizationState";
+        type Response = AuthorizationState;
+        const OFFLINE: bool = true;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl GetAuthorizationState {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for GetAuthorizationState {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Sets the parameters for TDLib initialization. Works only when the current authorization state is authorizationStateWaitTdlibParameters"]
+    pub struct SetTdlibParameters {
+        #[doc = "Parameters"]
+        pub parameters: TdlibParameters,
+    }
+    impl Method for SetTdlibParameters {
+        const TYPE: &'static str = "setTdlibParameters";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> =
+            Some(&["authorizationStateWaitTdlibParameters"]);
+    }
+    impl SetTdlibParameters {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(parameters: impl Into<TdlibParameters>) -> Self {
+            Self {
+                parameters: parameters.into(),
+            }
+        }
+    }
+    impl Validate for SetTdlibParameters {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.parameters
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "parameters"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Checks the database encryption key for correctness. Works only when the current authorization state is authorizationStateWaitEncryptionKey"]
+    pub struct CheckDatabaseEncryptionKey {
+        #[doc = "Encryption key to check or set up"]
+        pub encryption_key: Bytes,
+    }
+    impl Method for CheckDatabaseEncryptionKey {
+        const TYPE: &'static str = "checkDatabaseEncryptionKey";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> =
+            Some(&["authorizationStateWaitEncryptionKey"]);
+    }
+    impl CheckDatabaseEncryptionKey {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Encryption key to check or set up"]
+        pub fn with_encryption_key(mut self, value: impl Into<Bytes>) -> Self {
+            self.encryption_key = value.into();
+            self
+        }
+    }
+    impl Validate for CheckDatabaseEncryptionKey {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Closes the TDLib instance after a proper logout. Requires an available network connection. All local data will be destroyed. After the logout completes, updateAuthorizationState with authorizationStateClosed will be sent"]
+    pub struct LogOut {}
+    impl Method for LogOut {
+        const TYPE: &'static str = "logOut";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl LogOut {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for LogOut {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Closes the TDLib instance. All databases will be flushed to disk and properly closed. After the close completes, updateAuthorizationState with authorizationStateClosed will be sent"]
+    pub struct Close {}
+    impl Method for Close {
+        const TYPE: &'static str = "close";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl Close {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for Close {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Closes the TDLib instance, destroying all local data without a proper logout. The current user session will remain in the list of all active sessions. All local data will be destroyed. After the destruction completes updateAuthorizationState with authorizationStateClosed will be sent"]
+    pub struct Destroy {}
+    impl Method for Destroy {
+        const TYPE: &'static str = "destroy";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl Destroy {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for Destroy {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns all updates needed to restore current TDLib state, i.e. all actual UpdateAuthorizationState/UpdateUser/UpdateNewChat and others. This is especially useful if TDLib is run in a separate process. This is an offline method. Can be called before authorization"]
+    pub struct GetCurrentState {}
+    impl Method for GetCurrentState {
+        const TYPE: &'static str = "getCurrentState";
+        type Response = Updates;
+        const OFFLINE: bool = true;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl GetCurrentState {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for GetCurrentState {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns the current user"]
+    pub struct GetMe {}
+    impl Method for GetMe {
+        const TYPE: &'static str = "getMe";
+        type Response = User;
+    }
+    impl GetMe {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for GetMe {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Returns information about a user by their identifier. This is an offline request if the current user is not a bot"]
+    pub struct GetUser {
+        #[doc = "User identifier"]
+        pub user_id: UserId,
+    }
+    impl Method for GetUser {
+        const TYPE: &'static str = "getUser";
+        type Response = User;
+    }
+    impl GetUser {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(user_id: impl Into<UserId>) -> Self {
+            Self {
+                user_id: user_id.into(),
+            }
+        }
+    }
+    impl Validate for GetUser {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Returns full information about a user by their identifier"]
+    pub struct GetUserFullInfo {
+        #[doc = "User identifier"]
+        pub user_id: UserId,
+    }
+    impl Method for GetUserFullInfo {
+        const TYPE: &'static str = "getUserFullInfo";
+        type Response = UserFullInfo;
+    }
+    impl GetUserFullInfo {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(user_id: impl Into<UserId>) -> Self {
+            Self {
+                user_id: user_id.into(),
+            }
+        }
+    }
+    impl Validate for GetUserFullInfo {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Removes an active notification from notification list. Needs to be called only if the notification is removed by the current user"]
+    pub struct RemoveNotification {
+        #[doc = "Identifier of notification group to which the notification belongs"]
+        pub notification_group_id: NotificationGroupId,
+        #[doc = "Identifier of removed notification"]
+        pub notification_id: NotificationId,
+    }
+    impl Method for RemoveNotification {
+        const TYPE: &'static str = "removeNotification";
+        type Response = Ok;
+    }
+    impl RemoveNotification {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(
+            notification_group_id: impl Into<NotificationGroupId>,
+            notification_id: impl Into<NotificationId>,
+        ) -> Self {
+            Self {
+                notification_group_id: notification_group_id.into(),
+                notification_id: notification_id.into(),
+            }
+        }
+    }
+    impl Validate for RemoveNotification {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Removes a group of active notifications. Needs to be called only if the notification group is removed by the current user"]
+    pub struct RemoveNotificationGroup {
+        #[doc = "Notification group identifier"]
+        pub notification_group_id: NotificationGroupId,
+        #[doc = "The maximum identifier of removed notifications"]
+        pub max_notification_id: NotificationId,
+    }
+    impl Method for RemoveNotificationGroup {
+        const TYPE: &'static str = "removeNotificationGroup";
+        type Response = Ok;
+    }
+    impl RemoveNotificationGroup {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(
+            notification_group_id: impl Into<NotificationGroupId>,
+            max_notification_id: impl Into<NotificationId>,
+        ) -> Self {
+            Self {
+                notification_group_id: notification_group_id.into(),
+                max_notification_id: max_notification_id.into(),
+            }
+        }
+    }
+    impl Validate for RemoveNotificationGroup {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns a string stored in the local database from the specified localization target and language pack by its key. Returns a 404 error if the string is not found. This is an offline method. Can be called before authorization. Can be called synchronously"]
+    pub struct GetLanguagePackString {
+        #[doc = "Path to the language pack database in which strings are stored"]
+        pub language_pack_database_path: String,
+        #[doc = "Localization target to which the language pack belongs"]
+        pub localization_target: String,
+        #[doc = "Language pack identifier"]
+        pub language_pack_id: String,
+        #[doc = "Language pack key of the string to be returned"]
+        pub key: String,
+    }
+    impl Method for GetLanguagePackString {
+        const TYPE: &'static str = "getLanguagePackString";
+        type Response = LanguagePackStringValue;
+        const OFFLINE: bool = true;
+        const SYNCHRONOUS: bool = true;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl SyncMethod for GetLanguagePackString {}
+    impl GetLanguagePackString {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Path to the language pack database in which strings are stored"]
+        pub fn with_language_pack_database_path(mut self, value: impl Into<String>) -> Self {
+            self.language_pack_database_path = value.into();
+            self
+        }
+        #[doc = "Localization target to which the language pack belongs"]
+        pub fn with_localization_target(mut self, value: impl Into<String>) -> Self {
+            self.localization_target = value.into();
+            self
+        }
+        #[doc = "Language pack identifier"]
+        pub fn with_language_pack_id(mut self, value: impl Into<String>) -> Self {
+            self.language_pack_id = value.into();
+            self
+        }
+        #[doc = "Language pack key of the string to be returned"]
+        pub fn with_key(mut self, value: impl Into<String>) -> Self {
+            self.key = value.into();
+            self
+        }
+    }
+    impl Validate for GetLanguagePackString {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Converts a JSON-serialized string to corresponding JsonValue object. This is an offline method. Can be called before authorization. Can be called synchronously"]
+    pub struct GetJsonValue {
+        #[doc = "The JSON-serialized string"]
+        pub json: String,
+    }
+    impl Method for GetJsonValue {
+        const TYPE: &'static str = "getJsonValue";
+        type Response = JsonValue;
+        const OFFLINE: bool = true;
+        const SYNCHRONOUS: bool = true;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl SyncMethod for GetJsonValue {}
+    impl GetJsonValue {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The JSON-serialized string"]
+        pub fn with_json(mut self, value: impl Into<String>) -> Self {
+            self.json = value.into();
+            self
+        }
+    }
+    impl Validate for GetJsonValue {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Converts a JsonValue object to corresponding JSON-serialized string. This is an offline method. Can be called before authorization. Can be called synchronously"]
+    pub struct GetJsonString {
+        #[doc = "The JsonValue object"]
+        pub json_value: JsonValue,
+    }
+    impl Method for GetJsonString {
+        const TYPE: &'static str = "getJsonString";
+        type Response = Text;
+        const OFFLINE: bool = true;
+        const SYNCHRONOUS: bool = true;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl SyncMethod for GetJsonString {}
+    impl GetJsonString {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(json_value: impl Into<JsonValue>) -> Self {
+            Self {
+                json_value: json_value.into(),
+            }
+        }
+    }
+    impl Validate for GetJsonString {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.json_value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "json_value"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Checks whether the current session can be used to transfer a chat ownership to another user"]
+    pub struct CanTransferOwnership {}
+    impl Method for CanTransferOwnership {
+        const TYPE: &'static str = "canTransferOwnership";
+        type Response = CanTransferOwnershipResult;
+    }
+    impl CanTransferOwnership {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for CanTransferOwnership {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Returns the notification settings for chats of a given type"]
+    pub struct GetScopeNotificationSettings {
+        #[doc = "Types of chats for which to return the notification settings information"]
+        pub scope: NotificationSettingsScope,
+    }
+    impl Method for GetScopeNotificationSettings {
+        const TYPE: &'static str = "getScopeNotificationSettings";
+        type Response = ScopeNotificationSettings;
+    }
+    impl GetScopeNotificationSettings {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(scope: impl Into<NotificationSettingsScope>) -> Self {
+            Self {
+                scope: scope.into(),
+            }
+        }
+    }
+    impl Validate for GetScopeNotificationSettings {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.scope
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "scope"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Changes notification settings for chats of a given type"]
+    pub struct SetScopeNotificationSettings {
+        #[doc = "Types of chats for which to change the notification settings"]
+        pub scope: NotificationSettingsScope,
+        #[doc = "The new notification settings for the given scope"]
+        pub notification_settings: ScopeNotificationSettings,
+    }
+    impl Method for SetScopeNotificationSettings {
+        const TYPE: &'static str = "setScopeNotificationSettings";
+        type Response = Ok;
+    }
+    impl SetScopeNotificationSettings {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(scope: impl Into<NotificationSettingsScope>) -> Self {
+            Self {
+                scope: scope.into(),
+                notification_settings: Default::default(),
+            }
+        }
+        #[doc = "The new notification settings for the given scope"]
+        pub fn with_notification_settings(
+            mut self,
+            value: impl Into<ScopeNotificationSettings>,
+        ) -> Self {
+            self.notification_settings = value.into();
+            self
+        }
+    }
+    impl Validate for SetScopeNotificationSettings {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.scope
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "scope"))?;
+            self.notification_settings
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "notification_settings"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Resets all notification settings to their default values. By default, all chats are unmuted, the sound is set to \"default\" and message previews are shown"]
+    pub struct ResetAllNotificationSettings {}
+    impl Method for ResetAllNotificationSettings {
+        const TYPE: &'static str = "resetAllNotificationSettings";
+        type Response = Ok;
+    }
+    impl ResetAllNotificationSettings {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for ResetAllNotificationSettings {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Adds a user to the blacklist"]
+    pub struct BlockUser {
+        #[doc = "User identifier"]
+        pub user_id: UserId,
+    }
+    impl Method for BlockUser {
+        const TYPE: &'static str = "blockUser";
+        type Response = Ok;
+    }
+    impl BlockUser {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(user_id: impl Into<UserId>) -> Self {
+            Self {
+                user_id: user_id.into(),
+            }
+        }
+    }
+    impl Validate for BlockUser {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Removes a user from the blacklist"]
+    pub struct UnblockUser {
+        #[doc = "User identifier"]
+        pub user_id: UserId,
+    }
+    impl Method for UnblockUser {
+        const TYPE: &'static str = "unblockUser";
+        type Response = Ok;
+    }
+    impl UnblockUser {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(user_id: impl Into<UserId>) -> Self {
+            Self {
+                user_id: user_id.into(),
+            }
+        }
+    }
+    impl Validate for UnblockUser {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns users that were blocked by the current user"]
+    pub struct GetBlockedUsers {
+        #[doc = "Number of users to skip in the result; must be non-negative"]
+        pub offset: i32,
+        #[doc = "The maximum number of users to return; up to 100"]
+        pub limit: i32,
+    }
+    impl Method for GetBlockedUsers {
+        const TYPE: &'static str = "getBlockedUsers";
+        type Response = Users;
+    }
+    impl GetBlockedUsers {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Number of users to skip in the result; must be non-negative"]
+        pub fn with_offset(mut self, value: impl Into<i32>) -> Self {
+            self.offset = value.into();
+            self
+        }
+        #[doc = "The maximum number of users to return; up to 100"]
+        pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+            self.limit = value.into();
+            self
+        }
+    }
+    impl Validate for GetBlockedUsers {
+        fn validate(&self) -> Result<(), ValidationError> {
+            validate::check(
+                Self::TYPE,
+                "offset",
+                Measure::Value,
+                i64::from(self.offset),
+                Some(0),
+                None,
+            )?;
+            validate::check(
+                Self::TYPE,
+                "limit",
+                Measure::Value,
+                i64::from(self.limit),
+                None,
+                Some(100),
+            )?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Adds a user to the contact list or edits an existing contact by their user identifier"]
+    pub struct AddContact {
+        #[doc = "The contact to add or edit; phone number can be empty and needs to be specified only if known, vCard is ignored"]
+        pub contact: Contact,
+        #[doc = "True, if the new contact needs to be allowed to see current user's phone number. A corresponding rule to userPrivacySettingShowPhoneNumber will be added if needed. Use the field UserFullInfo.need_phone_number_privacy_exception to check whether the current user needs to be asked to share their phone number"]
+        pub share_phone_number: bool,
+    }
+    impl Method for AddContact {
+        const TYPE: &'static str = "addContact";
+        type Response = Ok;
+    }
+    impl AddContact {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(contact: impl Into<Contact>) -> Self {
+            Self {
+                contact: contact.into(),
+                share_phone_number: Default::default(),
+            }
+        }
+        #[doc = "True, if the new contact needs to be allowed to see current user's phone number. A corresponding rule to userPrivacySettingShowPhoneNumber will be added if needed. Use the field UserFullInfo.need_phone_number_privacy_exception to check whether the current user needs to be asked to share their phone number"]
+        pub fn with_share_phone_number(mut self, value: impl Into<bool>) -> Self {
+            self.share_phone_number = value.into();
+            self
+        }
+    }
+    impl Validate for AddContact {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.contact
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "contact"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Adds new contacts or edits existing contacts by their phone numbers; contacts' user identifiers are ignored"]
+    pub struct ImportContacts {
+        #[doc = "The list of contacts to import or edit; contacts' vCard are ignored and are not imported"]
+        pub contacts: Vec<Contact>,
+    }
+    impl Method for ImportContacts {
+        const TYPE: &'static str = "importContacts";
+        type Response = ImportedContacts;
+    }
+    impl ImportContacts {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The list of contacts to import or edit; contacts' vCard are ignored and are not imported"]
+        pub fn with_contacts(mut self, value: impl Into<Vec<Contact>>) -> Self {
+            self.contacts = value.into();
+            self
+        }
+    }
+    impl Validate for ImportContacts {
+        fn validate(&self) -> Result<(), ValidationError> {
+            for (i, value) in self.contacts.iter().enumerate() {
+                value
+                    .validate()
+                    .map_err(|err| err.within(Self::TYPE, &format!("contacts[{}]", i)))?;
+            }
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns all user contacts"]
+    pub struct GetContacts {}
+    impl Method for GetContacts {
+        const TYPE: &'static str = "getContacts";
+        type Response = Users;
+    }
+    impl GetContacts {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for GetContacts {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Searches for the specified query in the first names, last names and usernames of the known user contacts"]
+    pub struct SearchContacts {
+        #[doc = "Query to search for; may be empty to return all contacts"]
+        pub query: String,
+        #[doc = "The maximum number of users to be returned"]
+        pub limit: i32,
+    }
+    impl Method for SearchContacts {
+        const TYPE: &'static str = "searchContacts";
+        type Response = Users;
+    }
+    impl SearchContacts {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Query to search for; may be empty to return all contacts"]
+        pub fn with_query(mut self, value: impl Into<String>) -> Self {
+            self.query = value.into();
+            self
+        }
+        #[doc = "The maximum number of users to be returned"]
+        pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+            self.limit = value.into();
+            self
+        }
+    }
+    impl Validate for SearchContacts {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Removes users from the contact list"]
+    pub struct RemoveContacts {
+        #[doc = "Identifiers of users to be deleted"]
+        pub user_ids: Vec<UserId>,
+    }
+    impl Method for RemoveContacts {
+        const TYPE: &'static str = "removeContacts";
+        type Response = Ok;
+    }
+    impl RemoveContacts {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Identifiers of users to be deleted"]
+        pub fn with_user_ids(mut self, value: impl Into<Vec<UserId>>) -> Self {
+            self.user_ids = value.into();
+            self
+        }
+    }
+    impl Validate for RemoveContacts {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns the total number of imported contacts"]
+    pub struct GetImportedContactCount {}
+    impl Method for GetImportedContactCount {
+        const TYPE: &'static str = "getImportedContactCount";
+        type Response = Count;
+    }
+    impl GetImportedContactCount {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for GetImportedContactCount {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Changes imported contacts using the list of current user contacts saved on the device. Imports newly added contacts and, if at least the file database is enabled, deletes recently deleted contacts. Query result depends on the result of the previous query, so only one query is possible at the same time"]
+    pub struct ChangeImportedContacts {
+        #[doc = "The new list of contacts, contact's vCard are ignored and are not imported"]
+        pub contacts: Vec<Contact>,
+    }
+    impl Method for ChangeImportedContacts {
+        const TYPE: &'static str = "changeImportedContacts";
+        type Response = ImportedContacts;
+    }
+    impl ChangeImportedContacts {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The new list of contacts, contact's vCard are ignored and are not imported"]
+        pub fn with_contacts(mut self, value: impl Into<Vec<Contact>>) -> Self {
+            self.contacts = value.into();
+            self
+        }
+    }
+    impl Validate for ChangeImportedContacts {
+        fn validate(&self) -> Result<(), ValidationError> {
+            for (i, value) in self.contacts.iter().enumerate() {
+                value
+                    .validate()
+                    .map_err(|err| err.within(Self::TYPE, &format!("contacts[{}]", i)))?;
+            }
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Clears all imported contacts, contact list remains unchanged"]
+    pub struct ClearImportedContacts {}
+    impl Method for ClearImportedContacts {
+        const TYPE: &'static str = "clearImportedContacts";
+        type Response = Ok;
+    }
+    impl ClearImportedContacts {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for ClearImportedContacts {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Shares the phone number of the current user with a mutual contact. Supposed to be called when the user clicks on chatActionBarSharePhoneNumber"]
+    pub struct SharePhoneNumber {
+        #[doc = "Identifier of the user with whom to share the phone number. The user must be a mutual contact"]
+        pub user_id: UserId,
+    }
+    impl Method for SharePhoneNumber {
+        const TYPE: &'static str = "sharePhoneNumber";
+        type Response = Ok;
+    }
+    impl SharePhoneNumber {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(user_id: impl Into<UserId>) -> Self {
+            Self {
+                user_id: user_id.into(),
+            }
+        }
+    }
+    impl Validate for SharePhoneNumber {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Returns the profile photos of a user. The result of this query may be outdated: some photos might have been deleted already"]
+    pub struct GetUserProfilePhotos {
+        #[doc = "User identifier"]
+        pub user_id: UserId,
+        #[doc = "The number of photos to skip; must be non-negative"]
+        pub offset: i32,
+        #[doc = "The maximum number of photos to be returned; up to 100"]
+        pub limit: i32,
+    }
+    impl Method for GetUserProfilePhotos {
+        const TYPE: &'static str = "getUserProfilePhotos";
+        type Response = UserProfilePhotos;
+    }
+    impl GetUserProfilePhotos {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(user_id: impl Into<UserId>) -> Self {
+            Self {
+                user_id: user_id.into(),
+                offset: Default::default(),
+                limit: Default::default(),
+            }
+        }
+        #[doc = "The number of photos to skip; must be non-negative"]
+        pub fn with_offset(mut self, value: impl Into<i32>) -> Self {
+            self.offset = value.into();
+            self
+        }
+        #[doc = "The maximum number of photos to be returned; up to 100"]
+        pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+            self.limit = value.into();
+            self
+        }
+    }
+    impl Validate for GetUserProfilePhotos {
+        fn validate(&self) -> Result<(), ValidationError> {
+            validate::check(
+                Self::TYPE,
+                "offset",
+                Measure::Value,
+                i64::from(self.offset),
+                Some(0),
+                None,
+            )?;
+            validate::check(
+                Self::TYPE,
+                "limit",
+                Measure::Value,
+                i64::from(self.limit),
+                None,
+                Some(100),
+            )?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Searches for recently used hashtags by their prefix"]
+    pub struct SearchHashtags {
+        #[doc = "Hashtag prefix to search for"]
+        pub prefix: String,
+        #[doc = "The maximum number of hashtags to be returned"]
+        pub limit: i32,
+    }
+    impl Method for SearchHashtags {
+        const TYPE: &'static str = "searchHashtags";
+        type Response = Hashtags;
+    }
+    impl SearchHashtags {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Hashtag prefix to search for"]
+        pub fn with_prefix(mut self, value: impl Into<String>) -> Self {
+            self.prefix = value.into();
+            self
+        }
+        #[doc = "The maximum number of hashtags to be returned"]
+        pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+            self.limit = value.into();
+            self
+        }
+    }
+    impl Validate for SearchHashtags {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Removes a hashtag from the list of recently used hashtags"]
+    pub struct RemoveRecentHashtag {
+        #[doc = "Hashtag to delete"]
+        pub hashtag: String,
+    }
+    impl Method for RemoveRecentHashtag {
+        const TYPE: &'static str = "removeRecentHashtag";
+        type Response = Ok;
+    }
+    impl RemoveRecentHashtag {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Hashtag to delete"]
+        pub fn with_hashtag(mut self, value: impl Into<String>) -> Self {
+            self.hashtag = value.into();
+            self
+        }
+    }
+    impl Validate for RemoveRecentHashtag {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Uploads a new profile photo for the current user. If something changes, updateUser will be sent"]
+    pub struct SetProfilePhoto {
+        #[doc = "Profile photo to set. inputFileId and inputFileRemote may still be unsupported"]
+        pub photo: InputFile,
+    }
+    impl Method for SetProfilePhoto {
+        const TYPE: &'static str = "setProfilePhoto";
+        type Response = Ok;
+    }
+    impl SetProfilePhoto {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(photo: impl Into<InputFile>) -> Self {
+            Self {
+                photo: photo.into(),
+            }
+        }
+    }
+    impl Validate for SetProfilePhoto {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.photo
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "photo"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Deletes a profile photo. If something changes, updateUser will be sent"]
+    pub struct DeleteProfilePhoto {
+        #[serde(
+            deserialize_with = "crate::utils::from_str_to_t",
+            serialize_with = "crate::utils::t_to_str"
+        )]
+        #[doc = "Identifier of the profile photo to delete"]
+        pub profile_photo_id: i64,
+    }
+    impl Method for DeleteProfilePhoto {
+        const TYPE: &'static str = "deleteProfilePhoto";
+        type Response = Ok;
+    }
+    impl DeleteProfilePhoto {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Identifier of the profile photo to delete"]
+        pub fn with_profile_photo_id(mut self, value: impl Into<i64>) -> Self {
+            self.profile_photo_id = value.into();
+            self
+        }
+    }
+    impl Validate for DeleteProfilePhoto {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Changes the first and last name of the current user. If something changes, updateUser will be sent"]
+    pub struct SetName {
+        #[doc = "The new value of the first name for the user; 1-64 characters"]
+        pub first_name: String,
+        #[doc = "The new value of the optional last name for the user; 0-64 characters"]
+        pub last_name: String,
+    }
+    impl Method for SetName {
+        const TYPE: &'static str = "setName";
+        type Response = Ok;
+    }
+    impl SetName {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(first_name: impl Into<String>) -> Self {
+            Self {
+                first_name: first_name.into(),
+                last_name: Default::default(),
+            }
+        }
+        #[doc = "The new value of the optional last name for the user; 0-64 characters"]
+        pub fn with_last_name(mut self, value: impl Into<String>) -> Self {
+            self.last_name = value.into();
+            self
+        }
+    }
+    impl Validate for SetName {
+        fn validate(&self) -> Result<(), ValidationError> {
+            validate::check(
+                Self::TYPE,
+                "first_name",
+                Measure::Characters,
+                self.first_name.chars().count() as i64,
+                Some(1),
+                Some(64),
+            )?;
+            validate::check(
+                Self::TYPE,
+                "last_name",
+                Measure::Characters,
+                self.last_name.chars().count() as i64,
+                Some(0),
+                Some(64),
+            )?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Changes the bio of the current user"]
+    pub struct SetBio {
+        #[doc = "The new value of the user bio; 0-70 characters without line feeds"]
+        pub bio: String,
+    }
+    impl Method for SetBio {
+        const TYPE: &'static str = "setBio";
+        type Response = Ok;
+    }
+    impl SetBio {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The new value of the user bio; 0-70 characters without line feeds"]
+        pub fn with_bio(mut self, value: impl Into<String>) -> Self {
+            self.bio = value.into();
+            self
+        }
+    }
+    impl Validate for SetBio {
+        fn validate(&self) -> Result<(), ValidationError> {
+            validate::check(
+                Self::TYPE,
+                "bio",
+                Measure::Characters,
+                self.bio.chars().count() as i64,
+                Some(0),
+                Some(70),
+            )?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Changes the username of the current user. If something changes, updateUser will be sent"]
+    pub struct SetUsername {
+        #[doc = "The new value of the username. Use an empty string to remove the username"]
+        pub username: String,
+    }
+    impl Method for SetUsername {
+        const TYPE: &'static str = "setUsername";
+        type Response = Ok;
+    }
+    impl SetUsername {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The new value of the username. Use an empty string to remove the username"]
+        pub fn with_username(mut self, value: impl Into<String>) -> Self {
+            self.username = value.into();
+            self
+        }
+    }
+    impl Validate for SetUsername {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns a user that can be contacted to get support"]
+    pub struct GetSupportUser {}
+    impl Method for GetSupportUser {
+        const TYPE: &'static str = "getSupportUser";
+        type Response = User;
+    }
+    impl GetSupportUser {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for GetSupportUser {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns backgrounds installed by the user"]
+    pub struct GetBackgrounds {
+        #[doc = "True, if the backgrounds needs to be ordered for dark theme"]
+        pub for_dark_theme: bool,
+    }
+    impl Method for GetBackgrounds {
+        const TYPE: &'static str = "getBackgrounds";
+        type Response = Backgrounds;
+    }
+    impl GetBackgrounds {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "True, if the backgrounds needs to be ordered for dark theme"]
+        pub fn with_for_dark_theme(mut self, value: impl Into<bool>) -> Self {
+            self.for_dark_theme = value.into();
+            self
+        }
+    }
+    impl Validate for GetBackgrounds {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Constructs a persistent HTTP URL for a background"]
+    pub struct GetBackgroundUrl {
+        #[doc = "Background name"]
+        pub name: String,
+        #[serde(rename = "type")]
+        #[doc = "Background type"]
+        pub type_: BackgroundType,
+    }
+    impl Method for GetBackgroundUrl {
+        const TYPE: &'static str = "getBackgroundUrl";
+        type Response = HttpUrl;
+    }
+    impl GetBackgroundUrl {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(type_: impl Into<BackgroundType>) -> Self {
+            Self {
+                name: Default::default(),
+                type_: type_.into(),
+            }
+        }
+        #[doc = "Background name"]
+        pub fn with_name(mut self, value: impl Into<String>) -> Self {
+            self.name = value.into();
+            self
+        }
+    }
+    impl Validate for GetBackgroundUrl {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.type_
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "type"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Searches for a background by its name"]
+    pub struct SearchBackground {
+        #[doc = "The name of the background"]
+        pub name: String,
+    }
+    impl Method for SearchBackground {
+        const TYPE: &'static str = "searchBackground";
+        type Response = Background;
+    }
+    impl SearchBackground {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The name of the background"]
+        pub fn with_name(mut self, value: impl Into<String>) -> Self {
+            self.name = value.into();
+            self
+        }
+    }
+    impl Validate for SearchBackground {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Changes the background selected by the user; adds background to the list of installed backgrounds"]
+    pub struct SetBackground {
+        #[serde(default)]
+        #[doc = "The input background to use, null for filled backgrounds"]
+        pub background: Option<InputBackground>,
+        #[serde(rename = "type")]
+        #[serde(default)]
+        #[doc = "Background type; null for default background. The method will return error 404 if type is null"]
+        pub type_: Option<BackgroundType>,
+        #[doc = "True, if the background is chosen for dark theme"]
+        pub for_dark_theme: bool,
+    }
+    impl Method for SetBackground {
+        const TYPE: &'static str = "setBackground";
+        type Response = Background;
+    }
+    impl SetBackground {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The input background to use, null for filled backgrounds"]
+        pub fn with_background(mut self, value: impl Into<InputBackground>) -> Self {
+            self.background = Some(value.into());
+            self
+        }
+        #[doc = "Background type; null for default background. The method will return error 404 if type is null"]
+        pub fn with_type(mut self, value: impl Into<BackgroundType>) -> Self {
+            self.type_ = Some(value.into());
+            self
+        }
+        #[doc = "True, if the background is chosen for dark theme"]
+        pub fn with_for_dark_theme(mut self, value: impl Into<bool>) -> Self {
+            self.for_dark_theme = value.into();
+            self
+        }
+    }
+    impl Validate for SetBackground {
+        fn validate(&self) -> Result<(), ValidationError> {
+            if let Some(value) = &self.background {
+                value
+                    .validate()
+                    .map_err(|err| err.within(Self::TYPE, "background"))?;
+            }
+            if let Some(value) = &self.type_ {
+                value
+                    .validate()
+                    .map_err(|err| err.within(Self::TYPE, "type"))?;
+            }
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Removes background from the list of installed backgrounds"]
+    pub struct RemoveBackground {
+        #[serde(
+            deserialize_with = "crate::utils::from_str_to_t",
+            serialize_with = "crate::utils::t_to_str"
+        )]
+        #[doc = "The background identifier"]
+        pub background_id: i64,
+    }
+    impl Method for RemoveBackground {
+        const TYPE: &'static str = "removeBackground";
+        type Response = Ok;
+    }
+    impl RemoveBackground {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The background identifier"]
+        pub fn with_background_id(mut self, value: impl Into<i64>) -> Self {
+            self.background_id = value.into();
+            self
+        }
+    }
+    impl Validate for RemoveBackground {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Resets list of installed backgrounds to its default value"]
+    pub struct ResetBackgrounds {}
+    impl Method for ResetBackgrounds {
+        const TYPE: &'static str = "resetBackgrounds";
+        type Response = Ok;
+    }
+    impl ResetBackgrounds {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for ResetBackgrounds {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns information about the current localization target. This is an offline request if only_local is true. Can be called before authorization"]
+    pub struct GetLocalizationTargetInfo {
+        #[doc = "If true, returns only locally available information without sending network requests"]
+        pub only_local: bool,
+    }
+    impl Method for GetLocalizationTargetInfo {
+        const TYPE: &'static str = "getLocalizationTargetInfo";
+        type Response = LocalizationTargetInfo;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl GetLocalizationTargetInfo {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "If true, returns only locally available information without sending network requests"]
+        pub fn with_only_local(mut self, value: impl Into<bool>) -> Self {
+            self.only_local = value.into();
+            self
+        }
+    }
+    impl Validate for GetLocalizationTargetInfo {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns information about a language pack. Returned language pack identifier may be different from a provided one. Can be called before authorization"]
+    pub struct GetLanguagePackInfo {
+        #[doc = "Language pack identifier"]
+        pub language_pack_id: String,
+    }
+    impl Method for GetLanguagePackInfo {
+        const TYPE: &'static str = "getLanguagePackInfo";
+        type Response = LanguagePackInfo;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl GetLanguagePackInfo {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Language pack identifier"]
+        pub fn with_language_pack_id(mut self, value: impl Into<String>) -> Self {
+            self.language_pack_id = value.into();
+            self
+        }
+    }
+    impl Validate for GetLanguagePackInfo {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns strings from a language pack in the current localization target by their keys. Can be called before authorization"]
+    pub struct GetLanguagePackStrings {
+        #[doc = "Language pack identifier of the strings to be returned"]
+        pub language_pack_id: String,
+        #[doc = "Language pack keys of the strings to be returned; leave empty to request all available strings"]
+        pub keys: Vec<String>,
+    }
+    impl Method for GetLanguagePackStrings {
+        const TYPE: &'static str = "getLanguagePackStrings";
+        type Response = LanguagePackStrings;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl GetLanguagePackStrings {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Language pack identifier of the strings to be returned"]
+        pub fn with_language_pack_id(mut self, value: impl Into<String>) -> Self {
+            self.language_pack_id = value.into();
+            self
+        }
+        #[doc = "Language pack keys of the strings to be returned; leave empty to request all available strings"]
+        pub fn with_keys(mut self, value: impl Into<Vec<String>>) -> Self {
+            self.keys = value.into();
+            self
+        }
+    }
+    impl Validate for GetLanguagePackStrings {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Fetches the latest versions of all strings from a language pack in the current localization target from the server. This method doesn't need to be called explicitly for the current used/base language packs. Can be called before authorization"]
+    pub struct SynchronizeLanguagePack {
+        #[doc = "Language pack identifier"]
+        pub language_pack_id: String,
+    }
+    impl Method for SynchronizeLanguagePack {
+        const TYPE: &'static str = "synchronizeLanguagePack";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl SynchronizeLanguagePack {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Language pack identifier"]
+        pub fn with_language_pack_id(mut self, value: impl Into<String>) -> Self {
+            self.language_pack_id = value.into();
+            self
+        }
+    }
+    impl Validate for SynchronizeLanguagePack {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Adds a custom server language pack to the list of installed language packs in current localization target. Can be called before authorization"]
+    pub struct AddCustomServerLanguagePack {
+        #[doc = "Identifier of a language pack to be added; may be different from a name that is used in an \"https://t.me/setlanguage/\" link"]
+        pub language_pack_id: String,
+    }
+    impl Method for AddCustomServerLanguagePack {
+        const TYPE: &'static str = "addCustomServerLanguagePack";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl AddCustomServerLanguagePack {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Identifier of a language pack to be added; may be different from a name that is used in an \"https://t.me/setlanguage/\" link"]
+        pub fn with_language_pack_id(mut self, value: impl Into<String>) -> Self {
+            self.language_pack_id = value.into();
+            self
+        }
+    }
+    impl Validate for AddCustomServerLanguagePack {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Adds or changes a custom local language pack to the current localization target"]
+    pub struct SetCustomLanguagePack {
+        #[doc = "Information about the language pack. Language pack ID must start with 'X', consist only of English letters, digits and hyphens, and must not exceed 64 characters. Can be called before authorization"]
+        pub info: LanguagePackInfo,
+        #[doc = "Strings of the new language pack"]
+        pub strings: Vec<LanguagePackString>,
+    }
+    impl Method for SetCustomLanguagePack {
+        const TYPE: &'static str = "setCustomLanguagePack";
+        type Response = Ok;
+    }
+    impl SetCustomLanguagePack {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Information about the language pack. Language pack ID must start with 'X', consist only of English letters, digits and hyphens, and must not exceed 64 characters. Can be called before authorization"]
+        pub fn with_info(mut self, value: impl Into<LanguagePackInfo>) -> Self {
+            self.info = value.into();
+            self
+        }
+        #[doc = "Strings of the new language pack"]
+        pub fn with_strings(mut self, value: impl Into<Vec<LanguagePackString>>) -> Self {
+            self.strings = value.into();
+            self
+        }
+    }
+    impl Validate for SetCustomLanguagePack {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.info
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "info"))?;
+            for (i, value) in self.strings.iter().enumerate() {
+                value
+                    .validate()
+                    .map_err(|err| err.within(Self::TYPE, &format!("strings[{}]", i)))?;
+            }
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Edits information about a custom local language pack in the current localization target. Can be called before authorization"]
+    pub struct EditCustomLanguagePackInfo {
+        #[doc = "New information about the custom local language pack"]
+        pub info: LanguagePackInfo,
+    }
+    impl Method for EditCustomLanguagePackInfo {
+        const TYPE: &'static str = "editCustomLanguagePackInfo";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl EditCustomLanguagePackInfo {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "New information about the custom local language pack"]
+        pub fn with_info(mut self, value: impl Into<LanguagePackInfo>) -> Self {
+            self.info = value.into();
+            self
+        }
+    }
+    impl Validate for EditCustomLanguagePackInfo {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.info
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "info"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Adds, edits or deletes a string in a custom local language pack. Can be called before authorization"]
+    pub struct SetCustomLanguagePackString {
+        #[doc = "Identifier of a previously added custom local language pack in the current localization target"]
+        pub language_pack_id: String,
+        #[doc = "New language pack string"]
+        pub new_string: LanguagePackString,
+    }
+    impl Method for SetCustomLanguagePackString {
+        const TYPE: &'static str = "setCustomLanguagePackString";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl SetCustomLanguagePackString {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(new_string: impl Into<LanguagePackString>) -> Self {
+            Self {
+                language_pack_id: Default::default(),
+                new_string: new_string.into(),
+            }
+        }
+        #[doc = "Identifier of a previously added custom local language pack in the current localization target"]
+        pub fn with_language_pack_id(mut self, value: impl Into<String>) -> Self {
+            self.language_pack_id = value.into();
+            self
+        }
+    }
+    impl Validate for SetCustomLanguagePackString {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.new_string
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "new_string"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Deletes all information about a language pack in the current localization target. The language pack which is currently in use (including base language pack) or is being synchronized can't be deleted. Can be called before authorization"]
+    pub struct DeleteLanguagePack {
+        #[doc = "Identifier of the language pack to delete"]
+        pub language_pack_id: String,
+    }
+    impl Method for DeleteLanguagePack {
+        const TYPE: &'static str = "deleteLanguagePack";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl DeleteLanguagePack {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Identifier of the language pack to delete"]
+        pub fn with_language_pack_id(mut self, value: impl Into<String>) -> Self {
+            self.language_pack_id = value.into();
+            self
+        }
+    }
+    impl Validate for DeleteLanguagePack {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Registers the currently used device for receiving push notifications. Returns a globally unique identifier of the push notification subscription"]
+    pub struct RegisterDevice {
+        #[doc = "Device token"]
+        pub device_token: DeviceToken,
+        #[doc = "List of user identifiers of other users currently using the client"]
+        pub other_user_ids: Vec<UserId>,
+    }
+    impl Method for RegisterDevice {
+        const TYPE: &'static str = "registerDevice";
+        type Response = PushReceiverId;
+    }
+    impl RegisterDevice {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(device_token: impl Into<DeviceToken>) -> Self {
+            Self {
+                device_token: device_token.into(),
+                other_user_ids: Default::default(),
+            }
+        }
+        #[doc = "List of user identifiers of other users currently using the client"]
+        pub fn with_other_user_ids(mut self, value: impl Into<Vec<UserId>>) -> Self {
+            self.other_user_ids = value.into();
+            self
+        }
+    }
+    impl Validate for RegisterDevice {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.device_token
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "device_token"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Handles a push notification. Returns error with code 406 if the push notification is not supported and connection to the server is required to fetch new data. Can be called before authorization"]
+    pub struct ProcessPushNotification {
+        #[doc = "JSON-encoded push notification payload with all fields sent by the server, and \"google.sent_time\" and \"google.notification.sound\" fields added"]
+        pub payload: String,
+    }
+    impl Method for ProcessPushNotification {
+        const TYPE: &'static str = "processPushNotification";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl ProcessPushNotification {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "JSON-encoded push notification payload with all fields sent by the server, and \"google.sent_time\" and \"google.notification.sound\" fields added"]
+        pub fn with_payload(mut self, value: impl Into<String>) -> Self {
+            self.payload = value.into();
+            self
+        }
+    }
+    impl Validate for ProcessPushNotification {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns a globally unique push notification subscription identifier for identification of an account, which has received a push notification. This is an offline method. Can be called before authorization. Can be called synchronously"]
+    pub struct GetPushReceiverId {
+        #[doc = "JSON-encoded push notification payload"]
+        pub payload: String,
+    }
+    impl Method for GetPushReceiverId {
+        const TYPE: &'static str = "getPushReceiverId";
+        type Response = PushReceiverId;
+        const OFFLINE: bool = true;
+        const SYNCHRONOUS: bool = true;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl SyncMethod for GetPushReceiverId {}
+    impl GetPushReceiverId {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "JSON-encoded push notification payload"]
+        pub fn with_payload(mut self, value: impl Into<String>) -> Self {
+            self.payload = value.into();
+            self
+        }
+    }
+    impl Validate for GetPushReceiverId {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns t.me URLs recently visited by a newly registered user"]
+    pub struct GetRecentlyVisitedTMeUrls {
+        #[doc = "Google Play referrer to identify the user"]
+        pub referrer: String,
+    }
+    impl Method for GetRecentlyVisitedTMeUrls {
+        const TYPE: &'static str = "getRecentlyVisitedTMeUrls";
+        type Response = TMeUrls;
+    }
+    impl GetRecentlyVisitedTMeUrls {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Google Play referrer to identify the user"]
+        pub fn with_referrer(mut self, value: impl Into<String>) -> Self {
+            self.referrer = value.into();
+            self
+        }
+    }
+    impl Validate for GetRecentlyVisitedTMeUrls {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Changes user privacy settings"]
+    pub struct SetUserPrivacySettingRules {
+        #[doc = "The privacy setting"]
+        pub setting: UserPrivacySetting,
+        #[doc = "The new privacy rules"]
+        pub rules: UserPrivacySettingRules,
+    }
+    impl Method for SetUserPrivacySettingRules {
+        const TYPE: &'static str = "setUserPrivacySettingRules";
+        type Response = Ok;
+    }
+    impl SetUserPrivacySettingRules {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(setting: impl Into<UserPrivacySetting>) -> Self {
+            Self {
+                setting: setting.into(),
+                rules: Default::default(),
+            }
+        }
+        #[doc = "The new privacy rules"]
+        pub fn with_rules(mut self, value: impl Into<UserPrivacySettingRules>) -> Self {
+            self.rules = value.into();
+            self
+        }
+    }
+    impl Validate for SetUserPrivacySettingRules {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.setting
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "setting"))?;
+            self.rules
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "rules"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Returns the current privacy settings"]
+    pub struct GetUserPrivacySettingRules {
+        #[doc = "The privacy setting"]
+        pub setting: UserPrivacySetting,
+    }
+    impl Method for GetUserPrivacySettingRules {
+        const TYPE: &'static str = "getUserPrivacySettingRules";
+        type Response = UserPrivacySettingRules;
+    }
+    impl GetUserPrivacySettingRules {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(setting: impl Into<UserPrivacySetting>) -> Self {
+            Self {
+                setting: setting.into(),
+            }
+        }
+    }
+    impl Validate for GetUserPrivacySettingRules {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.setting
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "setting"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns the value of an option by its name. (Check the list of available options on https://core.telegram.org/tdlib/options.) Can be called before authorization"]
+    pub struct GetOption {
+        #[doc = "The name of the option"]
+        pub name: String,
+    }
+    impl Method for GetOption {
+        const TYPE: &'static str = "getOption";
+        type Response = OptionValue;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl GetOption {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The name of the option"]
+        pub fn with_name(mut self, value: impl Into<String>) -> Self {
+            self.name = value.into();
+            self
+        }
+    }
+    impl Validate for GetOption {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Sets the value of an option. (Check the list of available options on https://core.telegram.org/tdlib/options.) Only writable options can be set. Can be called before authorization"]
+    pub struct SetOption {
+        #[doc = "The name of the option"]
+        pub name: String,
+        #[doc = "The new value of the option"]
+        pub value: OptionValue,
+    }
+    impl Method for SetOption {
+        const TYPE: &'static str = "setOption";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl SetOption {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(value: impl Into<OptionValue>) -> Self {
+            Self {
+                name: Default::default(),
+                value: value.into(),
+            }
+        }
+        #[doc = "The name of the option"]
+        pub fn with_name(mut self, value: impl Into<String>) -> Self {
+            self.name = value.into();
+            self
+        }
+    }
+    impl Validate for SetOption {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "value"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Sets the current network type. Can be called before authorization. Calling this method forces all network connections to reopen, mitigating the delay in switching between different networks, so it should be called whenever the network is changed, even if the network type remains the same. Network type is used to check whether the library can use the network at all and also for collecting detailed network data usage statistics"]
+    pub struct SetNetworkType {
+        #[serde(rename = "type")]
+        #[doc = "The new network type. By default, networkTypeOther"]
+        pub type_: NetworkType,
+    }
+    impl Method for SetNetworkType {
+        const TYPE: &'static str = "setNetworkType";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl SetNetworkType {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(type_: impl Into<NetworkType>) -> Self {
+            Self {
+                type_: type_.into(),
+            }
+        }
+    }
+    impl Validate for SetNetworkType {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.type_
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "type"))?;
+            Ok(())
+        }
+    }
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Succeeds after a specified amount of time has passed. Can be called before authorization. Can be called before initialization"]
+    pub struct SetAlarm {
+        #[doc = "Number of seconds before the function returns"]
+        pub seconds: f64,
+    }
+    impl Method for SetAlarm {
+        const TYPE: &'static str = "setAlarm";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl SetAlarm {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "Number of seconds before the function returns"]
+        pub fn with_seconds(mut self, value: impl Into<f64>) -> Self {
+            self.seconds = value.into();
+            self
+        }
+    }
+    impl Validate for SetAlarm {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Uses current user IP to found their country. Returns two-letter ISO 3166-1 alpha-2 country code. Can be called before authorization"]
+    pub struct GetCountryCode {}
+    impl Method for GetCountryCode {
+        const TYPE: &'static str = "getCountryCode";
+        type Response = Text;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl GetCountryCode {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for GetCountryCode {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns information about a tg:// deep link. Use \"tg://need_update_for_some_feature\" or \"tg:some_unsupported_feature\" for testing. Returns a 404 error for unknown links. Can be called before authorization"]
+    pub struct GetDeepLinkInfo {
+        #[doc = "The link"]
+        pub link: String,
+    }
+    impl Method for GetDeepLinkInfo {
+        const TYPE: &'static str = "getDeepLinkInfo";
+        type Response = DeepLinkInfo;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl GetDeepLinkInfo {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+        #[doc = "The link"]
+        pub fn with_link(mut self, value: impl Into<String>) -> Self {
+            self.link = value.into();
+            self
+        }
+    }
+    impl Validate for GetDeepLinkInfo {}
+    #[derive(Serialize, Deserialize, Debug, Clone, Default)]
+    #[doc = "Returns application config, provided by the server. Can be called before authorization"]
+    pub struct GetApplicationConfig {}
+    impl Method for GetApplicationConfig {
+        const TYPE: &'static str = "getApplicationConfig";
+        type Response = JsonValue;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl GetApplicationConfig {
+        #[doc = "Returns the object with every field set to its default value"]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+    impl Validate for GetApplicationConfig {}
+    #[derive(Serialize, Deserialize, Debug, Clone)]
+    #[doc = "Saves application log event on the server. Can be called before authorization"]
+    pub struct SaveApplicationLogEvent {
+        #[serde(rename = "type")]
+        #[doc = "Event type"]
+        pub type_: String,
+        #[doc = "Optional chat identifier, associated with the event"]
+        pub chat_id: ChatId,
+        #[doc = "The log event data"]
+        pub data: JsonValue,
+    }
+    impl Method for SaveApplicationLogEvent {
+        const TYPE: &'static str = "saveApplicationLogEvent";
+        type Response = Ok;
+        const BEFORE_AUTHORIZATION: bool = true;
+        const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+    }
+    impl SaveApplicationLogEvent {
+        #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+        pub fn new(chat_id: impl Into<ChatId>, data: impl Into<JsonValue>) -> Self {
+            Self {
+                type_: Default::default(),
+                chat_id: chat_id.into(),
+                data: data.into(),
+            }
+        }
+        #[doc = "Event type"]
+        pub fn with_type(mut self, value: impl Into<String>) -> Self {
+            self.type_ = value.into();
+            self
+        }
+    }
+    impl Validate for SaveApplicationLogEvent {
+        fn validate(&self) -> Result<(), ValidationError> {
+            self.data
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "data"))?;
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,309 @@
+//! The types of the `auth` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Information about the email address authentication code that was sent"]
+pub struct EmailAddressAuthenticationCodeInfo {
+    #[doc = "Pattern of the email address to which an authentication code was sent"]
+    pub email_address_pattern: String,
+    #[doc = "Length of the code; 0 if unknown"]
+    pub length: i32,
+}
+impl TlObject for EmailAddressAuthenticationCodeInfo {
+    const CLASS: &'static str = "EmailAddressAuthenticationCodeInfo";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for EmailAddressAuthenticationCodeInfo {
+    const TYPE: &'static str = "emailAddressAuthenticationCodeInfo";
+}
+#[cfg(feature = "auth")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents the current state of 2-step verification"]
+pub struct PasswordState {
+    #[doc = "True, if a 2-step verification password is set"]
+    pub has_password: bool,
+    #[doc = "Hint for the password; may be empty"]
+    pub password_hint: String,
+    #[doc = "True, if a recovery email is set"]
+    pub has_recovery_email_address: bool,
+    #[doc = "True, if some Telegram Passport elements were saved"]
+    pub has_passport_data: bool,
+    #[serde(default)]
+    #[doc = "Information about the recovery email address to which the confirmation email was sent; may be null"]
+    pub recovery_email_address_code_info: Option<EmailAddressAuthenticationCodeInfo>,
+}
+#[cfg(feature = "auth")]
+impl TlObject for PasswordState {
+    const CLASS: &'static str = "PasswordState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "auth")]
+impl TlConstructor for PasswordState {
+    const TYPE: &'static str = "passwordState";
+}
+#[cfg(feature = "auth")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about the current recovery email address"]
+pub struct RecoveryEmailAddress {
+    #[doc = "Recovery email address"]
+    pub recovery_email_address: String,
+}
+#[cfg(feature = "auth")]
+impl TlObject for RecoveryEmailAddress {
+    const CLASS: &'static str = "RecoveryEmailAddress";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "auth")]
+impl TlConstructor for RecoveryEmailAddress {
+    const TYPE: &'static str = "recoveryEmailAddress";
+}
+#[cfg(feature = "auth")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Returns information about the availability of a temporary password, which can be used for payments"]
+pub struct TemporaryPasswordState {
+    #[doc = "True, if a temporary password is available"]
+    pub has_password: bool,
+    #[doc = "Time left before the temporary password expires, in seconds"]
+    pub valid_for: i32,
+}
+#[cfg(feature = "auth")]
+impl TlObject for TemporaryPasswordState {
+    const CLASS: &'static str = "TemporaryPasswordState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "auth")]
+impl TlConstructor for TemporaryPasswordState {
+    const TYPE: &'static str = "temporaryPasswordState";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Contains settings for the authentication of the user's phone number"]
+pub struct PhoneNumberAuthenticationSettings {
+    #[doc = "Pass true if the authentication code may be sent via flash call to the specified phone number"]
+    pub allow_flash_call: bool,
+    #[doc = "Pass true if the authenticated phone number is used on the current device"]
+    pub is_current_phone_number: bool,
+    #[doc = "For official applications only. True, if the app can use Android SMS Retriever API (requires Google Play Services >= 10.2) to automatically receive the authentication code from the SMS. See https://developers.google.com/identity/sms-retriever/ for more details"]
+    pub allow_sms_retriever_api: bool,
+}
+impl TlObject for PhoneNumberAuthenticationSettings {
+    const CLASS: &'static str = "PhoneNumberAuthenticationSettings";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PhoneNumberAuthenticationSettings {
+    const TYPE: &'static str = "phoneNumberAuthenticationSettings";
+}
+impl PhoneNumberAuthenticationSettings {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Pass true if the authentication code may be sent via flash call to the specified phone number"]
+    pub fn with_allow_flash_call(mut self, value: impl Into<bool>) -> Self {
+        self.allow_flash_call = value.into();
+        self
+    }
+    #[doc = "Pass true if the authenticated phone number is used on the current device"]
+    pub fn with_is_current_phone_number(mut self, value: impl Into<bool>) -> Self {
+        self.is_current_phone_number = value.into();
+        self
+    }
+    #[doc = "For official applications only. True, if the app can use Android SMS Retriever API (requires Google Play Services >= 10.2) to automatically receive the authentication code from the SMS. See https://developers.google.com/identity/sms-retriever/ for more details"]
+    pub fn with_allow_sms_retriever_api(mut self, value: impl Into<bool>) -> Self {
+        self.allow_sms_retriever_api = value.into();
+        self
+    }
+}
+impl Validate for PhoneNumberAuthenticationSettings {}
+#[cfg(feature = "auth")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about the period of inactivity after which the current user's account will automatically be deleted"]
+pub struct AccountTtl {
+    #[doc = "Number of days of inactivity before the account will be flagged for deletion; should range from 30-366 days"]
+    pub days: i32,
+}
+#[cfg(feature = "auth")]
+impl TlObject for AccountTtl {
+    const CLASS: &'static str = "AccountTtl";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "auth")]
+impl TlConstructor for AccountTtl {
+    const TYPE: &'static str = "accountTtl";
+}
+#[cfg(feature = "auth")]
+impl AccountTtl {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(days: impl Into<i32>) -> Self {
+        Self { days: days.into() }
+    }
+}
+#[cfg(feature = "auth")]
+impl Validate for AccountTtl {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "days",
+            Measure::Value,
+            i64::from(self.days),
+            Some(30),
+            Some(366),
+        )?;
+        Ok(())
+    }
+}
+#[cfg(feature = "auth")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about one session in a Telegram application used by the current user. Sessions should be shown to the user in the returned order"]
+pub struct Session {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Session identifier"]
+    pub id: i64,
+    #[doc = "True, if this session is the current session"]
+    pub is_current: bool,
+    #[doc = "True, if a password is needed to complete authorization of the session"]
+    pub is_password_pending: bool,
+    #[doc = "Telegram API identifier, as provided by the application"]
+    pub api_id: i32,
+    #[doc = "Name of the application, as provided by the application"]
+    pub application_name: String,
+    #[doc = "The version of the application, as provided by the application"]
+    pub application_version: String,
+    #[doc = "True, if the application is an official application or uses the api_id of an official application"]
+    pub is_official_application: bool,
+    #[doc = "Model of the device the application has been run or is running on, as provided by the application"]
+    pub device_model: String,
+    #[doc = "Operating system the application has been run or is running on, as provided by the application"]
+    pub platform: String,
+    #[doc = "Version of the operating system the application has been run or is running on, as provided by the application"]
+    pub system_version: String,
+    #[doc = "Point in time (Unix timestamp) when the user has logged in"]
+    pub log_in_date: i32,
+    #[doc = "Point in time (Unix timestamp) when the session was last used"]
+    pub last_active_date: i32,
+    #[doc = "IP address from which the session was created, in human-readable format"]
+    pub ip: String,
+    #[doc = "A two-letter country code for the country from which the session was created, based on the IP address"]
+    pub country: String,
+    #[doc = "Region code from which the session was created, based on the IP address"]
+    pub region: String,
+}
+#[cfg(feature = "auth")]
+impl TlObject for Session {
+    const CLASS: &'static str = "Session";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "auth")]
+impl TlConstructor for Session {
+    const TYPE: &'static str = "session";
+}
+#[cfg(feature = "auth")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a list of sessions"]
+pub struct Sessions {
+    #[doc = "List of sessions"]
+    pub sessions: Vec<Session>,
+}
+#[cfg(feature = "auth")]
+impl TlObject for Sessions {
+    const CLASS: &'static str = "Sessions";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "auth")]
+impl TlConstructor for Sessions {
+    const TYPE: &'static str = "sessions";
+}
+#[cfg(feature = "auth")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about one website the current user is logged in with Telegram"]
+pub struct ConnectedWebsite {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Website identifier"]
+    pub id: i64,
+    #[doc = "The domain name of the website"]
+    pub domain_name: String,
+    #[doc = "User identifier of a bot linked with the website"]
+    pub bot_user_id: UserId,
+    #[doc = "The version of a browser used to log in"]
+    pub browser: String,
+    #[doc = "Operating system the browser is running on"]
+    pub platform: String,
+    #[doc = "Point in time (Unix timestamp) when the user was logged in"]
+    pub log_in_date: i32,
+    #[doc = "Point in time (Unix timestamp) when obtained authorization was last used"]
+    pub last_active_date: i32,
+    #[doc = "IP address from which the user was logged in, in human-readable format"]
+    pub ip: String,
+    #[doc = "Human-readable description of a country and a region, from which the user was logged in, based on the IP address"]
+    pub location: String,
+}
+#[cfg(feature = "auth")]
+impl TlObject for ConnectedWebsite {
+    const CLASS: &'static str = "ConnectedWebsite";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "auth")]
+impl TlConstructor for ConnectedWebsite {
+    const TYPE: &'static str = "connectedWebsite";
+}
+#[cfg(feature = "auth")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a list of websites the current user is logged in with Telegram"]
+pub struct ConnectedWebsites {
+    #[doc = "List of connected websites"]
+    pub websites: Vec<ConnectedWebsite>,
+}
+#[cfg(feature = "auth")]
+impl TlObject for ConnectedWebsites {
+    const CLASS: &'static str = "ConnectedWebsites";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "auth")]
+impl TlConstructor for ConnectedWebsites {
+    const TYPE: &'static str = "connectedWebsites";
+}
+#[cfg(feature = "auth")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "New terms of service must be accepted by the user. If the terms of service are declined, then the deleteAccount method should be called with the reason \"Decline ToS update\""]
+pub struct UpdateTermsOfService {
+    #[doc = "Identifier of the terms of service"]
+    pub terms_of_service_id: String,
+    #[doc = "The new terms of service"]
+    pub terms_of_service: TermsOfService,
+}
+#[cfg(feature = "auth")]
+impl TlObject for UpdateTermsOfService {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "auth")]
+impl TlConstructor for UpdateTermsOfService {
+    const TYPE: &'static str = "updateTermsOfService";
+}
@@ -0,0 +1,936 @@
+//! The types of the `files` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a part of a file"]
+pub struct FilePart {
+    #[doc = "File bytes"]
+    pub data: Bytes,
+}
+impl TlObject for FilePart {
+    const CLASS: &'static str = "FilePart";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FilePart {
+    const TYPE: &'static str = "filePart";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The data is not a file"]
+pub struct FileTypeNone {}
+impl TlObject for FileTypeNone {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeNone {
+    const TYPE: &'static str = "fileTypeNone";
+}
+impl FileTypeNone {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeNone {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is an animation"]
+pub struct FileTypeAnimation {}
+impl TlObject for FileTypeAnimation {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeAnimation {
+    const TYPE: &'static str = "fileTypeAnimation";
+}
+impl FileTypeAnimation {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeAnimation {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is an audio file"]
+pub struct FileTypeAudio {}
+impl TlObject for FileTypeAudio {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeAudio {
+    const TYPE: &'static str = "fileTypeAudio";
+}
+impl FileTypeAudio {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeAudio {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is a document"]
+pub struct FileTypeDocument {}
+impl TlObject for FileTypeDocument {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeDocument {
+    const TYPE: &'static str = "fileTypeDocument";
+}
+impl FileTypeDocument {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeDocument {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is a photo"]
+pub struct FileTypePhoto {}
+impl TlObject for FileTypePhoto {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypePhoto {
+    const TYPE: &'static str = "fileTypePhoto";
+}
+impl FileTypePhoto {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypePhoto {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is a profile photo"]
+pub struct FileTypeProfilePhoto {}
+impl TlObject for FileTypeProfilePhoto {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeProfilePhoto {
+    const TYPE: &'static str = "fileTypeProfilePhoto";
+}
+impl FileTypeProfilePhoto {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeProfilePhoto {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file was sent to a secret chat (the file type is not known to the server)"]
+pub struct FileTypeSecret {}
+impl TlObject for FileTypeSecret {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeSecret {
+    const TYPE: &'static str = "fileTypeSecret";
+}
+impl FileTypeSecret {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeSecret {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is a thumbnail of a file from a secret chat"]
+pub struct FileTypeSecretThumbnail {}
+impl TlObject for FileTypeSecretThumbnail {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeSecretThumbnail {
+    const TYPE: &'static str = "fileTypeSecretThumbnail";
+}
+impl FileTypeSecretThumbnail {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeSecretThumbnail {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is a file from Secure storage used for storing Telegram Passport files"]
+pub struct FileTypeSecure {}
+impl TlObject for FileTypeSecure {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeSecure {
+    const TYPE: &'static str = "fileTypeSecure";
+}
+impl FileTypeSecure {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeSecure {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is a sticker"]
+pub struct FileTypeSticker {}
+impl TlObject for FileTypeSticker {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeSticker {
+    const TYPE: &'static str = "fileTypeSticker";
+}
+impl FileTypeSticker {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeSticker {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is a thumbnail of another file"]
+pub struct FileTypeThumbnail {}
+impl TlObject for FileTypeThumbnail {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeThumbnail {
+    const TYPE: &'static str = "fileTypeThumbnail";
+}
+impl FileTypeThumbnail {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeThumbnail {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file type is not yet known"]
+pub struct FileTypeUnknown {}
+impl TlObject for FileTypeUnknown {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeUnknown {
+    const TYPE: &'static str = "fileTypeUnknown";
+}
+impl FileTypeUnknown {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeUnknown {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is a video"]
+pub struct FileTypeVideo {}
+impl TlObject for FileTypeVideo {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeVideo {
+    const TYPE: &'static str = "fileTypeVideo";
+}
+impl FileTypeVideo {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeVideo {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is a video note"]
+pub struct FileTypeVideoNote {}
+impl TlObject for FileTypeVideoNote {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeVideoNote {
+    const TYPE: &'static str = "fileTypeVideoNote";
+}
+impl FileTypeVideoNote {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeVideoNote {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is a voice note"]
+pub struct FileTypeVoiceNote {}
+impl TlObject for FileTypeVoiceNote {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeVoiceNote {
+    const TYPE: &'static str = "fileTypeVoiceNote";
+}
+impl FileTypeVoiceNote {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeVoiceNote {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file is a wallpaper or a background pattern"]
+pub struct FileTypeWallpaper {}
+impl TlObject for FileTypeWallpaper {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for FileTypeWallpaper {
+    const TYPE: &'static str = "fileTypeWallpaper";
+}
+impl FileTypeWallpaper {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for FileTypeWallpaper {}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Represents the type of a file"]
+pub enum FileType {
+    FileTypeNone(FileTypeNone),
+    FileTypeAnimation(FileTypeAnimation),
+    FileTypeAudio(FileTypeAudio),
+    FileTypeDocument(FileTypeDocument),
+    FileTypePhoto(FileTypePhoto),
+    FileTypeProfilePhoto(FileTypeProfilePhoto),
+    FileTypeSecret(FileTypeSecret),
+    FileTypeSecretThumbnail(FileTypeSecretThumbnail),
+    FileTypeSecure(FileTypeSecure),
+    FileTypeSticker(FileTypeSticker),
+    FileTypeThumbnail(FileTypeThumbnail),
+    FileTypeUnknown(FileTypeUnknown),
+    FileTypeVideo(FileTypeVideo),
+    FileTypeVideoNote(FileTypeVideoNote),
+    FileTypeVoiceNote(FileTypeVoiceNote),
+    FileTypeWallpaper(FileTypeWallpaper),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for FileType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "fileTypeNone" => Deserialize::deserialize(value).map(FileType::FileTypeNone),
+            "fileTypeAnimation" => Deserialize::deserialize(value).map(FileType::FileTypeAnimation),
+            "fileTypeAudio" => Deserialize::deserialize(value).map(FileType::FileTypeAudio),
+            "fileTypeDocument" => Deserialize::deserialize(value).map(FileType::FileTypeDocument),
+            "fileTypePhoto" => Deserialize::deserialize(value).map(FileType::FileTypePhoto),
+            "fileTypeProfilePhoto" => {
+                Deserialize::deserialize(value).map(FileType::FileTypeProfilePhoto)
+            }
+            "fileTypeSecret" => Deserialize::deserialize(value).map(FileType::FileTypeSecret),
+            "fileTypeSecretThumbnail" => {
+                Deserialize::deserialize(value).map(FileType::FileTypeSecretThumbnail)
+            }
+            "fileTypeSecure" => Deserialize::deserialize(value).map(FileType::FileTypeSecure),
+            "fileTypeSticker" => Deserialize::deserialize(value).map(FileType::FileTypeSticker),
+            "fileTypeThumbnail" => Deserialize::deserialize(value).map(FileType::FileTypeThumbnail),
+            "fileTypeUnknown" => Deserialize::deserialize(value).map(FileType::FileTypeUnknown),
+            "fileTypeVideo" => Deserialize::deserialize(value).map(FileType::FileTypeVideo),
+            "fileTypeVideoNote" => Deserialize::deserialize(value).map(FileType::FileTypeVideoNote),
+            "fileTypeVoiceNote" => Deserialize::deserialize(value).map(FileType::FileTypeVoiceNote),
+            "fileTypeWallpaper" => Deserialize::deserialize(value).map(FileType::FileTypeWallpaper),
+            _ => Unknown::deserialize(value).map(FileType::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for FileType {
+    const CLASS: &'static str = "FileType";
+    fn type_name(&self) -> &str {
+        match self {
+            FileType::FileTypeNone(_) => "fileTypeNone",
+            FileType::FileTypeAnimation(_) => "fileTypeAnimation",
+            FileType::FileTypeAudio(_) => "fileTypeAudio",
+            FileType::FileTypeDocument(_) => "fileTypeDocument",
+            FileType::FileTypePhoto(_) => "fileTypePhoto",
+            FileType::FileTypeProfilePhoto(_) => "fileTypeProfilePhoto",
+            FileType::FileTypeSecret(_) => "fileTypeSecret",
+            FileType::FileTypeSecretThumbnail(_) => "fileTypeSecretThumbnail",
+            FileType::FileTypeSecure(_) => "fileTypeSecure",
+            FileType::FileTypeSticker(_) => "fileTypeSticker",
+            FileType::FileTypeThumbnail(_) => "fileTypeThumbnail",
+            FileType::FileTypeUnknown(_) => "fileTypeUnknown",
+            FileType::FileTypeVideo(_) => "fileTypeVideo",
+            FileType::FileTypeVideoNote(_) => "fileTypeVideoNote",
+            FileType::FileTypeVoiceNote(_) => "fileTypeVoiceNote",
+            FileType::FileTypeWallpaper(_) => "fileTypeWallpaper",
+            FileType::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for FileType {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            FileType::FileTypeNone(value) => value.validate(),
+            FileType::FileTypeAnimation(value) => value.validate(),
+            FileType::FileTypeAudio(value) => value.validate(),
+            FileType::FileTypeDocument(value) => value.validate(),
+            FileType::FileTypePhoto(value) => value.validate(),
+            FileType::FileTypeProfilePhoto(value) => value.validate(),
+            FileType::FileTypeSecret(value) => value.validate(),
+            FileType::FileTypeSecretThumbnail(value) => value.validate(),
+            FileType::FileTypeSecure(value) => value.validate(),
+            FileType::FileTypeSticker(value) => value.validate(),
+            FileType::FileTypeThumbnail(value) => value.validate(),
+            FileType::FileTypeUnknown(value) => value.validate(),
+            FileType::FileTypeVideo(value) => value.validate(),
+            FileType::FileTypeVideoNote(value) => value.validate(),
+            FileType::FileTypeVoiceNote(value) => value.validate(),
+            FileType::FileTypeWallpaper(value) => value.validate(),
+            FileType::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<FileTypeNone> for FileType {
+    fn from(value: FileTypeNone) -> Self {
+        FileType::FileTypeNone(value)
+    }
+}
+impl From<FileTypeAnimation> for FileType {
+    fn from(value: FileTypeAnimation) -> Self {
+        FileType::FileTypeAnimation(value)
+    }
+}
+impl From<FileTypeAudio> for FileType {
+    fn from(value: FileTypeAudio) -> Self {
+        FileType::FileTypeAudio(value)
+    }
+}
+impl From<FileTypeDocument> for FileType {
+    fn from(value: FileTypeDocument) -> Self {
+        FileType::FileTypeDocument(value)
+    }
+}
+impl From<FileTypePhoto> for FileType {
+    fn from(value: FileTypePhoto) -> Self {
+        FileType::FileTypePhoto(value)
+    }
+}
+impl From<FileTypeProfilePhoto> for FileType {
+    fn from(value: FileTypeProfilePhoto) -> Self {
+        FileType::FileTypeProfilePhoto(value)
+    }
+}
+impl From<FileTypeSecret> for FileType {
+    fn from(value: FileTypeSecret) -> Self {
+        FileType::FileTypeSecret(value)
+    }
+}
+impl From<FileTypeSecretThumbnail> for FileType {
+    fn from(value: FileTypeSecretThumbnail) -> Self {
+        FileType::FileTypeSecretThumbnail(value)
+    }
+}
+impl From<FileTypeSecure> for FileType {
+    fn from(value: FileTypeSecure) -> Self {
+        FileType::FileTypeSecure(value)
+    }
+}
+impl From<FileTypeSticker> for FileType {
+    fn from(value: FileTypeSticker) -> Self {
+        FileType::FileTypeSticker(value)
+    }
+}
+impl From<FileTypeThumbnail> for FileType {
+    fn from(value: FileTypeThumbnail) -> Self {
+        FileType::FileTypeThumbnail(value)
+    }
+}
+impl From<FileTypeUnknown> for FileType {
+    fn from(value: FileTypeUnknown) -> Self {
+        FileType::FileTypeUnknown(value)
+    }
+}
+impl From<FileTypeVideo> for FileType {
+    fn from(value: FileTypeVideo) -> Self {
+        FileType::FileTypeVideo(value)
+    }
+}
+impl From<FileTypeVideoNote> for FileType {
+    fn from(value: FileTypeVideoNote) -> Self {
+        FileType::FileTypeVideoNote(value)
+    }
+}
+impl From<FileTypeVoiceNote> for FileType {
+    fn from(value: FileTypeVoiceNote) -> Self {
+        FileType::FileTypeVoiceNote(value)
+    }
+}
+impl From<FileTypeWallpaper> for FileType {
+    fn from(value: FileTypeWallpaper) -> Self {
+        FileType::FileTypeWallpaper(value)
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains the storage usage statistics for a specific file type"]
+pub struct StorageStatisticsByFileType {
+    #[doc = "File type"]
+    pub file_type: FileType,
+    #[doc = "Total size of the files"]
+    pub size: i64,
+    #[doc = "Total number of files"]
+    pub count: i32,
+}
+impl TlObject for StorageStatisticsByFileType {
+    const CLASS: &'static str = "StorageStatisticsByFileType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for StorageStatisticsByFileType {
+    const TYPE: &'static str = "storageStatisticsByFileType";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains the storage usage statistics for a specific chat"]
+pub struct StorageStatisticsByChat {
+    #[doc = "Chat identifier; 0 if none"]
+    pub chat_id: ChatId,
+    #[doc = "Total size of the files in the chat"]
+    pub size: i64,
+    #[doc = "Total number of files in the chat"]
+    pub count: i32,
+    #[doc = "Statistics split by file types"]
+    pub by_file_type: Vec<StorageStatisticsByFileType>,
+}
+impl TlObject for StorageStatisticsByChat {
+    const CLASS: &'static str = "StorageStatisticsByChat";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for StorageStatisticsByChat {
+    const TYPE: &'static str = "storageStatisticsByChat";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains the exact storage usage statistics split by chats and file type"]
+pub struct StorageStatistics {
+    #[doc = "Total size of files"]
+    pub size: i64,
+    #[doc = "Total number of files"]
+    pub count: i32,
+    #[doc = "Statistics split by chats"]
+    pub by_chat: Vec<StorageStatisticsByChat>,
+}
+impl TlObject for StorageStatistics {
+    const CLASS: &'static str = "StorageStatistics";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for StorageStatistics {
+    const TYPE: &'static str = "storageStatistics";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains approximate storage usage statistics, excluding files of unknown file type"]
+pub struct StorageStatisticsFast {
+    #[doc = "Approximate total size of files"]
+    pub files_size: i64,
+    #[doc = "Approximate number of files"]
+    pub file_count: i32,
+    #[doc = "Size of the database"]
+    pub database_size: i64,
+    #[doc = "Size of the language pack database"]
+    pub language_pack_database_size: i64,
+    #[doc = "Size of the TDLib internal log"]
+    pub log_size: i64,
+}
+impl TlObject for StorageStatisticsFast {
+    const CLASS: &'static str = "StorageStatisticsFast";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for StorageStatisticsFast {
+    const TYPE: &'static str = "storageStatisticsFast";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains database statistics"]
+pub struct DatabaseStatistics {
+    #[doc = "Database statistics in an unspecified human-readable format"]
+    pub statistics: String,
+}
+impl TlObject for DatabaseStatistics {
+    const CLASS: &'static str = "DatabaseStatistics";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for DatabaseStatistics {
+    const TYPE: &'static str = "databaseStatistics";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about the total amount of data that was used to send and receive files"]
+pub struct NetworkStatisticsEntryFile {
+    #[doc = "Type of the file the data is part of"]
+    pub file_type: FileType,
+    #[doc = "Type of the network the data was sent through. Call setNetworkType to maintain the actual network type"]
+    pub network_type: NetworkType,
+    #[doc = "Total number of bytes sent"]
+    pub sent_bytes: i64,
+    #[doc = "Total number of bytes received"]
+    pub received_bytes: i64,
+}
+impl TlObject for NetworkStatisticsEntryFile {
+    const CLASS: &'static str = "NetworkStatisticsEntry";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for NetworkStatisticsEntryFile {
+    const TYPE: &'static str = "networkStatisticsEntryFile";
+}
+impl NetworkStatisticsEntryFile {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(file_type: impl Into<FileType>, network_type: impl Into<NetworkType>) -> Self {
+        Self {
+            file_type: file_type.into(),
+            network_type: network_type.into(),
+            sent_bytes: Default::default(),
+            received_bytes: Default::default(),
+        }
+    }
+    #[doc = "Total number of bytes sent"]
+    pub fn with_sent_bytes(mut self, value: impl Into<i64>) -> Self {
+        self.sent_bytes = value.into();
+        self
+    }
+    #[doc = "Total number of bytes received"]
+    pub fn with_received_bytes(mut self, value: impl Into<i64>) -> Self {
+        self.received_bytes = value.into();
+        self
+    }
+}
+impl Validate for NetworkStatisticsEntryFile {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.file_type
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "file_type"))?;
+        self.network_type
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "network_type"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about the total amount of data that was used for calls"]
+pub struct NetworkStatisticsEntryCall {
+    #[doc = "Type of the network the data was sent through. Call setNetworkType to maintain the actual network type"]
+    pub network_type: NetworkType,
+    #[doc = "Total number of bytes sent"]
+    pub sent_bytes: i64,
+    #[doc = "Total number of bytes received"]
+    pub received_bytes: i64,
+    #[doc = "Total call duration, in seconds"]
+    pub duration: f64,
+}
+impl TlObject for NetworkStatisticsEntryCall {
+    const CLASS: &'static str = "NetworkStatisticsEntry";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for NetworkStatisticsEntryCall {
+    const TYPE: &'static str = "networkStatisticsEntryCall";
+}
+impl NetworkStatisticsEntryCall {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(network_type: impl Into<NetworkType>) -> Self {
+        Self {
+            network_type: network_type.into(),
+            sent_bytes: Default::default(),
+            received_bytes: Default::default(),
+            duration: Default::default(),
+        }
+    }
+    #[doc = "Total number of bytes sent"]
+    pub fn with_sent_bytes(mut self, value: impl Into<i64>) -> Self {
+        self.sent_bytes = value.into();
+        self
+    }
+    #[doc = "Total number of bytes received"]
+    pub fn with_received_bytes(mut self, value: impl Into<i64>) -> Self {
+        self.received_bytes = value.into();
+        self
+    }
+    #[doc = "Total call duration, in seconds"]
+    pub fn with_duration(mut self, value: impl Into<f64>) -> Self {
+        self.duration = value.into();
+        self
+    }
+}
+impl Validate for NetworkStatisticsEntryCall {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.network_type
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "network_type"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Contains statistics about network usage"]
+pub enum NetworkStatisticsEntry {
+    NetworkStatisticsEntryFile(NetworkStatisticsEntryFile),
+    NetworkStatisticsEntryCall(NetworkStatisticsEntryCall),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for NetworkStatisticsEntry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "networkStatisticsEntryFile" => Deserialize::deserialize(value)
+                .map(NetworkStatisticsEntry::NetworkStatisticsEntryFile),
+            "networkStatisticsEntryCall" => Deserialize::deserialize(value)
+                .map(NetworkStatisticsEntry::NetworkStatisticsEntryCall),
+            _ => Unknown::deserialize(value).map(NetworkStatisticsEntry::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for NetworkStatisticsEntry {
+    const CLASS: &'static str = "NetworkStatisticsEntry";
+    fn type_name(&self) -> &str {
+        match self {
+            NetworkStatisticsEntry::NetworkStatisticsEntryFile(_) => "networkStatisticsEntryFile",
+            NetworkStatisticsEntry::NetworkStatisticsEntryCall(_) => "networkStatisticsEntryCall",
+            NetworkStatisticsEntry::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for NetworkStatisticsEntry {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            NetworkStatisticsEntry::NetworkStatisticsEntryFile(value) => value.validate(),
+            NetworkStatisticsEntry::NetworkStatisticsEntryCall(value) => value.validate(),
+            NetworkStatisticsEntry::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<NetworkStatisticsEntryFile> for NetworkStatisticsEntry {
+    fn from(value: NetworkStatisticsEntryFile) -> Self {
+        NetworkStatisticsEntry::NetworkStatisticsEntryFile(value)
+    }
+}
+impl From<NetworkStatisticsEntryCall> for NetworkStatisticsEntry {
+    fn from(value: NetworkStatisticsEntryCall) -> Self {
+        NetworkStatisticsEntry::NetworkStatisticsEntryCall(value)
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A full list of available network statistic entries"]
+pub struct NetworkStatistics {
+    #[doc = "Point in time (Unix timestamp) when the app began collecting statistics"]
+    pub since_date: i32,
+    #[doc = "Network statistics entries"]
+    pub entries: Vec<NetworkStatisticsEntry>,
+}
+impl TlObject for NetworkStatistics {
+    const CLASS: &'static str = "NetworkStatistics";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for NetworkStatistics {
+    const TYPE: &'static str = "networkStatistics";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Contains auto-download settings"]
+pub struct AutoDownloadSettings {
+    #[doc = "True, if the auto-download is enabled"]
+    pub is_auto_download_enabled: bool,
+    #[doc = "The maximum size of a photo file to be auto-downloaded"]
+    pub max_photo_file_size: i32,
+    #[doc = "The maximum size of a video file to be auto-downloaded"]
+    pub max_video_file_size: i32,
+    #[doc = "The maximum size of other file types to be auto-downloaded"]
+    pub max_other_file_size: i32,
+    #[doc = "The maximum suggested bitrate for uploaded videos"]
+    pub video_upload_bitrate: i32,
+    #[doc = "True, if the beginning of videos needs to be preloaded for instant playback"]
+    pub preload_large_videos: bool,
+    #[doc = "True, if the next audio track needs to be preloaded while the user is listening to an audio file"]
+    pub preload_next_audio: bool,
+    #[doc = "True, if \"use less data for calls\" option needs to be enabled"]
+    pub use_less_data_for_calls: bool,
+}
+impl TlObject for AutoDownloadSettings {
+    const CLASS: &'static str = "AutoDownloadSettings";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for AutoDownloadSettings {
+    const TYPE: &'static str = "autoDownloadSettings";
+}
+impl AutoDownloadSettings {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "True, if the auto-download is enabled"]
+    pub fn with_is_auto_download_enabled(mut self, value: impl Into<bool>) -> Self {
+        self.is_auto_download_enabled = value.into();
+        self
+    }
+    #[doc = "The maximum size of a photo file to be auto-downloaded"]
+    pub fn with_max_photo_file_size(mut self, value: impl Into<i32>) -> Self {
+        self.max_photo_file_size = value.into();
+        self
+    }
+    #[doc = "The maximum size of a video file to be auto-downloaded"]
+    pub fn with_max_video_file_size(mut self, value: impl Into<i32>) -> Self {
+        self.max_video_file_size = value.into();
+        self
+    }
+    #[doc = "The maximum size of other file types to be auto-downloaded"]
+    pub fn with_max_other_file_size(mut self, value: impl Into<i32>) -> Self {
+        self.max_other_file_size = value.into();
+        self
+    }
+    #[doc = "The maximum suggested bitrate for uploaded videos"]
+    pub fn with_video_upload_bitrate(mut self, value: impl Into<i32>) -> Self {
+        self.video_upload_bitrate = value.into();
+        self
+    }
+    #[doc = "True, if the beginning of videos needs to be preloaded for instant playback"]
+    pub fn with_preload_large_videos(mut self, value: impl Into<bool>) -> Self {
+        self.preload_large_videos = value.into();
+        self
+    }
+    #[doc = "True, if the next audio track needs to be preloaded while the user is listening to an audio file"]
+    pub fn with_preload_next_audio(mut self, value: impl Into<bool>) -> Self {
+        self.preload_next_audio = value.into();
+        self
+    }
+    #[doc = "True, if \"use less data for calls\" option needs to be enabled"]
+    pub fn with_use_less_data_for_calls(mut self, value: impl Into<bool>) -> Self {
+        self.use_less_data_for_calls = value.into();
+        self
+    }
+}
+impl Validate for AutoDownloadSettings {}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains auto-download settings presets for the user"]
+pub struct AutoDownloadSettingsPresets {
+    #[doc = "Preset with lowest settings; supposed to be used by default when roaming"]
+    pub low: AutoDownloadSettings,
+    #[doc = "Preset with medium settings; supposed to be used by default when using mobile data"]
+    pub medium: AutoDownloadSettings,
+    #[doc = "Preset with highest settings; supposed to be used by default when connected on Wi-Fi"]
+    pub high: AutoDownloadSettings,
+}
+impl TlObject for AutoDownloadSettingsPresets {
+    const CLASS: &'static str = "AutoDownloadSettingsPresets";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for AutoDownloadSettingsPresets {
+    const TYPE: &'static str = "autoDownloadSettingsPresets";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Information about a file was updated"]
+pub struct UpdateFile {
+    #[doc = "New data about the file"]
+    pub file: File,
+}
+impl TlObject for UpdateFile {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for UpdateFile {
+    const TYPE: &'static str = "updateFile";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The file generation process needs to be started by the client"]
+pub struct UpdateFileGenerationStart {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Unique identifier for the generation process"]
+    pub generation_id: i64,
+    #[doc = "The path to a file from which a new file is generated; may be empty"]
+    pub original_path: String,
+    #[doc = "The path to a file that should be created and where the new file should be generated"]
+    pub destination_path: String,
+    #[doc = "String specifying the conversion applied to the original file. If conversion is \"#url#\" than original_path contains an HTTP/HTTPS URL of a file, which should be downloaded by the client"]
+    pub conversion: String,
+}
+impl TlObject for UpdateFileGenerationStart {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for UpdateFileGenerationStart {
+    const TYPE: &'static str = "updateFileGenerationStart";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "File generation is no longer needed"]
+pub struct UpdateFileGenerationStop {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Unique identifier for the generation process"]
+    pub generation_id: i64,
+}
+impl TlObject for UpdateFileGenerationStop {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for UpdateFileGenerationStop {
+    const TYPE: &'static str = "updateFileGenerationStop";
+}
@@ -0,0 +1,167 @@
+//! The types of the `logging` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The log is written to stderr or an OS specific log"]
+pub struct LogStreamDefault {}
+impl TlObject for LogStreamDefault {
+    const CLASS: &'static str = "LogStream";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for LogStreamDefault {
+    const TYPE: &'static str = "logStreamDefault";
+}
+impl LogStreamDefault {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for LogStreamDefault {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The log is written to a file"]
+pub struct LogStreamFile {
+    #[doc = "Path to the file to where the internal TDLib log will be written"]
+    pub path: String,
+    #[doc = "The maximum size of the file to where the internal TDLib log is written before the file will be auto-rotated"]
+    pub max_file_size: i64,
+}
+impl TlObject for LogStreamFile {
+    const CLASS: &'static str = "LogStream";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for LogStreamFile {
+    const TYPE: &'static str = "logStreamFile";
+}
+impl LogStreamFile {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Path to the file to where the internal TDLib log will be written"]
+    pub fn with_path(mut self, value: impl Into<String>) -> Self {
+        self.path = value.into();
+        self
+    }
+    #[doc = "The maximum size of the file to where the internal TDLib log is written before the file will be auto-rotated"]
+    pub fn with_max_file_size(mut self, value: impl Into<i64>) -> Self {
+        self.max_file_size = value.into();
+        self
+    }
+}
+impl Validate for LogStreamFile {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The log is written nowhere"]
+pub struct LogStreamEmpty {}
+impl TlObject for LogStreamEmpty {
+    const CLASS: &'static str = "LogStream";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for LogStreamEmpty {
+    const TYPE: &'static str = "logStreamEmpty";
+}
+impl LogStreamEmpty {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for LogStreamEmpty {}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes a stream to which TDLib internal log is written"]
+pub enum LogStream {
+    LogStreamDefault(LogStreamDefault),
+    LogStreamFile(LogStreamFile),
+    LogStreamEmpty(LogStreamEmpty),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for LogStream {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "logStreamDefault" => Deserialize::deserialize(value).map(LogStream::LogStreamDefault),
+            "logStreamFile" => Deserialize::deserialize(value).map(LogStream::LogStreamFile),
+            "logStreamEmpty" => Deserialize::deserialize(value).map(LogStream::LogStreamEmpty),
+            _ => Unknown::deserialize(value).map(LogStream::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for LogStream {
+    const CLASS: &'static str = "LogStream";
+    fn type_name(&self) -> &str {
+        match self {
+            LogStream::LogStreamDefault(_) => "logStreamDefault",
+            LogStream::LogStreamFile(_) => "logStreamFile",
+            LogStream::LogStreamEmpty(_) => "logStreamEmpty",
+            LogStream::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for LogStream {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            LogStream::LogStreamDefault(value) => value.validate(),
+            LogStream::LogStreamFile(value) => value.validate(),
+            LogStream::LogStreamEmpty(value) => value.validate(),
+            LogStream::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<LogStreamDefault> for LogStream {
+    fn from(value: LogStreamDefault) -> Self {
+        LogStream::LogStreamDefault(value)
+    }
+}
+impl From<LogStreamFile> for LogStream {
+    fn from(value: LogStreamFile) -> Self {
+        LogStream::LogStreamFile(value)
+    }
+}
+impl From<LogStreamEmpty> for LogStream {
+    fn from(value: LogStreamEmpty) -> Self {
+        LogStream::LogStreamEmpty(value)
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a TDLib internal log verbosity level"]
+pub struct LogVerbosityLevel {
+    #[doc = "Log verbosity level"]
+    pub verbosity_level: i32,
+}
+impl TlObject for LogVerbosityLevel {
+    const CLASS: &'static str = "LogVerbosityLevel";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for LogVerbosityLevel {
+    const TYPE: &'static str = "logVerbosityLevel";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a list of available TDLib internal log tags"]
+pub struct LogTags {
+    #[doc = "List of log tags"]
+    pub tags: Vec<String>,
+}
+impl TlObject for LogTags {
+    const CLASS: &'static str = "LogTags";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for LogTags {
+    const TYPE: &'static str = "logTags";
+}
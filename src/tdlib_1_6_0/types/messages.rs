@@ -0,0 +1,5192 @@
+//! The types of the `messages` feature.
+
+use super::*;
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a list of text entities"]
+pub struct TextEntities {
+    #[doc = "List of text entities"]
+    pub entities: Vec<TextEntity>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for TextEntities {
+    const CLASS: &'static str = "TextEntities";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for TextEntities {
+    const TYPE: &'static str = "textEntities";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The message was originally written by a known user"]
+pub struct MessageForwardOriginUser {
+    #[doc = "Identifier of the user that originally sent the message"]
+    pub sender_user_id: UserId,
+}
+impl TlObject for MessageForwardOriginUser {
+    const CLASS: &'static str = "MessageForwardOrigin";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for MessageForwardOriginUser {
+    const TYPE: &'static str = "messageForwardOriginUser";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The message was originally written by a user, which is hidden by their privacy settings"]
+pub struct MessageForwardOriginHiddenUser {
+    #[doc = "Name of the sender"]
+    pub sender_name: String,
+}
+impl TlObject for MessageForwardOriginHiddenUser {
+    const CLASS: &'static str = "MessageForwardOrigin";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for MessageForwardOriginHiddenUser {
+    const TYPE: &'static str = "messageForwardOriginHiddenUser";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The message was originally a post in a channel"]
+pub struct MessageForwardOriginChannel {
+    #[doc = "Identifier of the chat from which the message was originally forwarded"]
+    pub chat_id: ChatId,
+    #[doc = "Message identifier of the original message; 0 if unknown"]
+    pub message_id: MessageId,
+    #[doc = "Original post author signature"]
+    pub author_signature: String,
+}
+impl TlObject for MessageForwardOriginChannel {
+    const CLASS: &'static str = "MessageForwardOrigin";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for MessageForwardOriginChannel {
+    const TYPE: &'static str = "messageForwardOriginChannel";
+}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Contains information about the origin of a forwarded message"]
+pub enum MessageForwardOrigin {
+    MessageForwardOriginUser(MessageForwardOriginUser),
+    MessageForwardOriginHiddenUser(MessageForwardOriginHiddenUser),
+    MessageForwardOriginChannel(MessageForwardOriginChannel),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for MessageForwardOrigin {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "messageForwardOriginUser" => {
+                Deserialize::deserialize(value).map(MessageForwardOrigin::MessageForwardOriginUser)
+            }
+            "messageForwardOriginHiddenUser" => Deserialize::deserialize(value)
+                .map(MessageForwardOrigin::MessageForwardOriginHiddenUser),
+            "messageForwardOriginChannel" => Deserialize::deserialize(value)
+                .map(MessageForwardOrigin::MessageForwardOriginChannel),
+            _ => Unknown::deserialize(value).map(MessageForwardOrigin::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for MessageForwardOrigin {
+    const CLASS: &'static str = "MessageForwardOrigin";
+    fn type_name(&self) -> &str {
+        match self {
+            MessageForwardOrigin::MessageForwardOriginUser(_) => "messageForwardOriginUser",
+            MessageForwardOrigin::MessageForwardOriginHiddenUser(_) => {
+                "messageForwardOriginHiddenUser"
+            }
+            MessageForwardOrigin::MessageForwardOriginChannel(_) => "messageForwardOriginChannel",
+            MessageForwardOrigin::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about a forwarded message"]
+pub struct MessageForwardInfo {
+    #[doc = "Origin of a forwarded message"]
+    pub origin: MessageForwardOrigin,
+    #[doc = "Point in time (Unix timestamp) when the message was originally sent"]
+    pub date: i32,
+    #[doc = "For messages forwarded to the chat with the current user (Saved Messages) or to the channel's discussion group, the identifier of the chat from which the message was forwarded last time; 0 if unknown"]
+    pub from_chat_id: ChatId,
+    #[doc = "For messages forwarded to the chat with the current user (Saved Messages) or to the channel's discussion group, the identifier of the original message from which the new message was forwarded last time; 0 if unknown"]
+    pub from_message_id: MessageId,
+}
+impl TlObject for MessageForwardInfo {
+    const CLASS: &'static str = "MessageForwardInfo";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for MessageForwardInfo {
+    const TYPE: &'static str = "messageForwardInfo";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The message is being sent now, but has not yet been delivered to the server"]
+pub struct MessageSendingStatePending {}
+impl TlObject for MessageSendingStatePending {
+    const CLASS: &'static str = "MessageSendingState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for MessageSendingStatePending {
+    const TYPE: &'static str = "messageSendingStatePending";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The message failed to be sent"]
+pub struct MessageSendingStateFailed {
+    #[doc = "An error code; 0 if unknown"]
+    pub error_code: i32,
+    #[doc = "Error message"]
+    pub error_message: String,
+    #[doc = "True, if the message can be re-sent"]
+    pub can_retry: bool,
+    #[doc = "Time left before the message can be re-sent, in seconds. No update is sent when this field changes"]
+    pub retry_after: f64,
+}
+impl TlObject for MessageSendingStateFailed {
+    const CLASS: &'static str = "MessageSendingState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for MessageSendingStateFailed {
+    const TYPE: &'static str = "messageSendingStateFailed";
+}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Contains information about the sending state of the message"]
+pub enum MessageSendingState {
+    MessageSendingStatePending(MessageSendingStatePending),
+    MessageSendingStateFailed(MessageSendingStateFailed),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for MessageSendingState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "messageSendingStatePending" => {
+                Deserialize::deserialize(value).map(MessageSendingState::MessageSendingStatePending)
+            }
+            "messageSendingStateFailed" => {
+                Deserialize::deserialize(value).map(MessageSendingState::MessageSendingStateFailed)
+            }
+            _ => Unknown::deserialize(value).map(MessageSendingState::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for MessageSendingState {
+    const CLASS: &'static str = "MessageSendingState";
+    fn type_name(&self) -> &str {
+        match self {
+            MessageSendingState::MessageSendingStatePending(_) => "messageSendingStatePending",
+            MessageSendingState::MessageSendingStateFailed(_) => "messageSendingStateFailed",
+            MessageSendingState::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Describes a message"]
+pub struct Message {
+    #[doc = "Message identifier, unique for the chat to which the message belongs"]
+    pub id: MessageId,
+    #[doc = "Identifier of the user who sent the message; 0 if unknown. Currently, it is unknown for channel posts and for channel posts automatically forwarded to discussion group"]
+    pub sender_user_id: UserId,
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[serde(default)]
+    #[doc = "Information about the sending state of the message; may be null"]
+    pub sending_state: Option<MessageSendingState>,
+    #[serde(default)]
+    #[doc = "Information about the scheduling state of the message; may be null"]
+    pub scheduling_state: Option<MessageSchedulingState>,
+    #[doc = "True, if the message is outgoing"]
+    pub is_outgoing: bool,
+    #[doc = "True, if the message can be edited. For live location and poll messages this fields shows whether editMessageLiveLocation or stopPoll can be used with this message by the client"]
+    pub can_be_edited: bool,
+    #[doc = "True, if the message can be forwarded"]
+    pub can_be_forwarded: bool,
+    #[doc = "True, if the message can be deleted only for the current user while other users will continue to see it"]
+    pub can_be_deleted_only_for_self: bool,
+    #[doc = "True, if the message can be deleted for all users"]
+    pub can_be_deleted_for_all_users: bool,
+    #[doc = "True, if the message is a channel post. All messages to channels are channel posts, all other messages are not channel posts"]
+    pub is_channel_post: bool,
+    #[doc = "True, if the message contains an unread mention for the current user"]
+    pub contains_unread_mention: bool,
+    #[doc = "Point in time (Unix timestamp) when the message was sent"]
+    pub date: i32,
+    #[doc = "Point in time (Unix timestamp) when the message was last edited"]
+    pub edit_date: i32,
+    #[serde(default)]
+    #[doc = "Information about the initial message sender; may be null"]
+    pub forward_info: Option<MessageForwardInfo>,
+    #[doc = "If non-zero, the identifier of the message this message is replying to; can be the identifier of a deleted message"]
+    pub reply_to_message_id: MessageId,
+    #[doc = "For self-destructing messages, the message's TTL (Time To Live), in seconds; 0 if none. TDLib will send updateDeleteMessages or updateMessageContent once the TTL expires"]
+    pub ttl: i32,
+    #[doc = "Time left before the message expires, in seconds"]
+    pub ttl_expires_in: f64,
+    #[doc = "If non-zero, the user identifier of the bot through which this message was sent"]
+    pub via_bot_user_id: UserId,
+    #[doc = "For channel posts, optional author signature"]
+    pub author_signature: String,
+    #[doc = "Number of times this message was viewed"]
+    pub views: i32,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Unique identifier of an album this message belongs to. Only photos and videos can be grouped together in albums"]
+    pub media_album_id: i64,
+    #[doc = "If non-empty, contains a human-readable description of the reason why access to this message must be restricted"]
+    pub restriction_reason: String,
+    #[doc = "Content of the message"]
+    pub content: MessageContent,
+    #[serde(default)]
+    #[doc = "Reply markup for the message; may be null"]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+impl TlObject for Message {
+    const CLASS: &'static str = "Message";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for Message {
+    const TYPE: &'static str = "message";
+}
+#[cfg(any(feature = "calls", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a list of messages"]
+pub struct Messages {
+    #[doc = "Approximate total count of messages found"]
+    pub total_count: i32,
+    #[doc = "List of messages; messages may be null"]
+    pub messages: Vec<Option<Message>>,
+}
+#[cfg(any(feature = "calls", feature = "messages"))]
+impl TlObject for Messages {
+    const CLASS: &'static str = "Messages";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "calls", feature = "messages"))]
+impl TlConstructor for Messages {
+    const TYPE: &'static str = "messages";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a list of messages found by a search"]
+pub struct FoundMessages {
+    #[doc = "List of messages"]
+    pub messages: Vec<Message>,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Value to pass as from_search_id to get more results"]
+    pub next_from_search_id: i64,
+}
+#[cfg(feature = "messages")]
+impl TlObject for FoundMessages {
+    const CLASS: &'static str = "FoundMessages";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for FoundMessages {
+    const TYPE: &'static str = "foundMessages";
+}
+#[cfg(any(feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about a message draft"]
+pub struct DraftMessage {
+    #[doc = "Identifier of the message to reply to; 0 if none"]
+    pub reply_to_message_id: MessageId,
+    #[doc = "Content of the message draft; this should always be of type inputMessageText"]
+    pub input_message_text: InputMessageContent,
+}
+#[cfg(any(feature = "chats", feature = "messages"))]
+impl TlObject for DraftMessage {
+    const CLASS: &'static str = "DraftMessage";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "chats", feature = "messages"))]
+impl TlConstructor for DraftMessage {
+    const TYPE: &'static str = "draftMessage";
+}
+#[cfg(any(feature = "chats", feature = "messages"))]
+impl DraftMessage {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_text: impl Into<InputMessageContent>) -> Self {
+        Self {
+            reply_to_message_id: Default::default(),
+            input_message_text: input_message_text.into(),
+        }
+    }
+    #[doc = "Identifier of the message to reply to; 0 if none"]
+    pub fn with_reply_to_message_id(mut self, value: impl Into<MessageId>) -> Self {
+        self.reply_to_message_id = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "chats", feature = "messages"))]
+impl Validate for DraftMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.input_message_text
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_text"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A plain text"]
+pub struct RichTextPlain {
+    #[doc = "Text"]
+    pub text: String,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextPlain {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextPlain {
+    const TYPE: &'static str = "richTextPlain";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A bold rich text"]
+pub struct RichTextBold {
+    #[doc = "Text"]
+    pub text: Box<RichText>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextBold {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextBold {
+    const TYPE: &'static str = "richTextBold";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An italicized rich text"]
+pub struct RichTextItalic {
+    #[doc = "Text"]
+    pub text: Box<RichText>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextItalic {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextItalic {
+    const TYPE: &'static str = "richTextItalic";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An underlined rich text"]
+pub struct RichTextUnderline {
+    #[doc = "Text"]
+    pub text: Box<RichText>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextUnderline {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextUnderline {
+    const TYPE: &'static str = "richTextUnderline";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A strikethrough rich text"]
+pub struct RichTextStrikethrough {
+    #[doc = "Text"]
+    pub text: Box<RichText>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextStrikethrough {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextStrikethrough {
+    const TYPE: &'static str = "richTextStrikethrough";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A fixed-width rich text"]
+pub struct RichTextFixed {
+    #[doc = "Text"]
+    pub text: Box<RichText>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextFixed {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextFixed {
+    const TYPE: &'static str = "richTextFixed";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A rich text URL link"]
+pub struct RichTextUrl {
+    #[doc = "Text"]
+    pub text: Box<RichText>,
+    #[doc = "URL"]
+    pub url: String,
+    #[doc = "True, if the URL has cached instant view server-side"]
+    pub is_cached: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextUrl {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextUrl {
+    const TYPE: &'static str = "richTextUrl";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A rich text email link"]
+pub struct RichTextEmailAddress {
+    #[doc = "Text"]
+    pub text: Box<RichText>,
+    #[doc = "Email address"]
+    pub email_address: String,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextEmailAddress {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextEmailAddress {
+    const TYPE: &'static str = "richTextEmailAddress";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A subscript rich text"]
+pub struct RichTextSubscript {
+    #[doc = "Text"]
+    pub text: Box<RichText>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextSubscript {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextSubscript {
+    const TYPE: &'static str = "richTextSubscript";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A superscript rich text"]
+pub struct RichTextSuperscript {
+    #[doc = "Text"]
+    pub text: Box<RichText>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextSuperscript {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextSuperscript {
+    const TYPE: &'static str = "richTextSuperscript";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A marked rich text"]
+pub struct RichTextMarked {
+    #[doc = "Text"]
+    pub text: Box<RichText>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextMarked {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextMarked {
+    const TYPE: &'static str = "richTextMarked";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A rich text phone number"]
+pub struct RichTextPhoneNumber {
+    #[doc = "Text"]
+    pub text: Box<RichText>,
+    #[doc = "Phone number"]
+    pub phone_number: String,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextPhoneNumber {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextPhoneNumber {
+    const TYPE: &'static str = "richTextPhoneNumber";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A small image inside the text"]
+pub struct RichTextIcon {
+    #[doc = "The image represented as a document. The image can be in GIF, JPEG or PNG format"]
+    pub document: Document,
+    #[doc = "Width of a bounding box in which the image should be shown; 0 if unknown"]
+    pub width: i32,
+    #[doc = "Height of a bounding box in which the image should be shown; 0 if unknown"]
+    pub height: i32,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextIcon {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextIcon {
+    const TYPE: &'static str = "richTextIcon";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A rich text anchor"]
+pub struct RichTextAnchor {
+    #[doc = "Text"]
+    pub text: Box<RichText>,
+    #[doc = "Anchor name"]
+    pub name: String,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTextAnchor {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTextAnchor {
+    const TYPE: &'static str = "richTextAnchor";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A concatenation of rich texts"]
+pub struct RichTexts {
+    #[doc = "Texts"]
+    pub texts: Vec<RichText>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichTexts {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for RichTexts {
+    const TYPE: &'static str = "richTexts";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes a text object inside an instant-view web page"]
+pub enum RichText {
+    RichTextPlain(RichTextPlain),
+    RichTextBold(RichTextBold),
+    RichTextItalic(RichTextItalic),
+    RichTextUnderline(RichTextUnderline),
+    RichTextStrikethrough(RichTextStrikethrough),
+    RichTextFixed(RichTextFixed),
+    RichTextUrl(RichTextUrl),
+    RichTextEmailAddress(RichTextEmailAddress),
+    RichTextSubscript(RichTextSubscript),
+    RichTextSuperscript(RichTextSuperscript),
+    RichTextMarked(RichTextMarked),
+    RichTextPhoneNumber(RichTextPhoneNumber),
+    RichTextIcon(Box<RichTextIcon>),
+    RichTextAnchor(RichTextAnchor),
+    RichTexts(RichTexts),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "messages")]
+impl<'de> Deserialize<'de> for RichText {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "richTextPlain" => Deserialize::deserialize(value).map(RichText::RichTextPlain),
+            "richTextBold" => Deserialize::deserialize(value).map(RichText::RichTextBold),
+            "richTextItalic" => Deserialize::deserialize(value).map(RichText::RichTextItalic),
+            "richTextUnderline" => Deserialize::deserialize(value).map(RichText::RichTextUnderline),
+            "richTextStrikethrough" => {
+                Deserialize::deserialize(value).map(RichText::RichTextStrikethrough)
+            }
+            "richTextFixed" => Deserialize::deserialize(value).map(RichText::RichTextFixed),
+            "richTextUrl" => Deserialize::deserialize(value).map(RichText::RichTextUrl),
+            "richTextEmailAddress" => {
+                Deserialize::deserialize(value).map(RichText::RichTextEmailAddress)
+            }
+            "richTextSubscript" => Deserialize::deserialize(value).map(RichText::RichTextSubscript),
+            "richTextSuperscript" => {
+                Deserialize::deserialize(value).map(RichText::RichTextSuperscript)
+            }
+            "richTextMarked" => Deserialize::deserialize(value).map(RichText::RichTextMarked),
+            "richTextPhoneNumber" => {
+                Deserialize::deserialize(value).map(RichText::RichTextPhoneNumber)
+            }
+            "richTextIcon" => Deserialize::deserialize(value).map(RichText::RichTextIcon),
+            "richTextAnchor" => Deserialize::deserialize(value).map(RichText::RichTextAnchor),
+            "richTexts" => Deserialize::deserialize(value).map(RichText::RichTexts),
+            _ => Unknown::deserialize(value).map(RichText::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "messages")]
+impl TlObject for RichText {
+    const CLASS: &'static str = "RichText";
+    fn type_name(&self) -> &str {
+        match self {
+            RichText::RichTextPlain(_) => "richTextPlain",
+            RichText::RichTextBold(_) => "richTextBold",
+            RichText::RichTextItalic(_) => "richTextItalic",
+            RichText::RichTextUnderline(_) => "richTextUnderline",
+            RichText::RichTextStrikethrough(_) => "richTextStrikethrough",
+            RichText::RichTextFixed(_) => "richTextFixed",
+            RichText::RichTextUrl(_) => "richTextUrl",
+            RichText::RichTextEmailAddress(_) => "richTextEmailAddress",
+            RichText::RichTextSubscript(_) => "richTextSubscript",
+            RichText::RichTextSuperscript(_) => "richTextSuperscript",
+            RichText::RichTextMarked(_) => "richTextMarked",
+            RichText::RichTextPhoneNumber(_) => "richTextPhoneNumber",
+            RichText::RichTextIcon(_) => "richTextIcon",
+            RichText::RichTextAnchor(_) => "richTextAnchor",
+            RichText::RichTexts(_) => "richTexts",
+            RichText::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a caption of an instant view web page block, consisting of a text and a trailing credit"]
+pub struct PageBlockCaption {
+    #[doc = "Content of the caption"]
+    pub text: RichText,
+    #[doc = "Block credit (like HTML tag <cite>)"]
+    pub credit: RichText,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockCaption {
+    const CLASS: &'static str = "PageBlockCaption";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockCaption {
+    const TYPE: &'static str = "pageBlockCaption";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Describes an item of a list page block"]
+pub struct PageBlockListItem {
+    #[doc = "Item label"]
+    pub label: String,
+    #[doc = "Item blocks"]
+    pub page_blocks: Vec<PageBlock>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockListItem {
+    const CLASS: &'static str = "PageBlockListItem";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockListItem {
+    const TYPE: &'static str = "pageBlockListItem";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The content should be left-aligned"]
+pub struct PageBlockHorizontalAlignmentLeft {}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockHorizontalAlignmentLeft {
+    const CLASS: &'static str = "PageBlockHorizontalAlignment";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockHorizontalAlignmentLeft {
+    const TYPE: &'static str = "pageBlockHorizontalAlignmentLeft";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The content should be center-aligned"]
+pub struct PageBlockHorizontalAlignmentCenter {}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockHorizontalAlignmentCenter {
+    const CLASS: &'static str = "PageBlockHorizontalAlignment";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockHorizontalAlignmentCenter {
+    const TYPE: &'static str = "pageBlockHorizontalAlignmentCenter";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The content should be right-aligned"]
+pub struct PageBlockHorizontalAlignmentRight {}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockHorizontalAlignmentRight {
+    const CLASS: &'static str = "PageBlockHorizontalAlignment";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockHorizontalAlignmentRight {
+    const TYPE: &'static str = "pageBlockHorizontalAlignmentRight";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes a horizontal alignment of a table cell content"]
+pub enum PageBlockHorizontalAlignment {
+    PageBlockHorizontalAlignmentLeft(PageBlockHorizontalAlignmentLeft),
+    PageBlockHorizontalAlignmentCenter(PageBlockHorizontalAlignmentCenter),
+    PageBlockHorizontalAlignmentRight(PageBlockHorizontalAlignmentRight),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "messages")]
+impl<'de> Deserialize<'de> for PageBlockHorizontalAlignment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "pageBlockHorizontalAlignmentLeft" => Deserialize::deserialize(value)
+                .map(PageBlockHorizontalAlignment::PageBlockHorizontalAlignmentLeft),
+            "pageBlockHorizontalAlignmentCenter" => Deserialize::deserialize(value)
+                .map(PageBlockHorizontalAlignment::PageBlockHorizontalAlignmentCenter),
+            "pageBlockHorizontalAlignmentRight" => Deserialize::deserialize(value)
+                .map(PageBlockHorizontalAlignment::PageBlockHorizontalAlignmentRight),
+            _ => Unknown::deserialize(value).map(PageBlockHorizontalAlignment::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockHorizontalAlignment {
+    const CLASS: &'static str = "PageBlockHorizontalAlignment";
+    fn type_name(&self) -> &str {
+        match self {
+            PageBlockHorizontalAlignment::PageBlockHorizontalAlignmentLeft(_) => {
+                "pageBlockHorizontalAlignmentLeft"
+            }
+            PageBlockHorizontalAlignment::PageBlockHorizontalAlignmentCenter(_) => {
+                "pageBlockHorizontalAlignmentCenter"
+            }
+            PageBlockHorizontalAlignment::PageBlockHorizontalAlignmentRight(_) => {
+                "pageBlockHorizontalAlignmentRight"
+            }
+            PageBlockHorizontalAlignment::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The content should be top-aligned"]
+pub struct PageBlockVerticalAlignmentTop {}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockVerticalAlignmentTop {
+    const CLASS: &'static str = "PageBlockVerticalAlignment";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockVerticalAlignmentTop {
+    const TYPE: &'static str = "pageBlockVerticalAlignmentTop";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The content should be middle-aligned"]
+pub struct PageBlockVerticalAlignmentMiddle {}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockVerticalAlignmentMiddle {
+    const CLASS: &'static str = "PageBlockVerticalAlignment";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockVerticalAlignmentMiddle {
+    const TYPE: &'static str = "pageBlockVerticalAlignmentMiddle";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The content should be bottom-aligned"]
+pub struct PageBlockVerticalAlignmentBottom {}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockVerticalAlignmentBottom {
+    const CLASS: &'static str = "PageBlockVerticalAlignment";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockVerticalAlignmentBottom {
+    const TYPE: &'static str = "pageBlockVerticalAlignmentBottom";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes a Vertical alignment of a table cell content"]
+pub enum PageBlockVerticalAlignment {
+    PageBlockVerticalAlignmentTop(PageBlockVerticalAlignmentTop),
+    PageBlockVerticalAlignmentMiddle(PageBlockVerticalAlignmentMiddle),
+    PageBlockVerticalAlignmentBottom(PageBlockVerticalAlignmentBottom),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "messages")]
+impl<'de> Deserialize<'de> for PageBlockVerticalAlignment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "pageBlockVerticalAlignmentTop" => Deserialize::deserialize(value)
+                .map(PageBlockVerticalAlignment::PageBlockVerticalAlignmentTop),
+            "pageBlockVerticalAlignmentMiddle" => Deserialize::deserialize(value)
+                .map(PageBlockVerticalAlignment::PageBlockVerticalAlignmentMiddle),
+            "pageBlockVerticalAlignmentBottom" => Deserialize::deserialize(value)
+                .map(PageBlockVerticalAlignment::PageBlockVerticalAlignmentBottom),
+            _ => Unknown::deserialize(value).map(PageBlockVerticalAlignment::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockVerticalAlignment {
+    const CLASS: &'static str = "PageBlockVerticalAlignment";
+    fn type_name(&self) -> &str {
+        match self {
+            PageBlockVerticalAlignment::PageBlockVerticalAlignmentTop(_) => {
+                "pageBlockVerticalAlignmentTop"
+            }
+            PageBlockVerticalAlignment::PageBlockVerticalAlignmentMiddle(_) => {
+                "pageBlockVerticalAlignmentMiddle"
+            }
+            PageBlockVerticalAlignment::PageBlockVerticalAlignmentBottom(_) => {
+                "pageBlockVerticalAlignmentBottom"
+            }
+            PageBlockVerticalAlignment::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a cell of a table"]
+pub struct PageBlockTableCell {
+    #[serde(default)]
+    #[doc = "Cell text; may be null. If the text is null, then the cell should be invisible"]
+    pub text: Option<RichText>,
+    #[doc = "True, if it is a header cell"]
+    pub is_header: bool,
+    #[doc = "The number of columns the cell should span"]
+    pub colspan: i32,
+    #[doc = "The number of rows the cell should span"]
+    pub rowspan: i32,
+    #[doc = "Horizontal cell content alignment"]
+    pub align: PageBlockHorizontalAlignment,
+    #[doc = "Vertical cell content alignment"]
+    pub valign: PageBlockVerticalAlignment,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockTableCell {
+    const CLASS: &'static str = "PageBlockTableCell";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockTableCell {
+    const TYPE: &'static str = "pageBlockTableCell";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about a related article"]
+pub struct PageBlockRelatedArticle {
+    #[doc = "Related article URL"]
+    pub url: String,
+    #[doc = "Article title; may be empty"]
+    pub title: String,
+    #[doc = "Contains information about a related article"]
+    pub description: String,
+    #[serde(default)]
+    #[doc = "Article photo; may be null"]
+    pub photo: Option<Photo>,
+    #[doc = "Article author; may be empty"]
+    pub author: String,
+    #[doc = "Point in time (Unix timestamp) when the article was published; 0 if unknown"]
+    pub publish_date: i32,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockRelatedArticle {
+    const CLASS: &'static str = "PageBlockRelatedArticle";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockRelatedArticle {
+    const TYPE: &'static str = "pageBlockRelatedArticle";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The title of a page"]
+pub struct PageBlockTitle {
+    #[doc = "Title"]
+    pub title: RichText,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockTitle {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockTitle {
+    const TYPE: &'static str = "pageBlockTitle";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The subtitle of a page"]
+pub struct PageBlockSubtitle {
+    #[doc = "Subtitle"]
+    pub subtitle: RichText,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockSubtitle {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockSubtitle {
+    const TYPE: &'static str = "pageBlockSubtitle";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The author and publishing date of a page"]
+pub struct PageBlockAuthorDate {
+    #[doc = "Author"]
+    pub author: RichText,
+    #[doc = "Point in time (Unix timestamp) when the article was published; 0 if unknown"]
+    pub publish_date: i32,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockAuthorDate {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockAuthorDate {
+    const TYPE: &'static str = "pageBlockAuthorDate";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A header"]
+pub struct PageBlockHeader {
+    #[doc = "Header"]
+    pub header: RichText,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockHeader {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockHeader {
+    const TYPE: &'static str = "pageBlockHeader";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A subheader"]
+pub struct PageBlockSubheader {
+    #[doc = "Subheader"]
+    pub subheader: RichText,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockSubheader {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockSubheader {
+    const TYPE: &'static str = "pageBlockSubheader";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A kicker"]
+pub struct PageBlockKicker {
+    #[doc = "Kicker"]
+    pub kicker: RichText,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockKicker {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockKicker {
+    const TYPE: &'static str = "pageBlockKicker";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A text paragraph"]
+pub struct PageBlockParagraph {
+    #[doc = "Paragraph text"]
+    pub text: RichText,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockParagraph {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockParagraph {
+    const TYPE: &'static str = "pageBlockParagraph";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A preformatted text paragraph"]
+pub struct PageBlockPreformatted {
+    #[doc = "Paragraph text"]
+    pub text: RichText,
+    #[doc = "Programming language for which the text should be formatted"]
+    pub language: String,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockPreformatted {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockPreformatted {
+    const TYPE: &'static str = "pageBlockPreformatted";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The footer of a page"]
+pub struct PageBlockFooter {
+    #[doc = "Footer"]
+    pub footer: RichText,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockFooter {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockFooter {
+    const TYPE: &'static str = "pageBlockFooter";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An empty block separating a page"]
+pub struct PageBlockDivider {}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockDivider {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockDivider {
+    const TYPE: &'static str = "pageBlockDivider";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An invisible anchor on a page, which can be used in a URL to open the page from the specified anchor"]
+pub struct PageBlockAnchor {
+    #[doc = "Name of the anchor"]
+    pub name: String,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockAnchor {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockAnchor {
+    const TYPE: &'static str = "pageBlockAnchor";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A list of data blocks"]
+pub struct PageBlockList {
+    #[doc = "The items of the list"]
+    pub items: Vec<PageBlockListItem>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockList {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockList {
+    const TYPE: &'static str = "pageBlockList";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A block quote"]
+pub struct PageBlockBlockQuote {
+    #[doc = "Quote text"]
+    pub text: RichText,
+    #[doc = "Quote credit"]
+    pub credit: RichText,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockBlockQuote {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockBlockQuote {
+    const TYPE: &'static str = "pageBlockBlockQuote";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A pull quote"]
+pub struct PageBlockPullQuote {
+    #[doc = "Quote text"]
+    pub text: RichText,
+    #[doc = "Quote credit"]
+    pub credit: RichText,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockPullQuote {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockPullQuote {
+    const TYPE: &'static str = "pageBlockPullQuote";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An animation"]
+pub struct PageBlockAnimation {
+    #[serde(default)]
+    #[doc = "Animation file; may be null"]
+    pub animation: Option<Animation>,
+    #[doc = "Animation caption"]
+    pub caption: PageBlockCaption,
+    #[doc = "True, if the animation should be played automatically"]
+    pub need_autoplay: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockAnimation {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockAnimation {
+    const TYPE: &'static str = "pageBlockAnimation";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An audio file"]
+pub struct PageBlockAudio {
+    #[serde(default)]
+    #[doc = "Audio file; may be null"]
+    pub audio: Option<Audio>,
+    #[doc = "Audio file caption"]
+    pub caption: PageBlockCaption,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockAudio {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockAudio {
+    const TYPE: &'static str = "pageBlockAudio";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A photo"]
+pub struct PageBlockPhoto {
+    #[serde(default)]
+    #[doc = "Photo file; may be null"]
+    pub photo: Option<Photo>,
+    #[doc = "Photo caption"]
+    pub caption: PageBlockCaption,
+    #[doc = "URL that needs to be opened when the photo is clicked"]
+    pub url: String,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockPhoto {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockPhoto {
+    const TYPE: &'static str = "pageBlockPhoto";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A video"]
+pub struct PageBlockVideo {
+    #[serde(default)]
+    #[doc = "Video file; may be null"]
+    pub video: Option<Video>,
+    #[doc = "Video caption"]
+    pub caption: PageBlockCaption,
+    #[doc = "True, if the video should be played automatically"]
+    pub need_autoplay: bool,
+    #[doc = "True, if the video should be looped"]
+    pub is_looped: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockVideo {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockVideo {
+    const TYPE: &'static str = "pageBlockVideo";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A voice note"]
+pub struct PageBlockVoiceNote {
+    #[serde(default)]
+    #[doc = "Voice note; may be null"]
+    pub voice_note: Option<VoiceNote>,
+    #[doc = "Voice note caption"]
+    pub caption: PageBlockCaption,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockVoiceNote {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockVoiceNote {
+    const TYPE: &'static str = "pageBlockVoiceNote";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A page cover"]
+pub struct PageBlockCover {
+    #[doc = "Cover"]
+    pub cover: Box<PageBlock>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockCover {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockCover {
+    const TYPE: &'static str = "pageBlockCover";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An embedded web page"]
+pub struct PageBlockEmbedded {
+    #[doc = "Web page URL, if available"]
+    pub url: String,
+    #[doc = "HTML-markup of the embedded page"]
+    pub html: String,
+    #[serde(default)]
+    #[doc = "Poster photo, if available; may be null"]
+    pub poster_photo: Option<Photo>,
+    #[doc = "Block width; 0 if unknown"]
+    pub width: i32,
+    #[doc = "Block height; 0 if unknown"]
+    pub height: i32,
+    #[doc = "Block caption"]
+    pub caption: PageBlockCaption,
+    #[doc = "True, if the block should be full width"]
+    pub is_full_width: bool,
+    #[doc = "True, if scrolling should be allowed"]
+    pub allow_scrolling: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockEmbedded {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockEmbedded {
+    const TYPE: &'static str = "pageBlockEmbedded";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An embedded post"]
+pub struct PageBlockEmbeddedPost {
+    #[doc = "Web page URL"]
+    pub url: String,
+    #[doc = "Post author"]
+    pub author: String,
+    #[serde(default)]
+    #[doc = "Post author photo; may be null"]
+    pub author_photo: Option<Photo>,
+    #[doc = "Point in time (Unix timestamp) when the post was created; 0 if unknown"]
+    pub date: i32,
+    #[doc = "Post content"]
+    pub page_blocks: Vec<PageBlock>,
+    #[doc = "Post caption"]
+    pub caption: PageBlockCaption,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockEmbeddedPost {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockEmbeddedPost {
+    const TYPE: &'static str = "pageBlockEmbeddedPost";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A collage"]
+pub struct PageBlockCollage {
+    #[doc = "Collage item contents"]
+    pub page_blocks: Vec<PageBlock>,
+    #[doc = "Block caption"]
+    pub caption: PageBlockCaption,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockCollage {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockCollage {
+    const TYPE: &'static str = "pageBlockCollage";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A slideshow"]
+pub struct PageBlockSlideshow {
+    #[doc = "Slideshow item contents"]
+    pub page_blocks: Vec<PageBlock>,
+    #[doc = "Block caption"]
+    pub caption: PageBlockCaption,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockSlideshow {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockSlideshow {
+    const TYPE: &'static str = "pageBlockSlideshow";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A link to a chat"]
+pub struct PageBlockChatLink {
+    #[doc = "Chat title"]
+    pub title: String,
+    #[serde(default)]
+    #[doc = "Chat photo; may be null"]
+    pub photo: Option<ChatPhoto>,
+    #[doc = "Chat username, by which all other information about the chat should be resolved"]
+    pub username: String,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockChatLink {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockChatLink {
+    const TYPE: &'static str = "pageBlockChatLink";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A table"]
+pub struct PageBlockTable {
+    #[doc = "Table caption"]
+    pub caption: RichText,
+    #[doc = "Table cells"]
+    pub cells: Vec<Vec<PageBlockTableCell>>,
+    #[doc = "True, if the table is bordered"]
+    pub is_bordered: bool,
+    #[doc = "True, if the table is striped"]
+    pub is_striped: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockTable {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockTable {
+    const TYPE: &'static str = "pageBlockTable";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A collapsible block"]
+pub struct PageBlockDetails {
+    #[doc = "Always visible heading for the block"]
+    pub header: RichText,
+    #[doc = "Block contents"]
+    pub page_blocks: Vec<PageBlock>,
+    #[doc = "True, if the block is open by default"]
+    pub is_open: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockDetails {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockDetails {
+    const TYPE: &'static str = "pageBlockDetails";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Related articles"]
+pub struct PageBlockRelatedArticles {
+    #[doc = "Block header"]
+    pub header: RichText,
+    #[doc = "List of related articles"]
+    pub articles: Vec<PageBlockRelatedArticle>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockRelatedArticles {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockRelatedArticles {
+    const TYPE: &'static str = "pageBlockRelatedArticles";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A map"]
+pub struct PageBlockMap {
+    #[doc = "Location of the map center"]
+    pub location: Location,
+    #[doc = "Map zoom level"]
+    pub zoom: i32,
+    #[doc = "Map width"]
+    pub width: i32,
+    #[doc = "Map height"]
+    pub height: i32,
+    #[doc = "Block caption"]
+    pub caption: PageBlockCaption,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlockMap {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PageBlockMap {
+    const TYPE: &'static str = "pageBlockMap";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes a block of an instant view web page"]
+pub enum PageBlock {
+    PageBlockTitle(PageBlockTitle),
+    PageBlockSubtitle(PageBlockSubtitle),
+    PageBlockAuthorDate(PageBlockAuthorDate),
+    PageBlockHeader(PageBlockHeader),
+    PageBlockSubheader(PageBlockSubheader),
+    PageBlockKicker(PageBlockKicker),
+    PageBlockParagraph(PageBlockParagraph),
+    PageBlockPreformatted(PageBlockPreformatted),
+    PageBlockFooter(PageBlockFooter),
+    PageBlockDivider(PageBlockDivider),
+    PageBlockAnchor(PageBlockAnchor),
+    PageBlockList(PageBlockList),
+    PageBlockBlockQuote(PageBlockBlockQuote),
+    PageBlockPullQuote(PageBlockPullQuote),
+    PageBlockAnimation(Box<PageBlockAnimation>),
+    PageBlockAudio(Box<PageBlockAudio>),
+    PageBlockPhoto(Box<PageBlockPhoto>),
+    PageBlockVideo(Box<PageBlockVideo>),
+    PageBlockVoiceNote(Box<PageBlockVoiceNote>),
+    PageBlockCover(PageBlockCover),
+    PageBlockEmbedded(Box<PageBlockEmbedded>),
+    PageBlockEmbeddedPost(Box<PageBlockEmbeddedPost>),
+    PageBlockCollage(PageBlockCollage),
+    PageBlockSlideshow(PageBlockSlideshow),
+    PageBlockChatLink(Box<PageBlockChatLink>),
+    PageBlockTable(PageBlockTable),
+    PageBlockDetails(PageBlockDetails),
+    PageBlockRelatedArticles(PageBlockRelatedArticles),
+    PageBlockMap(PageBlockMap),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "messages")]
+impl<'de> Deserialize<'de> for PageBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "pageBlockTitle" => Deserialize::deserialize(value).map(PageBlock::PageBlockTitle),
+            "pageBlockSubtitle" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockSubtitle)
+            }
+            "pageBlockAuthorDate" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockAuthorDate)
+            }
+            "pageBlockHeader" => Deserialize::deserialize(value).map(PageBlock::PageBlockHeader),
+            "pageBlockSubheader" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockSubheader)
+            }
+            "pageBlockKicker" => Deserialize::deserialize(value).map(PageBlock::PageBlockKicker),
+            "pageBlockParagraph" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockParagraph)
+            }
+            "pageBlockPreformatted" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockPreformatted)
+            }
+            "pageBlockFooter" => Deserialize::deserialize(value).map(PageBlock::PageBlockFooter),
+            "pageBlockDivider" => Deserialize::deserialize(value).map(PageBlock::PageBlockDivider),
+            "pageBlockAnchor" => Deserialize::deserialize(value).map(PageBlock::PageBlockAnchor),
+            "pageBlockList" => Deserialize::deserialize(value).map(PageBlock::PageBlockList),
+            "pageBlockBlockQuote" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockBlockQuote)
+            }
+            "pageBlockPullQuote" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockPullQuote)
+            }
+            "pageBlockAnimation" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockAnimation)
+            }
+            "pageBlockAudio" => Deserialize::deserialize(value).map(PageBlock::PageBlockAudio),
+            "pageBlockPhoto" => Deserialize::deserialize(value).map(PageBlock::PageBlockPhoto),
+            "pageBlockVideo" => Deserialize::deserialize(value).map(PageBlock::PageBlockVideo),
+            "pageBlockVoiceNote" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockVoiceNote)
+            }
+            "pageBlockCover" => Deserialize::deserialize(value).map(PageBlock::PageBlockCover),
+            "pageBlockEmbedded" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockEmbedded)
+            }
+            "pageBlockEmbeddedPost" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockEmbeddedPost)
+            }
+            "pageBlockCollage" => Deserialize::deserialize(value).map(PageBlock::PageBlockCollage),
+            "pageBlockSlideshow" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockSlideshow)
+            }
+            "pageBlockChatLink" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockChatLink)
+            }
+            "pageBlockTable" => Deserialize::deserialize(value).map(PageBlock::PageBlockTable),
+            "pageBlockDetails" => Deserialize::deserialize(value).map(PageBlock::PageBlockDetails),
+            "pageBlockRelatedArticles" => {
+                Deserialize::deserialize(value).map(PageBlock::PageBlockRelatedArticles)
+            }
+            "pageBlockMap" => Deserialize::deserialize(value).map(PageBlock::PageBlockMap),
+            _ => Unknown::deserialize(value).map(PageBlock::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "messages")]
+impl TlObject for PageBlock {
+    const CLASS: &'static str = "PageBlock";
+    fn type_name(&self) -> &str {
+        match self {
+            PageBlock::PageBlockTitle(_) => "pageBlockTitle",
+            PageBlock::PageBlockSubtitle(_) => "pageBlockSubtitle",
+            PageBlock::PageBlockAuthorDate(_) => "pageBlockAuthorDate",
+            PageBlock::PageBlockHeader(_) => "pageBlockHeader",
+            PageBlock::PageBlockSubheader(_) => "pageBlockSubheader",
+            PageBlock::PageBlockKicker(_) => "pageBlockKicker",
+            PageBlock::PageBlockParagraph(_) => "pageBlockParagraph",
+            PageBlock::PageBlockPreformatted(_) => "pageBlockPreformatted",
+            PageBlock::PageBlockFooter(_) => "pageBlockFooter",
+            PageBlock::PageBlockDivider(_) => "pageBlockDivider",
+            PageBlock::PageBlockAnchor(_) => "pageBlockAnchor",
+            PageBlock::PageBlockList(_) => "pageBlockList",
+            PageBlock::PageBlockBlockQuote(_) => "pageBlockBlockQuote",
+            PageBlock::PageBlockPullQuote(_) => "pageBlockPullQuote",
+            PageBlock::PageBlockAnimation(_) => "pageBlockAnimation",
+            PageBlock::PageBlockAudio(_) => "pageBlockAudio",
+            PageBlock::PageBlockPhoto(_) => "pageBlockPhoto",
+            PageBlock::PageBlockVideo(_) => "pageBlockVideo",
+            PageBlock::PageBlockVoiceNote(_) => "pageBlockVoiceNote",
+            PageBlock::PageBlockCover(_) => "pageBlockCover",
+            PageBlock::PageBlockEmbedded(_) => "pageBlockEmbedded",
+            PageBlock::PageBlockEmbeddedPost(_) => "pageBlockEmbeddedPost",
+            PageBlock::PageBlockCollage(_) => "pageBlockCollage",
+            PageBlock::PageBlockSlideshow(_) => "pageBlockSlideshow",
+            PageBlock::PageBlockChatLink(_) => "pageBlockChatLink",
+            PageBlock::PageBlockTable(_) => "pageBlockTable",
+            PageBlock::PageBlockDetails(_) => "pageBlockDetails",
+            PageBlock::PageBlockRelatedArticles(_) => "pageBlockRelatedArticles",
+            PageBlock::PageBlockMap(_) => "pageBlockMap",
+            PageBlock::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Describes an instant view page for a web page"]
+pub struct WebPageInstantView {
+    #[doc = "Content of the web page"]
+    pub page_blocks: Vec<PageBlock>,
+    #[doc = "Version of the instant view, currently can be 1 or 2"]
+    pub version: i32,
+    #[doc = "Instant view URL; may be different from WebPage.url and must be used for the correct anchors handling"]
+    pub url: String,
+    #[doc = "True, if the instant view must be shown from right to left"]
+    pub is_rtl: bool,
+    #[doc = "True, if the instant view contains the full page. A network request might be needed to get the full web page instant view"]
+    pub is_full: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for WebPageInstantView {
+    const CLASS: &'static str = "WebPageInstantView";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for WebPageInstantView {
+    const TYPE: &'static str = "webPageInstantView";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The message will be sent at the specified date"]
+pub struct MessageSchedulingStateSendAtDate {
+    #[doc = "Date the message will be sent. The date must be within 367 days in the future"]
+    pub send_date: i32,
+}
+impl TlObject for MessageSchedulingStateSendAtDate {
+    const CLASS: &'static str = "MessageSchedulingState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for MessageSchedulingStateSendAtDate {
+    const TYPE: &'static str = "messageSchedulingStateSendAtDate";
+}
+impl MessageSchedulingStateSendAtDate {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Date the message will be sent. The date must be within 367 days in the future"]
+    pub fn with_send_date(mut self, value: impl Into<i32>) -> Self {
+        self.send_date = value.into();
+        self
+    }
+}
+impl Validate for MessageSchedulingStateSendAtDate {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The message will be sent when the peer will be online. Applicable to private chats only and when the exact online status of the peer is known"]
+pub struct MessageSchedulingStateSendWhenOnline {}
+impl TlObject for MessageSchedulingStateSendWhenOnline {
+    const CLASS: &'static str = "MessageSchedulingState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for MessageSchedulingStateSendWhenOnline {
+    const TYPE: &'static str = "messageSchedulingStateSendWhenOnline";
+}
+impl MessageSchedulingStateSendWhenOnline {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for MessageSchedulingStateSendWhenOnline {}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Contains information about the time when a scheduled message will be sent"]
+pub enum MessageSchedulingState {
+    MessageSchedulingStateSendAtDate(MessageSchedulingStateSendAtDate),
+    MessageSchedulingStateSendWhenOnline(MessageSchedulingStateSendWhenOnline),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for MessageSchedulingState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "messageSchedulingStateSendAtDate" => Deserialize::deserialize(value)
+                .map(MessageSchedulingState::MessageSchedulingStateSendAtDate),
+            "messageSchedulingStateSendWhenOnline" => Deserialize::deserialize(value)
+                .map(MessageSchedulingState::MessageSchedulingStateSendWhenOnline),
+            _ => Unknown::deserialize(value).map(MessageSchedulingState::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for MessageSchedulingState {
+    const CLASS: &'static str = "MessageSchedulingState";
+    fn type_name(&self) -> &str {
+        match self {
+            MessageSchedulingState::MessageSchedulingStateSendAtDate(_) => {
+                "messageSchedulingStateSendAtDate"
+            }
+            MessageSchedulingState::MessageSchedulingStateSendWhenOnline(_) => {
+                "messageSchedulingStateSendWhenOnline"
+            }
+            MessageSchedulingState::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for MessageSchedulingState {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            MessageSchedulingState::MessageSchedulingStateSendAtDate(value) => value.validate(),
+            MessageSchedulingState::MessageSchedulingStateSendWhenOnline(value) => value.validate(),
+            MessageSchedulingState::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<MessageSchedulingStateSendAtDate> for MessageSchedulingState {
+    fn from(value: MessageSchedulingStateSendAtDate) -> Self {
+        MessageSchedulingState::MessageSchedulingStateSendAtDate(value)
+    }
+}
+impl From<MessageSchedulingStateSendWhenOnline> for MessageSchedulingState {
+    fn from(value: MessageSchedulingStateSendWhenOnline) -> Self {
+        MessageSchedulingState::MessageSchedulingStateSendWhenOnline(value)
+    }
+}
+#[cfg(any(feature = "bots", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Options to be used when a message is send"]
+pub struct SendMessageOptions {
+    #[doc = "Pass true to disable notification for the message. Must be false if the message is sent to a secret chat"]
+    pub disable_notification: bool,
+    #[doc = "Pass true if the message is sent from the background"]
+    pub from_background: bool,
+    #[serde(default)]
+    #[doc = "Message scheduling state. Messages sent to a secret chat, live location messages and self-destructing messages can't be scheduled"]
+    pub scheduling_state: Option<MessageSchedulingState>,
+}
+#[cfg(any(feature = "bots", feature = "messages"))]
+impl TlObject for SendMessageOptions {
+    const CLASS: &'static str = "SendMessageOptions";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "messages"))]
+impl TlConstructor for SendMessageOptions {
+    const TYPE: &'static str = "sendMessageOptions";
+}
+#[cfg(any(feature = "bots", feature = "messages"))]
+impl SendMessageOptions {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Pass true to disable notification for the message. Must be false if the message is sent to a secret chat"]
+    pub fn with_disable_notification(mut self, value: impl Into<bool>) -> Self {
+        self.disable_notification = value.into();
+        self
+    }
+    #[doc = "Pass true if the message is sent from the background"]
+    pub fn with_from_background(mut self, value: impl Into<bool>) -> Self {
+        self.from_background = value.into();
+        self
+    }
+    #[doc = "Message scheduling state. Messages sent to a secret chat, live location messages and self-destructing messages can't be scheduled"]
+    pub fn with_scheduling_state(mut self, value: impl Into<MessageSchedulingState>) -> Self {
+        self.scheduling_state = Some(value.into());
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "messages"))]
+impl Validate for SendMessageOptions {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.scheduling_state {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "scheduling_state"))?;
+        }
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A text message"]
+pub struct InputMessageText {
+    #[doc = "Formatted text to be sent; 1-GetOption(\"message_text_length_max\") characters. Only Bold, Italic, Underline, Strikethrough, Code, Pre, PreCode, TextUrl and MentionName entities are allowed to be specified manually"]
+    pub text: FormattedText,
+    #[doc = "True, if rich web page previews for URLs in the message text should be disabled"]
+    pub disable_web_page_preview: bool,
+    #[doc = "True, if a chat message draft should be deleted"]
+    pub clear_draft: bool,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageText {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageText {
+    const TYPE: &'static str = "inputMessageText";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageText {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Formatted text to be sent; 1-GetOption(\"message_text_length_max\") characters. Only Bold, Italic, Underline, Strikethrough, Code, Pre, PreCode, TextUrl and MentionName entities are allowed to be specified manually"]
+    pub fn with_text(mut self, value: impl Into<FormattedText>) -> Self {
+        self.text = value.into();
+        self
+    }
+    #[doc = "True, if rich web page previews for URLs in the message text should be disabled"]
+    pub fn with_disable_web_page_preview(mut self, value: impl Into<bool>) -> Self {
+        self.disable_web_page_preview = value.into();
+        self
+    }
+    #[doc = "True, if a chat message draft should be deleted"]
+    pub fn with_clear_draft(mut self, value: impl Into<bool>) -> Self {
+        self.clear_draft = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageText {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.text
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "text"))?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An animation message (GIF-style)."]
+pub struct InputMessageAnimation {
+    #[doc = "Animation file to be sent"]
+    pub animation: InputFile,
+    #[doc = "Animation thumbnail, if available"]
+    pub thumbnail: InputThumbnail,
+    #[doc = "Duration of the animation, in seconds"]
+    pub duration: i32,
+    #[doc = "Width of the animation; may be replaced by the server"]
+    pub width: i32,
+    #[doc = "Height of the animation; may be replaced by the server"]
+    pub height: i32,
+    #[doc = "Animation caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub caption: FormattedText,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageAnimation {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageAnimation {
+    const TYPE: &'static str = "inputMessageAnimation";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageAnimation {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(animation: impl Into<InputFile>, thumbnail: impl Into<InputThumbnail>) -> Self {
+        Self {
+            animation: animation.into(),
+            thumbnail: thumbnail.into(),
+            duration: Default::default(),
+            width: Default::default(),
+            height: Default::default(),
+            caption: Default::default(),
+        }
+    }
+    #[doc = "Duration of the animation, in seconds"]
+    pub fn with_duration(mut self, value: impl Into<i32>) -> Self {
+        self.duration = value.into();
+        self
+    }
+    #[doc = "Width of the animation; may be replaced by the server"]
+    pub fn with_width(mut self, value: impl Into<i32>) -> Self {
+        self.width = value.into();
+        self
+    }
+    #[doc = "Height of the animation; may be replaced by the server"]
+    pub fn with_height(mut self, value: impl Into<i32>) -> Self {
+        self.height = value.into();
+        self
+    }
+    #[doc = "Animation caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub fn with_caption(mut self, value: impl Into<FormattedText>) -> Self {
+        self.caption = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageAnimation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.animation
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "animation"))?;
+        self.thumbnail
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "thumbnail"))?;
+        self.caption
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "caption"))?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An audio message"]
+pub struct InputMessageAudio {
+    #[doc = "Audio file to be sent"]
+    pub audio: InputFile,
+    #[doc = "Thumbnail of the cover for the album, if available"]
+    pub album_cover_thumbnail: InputThumbnail,
+    #[doc = "Duration of the audio, in seconds; may be replaced by the server"]
+    pub duration: i32,
+    #[doc = "Title of the audio; 0-64 characters; may be replaced by the server"]
+    pub title: String,
+    #[doc = "Performer of the audio; 0-64 characters, may be replaced by the server"]
+    pub performer: String,
+    #[doc = "Audio caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub caption: FormattedText,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageAudio {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageAudio {
+    const TYPE: &'static str = "inputMessageAudio";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageAudio {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        audio: impl Into<InputFile>,
+        album_cover_thumbnail: impl Into<InputThumbnail>,
+    ) -> Self {
+        Self {
+            audio: audio.into(),
+            album_cover_thumbnail: album_cover_thumbnail.into(),
+            duration: Default::default(),
+            title: Default::default(),
+            performer: Default::default(),
+            caption: Default::default(),
+        }
+    }
+    #[doc = "Duration of the audio, in seconds; may be replaced by the server"]
+    pub fn with_duration(mut self, value: impl Into<i32>) -> Self {
+        self.duration = value.into();
+        self
+    }
+    #[doc = "Title of the audio; 0-64 characters; may be replaced by the server"]
+    pub fn with_title(mut self, value: impl Into<String>) -> Self {
+        self.title = value.into();
+        self
+    }
+    #[doc = "Performer of the audio; 0-64 characters, may be replaced by the server"]
+    pub fn with_performer(mut self, value: impl Into<String>) -> Self {
+        self.performer = value.into();
+        self
+    }
+    #[doc = "Audio caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub fn with_caption(mut self, value: impl Into<FormattedText>) -> Self {
+        self.caption = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageAudio {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.audio
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "audio"))?;
+        self.album_cover_thumbnail
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "album_cover_thumbnail"))?;
+        validate::check(
+            Self::TYPE,
+            "title",
+            Measure::Characters,
+            self.title.chars().count() as i64,
+            Some(0),
+            Some(64),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "performer",
+            Measure::Characters,
+            self.performer.chars().count() as i64,
+            Some(0),
+            Some(64),
+        )?;
+        self.caption
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "caption"))?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A document message (general file)"]
+pub struct InputMessageDocument {
+    #[doc = "Document to be sent"]
+    pub document: InputFile,
+    #[doc = "Document thumbnail, if available"]
+    pub thumbnail: InputThumbnail,
+    #[doc = "Document caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub caption: FormattedText,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageDocument {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageDocument {
+    const TYPE: &'static str = "inputMessageDocument";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageDocument {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(document: impl Into<InputFile>, thumbnail: impl Into<InputThumbnail>) -> Self {
+        Self {
+            document: document.into(),
+            thumbnail: thumbnail.into(),
+            caption: Default::default(),
+        }
+    }
+    #[doc = "Document caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub fn with_caption(mut self, value: impl Into<FormattedText>) -> Self {
+        self.caption = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageDocument {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.document
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "document"))?;
+        self.thumbnail
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "thumbnail"))?;
+        self.caption
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "caption"))?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A photo message"]
+pub struct InputMessagePhoto {
+    #[doc = "Photo to send"]
+    pub photo: InputFile,
+    #[doc = "Photo thumbnail to be sent, this is sent to the other party in secret chats only"]
+    pub thumbnail: InputThumbnail,
+    #[doc = "File identifiers of the stickers added to the photo, if applicable"]
+    pub added_sticker_file_ids: Vec<FileId>,
+    #[doc = "Photo width"]
+    pub width: i32,
+    #[doc = "Photo height"]
+    pub height: i32,
+    #[doc = "Photo caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub caption: FormattedText,
+    #[doc = "Photo TTL (Time To Live), in seconds (0-60). A non-zero TTL can be specified only in private chats"]
+    pub ttl: i32,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessagePhoto {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessagePhoto {
+    const TYPE: &'static str = "inputMessagePhoto";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessagePhoto {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(photo: impl Into<InputFile>, thumbnail: impl Into<InputThumbnail>) -> Self {
+        Self {
+            photo: photo.into(),
+            thumbnail: thumbnail.into(),
+            added_sticker_file_ids: Default::default(),
+            width: Default::default(),
+            height: Default::default(),
+            caption: Default::default(),
+            ttl: Default::default(),
+        }
+    }
+    #[doc = "File identifiers of the stickers added to the photo, if applicable"]
+    pub fn with_added_sticker_file_ids(mut self, value: impl Into<Vec<FileId>>) -> Self {
+        self.added_sticker_file_ids = value.into();
+        self
+    }
+    #[doc = "Photo width"]
+    pub fn with_width(mut self, value: impl Into<i32>) -> Self {
+        self.width = value.into();
+        self
+    }
+    #[doc = "Photo height"]
+    pub fn with_height(mut self, value: impl Into<i32>) -> Self {
+        self.height = value.into();
+        self
+    }
+    #[doc = "Photo caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub fn with_caption(mut self, value: impl Into<FormattedText>) -> Self {
+        self.caption = value.into();
+        self
+    }
+    #[doc = "Photo TTL (Time To Live), in seconds (0-60). A non-zero TTL can be specified only in private chats"]
+    pub fn with_ttl(mut self, value: impl Into<i32>) -> Self {
+        self.ttl = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessagePhoto {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.photo
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "photo"))?;
+        self.thumbnail
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "thumbnail"))?;
+        self.caption
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "caption"))?;
+        validate::check(
+            Self::TYPE,
+            "ttl",
+            Measure::Value,
+            i64::from(self.ttl),
+            Some(0),
+            Some(60),
+        )?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A sticker message"]
+pub struct InputMessageSticker {
+    #[doc = "Sticker to be sent"]
+    pub sticker: InputFile,
+    #[doc = "Sticker thumbnail, if available"]
+    pub thumbnail: InputThumbnail,
+    #[doc = "Sticker width"]
+    pub width: i32,
+    #[doc = "Sticker height"]
+    pub height: i32,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageSticker {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageSticker {
+    const TYPE: &'static str = "inputMessageSticker";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageSticker {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(sticker: impl Into<InputFile>, thumbnail: impl Into<InputThumbnail>) -> Self {
+        Self {
+            sticker: sticker.into(),
+            thumbnail: thumbnail.into(),
+            width: Default::default(),
+            height: Default::default(),
+        }
+    }
+    #[doc = "Sticker width"]
+    pub fn with_width(mut self, value: impl Into<i32>) -> Self {
+        self.width = value.into();
+        self
+    }
+    #[doc = "Sticker height"]
+    pub fn with_height(mut self, value: impl Into<i32>) -> Self {
+        self.height = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageSticker {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.sticker
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "sticker"))?;
+        self.thumbnail
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "thumbnail"))?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A video message"]
+pub struct InputMessageVideo {
+    #[doc = "Video to be sent"]
+    pub video: InputFile,
+    #[doc = "Video thumbnail, if available"]
+    pub thumbnail: InputThumbnail,
+    #[doc = "File identifiers of the stickers added to the video, if applicable"]
+    pub added_sticker_file_ids: Vec<FileId>,
+    #[doc = "Duration of the video, in seconds"]
+    pub duration: i32,
+    #[doc = "Video width"]
+    pub width: i32,
+    #[doc = "Video height"]
+    pub height: i32,
+    #[doc = "True, if the video should be tried to be streamed"]
+    pub supports_streaming: bool,
+    #[doc = "Video caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub caption: FormattedText,
+    #[doc = "Video TTL (Time To Live), in seconds (0-60). A non-zero TTL can be specified only in private chats"]
+    pub ttl: i32,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageVideo {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageVideo {
+    const TYPE: &'static str = "inputMessageVideo";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageVideo {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(video: impl Into<InputFile>, thumbnail: impl Into<InputThumbnail>) -> Self {
+        Self {
+            video: video.into(),
+            thumbnail: thumbnail.into(),
+            added_sticker_file_ids: Default::default(),
+            duration: Default::default(),
+            width: Default::default(),
+            height: Default::default(),
+            supports_streaming: Default::default(),
+            caption: Default::default(),
+            ttl: Default::default(),
+        }
+    }
+    #[doc = "File identifiers of the stickers added to the video, if applicable"]
+    pub fn with_added_sticker_file_ids(mut self, value: impl Into<Vec<FileId>>) -> Self {
+        self.added_sticker_file_ids = value.into();
+        self
+    }
+    #[doc = "Duration of the video, in seconds"]
+    pub fn with_duration(mut self, value: impl Into<i32>) -> Self {
+        self.duration = value.into();
+        self
+    }
+    #[doc = "Video width"]
+    pub fn with_width(mut self, value: impl Into<i32>) -> Self {
+        self.width = value.into();
+        self
+    }
+    #[doc = "Video height"]
+    pub fn with_height(mut self, value: impl Into<i32>) -> Self {
+        self.height = value.into();
+        self
+    }
+    #[doc = "True, if the video should be tried to be streamed"]
+    pub fn with_supports_streaming(mut self, value: impl Into<bool>) -> Self {
+        self.supports_streaming = value.into();
+        self
+    }
+    #[doc = "Video caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub fn with_caption(mut self, value: impl Into<FormattedText>) -> Self {
+        self.caption = value.into();
+        self
+    }
+    #[doc = "Video TTL (Time To Live), in seconds (0-60). A non-zero TTL can be specified only in private chats"]
+    pub fn with_ttl(mut self, value: impl Into<i32>) -> Self {
+        self.ttl = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageVideo {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.video
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "video"))?;
+        self.thumbnail
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "thumbnail"))?;
+        self.caption
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "caption"))?;
+        validate::check(
+            Self::TYPE,
+            "ttl",
+            Measure::Value,
+            i64::from(self.ttl),
+            Some(0),
+            Some(60),
+        )?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A video note message"]
+pub struct InputMessageVideoNote {
+    #[doc = "Video note to be sent"]
+    pub video_note: InputFile,
+    #[doc = "Video thumbnail, if available"]
+    pub thumbnail: InputThumbnail,
+    #[doc = "Duration of the video, in seconds"]
+    pub duration: i32,
+    #[doc = "Video width and height; must be positive and not greater than 640"]
+    pub length: i32,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageVideoNote {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageVideoNote {
+    const TYPE: &'static str = "inputMessageVideoNote";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageVideoNote {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        video_note: impl Into<InputFile>,
+        thumbnail: impl Into<InputThumbnail>,
+        length: impl Into<i32>,
+    ) -> Self {
+        Self {
+            video_note: video_note.into(),
+            thumbnail: thumbnail.into(),
+            duration: Default::default(),
+            length: length.into(),
+        }
+    }
+    #[doc = "Duration of the video, in seconds"]
+    pub fn with_duration(mut self, value: impl Into<i32>) -> Self {
+        self.duration = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageVideoNote {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.video_note
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "video_note"))?;
+        self.thumbnail
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "thumbnail"))?;
+        validate::check(
+            Self::TYPE,
+            "length",
+            Measure::Value,
+            i64::from(self.length),
+            Some(1),
+            Some(640),
+        )?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A voice note message"]
+pub struct InputMessageVoiceNote {
+    #[doc = "Voice note to be sent"]
+    pub voice_note: InputFile,
+    #[doc = "Duration of the voice note, in seconds"]
+    pub duration: i32,
+    #[doc = "Waveform representation of the voice note, in 5-bit format"]
+    pub waveform: String,
+    #[doc = "Voice note caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub caption: FormattedText,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageVoiceNote {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageVoiceNote {
+    const TYPE: &'static str = "inputMessageVoiceNote";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageVoiceNote {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(voice_note: impl Into<InputFile>) -> Self {
+        Self {
+            voice_note: voice_note.into(),
+            duration: Default::default(),
+            waveform: Default::default(),
+            caption: Default::default(),
+        }
+    }
+    #[doc = "Duration of the voice note, in seconds"]
+    pub fn with_duration(mut self, value: impl Into<i32>) -> Self {
+        self.duration = value.into();
+        self
+    }
+    #[doc = "Waveform representation of the voice note, in 5-bit format"]
+    pub fn with_waveform(mut self, value: impl Into<String>) -> Self {
+        self.waveform = value.into();
+        self
+    }
+    #[doc = "Voice note caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub fn with_caption(mut self, value: impl Into<FormattedText>) -> Self {
+        self.caption = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageVoiceNote {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.voice_note
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "voice_note"))?;
+        self.caption
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "caption"))?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A message with a location"]
+pub struct InputMessageLocation {
+    #[doc = "Location to be sent"]
+    pub location: Location,
+    #[doc = "Period for which the location can be updated, in seconds; should be between 60 and 86400 for a live location and 0 otherwise"]
+    pub live_period: i32,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageLocation {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageLocation {
+    const TYPE: &'static str = "inputMessageLocation";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageLocation {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Location to be sent"]
+    pub fn with_location(mut self, value: impl Into<Location>) -> Self {
+        self.location = value.into();
+        self
+    }
+    #[doc = "Period for which the location can be updated, in seconds; should be between 60 and 86400 for a live location and 0 otherwise"]
+    pub fn with_live_period(mut self, value: impl Into<i32>) -> Self {
+        self.live_period = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageLocation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.location
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "location"))?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A message with information about a venue"]
+pub struct InputMessageVenue {
+    #[doc = "Venue to send"]
+    pub venue: Venue,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageVenue {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageVenue {
+    const TYPE: &'static str = "inputMessageVenue";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageVenue {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Venue to send"]
+    pub fn with_venue(mut self, value: impl Into<Venue>) -> Self {
+        self.venue = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageVenue {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.venue
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "venue"))?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message containing a user contact"]
+pub struct InputMessageContact {
+    #[doc = "Contact to send"]
+    pub contact: Contact,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageContact {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageContact {
+    const TYPE: &'static str = "inputMessageContact";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageContact {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(contact: impl Into<Contact>) -> Self {
+        Self {
+            contact: contact.into(),
+        }
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageContact {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.contact
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "contact"))?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message with a game; not supported for channels or secret chats"]
+pub struct InputMessageGame {
+    #[doc = "User identifier of the bot that owns the game"]
+    pub bot_user_id: UserId,
+    #[doc = "Short name of the game"]
+    pub game_short_name: String,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageGame {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageGame {
+    const TYPE: &'static str = "inputMessageGame";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageGame {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(bot_user_id: impl Into<UserId>) -> Self {
+        Self {
+            bot_user_id: bot_user_id.into(),
+            game_short_name: Default::default(),
+        }
+    }
+    #[doc = "Short name of the game"]
+    pub fn with_game_short_name(mut self, value: impl Into<String>) -> Self {
+        self.game_short_name = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageGame {}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message with an invoice; can be used only by bots and only in private chats"]
+pub struct InputMessageInvoice {
+    #[doc = "Invoice"]
+    pub invoice: Invoice,
+    #[doc = "Product title; 1-32 characters"]
+    pub title: String,
+    #[doc = "A message with an invoice; can be used only by bots and only in private chats"]
+    pub description: String,
+    #[doc = "Product photo URL; optional"]
+    pub photo_url: String,
+    #[doc = "Product photo size"]
+    pub photo_size: i32,
+    #[doc = "Product photo width"]
+    pub photo_width: i32,
+    #[doc = "Product photo height"]
+    pub photo_height: i32,
+    #[doc = "The invoice payload"]
+    pub payload: Bytes,
+    #[doc = "Payment provider token"]
+    pub provider_token: String,
+    #[doc = "JSON-encoded data about the invoice, which will be shared with the payment provider"]
+    pub provider_data: String,
+    #[doc = "Unique invoice bot start_parameter for the generation of this invoice"]
+    pub start_parameter: String,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageInvoice {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageInvoice {
+    const TYPE: &'static str = "inputMessageInvoice";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageInvoice {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            invoice: Default::default(),
+            title: title.into(),
+            description: Default::default(),
+            photo_url: Default::default(),
+            photo_size: Default::default(),
+            photo_width: Default::default(),
+            photo_height: Default::default(),
+            payload: Default::default(),
+            provider_token: Default::default(),
+            provider_data: Default::default(),
+            start_parameter: Default::default(),
+        }
+    }
+    #[doc = "Invoice"]
+    pub fn with_invoice(mut self, value: impl Into<Invoice>) -> Self {
+        self.invoice = value.into();
+        self
+    }
+    #[doc = "A message with an invoice; can be used only by bots and only in private chats"]
+    pub fn with_description(mut self, value: impl Into<String>) -> Self {
+        self.description = value.into();
+        self
+    }
+    #[doc = "Product photo URL; optional"]
+    pub fn with_photo_url(mut self, value: impl Into<String>) -> Self {
+        self.photo_url = value.into();
+        self
+    }
+    #[doc = "Product photo size"]
+    pub fn with_photo_size(mut self, value: impl Into<i32>) -> Self {
+        self.photo_size = value.into();
+        self
+    }
+    #[doc = "Product photo width"]
+    pub fn with_photo_width(mut self, value: impl Into<i32>) -> Self {
+        self.photo_width = value.into();
+        self
+    }
+    #[doc = "Product photo height"]
+    pub fn with_photo_height(mut self, value: impl Into<i32>) -> Self {
+        self.photo_height = value.into();
+        self
+    }
+    #[doc = "The invoice payload"]
+    pub fn with_payload(mut self, value: impl Into<Bytes>) -> Self {
+        self.payload = value.into();
+        self
+    }
+    #[doc = "Payment provider token"]
+    pub fn with_provider_token(mut self, value: impl Into<String>) -> Self {
+        self.provider_token = value.into();
+        self
+    }
+    #[doc = "JSON-encoded data about the invoice, which will be shared with the payment provider"]
+    pub fn with_provider_data(mut self, value: impl Into<String>) -> Self {
+        self.provider_data = value.into();
+        self
+    }
+    #[doc = "Unique invoice bot start_parameter for the generation of this invoice"]
+    pub fn with_start_parameter(mut self, value: impl Into<String>) -> Self {
+        self.start_parameter = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageInvoice {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.invoice
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "invoice"))?;
+        validate::check(
+            Self::TYPE,
+            "title",
+            Measure::Characters,
+            self.title.chars().count() as i64,
+            Some(1),
+            Some(32),
+        )?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message with a poll. Polls can't be sent to secret chats. Polls can be sent only to a private chat with a bot"]
+pub struct InputMessagePoll {
+    #[doc = "Poll question, 1-255 characters"]
+    pub question: String,
+    #[doc = "List of poll answer options, 2-10 strings 1-100 characters each"]
+    pub options: Vec<String>,
+    #[doc = "True, if the poll voters are anonymous. Non-anonymous polls can't be sent or forwarded to channels"]
+    pub is_anonymous: bool,
+    #[serde(rename = "type")]
+    #[doc = "Type of the poll"]
+    pub type_: PollType,
+    #[serde(default)]
+    #[doc = "True, if the poll needs to be sent already closed; for bots only"]
+    pub is_closed: Option<bool>,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessagePoll {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessagePoll {
+    const TYPE: &'static str = "inputMessagePoll";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessagePoll {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        question: impl Into<String>,
+        options: impl Into<Vec<String>>,
+        type_: impl Into<PollType>,
+    ) -> Self {
+        Self {
+            question: question.into(),
+            options: options.into(),
+            is_anonymous: Default::default(),
+            type_: type_.into(),
+            is_closed: Default::default(),
+        }
+    }
+    #[doc = "True, if the poll voters are anonymous. Non-anonymous polls can't be sent or forwarded to channels"]
+    pub fn with_is_anonymous(mut self, value: impl Into<bool>) -> Self {
+        self.is_anonymous = value.into();
+        self
+    }
+    #[doc = "True, if the poll needs to be sent already closed; for bots only"]
+    pub fn with_is_closed(mut self, value: impl Into<bool>) -> Self {
+        self.is_closed = Some(value.into());
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessagePoll {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "question",
+            Measure::Characters,
+            self.question.chars().count() as i64,
+            Some(1),
+            Some(255),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "options",
+            Measure::Elements,
+            self.options.len() as i64,
+            Some(2),
+            Some(10),
+        )?;
+        for (i, value) in self.options.iter().enumerate() {
+            validate::check(
+                Self::TYPE,
+                &format!("options[{}]", i),
+                Measure::Characters,
+                value.chars().count() as i64,
+                Some(1),
+                Some(100),
+            )?;
+        }
+        self.type_
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "type"))?;
+        Ok(())
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A forwarded message"]
+pub struct InputMessageForwarded {
+    #[doc = "Identifier for the chat this forwarded message came from"]
+    pub from_chat_id: ChatId,
+    #[doc = "Identifier of the message to forward"]
+    pub message_id: MessageId,
+    #[doc = "True, if a game message should be shared within a launched game; applies only to game messages"]
+    pub in_game_share: bool,
+    #[doc = "True, if content of the message needs to be copied without a link to the original message. Always true if the message is forwarded to a secret chat"]
+    pub send_copy: bool,
+    #[doc = "True, if media caption of the message copy needs to be removed. Ignored if send_copy is false"]
+    pub remove_caption: bool,
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageForwarded {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlConstructor for InputMessageForwarded {
+    const TYPE: &'static str = "inputMessageForwarded";
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl InputMessageForwarded {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(from_chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            from_chat_id: from_chat_id.into(),
+            message_id: message_id.into(),
+            in_game_share: Default::default(),
+            send_copy: Default::default(),
+            remove_caption: Default::default(),
+        }
+    }
+    #[doc = "True, if a game message should be shared within a launched game; applies only to game messages"]
+    pub fn with_in_game_share(mut self, value: impl Into<bool>) -> Self {
+        self.in_game_share = value.into();
+        self
+    }
+    #[doc = "True, if content of the message needs to be copied without a link to the original message. Always true if the message is forwarded to a secret chat"]
+    pub fn with_send_copy(mut self, value: impl Into<bool>) -> Self {
+        self.send_copy = value.into();
+        self
+    }
+    #[doc = "True, if media caption of the message copy needs to be removed. Ignored if send_copy is false"]
+    pub fn with_remove_caption(mut self, value: impl Into<bool>) -> Self {
+        self.remove_caption = value.into();
+        self
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageForwarded {}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "The content of a message to send"]
+pub enum InputMessageContent {
+    InputMessageText(InputMessageText),
+    InputMessageAnimation(Box<InputMessageAnimation>),
+    InputMessageAudio(Box<InputMessageAudio>),
+    InputMessageDocument(Box<InputMessageDocument>),
+    InputMessagePhoto(Box<InputMessagePhoto>),
+    InputMessageSticker(InputMessageSticker),
+    InputMessageVideo(Box<InputMessageVideo>),
+    InputMessageVideoNote(InputMessageVideoNote),
+    InputMessageVoiceNote(Box<InputMessageVoiceNote>),
+    InputMessageLocation(InputMessageLocation),
+    InputMessageVenue(Box<InputMessageVenue>),
+    InputMessageContact(InputMessageContact),
+    InputMessageGame(InputMessageGame),
+    InputMessageInvoice(Box<InputMessageInvoice>),
+    InputMessagePoll(InputMessagePoll),
+    InputMessageForwarded(InputMessageForwarded),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl<'de> Deserialize<'de> for InputMessageContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "inputMessageText" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageText)
+            }
+            "inputMessageAnimation" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageAnimation)
+            }
+            "inputMessageAudio" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageAudio)
+            }
+            "inputMessageDocument" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageDocument)
+            }
+            "inputMessagePhoto" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessagePhoto)
+            }
+            "inputMessageSticker" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageSticker)
+            }
+            "inputMessageVideo" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageVideo)
+            }
+            "inputMessageVideoNote" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageVideoNote)
+            }
+            "inputMessageVoiceNote" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageVoiceNote)
+            }
+            "inputMessageLocation" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageLocation)
+            }
+            "inputMessageVenue" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageVenue)
+            }
+            "inputMessageContact" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageContact)
+            }
+            "inputMessageGame" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageGame)
+            }
+            "inputMessageInvoice" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageInvoice)
+            }
+            "inputMessagePoll" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessagePoll)
+            }
+            "inputMessageForwarded" => {
+                Deserialize::deserialize(value).map(InputMessageContent::InputMessageForwarded)
+            }
+            _ => Unknown::deserialize(value).map(InputMessageContent::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl TlObject for InputMessageContent {
+    const CLASS: &'static str = "InputMessageContent";
+    fn type_name(&self) -> &str {
+        match self {
+            InputMessageContent::InputMessageText(_) => "inputMessageText",
+            InputMessageContent::InputMessageAnimation(_) => "inputMessageAnimation",
+            InputMessageContent::InputMessageAudio(_) => "inputMessageAudio",
+            InputMessageContent::InputMessageDocument(_) => "inputMessageDocument",
+            InputMessageContent::InputMessagePhoto(_) => "inputMessagePhoto",
+            InputMessageContent::InputMessageSticker(_) => "inputMessageSticker",
+            InputMessageContent::InputMessageVideo(_) => "inputMessageVideo",
+            InputMessageContent::InputMessageVideoNote(_) => "inputMessageVideoNote",
+            InputMessageContent::InputMessageVoiceNote(_) => "inputMessageVoiceNote",
+            InputMessageContent::InputMessageLocation(_) => "inputMessageLocation",
+            InputMessageContent::InputMessageVenue(_) => "inputMessageVenue",
+            InputMessageContent::InputMessageContact(_) => "inputMessageContact",
+            InputMessageContent::InputMessageGame(_) => "inputMessageGame",
+            InputMessageContent::InputMessageInvoice(_) => "inputMessageInvoice",
+            InputMessageContent::InputMessagePoll(_) => "inputMessagePoll",
+            InputMessageContent::InputMessageForwarded(_) => "inputMessageForwarded",
+            InputMessageContent::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl Validate for InputMessageContent {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            InputMessageContent::InputMessageText(value) => value.validate(),
+            InputMessageContent::InputMessageAnimation(value) => value.validate(),
+            InputMessageContent::InputMessageAudio(value) => value.validate(),
+            InputMessageContent::InputMessageDocument(value) => value.validate(),
+            InputMessageContent::InputMessagePhoto(value) => value.validate(),
+            InputMessageContent::InputMessageSticker(value) => value.validate(),
+            InputMessageContent::InputMessageVideo(value) => value.validate(),
+            InputMessageContent::InputMessageVideoNote(value) => value.validate(),
+            InputMessageContent::InputMessageVoiceNote(value) => value.validate(),
+            InputMessageContent::InputMessageLocation(value) => value.validate(),
+            InputMessageContent::InputMessageVenue(value) => value.validate(),
+            InputMessageContent::InputMessageContact(value) => value.validate(),
+            InputMessageContent::InputMessageGame(value) => value.validate(),
+            InputMessageContent::InputMessageInvoice(value) => value.validate(),
+            InputMessageContent::InputMessagePoll(value) => value.validate(),
+            InputMessageContent::InputMessageForwarded(value) => value.validate(),
+            InputMessageContent::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageText> for InputMessageContent {
+    fn from(value: InputMessageText) -> Self {
+        InputMessageContent::InputMessageText(value)
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageAnimation> for InputMessageContent {
+    fn from(value: InputMessageAnimation) -> Self {
+        InputMessageContent::InputMessageAnimation(Box::new(value))
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageAudio> for InputMessageContent {
+    fn from(value: InputMessageAudio) -> Self {
+        InputMessageContent::InputMessageAudio(Box::new(value))
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageDocument> for InputMessageContent {
+    fn from(value: InputMessageDocument) -> Self {
+        InputMessageContent::InputMessageDocument(Box::new(value))
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessagePhoto> for InputMessageContent {
+    fn from(value: InputMessagePhoto) -> Self {
+        InputMessageContent::InputMessagePhoto(Box::new(value))
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageSticker> for InputMessageContent {
+    fn from(value: InputMessageSticker) -> Self {
+        InputMessageContent::InputMessageSticker(value)
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageVideo> for InputMessageContent {
+    fn from(value: InputMessageVideo) -> Self {
+        InputMessageContent::InputMessageVideo(Box::new(value))
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageVideoNote> for InputMessageContent {
+    fn from(value: InputMessageVideoNote) -> Self {
+        InputMessageContent::InputMessageVideoNote(value)
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageVoiceNote> for InputMessageContent {
+    fn from(value: InputMessageVoiceNote) -> Self {
+        InputMessageContent::InputMessageVoiceNote(Box::new(value))
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageLocation> for InputMessageContent {
+    fn from(value: InputMessageLocation) -> Self {
+        InputMessageContent::InputMessageLocation(value)
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageVenue> for InputMessageContent {
+    fn from(value: InputMessageVenue) -> Self {
+        InputMessageContent::InputMessageVenue(Box::new(value))
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageContact> for InputMessageContent {
+    fn from(value: InputMessageContact) -> Self {
+        InputMessageContent::InputMessageContact(value)
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageGame> for InputMessageContent {
+    fn from(value: InputMessageGame) -> Self {
+        InputMessageContent::InputMessageGame(value)
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageInvoice> for InputMessageContent {
+    fn from(value: InputMessageInvoice) -> Self {
+        InputMessageContent::InputMessageInvoice(Box::new(value))
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessagePoll> for InputMessageContent {
+    fn from(value: InputMessagePoll) -> Self {
+        InputMessageContent::InputMessagePoll(value)
+    }
+}
+#[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+impl From<InputMessageForwarded> for InputMessageContent {
+    fn from(value: InputMessageForwarded) -> Self {
+        InputMessageContent::InputMessageForwarded(value)
+    }
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns all found messages, no filter is applied"]
+pub struct SearchMessagesFilterEmpty {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterEmpty {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterEmpty {
+    const TYPE: &'static str = "searchMessagesFilterEmpty";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterEmpty {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterEmpty {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only animation messages"]
+pub struct SearchMessagesFilterAnimation {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterAnimation {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterAnimation {
+    const TYPE: &'static str = "searchMessagesFilterAnimation";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterAnimation {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterAnimation {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only audio messages"]
+pub struct SearchMessagesFilterAudio {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterAudio {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterAudio {
+    const TYPE: &'static str = "searchMessagesFilterAudio";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterAudio {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterAudio {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only document messages"]
+pub struct SearchMessagesFilterDocument {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterDocument {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterDocument {
+    const TYPE: &'static str = "searchMessagesFilterDocument";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterDocument {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterDocument {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only photo messages"]
+pub struct SearchMessagesFilterPhoto {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterPhoto {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterPhoto {
+    const TYPE: &'static str = "searchMessagesFilterPhoto";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterPhoto {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterPhoto {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only video messages"]
+pub struct SearchMessagesFilterVideo {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterVideo {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterVideo {
+    const TYPE: &'static str = "searchMessagesFilterVideo";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterVideo {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterVideo {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only voice note messages"]
+pub struct SearchMessagesFilterVoiceNote {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterVoiceNote {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterVoiceNote {
+    const TYPE: &'static str = "searchMessagesFilterVoiceNote";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterVoiceNote {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterVoiceNote {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only photo and video messages"]
+pub struct SearchMessagesFilterPhotoAndVideo {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterPhotoAndVideo {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterPhotoAndVideo {
+    const TYPE: &'static str = "searchMessagesFilterPhotoAndVideo";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterPhotoAndVideo {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterPhotoAndVideo {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only messages containing URLs"]
+pub struct SearchMessagesFilterUrl {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterUrl {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterUrl {
+    const TYPE: &'static str = "searchMessagesFilterUrl";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterUrl {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterUrl {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only messages containing chat photos"]
+pub struct SearchMessagesFilterChatPhoto {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterChatPhoto {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterChatPhoto {
+    const TYPE: &'static str = "searchMessagesFilterChatPhoto";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterChatPhoto {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterChatPhoto {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only call messages"]
+pub struct SearchMessagesFilterCall {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterCall {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterCall {
+    const TYPE: &'static str = "searchMessagesFilterCall";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterCall {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterCall {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only incoming call messages with missed/declined discard reasons"]
+pub struct SearchMessagesFilterMissedCall {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterMissedCall {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterMissedCall {
+    const TYPE: &'static str = "searchMessagesFilterMissedCall";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterMissedCall {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterMissedCall {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only video note messages"]
+pub struct SearchMessagesFilterVideoNote {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterVideoNote {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterVideoNote {
+    const TYPE: &'static str = "searchMessagesFilterVideoNote";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterVideoNote {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterVideoNote {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only voice and video note messages"]
+pub struct SearchMessagesFilterVoiceAndVideoNote {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterVoiceAndVideoNote {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterVoiceAndVideoNote {
+    const TYPE: &'static str = "searchMessagesFilterVoiceAndVideoNote";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterVoiceAndVideoNote {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterVoiceAndVideoNote {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only messages with mentions of the current user, or messages that are replies to their messages"]
+pub struct SearchMessagesFilterMention {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterMention {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterMention {
+    const TYPE: &'static str = "searchMessagesFilterMention";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterMention {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterMention {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns only messages with unread mentions of the current user, or messages that are replies to their messages. When using this filter the results can't be additionally filtered by a query or by the sending user"]
+pub struct SearchMessagesFilterUnreadMention {}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilterUnreadMention {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for SearchMessagesFilterUnreadMention {
+    const TYPE: &'static str = "searchMessagesFilterUnreadMention";
+}
+#[cfg(feature = "messages")]
+impl SearchMessagesFilterUnreadMention {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilterUnreadMention {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Represents a filter for message search results"]
+pub enum SearchMessagesFilter {
+    SearchMessagesFilterEmpty(SearchMessagesFilterEmpty),
+    SearchMessagesFilterAnimation(SearchMessagesFilterAnimation),
+    SearchMessagesFilterAudio(SearchMessagesFilterAudio),
+    SearchMessagesFilterDocument(SearchMessagesFilterDocument),
+    SearchMessagesFilterPhoto(SearchMessagesFilterPhoto),
+    SearchMessagesFilterVideo(SearchMessagesFilterVideo),
+    SearchMessagesFilterVoiceNote(SearchMessagesFilterVoiceNote),
+    SearchMessagesFilterPhotoAndVideo(SearchMessagesFilterPhotoAndVideo),
+    SearchMessagesFilterUrl(SearchMessagesFilterUrl),
+    SearchMessagesFilterChatPhoto(SearchMessagesFilterChatPhoto),
+    SearchMessagesFilterCall(SearchMessagesFilterCall),
+    SearchMessagesFilterMissedCall(SearchMessagesFilterMissedCall),
+    SearchMessagesFilterVideoNote(SearchMessagesFilterVideoNote),
+    SearchMessagesFilterVoiceAndVideoNote(SearchMessagesFilterVoiceAndVideoNote),
+    SearchMessagesFilterMention(SearchMessagesFilterMention),
+    SearchMessagesFilterUnreadMention(SearchMessagesFilterUnreadMention),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "messages")]
+impl<'de> Deserialize<'de> for SearchMessagesFilter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "searchMessagesFilterEmpty" => {
+                Deserialize::deserialize(value).map(SearchMessagesFilter::SearchMessagesFilterEmpty)
+            }
+            "searchMessagesFilterAnimation" => Deserialize::deserialize(value)
+                .map(SearchMessagesFilter::SearchMessagesFilterAnimation),
+            "searchMessagesFilterAudio" => {
+                Deserialize::deserialize(value).map(SearchMessagesFilter::SearchMessagesFilterAudio)
+            }
+            "searchMessagesFilterDocument" => Deserialize::deserialize(value)
+                .map(SearchMessagesFilter::SearchMessagesFilterDocument),
+            "searchMessagesFilterPhoto" => {
+                Deserialize::deserialize(value).map(SearchMessagesFilter::SearchMessagesFilterPhoto)
+            }
+            "searchMessagesFilterVideo" => {
+                Deserialize::deserialize(value).map(SearchMessagesFilter::SearchMessagesFilterVideo)
+            }
+            "searchMessagesFilterVoiceNote" => Deserialize::deserialize(value)
+                .map(SearchMessagesFilter::SearchMessagesFilterVoiceNote),
+            "searchMessagesFilterPhotoAndVideo" => Deserialize::deserialize(value)
+                .map(SearchMessagesFilter::SearchMessagesFilterPhotoAndVideo),
+            "searchMessagesFilterUrl" => {
+                Deserialize::deserialize(value).map(SearchMessagesFilter::SearchMessagesFilterUrl)
+            }
+            "searchMessagesFilterChatPhoto" => Deserialize::deserialize(value)
+                .map(SearchMessagesFilter::SearchMessagesFilterChatPhoto),
+            "searchMessagesFilterCall" => {
+                Deserialize::deserialize(value).map(SearchMessagesFilter::SearchMessagesFilterCall)
+            }
+            "searchMessagesFilterMissedCall" => Deserialize::deserialize(value)
+                .map(SearchMessagesFilter::SearchMessagesFilterMissedCall),
+            "searchMessagesFilterVideoNote" => Deserialize::deserialize(value)
+                .map(SearchMessagesFilter::SearchMessagesFilterVideoNote),
+            "searchMessagesFilterVoiceAndVideoNote" => Deserialize::deserialize(value)
+                .map(SearchMessagesFilter::SearchMessagesFilterVoiceAndVideoNote),
+            "searchMessagesFilterMention" => Deserialize::deserialize(value)
+                .map(SearchMessagesFilter::SearchMessagesFilterMention),
+            "searchMessagesFilterUnreadMention" => Deserialize::deserialize(value)
+                .map(SearchMessagesFilter::SearchMessagesFilterUnreadMention),
+            _ => Unknown::deserialize(value).map(SearchMessagesFilter::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "messages")]
+impl TlObject for SearchMessagesFilter {
+    const CLASS: &'static str = "SearchMessagesFilter";
+    fn type_name(&self) -> &str {
+        match self {
+            SearchMessagesFilter::SearchMessagesFilterEmpty(_) => "searchMessagesFilterEmpty",
+            SearchMessagesFilter::SearchMessagesFilterAnimation(_) => {
+                "searchMessagesFilterAnimation"
+            }
+            SearchMessagesFilter::SearchMessagesFilterAudio(_) => "searchMessagesFilterAudio",
+            SearchMessagesFilter::SearchMessagesFilterDocument(_) => "searchMessagesFilterDocument",
+            SearchMessagesFilter::SearchMessagesFilterPhoto(_) => "searchMessagesFilterPhoto",
+            SearchMessagesFilter::SearchMessagesFilterVideo(_) => "searchMessagesFilterVideo",
+            SearchMessagesFilter::SearchMessagesFilterVoiceNote(_) => {
+                "searchMessagesFilterVoiceNote"
+            }
+            SearchMessagesFilter::SearchMessagesFilterPhotoAndVideo(_) => {
+                "searchMessagesFilterPhotoAndVideo"
+            }
+            SearchMessagesFilter::SearchMessagesFilterUrl(_) => "searchMessagesFilterUrl",
+            SearchMessagesFilter::SearchMessagesFilterChatPhoto(_) => {
+                "searchMessagesFilterChatPhoto"
+            }
+            SearchMessagesFilter::SearchMessagesFilterCall(_) => "searchMessagesFilterCall",
+            SearchMessagesFilter::SearchMessagesFilterMissedCall(_) => {
+                "searchMessagesFilterMissedCall"
+            }
+            SearchMessagesFilter::SearchMessagesFilterVideoNote(_) => {
+                "searchMessagesFilterVideoNote"
+            }
+            SearchMessagesFilter::SearchMessagesFilterVoiceAndVideoNote(_) => {
+                "searchMessagesFilterVoiceAndVideoNote"
+            }
+            SearchMessagesFilter::SearchMessagesFilterMention(_) => "searchMessagesFilterMention",
+            SearchMessagesFilter::SearchMessagesFilterUnreadMention(_) => {
+                "searchMessagesFilterUnreadMention"
+            }
+            SearchMessagesFilter::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for SearchMessagesFilter {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            SearchMessagesFilter::SearchMessagesFilterEmpty(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterAnimation(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterAudio(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterDocument(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterPhoto(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterVideo(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterVoiceNote(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterPhotoAndVideo(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterUrl(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterChatPhoto(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterCall(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterMissedCall(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterVideoNote(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterVoiceAndVideoNote(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterMention(value) => value.validate(),
+            SearchMessagesFilter::SearchMessagesFilterUnreadMention(value) => value.validate(),
+            SearchMessagesFilter::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterEmpty> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterEmpty) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterEmpty(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterAnimation> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterAnimation) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterAnimation(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterAudio> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterAudio) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterAudio(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterDocument> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterDocument) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterDocument(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterPhoto> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterPhoto) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterPhoto(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterVideo> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterVideo) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterVideo(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterVoiceNote> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterVoiceNote) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterVoiceNote(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterPhotoAndVideo> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterPhotoAndVideo) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterPhotoAndVideo(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterUrl> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterUrl) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterUrl(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterChatPhoto> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterChatPhoto) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterChatPhoto(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterCall> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterCall) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterCall(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterMissedCall> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterMissedCall) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterMissedCall(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterVideoNote> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterVideoNote) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterVideoNote(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterVoiceAndVideoNote> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterVoiceAndVideoNote) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterVoiceAndVideoNote(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterMention> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterMention) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterMention(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<SearchMessagesFilterUnreadMention> for SearchMessagesFilter {
+    fn from(value: SearchMessagesFilterUnreadMention) -> Self {
+        SearchMessagesFilter::SearchMessagesFilterUnreadMention(value)
+    }
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A general message with hidden content"]
+pub struct PushMessageContentHidden {
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentHidden {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentHidden {
+    const TYPE: &'static str = "pushMessageContentHidden";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An animation message (GIF-style)."]
+pub struct PushMessageContentAnimation {
+    #[serde(default)]
+    #[doc = "Message content; may be null"]
+    pub animation: Option<Animation>,
+    #[doc = "Animation caption"]
+    pub caption: String,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentAnimation {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentAnimation {
+    const TYPE: &'static str = "pushMessageContentAnimation";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An audio message"]
+pub struct PushMessageContentAudio {
+    #[serde(default)]
+    #[doc = "Message content; may be null"]
+    pub audio: Option<Audio>,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentAudio {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentAudio {
+    const TYPE: &'static str = "pushMessageContentAudio";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message with a user contact"]
+pub struct PushMessageContentContact {
+    #[doc = "Contact's name"]
+    pub name: String,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentContact {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentContact {
+    const TYPE: &'static str = "pushMessageContentContact";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A contact has registered with Telegram"]
+pub struct PushMessageContentContactRegistered {}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentContactRegistered {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentContactRegistered {
+    const TYPE: &'static str = "pushMessageContentContactRegistered";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A document message (a general file)"]
+pub struct PushMessageContentDocument {
+    #[serde(default)]
+    #[doc = "Message content; may be null"]
+    pub document: Option<Document>,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentDocument {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentDocument {
+    const TYPE: &'static str = "pushMessageContentDocument";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message with a game"]
+pub struct PushMessageContentGame {
+    #[doc = "Game title, empty for pinned game message"]
+    pub title: String,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentGame {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentGame {
+    const TYPE: &'static str = "pushMessageContentGame";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new high score was achieved in a game"]
+pub struct PushMessageContentGameScore {
+    #[doc = "Game title, empty for pinned message"]
+    pub title: String,
+    #[doc = "New score, 0 for pinned message"]
+    pub score: i32,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentGameScore {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentGameScore {
+    const TYPE: &'static str = "pushMessageContentGameScore";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message with an invoice from a bot"]
+pub struct PushMessageContentInvoice {
+    #[doc = "Product price"]
+    pub price: String,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentInvoice {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentInvoice {
+    const TYPE: &'static str = "pushMessageContentInvoice";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message with a location"]
+pub struct PushMessageContentLocation {
+    #[doc = "True, if the location is live"]
+    pub is_live: bool,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentLocation {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentLocation {
+    const TYPE: &'static str = "pushMessageContentLocation";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A photo message"]
+pub struct PushMessageContentPhoto {
+    #[serde(default)]
+    #[doc = "Message content; may be null"]
+    pub photo: Option<Photo>,
+    #[doc = "Photo caption"]
+    pub caption: String,
+    #[doc = "True, if the photo is secret"]
+    pub is_secret: bool,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentPhoto {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentPhoto {
+    const TYPE: &'static str = "pushMessageContentPhoto";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message with a poll"]
+pub struct PushMessageContentPoll {
+    #[doc = "Poll question"]
+    pub question: String,
+    #[doc = "True, if the poll is regular and not in quiz mode"]
+    pub is_regular: bool,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentPoll {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentPoll {
+    const TYPE: &'static str = "pushMessageContentPoll";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A screenshot of a message in the chat has been taken"]
+pub struct PushMessageContentScreenshotTaken {}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentScreenshotTaken {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentScreenshotTaken {
+    const TYPE: &'static str = "pushMessageContentScreenshotTaken";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message with a sticker"]
+pub struct PushMessageContentSticker {
+    #[serde(default)]
+    #[doc = "Message content; may be null"]
+    pub sticker: Option<Sticker>,
+    #[doc = "Emoji corresponding to the sticker; may be empty"]
+    pub emoji: String,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentSticker {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentSticker {
+    const TYPE: &'static str = "pushMessageContentSticker";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A text message"]
+pub struct PushMessageContentText {
+    #[doc = "Message text"]
+    pub text: String,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentText {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentText {
+    const TYPE: &'static str = "pushMessageContentText";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A video message"]
+pub struct PushMessageContentVideo {
+    #[serde(default)]
+    #[doc = "Message content; may be null"]
+    pub video: Option<Video>,
+    #[doc = "Video caption"]
+    pub caption: String,
+    #[doc = "True, if the video is secret"]
+    pub is_secret: bool,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentVideo {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentVideo {
+    const TYPE: &'static str = "pushMessageContentVideo";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A video note message"]
+pub struct PushMessageContentVideoNote {
+    #[serde(default)]
+    #[doc = "Message content; may be null"]
+    pub video_note: Option<VideoNote>,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentVideoNote {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentVideoNote {
+    const TYPE: &'static str = "pushMessageContentVideoNote";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A voice note message"]
+pub struct PushMessageContentVoiceNote {
+    #[serde(default)]
+    #[doc = "Message content; may be null"]
+    pub voice_note: Option<VoiceNote>,
+    #[doc = "True, if the message is a pinned message with the specified content"]
+    pub is_pinned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentVoiceNote {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentVoiceNote {
+    const TYPE: &'static str = "pushMessageContentVoiceNote";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A newly created basic group"]
+pub struct PushMessageContentBasicGroupChatCreate {}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentBasicGroupChatCreate {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentBasicGroupChatCreate {
+    const TYPE: &'static str = "pushMessageContentBasicGroupChatCreate";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "New chat members were invited to a group"]
+pub struct PushMessageContentChatAddMembers {
+    #[doc = "Name of the added member"]
+    pub member_name: String,
+    #[doc = "True, if the current user was added to the group"]
+    pub is_current_user: bool,
+    #[doc = "True, if the user has returned to the group themself"]
+    pub is_returned: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentChatAddMembers {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentChatAddMembers {
+    const TYPE: &'static str = "pushMessageContentChatAddMembers";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A chat photo was edited"]
+pub struct PushMessageContentChatChangePhoto {}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentChatChangePhoto {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentChatChangePhoto {
+    const TYPE: &'static str = "pushMessageContentChatChangePhoto";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A chat title was edited"]
+pub struct PushMessageContentChatChangeTitle {
+    #[doc = "New chat title"]
+    pub title: String,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentChatChangeTitle {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentChatChangeTitle {
+    const TYPE: &'static str = "pushMessageContentChatChangeTitle";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A chat member was deleted"]
+pub struct PushMessageContentChatDeleteMember {
+    #[doc = "Name of the deleted member"]
+    pub member_name: String,
+    #[doc = "True, if the current user was deleted from the group"]
+    pub is_current_user: bool,
+    #[doc = "True, if the user has left the group themself"]
+    pub is_left: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentChatDeleteMember {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentChatDeleteMember {
+    const TYPE: &'static str = "pushMessageContentChatDeleteMember";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new member joined the chat by invite link"]
+pub struct PushMessageContentChatJoinByLink {}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentChatJoinByLink {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentChatJoinByLink {
+    const TYPE: &'static str = "pushMessageContentChatJoinByLink";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A forwarded messages"]
+pub struct PushMessageContentMessageForwards {
+    #[doc = "Number of forwarded messages"]
+    pub total_count: i32,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentMessageForwards {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentMessageForwards {
+    const TYPE: &'static str = "pushMessageContentMessageForwards";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A media album"]
+pub struct PushMessageContentMediaAlbum {
+    #[doc = "Number of messages in the album"]
+    pub total_count: i32,
+    #[doc = "True, if the album has at least one photo"]
+    pub has_photos: bool,
+    #[doc = "True, if the album has at least one video"]
+    pub has_videos: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContentMediaAlbum {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PushMessageContentMediaAlbum {
+    const TYPE: &'static str = "pushMessageContentMediaAlbum";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Contains content of a push message notification"]
+pub enum PushMessageContent {
+    PushMessageContentHidden(PushMessageContentHidden),
+    PushMessageContentAnimation(Box<PushMessageContentAnimation>),
+    PushMessageContentAudio(Box<PushMessageContentAudio>),
+    PushMessageContentContact(PushMessageContentContact),
+    PushMessageContentContactRegistered(PushMessageContentContactRegistered),
+    PushMessageContentDocument(Box<PushMessageContentDocument>),
+    PushMessageContentGame(PushMessageContentGame),
+    PushMessageContentGameScore(PushMessageContentGameScore),
+    PushMessageContentInvoice(PushMessageContentInvoice),
+    PushMessageContentLocation(PushMessageContentLocation),
+    PushMessageContentPhoto(PushMessageContentPhoto),
+    PushMessageContentPoll(PushMessageContentPoll),
+    PushMessageContentScreenshotTaken(PushMessageContentScreenshotTaken),
+    PushMessageContentSticker(Box<PushMessageContentSticker>),
+    PushMessageContentText(PushMessageContentText),
+    PushMessageContentVideo(Box<PushMessageContentVideo>),
+    PushMessageContentVideoNote(Box<PushMessageContentVideoNote>),
+    PushMessageContentVoiceNote(Box<PushMessageContentVoiceNote>),
+    PushMessageContentBasicGroupChatCreate(PushMessageContentBasicGroupChatCreate),
+    PushMessageContentChatAddMembers(PushMessageContentChatAddMembers),
+    PushMessageContentChatChangePhoto(PushMessageContentChatChangePhoto),
+    PushMessageContentChatChangeTitle(PushMessageContentChatChangeTitle),
+    PushMessageContentChatDeleteMember(PushMessageContentChatDeleteMember),
+    PushMessageContentChatJoinByLink(PushMessageContentChatJoinByLink),
+    PushMessageContentMessageForwards(PushMessageContentMessageForwards),
+    PushMessageContentMediaAlbum(PushMessageContentMediaAlbum),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "messages")]
+impl<'de> Deserialize<'de> for PushMessageContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "pushMessageContentHidden" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentHidden)
+            }
+            "pushMessageContentAnimation" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentAnimation)
+            }
+            "pushMessageContentAudio" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentAudio)
+            }
+            "pushMessageContentContact" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentContact)
+            }
+            "pushMessageContentContactRegistered" => Deserialize::deserialize(value)
+                .map(PushMessageContent::PushMessageContentContactRegistered),
+            "pushMessageContentDocument" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentDocument)
+            }
+            "pushMessageContentGame" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentGame)
+            }
+            "pushMessageContentGameScore" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentGameScore)
+            }
+            "pushMessageContentInvoice" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentInvoice)
+            }
+            "pushMessageContentLocation" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentLocation)
+            }
+            "pushMessageContentPhoto" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentPhoto)
+            }
+            "pushMessageContentPoll" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentPoll)
+            }
+            "pushMessageContentScreenshotTaken" => Deserialize::deserialize(value)
+                .map(PushMessageContent::PushMessageContentScreenshotTaken),
+            "pushMessageContentSticker" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentSticker)
+            }
+            "pushMessageContentText" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentText)
+            }
+            "pushMessageContentVideo" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentVideo)
+            }
+            "pushMessageContentVideoNote" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentVideoNote)
+            }
+            "pushMessageContentVoiceNote" => {
+                Deserialize::deserialize(value).map(PushMessageContent::PushMessageContentVoiceNote)
+            }
+            "pushMessageContentBasicGroupChatCreate" => Deserialize::deserialize(value)
+                .map(PushMessageContent::PushMessageContentBasicGroupChatCreate),
+            "pushMessageContentChatAddMembers" => Deserialize::deserialize(value)
+                .map(PushMessageContent::PushMessageContentChatAddMembers),
+            "pushMessageContentChatChangePhoto" => Deserialize::deserialize(value)
+                .map(PushMessageContent::PushMessageContentChatChangePhoto),
+            "pushMessageContentChatChangeTitle" => Deserialize::deserialize(value)
+                .map(PushMessageContent::PushMessageContentChatChangeTitle),
+            "pushMessageContentChatDeleteMember" => Deserialize::deserialize(value)
+                .map(PushMessageContent::PushMessageContentChatDeleteMember),
+            "pushMessageContentChatJoinByLink" => Deserialize::deserialize(value)
+                .map(PushMessageContent::PushMessageContentChatJoinByLink),
+            "pushMessageContentMessageForwards" => Deserialize::deserialize(value)
+                .map(PushMessageContent::PushMessageContentMessageForwards),
+            "pushMessageContentMediaAlbum" => Deserialize::deserialize(value)
+                .map(PushMessageContent::PushMessageContentMediaAlbum),
+            _ => Unknown::deserialize(value).map(PushMessageContent::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "messages")]
+impl TlObject for PushMessageContent {
+    const CLASS: &'static str = "PushMessageContent";
+    fn type_name(&self) -> &str {
+        match self {
+            PushMessageContent::PushMessageContentHidden(_) => "pushMessageContentHidden",
+            PushMessageContent::PushMessageContentAnimation(_) => "pushMessageContentAnimation",
+            PushMessageContent::PushMessageContentAudio(_) => "pushMessageContentAudio",
+            PushMessageContent::PushMessageContentContact(_) => "pushMessageContentContact",
+            PushMessageContent::PushMessageContentContactRegistered(_) => {
+                "pushMessageContentContactRegistered"
+            }
+            PushMessageContent::PushMessageContentDocument(_) => "pushMessageContentDocument",
+            PushMessageContent::PushMessageContentGame(_) => "pushMessageContentGame",
+            PushMessageContent::PushMessageContentGameScore(_) => "pushMessageContentGameScore",
+            PushMessageContent::PushMessageContentInvoice(_) => "pushMessageContentInvoice",
+            PushMessageContent::PushMessageContentLocation(_) => "pushMessageContentLocation",
+            PushMessageContent::PushMessageContentPhoto(_) => "pushMessageContentPhoto",
+            PushMessageContent::PushMessageContentPoll(_) => "pushMessageContentPoll",
+            PushMessageContent::PushMessageContentScreenshotTaken(_) => {
+                "pushMessageContentScreenshotTaken"
+            }
+            PushMessageContent::PushMessageContentSticker(_) => "pushMessageContentSticker",
+            PushMessageContent::PushMessageContentText(_) => "pushMessageContentText",
+            PushMessageContent::PushMessageContentVideo(_) => "pushMessageContentVideo",
+            PushMessageContent::PushMessageContentVideoNote(_) => "pushMessageContentVideoNote",
+            PushMessageContent::PushMessageContentVoiceNote(_) => "pushMessageContentVoiceNote",
+            PushMessageContent::PushMessageContentBasicGroupChatCreate(_) => {
+                "pushMessageContentBasicGroupChatCreate"
+            }
+            PushMessageContent::PushMessageContentChatAddMembers(_) => {
+                "pushMessageContentChatAddMembers"
+            }
+            PushMessageContent::PushMessageContentChatChangePhoto(_) => {
+                "pushMessageContentChatChangePhoto"
+            }
+            PushMessageContent::PushMessageContentChatChangeTitle(_) => {
+                "pushMessageContentChatChangeTitle"
+            }
+            PushMessageContent::PushMessageContentChatDeleteMember(_) => {
+                "pushMessageContentChatDeleteMember"
+            }
+            PushMessageContent::PushMessageContentChatJoinByLink(_) => {
+                "pushMessageContentChatJoinByLink"
+            }
+            PushMessageContent::PushMessageContentMessageForwards(_) => {
+                "pushMessageContentMessageForwards"
+            }
+            PushMessageContent::PushMessageContentMediaAlbum(_) => "pushMessageContentMediaAlbum",
+            PushMessageContent::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "New message was received"]
+pub struct NotificationTypeNewMessage {
+    #[doc = "The message"]
+    pub message: Message,
+}
+#[cfg(feature = "messages")]
+impl TlObject for NotificationTypeNewMessage {
+    const CLASS: &'static str = "NotificationType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for NotificationTypeNewMessage {
+    const TYPE: &'static str = "notificationTypeNewMessage";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "New message was received through a push notification"]
+pub struct NotificationTypeNewPushMessage {
+    #[doc = "The message identifier. The message will not be available in the chat history, but the ID can be used in viewMessages and as reply_to_message_id"]
+    pub message_id: MessageId,
+    #[doc = "Sender of the message. Corresponding user may be inaccessible"]
+    pub sender_user_id: UserId,
+    #[doc = "Push message content"]
+    pub content: PushMessageContent,
+}
+#[cfg(feature = "messages")]
+impl TlObject for NotificationTypeNewPushMessage {
+    const CLASS: &'static str = "NotificationType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for NotificationTypeNewPushMessage {
+    const TYPE: &'static str = "notificationTypeNewPushMessage";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a public HTTPS link to a message in a supergroup or channel with a username"]
+pub struct PublicMessageLink {
+    #[doc = "Message link"]
+    pub link: String,
+    #[doc = "HTML-code for embedding the message"]
+    pub html: String,
+}
+#[cfg(feature = "messages")]
+impl TlObject for PublicMessageLink {
+    const CLASS: &'static str = "PublicMessageLink";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for PublicMessageLink {
+    const TYPE: &'static str = "publicMessageLink";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about a link to a message in a chat"]
+pub struct MessageLinkInfo {
+    #[doc = "True, if the link is a public link for a message in a chat"]
+    pub is_public: bool,
+    #[doc = "If found, identifier of the chat to which the message belongs, 0 otherwise"]
+    pub chat_id: ChatId,
+    #[serde(default)]
+    #[doc = "If found, the linked message; may be null"]
+    pub message: Option<Message>,
+    #[doc = "True, if the whole media album to which the message belongs is linked"]
+    pub for_album: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for MessageLinkInfo {
+    const CLASS: &'static str = "MessageLinkInfo";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for MessageLinkInfo {
+    const TYPE: &'static str = "messageLinkInfo";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The text should be parsed in markdown-style"]
+pub struct TextParseModeMarkdown {
+    #[doc = "Version of the parser: 0 or 1 - Bot API Markdown parse mode, 2 - Bot API MarkdownV2 parse mode"]
+    pub version: i32,
+}
+#[cfg(feature = "messages")]
+impl TlObject for TextParseModeMarkdown {
+    const CLASS: &'static str = "TextParseMode";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for TextParseModeMarkdown {
+    const TYPE: &'static str = "textParseModeMarkdown";
+}
+#[cfg(feature = "messages")]
+impl TextParseModeMarkdown {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Version of the parser: 0 or 1 - Bot API Markdown parse mode, 2 - Bot API MarkdownV2 parse mode"]
+    pub fn with_version(mut self, value: impl Into<i32>) -> Self {
+        self.version = value.into();
+        self
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for TextParseModeMarkdown {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The text should be parsed in HTML-style"]
+pub struct TextParseModeHTML {}
+#[cfg(feature = "messages")]
+impl TlObject for TextParseModeHTML {
+    const CLASS: &'static str = "TextParseMode";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for TextParseModeHTML {
+    const TYPE: &'static str = "textParseModeHTML";
+}
+#[cfg(feature = "messages")]
+impl TextParseModeHTML {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for TextParseModeHTML {}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes the way the text should be parsed for TextEntities"]
+pub enum TextParseMode {
+    TextParseModeMarkdown(TextParseModeMarkdown),
+    TextParseModeHTML(TextParseModeHTML),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "messages")]
+impl<'de> Deserialize<'de> for TextParseMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "textParseModeMarkdown" => {
+                Deserialize::deserialize(value).map(TextParseMode::TextParseModeMarkdown)
+            }
+            "textParseModeHTML" => {
+                Deserialize::deserialize(value).map(TextParseMode::TextParseModeHTML)
+            }
+            _ => Unknown::deserialize(value).map(TextParseMode::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "messages")]
+impl TlObject for TextParseMode {
+    const CLASS: &'static str = "TextParseMode";
+    fn type_name(&self) -> &str {
+        match self {
+            TextParseMode::TextParseModeMarkdown(_) => "textParseModeMarkdown",
+            TextParseMode::TextParseModeHTML(_) => "textParseModeHTML",
+            TextParseMode::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "messages")]
+impl Validate for TextParseMode {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            TextParseMode::TextParseModeMarkdown(value) => value.validate(),
+            TextParseMode::TextParseModeHTML(value) => value.validate(),
+            TextParseMode::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(feature = "messages")]
+impl From<TextParseModeMarkdown> for TextParseMode {
+    fn from(value: TextParseModeMarkdown) -> Self {
+        TextParseMode::TextParseModeMarkdown(value)
+    }
+}
+#[cfg(feature = "messages")]
+impl From<TextParseModeHTML> for TextParseMode {
+    fn from(value: TextParseModeHTML) -> Self {
+        TextParseMode::TextParseModeHTML(value)
+    }
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new message was received; can also be an outgoing message"]
+pub struct UpdateNewMessage {
+    #[doc = "The new message"]
+    pub message: Message,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateNewMessage {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateNewMessage {
+    const TYPE: &'static str = "updateNewMessage";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A request to send a message has reached the Telegram server. This doesn't mean that the message will be sent successfully or even that the send message request will be processed. This update will be sent only if the option \"use_quick_ack\" is set to true. This update may be sent multiple times for the same message"]
+pub struct UpdateMessageSendAcknowledged {
+    #[doc = "The chat identifier of the sent message"]
+    pub chat_id: ChatId,
+    #[doc = "A temporary message identifier"]
+    pub message_id: MessageId,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateMessageSendAcknowledged {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateMessageSendAcknowledged {
+    const TYPE: &'static str = "updateMessageSendAcknowledged";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message has been successfully sent"]
+pub struct UpdateMessageSendSucceeded {
+    #[doc = "Information about the sent message. Usually only the message identifier, date, and content are changed, but almost all other fields can also change"]
+    pub message: Message,
+    #[doc = "The previous temporary message identifier"]
+    pub old_message_id: MessageId,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateMessageSendSucceeded {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateMessageSendSucceeded {
+    const TYPE: &'static str = "updateMessageSendSucceeded";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message failed to send. Be aware that some messages being sent can be irrecoverably deleted, in which case updateDeleteMessages will be received instead of this update"]
+pub struct UpdateMessageSendFailed {
+    #[doc = "Contains information about the message which failed to send"]
+    pub message: Message,
+    #[doc = "The previous temporary message identifier"]
+    pub old_message_id: MessageId,
+    #[doc = "An error code"]
+    pub error_code: i32,
+    #[doc = "Error message"]
+    pub error_message: String,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateMessageSendFailed {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateMessageSendFailed {
+    const TYPE: &'static str = "updateMessageSendFailed";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The message content has changed"]
+pub struct UpdateMessageContent {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Message identifier"]
+    pub message_id: MessageId,
+    #[doc = "New message content"]
+    pub new_content: MessageContent,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateMessageContent {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateMessageContent {
+    const TYPE: &'static str = "updateMessageContent";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message was edited. Changes in the message content will come in a separate updateMessageContent"]
+pub struct UpdateMessageEdited {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Message identifier"]
+    pub message_id: MessageId,
+    #[doc = "Point in time (Unix timestamp) when the message was edited"]
+    pub edit_date: i32,
+    #[serde(default)]
+    #[doc = "New message reply markup; may be null"]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateMessageEdited {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateMessageEdited {
+    const TYPE: &'static str = "updateMessageEdited";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The view count of the message has changed"]
+pub struct UpdateMessageViews {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Message identifier"]
+    pub message_id: MessageId,
+    #[doc = "New value of the view count"]
+    pub views: i32,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateMessageViews {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateMessageViews {
+    const TYPE: &'static str = "updateMessageViews";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The message content was opened. Updates voice note messages to \"listened\", video note messages to \"viewed\" and starts the TTL timer for self-destructing messages"]
+pub struct UpdateMessageContentOpened {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Message identifier"]
+    pub message_id: MessageId,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateMessageContentOpened {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateMessageContentOpened {
+    const TYPE: &'static str = "updateMessageContentOpened";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message with an unread mention was read"]
+pub struct UpdateMessageMentionRead {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Message identifier"]
+    pub message_id: MessageId,
+    #[doc = "The new number of unread mention messages left in the chat"]
+    pub unread_mention_count: i32,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateMessageMentionRead {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateMessageMentionRead {
+    const TYPE: &'static str = "updateMessageMentionRead";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message with a live location was viewed. When the update is received, the client is supposed to update the live location"]
+pub struct UpdateMessageLiveLocationViewed {
+    #[doc = "Identifier of the chat with the live location message"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message with live location"]
+    pub message_id: MessageId,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateMessageLiveLocationViewed {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateMessageLiveLocationViewed {
+    const TYPE: &'static str = "updateMessageLiveLocationViewed";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The last message of a chat was changed. If last_message is null, then the last message in the chat became unknown. Some new unknown messages might be added to the chat in this case"]
+pub struct UpdateChatLastMessage {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[serde(default)]
+    #[doc = "The new last message in the chat; may be null"]
+    pub last_message: Option<Message>,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "New value of the chat order"]
+    pub order: i64,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateChatLastMessage {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateChatLastMessage {
+    const TYPE: &'static str = "updateChatLastMessage";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A chat's has_scheduled_messages field has changed"]
+pub struct UpdateChatHasScheduledMessages {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New value of has_scheduled_messages"]
+    pub has_scheduled_messages: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateChatHasScheduledMessages {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateChatHasScheduledMessages {
+    const TYPE: &'static str = "updateChatHasScheduledMessages";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat pinned message was changed"]
+pub struct UpdateChatPinnedMessage {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "The new identifier of the pinned message; 0 if there is no pinned message in the chat"]
+    pub pinned_message_id: MessageId,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateChatPinnedMessage {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateChatPinnedMessage {
+    const TYPE: &'static str = "updateChatPinnedMessage";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A chat draft has changed. Be aware that the update may come in the currently opened chat but with old content of the draft. If the user has changed the content of the draft, this update shouldn't be applied"]
+pub struct UpdateChatDraftMessage {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[serde(default)]
+    #[doc = "The new draft message; may be null"]
+    pub draft_message: Option<DraftMessage>,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "New value of the chat order"]
+    pub order: i64,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateChatDraftMessage {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateChatDraftMessage {
+    const TYPE: &'static str = "updateChatDraftMessage";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Some messages were deleted"]
+pub struct UpdateDeleteMessages {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Identifiers of the deleted messages"]
+    pub message_ids: Vec<MessageId>,
+    #[doc = "True, if the messages are permanently deleted by a user (as opposed to just becoming inaccessible)"]
+    pub is_permanent: bool,
+    #[doc = "True, if the messages are deleted only from the cache and can possibly be retrieved again in the future"]
+    pub from_cache: bool,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateDeleteMessages {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateDeleteMessages {
+    const TYPE: &'static str = "updateDeleteMessages";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Number of unread messages in a chat list has changed. This update is sent only if the message database is used"]
+pub struct UpdateUnreadMessageCount {
+    #[doc = "The chat list with changed number of unread messages"]
+    pub chat_list: ChatList,
+    #[doc = "Total number of unread messages"]
+    pub unread_count: i32,
+    #[doc = "Total number of unread messages in unmuted chats"]
+    pub unread_unmuted_count: i32,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdateUnreadMessageCount {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdateUnreadMessageCount {
+    const TYPE: &'static str = "updateUnreadMessageCount";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A poll was updated; for bots only"]
+pub struct UpdatePoll {
+    #[doc = "New data about the poll"]
+    pub poll: Poll,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdatePoll {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdatePoll {
+    const TYPE: &'static str = "updatePoll";
+}
+#[cfg(feature = "messages")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A user changed the answer to a poll; for bots only"]
+pub struct UpdatePollAnswer {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Unique poll identifier"]
+    pub poll_id: i64,
+    #[doc = "The user, who changed the answer to the poll"]
+    pub user_id: UserId,
+    #[doc = "0-based identifiers of answer options, chosen by the user"]
+    pub option_ids: Vec<i32>,
+}
+#[cfg(feature = "messages")]
+impl TlObject for UpdatePollAnswer {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "messages")]
+impl TlConstructor for UpdatePollAnswer {
+    const TYPE: &'static str = "updatePollAnswer";
+}
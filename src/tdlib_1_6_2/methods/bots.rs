@@ -0,0 +1,947 @@
+//! The methods of the `bots` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Invites a bot to a chat (if it is not yet a member) and sends it the /start command. Bots can't be invited to a private chat other than the chat with the bot. Bots can't be invited to channels (although they can be added as admins) and secret chats. Returns the sent message"]
+pub struct SendBotStartMessage {
+    #[doc = "Identifier of the bot"]
+    pub bot_user_id: UserId,
+    #[doc = "Identifier of the target chat"]
+    pub chat_id: ChatId,
+    #[doc = "A hidden parameter sent to the bot for deep linking purposes (https://core.telegram.org/bots#deep-linking)"]
+    pub parameter: String,
+}
+impl Method for SendBotStartMessage {
+    const TYPE: &'static str = "sendBotStartMessage";
+    type Response = Message;
+}
+impl SendBotStartMessage {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(bot_user_id: impl Into<UserId>, chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            bot_user_id: bot_user_id.into(),
+            chat_id: chat_id.into(),
+            parameter: Default::default(),
+        }
+    }
+    #[doc = "A hidden parameter sent to the bot for deep linking purposes (https://core.telegram.org/bots#deep-linking)"]
+    pub fn with_parameter(mut self, value: impl Into<String>) -> Self {
+        self.parameter = value.into();
+        self
+    }
+}
+impl Validate for SendBotStartMessage {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sends the result of an inline query as a message. Returns the sent message. Always clears a chat draft message"]
+pub struct SendInlineQueryResultMessage {
+    #[doc = "Target chat"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of a message to reply to or 0"]
+    pub reply_to_message_id: MessageId,
+    #[doc = "Options to be used to send the message"]
+    pub options: SendMessageOptions,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of the inline query"]
+    pub query_id: i64,
+    #[doc = "Identifier of the inline result"]
+    pub result_id: String,
+    #[doc = "If true, there will be no mention of a bot, via which the message is sent. Can be used only for bots GetOption(\"animation_search_bot_username\"), GetOption(\"photo_search_bot_username\") and GetOption(\"venue_search_bot_username\")"]
+    pub hide_via_bot: bool,
+}
+impl Method for SendInlineQueryResultMessage {
+    const TYPE: &'static str = "sendInlineQueryResultMessage";
+    type Response = Message;
+}
+impl SendInlineQueryResultMessage {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            reply_to_message_id: Default::default(),
+            options: Default::default(),
+            query_id: Default::default(),
+            result_id: Default::default(),
+            hide_via_bot: Default::default(),
+        }
+    }
+    #[doc = "Identifier of a message to reply to or 0"]
+    pub fn with_reply_to_message_id(mut self, value: impl Into<MessageId>) -> Self {
+        self.reply_to_message_id = value.into();
+        self
+    }
+    #[doc = "Options to be used to send the message"]
+    pub fn with_options(mut self, value: impl Into<SendMessageOptions>) -> Self {
+        self.options = value.into();
+        self
+    }
+    #[doc = "Identifier of the inline query"]
+    pub fn with_query_id(mut self, value: impl Into<i64>) -> Self {
+        self.query_id = value.into();
+        self
+    }
+    #[doc = "Identifier of the inline result"]
+    pub fn with_result_id(mut self, value: impl Into<String>) -> Self {
+        self.result_id = value.into();
+        self
+    }
+    #[doc = "If true, there will be no mention of a bot, via which the message is sent. Can be used only for bots GetOption(\"animation_search_bot_username\"), GetOption(\"photo_search_bot_username\") and GetOption(\"venue_search_bot_username\")"]
+    pub fn with_hide_via_bot(mut self, value: impl Into<bool>) -> Self {
+        self.hide_via_bot = value.into();
+        self
+    }
+}
+impl Validate for SendInlineQueryResultMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.options
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "options"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Edits the message reply markup; for bots only. Returns the edited message after the edit is completed on the server side"]
+pub struct EditMessageReplyMarkup {
+    #[doc = "The chat the message belongs to"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message"]
+    pub message_id: MessageId,
+    #[doc = "The new message reply markup"]
+    pub reply_markup: ReplyMarkup,
+}
+impl Method for EditMessageReplyMarkup {
+    const TYPE: &'static str = "editMessageReplyMarkup";
+    type Response = Message;
+    const BOTS_ONLY: bool = true;
+}
+impl EditMessageReplyMarkup {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        chat_id: impl Into<ChatId>,
+        message_id: impl Into<MessageId>,
+        reply_markup: impl Into<ReplyMarkup>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            reply_markup: reply_markup.into(),
+        }
+    }
+}
+impl Validate for EditMessageReplyMarkup {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.reply_markup
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Edits the text of an inline text or game message sent via a bot; for bots only"]
+pub struct EditInlineMessageText {
+    #[doc = "Inline message identifier"]
+    pub inline_message_id: String,
+    #[doc = "The new message reply markup"]
+    pub reply_markup: ReplyMarkup,
+    #[doc = "New text content of the message. Should be of type InputMessageText"]
+    pub input_message_content: InputMessageContent,
+}
+impl Method for EditInlineMessageText {
+    const TYPE: &'static str = "editInlineMessageText";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl EditInlineMessageText {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        reply_markup: impl Into<ReplyMarkup>,
+        input_message_content: impl Into<InputMessageContent>,
+    ) -> Self {
+        Self {
+            inline_message_id: Default::default(),
+            reply_markup: reply_markup.into(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Inline message identifier"]
+    pub fn with_inline_message_id(mut self, value: impl Into<String>) -> Self {
+        self.inline_message_id = value.into();
+        self
+    }
+}
+impl Validate for EditInlineMessageText {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.reply_markup
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Edits the content of a live location in an inline message sent via a bot; for bots only"]
+pub struct EditInlineMessageLiveLocation {
+    #[doc = "Inline message identifier"]
+    pub inline_message_id: String,
+    #[doc = "The new message reply markup"]
+    pub reply_markup: ReplyMarkup,
+    #[serde(default)]
+    #[doc = "New location content of the message; may be null. Pass null to stop sharing the live location"]
+    pub location: Option<Location>,
+}
+impl Method for EditInlineMessageLiveLocation {
+    const TYPE: &'static str = "editInlineMessageLiveLocation";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl EditInlineMessageLiveLocation {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(reply_markup: impl Into<ReplyMarkup>) -> Self {
+        Self {
+            inline_message_id: Default::default(),
+            reply_markup: reply_markup.into(),
+            location: Default::default(),
+        }
+    }
+    #[doc = "Inline message identifier"]
+    pub fn with_inline_message_id(mut self, value: impl Into<String>) -> Self {
+        self.inline_message_id = value.into();
+        self
+    }
+    #[doc = "New location content of the message; may be null. Pass null to stop sharing the live location"]
+    pub fn with_location(mut self, value: impl Into<Location>) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+}
+impl Validate for EditInlineMessageLiveLocation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.reply_markup
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        if let Some(value) = &self.location {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "location"))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Edits the content of a message with an animation, an audio, a document, a photo or a video in an inline message sent via a bot; for bots only"]
+pub struct EditInlineMessageMedia {
+    #[doc = "Inline message identifier"]
+    pub inline_message_id: String,
+    #[serde(default)]
+    #[doc = "The new message reply markup; for bots only"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "New content of the message. Must be one of the following types: InputMessageAnimation, InputMessageAudio, InputMessageDocument, InputMessagePhoto or InputMessageVideo"]
+    pub input_message_content: InputMessageContent,
+}
+impl Method for EditInlineMessageMedia {
+    const TYPE: &'static str = "editInlineMessageMedia";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl EditInlineMessageMedia {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_content: impl Into<InputMessageContent>) -> Self {
+        Self {
+            inline_message_id: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Inline message identifier"]
+    pub fn with_inline_message_id(mut self, value: impl Into<String>) -> Self {
+        self.inline_message_id = value.into();
+        self
+    }
+    #[doc = "The new message reply markup; for bots only"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+impl Validate for EditInlineMessageMedia {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Edits the caption of an inline message sent via a bot; for bots only"]
+pub struct EditInlineMessageCaption {
+    #[doc = "Inline message identifier"]
+    pub inline_message_id: String,
+    #[doc = "The new message reply markup"]
+    pub reply_markup: ReplyMarkup,
+    #[doc = "New message content caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub caption: FormattedText,
+}
+impl Method for EditInlineMessageCaption {
+    const TYPE: &'static str = "editInlineMessageCaption";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl EditInlineMessageCaption {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(reply_markup: impl Into<ReplyMarkup>) -> Self {
+        Self {
+            inline_message_id: Default::default(),
+            reply_markup: reply_markup.into(),
+            caption: Default::default(),
+        }
+    }
+    #[doc = "Inline message identifier"]
+    pub fn with_inline_message_id(mut self, value: impl Into<String>) -> Self {
+        self.inline_message_id = value.into();
+        self
+    }
+    #[doc = "New message content caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub fn with_caption(mut self, value: impl Into<FormattedText>) -> Self {
+        self.caption = value.into();
+        self
+    }
+}
+impl Validate for EditInlineMessageCaption {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.reply_markup
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        self.caption
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "caption"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Edits the reply markup of an inline message sent via a bot; for bots only"]
+pub struct EditInlineMessageReplyMarkup {
+    #[doc = "Inline message identifier"]
+    pub inline_message_id: String,
+    #[doc = "The new message reply markup"]
+    pub reply_markup: ReplyMarkup,
+}
+impl Method for EditInlineMessageReplyMarkup {
+    const TYPE: &'static str = "editInlineMessageReplyMarkup";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl EditInlineMessageReplyMarkup {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(reply_markup: impl Into<ReplyMarkup>) -> Self {
+        Self {
+            inline_message_id: Default::default(),
+            reply_markup: reply_markup.into(),
+        }
+    }
+    #[doc = "Inline message identifier"]
+    pub fn with_inline_message_id(mut self, value: impl Into<String>) -> Self {
+        self.inline_message_id = value.into();
+        self
+    }
+}
+impl Validate for EditInlineMessageReplyMarkup {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.reply_markup
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a button of type inlineKeyboardButtonTypeLoginUrl. The method needs to be called when the user presses the button"]
+pub struct GetLoginUrlInfo {
+    #[doc = "Chat identifier of the message with the button"]
+    pub chat_id: ChatId,
+    #[doc = "Message identifier of the message with the button"]
+    pub message_id: MessageId,
+    #[doc = "Button identifier"]
+    pub button_id: i32,
+}
+impl Method for GetLoginUrlInfo {
+    const TYPE: &'static str = "getLoginUrlInfo";
+    type Response = LoginUrlInfo;
+}
+impl GetLoginUrlInfo {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            button_id: Default::default(),
+        }
+    }
+    #[doc = "Button identifier"]
+    pub fn with_button_id(mut self, value: impl Into<i32>) -> Self {
+        self.button_id = value.into();
+        self
+    }
+}
+impl Validate for GetLoginUrlInfo {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns an HTTP URL which can be used to automatically authorize the user on a website after clicking an inline button of type inlineKeyboardButtonTypeLoginUrl. Use the method getLoginUrlInfo to find whether a prior user confirmation is needed. If an error is returned, then the button must be handled as an ordinary URL button"]
+pub struct GetLoginUrl {
+    #[doc = "Chat identifier of the message with the button"]
+    pub chat_id: ChatId,
+    #[doc = "Message identifier of the message with the button"]
+    pub message_id: MessageId,
+    #[doc = "Button identifier"]
+    pub button_id: i32,
+    #[doc = "True, if the user allowed the bot to send them messages"]
+    pub allow_write_access: bool,
+}
+impl Method for GetLoginUrl {
+    const TYPE: &'static str = "getLoginUrl";
+    type Response = HttpUrl;
+}
+impl GetLoginUrl {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            button_id: Default::default(),
+            allow_write_access: Default::default(),
+        }
+    }
+    #[doc = "Button identifier"]
+    pub fn with_button_id(mut self, value: impl Into<i32>) -> Self {
+        self.button_id = value.into();
+        self
+    }
+    #[doc = "True, if the user allowed the bot to send them messages"]
+    pub fn with_allow_write_access(mut self, value: impl Into<bool>) -> Self {
+        self.allow_write_access = value.into();
+        self
+    }
+}
+impl Validate for GetLoginUrl {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sends an inline query to a bot and returns its results. Returns an error with code 502 if the bot fails to answer the query before the query timeout expires"]
+pub struct GetInlineQueryResults {
+    #[doc = "The identifier of the target bot"]
+    pub bot_user_id: UserId,
+    #[doc = "Identifier of the chat where the query was sent"]
+    pub chat_id: ChatId,
+    #[doc = "Location of the user, only if needed"]
+    pub user_location: Location,
+    #[doc = "Text of the query"]
+    pub query: String,
+    #[doc = "Offset of the first entry to return"]
+    pub offset: String,
+}
+impl Method for GetInlineQueryResults {
+    const TYPE: &'static str = "getInlineQueryResults";
+    type Response = InlineQueryResults;
+}
+impl GetInlineQueryResults {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(bot_user_id: impl Into<UserId>, chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            bot_user_id: bot_user_id.into(),
+            chat_id: chat_id.into(),
+            user_location: Default::default(),
+            query: Default::default(),
+            offset: Default::default(),
+        }
+    }
+    #[doc = "Location of the user, only if needed"]
+    pub fn with_user_location(mut self, value: impl Into<Location>) -> Self {
+        self.user_location = value.into();
+        self
+    }
+    #[doc = "Text of the query"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+    #[doc = "Offset of the first entry to return"]
+    pub fn with_offset(mut self, value: impl Into<String>) -> Self {
+        self.offset = value.into();
+        self
+    }
+}
+impl Validate for GetInlineQueryResults {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.user_location
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "user_location"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Sets the result of an inline query; for bots only"]
+pub struct AnswerInlineQuery {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of the inline query"]
+    pub inline_query_id: i64,
+    #[doc = "True, if the result of the query can be cached for the specified user"]
+    pub is_personal: bool,
+    #[doc = "The results of the query"]
+    pub results: Vec<InputInlineQueryResult>,
+    #[doc = "Allowed time to cache the results of the query, in seconds"]
+    pub cache_time: i32,
+    #[doc = "Offset for the next inline query; pass an empty string if there are no more results"]
+    pub next_offset: String,
+    #[doc = "If non-empty, this text should be shown on the button that opens a private chat with the bot and sends a start message to the bot with the parameter switch_pm_parameter"]
+    pub switch_pm_text: String,
+    #[doc = "The parameter for the bot start message"]
+    pub switch_pm_parameter: String,
+}
+impl Method for AnswerInlineQuery {
+    const TYPE: &'static str = "answerInlineQuery";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl AnswerInlineQuery {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Identifier of the inline query"]
+    pub fn with_inline_query_id(mut self, value: impl Into<i64>) -> Self {
+        self.inline_query_id = value.into();
+        self
+    }
+    #[doc = "True, if the result of the query can be cached for the specified user"]
+    pub fn with_is_personal(mut self, value: impl Into<bool>) -> Self {
+        self.is_personal = value.into();
+        self
+    }
+    #[doc = "The results of the query"]
+    pub fn with_results(mut self, value: impl Into<Vec<InputInlineQueryResult>>) -> Self {
+        self.results = value.into();
+        self
+    }
+    #[doc = "Allowed time to cache the results of the query, in seconds"]
+    pub fn with_cache_time(mut self, value: impl Into<i32>) -> Self {
+        self.cache_time = value.into();
+        self
+    }
+    #[doc = "Offset for the next inline query; pass an empty string if there are no more results"]
+    pub fn with_next_offset(mut self, value: impl Into<String>) -> Self {
+        self.next_offset = value.into();
+        self
+    }
+    #[doc = "If non-empty, this text should be shown on the button that opens a private chat with the bot and sends a start message to the bot with the parameter switch_pm_parameter"]
+    pub fn with_switch_pm_text(mut self, value: impl Into<String>) -> Self {
+        self.switch_pm_text = value.into();
+        self
+    }
+    #[doc = "The parameter for the bot start message"]
+    pub fn with_switch_pm_parameter(mut self, value: impl Into<String>) -> Self {
+        self.switch_pm_parameter = value.into();
+        self
+    }
+}
+impl Validate for AnswerInlineQuery {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, value) in self.results.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("results[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sends a callback query to a bot and returns an answer. Returns an error with code 502 if the bot fails to answer the query before the query timeout expires"]
+pub struct GetCallbackQueryAnswer {
+    #[doc = "Identifier of the chat with the message"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message from which the query originated"]
+    pub message_id: MessageId,
+    #[doc = "Query payload"]
+    pub payload: CallbackQueryPayload,
+}
+impl Method for GetCallbackQueryAnswer {
+    const TYPE: &'static str = "getCallbackQueryAnswer";
+    type Response = CallbackQueryAnswer;
+}
+impl GetCallbackQueryAnswer {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        chat_id: impl Into<ChatId>,
+        message_id: impl Into<MessageId>,
+        payload: impl Into<CallbackQueryPayload>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            payload: payload.into(),
+        }
+    }
+}
+impl Validate for GetCallbackQueryAnswer {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.payload
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "payload"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Sets the result of a callback query; for bots only"]
+pub struct AnswerCallbackQuery {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of the callback query"]
+    pub callback_query_id: i64,
+    #[doc = "Text of the answer"]
+    pub text: String,
+    #[doc = "If true, an alert should be shown to the user instead of a toast notification"]
+    pub show_alert: bool,
+    #[doc = "URL to be opened"]
+    pub url: String,
+    #[doc = "Time during which the result of the query can be cached, in seconds"]
+    pub cache_time: i32,
+}
+impl Method for AnswerCallbackQuery {
+    const TYPE: &'static str = "answerCallbackQuery";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl AnswerCallbackQuery {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Identifier of the callback query"]
+    pub fn with_callback_query_id(mut self, value: impl Into<i64>) -> Self {
+        self.callback_query_id = value.into();
+        self
+    }
+    #[doc = "Text of the answer"]
+    pub fn with_text(mut self, value: impl Into<String>) -> Self {
+        self.text = value.into();
+        self
+    }
+    #[doc = "If true, an alert should be shown to the user instead of a toast notification"]
+    pub fn with_show_alert(mut self, value: impl Into<bool>) -> Self {
+        self.show_alert = value.into();
+        self
+    }
+    #[doc = "URL to be opened"]
+    pub fn with_url(mut self, value: impl Into<String>) -> Self {
+        self.url = value.into();
+        self
+    }
+    #[doc = "Time during which the result of the query can be cached, in seconds"]
+    pub fn with_cache_time(mut self, value: impl Into<i32>) -> Self {
+        self.cache_time = value.into();
+        self
+    }
+}
+impl Validate for AnswerCallbackQuery {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Updates the game score of the specified user in the game; for bots only"]
+pub struct SetGameScore {
+    #[doc = "The chat to which the message with the game belongs"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message"]
+    pub message_id: MessageId,
+    #[doc = "True, if the message should be edited"]
+    pub edit_message: bool,
+    #[doc = "User identifier"]
+    pub user_id: UserId,
+    #[doc = "The new score"]
+    pub score: i32,
+    #[doc = "Pass true to update the score even if it decreases. If the score is 0, the user will be deleted from the high score table"]
+    pub force: bool,
+}
+impl Method for SetGameScore {
+    const TYPE: &'static str = "setGameScore";
+    type Response = Message;
+    const BOTS_ONLY: bool = true;
+}
+impl SetGameScore {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        chat_id: impl Into<ChatId>,
+        message_id: impl Into<MessageId>,
+        user_id: impl Into<UserId>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            edit_message: Default::default(),
+            user_id: user_id.into(),
+            score: Default::default(),
+            force: Default::default(),
+        }
+    }
+    #[doc = "True, if the message should be edited"]
+    pub fn with_edit_message(mut self, value: impl Into<bool>) -> Self {
+        self.edit_message = value.into();
+        self
+    }
+    #[doc = "The new score"]
+    pub fn with_score(mut self, value: impl Into<i32>) -> Self {
+        self.score = value.into();
+        self
+    }
+    #[doc = "Pass true to update the score even if it decreases. If the score is 0, the user will be deleted from the high score table"]
+    pub fn with_force(mut self, value: impl Into<bool>) -> Self {
+        self.force = value.into();
+        self
+    }
+}
+impl Validate for SetGameScore {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Updates the game score of the specified user in a game; for bots only"]
+pub struct SetInlineGameScore {
+    #[doc = "Inline message identifier"]
+    pub inline_message_id: String,
+    #[doc = "True, if the message should be edited"]
+    pub edit_message: bool,
+    #[doc = "User identifier"]
+    pub user_id: UserId,
+    #[doc = "The new score"]
+    pub score: i32,
+    #[doc = "Pass true to update the score even if it decreases. If the score is 0, the user will be deleted from the high score table"]
+    pub force: bool,
+}
+impl Method for SetInlineGameScore {
+    const TYPE: &'static str = "setInlineGameScore";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl SetInlineGameScore {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(user_id: impl Into<UserId>) -> Self {
+        Self {
+            inline_message_id: Default::default(),
+            edit_message: Default::default(),
+            user_id: user_id.into(),
+            score: Default::default(),
+            force: Default::default(),
+        }
+    }
+    #[doc = "Inline message identifier"]
+    pub fn with_inline_message_id(mut self, value: impl Into<String>) -> Self {
+        self.inline_message_id = value.into();
+        self
+    }
+    #[doc = "True, if the message should be edited"]
+    pub fn with_edit_message(mut self, value: impl Into<bool>) -> Self {
+        self.edit_message = value.into();
+        self
+    }
+    #[doc = "The new score"]
+    pub fn with_score(mut self, value: impl Into<i32>) -> Self {
+        self.score = value.into();
+        self
+    }
+    #[doc = "Pass true to update the score even if it decreases. If the score is 0, the user will be deleted from the high score table"]
+    pub fn with_force(mut self, value: impl Into<bool>) -> Self {
+        self.force = value.into();
+        self
+    }
+}
+impl Validate for SetInlineGameScore {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns the high scores for a game and some part of the high score table in the range of the specified user; for bots only"]
+pub struct GetGameHighScores {
+    #[doc = "The chat that contains the message with the game"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message"]
+    pub message_id: MessageId,
+    #[doc = "User identifier"]
+    pub user_id: UserId,
+}
+impl Method for GetGameHighScores {
+    const TYPE: &'static str = "getGameHighScores";
+    type Response = GameHighScores;
+    const BOTS_ONLY: bool = true;
+}
+impl GetGameHighScores {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        chat_id: impl Into<ChatId>,
+        message_id: impl Into<MessageId>,
+        user_id: impl Into<UserId>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            user_id: user_id.into(),
+        }
+    }
+}
+impl Validate for GetGameHighScores {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns game high scores and some part of the high score table in the range of the specified user; for bots only"]
+pub struct GetInlineGameHighScores {
+    #[doc = "Inline message identifier"]
+    pub inline_message_id: String,
+    #[doc = "User identifier"]
+    pub user_id: UserId,
+}
+impl Method for GetInlineGameHighScores {
+    const TYPE: &'static str = "getInlineGameHighScores";
+    type Response = GameHighScores;
+    const BOTS_ONLY: bool = true;
+}
+impl GetInlineGameHighScores {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(user_id: impl Into<UserId>) -> Self {
+        Self {
+            inline_message_id: Default::default(),
+            user_id: user_id.into(),
+        }
+    }
+    #[doc = "Inline message identifier"]
+    pub fn with_inline_message_id(mut self, value: impl Into<String>) -> Self {
+        self.inline_message_id = value.into();
+        self
+    }
+}
+impl Validate for GetInlineGameHighScores {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Deletes the default reply markup from a chat. Must be called after a one-time keyboard or a ForceReply reply markup has been used. UpdateChatReplyMarkup will be sent if the reply markup will be changed"]
+pub struct DeleteChatReplyMarkup {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "The message identifier of the used keyboard"]
+    pub message_id: MessageId,
+}
+impl Method for DeleteChatReplyMarkup {
+    const TYPE: &'static str = "deleteChatReplyMarkup";
+    type Response = Ok;
+}
+impl DeleteChatReplyMarkup {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+        }
+    }
+}
+impl Validate for DeleteChatReplyMarkup {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns up to 20 recently used inline bots in the order of their last usage"]
+pub struct GetRecentInlineBots {}
+impl Method for GetRecentInlineBots {
+    const TYPE: &'static str = "getRecentInlineBots";
+    type Response = Users;
+}
+impl GetRecentInlineBots {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetRecentInlineBots {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Informs the server about the number of pending bot updates if they haven't been processed for a long time; for bots only"]
+pub struct SetBotUpdatesStatus {
+    #[doc = "The number of pending updates"]
+    pub pending_update_count: i32,
+    #[doc = "The last error message"]
+    pub error_message: String,
+}
+impl Method for SetBotUpdatesStatus {
+    const TYPE: &'static str = "setBotUpdatesStatus";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl SetBotUpdatesStatus {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The number of pending updates"]
+    pub fn with_pending_update_count(mut self, value: impl Into<i32>) -> Self {
+        self.pending_update_count = value.into();
+        self
+    }
+    #[doc = "The last error message"]
+    pub fn with_error_message(mut self, value: impl Into<String>) -> Self {
+        self.error_message = value.into();
+        self
+    }
+}
+impl Validate for SetBotUpdatesStatus {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Sends a custom request; for bots only"]
+pub struct SendCustomRequest {
+    #[doc = "The method name"]
+    pub method: String,
+    #[doc = "JSON-serialized method parameters"]
+    pub parameters: String,
+}
+impl Method for SendCustomRequest {
+    const TYPE: &'static str = "sendCustomRequest";
+    type Response = CustomRequestResult;
+    const BOTS_ONLY: bool = true;
+}
+impl SendCustomRequest {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The method name"]
+    pub fn with_method(mut self, value: impl Into<String>) -> Self {
+        self.method = value.into();
+        self
+    }
+    #[doc = "JSON-serialized method parameters"]
+    pub fn with_parameters(mut self, value: impl Into<String>) -> Self {
+        self.parameters = value.into();
+        self
+    }
+}
+impl Validate for SendCustomRequest {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Answers a custom query; for bots only"]
+pub struct AnswerCustomQuery {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of a custom query"]
+    pub custom_query_id: i64,
+    #[doc = "JSON-serialized answer to the query"]
+    pub data: String,
+}
+impl Method for AnswerCustomQuery {
+    const TYPE: &'static str = "answerCustomQuery";
+    type Response = Ok;
+    const BOTS_ONLY: bool = true;
+}
+impl AnswerCustomQuery {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Identifier of a custom query"]
+    pub fn with_custom_query_id(mut self, value: impl Into<i64>) -> Self {
+        self.custom_query_id = value.into();
+        self
+    }
+    #[doc = "JSON-serialized answer to the query"]
+    pub fn with_data(mut self, value: impl Into<String>) -> Self {
+        self.data = value.into();
+        self
+    }
+}
+impl Validate for AnswerCustomQuery {}
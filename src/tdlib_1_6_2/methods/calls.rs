@@ -0,0 +1,257 @@
+//! The methods of the `calls` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Searches for call messages. Returns the results in reverse chronological order (i. e., in order of decreasing message_id). For optimal performance the number of returned messages is chosen by the library"]
+pub struct SearchCallMessages {
+    #[doc = "Identifier of the message from which to search; use 0 to get results from the last message"]
+    pub from_message_id: MessageId,
+    #[doc = "The maximum number of messages to be returned; up to 100. Fewer messages may be returned than specified by the limit, even if the end of the message history has not been reached"]
+    pub limit: i32,
+    #[doc = "If true, returns only messages with missed calls"]
+    pub only_missed: bool,
+}
+impl Method for SearchCallMessages {
+    const TYPE: &'static str = "searchCallMessages";
+    type Response = Messages;
+}
+impl SearchCallMessages {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Identifier of the message from which to search; use 0 to get results from the last message"]
+    pub fn with_from_message_id(mut self, value: impl Into<MessageId>) -> Self {
+        self.from_message_id = value.into();
+        self
+    }
+    #[doc = "The maximum number of messages to be returned; up to 100. Fewer messages may be returned than specified by the limit, even if the end of the message history has not been reached"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+    #[doc = "If true, returns only messages with missed calls"]
+    pub fn with_only_missed(mut self, value: impl Into<bool>) -> Self {
+        self.only_missed = value.into();
+        self
+    }
+}
+impl Validate for SearchCallMessages {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "limit",
+            Measure::Value,
+            i64::from(self.limit),
+            None,
+            Some(100),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Creates a new call"]
+pub struct CreateCall {
+    #[doc = "Identifier of the user to be called"]
+    pub user_id: UserId,
+    #[doc = "Description of the call protocols supported by the client"]
+    pub protocol: CallProtocol,
+}
+impl Method for CreateCall {
+    const TYPE: &'static str = "createCall";
+    type Response = CallId;
+}
+impl CreateCall {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(user_id: impl Into<UserId>) -> Self {
+        Self {
+            user_id: user_id.into(),
+            protocol: Default::default(),
+        }
+    }
+    #[doc = "Description of the call protocols supported by the client"]
+    pub fn with_protocol(mut self, value: impl Into<CallProtocol>) -> Self {
+        self.protocol = value.into();
+        self
+    }
+}
+impl Validate for CreateCall {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.protocol
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "protocol"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Accepts an incoming call"]
+pub struct AcceptCall {
+    #[doc = "Call identifier"]
+    pub call_id: i32,
+    #[doc = "Description of the call protocols supported by the client"]
+    pub protocol: CallProtocol,
+}
+impl Method for AcceptCall {
+    const TYPE: &'static str = "acceptCall";
+    type Response = Ok;
+}
+impl AcceptCall {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Call identifier"]
+    pub fn with_call_id(mut self, value: impl Into<i32>) -> Self {
+        self.call_id = value.into();
+        self
+    }
+    #[doc = "Description of the call protocols supported by the client"]
+    pub fn with_protocol(mut self, value: impl Into<CallProtocol>) -> Self {
+        self.protocol = value.into();
+        self
+    }
+}
+impl Validate for AcceptCall {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.protocol
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "protocol"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Discards a call"]
+pub struct DiscardCall {
+    #[doc = "Call identifier"]
+    pub call_id: i32,
+    #[doc = "True, if the user was disconnected"]
+    pub is_disconnected: bool,
+    #[doc = "The call duration, in seconds"]
+    pub duration: i32,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of the connection used during the call"]
+    pub connection_id: i64,
+}
+impl Method for DiscardCall {
+    const TYPE: &'static str = "discardCall";
+    type Response = Ok;
+}
+impl DiscardCall {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Call identifier"]
+    pub fn with_call_id(mut self, value: impl Into<i32>) -> Self {
+        self.call_id = value.into();
+        self
+    }
+    #[doc = "True, if the user was disconnected"]
+    pub fn with_is_disconnected(mut self, value: impl Into<bool>) -> Self {
+        self.is_disconnected = value.into();
+        self
+    }
+    #[doc = "The call duration, in seconds"]
+    pub fn with_duration(mut self, value: impl Into<i32>) -> Self {
+        self.duration = value.into();
+        self
+    }
+    #[doc = "Identifier of the connection used during the call"]
+    pub fn with_connection_id(mut self, value: impl Into<i64>) -> Self {
+        self.connection_id = value.into();
+        self
+    }
+}
+impl Validate for DiscardCall {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sends a call rating"]
+pub struct SendCallRating {
+    #[doc = "Call identifier"]
+    pub call_id: i32,
+    #[doc = "Call rating; 1-5"]
+    pub rating: i32,
+    #[doc = "An optional user comment if the rating is less than 5"]
+    pub comment: String,
+    #[doc = "List of the exact types of problems with the call, specified by the user"]
+    pub problems: Vec<CallProblem>,
+}
+impl Method for SendCallRating {
+    const TYPE: &'static str = "sendCallRating";
+    type Response = Ok;
+}
+impl SendCallRating {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(rating: impl Into<i32>) -> Self {
+        Self {
+            call_id: Default::default(),
+            rating: rating.into(),
+            comment: Default::default(),
+            problems: Default::default(),
+        }
+    }
+    #[doc = "Call identifier"]
+    pub fn with_call_id(mut self, value: impl Into<i32>) -> Self {
+        self.call_id = value.into();
+        self
+    }
+    #[doc = "An optional user comment if the rating is less than 5"]
+    pub fn with_comment(mut self, value: impl Into<String>) -> Self {
+        self.comment = value.into();
+        self
+    }
+    #[doc = "List of the exact types of problems with the call, specified by the user"]
+    pub fn with_problems(mut self, value: impl Into<Vec<CallProblem>>) -> Self {
+        self.problems = value.into();
+        self
+    }
+}
+impl Validate for SendCallRating {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "rating",
+            Measure::Value,
+            i64::from(self.rating),
+            Some(1),
+            Some(5),
+        )?;
+        for (i, value) in self.problems.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("problems[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Sends debug information for a call"]
+pub struct SendCallDebugInformation {
+    #[doc = "Call identifier"]
+    pub call_id: i32,
+    #[doc = "Debug information in application-specific format"]
+    pub debug_information: String,
+}
+impl Method for SendCallDebugInformation {
+    const TYPE: &'static str = "sendCallDebugInformation";
+    type Response = Ok;
+}
+impl SendCallDebugInformation {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Call identifier"]
+    pub fn with_call_id(mut self, value: impl Into<i32>) -> Self {
+        self.call_id = value.into();
+        self
+    }
+    #[doc = "Debug information in application-specific format"]
+    pub fn with_debug_information(mut self, value: impl Into<String>) -> Self {
+        self.debug_information = value.into();
+        self
+    }
+}
+impl Validate for SendCallDebugInformation {}
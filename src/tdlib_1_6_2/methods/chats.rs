@@ -0,0 +1,2145 @@
+//! The methods of the `chats` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a basic group by its identifier. This is an offline request if the current user is not a bot"]
+pub struct GetBasicGroup {
+    #[doc = "Basic group identifier"]
+    pub basic_group_id: BasicGroupId,
+}
+impl Method for GetBasicGroup {
+    const TYPE: &'static str = "getBasicGroup";
+    type Response = BasicGroup;
+}
+impl GetBasicGroup {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(basic_group_id: impl Into<BasicGroupId>) -> Self {
+        Self {
+            basic_group_id: basic_group_id.into(),
+        }
+    }
+}
+impl Validate for GetBasicGroup {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns full information about a basic group by its identifier"]
+pub struct GetBasicGroupFullInfo {
+    #[doc = "Basic group identifier"]
+    pub basic_group_id: BasicGroupId,
+}
+impl Method for GetBasicGroupFullInfo {
+    const TYPE: &'static str = "getBasicGroupFullInfo";
+    type Response = BasicGroupFullInfo;
+}
+impl GetBasicGroupFullInfo {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(basic_group_id: impl Into<BasicGroupId>) -> Self {
+        Self {
+            basic_group_id: basic_group_id.into(),
+        }
+    }
+}
+impl Validate for GetBasicGroupFullInfo {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a supergroup or a channel by its identifier. This is an offline request if the current user is not a bot"]
+pub struct GetSupergroup {
+    #[doc = "Supergroup or channel identifier"]
+    pub supergroup_id: SupergroupId,
+}
+impl Method for GetSupergroup {
+    const TYPE: &'static str = "getSupergroup";
+    type Response = Supergroup;
+}
+impl GetSupergroup {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(supergroup_id: impl Into<SupergroupId>) -> Self {
+        Self {
+            supergroup_id: supergroup_id.into(),
+        }
+    }
+}
+impl Validate for GetSupergroup {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns full information about a supergroup or a channel by its identifier, cached for up to 1 minute"]
+pub struct GetSupergroupFullInfo {
+    #[doc = "Supergroup or channel identifier"]
+    pub supergroup_id: SupergroupId,
+}
+impl Method for GetSupergroupFullInfo {
+    const TYPE: &'static str = "getSupergroupFullInfo";
+    type Response = SupergroupFullInfo;
+}
+impl GetSupergroupFullInfo {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(supergroup_id: impl Into<SupergroupId>) -> Self {
+        Self {
+            supergroup_id: supergroup_id.into(),
+        }
+    }
+}
+impl Validate for GetSupergroupFullInfo {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a secret chat by its identifier. This is an offline request"]
+pub struct GetSecretChat {
+    #[doc = "Secret chat identifier"]
+    pub secret_chat_id: SecretChatId,
+}
+impl Method for GetSecretChat {
+    const TYPE: &'static str = "getSecretChat";
+    type Response = SecretChat;
+    const OFFLINE: bool = true;
+}
+impl GetSecretChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(secret_chat_id: impl Into<SecretChatId>) -> Self {
+        Self {
+            secret_chat_id: secret_chat_id.into(),
+        }
+    }
+}
+impl Validate for GetSecretChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a chat by its identifier, this is an offline request if the current user is not a bot"]
+pub struct GetChat {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+}
+impl Method for GetChat {
+    const TYPE: &'static str = "getChat";
+    type Response = Chat;
+}
+impl GetChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for GetChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns an ordered list of chats in a chat list. Chats are sorted by the pair (order, chat_id) in decreasing order. (For example, to get a list of chats from the beginning, the offset_order should be equal to a biggest signed 64-bit number 9223372036854775807 == 2^63 - 1). For optimal performance the number of returned chats is chosen by the library"]
+pub struct GetChats {
+    #[doc = "The chat list in which to return chats"]
+    pub chat_list: ChatList,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Chat order to return chats from"]
+    pub offset_order: i64,
+    #[doc = "Chat identifier to return chats from"]
+    pub offset_chat_id: ChatId,
+    #[doc = "The maximum number of chats to be returned. It is possible that fewer chats than the limit are returned even if the end of the list is not reached"]
+    pub limit: i32,
+}
+impl Method for GetChats {
+    const TYPE: &'static str = "getChats";
+    type Response = Chats;
+}
+impl GetChats {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_list: impl Into<ChatList>, offset_chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_list: chat_list.into(),
+            offset_order: Default::default(),
+            offset_chat_id: offset_chat_id.into(),
+            limit: Default::default(),
+        }
+    }
+    #[doc = "Chat order to return chats from"]
+    pub fn with_offset_order(mut self, value: impl Into<i64>) -> Self {
+        self.offset_order = value.into();
+        self
+    }
+    #[doc = "The maximum number of chats to be returned. It is possible that fewer chats than the limit are returned even if the end of the list is not reached"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for GetChats {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.chat_list
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "chat_list"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Searches a public chat by its username. Currently only private chats, supergroups and channels can be public. Returns the chat if found; otherwise an error is returned"]
+pub struct SearchPublicChat {
+    #[doc = "Username to be resolved"]
+    pub username: String,
+}
+impl Method for SearchPublicChat {
+    const TYPE: &'static str = "searchPublicChat";
+    type Response = Chat;
+}
+impl SearchPublicChat {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Username to be resolved"]
+    pub fn with_username(mut self, value: impl Into<String>) -> Self {
+        self.username = value.into();
+        self
+    }
+}
+impl Validate for SearchPublicChat {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Searches public chats by looking for specified query in their username and title. Currently only private chats, supergroups and channels can be public. Returns a meaningful number of results. Returns nothing if the length of the searched username prefix is less than 5. Excludes private chats with contacts and chats from the chat list from the results"]
+pub struct SearchPublicChats {
+    #[doc = "Query to search for"]
+    pub query: String,
+}
+impl Method for SearchPublicChats {
+    const TYPE: &'static str = "searchPublicChats";
+    type Response = Chats;
+}
+impl SearchPublicChats {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Query to search for"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+}
+impl Validate for SearchPublicChats {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Searches for the specified query in the title and username of already known chats, this is an offline request. Returns chats in the order seen in the chat list"]
+pub struct SearchChats {
+    #[doc = "Query to search for. If the query is empty, returns up to 20 recently found chats"]
+    pub query: String,
+    #[doc = "The maximum number of chats to be returned"]
+    pub limit: i32,
+}
+impl Method for SearchChats {
+    const TYPE: &'static str = "searchChats";
+    type Response = Chats;
+    const OFFLINE: bool = true;
+}
+impl SearchChats {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Query to search for. If the query is empty, returns up to 20 recently found chats"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+    #[doc = "The maximum number of chats to be returned"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for SearchChats {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Searches for the specified query in the title and username of already known chats via request to the server. Returns chats in the order seen in the chat list"]
+pub struct SearchChatsOnServer {
+    #[doc = "Query to search for"]
+    pub query: String,
+    #[doc = "The maximum number of chats to be returned"]
+    pub limit: i32,
+}
+impl Method for SearchChatsOnServer {
+    const TYPE: &'static str = "searchChatsOnServer";
+    type Response = Chats;
+}
+impl SearchChatsOnServer {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Query to search for"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+    #[doc = "The maximum number of chats to be returned"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for SearchChatsOnServer {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns a list of users and location-based supergroups nearby. The list of users nearby will be updated for 60 seconds after the request by the updates updateUsersNearby. The request should be sent again every 25 seconds with adjusted location to not miss new chats"]
+pub struct SearchChatsNearby {
+    #[doc = "Current user location"]
+    pub location: Location,
+}
+impl Method for SearchChatsNearby {
+    const TYPE: &'static str = "searchChatsNearby";
+    type Response = ChatsNearby;
+}
+impl SearchChatsNearby {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Current user location"]
+    pub fn with_location(mut self, value: impl Into<Location>) -> Self {
+        self.location = value.into();
+        self
+    }
+}
+impl Validate for SearchChatsNearby {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.location
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "location"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns a list of frequently used chats. Supported only if the chat info database is enabled"]
+pub struct GetTopChats {
+    #[doc = "Category of chats to be returned"]
+    pub category: TopChatCategory,
+    #[doc = "The maximum number of chats to be returned; up to 30"]
+    pub limit: i32,
+}
+impl Method for GetTopChats {
+    const TYPE: &'static str = "getTopChats";
+    type Response = Chats;
+}
+impl GetTopChats {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(category: impl Into<TopChatCategory>) -> Self {
+        Self {
+            category: category.into(),
+            limit: Default::default(),
+        }
+    }
+    #[doc = "The maximum number of chats to be returned; up to 30"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for GetTopChats {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.category
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "category"))?;
+        validate::check(
+            Self::TYPE,
+            "limit",
+            Measure::Value,
+            i64::from(self.limit),
+            None,
+            Some(30),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Removes a chat from the list of frequently used chats. Supported only if the chat info database is enabled"]
+pub struct RemoveTopChat {
+    #[doc = "Category of frequently used chats"]
+    pub category: TopChatCategory,
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+}
+impl Method for RemoveTopChat {
+    const TYPE: &'static str = "removeTopChat";
+    type Response = Ok;
+}
+impl RemoveTopChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(category: impl Into<TopChatCategory>, chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            category: category.into(),
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for RemoveTopChat {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.category
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "category"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Adds a chat to the list of recently found chats. The chat is added to the beginning of the list. If the chat is already in the list, it will be removed from the list first"]
+pub struct AddRecentlyFoundChat {
+    #[doc = "Identifier of the chat to add"]
+    pub chat_id: ChatId,
+}
+impl Method for AddRecentlyFoundChat {
+    const TYPE: &'static str = "addRecentlyFoundChat";
+    type Response = Ok;
+}
+impl AddRecentlyFoundChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for AddRecentlyFoundChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Removes a chat from the list of recently found chats"]
+pub struct RemoveRecentlyFoundChat {
+    #[doc = "Identifier of the chat to be removed"]
+    pub chat_id: ChatId,
+}
+impl Method for RemoveRecentlyFoundChat {
+    const TYPE: &'static str = "removeRecentlyFoundChat";
+    type Response = Ok;
+}
+impl RemoveRecentlyFoundChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for RemoveRecentlyFoundChat {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Clears the list of recently found chats"]
+pub struct ClearRecentlyFoundChats {}
+impl Method for ClearRecentlyFoundChats {
+    const TYPE: &'static str = "clearRecentlyFoundChats";
+    type Response = Ok;
+}
+impl ClearRecentlyFoundChats {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for ClearRecentlyFoundChats {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Checks whether a username can be set for a chat"]
+pub struct CheckChatUsername {
+    #[doc = "Chat identifier; should be identifier of a supergroup chat, or a channel chat, or a private chat with self, or zero if chat is being created"]
+    pub chat_id: ChatId,
+    #[doc = "Username to be checked"]
+    pub username: String,
+}
+impl Method for CheckChatUsername {
+    const TYPE: &'static str = "checkChatUsername";
+    type Response = CheckChatUsernameResult;
+}
+impl CheckChatUsername {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Chat identifier; should be identifier of a supergroup chat, or a channel chat, or a private chat with self, or zero if chat is being created"]
+    pub fn with_chat_id(mut self, value: impl Into<ChatId>) -> Self {
+        self.chat_id = value.into();
+        self
+    }
+    #[doc = "Username to be checked"]
+    pub fn with_username(mut self, value: impl Into<String>) -> Self {
+        self.username = value.into();
+        self
+    }
+}
+impl Validate for CheckChatUsername {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns a list of public chats of the specified type, owned by the user"]
+pub struct GetCreatedPublicChats {
+    #[serde(rename = "type")]
+    #[doc = "Type of the public chats to return"]
+    pub type_: PublicChatType,
+}
+impl Method for GetCreatedPublicChats {
+    const TYPE: &'static str = "getCreatedPublicChats";
+    type Response = Chats;
+}
+impl GetCreatedPublicChats {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(type_: impl Into<PublicChatType>) -> Self {
+        Self {
+            type_: type_.into(),
+        }
+    }
+}
+impl Validate for GetCreatedPublicChats {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.type_
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "type"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Checks whether the maximum number of owned public chats has been reached. Returns corresponding error if the limit was reached"]
+pub struct CheckCreatedPublicChatsLimit {
+    #[serde(rename = "type")]
+    #[doc = "Type of the public chats, for which to check the limit"]
+    pub type_: PublicChatType,
+}
+impl Method for CheckCreatedPublicChatsLimit {
+    const TYPE: &'static str = "checkCreatedPublicChatsLimit";
+    type Response = Ok;
+}
+impl CheckCreatedPublicChatsLimit {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(type_: impl Into<PublicChatType>) -> Self {
+        Self {
+            type_: type_.into(),
+        }
+    }
+}
+impl Validate for CheckCreatedPublicChatsLimit {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.type_
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "type"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns a list of basic group and supergroup chats, which can be used as a discussion group for a channel. Basic group chats need to be first upgraded to supergroups before they can be set as a discussion group"]
+pub struct GetSuitableDiscussionChats {}
+impl Method for GetSuitableDiscussionChats {
+    const TYPE: &'static str = "getSuitableDiscussionChats";
+    type Response = Chats;
+}
+impl GetSuitableDiscussionChats {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetSuitableDiscussionChats {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns a list of recently inactive supergroups and channels. Can be used when user reaches limit on the number of joined supergroups and channels and receives CHANNELS_TOO_MUCH error"]
+pub struct GetInactiveSupergroupChats {}
+impl Method for GetInactiveSupergroupChats {
+    const TYPE: &'static str = "getInactiveSupergroupChats";
+    type Response = Chats;
+}
+impl GetInactiveSupergroupChats {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetInactiveSupergroupChats {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns a list of common group chats with a given user. Chats are sorted by their type and creation date"]
+pub struct GetGroupsInCommon {
+    #[doc = "User identifier"]
+    pub user_id: UserId,
+    #[doc = "Chat identifier starting from which to return chats; use 0 for the first request"]
+    pub offset_chat_id: ChatId,
+    #[doc = "The maximum number of chats to be returned; up to 100"]
+    pub limit: i32,
+}
+impl Method for GetGroupsInCommon {
+    const TYPE: &'static str = "getGroupsInCommon";
+    type Response = Chats;
+}
+impl GetGroupsInCommon {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(user_id: impl Into<UserId>) -> Self {
+        Self {
+            user_id: user_id.into(),
+            offset_chat_id: Default::default(),
+            limit: Default::default(),
+        }
+    }
+    #[doc = "Chat identifier starting from which to return chats; use 0 for the first request"]
+    pub fn with_offset_chat_id(mut self, value: impl Into<ChatId>) -> Self {
+        self.offset_chat_id = value.into();
+        self
+    }
+    #[doc = "The maximum number of chats to be returned; up to 100"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for GetGroupsInCommon {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "limit",
+            Measure::Value,
+            i64::from(self.limit),
+            None,
+            Some(100),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sends a notification about a screenshot taken in a chat. Supported only in private and secret chats"]
+pub struct SendChatScreenshotTakenNotification {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+}
+impl Method for SendChatScreenshotTakenNotification {
+    const TYPE: &'static str = "sendChatScreenshotTakenNotification";
+    type Response = Ok;
+}
+impl SendChatScreenshotTakenNotification {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for SendChatScreenshotTakenNotification {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sends a notification about user activity in a chat"]
+pub struct SendChatAction {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "The action description"]
+    pub action: ChatAction,
+}
+impl Method for SendChatAction {
+    const TYPE: &'static str = "sendChatAction";
+    type Response = Ok;
+}
+impl SendChatAction {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, action: impl Into<ChatAction>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            action: action.into(),
+        }
+    }
+}
+impl Validate for SendChatAction {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.action
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "action"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Informs TDLib that the chat is opened by the user. Many useful activities depend on the chat being opened or closed (e.g., in supergroups and channels all updates are received only for opened chats)"]
+pub struct OpenChat {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+}
+impl Method for OpenChat {
+    const TYPE: &'static str = "openChat";
+    type Response = Ok;
+}
+impl OpenChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for OpenChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Informs TDLib that the chat is closed by the user. Many useful activities depend on the chat being opened or closed"]
+pub struct CloseChat {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+}
+impl Method for CloseChat {
+    const TYPE: &'static str = "closeChat";
+    type Response = Ok;
+}
+impl CloseChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for CloseChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Marks all mentions in a chat as read"]
+pub struct ReadAllChatMentions {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+}
+impl Method for ReadAllChatMentions {
+    const TYPE: &'static str = "readAllChatMentions";
+    type Response = Ok;
+}
+impl ReadAllChatMentions {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for ReadAllChatMentions {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns an existing chat corresponding to a given user"]
+pub struct CreatePrivateChat {
+    #[doc = "User identifier"]
+    pub user_id: UserId,
+    #[doc = "If true, the chat will be created without network request. In this case all information about the chat except its type, title and photo can be incorrect"]
+    pub force: bool,
+}
+impl Method for CreatePrivateChat {
+    const TYPE: &'static str = "createPrivateChat";
+    type Response = Chat;
+}
+impl CreatePrivateChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(user_id: impl Into<UserId>) -> Self {
+        Self {
+            user_id: user_id.into(),
+            force: Default::default(),
+        }
+    }
+    #[doc = "If true, the chat will be created without network request. In this case all information about the chat except its type, title and photo can be incorrect"]
+    pub fn with_force(mut self, value: impl Into<bool>) -> Self {
+        self.force = value.into();
+        self
+    }
+}
+impl Validate for CreatePrivateChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns an existing chat corresponding to a known basic group"]
+pub struct CreateBasicGroupChat {
+    #[doc = "Basic group identifier"]
+    pub basic_group_id: BasicGroupId,
+    #[doc = "If true, the chat will be created without network request. In this case all information about the chat except its type, title and photo can be incorrect"]
+    pub force: bool,
+}
+impl Method for CreateBasicGroupChat {
+    const TYPE: &'static str = "createBasicGroupChat";
+    type Response = Chat;
+}
+impl CreateBasicGroupChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(basic_group_id: impl Into<BasicGroupId>) -> Self {
+        Self {
+            basic_group_id: basic_group_id.into(),
+            force: Default::default(),
+        }
+    }
+    #[doc = "If true, the chat will be created without network request. In this case all information about the chat except its type, title and photo can be incorrect"]
+    pub fn with_force(mut self, value: impl Into<bool>) -> Self {
+        self.force = value.into();
+        self
+    }
+}
+impl Validate for CreateBasicGroupChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns an existing chat corresponding to a known supergroup or channel"]
+pub struct CreateSupergroupChat {
+    #[doc = "Supergroup or channel identifier"]
+    pub supergroup_id: SupergroupId,
+    #[doc = "If true, the chat will be created without network request. In this case all information about the chat except its type, title and photo can be incorrect"]
+    pub force: bool,
+}
+impl Method for CreateSupergroupChat {
+    const TYPE: &'static str = "createSupergroupChat";
+    type Response = Chat;
+}
+impl CreateSupergroupChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(supergroup_id: impl Into<SupergroupId>) -> Self {
+        Self {
+            supergroup_id: supergroup_id.into(),
+            force: Default::default(),
+        }
+    }
+    #[doc = "If true, the chat will be created without network request. In this case all information about the chat except its type, title and photo can be incorrect"]
+    pub fn with_force(mut self, value: impl Into<bool>) -> Self {
+        self.force = value.into();
+        self
+    }
+}
+impl Validate for CreateSupergroupChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns an existing chat corresponding to a known secret chat"]
+pub struct CreateSecretChat {
+    #[doc = "Secret chat identifier"]
+    pub secret_chat_id: SecretChatId,
+}
+impl Method for CreateSecretChat {
+    const TYPE: &'static str = "createSecretChat";
+    type Response = Chat;
+}
+impl CreateSecretChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(secret_chat_id: impl Into<SecretChatId>) -> Self {
+        Self {
+            secret_chat_id: secret_chat_id.into(),
+        }
+    }
+}
+impl Validate for CreateSecretChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Creates a new basic group and sends a corresponding messageBasicGroupChatCreate. Returns the newly created chat"]
+pub struct CreateNewBasicGroupChat {
+    #[doc = "Identifiers of users to be added to the basic group"]
+    pub user_ids: Vec<UserId>,
+    #[doc = "Title of the new basic group; 1-128 characters"]
+    pub title: String,
+}
+impl Method for CreateNewBasicGroupChat {
+    const TYPE: &'static str = "createNewBasicGroupChat";
+    type Response = Chat;
+}
+impl CreateNewBasicGroupChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            user_ids: Default::default(),
+            title: title.into(),
+        }
+    }
+    #[doc = "Identifiers of users to be added to the basic group"]
+    pub fn with_user_ids(mut self, value: impl Into<Vec<UserId>>) -> Self {
+        self.user_ids = value.into();
+        self
+    }
+}
+impl Validate for CreateNewBasicGroupChat {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "title",
+            Measure::Characters,
+            self.title.chars().count() as i64,
+            Some(1),
+            Some(128),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Creates a new supergroup or channel and sends a corresponding messageSupergroupChatCreate. Returns the newly created chat"]
+pub struct CreateNewSupergroupChat {
+    #[doc = "Title of the new chat; 1-128 characters"]
+    pub title: String,
+    #[doc = "True, if a channel chat should be created"]
+    pub is_channel: bool,
+    #[doc = "Creates a new supergroup or channel and sends a corresponding messageSupergroupChatCreate. Returns the newly created chat"]
+    pub description: String,
+    #[doc = "Chat location if a location-based supergroup is being created"]
+    pub location: ChatLocation,
+}
+impl Method for CreateNewSupergroupChat {
+    const TYPE: &'static str = "createNewSupergroupChat";
+    type Response = Chat;
+}
+impl CreateNewSupergroupChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(title: impl Into<String>, location: impl Into<ChatLocation>) -> Self {
+        Self {
+            title: title.into(),
+            is_channel: Default::default(),
+            description: Default::default(),
+            location: location.into(),
+        }
+    }
+    #[doc = "True, if a channel chat should be created"]
+    pub fn with_is_channel(mut self, value: impl Into<bool>) -> Self {
+        self.is_channel = value.into();
+        self
+    }
+    #[doc = "Creates a new supergroup or channel and sends a corresponding messageSupergroupChatCreate. Returns the newly created chat"]
+    pub fn with_description(mut self, value: impl Into<String>) -> Self {
+        self.description = value.into();
+        self
+    }
+}
+impl Validate for CreateNewSupergroupChat {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "title",
+            Measure::Characters,
+            self.title.chars().count() as i64,
+            Some(1),
+            Some(128),
+        )?;
+        self.location
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "location"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Creates a new secret chat. Returns the newly created chat"]
+pub struct CreateNewSecretChat {
+    #[doc = "Identifier of the target user"]
+    pub user_id: UserId,
+}
+impl Method for CreateNewSecretChat {
+    const TYPE: &'static str = "createNewSecretChat";
+    type Response = Chat;
+}
+impl CreateNewSecretChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(user_id: impl Into<UserId>) -> Self {
+        Self {
+            user_id: user_id.into(),
+        }
+    }
+}
+impl Validate for CreateNewSecretChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Creates a new supergroup from an existing basic group and sends a corresponding messageChatUpgradeTo and messageChatUpgradeFrom; requires creator privileges. Deactivates the original basic group"]
+pub struct UpgradeBasicGroupChatToSupergroupChat {
+    #[doc = "Identifier of the chat to upgrade"]
+    pub chat_id: ChatId,
+}
+impl Method for UpgradeBasicGroupChatToSupergroupChat {
+    const TYPE: &'static str = "upgradeBasicGroupChatToSupergroupChat";
+    type Response = Chat;
+}
+impl UpgradeBasicGroupChatToSupergroupChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for UpgradeBasicGroupChatToSupergroupChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Moves a chat to a different chat list. Current chat list of the chat must ne non-null"]
+pub struct SetChatChatList {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New chat list of the chat. The chat with the current user (Saved Messages) and the chat 777000 (Telegram) can't be moved to the Archive chat list"]
+    pub chat_list: ChatList,
+}
+impl Method for SetChatChatList {
+    const TYPE: &'static str = "setChatChatList";
+    type Response = Ok;
+}
+impl SetChatChatList {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, chat_list: impl Into<ChatList>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            chat_list: chat_list.into(),
+        }
+    }
+}
+impl Validate for SetChatChatList {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.chat_list
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "chat_list"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the chat title. Supported only for basic groups, supergroups and channels. Requires can_change_info rights. The title will not be changed until the request to the server has been completed"]
+pub struct SetChatTitle {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New title of the chat; 1-128 characters"]
+    pub title: String,
+}
+impl Method for SetChatTitle {
+    const TYPE: &'static str = "setChatTitle";
+    type Response = Ok;
+}
+impl SetChatTitle {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, title: impl Into<String>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            title: title.into(),
+        }
+    }
+}
+impl Validate for SetChatTitle {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "title",
+            Measure::Characters,
+            self.title.chars().count() as i64,
+            Some(1),
+            Some(128),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the photo of a chat. Supported only for basic groups, supergroups and channels. Requires can_change_info rights. The photo will not be changed before request to the server has been completed"]
+pub struct SetChatPhoto {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New chat photo. You can use a zero InputFileId to delete the chat photo. Files that are accessible only by HTTP URL are not acceptable"]
+    pub photo: InputFile,
+}
+impl Method for SetChatPhoto {
+    const TYPE: &'static str = "setChatPhoto";
+    type Response = Ok;
+}
+impl SetChatPhoto {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, photo: impl Into<InputFile>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            photo: photo.into(),
+        }
+    }
+}
+impl Validate for SetChatPhoto {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.photo
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "photo"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the chat members permissions. Supported only for basic groups and supergroups. Requires can_restrict_members administrator right"]
+pub struct SetChatPermissions {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New non-administrator members permissions in the chat"]
+    pub permissions: ChatPermissions,
+}
+impl Method for SetChatPermissions {
+    const TYPE: &'static str = "setChatPermissions";
+    type Response = Ok;
+}
+impl SetChatPermissions {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            permissions: Default::default(),
+        }
+    }
+    #[doc = "New non-administrator members permissions in the chat"]
+    pub fn with_permissions(mut self, value: impl Into<ChatPermissions>) -> Self {
+        self.permissions = value.into();
+        self
+    }
+}
+impl Validate for SetChatPermissions {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.permissions
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "permissions"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the notification settings of a chat. Notification settings of a chat with the current user (Saved Messages) can't be changed"]
+pub struct SetChatNotificationSettings {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New notification settings for the chat. If the chat is muted for more than 1 week, it is considered to be muted forever"]
+    pub notification_settings: ChatNotificationSettings,
+}
+impl Method for SetChatNotificationSettings {
+    const TYPE: &'static str = "setChatNotificationSettings";
+    type Response = Ok;
+}
+impl SetChatNotificationSettings {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            notification_settings: Default::default(),
+        }
+    }
+    #[doc = "New notification settings for the chat. If the chat is muted for more than 1 week, it is considered to be muted forever"]
+    pub fn with_notification_settings(
+        mut self,
+        value: impl Into<ChatNotificationSettings>,
+    ) -> Self {
+        self.notification_settings = value.into();
+        self
+    }
+}
+impl Validate for SetChatNotificationSettings {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.notification_settings
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "notification_settings"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the pinned state of a chat. You can pin up to GetOption(\"pinned_chat_count_max\")/GetOption(\"pinned_archived_chat_count_max\") non-secret chats and the same number of secret chats in the main/archive chat list"]
+pub struct ToggleChatIsPinned {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New value of is_pinned"]
+    pub is_pinned: bool,
+}
+impl Method for ToggleChatIsPinned {
+    const TYPE: &'static str = "toggleChatIsPinned";
+    type Response = Ok;
+}
+impl ToggleChatIsPinned {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            is_pinned: Default::default(),
+        }
+    }
+    #[doc = "New value of is_pinned"]
+    pub fn with_is_pinned(mut self, value: impl Into<bool>) -> Self {
+        self.is_pinned = value.into();
+        self
+    }
+}
+impl Validate for ToggleChatIsPinned {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the marked as unread state of a chat"]
+pub struct ToggleChatIsMarkedAsUnread {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New value of is_marked_as_unread"]
+    pub is_marked_as_unread: bool,
+}
+impl Method for ToggleChatIsMarkedAsUnread {
+    const TYPE: &'static str = "toggleChatIsMarkedAsUnread";
+    type Response = Ok;
+}
+impl ToggleChatIsMarkedAsUnread {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            is_marked_as_unread: Default::default(),
+        }
+    }
+    #[doc = "New value of is_marked_as_unread"]
+    pub fn with_is_marked_as_unread(mut self, value: impl Into<bool>) -> Self {
+        self.is_marked_as_unread = value.into();
+        self
+    }
+}
+impl Validate for ToggleChatIsMarkedAsUnread {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the value of the default disable_notification parameter, used when a message is sent to a chat"]
+pub struct ToggleChatDefaultDisableNotification {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New value of default_disable_notification"]
+    pub default_disable_notification: bool,
+}
+impl Method for ToggleChatDefaultDisableNotification {
+    const TYPE: &'static str = "toggleChatDefaultDisableNotification";
+    type Response = Ok;
+}
+impl ToggleChatDefaultDisableNotification {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            default_disable_notification: Default::default(),
+        }
+    }
+    #[doc = "New value of default_disable_notification"]
+    pub fn with_default_disable_notification(mut self, value: impl Into<bool>) -> Self {
+        self.default_disable_notification = value.into();
+        self
+    }
+}
+impl Validate for ToggleChatDefaultDisableNotification {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes client data associated with a chat"]
+pub struct SetChatClientData {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New value of client_data"]
+    pub client_data: String,
+}
+impl Method for SetChatClientData {
+    const TYPE: &'static str = "setChatClientData";
+    type Response = Ok;
+}
+impl SetChatClientData {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            client_data: Default::default(),
+        }
+    }
+    #[doc = "New value of client_data"]
+    pub fn with_client_data(mut self, value: impl Into<String>) -> Self {
+        self.client_data = value.into();
+        self
+    }
+}
+impl Validate for SetChatClientData {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes information about a chat. Available for basic groups, supergroups, and channels. Requires can_change_info rights"]
+pub struct SetChatDescription {
+    #[doc = "Identifier of the chat"]
+    pub chat_id: ChatId,
+    #[doc = "Changes information about a chat. Available for basic groups, supergroups, and channels. Requires can_change_info rights"]
+    pub description: String,
+}
+impl Method for SetChatDescription {
+    const TYPE: &'static str = "setChatDescription";
+    type Response = Ok;
+}
+impl SetChatDescription {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            description: Default::default(),
+        }
+    }
+    #[doc = "Changes information about a chat. Available for basic groups, supergroups, and channels. Requires can_change_info rights"]
+    pub fn with_description(mut self, value: impl Into<String>) -> Self {
+        self.description = value.into();
+        self
+    }
+}
+impl Validate for SetChatDescription {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Changes the discussion group of a channel chat; requires can_change_info rights in the channel if it is specified"]
+pub struct SetChatDiscussionGroup {
+    #[doc = "Identifier of the channel chat. Pass 0 to remove a link from the supergroup passed in the second argument to a linked channel chat (requires can_pin_messages rights in the supergroup)"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of a new channel's discussion group. Use 0 to remove the discussion group. Use the method getSuitableDiscussionChats to find all suitable groups. Basic group chats needs to be first upgraded to supergroup chats. If new chat members don't have access to old messages in the supergroup, then toggleSupergroupIsAllHistoryAvailable needs to be used first to change that"]
+    pub discussion_chat_id: ChatId,
+}
+impl Method for SetChatDiscussionGroup {
+    const TYPE: &'static str = "setChatDiscussionGroup";
+    type Response = Ok;
+}
+impl SetChatDiscussionGroup {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Identifier of the channel chat. Pass 0 to remove a link from the supergroup passed in the second argument to a linked channel chat (requires can_pin_messages rights in the supergroup)"]
+    pub fn with_chat_id(mut self, value: impl Into<ChatId>) -> Self {
+        self.chat_id = value.into();
+        self
+    }
+    #[doc = "Identifier of a new channel's discussion group. Use 0 to remove the discussion group. Use the method getSuitableDiscussionChats to find all suitable groups. Basic group chats needs to be first upgraded to supergroup chats. If new chat members don't have access to old messages in the supergroup, then toggleSupergroupIsAllHistoryAvailable needs to be used first to change that"]
+    pub fn with_discussion_chat_id(mut self, value: impl Into<ChatId>) -> Self {
+        self.discussion_chat_id = value.into();
+        self
+    }
+}
+impl Validate for SetChatDiscussionGroup {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the location of a chat. Available only for some location-based supergroups, use supergroupFullInfo.can_set_location to check whether the method is allowed to use"]
+pub struct SetChatLocation {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New location for the chat; must be valid and not null"]
+    pub location: ChatLocation,
+}
+impl Method for SetChatLocation {
+    const TYPE: &'static str = "setChatLocation";
+    type Response = Ok;
+}
+impl SetChatLocation {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, location: impl Into<ChatLocation>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            location: location.into(),
+        }
+    }
+}
+impl Validate for SetChatLocation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.location
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "location"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the slow mode delay of a chat. Available only for supergroups; requires can_restrict_members rights"]
+pub struct SetChatSlowModeDelay {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New slow mode delay for the chat; must be one of 0, 10, 30, 60, 300, 900, 3600"]
+    pub slow_mode_delay: i32,
+}
+impl Method for SetChatSlowModeDelay {
+    const TYPE: &'static str = "setChatSlowModeDelay";
+    type Response = Ok;
+}
+impl SetChatSlowModeDelay {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            slow_mode_delay: Default::default(),
+        }
+    }
+    #[doc = "New slow mode delay for the chat; must be one of 0, 10, 30, 60, 300, 900, 3600"]
+    pub fn with_slow_mode_delay(mut self, value: impl Into<i32>) -> Self {
+        self.slow_mode_delay = value.into();
+        self
+    }
+}
+impl Validate for SetChatSlowModeDelay {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check_one_of(
+            Self::TYPE,
+            "slow_mode_delay",
+            i64::from(self.slow_mode_delay),
+            &[0, 10, 30, 60, 300, 900, 3600],
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Adds current user as a new member to a chat. Private and secret chats can't be joined using this method"]
+pub struct JoinChat {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+}
+impl Method for JoinChat {
+    const TYPE: &'static str = "joinChat";
+    type Response = Ok;
+}
+impl JoinChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for JoinChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Removes current user from chat members. Private and secret chats can't be left using this method"]
+pub struct LeaveChat {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+}
+impl Method for LeaveChat {
+    const TYPE: &'static str = "leaveChat";
+    type Response = Ok;
+}
+impl LeaveChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for LeaveChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Adds a new member to a chat. Members can't be added to private or secret chats. Members will not be added until the chat state has been synchronized with the server"]
+pub struct AddChatMember {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the user"]
+    pub user_id: UserId,
+    #[doc = "The number of earlier messages from the chat to be forwarded to the new member; up to 100. Ignored for supergroups and channels"]
+    pub forward_limit: i32,
+}
+impl Method for AddChatMember {
+    const TYPE: &'static str = "addChatMember";
+    type Response = Ok;
+}
+impl AddChatMember {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, user_id: impl Into<UserId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            user_id: user_id.into(),
+            forward_limit: Default::default(),
+        }
+    }
+    #[doc = "The number of earlier messages from the chat to be forwarded to the new member; up to 100. Ignored for supergroups and channels"]
+    pub fn with_forward_limit(mut self, value: impl Into<i32>) -> Self {
+        self.forward_limit = value.into();
+        self
+    }
+}
+impl Validate for AddChatMember {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "forward_limit",
+            Measure::Value,
+            i64::from(self.forward_limit),
+            None,
+            Some(100),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Adds multiple new members to a chat. Currently this option is only available for supergroups and channels. This option can't be used to join a chat. Members can't be added to a channel if it has more than 200 members. Members will not be added until the chat state has been synchronized with the server"]
+pub struct AddChatMembers {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Identifiers of the users to be added to the chat"]
+    pub user_ids: Vec<UserId>,
+}
+impl Method for AddChatMembers {
+    const TYPE: &'static str = "addChatMembers";
+    type Response = Ok;
+}
+impl AddChatMembers {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            user_ids: Default::default(),
+        }
+    }
+    #[doc = "Identifiers of the users to be added to the chat"]
+    pub fn with_user_ids(mut self, value: impl Into<Vec<UserId>>) -> Self {
+        self.user_ids = value.into();
+        self
+    }
+}
+impl Validate for AddChatMembers {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the status of a chat member, needs appropriate privileges. This function is currently not suitable for adding new members to the chat and transferring chat ownership; instead, use addChatMember or transferChatOwnership. The chat member status will not be changed until it has been synchronized with the server"]
+pub struct SetChatMemberStatus {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "User identifier"]
+    pub user_id: UserId,
+    #[doc = "The new status of the member in the chat"]
+    pub status: ChatMemberStatus,
+}
+impl Method for SetChatMemberStatus {
+    const TYPE: &'static str = "setChatMemberStatus";
+    type Response = Ok;
+}
+impl SetChatMemberStatus {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        chat_id: impl Into<ChatId>,
+        user_id: impl Into<UserId>,
+        status: impl Into<ChatMemberStatus>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            user_id: user_id.into(),
+            status: status.into(),
+        }
+    }
+}
+impl Validate for SetChatMemberStatus {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.status
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "status"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the owner of a chat. The current user must be a current owner of the chat. Use the method canTransferOwnership to check whether the ownership can be transferred from the current session. Available only for supergroups and channel chats"]
+pub struct TransferChatOwnership {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the user to which transfer the ownership. The ownership can't be transferred to a bot or to a deleted user"]
+    pub user_id: UserId,
+    #[doc = "The password of the current user"]
+    pub password: String,
+}
+impl Method for TransferChatOwnership {
+    const TYPE: &'static str = "transferChatOwnership";
+    type Response = Ok;
+}
+impl TransferChatOwnership {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, user_id: impl Into<UserId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            user_id: user_id.into(),
+            password: Default::default(),
+        }
+    }
+    #[doc = "The password of the current user"]
+    pub fn with_password(mut self, value: impl Into<String>) -> Self {
+        self.password = value.into();
+        self
+    }
+}
+impl Validate for TransferChatOwnership {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a single member of a chat"]
+pub struct GetChatMember {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "User identifier"]
+    pub user_id: UserId,
+}
+impl Method for GetChatMember {
+    const TYPE: &'static str = "getChatMember";
+    type Response = ChatMember;
+}
+impl GetChatMember {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, user_id: impl Into<UserId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            user_id: user_id.into(),
+        }
+    }
+}
+impl Validate for GetChatMember {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Searches for a specified query in the first name, last name and username of the members of a specified chat. Requires administrator rights in channels"]
+pub struct SearchChatMembers {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Query to search for"]
+    pub query: String,
+    #[doc = "The maximum number of users to be returned"]
+    pub limit: i32,
+    #[doc = "The type of users to return. By default, chatMembersFilterMembers"]
+    pub filter: ChatMembersFilter,
+}
+impl Method for SearchChatMembers {
+    const TYPE: &'static str = "searchChatMembers";
+    type Response = ChatMembers;
+}
+impl SearchChatMembers {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, filter: impl Into<ChatMembersFilter>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            query: Default::default(),
+            limit: Default::default(),
+            filter: filter.into(),
+        }
+    }
+    #[doc = "Query to search for"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+    #[doc = "The maximum number of users to be returned"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for SearchChatMembers {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.filter
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "filter"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns a list of administrators of the chat with their custom titles"]
+pub struct GetChatAdministrators {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+}
+impl Method for GetChatAdministrators {
+    const TYPE: &'static str = "getChatAdministrators";
+    type Response = ChatAdministrators;
+}
+impl GetChatAdministrators {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for GetChatAdministrators {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns list of chats with non-default notification settings"]
+pub struct GetChatNotificationSettingsExceptions {
+    #[doc = "If specified, only chats from the specified scope will be returned"]
+    pub scope: NotificationSettingsScope,
+    #[doc = "If true, also chats with non-default sound will be returned"]
+    pub compare_sound: bool,
+}
+impl Method for GetChatNotificationSettingsExceptions {
+    const TYPE: &'static str = "getChatNotificationSettingsExceptions";
+    type Response = Chats;
+}
+impl GetChatNotificationSettingsExceptions {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(scope: impl Into<NotificationSettingsScope>) -> Self {
+        Self {
+            scope: scope.into(),
+            compare_sound: Default::default(),
+        }
+    }
+    #[doc = "If true, also chats with non-default sound will be returned"]
+    pub fn with_compare_sound(mut self, value: impl Into<bool>) -> Self {
+        self.compare_sound = value.into();
+        self
+    }
+}
+impl Validate for GetChatNotificationSettingsExceptions {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.scope
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "scope"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the order of pinned chats"]
+pub struct SetPinnedChats {
+    #[doc = "Chat list in which to change the order of pinned chats"]
+    pub chat_list: ChatList,
+    #[doc = "The new list of pinned chats"]
+    pub chat_ids: Vec<ChatId>,
+}
+impl Method for SetPinnedChats {
+    const TYPE: &'static str = "setPinnedChats";
+    type Response = Ok;
+}
+impl SetPinnedChats {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_list: impl Into<ChatList>) -> Self {
+        Self {
+            chat_list: chat_list.into(),
+            chat_ids: Default::default(),
+        }
+    }
+    #[doc = "The new list of pinned chats"]
+    pub fn with_chat_ids(mut self, value: impl Into<Vec<ChatId>>) -> Self {
+        self.chat_ids = value.into();
+        self
+    }
+}
+impl Validate for SetPinnedChats {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.chat_list
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "chat_list"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Generates a new invite link for a chat; the previously generated link is revoked. Available for basic groups, supergroups, and channels. Requires administrator privileges and can_invite_users right"]
+pub struct GenerateChatInviteLink {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+}
+impl Method for GenerateChatInviteLink {
+    const TYPE: &'static str = "generateChatInviteLink";
+    type Response = ChatInviteLink;
+}
+impl GenerateChatInviteLink {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for GenerateChatInviteLink {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Checks the validity of an invite link for a chat and returns information about the corresponding chat"]
+pub struct CheckChatInviteLink {
+    #[doc = "Invite link to be checked; should begin with \"https://t.me/joinchat/\", \"https://telegram.me/joinchat/\", or \"https://telegram.dog/joinchat/\""]
+    pub invite_link: String,
+}
+impl Method for CheckChatInviteLink {
+    const TYPE: &'static str = "checkChatInviteLink";
+    type Response = ChatInviteLinkInfo;
+}
+impl CheckChatInviteLink {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Invite link to be checked; should begin with \"https://t.me/joinchat/\", \"https://telegram.me/joinchat/\", or \"https://telegram.dog/joinchat/\""]
+    pub fn with_invite_link(mut self, value: impl Into<String>) -> Self {
+        self.invite_link = value.into();
+        self
+    }
+}
+impl Validate for CheckChatInviteLink {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Uses an invite link to add the current user to the chat if possible. The new member will not be added until the chat state has been synchronized with the server"]
+pub struct JoinChatByInviteLink {
+    #[doc = "Invite link to import; should begin with \"https://t.me/joinchat/\", \"https://telegram.me/joinchat/\", or \"https://telegram.dog/joinchat/\""]
+    pub invite_link: String,
+}
+impl Method for JoinChatByInviteLink {
+    const TYPE: &'static str = "joinChatByInviteLink";
+    type Response = Chat;
+}
+impl JoinChatByInviteLink {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Invite link to import; should begin with \"https://t.me/joinchat/\", \"https://telegram.me/joinchat/\", or \"https://telegram.dog/joinchat/\""]
+    pub fn with_invite_link(mut self, value: impl Into<String>) -> Self {
+        self.invite_link = value.into();
+        self
+    }
+}
+impl Validate for JoinChatByInviteLink {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the username of a supergroup or channel, requires owner privileges in the supergroup or channel"]
+pub struct SetSupergroupUsername {
+    #[doc = "Identifier of the supergroup or channel"]
+    pub supergroup_id: SupergroupId,
+    #[doc = "New value of the username. Use an empty string to remove the username"]
+    pub username: String,
+}
+impl Method for SetSupergroupUsername {
+    const TYPE: &'static str = "setSupergroupUsername";
+    type Response = Ok;
+}
+impl SetSupergroupUsername {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(supergroup_id: impl Into<SupergroupId>) -> Self {
+        Self {
+            supergroup_id: supergroup_id.into(),
+            username: Default::default(),
+        }
+    }
+    #[doc = "New value of the username. Use an empty string to remove the username"]
+    pub fn with_username(mut self, value: impl Into<String>) -> Self {
+        self.username = value.into();
+        self
+    }
+}
+impl Validate for SetSupergroupUsername {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Toggles sender signatures messages sent in a channel; requires can_change_info rights"]
+pub struct ToggleSupergroupSignMessages {
+    #[doc = "Identifier of the channel"]
+    pub supergroup_id: SupergroupId,
+    #[doc = "New value of sign_messages"]
+    pub sign_messages: bool,
+}
+impl Method for ToggleSupergroupSignMessages {
+    const TYPE: &'static str = "toggleSupergroupSignMessages";
+    type Response = Ok;
+}
+impl ToggleSupergroupSignMessages {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(supergroup_id: impl Into<SupergroupId>) -> Self {
+        Self {
+            supergroup_id: supergroup_id.into(),
+            sign_messages: Default::default(),
+        }
+    }
+    #[doc = "New value of sign_messages"]
+    pub fn with_sign_messages(mut self, value: impl Into<bool>) -> Self {
+        self.sign_messages = value.into();
+        self
+    }
+}
+impl Validate for ToggleSupergroupSignMessages {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Toggles whether the message history of a supergroup is available to new members; requires can_change_info rights"]
+pub struct ToggleSupergroupIsAllHistoryAvailable {
+    #[doc = "The identifier of the supergroup"]
+    pub supergroup_id: SupergroupId,
+    #[doc = "The new value of is_all_history_available"]
+    pub is_all_history_available: bool,
+}
+impl Method for ToggleSupergroupIsAllHistoryAvailable {
+    const TYPE: &'static str = "toggleSupergroupIsAllHistoryAvailable";
+    type Response = Ok;
+}
+impl ToggleSupergroupIsAllHistoryAvailable {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(supergroup_id: impl Into<SupergroupId>) -> Self {
+        Self {
+            supergroup_id: supergroup_id.into(),
+            is_all_history_available: Default::default(),
+        }
+    }
+    #[doc = "The new value of is_all_history_available"]
+    pub fn with_is_all_history_available(mut self, value: impl Into<bool>) -> Self {
+        self.is_all_history_available = value.into();
+        self
+    }
+}
+impl Validate for ToggleSupergroupIsAllHistoryAvailable {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Reports some messages from a user in a supergroup as spam; requires administrator rights in the supergroup"]
+pub struct ReportSupergroupSpam {
+    #[doc = "Supergroup identifier"]
+    pub supergroup_id: SupergroupId,
+    #[doc = "User identifier"]
+    pub user_id: UserId,
+    #[doc = "Identifiers of messages sent in the supergroup by the user. This list must be non-empty"]
+    pub message_ids: Vec<MessageId>,
+}
+impl Method for ReportSupergroupSpam {
+    const TYPE: &'static str = "reportSupergroupSpam";
+    type Response = Ok;
+}
+impl ReportSupergroupSpam {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        supergroup_id: impl Into<SupergroupId>,
+        user_id: impl Into<UserId>,
+        message_ids: impl Into<Vec<MessageId>>,
+    ) -> Self {
+        Self {
+            supergroup_id: supergroup_id.into(),
+            user_id: user_id.into(),
+            message_ids: message_ids.into(),
+        }
+    }
+}
+impl Validate for ReportSupergroupSpam {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "message_ids",
+            Measure::Elements,
+            self.message_ids.len() as i64,
+            Some(1),
+            None,
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about members or banned users in a supergroup or channel. Can be used only if SupergroupFullInfo.can_get_members == true; additionally, administrator privileges may be required for some filters"]
+pub struct GetSupergroupMembers {
+    #[doc = "Identifier of the supergroup or channel"]
+    pub supergroup_id: SupergroupId,
+    #[doc = "The type of users to return. By default, supergroupMembersRecent"]
+    pub filter: SupergroupMembersFilter,
+    #[doc = "Number of users to skip"]
+    pub offset: i32,
+    #[doc = "The maximum number of users be returned; up to 200"]
+    pub limit: i32,
+}
+impl Method for GetSupergroupMembers {
+    const TYPE: &'static str = "getSupergroupMembers";
+    type Response = ChatMembers;
+}
+impl GetSupergroupMembers {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        supergroup_id: impl Into<SupergroupId>,
+        filter: impl Into<SupergroupMembersFilter>,
+    ) -> Self {
+        Self {
+            supergroup_id: supergroup_id.into(),
+            filter: filter.into(),
+            offset: Default::default(),
+            limit: Default::default(),
+        }
+    }
+    #[doc = "Number of users to skip"]
+    pub fn with_offset(mut self, value: impl Into<i32>) -> Self {
+        self.offset = value.into();
+        self
+    }
+    #[doc = "The maximum number of users be returned; up to 200"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for GetSupergroupMembers {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.filter
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "filter"))?;
+        validate::check(
+            Self::TYPE,
+            "limit",
+            Measure::Value,
+            i64::from(self.limit),
+            None,
+            Some(200),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Deletes a supergroup or channel along with all messages in the corresponding chat. This will release the supergroup or channel username and remove all members; requires owner privileges in the supergroup or channel. Chats with more than 1000 members can't be deleted using this method"]
+pub struct DeleteSupergroup {
+    #[doc = "Identifier of the supergroup or channel"]
+    pub supergroup_id: SupergroupId,
+}
+impl Method for DeleteSupergroup {
+    const TYPE: &'static str = "deleteSupergroup";
+    type Response = Ok;
+}
+impl DeleteSupergroup {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(supergroup_id: impl Into<SupergroupId>) -> Self {
+        Self {
+            supergroup_id: supergroup_id.into(),
+        }
+    }
+}
+impl Validate for DeleteSupergroup {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Closes a secret chat, effectively transferring its state to secretChatStateClosed"]
+pub struct CloseSecretChat {
+    #[doc = "Secret chat identifier"]
+    pub secret_chat_id: SecretChatId,
+}
+impl Method for CloseSecretChat {
+    const TYPE: &'static str = "closeSecretChat";
+    type Response = Ok;
+}
+impl CloseSecretChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(secret_chat_id: impl Into<SecretChatId>) -> Self {
+        Self {
+            secret_chat_id: secret_chat_id.into(),
+        }
+    }
+}
+impl Validate for CloseSecretChat {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns a list of service actions taken by chat members and administrators in the last 48 hours. Available only for supergroups and channels. Requires administrator rights. Returns results in reverse chronological order (i. e., in order of decreasing event_id)"]
+pub struct GetChatEventLog {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Search query by which to filter events"]
+    pub query: String,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of an event from which to return results. Use 0 to get results from the latest events"]
+    pub from_event_id: i64,
+    #[doc = "The maximum number of events to return; up to 100"]
+    pub limit: i32,
+    #[doc = "The types of events to return. By default, all types will be returned"]
+    pub filters: ChatEventLogFilters,
+    #[doc = "User identifiers by which to filter events. By default, events relating to all users will be returned"]
+    pub user_ids: Vec<UserId>,
+}
+impl Method for GetChatEventLog {
+    const TYPE: &'static str = "getChatEventLog";
+    type Response = ChatEvents;
+}
+impl GetChatEventLog {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            query: Default::default(),
+            from_event_id: Default::default(),
+            limit: Default::default(),
+            filters: Default::default(),
+            user_ids: Default::default(),
+        }
+    }
+    #[doc = "Search query by which to filter events"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+    #[doc = "Identifier of an event from which to return results. Use 0 to get results from the latest events"]
+    pub fn with_from_event_id(mut self, value: impl Into<i64>) -> Self {
+        self.from_event_id = value.into();
+        self
+    }
+    #[doc = "The maximum number of events to return; up to 100"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+    #[doc = "The types of events to return. By default, all types will be returned"]
+    pub fn with_filters(mut self, value: impl Into<ChatEventLogFilters>) -> Self {
+        self.filters = value.into();
+        self
+    }
+    #[doc = "User identifiers by which to filter events. By default, events relating to all users will be returned"]
+    pub fn with_user_ids(mut self, value: impl Into<Vec<UserId>>) -> Self {
+        self.user_ids = value.into();
+        self
+    }
+}
+impl Validate for GetChatEventLog {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "limit",
+            Measure::Value,
+            i64::from(self.limit),
+            None,
+            Some(100),
+        )?;
+        self.filters
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "filters"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Removes a chat action bar without any other action"]
+pub struct RemoveChatActionBar {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+}
+impl Method for RemoveChatActionBar {
+    const TYPE: &'static str = "removeChatActionBar";
+    type Response = Ok;
+}
+impl RemoveChatActionBar {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for RemoveChatActionBar {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Reports a chat to the Telegram moderators. A chat can be reported only from the chat action bar, or if this is a private chats with a bot, a private chat with a user sharing their location, a supergroup, or a channel, since other chats can't be checked by moderators"]
+pub struct ReportChat {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "The reason for reporting the chat"]
+    pub reason: ChatReportReason,
+    #[doc = "Identifiers of reported messages, if any"]
+    pub message_ids: Vec<MessageId>,
+}
+impl Method for ReportChat {
+    const TYPE: &'static str = "reportChat";
+    type Response = Ok;
+}
+impl ReportChat {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, reason: impl Into<ChatReportReason>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            reason: reason.into(),
+            message_ids: Default::default(),
+        }
+    }
+    #[doc = "Identifiers of reported messages, if any"]
+    pub fn with_message_ids(mut self, value: impl Into<Vec<MessageId>>) -> Self {
+        self.message_ids = value.into();
+        self
+    }
+}
+impl Validate for ReportChat {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.reason
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "reason"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns an HTTP URL with the chat statistics. Currently this method of getting the statistics is disabled and can be deleted in the future"]
+pub struct GetChatStatisticsUrl {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Parameters from \"tg://statsrefresh?params=******\" link"]
+    pub parameters: String,
+    #[doc = "Pass true if a URL with the dark theme must be returned"]
+    pub is_dark: bool,
+}
+impl Method for GetChatStatisticsUrl {
+    const TYPE: &'static str = "getChatStatisticsUrl";
+    type Response = HttpUrl;
+}
+impl GetChatStatisticsUrl {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            parameters: Default::default(),
+            is_dark: Default::default(),
+        }
+    }
+    #[doc = "Parameters from \"tg://statsrefresh?params=******\" link"]
+    pub fn with_parameters(mut self, value: impl Into<String>) -> Self {
+        self.parameters = value.into();
+        self
+    }
+    #[doc = "Pass true if a URL with the dark theme must be returned"]
+    pub fn with_is_dark(mut self, value: impl Into<bool>) -> Self {
+        self.is_dark = value.into();
+        self
+    }
+}
+impl Validate for GetChatStatisticsUrl {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns detailed statistics about a chat. Currently this method can be used only for channels. Requires administrator rights in the channel"]
+pub struct GetChatStatistics {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Pass true if a dark theme is used by the app"]
+    pub is_dark: bool,
+}
+impl Method for GetChatStatistics {
+    const TYPE: &'static str = "getChatStatistics";
+    type Response = ChatStatistics;
+}
+impl GetChatStatistics {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            is_dark: Default::default(),
+        }
+    }
+    #[doc = "Pass true if a dark theme is used by the app"]
+    pub fn with_is_dark(mut self, value: impl Into<bool>) -> Self {
+        self.is_dark = value.into();
+        self
+    }
+}
+impl Validate for GetChatStatistics {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Loads asynchronous or zoomed in chat statistics graph"]
+pub struct GetChatStatisticsGraph {
+    #[doc = "Chat identifer"]
+    pub chat_id: ChatId,
+    #[doc = "The token for graph loading"]
+    pub token: String,
+    #[doc = "X-value for zoomed in graph or 0 otherwise"]
+    pub x: i64,
+}
+impl Method for GetChatStatisticsGraph {
+    const TYPE: &'static str = "getChatStatisticsGraph";
+    type Response = StatisticsGraph;
+}
+impl GetChatStatisticsGraph {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            token: Default::default(),
+            x: Default::default(),
+        }
+    }
+    #[doc = "The token for graph loading"]
+    pub fn with_token(mut self, value: impl Into<String>) -> Self {
+        self.token = value.into();
+        self
+    }
+    #[doc = "X-value for zoomed in graph or 0 otherwise"]
+    pub fn with_x(mut self, value: impl Into<i64>) -> Self {
+        self.x = value.into();
+        self
+    }
+}
+impl Validate for GetChatStatisticsGraph {}
@@ -0,0 +1,1770 @@
+//! The methods of the `messages` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a message"]
+pub struct GetMessage {
+    #[doc = "Identifier of the chat the message belongs to"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message to get"]
+    pub message_id: MessageId,
+}
+impl Method for GetMessage {
+    const TYPE: &'static str = "getMessage";
+    type Response = Message;
+}
+impl GetMessage {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+        }
+    }
+}
+impl Validate for GetMessage {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a message, if it is available locally without sending network request. This is an offline request"]
+pub struct GetMessageLocally {
+    #[doc = "Identifier of the chat the message belongs to"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message to get"]
+    pub message_id: MessageId,
+}
+impl Method for GetMessageLocally {
+    const TYPE: &'static str = "getMessageLocally";
+    type Response = Message;
+    const OFFLINE: bool = true;
+}
+impl GetMessageLocally {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+        }
+    }
+}
+impl Validate for GetMessageLocally {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a message that is replied by given message"]
+pub struct GetRepliedMessage {
+    #[doc = "Identifier of the chat the message belongs to"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message reply to which get"]
+    pub message_id: MessageId,
+}
+impl Method for GetRepliedMessage {
+    const TYPE: &'static str = "getRepliedMessage";
+    type Response = Message;
+}
+impl GetRepliedMessage {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+        }
+    }
+}
+impl Validate for GetRepliedMessage {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about a pinned chat message"]
+pub struct GetChatPinnedMessage {
+    #[doc = "Identifier of the chat the message belongs to"]
+    pub chat_id: ChatId,
+}
+impl Method for GetChatPinnedMessage {
+    const TYPE: &'static str = "getChatPinnedMessage";
+    type Response = Message;
+}
+impl GetChatPinnedMessage {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for GetChatPinnedMessage {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about messages. If a message is not found, returns null on the corresponding position of the result"]
+pub struct GetMessages {
+    #[doc = "Identifier of the chat the messages belong to"]
+    pub chat_id: ChatId,
+    #[doc = "Identifiers of the messages to get"]
+    pub message_ids: Vec<MessageId>,
+}
+impl Method for GetMessages {
+    const TYPE: &'static str = "getMessages";
+    type Response = Messages;
+}
+impl GetMessages {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_ids: Default::default(),
+        }
+    }
+    #[doc = "Identifiers of the messages to get"]
+    pub fn with_message_ids(mut self, value: impl Into<Vec<MessageId>>) -> Self {
+        self.message_ids = value.into();
+        self
+    }
+}
+impl Validate for GetMessages {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns messages in a chat. The messages are returned in a reverse chronological order (i.e., in order of decreasing message_id). For optimal performance the number of returned messages is chosen by the library. This is an offline request if only_local is true"]
+pub struct GetChatHistory {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message starting from which history must be fetched; use 0 to get results from the last message"]
+    pub from_message_id: MessageId,
+    #[doc = "Specify 0 to get results from exactly the from_message_id or a negative offset up to 99 to get additionally some newer messages"]
+    pub offset: i32,
+    #[doc = "The maximum number of messages to be returned; must be positive and can't be greater than 100. If the offset is negative, the limit must be greater or equal to -offset. Fewer messages may be returned than specified by the limit, even if the end of the message history has not been reached"]
+    pub limit: i32,
+    #[doc = "If true, returns only messages that are available locally without sending network requests"]
+    pub only_local: bool,
+}
+impl Method for GetChatHistory {
+    const TYPE: &'static str = "getChatHistory";
+    type Response = Messages;
+}
+impl GetChatHistory {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, limit: impl Into<i32>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            from_message_id: Default::default(),
+            offset: Default::default(),
+            limit: limit.into(),
+            only_local: Default::default(),
+        }
+    }
+    #[doc = "Identifier of the message starting from which history must be fetched; use 0 to get results from the last message"]
+    pub fn with_from_message_id(mut self, value: impl Into<MessageId>) -> Self {
+        self.from_message_id = value.into();
+        self
+    }
+    #[doc = "Specify 0 to get results from exactly the from_message_id or a negative offset up to 99 to get additionally some newer messages"]
+    pub fn with_offset(mut self, value: impl Into<i32>) -> Self {
+        self.offset = value.into();
+        self
+    }
+    #[doc = "If true, returns only messages that are available locally without sending network requests"]
+    pub fn with_only_local(mut self, value: impl Into<bool>) -> Self {
+        self.only_local = value.into();
+        self
+    }
+}
+impl Validate for GetChatHistory {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "offset",
+            Measure::Value,
+            i64::from(self.offset),
+            Some(-99),
+            Some(0),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "limit",
+            Measure::Value,
+            i64::from(self.limit),
+            Some(1),
+            Some(100),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "limit",
+            Measure::Value,
+            i64::from(self.limit),
+            Some(-i64::from(self.offset)),
+            None,
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Deletes all messages in the chat. Use Chat.can_be_deleted_only_for_self and Chat.can_be_deleted_for_all_users fields to find whether and how the method can be applied to the chat"]
+pub struct DeleteChatHistory {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Pass true if the chat should be removed from the chat list"]
+    pub remove_from_chat_list: bool,
+    #[doc = "Pass true to try to delete chat history for all users"]
+    pub revoke: bool,
+}
+impl Method for DeleteChatHistory {
+    const TYPE: &'static str = "deleteChatHistory";
+    type Response = Ok;
+}
+impl DeleteChatHistory {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            remove_from_chat_list: Default::default(),
+            revoke: Default::default(),
+        }
+    }
+    #[doc = "Pass true if the chat should be removed from the chat list"]
+    pub fn with_remove_from_chat_list(mut self, value: impl Into<bool>) -> Self {
+        self.remove_from_chat_list = value.into();
+        self
+    }
+    #[doc = "Pass true to try to delete chat history for all users"]
+    pub fn with_revoke(mut self, value: impl Into<bool>) -> Self {
+        self.revoke = value.into();
+        self
+    }
+}
+impl Validate for DeleteChatHistory {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Searches for messages with given words in the chat. Returns the results in reverse chronological order, i.e. in order of decreasing message_id. Cannot be used in secret chats with a non-empty query (searchSecretMessages should be used instead), or without an enabled message database. For optimal performance the number of returned messages is chosen by the library"]
+pub struct SearchChatMessages {
+    #[doc = "Identifier of the chat in which to search messages"]
+    pub chat_id: ChatId,
+    #[doc = "Query to search for"]
+    pub query: String,
+    #[doc = "If not 0, only messages sent by the specified user will be returned. Not supported in secret chats"]
+    pub sender_user_id: UserId,
+    #[doc = "Identifier of the message starting from which history must be fetched; use 0 to get results from the last message"]
+    pub from_message_id: MessageId,
+    #[doc = "Specify 0 to get results from exactly the from_message_id or a negative offset to get the specified message and some newer messages"]
+    pub offset: i32,
+    #[doc = "The maximum number of messages to be returned; must be positive and can't be greater than 100. If the offset is negative, the limit must be greater than -offset. Fewer messages may be returned than specified by the limit, even if the end of the message history has not been reached"]
+    pub limit: i32,
+    #[doc = "Filter for message content in the search results"]
+    pub filter: SearchMessagesFilter,
+}
+impl Method for SearchChatMessages {
+    const TYPE: &'static str = "searchChatMessages";
+    type Response = Messages;
+}
+impl SearchChatMessages {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        chat_id: impl Into<ChatId>,
+        limit: impl Into<i32>,
+        filter: impl Into<SearchMessagesFilter>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            query: Default::default(),
+            sender_user_id: Default::default(),
+            from_message_id: Default::default(),
+            offset: Default::default(),
+            limit: limit.into(),
+            filter: filter.into(),
+        }
+    }
+    #[doc = "Query to search for"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+    #[doc = "If not 0, only messages sent by the specified user will be returned. Not supported in secret chats"]
+    pub fn with_sender_user_id(mut self, value: impl Into<UserId>) -> Self {
+        self.sender_user_id = value.into();
+        self
+    }
+    #[doc = "Identifier of the message starting from which history must be fetched; use 0 to get results from the last message"]
+    pub fn with_from_message_id(mut self, value: impl Into<MessageId>) -> Self {
+        self.from_message_id = value.into();
+        self
+    }
+    #[doc = "Specify 0 to get results from exactly the from_message_id or a negative offset to get the specified message and some newer messages"]
+    pub fn with_offset(mut self, value: impl Into<i32>) -> Self {
+        self.offset = value.into();
+        self
+    }
+}
+impl Validate for SearchChatMessages {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "offset",
+            Measure::Value,
+            i64::from(self.offset),
+            None,
+            Some(0),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "limit",
+            Measure::Value,
+            i64::from(self.limit),
+            Some(1),
+            Some(100),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "limit",
+            Measure::Value,
+            i64::from(self.limit),
+            Some(1 - i64::from(self.offset)),
+            None,
+        )?;
+        self.filter
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "filter"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Searches for messages in all chats except secret chats. Returns the results in reverse chronological order (i.e., in order of decreasing (date, chat_id, message_id)). For optimal performance the number of returned messages is chosen by the library"]
+pub struct SearchMessages {
+    #[serde(default)]
+    #[doc = "Chat list in which to search messages; pass null to search in all chats regardless of their chat list"]
+    pub chat_list: Option<ChatList>,
+    #[doc = "Query to search for"]
+    pub query: String,
+    #[doc = "The date of the message starting from which the results should be fetched. Use 0 or any date in the future to get results from the last message"]
+    pub offset_date: i32,
+    #[doc = "The chat identifier of the last found message, or 0 for the first request"]
+    pub offset_chat_id: ChatId,
+    #[doc = "The message identifier of the last found message, or 0 for the first request"]
+    pub offset_message_id: MessageId,
+    #[doc = "The maximum number of messages to be returned, up to 100. Fewer messages may be returned than specified by the limit, even if the end of the message history has not been reached"]
+    pub limit: i32,
+}
+impl Method for SearchMessages {
+    const TYPE: &'static str = "searchMessages";
+    type Response = Messages;
+}
+impl SearchMessages {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Chat list in which to search messages; pass null to search in all chats regardless of their chat list"]
+    pub fn with_chat_list(mut self, value: impl Into<ChatList>) -> Self {
+        self.chat_list = Some(value.into());
+        self
+    }
+    #[doc = "Query to search for"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+    #[doc = "The date of the message starting from which the results should be fetched. Use 0 or any date in the future to get results from the last message"]
+    pub fn with_offset_date(mut self, value: impl Into<i32>) -> Self {
+        self.offset_date = value.into();
+        self
+    }
+    #[doc = "The chat identifier of the last found message, or 0 for the first request"]
+    pub fn with_offset_chat_id(mut self, value: impl Into<ChatId>) -> Self {
+        self.offset_chat_id = value.into();
+        self
+    }
+    #[doc = "The message identifier of the last found message, or 0 for the first request"]
+    pub fn with_offset_message_id(mut self, value: impl Into<MessageId>) -> Self {
+        self.offset_message_id = value.into();
+        self
+    }
+    #[doc = "The maximum number of messages to be returned, up to 100. Fewer messages may be returned than specified by the limit, even if the end of the message history has not been reached"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for SearchMessages {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.chat_list {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "chat_list"))?;
+        }
+        validate::check(
+            Self::TYPE,
+            "limit",
+            Measure::Value,
+            i64::from(self.limit),
+            None,
+            Some(100),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Searches for messages in secret chats. Returns the results in reverse chronological order. For optimal performance the number of returned messages is chosen by the library"]
+pub struct SearchSecretMessages {
+    #[doc = "Identifier of the chat in which to search. Specify 0 to search in all secret chats"]
+    pub chat_id: ChatId,
+    #[doc = "Query to search for. If empty, searchChatMessages should be used instead"]
+    pub query: String,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "The identifier from the result of a previous request, use 0 to get results from the last message"]
+    pub from_search_id: i64,
+    #[doc = "The maximum number of messages to be returned; up to 100. Fewer messages may be returned than specified by the limit, even if the end of the message history has not been reached"]
+    pub limit: i32,
+    #[doc = "A filter for the content of messages in the search results"]
+    pub filter: SearchMessagesFilter,
+}
+impl Method for SearchSecretMessages {
+    const TYPE: &'static str = "searchSecretMessages";
+    type Response = FoundMessages;
+}
+impl SearchSecretMessages {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(filter: impl Into<SearchMessagesFilter>) -> Self {
+        Self {
+            chat_id: Default::default(),
+            query: Default::default(),
+            from_search_id: Default::default(),
+            limit: Default::default(),
+            filter: filter.into(),
+        }
+    }
+    #[doc = "Identifier of the chat in which to search. Specify 0 to search in all secret chats"]
+    pub fn with_chat_id(mut self, value: impl Into<ChatId>) -> Self {
+        self.chat_id = value.into();
+        self
+    }
+    #[doc = "Query to search for. If empty, searchChatMessages should be used instead"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+    #[doc = "The identifier from the result of a previous request, use 0 to get results from the last message"]
+    pub fn with_from_search_id(mut self, value: impl Into<i64>) -> Self {
+        self.from_search_id = value.into();
+        self
+    }
+    #[doc = "The maximum number of messages to be returned; up to 100. Fewer messages may be returned than specified by the limit, even if the end of the message history has not been reached"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for SearchSecretMessages {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "limit",
+            Measure::Value,
+            i64::from(self.limit),
+            None,
+            Some(100),
+        )?;
+        self.filter
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "filter"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns information about the recent locations of chat members that were sent to the chat. Returns up to 1 location message per user"]
+pub struct SearchChatRecentLocationMessages {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "The maximum number of messages to be returned"]
+    pub limit: i32,
+}
+impl Method for SearchChatRecentLocationMessages {
+    const TYPE: &'static str = "searchChatRecentLocationMessages";
+    type Response = Messages;
+}
+impl SearchChatRecentLocationMessages {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            limit: Default::default(),
+        }
+    }
+    #[doc = "The maximum number of messages to be returned"]
+    pub fn with_limit(mut self, value: impl Into<i32>) -> Self {
+        self.limit = value.into();
+        self
+    }
+}
+impl Validate for SearchChatRecentLocationMessages {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns all active live locations that should be updated by the client. The list is persistent across application restarts only if the message database is used"]
+pub struct GetActiveLiveLocationMessages {}
+impl Method for GetActiveLiveLocationMessages {
+    const TYPE: &'static str = "getActiveLiveLocationMessages";
+    type Response = Messages;
+}
+impl GetActiveLiveLocationMessages {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetActiveLiveLocationMessages {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns the last message sent in a chat no later than the specified date"]
+pub struct GetChatMessageByDate {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Point in time (Unix timestamp) relative to which to search for messages"]
+    pub date: i32,
+}
+impl Method for GetChatMessageByDate {
+    const TYPE: &'static str = "getChatMessageByDate";
+    type Response = Message;
+}
+impl GetChatMessageByDate {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            date: Default::default(),
+        }
+    }
+    #[doc = "Point in time (Unix timestamp) relative to which to search for messages"]
+    pub fn with_date(mut self, value: impl Into<i32>) -> Self {
+        self.date = value.into();
+        self
+    }
+}
+impl Validate for GetChatMessageByDate {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns approximate number of messages of the specified type in the chat"]
+pub struct GetChatMessageCount {
+    #[doc = "Identifier of the chat in which to count messages"]
+    pub chat_id: ChatId,
+    #[doc = "Filter for message content; searchMessagesFilterEmpty is unsupported in this function"]
+    pub filter: SearchMessagesFilter,
+    #[doc = "If true, returns count that is available locally without sending network requests, returning -1 if the number of messages is unknown"]
+    pub return_local: bool,
+}
+impl Method for GetChatMessageCount {
+    const TYPE: &'static str = "getChatMessageCount";
+    type Response = Count;
+}
+impl GetChatMessageCount {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, filter: impl Into<SearchMessagesFilter>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            filter: filter.into(),
+            return_local: Default::default(),
+        }
+    }
+    #[doc = "If true, returns count that is available locally without sending network requests, returning -1 if the number of messages is unknown"]
+    pub fn with_return_local(mut self, value: impl Into<bool>) -> Self {
+        self.return_local = value.into();
+        self
+    }
+}
+impl Validate for GetChatMessageCount {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.filter
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "filter"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns all scheduled messages in a chat. The messages are returned in a reverse chronological order (i.e., in order of decreasing message_id)"]
+pub struct GetChatScheduledMessages {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+}
+impl Method for GetChatScheduledMessages {
+    const TYPE: &'static str = "getChatScheduledMessages";
+    type Response = Messages;
+}
+impl GetChatScheduledMessages {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for GetChatScheduledMessages {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns a public HTTPS link to a message. Available only for messages in supergroups and channels with a username"]
+pub struct GetPublicMessageLink {
+    #[doc = "Identifier of the chat to which the message belongs"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message"]
+    pub message_id: MessageId,
+    #[doc = "Pass true if a link for a whole media album should be returned"]
+    pub for_album: bool,
+}
+impl Method for GetPublicMessageLink {
+    const TYPE: &'static str = "getPublicMessageLink";
+    type Response = PublicMessageLink;
+}
+impl GetPublicMessageLink {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            for_album: Default::default(),
+        }
+    }
+    #[doc = "Pass true if a link for a whole media album should be returned"]
+    pub fn with_for_album(mut self, value: impl Into<bool>) -> Self {
+        self.for_album = value.into();
+        self
+    }
+}
+impl Validate for GetPublicMessageLink {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns a private HTTPS link to a message in a chat. Available only for already sent messages in supergroups and channels. The link will work only for members of the chat"]
+pub struct GetMessageLink {
+    #[doc = "Identifier of the chat to which the message belongs"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message"]
+    pub message_id: MessageId,
+}
+impl Method for GetMessageLink {
+    const TYPE: &'static str = "getMessageLink";
+    type Response = HttpUrl;
+}
+impl GetMessageLink {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+        }
+    }
+}
+impl Validate for GetMessageLink {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns information about a public or private message link"]
+pub struct GetMessageLinkInfo {
+    #[doc = "The message link in the format \"https://t.me/c/...\", or \"tg://privatepost?...\", or \"https://t.me/username/...\", or \"tg://resolve?...\""]
+    pub url: String,
+}
+impl Method for GetMessageLinkInfo {
+    const TYPE: &'static str = "getMessageLinkInfo";
+    type Response = MessageLinkInfo;
+}
+impl GetMessageLinkInfo {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The message link in the format \"https://t.me/c/...\", or \"tg://privatepost?...\", or \"https://t.me/username/...\", or \"tg://resolve?...\""]
+    pub fn with_url(mut self, value: impl Into<String>) -> Self {
+        self.url = value.into();
+        self
+    }
+}
+impl Validate for GetMessageLinkInfo {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sends a message. Returns the sent message"]
+pub struct SendMessage {
+    #[doc = "Target chat"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message to reply to or 0"]
+    pub reply_to_message_id: MessageId,
+    #[doc = "Options to be used to send the message"]
+    pub options: SendMessageOptions,
+    #[serde(default)]
+    #[doc = "Markup for replying to the message; for bots only"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent"]
+    pub input_message_content: InputMessageContent,
+}
+impl Method for SendMessage {
+    const TYPE: &'static str = "sendMessage";
+    type Response = Message;
+}
+impl SendMessage {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        chat_id: impl Into<ChatId>,
+        input_message_content: impl Into<InputMessageContent>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            reply_to_message_id: Default::default(),
+            options: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Identifier of the message to reply to or 0"]
+    pub fn with_reply_to_message_id(mut self, value: impl Into<MessageId>) -> Self {
+        self.reply_to_message_id = value.into();
+        self
+    }
+    #[doc = "Options to be used to send the message"]
+    pub fn with_options(mut self, value: impl Into<SendMessageOptions>) -> Self {
+        self.options = value.into();
+        self
+    }
+    #[doc = "Markup for replying to the message; for bots only"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+impl Validate for SendMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.options
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "options"))?;
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Sends messages grouped together into an album. Currently only photo and video messages can be grouped into an album. Returns sent messages"]
+pub struct SendMessageAlbum {
+    #[doc = "Target chat"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of a message to reply to or 0"]
+    pub reply_to_message_id: MessageId,
+    #[doc = "Options to be used to send the messages"]
+    pub options: SendMessageOptions,
+    #[doc = "Contents of messages to be sent"]
+    pub input_message_contents: Vec<InputMessageContent>,
+}
+impl Method for SendMessageAlbum {
+    const TYPE: &'static str = "sendMessageAlbum";
+    type Response = Messages;
+}
+impl SendMessageAlbum {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            reply_to_message_id: Default::default(),
+            options: Default::default(),
+            input_message_contents: Default::default(),
+        }
+    }
+    #[doc = "Identifier of a message to reply to or 0"]
+    pub fn with_reply_to_message_id(mut self, value: impl Into<MessageId>) -> Self {
+        self.reply_to_message_id = value.into();
+        self
+    }
+    #[doc = "Options to be used to send the messages"]
+    pub fn with_options(mut self, value: impl Into<SendMessageOptions>) -> Self {
+        self.options = value.into();
+        self
+    }
+    #[doc = "Contents of messages to be sent"]
+    pub fn with_input_message_contents(
+        mut self,
+        value: impl Into<Vec<InputMessageContent>>,
+    ) -> Self {
+        self.input_message_contents = value.into();
+        self
+    }
+}
+impl Validate for SendMessageAlbum {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.options
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "options"))?;
+        for (i, value) in self.input_message_contents.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("input_message_contents[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Forwards previously sent messages. Returns the forwarded messages in the same order as the message identifiers passed in message_ids. If a message can't be forwarded, null will be returned instead of the message"]
+pub struct ForwardMessages {
+    #[doc = "Identifier of the chat to which to forward messages"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the chat from which to forward messages"]
+    pub from_chat_id: ChatId,
+    #[doc = "Identifiers of the messages to forward"]
+    pub message_ids: Vec<MessageId>,
+    #[doc = "Options to be used to send the messages"]
+    pub options: SendMessageOptions,
+    #[doc = "True, if the messages should be grouped into an album after forwarding. For this to work, no more than 10 messages may be forwarded, and all of them must be photo or video messages"]
+    pub as_album: bool,
+    #[doc = "True, if content of the messages needs to be copied without links to the original messages. Always true if the messages are forwarded to a secret chat"]
+    pub send_copy: bool,
+    #[doc = "True, if media captions of message copies needs to be removed. Ignored if send_copy is false"]
+    pub remove_caption: bool,
+}
+impl Method for ForwardMessages {
+    const TYPE: &'static str = "forwardMessages";
+    type Response = Messages;
+}
+impl ForwardMessages {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, from_chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            from_chat_id: from_chat_id.into(),
+            message_ids: Default::default(),
+            options: Default::default(),
+            as_album: Default::default(),
+            send_copy: Default::default(),
+            remove_caption: Default::default(),
+        }
+    }
+    #[doc = "Identifiers of the messages to forward"]
+    pub fn with_message_ids(mut self, value: impl Into<Vec<MessageId>>) -> Self {
+        self.message_ids = value.into();
+        self
+    }
+    #[doc = "Options to be used to send the messages"]
+    pub fn with_options(mut self, value: impl Into<SendMessageOptions>) -> Self {
+        self.options = value.into();
+        self
+    }
+    #[doc = "True, if the messages should be grouped into an album after forwarding. For this to work, no more than 10 messages may be forwarded, and all of them must be photo or video messages"]
+    pub fn with_as_album(mut self, value: impl Into<bool>) -> Self {
+        self.as_album = value.into();
+        self
+    }
+    #[doc = "True, if content of the messages needs to be copied without links to the original messages. Always true if the messages are forwarded to a secret chat"]
+    pub fn with_send_copy(mut self, value: impl Into<bool>) -> Self {
+        self.send_copy = value.into();
+        self
+    }
+    #[doc = "True, if media captions of message copies needs to be removed. Ignored if send_copy is false"]
+    pub fn with_remove_caption(mut self, value: impl Into<bool>) -> Self {
+        self.remove_caption = value.into();
+        self
+    }
+}
+impl Validate for ForwardMessages {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.options
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "options"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Resends messages which failed to send. Can be called only for messages for which messageSendingStateFailed.can_retry is true and after specified in messageSendingStateFailed.retry_after time passed. If a message is re-sent, the corresponding failed to send message is deleted. Returns the sent messages in the same order as the message identifiers passed in message_ids. If a message can't be re-sent, null will be returned instead of the message"]
+pub struct ResendMessages {
+    #[doc = "Identifier of the chat to send messages"]
+    pub chat_id: ChatId,
+    #[doc = "Identifiers of the messages to resend. Message identifiers must be in a strictly increasing order"]
+    pub message_ids: Vec<MessageId>,
+}
+impl Method for ResendMessages {
+    const TYPE: &'static str = "resendMessages";
+    type Response = Messages;
+}
+impl ResendMessages {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_ids: Default::default(),
+        }
+    }
+    #[doc = "Identifiers of the messages to resend. Message identifiers must be in a strictly increasing order"]
+    pub fn with_message_ids(mut self, value: impl Into<Vec<MessageId>>) -> Self {
+        self.message_ids = value.into();
+        self
+    }
+}
+impl Validate for ResendMessages {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the current TTL setting (sets a new self-destruct timer) in a secret chat and sends the corresponding message"]
+pub struct SendChatSetTtlMessage {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New TTL value, in seconds"]
+    pub ttl: i32,
+}
+impl Method for SendChatSetTtlMessage {
+    const TYPE: &'static str = "sendChatSetTtlMessage";
+    type Response = Message;
+}
+impl SendChatSetTtlMessage {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            ttl: Default::default(),
+        }
+    }
+    #[doc = "New TTL value, in seconds"]
+    pub fn with_ttl(mut self, value: impl Into<i32>) -> Self {
+        self.ttl = value.into();
+        self
+    }
+}
+impl Validate for SendChatSetTtlMessage {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Adds a local message to a chat. The message is persistent across application restarts only if the message database is used. Returns the added message"]
+pub struct AddLocalMessage {
+    #[doc = "Target chat"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the user who will be shown as the sender of the message; may be 0 for channel posts"]
+    pub sender_user_id: UserId,
+    #[doc = "Identifier of the message to reply to or 0"]
+    pub reply_to_message_id: MessageId,
+    #[doc = "Pass true to disable notification for the message"]
+    pub disable_notification: bool,
+    #[doc = "The content of the message to be added"]
+    pub input_message_content: InputMessageContent,
+}
+impl Method for AddLocalMessage {
+    const TYPE: &'static str = "addLocalMessage";
+    type Response = Message;
+}
+impl AddLocalMessage {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        chat_id: impl Into<ChatId>,
+        input_message_content: impl Into<InputMessageContent>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            sender_user_id: Default::default(),
+            reply_to_message_id: Default::default(),
+            disable_notification: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Identifier of the user who will be shown as the sender of the message; may be 0 for channel posts"]
+    pub fn with_sender_user_id(mut self, value: impl Into<UserId>) -> Self {
+        self.sender_user_id = value.into();
+        self
+    }
+    #[doc = "Identifier of the message to reply to or 0"]
+    pub fn with_reply_to_message_id(mut self, value: impl Into<MessageId>) -> Self {
+        self.reply_to_message_id = value.into();
+        self
+    }
+    #[doc = "Pass true to disable notification for the message"]
+    pub fn with_disable_notification(mut self, value: impl Into<bool>) -> Self {
+        self.disable_notification = value.into();
+        self
+    }
+}
+impl Validate for AddLocalMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Deletes messages"]
+pub struct DeleteMessages {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Identifiers of the messages to be deleted"]
+    pub message_ids: Vec<MessageId>,
+    #[doc = "Pass true to try to delete messages for all chat members. Always true for supergroups, channels and secret chats"]
+    pub revoke: bool,
+}
+impl Method for DeleteMessages {
+    const TYPE: &'static str = "deleteMessages";
+    type Response = Ok;
+}
+impl DeleteMessages {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_ids: Default::default(),
+            revoke: Default::default(),
+        }
+    }
+    #[doc = "Identifiers of the messages to be deleted"]
+    pub fn with_message_ids(mut self, value: impl Into<Vec<MessageId>>) -> Self {
+        self.message_ids = value.into();
+        self
+    }
+    #[doc = "Pass true to try to delete messages for all chat members. Always true for supergroups, channels and secret chats"]
+    pub fn with_revoke(mut self, value: impl Into<bool>) -> Self {
+        self.revoke = value.into();
+        self
+    }
+}
+impl Validate for DeleteMessages {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Deletes all messages sent by the specified user to a chat. Supported only for supergroups; requires can_delete_messages administrator privileges"]
+pub struct DeleteChatMessagesFromUser {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "User identifier"]
+    pub user_id: UserId,
+}
+impl Method for DeleteChatMessagesFromUser {
+    const TYPE: &'static str = "deleteChatMessagesFromUser";
+    type Response = Ok;
+}
+impl DeleteChatMessagesFromUser {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, user_id: impl Into<UserId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            user_id: user_id.into(),
+        }
+    }
+}
+impl Validate for DeleteChatMessagesFromUser {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Edits the text of a message (or a text of a game message). Returns the edited message after the edit is completed on the server side"]
+pub struct EditMessageText {
+    #[doc = "The chat the message belongs to"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message"]
+    pub message_id: MessageId,
+    #[serde(default)]
+    #[doc = "The new message reply markup; for bots only"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "New text content of the message. Should be of type InputMessageText"]
+    pub input_message_content: InputMessageContent,
+}
+impl Method for EditMessageText {
+    const TYPE: &'static str = "editMessageText";
+    type Response = Message;
+}
+impl EditMessageText {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        chat_id: impl Into<ChatId>,
+        message_id: impl Into<MessageId>,
+        input_message_content: impl Into<InputMessageContent>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "The new message reply markup; for bots only"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+impl Validate for EditMessageText {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Edits the message content of a live location. Messages can be edited for a limited period of time specified in the live location. Returns the edited message after the edit is completed on the server side"]
+pub struct EditMessageLiveLocation {
+    #[doc = "The chat the message belongs to"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message"]
+    pub message_id: MessageId,
+    #[serde(default)]
+    #[doc = "The new message reply markup; for bots only"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[serde(default)]
+    #[doc = "New location content of the message; may be null. Pass null to stop sharing the live location"]
+    pub location: Option<Location>,
+}
+impl Method for EditMessageLiveLocation {
+    const TYPE: &'static str = "editMessageLiveLocation";
+    type Response = Message;
+}
+impl EditMessageLiveLocation {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            reply_markup: Default::default(),
+            location: Default::default(),
+        }
+    }
+    #[doc = "The new message reply markup; for bots only"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+    #[doc = "New location content of the message; may be null. Pass null to stop sharing the live location"]
+    pub fn with_location(mut self, value: impl Into<Location>) -> Self {
+        self.location = Some(value.into());
+        self
+    }
+}
+impl Validate for EditMessageLiveLocation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        if let Some(value) = &self.location {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "location"))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Edits the content of a message with an animation, an audio, a document, a photo or a video. The media in the message can't be replaced if the message was set to self-destruct. Media can't be replaced by self-destructing media. Media in an album can be edited only to contain a photo or a video. Returns the edited message after the edit is completed on the server side"]
+pub struct EditMessageMedia {
+    #[doc = "The chat the message belongs to"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message"]
+    pub message_id: MessageId,
+    #[serde(default)]
+    #[doc = "The new message reply markup; for bots only"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "New content of the message. Must be one of the following types: InputMessageAnimation, InputMessageAudio, InputMessageDocument, InputMessagePhoto or InputMessageVideo"]
+    pub input_message_content: InputMessageContent,
+}
+impl Method for EditMessageMedia {
+    const TYPE: &'static str = "editMessageMedia";
+    type Response = Message;
+}
+impl EditMessageMedia {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        chat_id: impl Into<ChatId>,
+        message_id: impl Into<MessageId>,
+        input_message_content: impl Into<InputMessageContent>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "The new message reply markup; for bots only"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+impl Validate for EditMessageMedia {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Edits the message content caption. Returns the edited message after the edit is completed on the server side"]
+pub struct EditMessageCaption {
+    #[doc = "The chat the message belongs to"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message"]
+    pub message_id: MessageId,
+    #[serde(default)]
+    #[doc = "The new message reply markup; for bots only"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "New message content caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub caption: FormattedText,
+}
+impl Method for EditMessageCaption {
+    const TYPE: &'static str = "editMessageCaption";
+    type Response = Message;
+}
+impl EditMessageCaption {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            reply_markup: Default::default(),
+            caption: Default::default(),
+        }
+    }
+    #[doc = "The new message reply markup; for bots only"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+    #[doc = "New message content caption; 0-GetOption(\"message_caption_length_max\") characters"]
+    pub fn with_caption(mut self, value: impl Into<FormattedText>) -> Self {
+        self.caption = value.into();
+        self
+    }
+}
+impl Validate for EditMessageCaption {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.caption
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "caption"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Edits the time when a scheduled message will be sent. Scheduling state of all messages in the same album or forwarded together with the message will be also changed"]
+pub struct EditMessageSchedulingState {
+    #[doc = "The chat the message belongs to"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message"]
+    pub message_id: MessageId,
+    #[serde(default)]
+    #[doc = "The new message scheduling state. Pass null to send the message immediately"]
+    pub scheduling_state: Option<MessageSchedulingState>,
+}
+impl Method for EditMessageSchedulingState {
+    const TYPE: &'static str = "editMessageSchedulingState";
+    type Response = Ok;
+}
+impl EditMessageSchedulingState {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            scheduling_state: Default::default(),
+        }
+    }
+    #[doc = "The new message scheduling state. Pass null to send the message immediately"]
+    pub fn with_scheduling_state(mut self, value: impl Into<MessageSchedulingState>) -> Self {
+        self.scheduling_state = Some(value.into());
+        self
+    }
+}
+impl Validate for EditMessageSchedulingState {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.scheduling_state {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "scheduling_state"))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns all entities (mentions, hashtags, cashtags, bot commands, bank card numbers, URLs, and email addresses) contained in the text. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct GetTextEntities {
+    #[doc = "The text in which to look for entites"]
+    pub text: String,
+}
+impl Method for GetTextEntities {
+    const TYPE: &'static str = "getTextEntities";
+    type Response = TextEntities;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for GetTextEntities {}
+impl GetTextEntities {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The text in which to look for entites"]
+    pub fn with_text(mut self, value: impl Into<String>) -> Self {
+        self.text = value.into();
+        self
+    }
+}
+impl Validate for GetTextEntities {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Parses Bold, Italic, Underline, Strikethrough, Code, Pre, PreCode, TextUrl and MentionName entities contained in the text. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct ParseTextEntities {
+    #[doc = "The text to parse"]
+    pub text: String,
+    #[doc = "Text parse mode"]
+    pub parse_mode: TextParseMode,
+}
+impl Method for ParseTextEntities {
+    const TYPE: &'static str = "parseTextEntities";
+    type Response = FormattedText;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for ParseTextEntities {}
+impl ParseTextEntities {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(parse_mode: impl Into<TextParseMode>) -> Self {
+        Self {
+            text: Default::default(),
+            parse_mode: parse_mode.into(),
+        }
+    }
+    #[doc = "The text to parse"]
+    pub fn with_text(mut self, value: impl Into<String>) -> Self {
+        self.text = value.into();
+        self
+    }
+}
+impl Validate for ParseTextEntities {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.parse_mode
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "parse_mode"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Parses Markdown entities in a human-friendly format, ignoring mark up errors. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct ParseMarkdown {
+    #[doc = "The text to parse. For example, \"__italic__ ~~strikethrough~~ **bold** `code` ```pre``` __[italic__ text_url](telegram.org) __italic**bold italic__bold**\""]
+    pub text: FormattedText,
+}
+impl Method for ParseMarkdown {
+    const TYPE: &'static str = "parseMarkdown";
+    type Response = FormattedText;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for ParseMarkdown {}
+impl ParseMarkdown {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The text to parse. For example, \"__italic__ ~~strikethrough~~ **bold** `code` ```pre``` __[italic__ text_url](telegram.org) __italic**bold italic__bold**\""]
+    pub fn with_text(mut self, value: impl Into<FormattedText>) -> Self {
+        self.text = value.into();
+        self
+    }
+}
+impl Validate for ParseMarkdown {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.text
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "text"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Replaces text entities with Markdown formatting in a human-friendly format. Entities that can't be represented in Markdown unambiguously are kept as is. This is an offline method. Can be called before authorization. Can be called synchronously"]
+pub struct GetMarkdownText {
+    #[doc = "The text"]
+    pub text: FormattedText,
+}
+impl Method for GetMarkdownText {
+    const TYPE: &'static str = "getMarkdownText";
+    type Response = FormattedText;
+    const OFFLINE: bool = true;
+    const SYNCHRONOUS: bool = true;
+    const BEFORE_AUTHORIZATION: bool = true;
+    const AUTHORIZATION_STATES: Option<&'static [&'static str]> = None;
+}
+impl SyncMethod for GetMarkdownText {}
+impl GetMarkdownText {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The text"]
+    pub fn with_text(mut self, value: impl Into<FormattedText>) -> Self {
+        self.text = value.into();
+        self
+    }
+}
+impl Validate for GetMarkdownText {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.text
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "text"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the user answer to a poll. A poll in quiz mode can be answered only once"]
+pub struct SetPollAnswer {
+    #[doc = "Identifier of the chat to which the poll belongs"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message containing the poll"]
+    pub message_id: MessageId,
+    #[doc = "0-based identifiers of answer options, chosen by the user. User can choose more than 1 answer option only is the poll allows multiple answers"]
+    pub option_ids: Vec<i32>,
+}
+impl Method for SetPollAnswer {
+    const TYPE: &'static str = "setPollAnswer";
+    type Response = Ok;
+}
+impl SetPollAnswer {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            option_ids: Default::default(),
+        }
+    }
+    #[doc = "0-based identifiers of answer options, chosen by the user. User can choose more than 1 answer option only is the poll allows multiple answers"]
+    pub fn with_option_ids(mut self, value: impl Into<Vec<i32>>) -> Self {
+        self.option_ids = value.into();
+        self
+    }
+}
+impl Validate for SetPollAnswer {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Returns users voted for the specified option in a non-anonymous polls. For the optimal performance the number of returned users is chosen by the library"]
+pub struct GetPollVoters {
+    #[doc = "Identifier of the chat to which the poll belongs"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message containing the poll"]
+    pub message_id: MessageId,
+    #[doc = "0-based identifier of the answer option"]
+    pub option_id: i32,
+    #[doc = "Number of users to skip in the result; must be non-negative"]
+    pub offset: i32,
+    #[doc = "The maximum number of users to be returned; must be positive and can't be greater than 50. Fewer users may be returned than specified by the limit, even if the end of the voter list has not been reached"]
+    pub limit: i32,
+}
+impl Method for GetPollVoters {
+    const TYPE: &'static str = "getPollVoters";
+    type Response = Users;
+}
+impl GetPollVoters {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        chat_id: impl Into<ChatId>,
+        message_id: impl Into<MessageId>,
+        limit: impl Into<i32>,
+    ) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            option_id: Default::default(),
+            offset: Default::default(),
+            limit: limit.into(),
+        }
+    }
+    #[doc = "0-based identifier of the answer option"]
+    pub fn with_option_id(mut self, value: impl Into<i32>) -> Self {
+        self.option_id = value.into();
+        self
+    }
+    #[doc = "Number of users to skip in the result; must be non-negative"]
+    pub fn with_offset(mut self, value: impl Into<i32>) -> Self {
+        self.offset = value.into();
+        self
+    }
+}
+impl Validate for GetPollVoters {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "offset",
+            Measure::Value,
+            i64::from(self.offset),
+            Some(0),
+            None,
+        )?;
+        validate::check(
+            Self::TYPE,
+            "limit",
+            Measure::Value,
+            i64::from(self.limit),
+            Some(1),
+            Some(50),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Stops a poll. A poll in a message can be stopped when the message has can_be_edited flag set"]
+pub struct StopPoll {
+    #[doc = "Identifier of the chat to which the poll belongs"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message containing the poll"]
+    pub message_id: MessageId,
+    #[serde(default)]
+    #[doc = "The new message reply markup; for bots only"]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+impl Method for StopPoll {
+    const TYPE: &'static str = "stopPoll";
+    type Response = Ok;
+}
+impl StopPoll {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            reply_markup: Default::default(),
+        }
+    }
+    #[doc = "The new message reply markup; for bots only"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+impl Validate for StopPoll {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Informs TDLib that messages are being viewed by the user. Many useful activities depend on whether the messages are currently being viewed or not (e.g., marking messages as read, incrementing a view counter, updating a view counter, removing deleted messages in supergroups and channels)"]
+pub struct ViewMessages {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "The identifiers of the messages being viewed"]
+    pub message_ids: Vec<MessageId>,
+    #[doc = "True, if messages in closed chats should be marked as read"]
+    pub force_read: bool,
+}
+impl Method for ViewMessages {
+    const TYPE: &'static str = "viewMessages";
+    type Response = Ok;
+}
+impl ViewMessages {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_ids: Default::default(),
+            force_read: Default::default(),
+        }
+    }
+    #[doc = "The identifiers of the messages being viewed"]
+    pub fn with_message_ids(mut self, value: impl Into<Vec<MessageId>>) -> Self {
+        self.message_ids = value.into();
+        self
+    }
+    #[doc = "True, if messages in closed chats should be marked as read"]
+    pub fn with_force_read(mut self, value: impl Into<bool>) -> Self {
+        self.force_read = value.into();
+        self
+    }
+}
+impl Validate for ViewMessages {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Informs TDLib that the message content has been opened (e.g., the user has opened a photo, video, document, location or venue, or has listened to an audio file or voice note message). An updateMessageContentOpened update will be generated if something has changed"]
+pub struct OpenMessageContent {
+    #[doc = "Chat identifier of the message"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message with the opened content"]
+    pub message_id: MessageId,
+}
+impl Method for OpenMessageContent {
+    const TYPE: &'static str = "openMessageContent";
+    type Response = Ok;
+}
+impl OpenMessageContent {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+        }
+    }
+}
+impl Validate for OpenMessageContent {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Changes the draft message in a chat"]
+pub struct SetChatDraftMessage {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[serde(default)]
+    #[doc = "New draft message; may be null"]
+    pub draft_message: Option<DraftMessage>,
+}
+impl Method for SetChatDraftMessage {
+    const TYPE: &'static str = "setChatDraftMessage";
+    type Response = Ok;
+}
+impl SetChatDraftMessage {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            draft_message: Default::default(),
+        }
+    }
+    #[doc = "New draft message; may be null"]
+    pub fn with_draft_message(mut self, value: impl Into<DraftMessage>) -> Self {
+        self.draft_message = Some(value.into());
+        self
+    }
+}
+impl Validate for SetChatDraftMessage {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.draft_message {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "draft_message"))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Pins a message in a chat; requires can_pin_messages rights"]
+pub struct PinChatMessage {
+    #[doc = "Identifier of the chat"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the new pinned message"]
+    pub message_id: MessageId,
+    #[doc = "True, if there should be no notification about the pinned message"]
+    pub disable_notification: bool,
+}
+impl Method for PinChatMessage {
+    const TYPE: &'static str = "pinChatMessage";
+    type Response = Ok;
+}
+impl PinChatMessage {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>, message_id: impl Into<MessageId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+            message_id: message_id.into(),
+            disable_notification: Default::default(),
+        }
+    }
+    #[doc = "True, if there should be no notification about the pinned message"]
+    pub fn with_disable_notification(mut self, value: impl Into<bool>) -> Self {
+        self.disable_notification = value.into();
+        self
+    }
+}
+impl Validate for PinChatMessage {}
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[doc = "Removes the pinned message from a chat; requires can_pin_messages rights in the group or channel"]
+pub struct UnpinChatMessage {
+    #[doc = "Identifier of the chat"]
+    pub chat_id: ChatId,
+}
+impl Method for UnpinChatMessage {
+    const TYPE: &'static str = "unpinChatMessage";
+    type Response = Ok;
+}
+impl UnpinChatMessage {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(chat_id: impl Into<ChatId>) -> Self {
+        Self {
+            chat_id: chat_id.into(),
+        }
+    }
+}
+impl Validate for UnpinChatMessage {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Clears draft messages in all chats"]
+pub struct ClearAllDraftMessages {
+    #[doc = "If true, local draft messages in secret chats will not be cleared"]
+    pub exclude_secret_chats: bool,
+}
+impl Method for ClearAllDraftMessages {
+    const TYPE: &'static str = "clearAllDraftMessages";
+    type Response = Ok;
+}
+impl ClearAllDraftMessages {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "If true, local draft messages in secret chats will not be cleared"]
+    pub fn with_exclude_secret_chats(mut self, value: impl Into<bool>) -> Self {
+        self.exclude_secret_chats = value.into();
+        self
+    }
+}
+impl Validate for ClearAllDraftMessages {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns a web page preview by the text of the message. Do not call this function too often. Returns a 404 error if the web page has no preview"]
+pub struct GetWebPagePreview {
+    #[doc = "Message text with formatting"]
+    pub text: FormattedText,
+}
+impl Method for GetWebPagePreview {
+    const TYPE: &'static str = "getWebPagePreview";
+    type Response = WebPage;
+}
+impl GetWebPagePreview {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Message text with formatting"]
+    pub fn with_text(mut self, value: impl Into<FormattedText>) -> Self {
+        self.text = value.into();
+        self
+    }
+}
+impl Validate for GetWebPagePreview {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.text
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "text"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns an instant view version of a web page if available. Returns a 404 error if the web page has no instant view page"]
+pub struct GetWebPageInstantView {
+    #[doc = "The web page URL"]
+    pub url: String,
+    #[doc = "If true, the full instant view for the web page will be returned"]
+    pub force_full: bool,
+}
+impl Method for GetWebPageInstantView {
+    const TYPE: &'static str = "getWebPageInstantView";
+    type Response = WebPageInstantView;
+}
+impl GetWebPageInstantView {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The web page URL"]
+    pub fn with_url(mut self, value: impl Into<String>) -> Self {
+        self.url = value.into();
+        self
+    }
+    #[doc = "If true, the full instant view for the web page will be returned"]
+    pub fn with_force_full(mut self, value: impl Into<bool>) -> Self {
+        self.force_full = value.into();
+        self
+    }
+}
+impl Validate for GetWebPageInstantView {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[doc = "Returns the default text for invitation messages to be used as a placeholder when the current user invites friends to Telegram"]
+pub struct GetInviteText {}
+impl Method for GetInviteText {
+    const TYPE: &'static str = "getInviteText";
+    type Response = Text;
+}
+impl GetInviteText {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for GetInviteText {}
@@ -0,0 +1,6517 @@
+//! Sizes of the generated enums and of the types of their variants, to audit
+//! the variants `tl-codegen` boxes.
+//!
+//! Variants whose type is larger than [`MAX_VARIANT_SIZE`] bytes on 64-bit
+//! targets are boxed, so moving an enum never copies much more than that.
+//!
+//! Which ones is decided when the code is generated, from sizes estimated
+//! with every feature enabled: the `estimated` sizes. On 32-bit targets, or
+//! with some features disabled, the same variants are boxed, and `size` is
+//! what the types take there.
+
+use crate::types::*;
+use std::mem::size_of;
+
+/// The size of an enum and of the types of its variants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EnumSize {
+    pub name: &'static str,
+    pub size: usize,
+    /// The size `tl-codegen` estimated on 64-bit targets, with every feature
+    /// enabled.
+    pub estimated: usize,
+    pub variants: &'static [VariantSize],
+}
+
+/// The size of the type of a variant, whether it is boxed or not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VariantSize {
+    pub name: &'static str,
+    pub size: usize,
+    /// The size `tl-codegen` estimated on 64-bit targets, from which it
+    /// decided to box the variant.
+    pub estimated: usize,
+    pub boxed: bool,
+}
+
+#[doc = "Variants larger than this are boxed"]
+pub const MAX_VARIANT_SIZE: usize = 128;
+#[doc = "Every enum of `types`, in the order they are defined, then Response"]
+pub static ENUMS: &[EnumSize] = &[
+    EnumSize {
+        name: "AuthenticationCodeType",
+        size: size_of::<AuthenticationCodeType>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "AuthenticationCodeTypeTelegramMessage",
+                size: size_of::<AuthenticationCodeTypeTelegramMessage>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthenticationCodeTypeSms",
+                size: size_of::<AuthenticationCodeTypeSms>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthenticationCodeTypeCall",
+                size: size_of::<AuthenticationCodeTypeCall>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthenticationCodeTypeFlashCall",
+                size: size_of::<AuthenticationCodeTypeFlashCall>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "AuthorizationState",
+        size: size_of::<AuthorizationState>(),
+        estimated: 128,
+        variants: &[
+            VariantSize {
+                name: "AuthorizationStateWaitTdlibParameters",
+                size: size_of::<AuthorizationStateWaitTdlibParameters>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthorizationStateWaitEncryptionKey",
+                size: size_of::<AuthorizationStateWaitEncryptionKey>(),
+                estimated: 1,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthorizationStateWaitPhoneNumber",
+                size: size_of::<AuthorizationStateWaitPhoneNumber>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthorizationStateWaitCode",
+                size: size_of::<AuthorizationStateWaitCode>(),
+                estimated: 128,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthorizationStateWaitOtherDeviceConfirmation",
+                size: size_of::<AuthorizationStateWaitOtherDeviceConfirmation>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthorizationStateWaitRegistration",
+                size: size_of::<AuthorizationStateWaitRegistration>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthorizationStateWaitPassword",
+                size: size_of::<AuthorizationStateWaitPassword>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthorizationStateReady",
+                size: size_of::<AuthorizationStateReady>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthorizationStateLoggingOut",
+                size: size_of::<AuthorizationStateLoggingOut>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthorizationStateClosing",
+                size: size_of::<AuthorizationStateClosing>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthorizationStateClosed",
+                size: size_of::<AuthorizationStateClosed>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "InputFile",
+        size: size_of::<InputFile>(),
+        estimated: 56,
+        variants: &[
+            VariantSize {
+                name: "InputFileId",
+                size: size_of::<InputFileId>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputFileRemote",
+                size: size_of::<InputFileRemote>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputFileLocal",
+                size: size_of::<InputFileLocal>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputFileGenerated",
+                size: size_of::<InputFileGenerated>(),
+                estimated: 56,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "MaskPoint",
+        size: size_of::<MaskPoint>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "MaskPointForehead",
+                size: size_of::<MaskPointForehead>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MaskPointEyes",
+                size: size_of::<MaskPointEyes>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MaskPointMouth",
+                size: size_of::<MaskPointMouth>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MaskPointChin",
+                size: size_of::<MaskPointChin>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "PollType",
+        size: size_of::<PollType>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "PollTypeRegular",
+                size: size_of::<PollTypeRegular>(),
+                estimated: 1,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PollTypeQuiz",
+                size: size_of::<PollTypeQuiz>(),
+                estimated: 4,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "UserType",
+        size: size_of::<UserType>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "UserTypeRegular",
+                size: size_of::<UserTypeRegular>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserTypeDeleted",
+                size: size_of::<UserTypeDeleted>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserTypeBot",
+                size: size_of::<UserTypeBot>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserTypeUnknown",
+                size: size_of::<UserTypeUnknown>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "chats")]
+    EnumSize {
+        name: "ChatMemberStatus",
+        size: size_of::<ChatMemberStatus>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "ChatMemberStatusCreator",
+                size: size_of::<ChatMemberStatusCreator>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatMemberStatusAdministrator",
+                size: size_of::<ChatMemberStatusAdministrator>(),
+                estimated: 40,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatMemberStatusMember",
+                size: size_of::<ChatMemberStatusMember>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatMemberStatusRestricted",
+                size: size_of::<ChatMemberStatusRestricted>(),
+                estimated: 16,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatMemberStatusLeft",
+                size: size_of::<ChatMemberStatusLeft>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatMemberStatusBanned",
+                size: size_of::<ChatMemberStatusBanned>(),
+                estimated: 4,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "chats")]
+    EnumSize {
+        name: "ChatMembersFilter",
+        size: size_of::<ChatMembersFilter>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "ChatMembersFilterContacts",
+                size: size_of::<ChatMembersFilterContacts>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatMembersFilterAdministrators",
+                size: size_of::<ChatMembersFilterAdministrators>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatMembersFilterMembers",
+                size: size_of::<ChatMembersFilterMembers>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatMembersFilterRestricted",
+                size: size_of::<ChatMembersFilterRestricted>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatMembersFilterBanned",
+                size: size_of::<ChatMembersFilterBanned>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatMembersFilterBots",
+                size: size_of::<ChatMembersFilterBots>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "chats")]
+    EnumSize {
+        name: "SupergroupMembersFilter",
+        size: size_of::<SupergroupMembersFilter>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "SupergroupMembersFilterRecent",
+                size: size_of::<SupergroupMembersFilterRecent>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SupergroupMembersFilterContacts",
+                size: size_of::<SupergroupMembersFilterContacts>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SupergroupMembersFilterAdministrators",
+                size: size_of::<SupergroupMembersFilterAdministrators>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SupergroupMembersFilterSearch",
+                size: size_of::<SupergroupMembersFilterSearch>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SupergroupMembersFilterRestricted",
+                size: size_of::<SupergroupMembersFilterRestricted>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SupergroupMembersFilterBanned",
+                size: size_of::<SupergroupMembersFilterBanned>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SupergroupMembersFilterBots",
+                size: size_of::<SupergroupMembersFilterBots>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "chats")]
+    EnumSize {
+        name: "SecretChatState",
+        size: size_of::<SecretChatState>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "SecretChatStatePending",
+                size: size_of::<SecretChatStatePending>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SecretChatStateReady",
+                size: size_of::<SecretChatStateReady>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SecretChatStateClosed",
+                size: size_of::<SecretChatStateClosed>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    EnumSize {
+        name: "MessageForwardOrigin",
+        size: size_of::<MessageForwardOrigin>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "MessageForwardOriginUser",
+                size: size_of::<MessageForwardOriginUser>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageForwardOriginHiddenUser",
+                size: size_of::<MessageForwardOriginHiddenUser>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageForwardOriginChannel",
+                size: size_of::<MessageForwardOriginChannel>(),
+                estimated: 40,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    EnumSize {
+        name: "MessageSendingState",
+        size: size_of::<MessageSendingState>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "MessageSendingStatePending",
+                size: size_of::<MessageSendingStatePending>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageSendingStateFailed",
+                size: size_of::<MessageSendingStateFailed>(),
+                estimated: 40,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "NotificationSettingsScope",
+        size: size_of::<NotificationSettingsScope>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "NotificationSettingsScopePrivateChats",
+                size: size_of::<NotificationSettingsScopePrivateChats>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NotificationSettingsScopeGroupChats",
+                size: size_of::<NotificationSettingsScopeGroupChats>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NotificationSettingsScopeChannelChats",
+                size: size_of::<NotificationSettingsScopeChannelChats>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "ChatType",
+        size: size_of::<ChatType>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "ChatTypePrivate",
+                size: size_of::<ChatTypePrivate>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatTypeBasicGroup",
+                size: size_of::<ChatTypeBasicGroup>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatTypeSupergroup",
+                size: size_of::<ChatTypeSupergroup>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatTypeSecret",
+                size: size_of::<ChatTypeSecret>(),
+                estimated: 8,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(any(feature = "chats", feature = "messages"))]
+    EnumSize {
+        name: "ChatList",
+        size: size_of::<ChatList>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "ChatListMain",
+                size: size_of::<ChatListMain>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatListArchive",
+                size: size_of::<ChatListArchive>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "chats")]
+    EnumSize {
+        name: "PublicChatType",
+        size: size_of::<PublicChatType>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "PublicChatTypeHasUsername",
+                size: size_of::<PublicChatTypeHasUsername>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PublicChatTypeIsLocationBased",
+                size: size_of::<PublicChatTypeIsLocationBased>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "chats")]
+    EnumSize {
+        name: "ChatActionBar",
+        size: size_of::<ChatActionBar>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "ChatActionBarReportSpam",
+                size: size_of::<ChatActionBarReportSpam>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionBarReportUnrelatedLocation",
+                size: size_of::<ChatActionBarReportUnrelatedLocation>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionBarReportAddBlock",
+                size: size_of::<ChatActionBarReportAddBlock>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionBarAddContact",
+                size: size_of::<ChatActionBarAddContact>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionBarSharePhoneNumber",
+                size: size_of::<ChatActionBarSharePhoneNumber>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    EnumSize {
+        name: "KeyboardButtonType",
+        size: size_of::<KeyboardButtonType>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "KeyboardButtonTypeText",
+                size: size_of::<KeyboardButtonTypeText>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "KeyboardButtonTypeRequestPhoneNumber",
+                size: size_of::<KeyboardButtonTypeRequestPhoneNumber>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "KeyboardButtonTypeRequestLocation",
+                size: size_of::<KeyboardButtonTypeRequestLocation>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "KeyboardButtonTypeRequestPoll",
+                size: size_of::<KeyboardButtonTypeRequestPoll>(),
+                estimated: 2,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    EnumSize {
+        name: "InlineKeyboardButtonType",
+        size: size_of::<InlineKeyboardButtonType>(),
+        estimated: 56,
+        variants: &[
+            VariantSize {
+                name: "InlineKeyboardButtonTypeUrl",
+                size: size_of::<InlineKeyboardButtonTypeUrl>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InlineKeyboardButtonTypeLoginUrl",
+                size: size_of::<InlineKeyboardButtonTypeLoginUrl>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InlineKeyboardButtonTypeCallback",
+                size: size_of::<InlineKeyboardButtonTypeCallback>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InlineKeyboardButtonTypeCallbackGame",
+                size: size_of::<InlineKeyboardButtonTypeCallbackGame>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InlineKeyboardButtonTypeSwitchInline",
+                size: size_of::<InlineKeyboardButtonTypeSwitchInline>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InlineKeyboardButtonTypeBuy",
+                size: size_of::<InlineKeyboardButtonTypeBuy>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    EnumSize {
+        name: "ReplyMarkup",
+        size: size_of::<ReplyMarkup>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "ReplyMarkupRemoveKeyboard",
+                size: size_of::<ReplyMarkupRemoveKeyboard>(),
+                estimated: 1,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ReplyMarkupForceReply",
+                size: size_of::<ReplyMarkupForceReply>(),
+                estimated: 1,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ReplyMarkupShowKeyboard",
+                size: size_of::<ReplyMarkupShowKeyboard>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ReplyMarkupInlineKeyboard",
+                size: size_of::<ReplyMarkupInlineKeyboard>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "bots")]
+    EnumSize {
+        name: "LoginUrlInfo",
+        size: size_of::<LoginUrlInfo>(),
+        estimated: 56,
+        variants: &[
+            VariantSize {
+                name: "LoginUrlInfoOpen",
+                size: size_of::<LoginUrlInfoOpen>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "LoginUrlInfoRequestConfirmation",
+                size: size_of::<LoginUrlInfoRequestConfirmation>(),
+                estimated: 56,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "messages")]
+    EnumSize {
+        name: "RichText",
+        size: size_of::<RichText>(),
+        estimated: 56,
+        variants: &[
+            VariantSize {
+                name: "RichTextPlain",
+                size: size_of::<RichTextPlain>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextBold",
+                size: size_of::<RichTextBold>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextItalic",
+                size: size_of::<RichTextItalic>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextUnderline",
+                size: size_of::<RichTextUnderline>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextStrikethrough",
+                size: size_of::<RichTextStrikethrough>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextFixed",
+                size: size_of::<RichTextFixed>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextUrl",
+                size: size_of::<RichTextUrl>(),
+                estimated: 40,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextEmailAddress",
+                size: size_of::<RichTextEmailAddress>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextSubscript",
+                size: size_of::<RichTextSubscript>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextSuperscript",
+                size: size_of::<RichTextSuperscript>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextMarked",
+                size: size_of::<RichTextMarked>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextPhoneNumber",
+                size: size_of::<RichTextPhoneNumber>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextIcon",
+                size: size_of::<RichTextIcon>(),
+                estimated: 344,
+                boxed: true,
+            },
+            VariantSize {
+                name: "RichTextReference",
+                size: size_of::<RichTextReference>(),
+                estimated: 40,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextAnchor",
+                size: size_of::<RichTextAnchor>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTextAnchorLink",
+                size: size_of::<RichTextAnchorLink>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RichTexts",
+                size: size_of::<RichTexts>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "messages")]
+    EnumSize {
+        name: "PageBlockHorizontalAlignment",
+        size: size_of::<PageBlockHorizontalAlignment>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "PageBlockHorizontalAlignmentLeft",
+                size: size_of::<PageBlockHorizontalAlignmentLeft>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockHorizontalAlignmentCenter",
+                size: size_of::<PageBlockHorizontalAlignmentCenter>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockHorizontalAlignmentRight",
+                size: size_of::<PageBlockHorizontalAlignmentRight>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "messages")]
+    EnumSize {
+        name: "PageBlockVerticalAlignment",
+        size: size_of::<PageBlockVerticalAlignment>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "PageBlockVerticalAlignmentTop",
+                size: size_of::<PageBlockVerticalAlignmentTop>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockVerticalAlignmentMiddle",
+                size: size_of::<PageBlockVerticalAlignmentMiddle>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockVerticalAlignmentBottom",
+                size: size_of::<PageBlockVerticalAlignmentBottom>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "messages")]
+    EnumSize {
+        name: "PageBlock",
+        size: size_of::<PageBlock>(),
+        estimated: 120,
+        variants: &[
+            VariantSize {
+                name: "PageBlockTitle",
+                size: size_of::<PageBlockTitle>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockSubtitle",
+                size: size_of::<PageBlockSubtitle>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockAuthorDate",
+                size: size_of::<PageBlockAuthorDate>(),
+                estimated: 64,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockHeader",
+                size: size_of::<PageBlockHeader>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockSubheader",
+                size: size_of::<PageBlockSubheader>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockKicker",
+                size: size_of::<PageBlockKicker>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockParagraph",
+                size: size_of::<PageBlockParagraph>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockPreformatted",
+                size: size_of::<PageBlockPreformatted>(),
+                estimated: 80,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockFooter",
+                size: size_of::<PageBlockFooter>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockDivider",
+                size: size_of::<PageBlockDivider>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockAnchor",
+                size: size_of::<PageBlockAnchor>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockList",
+                size: size_of::<PageBlockList>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockBlockQuote",
+                size: size_of::<PageBlockBlockQuote>(),
+                estimated: 112,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockPullQuote",
+                size: size_of::<PageBlockPullQuote>(),
+                estimated: 112,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockAnimation",
+                size: size_of::<PageBlockAnimation>(),
+                estimated: 472,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PageBlockAudio",
+                size: size_of::<PageBlockAudio>(),
+                estimated: 504,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PageBlockPhoto",
+                size: size_of::<PageBlockPhoto>(),
+                estimated: 200,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PageBlockVideo",
+                size: size_of::<PageBlockVideo>(),
+                estimated: 472,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PageBlockVoiceNote",
+                size: size_of::<PageBlockVoiceNote>(),
+                estimated: 280,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PageBlockCover",
+                size: size_of::<PageBlockCover>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockEmbedded",
+                size: size_of::<PageBlockEmbedded>(),
+                estimated: 240,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PageBlockEmbeddedPost",
+                size: size_of::<PageBlockEmbeddedPost>(),
+                estimated: 256,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PageBlockCollage",
+                size: size_of::<PageBlockCollage>(),
+                estimated: 136,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PageBlockSlideshow",
+                size: size_of::<PageBlockSlideshow>(),
+                estimated: 136,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PageBlockChatLink",
+                size: size_of::<PageBlockChatLink>(),
+                estimated: 272,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PageBlockTable",
+                size: size_of::<PageBlockTable>(),
+                estimated: 88,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockDetails",
+                size: size_of::<PageBlockDetails>(),
+                estimated: 88,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockRelatedArticles",
+                size: size_of::<PageBlockRelatedArticles>(),
+                estimated: 80,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PageBlockMap",
+                size: size_of::<PageBlockMap>(),
+                estimated: 144,
+                boxed: true,
+            },
+        ],
+    },
+    #[cfg(feature = "payments")]
+    EnumSize {
+        name: "InputCredentials",
+        size: size_of::<InputCredentials>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "InputCredentialsSaved",
+                size: size_of::<InputCredentialsSaved>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputCredentialsNew",
+                size: size_of::<InputCredentialsNew>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputCredentialsAndroidPay",
+                size: size_of::<InputCredentialsAndroidPay>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputCredentialsApplePay",
+                size: size_of::<InputCredentialsApplePay>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "PassportElementType",
+        size: size_of::<PassportElementType>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "PassportElementTypePersonalDetails",
+                size: size_of::<PassportElementTypePersonalDetails>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTypePassport",
+                size: size_of::<PassportElementTypePassport>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTypeDriverLicense",
+                size: size_of::<PassportElementTypeDriverLicense>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTypeIdentityCard",
+                size: size_of::<PassportElementTypeIdentityCard>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTypeInternalPassport",
+                size: size_of::<PassportElementTypeInternalPassport>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTypeAddress",
+                size: size_of::<PassportElementTypeAddress>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTypeUtilityBill",
+                size: size_of::<PassportElementTypeUtilityBill>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTypeBankStatement",
+                size: size_of::<PassportElementTypeBankStatement>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTypeRentalAgreement",
+                size: size_of::<PassportElementTypeRentalAgreement>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTypePassportRegistration",
+                size: size_of::<PassportElementTypePassportRegistration>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTypeTemporaryRegistration",
+                size: size_of::<PassportElementTypeTemporaryRegistration>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTypePhoneNumber",
+                size: size_of::<PassportElementTypePhoneNumber>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTypeEmailAddress",
+                size: size_of::<PassportElementTypeEmailAddress>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "passport")]
+    EnumSize {
+        name: "PassportElement",
+        size: size_of::<PassportElement>(),
+        estimated: 56,
+        variants: &[
+            VariantSize {
+                name: "PassportElementPersonalDetails",
+                size: size_of::<PassportElementPersonalDetails>(),
+                estimated: 232,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PassportElementPassport",
+                size: size_of::<PassportElementPassport>(),
+                estimated: 424,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PassportElementDriverLicense",
+                size: size_of::<PassportElementDriverLicense>(),
+                estimated: 424,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PassportElementIdentityCard",
+                size: size_of::<PassportElementIdentityCard>(),
+                estimated: 424,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PassportElementInternalPassport",
+                size: size_of::<PassportElementInternalPassport>(),
+                estimated: 424,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PassportElementAddress",
+                size: size_of::<PassportElementAddress>(),
+                estimated: 144,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PassportElementUtilityBill",
+                size: size_of::<PassportElementUtilityBill>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementBankStatement",
+                size: size_of::<PassportElementBankStatement>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementRentalAgreement",
+                size: size_of::<PassportElementRentalAgreement>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementPassportRegistration",
+                size: size_of::<PassportElementPassportRegistration>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementTemporaryRegistration",
+                size: size_of::<PassportElementTemporaryRegistration>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementPhoneNumber",
+                size: size_of::<PassportElementPhoneNumber>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementEmailAddress",
+                size: size_of::<PassportElementEmailAddress>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "passport")]
+    EnumSize {
+        name: "InputPassportElement",
+        size: size_of::<InputPassportElement>(),
+        estimated: 56,
+        variants: &[
+            VariantSize {
+                name: "InputPassportElementPersonalDetails",
+                size: size_of::<InputPassportElementPersonalDetails>(),
+                estimated: 232,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputPassportElementPassport",
+                size: size_of::<InputPassportElementPassport>(),
+                estimated: 232,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputPassportElementDriverLicense",
+                size: size_of::<InputPassportElementDriverLicense>(),
+                estimated: 232,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputPassportElementIdentityCard",
+                size: size_of::<InputPassportElementIdentityCard>(),
+                estimated: 232,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputPassportElementInternalPassport",
+                size: size_of::<InputPassportElementInternalPassport>(),
+                estimated: 232,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputPassportElementAddress",
+                size: size_of::<InputPassportElementAddress>(),
+                estimated: 144,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputPassportElementUtilityBill",
+                size: size_of::<InputPassportElementUtilityBill>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementBankStatement",
+                size: size_of::<InputPassportElementBankStatement>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementRentalAgreement",
+                size: size_of::<InputPassportElementRentalAgreement>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementPassportRegistration",
+                size: size_of::<InputPassportElementPassportRegistration>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementTemporaryRegistration",
+                size: size_of::<InputPassportElementTemporaryRegistration>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementPhoneNumber",
+                size: size_of::<InputPassportElementPhoneNumber>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementEmailAddress",
+                size: size_of::<InputPassportElementEmailAddress>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "passport")]
+    EnumSize {
+        name: "PassportElementErrorSource",
+        size: size_of::<PassportElementErrorSource>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "PassportElementErrorSourceUnspecified",
+                size: size_of::<PassportElementErrorSourceUnspecified>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementErrorSourceDataField",
+                size: size_of::<PassportElementErrorSourceDataField>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementErrorSourceFrontSide",
+                size: size_of::<PassportElementErrorSourceFrontSide>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementErrorSourceReverseSide",
+                size: size_of::<PassportElementErrorSourceReverseSide>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementErrorSourceSelfie",
+                size: size_of::<PassportElementErrorSourceSelfie>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementErrorSourceTranslationFile",
+                size: size_of::<PassportElementErrorSourceTranslationFile>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementErrorSourceTranslationFiles",
+                size: size_of::<PassportElementErrorSourceTranslationFiles>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementErrorSourceFile",
+                size: size_of::<PassportElementErrorSourceFile>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementErrorSourceFiles",
+                size: size_of::<PassportElementErrorSourceFiles>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "passport")]
+    EnumSize {
+        name: "InputPassportElementErrorSource",
+        size: size_of::<InputPassportElementErrorSource>(),
+        estimated: 56,
+        variants: &[
+            VariantSize {
+                name: "InputPassportElementErrorSourceUnspecified",
+                size: size_of::<InputPassportElementErrorSourceUnspecified>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementErrorSourceDataField",
+                size: size_of::<InputPassportElementErrorSourceDataField>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementErrorSourceFrontSide",
+                size: size_of::<InputPassportElementErrorSourceFrontSide>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementErrorSourceReverseSide",
+                size: size_of::<InputPassportElementErrorSourceReverseSide>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementErrorSourceSelfie",
+                size: size_of::<InputPassportElementErrorSourceSelfie>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementErrorSourceTranslationFile",
+                size: size_of::<InputPassportElementErrorSourceTranslationFile>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementErrorSourceTranslationFiles",
+                size: size_of::<InputPassportElementErrorSourceTranslationFiles>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementErrorSourceFile",
+                size: size_of::<InputPassportElementErrorSourceFile>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputPassportElementErrorSourceFiles",
+                size: size_of::<InputPassportElementErrorSourceFiles>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "MessageContent",
+        size: size_of::<MessageContent>(),
+        estimated: 120,
+        variants: &[
+            VariantSize {
+                name: "MessageText",
+                size: size_of::<MessageText>(),
+                estimated: 2640,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessageAnimation",
+                size: size_of::<MessageAnimation>(),
+                estimated: 408,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessageAudio",
+                size: size_of::<MessageAudio>(),
+                estimated: 440,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessageDocument",
+                size: size_of::<MessageDocument>(),
+                estimated: 384,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessagePhoto",
+                size: size_of::<MessagePhoto>(),
+                estimated: 120,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageExpiredPhoto",
+                size: size_of::<MessageExpiredPhoto>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageSticker",
+                size: size_of::<MessageSticker>(),
+                estimated: 376,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessageVideo",
+                size: size_of::<MessageVideo>(),
+                estimated: 408,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessageExpiredVideo",
+                size: size_of::<MessageExpiredVideo>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageVideoNote",
+                size: size_of::<MessageVideoNote>(),
+                estimated: 304,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessageVoiceNote",
+                size: size_of::<MessageVoiceNote>(),
+                estimated: 224,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessageLocation",
+                size: size_of::<MessageLocation>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageVenue",
+                size: size_of::<MessageVenue>(),
+                estimated: 136,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessageContact",
+                size: size_of::<MessageContact>(),
+                estimated: 104,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageDice",
+                size: size_of::<MessageDice>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageGame",
+                size: size_of::<MessageGame>(),
+                estimated: 544,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessagePoll",
+                size: size_of::<MessagePoll>(),
+                estimated: 136,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessageInvoice",
+                size: size_of::<MessageInvoice>(),
+                estimated: 184,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessageCall",
+                size: size_of::<MessageCall>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageBasicGroupChatCreate",
+                size: size_of::<MessageBasicGroupChatCreate>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageSupergroupChatCreate",
+                size: size_of::<MessageSupergroupChatCreate>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageChatChangeTitle",
+                size: size_of::<MessageChatChangeTitle>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageChatChangePhoto",
+                size: size_of::<MessageChatChangePhoto>(),
+                estimated: 64,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageChatDeletePhoto",
+                size: size_of::<MessageChatDeletePhoto>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageChatAddMembers",
+                size: size_of::<MessageChatAddMembers>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageChatJoinByLink",
+                size: size_of::<MessageChatJoinByLink>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageChatDeleteMember",
+                size: size_of::<MessageChatDeleteMember>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageChatUpgradeTo",
+                size: size_of::<MessageChatUpgradeTo>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageChatUpgradeFrom",
+                size: size_of::<MessageChatUpgradeFrom>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessagePinMessage",
+                size: size_of::<MessagePinMessage>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageScreenshotTaken",
+                size: size_of::<MessageScreenshotTaken>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageChatSetTtl",
+                size: size_of::<MessageChatSetTtl>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageCustomServiceAction",
+                size: size_of::<MessageCustomServiceAction>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageGameScore",
+                size: size_of::<MessageGameScore>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessagePaymentSuccessful",
+                size: size_of::<MessagePaymentSuccessful>(),
+                estimated: 40,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessagePaymentSuccessfulBot",
+                size: size_of::<MessagePaymentSuccessfulBot>(),
+                estimated: 352,
+                boxed: true,
+            },
+            VariantSize {
+                name: "MessageContactRegistered",
+                size: size_of::<MessageContactRegistered>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageWebsiteConnected",
+                size: size_of::<MessageWebsiteConnected>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessagePassportDataSent",
+                size: size_of::<MessagePassportDataSent>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessagePassportDataReceived",
+                size: size_of::<MessagePassportDataReceived>(),
+                estimated: 96,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageUnsupported",
+                size: size_of::<MessageUnsupported>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "TextEntityType",
+        size: size_of::<TextEntityType>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "TextEntityTypeMention",
+                size: size_of::<TextEntityTypeMention>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeHashtag",
+                size: size_of::<TextEntityTypeHashtag>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeCashtag",
+                size: size_of::<TextEntityTypeCashtag>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeBotCommand",
+                size: size_of::<TextEntityTypeBotCommand>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeUrl",
+                size: size_of::<TextEntityTypeUrl>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeEmailAddress",
+                size: size_of::<TextEntityTypeEmailAddress>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypePhoneNumber",
+                size: size_of::<TextEntityTypePhoneNumber>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeBankCardNumber",
+                size: size_of::<TextEntityTypeBankCardNumber>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeBold",
+                size: size_of::<TextEntityTypeBold>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeItalic",
+                size: size_of::<TextEntityTypeItalic>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeUnderline",
+                size: size_of::<TextEntityTypeUnderline>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeStrikethrough",
+                size: size_of::<TextEntityTypeStrikethrough>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeCode",
+                size: size_of::<TextEntityTypeCode>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypePre",
+                size: size_of::<TextEntityTypePre>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypePreCode",
+                size: size_of::<TextEntityTypePreCode>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeTextUrl",
+                size: size_of::<TextEntityTypeTextUrl>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityTypeMentionName",
+                size: size_of::<TextEntityTypeMentionName>(),
+                estimated: 4,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(any(
+        feature = "bots",
+        feature = "calls",
+        feature = "chats",
+        feature = "messages"
+    ))]
+    EnumSize {
+        name: "MessageSchedulingState",
+        size: size_of::<MessageSchedulingState>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "MessageSchedulingStateSendAtDate",
+                size: size_of::<MessageSchedulingStateSendAtDate>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageSchedulingStateSendWhenOnline",
+                size: size_of::<MessageSchedulingStateSendWhenOnline>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+    EnumSize {
+        name: "InputMessageContent",
+        size: size_of::<InputMessageContent>(),
+        estimated: 136,
+        variants: &[
+            VariantSize {
+                name: "InputMessageText",
+                size: size_of::<InputMessageText>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputMessageAnimation",
+                size: size_of::<InputMessageAnimation>(),
+                estimated: 184,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputMessageAudio",
+                size: size_of::<InputMessageAudio>(),
+                estimated: 224,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputMessageDocument",
+                size: size_of::<InputMessageDocument>(),
+                estimated: 168,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputMessagePhoto",
+                size: size_of::<InputMessagePhoto>(),
+                estimated: 208,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputMessageSticker",
+                size: size_of::<InputMessageSticker>(),
+                estimated: 128,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputMessageVideo",
+                size: size_of::<InputMessageVideo>(),
+                estimated: 216,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputMessageVideoNote",
+                size: size_of::<InputMessageVideoNote>(),
+                estimated: 128,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputMessageVoiceNote",
+                size: size_of::<InputMessageVoiceNote>(),
+                estimated: 136,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputMessageLocation",
+                size: size_of::<InputMessageLocation>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputMessageVenue",
+                size: size_of::<InputMessageVenue>(),
+                estimated: 136,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputMessageContact",
+                size: size_of::<InputMessageContact>(),
+                estimated: 104,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputMessageDice",
+                size: size_of::<InputMessageDice>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputMessageGame",
+                size: size_of::<InputMessageGame>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputMessageInvoice",
+                size: size_of::<InputMessageInvoice>(),
+                estimated: 240,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputMessagePoll",
+                size: size_of::<InputMessagePoll>(),
+                estimated: 104,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputMessageForwarded",
+                size: size_of::<InputMessageForwarded>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "messages")]
+    EnumSize {
+        name: "SearchMessagesFilter",
+        size: size_of::<SearchMessagesFilter>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "SearchMessagesFilterEmpty",
+                size: size_of::<SearchMessagesFilterEmpty>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterAnimation",
+                size: size_of::<SearchMessagesFilterAnimation>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterAudio",
+                size: size_of::<SearchMessagesFilterAudio>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterDocument",
+                size: size_of::<SearchMessagesFilterDocument>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterPhoto",
+                size: size_of::<SearchMessagesFilterPhoto>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterVideo",
+                size: size_of::<SearchMessagesFilterVideo>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterVoiceNote",
+                size: size_of::<SearchMessagesFilterVoiceNote>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterPhotoAndVideo",
+                size: size_of::<SearchMessagesFilterPhotoAndVideo>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterUrl",
+                size: size_of::<SearchMessagesFilterUrl>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterChatPhoto",
+                size: size_of::<SearchMessagesFilterChatPhoto>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterCall",
+                size: size_of::<SearchMessagesFilterCall>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterMissedCall",
+                size: size_of::<SearchMessagesFilterMissedCall>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterVideoNote",
+                size: size_of::<SearchMessagesFilterVideoNote>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterVoiceAndVideoNote",
+                size: size_of::<SearchMessagesFilterVoiceAndVideoNote>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterMention",
+                size: size_of::<SearchMessagesFilterMention>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "SearchMessagesFilterUnreadMention",
+                size: size_of::<SearchMessagesFilterUnreadMention>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "chats")]
+    EnumSize {
+        name: "ChatAction",
+        size: size_of::<ChatAction>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "ChatActionTyping",
+                size: size_of::<ChatActionTyping>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionRecordingVideo",
+                size: size_of::<ChatActionRecordingVideo>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionUploadingVideo",
+                size: size_of::<ChatActionUploadingVideo>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionRecordingVoiceNote",
+                size: size_of::<ChatActionRecordingVoiceNote>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionUploadingVoiceNote",
+                size: size_of::<ChatActionUploadingVoiceNote>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionUploadingPhoto",
+                size: size_of::<ChatActionUploadingPhoto>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionUploadingDocument",
+                size: size_of::<ChatActionUploadingDocument>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionChoosingLocation",
+                size: size_of::<ChatActionChoosingLocation>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionChoosingContact",
+                size: size_of::<ChatActionChoosingContact>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionStartPlayingGame",
+                size: size_of::<ChatActionStartPlayingGame>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionRecordingVideoNote",
+                size: size_of::<ChatActionRecordingVideoNote>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionUploadingVideoNote",
+                size: size_of::<ChatActionUploadingVideoNote>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatActionCancel",
+                size: size_of::<ChatActionCancel>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "UserStatus",
+        size: size_of::<UserStatus>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "UserStatusEmpty",
+                size: size_of::<UserStatusEmpty>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserStatusOnline",
+                size: size_of::<UserStatusOnline>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserStatusOffline",
+                size: size_of::<UserStatusOffline>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserStatusRecently",
+                size: size_of::<UserStatusRecently>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserStatusLastWeek",
+                size: size_of::<UserStatusLastWeek>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserStatusLastMonth",
+                size: size_of::<UserStatusLastMonth>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "CallDiscardReason",
+        size: size_of::<CallDiscardReason>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "CallDiscardReasonEmpty",
+                size: size_of::<CallDiscardReasonEmpty>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallDiscardReasonMissed",
+                size: size_of::<CallDiscardReasonMissed>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallDiscardReasonDeclined",
+                size: size_of::<CallDiscardReasonDeclined>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallDiscardReasonDisconnected",
+                size: size_of::<CallDiscardReasonDisconnected>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallDiscardReasonHungUp",
+                size: size_of::<CallDiscardReasonHungUp>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "calls")]
+    EnumSize {
+        name: "CallState",
+        size: size_of::<CallState>(),
+        estimated: 56,
+        variants: &[
+            VariantSize {
+                name: "CallStatePending",
+                size: size_of::<CallStatePending>(),
+                estimated: 2,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallStateExchangingKeys",
+                size: size_of::<CallStateExchangingKeys>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallStateReady",
+                size: size_of::<CallStateReady>(),
+                estimated: 144,
+                boxed: true,
+            },
+            VariantSize {
+                name: "CallStateHangingUp",
+                size: size_of::<CallStateHangingUp>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallStateDiscarded",
+                size: size_of::<CallStateDiscarded>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallStateError",
+                size: size_of::<CallStateError>(),
+                estimated: 32,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "calls")]
+    EnumSize {
+        name: "CallProblem",
+        size: size_of::<CallProblem>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "CallProblemEcho",
+                size: size_of::<CallProblemEcho>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallProblemNoise",
+                size: size_of::<CallProblemNoise>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallProblemInterruptions",
+                size: size_of::<CallProblemInterruptions>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallProblemDistortedSpeech",
+                size: size_of::<CallProblemDistortedSpeech>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallProblemSilentLocal",
+                size: size_of::<CallProblemSilentLocal>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallProblemSilentRemote",
+                size: size_of::<CallProblemSilentRemote>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallProblemDropped",
+                size: size_of::<CallProblemDropped>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "bots")]
+    EnumSize {
+        name: "InputInlineQueryResult",
+        size: size_of::<InputInlineQueryResult>(),
+        estimated: 96,
+        variants: &[
+            VariantSize {
+                name: "InputInlineQueryResultAnimatedGif",
+                size: size_of::<InputInlineQueryResultAnimatedGif>(),
+                estimated: 296,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputInlineQueryResultAnimatedMpeg4",
+                size: size_of::<InputInlineQueryResultAnimatedMpeg4>(),
+                estimated: 296,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputInlineQueryResultArticle",
+                size: size_of::<InputInlineQueryResultArticle>(),
+                estimated: 320,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputInlineQueryResultAudio",
+                size: size_of::<InputInlineQueryResultAudio>(),
+                estimated: 288,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputInlineQueryResultContact",
+                size: size_of::<InputInlineQueryResultContact>(),
+                estimated: 344,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputInlineQueryResultDocument",
+                size: size_of::<InputInlineQueryResultDocument>(),
+                estimated: 336,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputInlineQueryResultGame",
+                size: size_of::<InputInlineQueryResultGame>(),
+                estimated: 96,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputInlineQueryResultLocation",
+                size: size_of::<InputInlineQueryResultLocation>(),
+                estimated: 288,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputInlineQueryResultPhoto",
+                size: size_of::<InputInlineQueryResultPhoto>(),
+                estimated: 312,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputInlineQueryResultSticker",
+                size: size_of::<InputInlineQueryResultSticker>(),
+                estimated: 264,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputInlineQueryResultVenue",
+                size: size_of::<InputInlineQueryResultVenue>(),
+                estimated: 376,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputInlineQueryResultVideo",
+                size: size_of::<InputInlineQueryResultVideo>(),
+                estimated: 344,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputInlineQueryResultVoiceNote",
+                size: size_of::<InputInlineQueryResultVoiceNote>(),
+                estimated: 264,
+                boxed: true,
+            },
+        ],
+    },
+    #[cfg(feature = "bots")]
+    EnumSize {
+        name: "InlineQueryResult",
+        size: size_of::<InlineQueryResult>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "InlineQueryResultArticle",
+                size: size_of::<InlineQueryResultArticle>(),
+                estimated: 248,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InlineQueryResultContact",
+                size: size_of::<InlineQueryResultContact>(),
+                estimated: 272,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InlineQueryResultLocation",
+                size: size_of::<InlineQueryResultLocation>(),
+                estimated: 208,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InlineQueryResultVenue",
+                size: size_of::<InlineQueryResultVenue>(),
+                estimated: 304,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InlineQueryResultGame",
+                size: size_of::<InlineQueryResultGame>(),
+                estimated: 568,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InlineQueryResultAnimation",
+                size: size_of::<InlineQueryResultAnimation>(),
+                estimated: 400,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InlineQueryResultAudio",
+                size: size_of::<InlineQueryResultAudio>(),
+                estimated: 416,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InlineQueryResultDocument",
+                size: size_of::<InlineQueryResultDocument>(),
+                estimated: 408,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InlineQueryResultPhoto",
+                size: size_of::<InlineQueryResultPhoto>(),
+                estimated: 136,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InlineQueryResultSticker",
+                size: size_of::<InlineQueryResultSticker>(),
+                estimated: 400,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InlineQueryResultVideo",
+                size: size_of::<InlineQueryResultVideo>(),
+                estimated: 424,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InlineQueryResultVoiceNote",
+                size: size_of::<InlineQueryResultVoiceNote>(),
+                estimated: 216,
+                boxed: true,
+            },
+        ],
+    },
+    #[cfg(feature = "bots")]
+    EnumSize {
+        name: "CallbackQueryPayload",
+        size: size_of::<CallbackQueryPayload>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "CallbackQueryPayloadData",
+                size: size_of::<CallbackQueryPayloadData>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallbackQueryPayloadGame",
+                size: size_of::<CallbackQueryPayloadGame>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "chats")]
+    EnumSize {
+        name: "ChatEventAction",
+        size: size_of::<ChatEventAction>(),
+        estimated: 128,
+        variants: &[
+            VariantSize {
+                name: "ChatEventMessageEdited",
+                size: size_of::<ChatEventMessageEdited>(),
+                estimated: 912,
+                boxed: true,
+            },
+            VariantSize {
+                name: "ChatEventMessageDeleted",
+                size: size_of::<ChatEventMessageDeleted>(),
+                estimated: 456,
+                boxed: true,
+            },
+            VariantSize {
+                name: "ChatEventPollStopped",
+                size: size_of::<ChatEventPollStopped>(),
+                estimated: 456,
+                boxed: true,
+            },
+            VariantSize {
+                name: "ChatEventMessagePinned",
+                size: size_of::<ChatEventMessagePinned>(),
+                estimated: 456,
+                boxed: true,
+            },
+            VariantSize {
+                name: "ChatEventMessageUnpinned",
+                size: size_of::<ChatEventMessageUnpinned>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventMemberJoined",
+                size: size_of::<ChatEventMemberJoined>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventMemberLeft",
+                size: size_of::<ChatEventMemberLeft>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventMemberInvited",
+                size: size_of::<ChatEventMemberInvited>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventMemberPromoted",
+                size: size_of::<ChatEventMemberPromoted>(),
+                estimated: 104,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventMemberRestricted",
+                size: size_of::<ChatEventMemberRestricted>(),
+                estimated: 104,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventTitleChanged",
+                size: size_of::<ChatEventTitleChanged>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventPermissionsChanged",
+                size: size_of::<ChatEventPermissionsChanged>(),
+                estimated: 16,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventDescriptionChanged",
+                size: size_of::<ChatEventDescriptionChanged>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventUsernameChanged",
+                size: size_of::<ChatEventUsernameChanged>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventPhotoChanged",
+                size: size_of::<ChatEventPhotoChanged>(),
+                estimated: 128,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventInvitesToggled",
+                size: size_of::<ChatEventInvitesToggled>(),
+                estimated: 1,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventLinkedChatChanged",
+                size: size_of::<ChatEventLinkedChatChanged>(),
+                estimated: 16,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventSlowModeDelayChanged",
+                size: size_of::<ChatEventSlowModeDelayChanged>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventSignMessagesToggled",
+                size: size_of::<ChatEventSignMessagesToggled>(),
+                estimated: 1,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventStickerSetChanged",
+                size: size_of::<ChatEventStickerSetChanged>(),
+                estimated: 16,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventLocationChanged",
+                size: size_of::<ChatEventLocationChanged>(),
+                estimated: 80,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatEventIsAllHistoryAvailableToggled",
+                size: size_of::<ChatEventIsAllHistoryAvailableToggled>(),
+                estimated: 1,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "LanguagePackStringValue",
+        size: size_of::<LanguagePackStringValue>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "LanguagePackStringValueOrdinary",
+                size: size_of::<LanguagePackStringValueOrdinary>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "LanguagePackStringValuePluralized",
+                size: size_of::<LanguagePackStringValuePluralized>(),
+                estimated: 144,
+                boxed: true,
+            },
+            VariantSize {
+                name: "LanguagePackStringValueDeleted",
+                size: size_of::<LanguagePackStringValueDeleted>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "DeviceToken",
+        size: size_of::<DeviceToken>(),
+        estimated: 72,
+        variants: &[
+            VariantSize {
+                name: "DeviceTokenFirebaseCloudMessaging",
+                size: size_of::<DeviceTokenFirebaseCloudMessaging>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DeviceTokenApplePush",
+                size: size_of::<DeviceTokenApplePush>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DeviceTokenApplePushVoIP",
+                size: size_of::<DeviceTokenApplePushVoIP>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DeviceTokenWindowsPush",
+                size: size_of::<DeviceTokenWindowsPush>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DeviceTokenMicrosoftPush",
+                size: size_of::<DeviceTokenMicrosoftPush>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DeviceTokenMicrosoftPushVoIP",
+                size: size_of::<DeviceTokenMicrosoftPushVoIP>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DeviceTokenWebPush",
+                size: size_of::<DeviceTokenWebPush>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DeviceTokenSimplePush",
+                size: size_of::<DeviceTokenSimplePush>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DeviceTokenUbuntuPush",
+                size: size_of::<DeviceTokenUbuntuPush>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DeviceTokenBlackBerryPush",
+                size: size_of::<DeviceTokenBlackBerryPush>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DeviceTokenTizenPush",
+                size: size_of::<DeviceTokenTizenPush>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "BackgroundFill",
+        size: size_of::<BackgroundFill>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "BackgroundFillSolid",
+                size: size_of::<BackgroundFillSolid>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "BackgroundFillGradient",
+                size: size_of::<BackgroundFillGradient>(),
+                estimated: 12,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "BackgroundType",
+        size: size_of::<BackgroundType>(),
+        estimated: 56,
+        variants: &[
+            VariantSize {
+                name: "BackgroundTypeWallpaper",
+                size: size_of::<BackgroundTypeWallpaper>(),
+                estimated: 2,
+                boxed: false,
+            },
+            VariantSize {
+                name: "BackgroundTypePattern",
+                size: size_of::<BackgroundTypePattern>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "BackgroundTypeFill",
+                size: size_of::<BackgroundTypeFill>(),
+                estimated: 48,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "InputBackground",
+        size: size_of::<InputBackground>(),
+        estimated: 56,
+        variants: &[
+            VariantSize {
+                name: "InputBackgroundLocal",
+                size: size_of::<InputBackgroundLocal>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputBackgroundRemote",
+                size: size_of::<InputBackgroundRemote>(),
+                estimated: 8,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "CanTransferOwnershipResult",
+        size: size_of::<CanTransferOwnershipResult>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "CanTransferOwnershipResultOk",
+                size: size_of::<CanTransferOwnershipResultOk>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CanTransferOwnershipResultPasswordNeeded",
+                size: size_of::<CanTransferOwnershipResultPasswordNeeded>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CanTransferOwnershipResultPasswordTooFresh",
+                size: size_of::<CanTransferOwnershipResultPasswordTooFresh>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CanTransferOwnershipResultSessionTooFresh",
+                size: size_of::<CanTransferOwnershipResultSessionTooFresh>(),
+                estimated: 4,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "chats")]
+    EnumSize {
+        name: "CheckChatUsernameResult",
+        size: size_of::<CheckChatUsernameResult>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "CheckChatUsernameResultOk",
+                size: size_of::<CheckChatUsernameResultOk>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CheckChatUsernameResultUsernameInvalid",
+                size: size_of::<CheckChatUsernameResultUsernameInvalid>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CheckChatUsernameResultUsernameOccupied",
+                size: size_of::<CheckChatUsernameResultUsernameOccupied>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CheckChatUsernameResultPublicChatsTooMuch",
+                size: size_of::<CheckChatUsernameResultPublicChatsTooMuch>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CheckChatUsernameResultPublicGroupsUnavailable",
+                size: size_of::<CheckChatUsernameResultPublicGroupsUnavailable>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "messages")]
+    EnumSize {
+        name: "PushMessageContent",
+        size: size_of::<PushMessageContent>(),
+        estimated: 96,
+        variants: &[
+            VariantSize {
+                name: "PushMessageContentHidden",
+                size: size_of::<PushMessageContentHidden>(),
+                estimated: 1,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentAnimation",
+                size: size_of::<PushMessageContentAnimation>(),
+                estimated: 384,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PushMessageContentAudio",
+                size: size_of::<PushMessageContentAudio>(),
+                estimated: 400,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PushMessageContentContact",
+                size: size_of::<PushMessageContentContact>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentContactRegistered",
+                size: size_of::<PushMessageContentContactRegistered>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentDocument",
+                size: size_of::<PushMessageContentDocument>(),
+                estimated: 344,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PushMessageContentGame",
+                size: size_of::<PushMessageContentGame>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentGameScore",
+                size: size_of::<PushMessageContentGameScore>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentInvoice",
+                size: size_of::<PushMessageContentInvoice>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentLocation",
+                size: size_of::<PushMessageContentLocation>(),
+                estimated: 2,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentPhoto",
+                size: size_of::<PushMessageContentPhoto>(),
+                estimated: 96,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentPoll",
+                size: size_of::<PushMessageContentPoll>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentScreenshotTaken",
+                size: size_of::<PushMessageContentScreenshotTaken>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentSticker",
+                size: size_of::<PushMessageContentSticker>(),
+                estimated: 408,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PushMessageContentText",
+                size: size_of::<PushMessageContentText>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentVideo",
+                size: size_of::<PushMessageContentVideo>(),
+                estimated: 384,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PushMessageContentVideoNote",
+                size: size_of::<PushMessageContentVideoNote>(),
+                estimated: 304,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PushMessageContentVoiceNote",
+                size: size_of::<PushMessageContentVoiceNote>(),
+                estimated: 176,
+                boxed: true,
+            },
+            VariantSize {
+                name: "PushMessageContentBasicGroupChatCreate",
+                size: size_of::<PushMessageContentBasicGroupChatCreate>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentChatAddMembers",
+                size: size_of::<PushMessageContentChatAddMembers>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentChatChangePhoto",
+                size: size_of::<PushMessageContentChatChangePhoto>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentChatChangeTitle",
+                size: size_of::<PushMessageContentChatChangeTitle>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentChatDeleteMember",
+                size: size_of::<PushMessageContentChatDeleteMember>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentChatJoinByLink",
+                size: size_of::<PushMessageContentChatJoinByLink>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentMessageForwards",
+                size: size_of::<PushMessageContentMessageForwards>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushMessageContentMediaAlbum",
+                size: size_of::<PushMessageContentMediaAlbum>(),
+                estimated: 8,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "NotificationType",
+        size: size_of::<NotificationType>(),
+        estimated: 48,
+        variants: &[
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "NotificationTypeNewMessage",
+                size: size_of::<NotificationTypeNewMessage>(),
+                estimated: 456,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "NotificationTypeNewSecretChat",
+                size: size_of::<NotificationTypeNewSecretChat>(),
+                estimated: 0,
+                boxed: false,
+            },
+            #[cfg(feature = "calls")]
+            VariantSize {
+                name: "NotificationTypeNewCall",
+                size: size_of::<NotificationTypeNewCall>(),
+                estimated: 4,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "NotificationTypeNewPushMessage",
+                size: size_of::<NotificationTypeNewPushMessage>(),
+                estimated: 136,
+                boxed: true,
+            },
+        ],
+    },
+    EnumSize {
+        name: "NotificationGroupType",
+        size: size_of::<NotificationGroupType>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "NotificationGroupTypeMessages",
+                size: size_of::<NotificationGroupTypeMessages>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NotificationGroupTypeMentions",
+                size: size_of::<NotificationGroupTypeMentions>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NotificationGroupTypeSecretChat",
+                size: size_of::<NotificationGroupTypeSecretChat>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NotificationGroupTypeCalls",
+                size: size_of::<NotificationGroupTypeCalls>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "OptionValue",
+        size: size_of::<OptionValue>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "OptionValueBoolean",
+                size: size_of::<OptionValueBoolean>(),
+                estimated: 1,
+                boxed: false,
+            },
+            VariantSize {
+                name: "OptionValueEmpty",
+                size: size_of::<OptionValueEmpty>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "OptionValueInteger",
+                size: size_of::<OptionValueInteger>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "OptionValueString",
+                size: size_of::<OptionValueString>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "JsonValue",
+        size: size_of::<JsonValue>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "JsonValueNull",
+                size: size_of::<JsonValueNull>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "JsonValueBoolean",
+                size: size_of::<JsonValueBoolean>(),
+                estimated: 1,
+                boxed: false,
+            },
+            VariantSize {
+                name: "JsonValueNumber",
+                size: size_of::<JsonValueNumber>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "JsonValueString",
+                size: size_of::<JsonValueString>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "JsonValueArray",
+                size: size_of::<JsonValueArray>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "JsonValueObject",
+                size: size_of::<JsonValueObject>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "UserPrivacySettingRule",
+        size: size_of::<UserPrivacySettingRule>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "UserPrivacySettingRuleAllowAll",
+                size: size_of::<UserPrivacySettingRuleAllowAll>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingRuleAllowContacts",
+                size: size_of::<UserPrivacySettingRuleAllowContacts>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingRuleAllowUsers",
+                size: size_of::<UserPrivacySettingRuleAllowUsers>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingRuleAllowChatMembers",
+                size: size_of::<UserPrivacySettingRuleAllowChatMembers>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingRuleRestrictAll",
+                size: size_of::<UserPrivacySettingRuleRestrictAll>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingRuleRestrictContacts",
+                size: size_of::<UserPrivacySettingRuleRestrictContacts>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingRuleRestrictUsers",
+                size: size_of::<UserPrivacySettingRuleRestrictUsers>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingRuleRestrictChatMembers",
+                size: size_of::<UserPrivacySettingRuleRestrictChatMembers>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "UserPrivacySetting",
+        size: size_of::<UserPrivacySetting>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "UserPrivacySettingShowStatus",
+                size: size_of::<UserPrivacySettingShowStatus>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingShowProfilePhoto",
+                size: size_of::<UserPrivacySettingShowProfilePhoto>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingShowLinkInForwardedMessages",
+                size: size_of::<UserPrivacySettingShowLinkInForwardedMessages>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingShowPhoneNumber",
+                size: size_of::<UserPrivacySettingShowPhoneNumber>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingAllowChatInvites",
+                size: size_of::<UserPrivacySettingAllowChatInvites>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingAllowCalls",
+                size: size_of::<UserPrivacySettingAllowCalls>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingAllowPeerToPeerCalls",
+                size: size_of::<UserPrivacySettingAllowPeerToPeerCalls>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingAllowFindingByPhoneNumber",
+                size: size_of::<UserPrivacySettingAllowFindingByPhoneNumber>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "chats")]
+    EnumSize {
+        name: "ChatReportReason",
+        size: size_of::<ChatReportReason>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "ChatReportReasonSpam",
+                size: size_of::<ChatReportReasonSpam>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatReportReasonViolence",
+                size: size_of::<ChatReportReasonViolence>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatReportReasonPornography",
+                size: size_of::<ChatReportReasonPornography>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatReportReasonChildAbuse",
+                size: size_of::<ChatReportReasonChildAbuse>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatReportReasonCopyright",
+                size: size_of::<ChatReportReasonCopyright>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatReportReasonUnrelatedLocation",
+                size: size_of::<ChatReportReasonUnrelatedLocation>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatReportReasonCustom",
+                size: size_of::<ChatReportReasonCustom>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "files")]
+    EnumSize {
+        name: "FileType",
+        size: size_of::<FileType>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "FileTypeNone",
+                size: size_of::<FileTypeNone>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeAnimation",
+                size: size_of::<FileTypeAnimation>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeAudio",
+                size: size_of::<FileTypeAudio>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeDocument",
+                size: size_of::<FileTypeDocument>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypePhoto",
+                size: size_of::<FileTypePhoto>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeProfilePhoto",
+                size: size_of::<FileTypeProfilePhoto>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeSecret",
+                size: size_of::<FileTypeSecret>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeSecretThumbnail",
+                size: size_of::<FileTypeSecretThumbnail>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeSecure",
+                size: size_of::<FileTypeSecure>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeSticker",
+                size: size_of::<FileTypeSticker>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeThumbnail",
+                size: size_of::<FileTypeThumbnail>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeUnknown",
+                size: size_of::<FileTypeUnknown>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeVideo",
+                size: size_of::<FileTypeVideo>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeVideoNote",
+                size: size_of::<FileTypeVideoNote>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeVoiceNote",
+                size: size_of::<FileTypeVoiceNote>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FileTypeWallpaper",
+                size: size_of::<FileTypeWallpaper>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "NetworkType",
+        size: size_of::<NetworkType>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "NetworkTypeNone",
+                size: size_of::<NetworkTypeNone>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NetworkTypeMobile",
+                size: size_of::<NetworkTypeMobile>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NetworkTypeMobileRoaming",
+                size: size_of::<NetworkTypeMobileRoaming>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NetworkTypeWiFi",
+                size: size_of::<NetworkTypeWiFi>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NetworkTypeOther",
+                size: size_of::<NetworkTypeOther>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "files")]
+    EnumSize {
+        name: "NetworkStatisticsEntry",
+        size: size_of::<NetworkStatisticsEntry>(),
+        estimated: 112,
+        variants: &[
+            VariantSize {
+                name: "NetworkStatisticsEntryFile",
+                size: size_of::<NetworkStatisticsEntryFile>(),
+                estimated: 112,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NetworkStatisticsEntryCall",
+                size: size_of::<NetworkStatisticsEntryCall>(),
+                estimated: 72,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "ConnectionState",
+        size: size_of::<ConnectionState>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "ConnectionStateWaitingForNetwork",
+                size: size_of::<ConnectionStateWaitingForNetwork>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ConnectionStateConnectingToProxy",
+                size: size_of::<ConnectionStateConnectingToProxy>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ConnectionStateConnecting",
+                size: size_of::<ConnectionStateConnecting>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ConnectionStateUpdating",
+                size: size_of::<ConnectionStateUpdating>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ConnectionStateReady",
+                size: size_of::<ConnectionStateReady>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "chats")]
+    EnumSize {
+        name: "TopChatCategory",
+        size: size_of::<TopChatCategory>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "TopChatCategoryUsers",
+                size: size_of::<TopChatCategoryUsers>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TopChatCategoryBots",
+                size: size_of::<TopChatCategoryBots>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TopChatCategoryGroups",
+                size: size_of::<TopChatCategoryGroups>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TopChatCategoryChannels",
+                size: size_of::<TopChatCategoryChannels>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TopChatCategoryInlineBots",
+                size: size_of::<TopChatCategoryInlineBots>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TopChatCategoryCalls",
+                size: size_of::<TopChatCategoryCalls>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TopChatCategoryForwardChats",
+                size: size_of::<TopChatCategoryForwardChats>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "TMeUrlType",
+        size: size_of::<TMeUrlType>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "TMeUrlTypeUser",
+                size: size_of::<TMeUrlTypeUser>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TMeUrlTypeSupergroup",
+                size: size_of::<TMeUrlTypeSupergroup>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TMeUrlTypeChatInvite",
+                size: size_of::<TMeUrlTypeChatInvite>(),
+                estimated: 336,
+                boxed: true,
+            },
+            VariantSize {
+                name: "TMeUrlTypeStickerSet",
+                size: size_of::<TMeUrlTypeStickerSet>(),
+                estimated: 8,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "messages")]
+    EnumSize {
+        name: "TextParseMode",
+        size: size_of::<TextParseMode>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "TextParseModeMarkdown",
+                size: size_of::<TextParseModeMarkdown>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextParseModeHTML",
+                size: size_of::<TextParseModeHTML>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "proxy")]
+    EnumSize {
+        name: "ProxyType",
+        size: size_of::<ProxyType>(),
+        estimated: 56,
+        variants: &[
+            VariantSize {
+                name: "ProxyTypeSocks5",
+                size: size_of::<ProxyTypeSocks5>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ProxyTypeHttp",
+                size: size_of::<ProxyTypeHttp>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ProxyTypeMtproto",
+                size: size_of::<ProxyTypeMtproto>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "stickers")]
+    EnumSize {
+        name: "InputSticker",
+        size: size_of::<InputSticker>(),
+        estimated: 80,
+        variants: &[
+            VariantSize {
+                name: "InputStickerStatic",
+                size: size_of::<InputStickerStatic>(),
+                estimated: 152,
+                boxed: true,
+            },
+            VariantSize {
+                name: "InputStickerAnimated",
+                size: size_of::<InputStickerAnimated>(),
+                estimated: 80,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "StatisticsGraph",
+        size: size_of::<StatisticsGraph>(),
+        estimated: 56,
+        variants: &[
+            VariantSize {
+                name: "StatisticsGraphData",
+                size: size_of::<StatisticsGraphData>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "StatisticsGraphAsync",
+                size: size_of::<StatisticsGraphAsync>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "StatisticsGraphError",
+                size: size_of::<StatisticsGraphError>(),
+                estimated: 24,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "Update",
+        size: size_of::<Update>(),
+        estimated: 136,
+        variants: &[
+            VariantSize {
+                name: "UpdateAuthorizationState",
+                size: size_of::<UpdateAuthorizationState>(),
+                estimated: 128,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateNewMessage",
+                size: size_of::<UpdateNewMessage>(),
+                estimated: 456,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageSendAcknowledged",
+                size: size_of::<UpdateMessageSendAcknowledged>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageSendSucceeded",
+                size: size_of::<UpdateMessageSendSucceeded>(),
+                estimated: 464,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageSendFailed",
+                size: size_of::<UpdateMessageSendFailed>(),
+                estimated: 496,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageContent",
+                size: size_of::<UpdateMessageContent>(),
+                estimated: 136,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageEdited",
+                size: size_of::<UpdateMessageEdited>(),
+                estimated: 72,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageViews",
+                size: size_of::<UpdateMessageViews>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageContentOpened",
+                size: size_of::<UpdateMessageContentOpened>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageMentionRead",
+                size: size_of::<UpdateMessageMentionRead>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageLiveLocationViewed",
+                size: size_of::<UpdateMessageLiveLocationViewed>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateNewChat",
+                size: size_of::<UpdateNewChat>(),
+                estimated: 1136,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatChatList",
+                size: size_of::<UpdateChatChatList>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatTitle",
+                size: size_of::<UpdateChatTitle>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatPhoto",
+                size: size_of::<UpdateChatPhoto>(),
+                estimated: 232,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatPermissions",
+                size: size_of::<UpdateChatPermissions>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateChatLastMessage",
+                size: size_of::<UpdateChatLastMessage>(),
+                estimated: 472,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatOrder",
+                size: size_of::<UpdateChatOrder>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatIsPinned",
+                size: size_of::<UpdateChatIsPinned>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatIsMarkedAsUnread",
+                size: size_of::<UpdateChatIsMarkedAsUnread>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatIsSponsored",
+                size: size_of::<UpdateChatIsSponsored>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateChatHasScheduledMessages",
+                size: size_of::<UpdateChatHasScheduledMessages>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatDefaultDisableNotification",
+                size: size_of::<UpdateChatDefaultDisableNotification>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatReadInbox",
+                size: size_of::<UpdateChatReadInbox>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatReadOutbox",
+                size: size_of::<UpdateChatReadOutbox>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatUnreadMentionCount",
+                size: size_of::<UpdateChatUnreadMentionCount>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatNotificationSettings",
+                size: size_of::<UpdateChatNotificationSettings>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateScopeNotificationSettings",
+                size: size_of::<UpdateScopeNotificationSettings>(),
+                estimated: 80,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatActionBar",
+                size: size_of::<UpdateChatActionBar>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateChatPinnedMessage",
+                size: size_of::<UpdateChatPinnedMessage>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateChatReplyMarkup",
+                size: size_of::<UpdateChatReplyMarkup>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateChatDraftMessage",
+                size: size_of::<UpdateChatDraftMessage>(),
+                estimated: 168,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatOnlineMemberCount",
+                size: size_of::<UpdateChatOnlineMemberCount>(),
+                estimated: 16,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateNotification",
+                size: size_of::<UpdateNotification>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateNotificationGroup",
+                size: size_of::<UpdateNotificationGroup>(),
+                estimated: 128,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateActiveNotifications",
+                size: size_of::<UpdateActiveNotifications>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateHavePendingNotifications",
+                size: size_of::<UpdateHavePendingNotifications>(),
+                estimated: 2,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateDeleteMessages",
+                size: size_of::<UpdateDeleteMessages>(),
+                estimated: 40,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateUserChatAction",
+                size: size_of::<UpdateUserChatAction>(),
+                estimated: 64,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateUserStatus",
+                size: size_of::<UpdateUserStatus>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateUser",
+                size: size_of::<UpdateUser>(),
+                estimated: 488,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateBasicGroup",
+                size: size_of::<UpdateBasicGroup>(),
+                estimated: 64,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateSupergroup",
+                size: size_of::<UpdateSupergroup>(),
+                estimated: 120,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateSecretChat",
+                size: size_of::<UpdateSecretChat>(),
+                estimated: 96,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateUserFullInfo",
+                size: size_of::<UpdateUserFullInfo>(),
+                estimated: 112,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateBasicGroupFullInfo",
+                size: size_of::<UpdateBasicGroupFullInfo>(),
+                estimated: 88,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateSupergroupFullInfo",
+                size: size_of::<UpdateSupergroupFullInfo>(),
+                estimated: 160,
+                boxed: true,
+            },
+            VariantSize {
+                name: "UpdateServiceNotification",
+                size: size_of::<UpdateServiceNotification>(),
+                estimated: 144,
+                boxed: true,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "UpdateFile",
+                size: size_of::<UpdateFile>(),
+                estimated: 112,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "UpdateFileGenerationStart",
+                size: size_of::<UpdateFileGenerationStart>(),
+                estimated: 80,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "UpdateFileGenerationStop",
+                size: size_of::<UpdateFileGenerationStop>(),
+                estimated: 8,
+                boxed: false,
+            },
+            #[cfg(feature = "calls")]
+            VariantSize {
+                name: "UpdateCall",
+                size: size_of::<UpdateCall>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateUserPrivacySettingRules",
+                size: size_of::<UpdateUserPrivacySettingRules>(),
+                estimated: 72,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateUnreadMessageCount",
+                size: size_of::<UpdateUnreadMessageCount>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateUnreadChatCount",
+                size: size_of::<UpdateUnreadChatCount>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateOption",
+                size: size_of::<UpdateOption>(),
+                estimated: 72,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "UpdateInstalledStickerSets",
+                size: size_of::<UpdateInstalledStickerSets>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "UpdateTrendingStickerSets",
+                size: size_of::<UpdateTrendingStickerSets>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "UpdateRecentStickers",
+                size: size_of::<UpdateRecentStickers>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "UpdateFavoriteStickers",
+                size: size_of::<UpdateFavoriteStickers>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "UpdateSavedAnimations",
+                size: size_of::<UpdateSavedAnimations>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateSelectedBackground",
+                size: size_of::<UpdateSelectedBackground>(),
+                estimated: 440,
+                boxed: true,
+            },
+            VariantSize {
+                name: "UpdateLanguagePackStrings",
+                size: size_of::<UpdateLanguagePackStrings>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateConnectionState",
+                size: size_of::<UpdateConnectionState>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "auth")]
+            VariantSize {
+                name: "UpdateTermsOfService",
+                size: size_of::<UpdateTermsOfService>(),
+                estimated: 80,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateUsersNearby",
+                size: size_of::<UpdateUsersNearby>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateNewInlineQuery",
+                size: size_of::<UpdateNewInlineQuery>(),
+                estimated: 88,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateNewChosenInlineResult",
+                size: size_of::<UpdateNewChosenInlineResult>(),
+                estimated: 104,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateNewCallbackQuery",
+                size: size_of::<UpdateNewCallbackQuery>(),
+                estimated: 88,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateNewInlineCallbackQuery",
+                size: size_of::<UpdateNewInlineCallbackQuery>(),
+                estimated: 96,
+                boxed: false,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "UpdateNewShippingQuery",
+                size: size_of::<UpdateNewShippingQuery>(),
+                estimated: 184,
+                boxed: true,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "UpdateNewPreCheckoutQuery",
+                size: size_of::<UpdateNewPreCheckoutQuery>(),
+                estimated: 312,
+                boxed: true,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateNewCustomEvent",
+                size: size_of::<UpdateNewCustomEvent>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateNewCustomQuery",
+                size: size_of::<UpdateNewCustomQuery>(),
+                estimated: 40,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdatePoll",
+                size: size_of::<UpdatePoll>(),
+                estimated: 136,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdatePollAnswer",
+                size: size_of::<UpdatePollAnswer>(),
+                estimated: 40,
+                boxed: false,
+            },
+        ],
+    },
+    #[cfg(feature = "logging")]
+    EnumSize {
+        name: "LogStream",
+        size: size_of::<LogStream>(),
+        estimated: 48,
+        variants: &[
+            VariantSize {
+                name: "LogStreamDefault",
+                size: size_of::<LogStreamDefault>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "LogStreamFile",
+                size: size_of::<LogStreamFile>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "LogStreamEmpty",
+                size: size_of::<LogStreamEmpty>(),
+                estimated: 0,
+                boxed: false,
+            },
+        ],
+    },
+    EnumSize {
+        name: "Response",
+        size: size_of::<Response>(),
+        estimated: 136,
+        variants: &[
+            VariantSize {
+                name: "Error",
+                size: size_of::<Error>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Ok",
+                size: size_of::<Ok>(),
+                estimated: 0,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TdlibParameters",
+                size: size_of::<TdlibParameters>(),
+                estimated: 184,
+                boxed: true,
+            },
+            VariantSize {
+                name: "AuthenticationCodeInfo",
+                size: size_of::<AuthenticationCodeInfo>(),
+                estimated: 128,
+                boxed: false,
+            },
+            #[cfg(any(feature = "auth", feature = "passport"))]
+            VariantSize {
+                name: "EmailAddressAuthenticationCodeInfo",
+                size: size_of::<EmailAddressAuthenticationCodeInfo>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntity",
+                size: size_of::<TextEntity>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "TextEntities",
+                size: size_of::<TextEntities>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "FormattedText",
+                size: size_of::<FormattedText>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TermsOfService",
+                size: size_of::<TermsOfService>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "auth")]
+            VariantSize {
+                name: "PasswordState",
+                size: size_of::<PasswordState>(),
+                estimated: 64,
+                boxed: false,
+            },
+            #[cfg(feature = "auth")]
+            VariantSize {
+                name: "RecoveryEmailAddress",
+                size: size_of::<RecoveryEmailAddress>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "auth")]
+            VariantSize {
+                name: "TemporaryPasswordState",
+                size: size_of::<TemporaryPasswordState>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "LocalFile",
+                size: size_of::<LocalFile>(),
+                estimated: 40,
+                boxed: false,
+            },
+            VariantSize {
+                name: "RemoteFile",
+                size: size_of::<RemoteFile>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "File",
+                size: size_of::<File>(),
+                estimated: 112,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PhotoSize",
+                size: size_of::<PhotoSize>(),
+                estimated: 144,
+                boxed: true,
+            },
+            VariantSize {
+                name: "Minithumbnail",
+                size: size_of::<Minithumbnail>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MaskPosition",
+                size: size_of::<MaskPosition>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PollOption",
+                size: size_of::<PollOption>(),
+                estimated: 40,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Animation",
+                size: size_of::<Animation>(),
+                estimated: 352,
+                boxed: true,
+            },
+            VariantSize {
+                name: "Audio",
+                size: size_of::<Audio>(),
+                estimated: 392,
+                boxed: true,
+            },
+            VariantSize {
+                name: "Document",
+                size: size_of::<Document>(),
+                estimated: 336,
+                boxed: true,
+            },
+            VariantSize {
+                name: "Photo",
+                size: size_of::<Photo>(),
+                estimated: 64,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Sticker",
+                size: size_of::<Sticker>(),
+                estimated: 376,
+                boxed: true,
+            },
+            VariantSize {
+                name: "Video",
+                size: size_of::<Video>(),
+                estimated: 352,
+                boxed: true,
+            },
+            VariantSize {
+                name: "VideoNote",
+                size: size_of::<VideoNote>(),
+                estimated: 296,
+                boxed: true,
+            },
+            VariantSize {
+                name: "VoiceNote",
+                size: size_of::<VoiceNote>(),
+                estimated: 168,
+                boxed: true,
+            },
+            VariantSize {
+                name: "Contact",
+                size: size_of::<Contact>(),
+                estimated: 104,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Location",
+                size: size_of::<Location>(),
+                estimated: 16,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Venue",
+                size: size_of::<Venue>(),
+                estimated: 136,
+                boxed: true,
+            },
+            VariantSize {
+                name: "Game",
+                size: size_of::<Game>(),
+                estimated: 544,
+                boxed: true,
+            },
+            VariantSize {
+                name: "Poll",
+                size: size_of::<Poll>(),
+                estimated: 136,
+                boxed: true,
+            },
+            VariantSize {
+                name: "ProfilePhoto",
+                size: size_of::<ProfilePhoto>(),
+                estimated: 232,
+                boxed: true,
+            },
+            VariantSize {
+                name: "ChatPhoto",
+                size: size_of::<ChatPhoto>(),
+                estimated: 224,
+                boxed: true,
+            },
+            VariantSize {
+                name: "BotCommand",
+                size: size_of::<BotCommand>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "BotInfo",
+                size: size_of::<BotInfo>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatLocation",
+                size: size_of::<ChatLocation>(),
+                estimated: 40,
+                boxed: false,
+            },
+            VariantSize {
+                name: "User",
+                size: size_of::<User>(),
+                estimated: 488,
+                boxed: true,
+            },
+            VariantSize {
+                name: "UserFullInfo",
+                size: size_of::<UserFullInfo>(),
+                estimated: 104,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserProfilePhoto",
+                size: size_of::<UserProfilePhoto>(),
+                estimated: 40,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserProfilePhotos",
+                size: size_of::<UserProfilePhotos>(),
+                estimated: 32,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Users",
+                size: size_of::<Users>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatAdministrator",
+                size: size_of::<ChatAdministrator>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatAdministrators",
+                size: size_of::<ChatAdministrators>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatPermissions",
+                size: size_of::<ChatPermissions>(),
+                estimated: 8,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatMember",
+                size: size_of::<ChatMember>(),
+                estimated: 112,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatMembers",
+                size: size_of::<ChatMembers>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "BasicGroup",
+                size: size_of::<BasicGroup>(),
+                estimated: 64,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "BasicGroupFullInfo",
+                size: size_of::<BasicGroupFullInfo>(),
+                estimated: 80,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "Supergroup",
+                size: size_of::<Supergroup>(),
+                estimated: 120,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "SupergroupFullInfo",
+                size: size_of::<SupergroupFullInfo>(),
+                estimated: 152,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "SecretChat",
+                size: size_of::<SecretChat>(),
+                estimated: 96,
+                boxed: false,
+            },
+            #[cfg(any(
+                feature = "bots",
+                feature = "calls",
+                feature = "chats",
+                feature = "messages"
+            ))]
+            VariantSize {
+                name: "MessageForwardInfo",
+                size: size_of::<MessageForwardInfo>(),
+                estimated: 72,
+                boxed: false,
+            },
+            #[cfg(any(
+                feature = "bots",
+                feature = "calls",
+                feature = "chats",
+                feature = "messages"
+            ))]
+            VariantSize {
+                name: "Message",
+                size: size_of::<Message>(),
+                estimated: 456,
+                boxed: true,
+            },
+            #[cfg(any(feature = "calls", feature = "messages"))]
+            VariantSize {
+                name: "Messages",
+                size: size_of::<Messages>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "FoundMessages",
+                size: size_of::<FoundMessages>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatNotificationSettings",
+                size: size_of::<ChatNotificationSettings>(),
+                estimated: 40,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ScopeNotificationSettings",
+                size: size_of::<ScopeNotificationSettings>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(any(feature = "chats", feature = "messages"))]
+            VariantSize {
+                name: "DraftMessage",
+                size: size_of::<DraftMessage>(),
+                estimated: 152,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "Chat",
+                size: size_of::<Chat>(),
+                estimated: 1136,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "Chats",
+                size: size_of::<Chats>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatNearby",
+                size: size_of::<ChatNearby>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatsNearby",
+                size: size_of::<ChatsNearby>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatInviteLink",
+                size: size_of::<ChatInviteLink>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatInviteLinkInfo",
+                size: size_of::<ChatInviteLinkInfo>(),
+                estimated: 336,
+                boxed: true,
+            },
+            #[cfg(any(
+                feature = "bots",
+                feature = "calls",
+                feature = "chats",
+                feature = "messages"
+            ))]
+            VariantSize {
+                name: "KeyboardButton",
+                size: size_of::<KeyboardButton>(),
+                estimated: 72,
+                boxed: false,
+            },
+            #[cfg(any(
+                feature = "bots",
+                feature = "calls",
+                feature = "chats",
+                feature = "messages"
+            ))]
+            VariantSize {
+                name: "InlineKeyboardButton",
+                size: size_of::<InlineKeyboardButton>(),
+                estimated: 80,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "PageBlockCaption",
+                size: size_of::<PageBlockCaption>(),
+                estimated: 112,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "PageBlockListItem",
+                size: size_of::<PageBlockListItem>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "PageBlockTableCell",
+                size: size_of::<PageBlockTableCell>(),
+                estimated: 168,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "PageBlockRelatedArticle",
+                size: size_of::<PageBlockRelatedArticle>(),
+                estimated: 168,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "WebPageInstantView",
+                size: size_of::<WebPageInstantView>(),
+                estimated: 40,
+                boxed: false,
+            },
+            VariantSize {
+                name: "WebPage",
+                size: size_of::<WebPage>(),
+                estimated: 2592,
+                boxed: true,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "BankCardActionOpenUrl",
+                size: size_of::<BankCardActionOpenUrl>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "BankCardInfo",
+                size: size_of::<BankCardInfo>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Address",
+                size: size_of::<Address>(),
+                estimated: 144,
+                boxed: true,
+            },
+            #[cfg(any(
+                feature = "bots",
+                feature = "chats",
+                feature = "messages",
+                feature = "payments"
+            ))]
+            VariantSize {
+                name: "LabeledPricePart",
+                size: size_of::<LabeledPricePart>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(any(
+                feature = "bots",
+                feature = "chats",
+                feature = "messages",
+                feature = "payments"
+            ))]
+            VariantSize {
+                name: "Invoice",
+                size: size_of::<Invoice>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "OrderInfo",
+                size: size_of::<OrderInfo>(),
+                estimated: 216,
+                boxed: true,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "ShippingOption",
+                size: size_of::<ShippingOption>(),
+                estimated: 72,
+                boxed: false,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "SavedCredentials",
+                size: size_of::<SavedCredentials>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "PaymentsProviderStripe",
+                size: size_of::<PaymentsProviderStripe>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "PaymentForm",
+                size: size_of::<PaymentForm>(),
+                estimated: 384,
+                boxed: true,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "ValidatedOrderInfo",
+                size: size_of::<ValidatedOrderInfo>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "PaymentResult",
+                size: size_of::<PaymentResult>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "PaymentReceipt",
+                size: size_of::<PaymentReceipt>(),
+                estimated: 376,
+                boxed: true,
+            },
+            VariantSize {
+                name: "DatedFile",
+                size: size_of::<DatedFile>(),
+                estimated: 120,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "Date",
+                size: size_of::<Date>(),
+                estimated: 12,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "PersonalDetails",
+                size: size_of::<PersonalDetails>(),
+                estimated: 232,
+                boxed: true,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "IdentityDocument",
+                size: size_of::<IdentityDocument>(),
+                estimated: 424,
+                boxed: true,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "InputIdentityDocument",
+                size: size_of::<InputIdentityDocument>(),
+                estimated: 232,
+                boxed: true,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "PersonalDocument",
+                size: size_of::<PersonalDocument>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "InputPersonalDocument",
+                size: size_of::<InputPersonalDocument>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "PassportElements",
+                size: size_of::<PassportElements>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "PassportElementError",
+                size: size_of::<PassportElementError>(),
+                estimated: 120,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "PassportSuitableElement",
+                size: size_of::<PassportSuitableElement>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "PassportRequiredElement",
+                size: size_of::<PassportRequiredElement>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "PassportAuthorizationForm",
+                size: size_of::<PassportAuthorizationForm>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "PassportElementsWithErrors",
+                size: size_of::<PassportElementsWithErrors>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "EncryptedCredentials",
+                size: size_of::<EncryptedCredentials>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "EncryptedPassportElement",
+                size: size_of::<EncryptedPassportElement>(),
+                estimated: 528,
+                boxed: true,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "InputPassportElementError",
+                size: size_of::<InputPassportElementError>(),
+                estimated: 128,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputThumbnail",
+                size: size_of::<InputThumbnail>(),
+                estimated: 64,
+                boxed: false,
+            },
+            #[cfg(any(feature = "bots", feature = "messages"))]
+            VariantSize {
+                name: "SendMessageOptions",
+                size: size_of::<SendMessageOptions>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "Stickers",
+                size: size_of::<Stickers>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "Emojis",
+                size: size_of::<Emojis>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "StickerSet",
+                size: size_of::<StickerSet>(),
+                estimated: 256,
+                boxed: true,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "StickerSetInfo",
+                size: size_of::<StickerSetInfo>(),
+                estimated: 240,
+                boxed: true,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "StickerSets",
+                size: size_of::<StickerSets>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "calls")]
+            VariantSize {
+                name: "CallProtocol",
+                size: size_of::<CallProtocol>(),
+                estimated: 40,
+                boxed: false,
+            },
+            #[cfg(feature = "calls")]
+            VariantSize {
+                name: "CallConnection",
+                size: size_of::<CallConnection>(),
+                estimated: 88,
+                boxed: false,
+            },
+            #[cfg(feature = "calls")]
+            VariantSize {
+                name: "CallId",
+                size: size_of::<CallId>(),
+                estimated: 4,
+                boxed: false,
+            },
+            #[cfg(feature = "calls")]
+            VariantSize {
+                name: "Call",
+                size: size_of::<Call>(),
+                estimated: 72,
+                boxed: false,
+            },
+            #[cfg(any(feature = "auth", feature = "passport"))]
+            VariantSize {
+                name: "PhoneNumberAuthenticationSettings",
+                size: size_of::<PhoneNumberAuthenticationSettings>(),
+                estimated: 3,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Animations",
+                size: size_of::<Animations>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ImportedContacts",
+                size: size_of::<ImportedContacts>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "HttpUrl",
+                size: size_of::<HttpUrl>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "InlineQueryResults",
+                size: size_of::<InlineQueryResults>(),
+                estimated: 104,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "CallbackQueryAnswer",
+                size: size_of::<CallbackQueryAnswer>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "CustomRequestResult",
+                size: size_of::<CustomRequestResult>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "GameHighScore",
+                size: size_of::<GameHighScore>(),
+                estimated: 12,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "GameHighScores",
+                size: size_of::<GameHighScores>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatEvent",
+                size: size_of::<ChatEvent>(),
+                estimated: 144,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatEvents",
+                size: size_of::<ChatEvents>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatEventLogFilters",
+                size: size_of::<ChatEventLogFilters>(),
+                estimated: 10,
+                boxed: false,
+            },
+            VariantSize {
+                name: "LanguagePackString",
+                size: size_of::<LanguagePackString>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "LanguagePackStrings",
+                size: size_of::<LanguagePackStrings>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "LanguagePackInfo",
+                size: size_of::<LanguagePackInfo>(),
+                estimated: 160,
+                boxed: true,
+            },
+            VariantSize {
+                name: "LocalizationTargetInfo",
+                size: size_of::<LocalizationTargetInfo>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PushReceiverId",
+                size: size_of::<PushReceiverId>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Background",
+                size: size_of::<Background>(),
+                estimated: 432,
+                boxed: true,
+            },
+            VariantSize {
+                name: "Backgrounds",
+                size: size_of::<Backgrounds>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Hashtags",
+                size: size_of::<Hashtags>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Notification",
+                size: size_of::<Notification>(),
+                estimated: 64,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NotificationGroup",
+                size: size_of::<NotificationGroup>(),
+                estimated: 88,
+                boxed: false,
+            },
+            VariantSize {
+                name: "JsonObjectMember",
+                size: size_of::<JsonObjectMember>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingRules",
+                size: size_of::<UserPrivacySettingRules>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "auth")]
+            VariantSize {
+                name: "AccountTtl",
+                size: size_of::<AccountTtl>(),
+                estimated: 4,
+                boxed: false,
+            },
+            #[cfg(feature = "auth")]
+            VariantSize {
+                name: "Session",
+                size: size_of::<Session>(),
+                estimated: 216,
+                boxed: true,
+            },
+            #[cfg(feature = "auth")]
+            VariantSize {
+                name: "Sessions",
+                size: size_of::<Sessions>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "auth")]
+            VariantSize {
+                name: "ConnectedWebsite",
+                size: size_of::<ConnectedWebsite>(),
+                estimated: 144,
+                boxed: true,
+            },
+            #[cfg(feature = "auth")]
+            VariantSize {
+                name: "ConnectedWebsites",
+                size: size_of::<ConnectedWebsites>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "PublicMessageLink",
+                size: size_of::<PublicMessageLink>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "MessageLinkInfo",
+                size: size_of::<MessageLinkInfo>(),
+                estimated: 472,
+                boxed: true,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "FilePart",
+                size: size_of::<FilePart>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "StorageStatisticsByFileType",
+                size: size_of::<StorageStatisticsByFileType>(),
+                estimated: 64,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "StorageStatisticsByChat",
+                size: size_of::<StorageStatisticsByChat>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "StorageStatistics",
+                size: size_of::<StorageStatistics>(),
+                estimated: 40,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "StorageStatisticsFast",
+                size: size_of::<StorageStatisticsFast>(),
+                estimated: 40,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "DatabaseStatistics",
+                size: size_of::<DatabaseStatistics>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "NetworkStatistics",
+                size: size_of::<NetworkStatistics>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "AutoDownloadSettings",
+                size: size_of::<AutoDownloadSettings>(),
+                estimated: 20,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "AutoDownloadSettingsPresets",
+                size: size_of::<AutoDownloadSettingsPresets>(),
+                estimated: 60,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TMeUrl",
+                size: size_of::<TMeUrl>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TMeUrls",
+                size: size_of::<TMeUrls>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Count",
+                size: size_of::<Count>(),
+                estimated: 4,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Text",
+                size: size_of::<Text>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "Seconds",
+                size: size_of::<Seconds>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DeepLinkInfo",
+                size: size_of::<DeepLinkInfo>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "proxy")]
+            VariantSize {
+                name: "Proxy",
+                size: size_of::<Proxy>(),
+                estimated: 96,
+                boxed: false,
+            },
+            #[cfg(feature = "proxy")]
+            VariantSize {
+                name: "Proxies",
+                size: size_of::<Proxies>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DateRange",
+                size: size_of::<DateRange>(),
+                estimated: 8,
+                boxed: false,
+            },
+            VariantSize {
+                name: "StatisticsValue",
+                size: size_of::<StatisticsValue>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(any(feature = "chats", feature = "messages"))]
+            VariantSize {
+                name: "ChatStatisticsMessageInteractionCounters",
+                size: size_of::<ChatStatisticsMessageInteractionCounters>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatStatistics",
+                size: size_of::<ChatStatistics>(),
+                estimated: 616,
+                boxed: true,
+            },
+            VariantSize {
+                name: "Updates",
+                size: size_of::<Updates>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "logging")]
+            VariantSize {
+                name: "LogVerbosityLevel",
+                size: size_of::<LogVerbosityLevel>(),
+                estimated: 4,
+                boxed: false,
+            },
+            #[cfg(feature = "logging")]
+            VariantSize {
+                name: "LogTags",
+                size: size_of::<LogTags>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "tests")]
+            VariantSize {
+                name: "TestInt",
+                size: size_of::<TestInt>(),
+                estimated: 4,
+                boxed: false,
+            },
+            #[cfg(feature = "tests")]
+            VariantSize {
+                name: "TestString",
+                size: size_of::<TestString>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "tests")]
+            VariantSize {
+                name: "TestBytes",
+                size: size_of::<TestBytes>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "tests")]
+            VariantSize {
+                name: "TestVectorInt",
+                size: size_of::<TestVectorInt>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "tests")]
+            VariantSize {
+                name: "TestVectorIntObject",
+                size: size_of::<TestVectorIntObject>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "tests")]
+            VariantSize {
+                name: "TestVectorString",
+                size: size_of::<TestVectorString>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "tests")]
+            VariantSize {
+                name: "TestVectorStringObject",
+                size: size_of::<TestVectorStringObject>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateAuthorizationState",
+                size: size_of::<UpdateAuthorizationState>(),
+                estimated: 128,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateNewMessage",
+                size: size_of::<UpdateNewMessage>(),
+                estimated: 456,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageSendAcknowledged",
+                size: size_of::<UpdateMessageSendAcknowledged>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageSendSucceeded",
+                size: size_of::<UpdateMessageSendSucceeded>(),
+                estimated: 464,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageSendFailed",
+                size: size_of::<UpdateMessageSendFailed>(),
+                estimated: 496,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageContent",
+                size: size_of::<UpdateMessageContent>(),
+                estimated: 136,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageEdited",
+                size: size_of::<UpdateMessageEdited>(),
+                estimated: 72,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageViews",
+                size: size_of::<UpdateMessageViews>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageContentOpened",
+                size: size_of::<UpdateMessageContentOpened>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageMentionRead",
+                size: size_of::<UpdateMessageMentionRead>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateMessageLiveLocationViewed",
+                size: size_of::<UpdateMessageLiveLocationViewed>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateNewChat",
+                size: size_of::<UpdateNewChat>(),
+                estimated: 1136,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatChatList",
+                size: size_of::<UpdateChatChatList>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatTitle",
+                size: size_of::<UpdateChatTitle>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatPhoto",
+                size: size_of::<UpdateChatPhoto>(),
+                estimated: 232,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatPermissions",
+                size: size_of::<UpdateChatPermissions>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateChatLastMessage",
+                size: size_of::<UpdateChatLastMessage>(),
+                estimated: 472,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatOrder",
+                size: size_of::<UpdateChatOrder>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatIsPinned",
+                size: size_of::<UpdateChatIsPinned>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatIsMarkedAsUnread",
+                size: size_of::<UpdateChatIsMarkedAsUnread>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatIsSponsored",
+                size: size_of::<UpdateChatIsSponsored>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateChatHasScheduledMessages",
+                size: size_of::<UpdateChatHasScheduledMessages>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatDefaultDisableNotification",
+                size: size_of::<UpdateChatDefaultDisableNotification>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatReadInbox",
+                size: size_of::<UpdateChatReadInbox>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatReadOutbox",
+                size: size_of::<UpdateChatReadOutbox>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatUnreadMentionCount",
+                size: size_of::<UpdateChatUnreadMentionCount>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatNotificationSettings",
+                size: size_of::<UpdateChatNotificationSettings>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateScopeNotificationSettings",
+                size: size_of::<UpdateScopeNotificationSettings>(),
+                estimated: 80,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatActionBar",
+                size: size_of::<UpdateChatActionBar>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateChatPinnedMessage",
+                size: size_of::<UpdateChatPinnedMessage>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateChatReplyMarkup",
+                size: size_of::<UpdateChatReplyMarkup>(),
+                estimated: 16,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateChatDraftMessage",
+                size: size_of::<UpdateChatDraftMessage>(),
+                estimated: 168,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateChatOnlineMemberCount",
+                size: size_of::<UpdateChatOnlineMemberCount>(),
+                estimated: 16,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateNotification",
+                size: size_of::<UpdateNotification>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateNotificationGroup",
+                size: size_of::<UpdateNotificationGroup>(),
+                estimated: 128,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateActiveNotifications",
+                size: size_of::<UpdateActiveNotifications>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateHavePendingNotifications",
+                size: size_of::<UpdateHavePendingNotifications>(),
+                estimated: 2,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateDeleteMessages",
+                size: size_of::<UpdateDeleteMessages>(),
+                estimated: 40,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateUserChatAction",
+                size: size_of::<UpdateUserChatAction>(),
+                estimated: 64,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateUserStatus",
+                size: size_of::<UpdateUserStatus>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateUser",
+                size: size_of::<UpdateUser>(),
+                estimated: 488,
+                boxed: true,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateBasicGroup",
+                size: size_of::<UpdateBasicGroup>(),
+                estimated: 64,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateSupergroup",
+                size: size_of::<UpdateSupergroup>(),
+                estimated: 120,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateSecretChat",
+                size: size_of::<UpdateSecretChat>(),
+                estimated: 96,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateUserFullInfo",
+                size: size_of::<UpdateUserFullInfo>(),
+                estimated: 112,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateBasicGroupFullInfo",
+                size: size_of::<UpdateBasicGroupFullInfo>(),
+                estimated: 88,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateSupergroupFullInfo",
+                size: size_of::<UpdateSupergroupFullInfo>(),
+                estimated: 160,
+                boxed: true,
+            },
+            VariantSize {
+                name: "UpdateServiceNotification",
+                size: size_of::<UpdateServiceNotification>(),
+                estimated: 144,
+                boxed: true,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "UpdateFile",
+                size: size_of::<UpdateFile>(),
+                estimated: 112,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "UpdateFileGenerationStart",
+                size: size_of::<UpdateFileGenerationStart>(),
+                estimated: 80,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "UpdateFileGenerationStop",
+                size: size_of::<UpdateFileGenerationStop>(),
+                estimated: 8,
+                boxed: false,
+            },
+            #[cfg(feature = "calls")]
+            VariantSize {
+                name: "UpdateCall",
+                size: size_of::<UpdateCall>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateUserPrivacySettingRules",
+                size: size_of::<UpdateUserPrivacySettingRules>(),
+                estimated: 72,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdateUnreadMessageCount",
+                size: size_of::<UpdateUnreadMessageCount>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "UpdateUnreadChatCount",
+                size: size_of::<UpdateUnreadChatCount>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateOption",
+                size: size_of::<UpdateOption>(),
+                estimated: 72,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "UpdateInstalledStickerSets",
+                size: size_of::<UpdateInstalledStickerSets>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "UpdateTrendingStickerSets",
+                size: size_of::<UpdateTrendingStickerSets>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "UpdateRecentStickers",
+                size: size_of::<UpdateRecentStickers>(),
+                estimated: 32,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "UpdateFavoriteStickers",
+                size: size_of::<UpdateFavoriteStickers>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "UpdateSavedAnimations",
+                size: size_of::<UpdateSavedAnimations>(),
+                estimated: 24,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateSelectedBackground",
+                size: size_of::<UpdateSelectedBackground>(),
+                estimated: 440,
+                boxed: true,
+            },
+            VariantSize {
+                name: "UpdateLanguagePackStrings",
+                size: size_of::<UpdateLanguagePackStrings>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateConnectionState",
+                size: size_of::<UpdateConnectionState>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "auth")]
+            VariantSize {
+                name: "UpdateTermsOfService",
+                size: size_of::<UpdateTermsOfService>(),
+                estimated: 80,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UpdateUsersNearby",
+                size: size_of::<UpdateUsersNearby>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateNewInlineQuery",
+                size: size_of::<UpdateNewInlineQuery>(),
+                estimated: 88,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateNewChosenInlineResult",
+                size: size_of::<UpdateNewChosenInlineResult>(),
+                estimated: 104,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateNewCallbackQuery",
+                size: size_of::<UpdateNewCallbackQuery>(),
+                estimated: 88,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateNewInlineCallbackQuery",
+                size: size_of::<UpdateNewInlineCallbackQuery>(),
+                estimated: 96,
+                boxed: false,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "UpdateNewShippingQuery",
+                size: size_of::<UpdateNewShippingQuery>(),
+                estimated: 184,
+                boxed: true,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "UpdateNewPreCheckoutQuery",
+                size: size_of::<UpdateNewPreCheckoutQuery>(),
+                estimated: 312,
+                boxed: true,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateNewCustomEvent",
+                size: size_of::<UpdateNewCustomEvent>(),
+                estimated: 24,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "UpdateNewCustomQuery",
+                size: size_of::<UpdateNewCustomQuery>(),
+                estimated: 40,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdatePoll",
+                size: size_of::<UpdatePoll>(),
+                estimated: 136,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "UpdatePollAnswer",
+                size: size_of::<UpdatePollAnswer>(),
+                estimated: 40,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthenticationCodeType",
+                size: size_of::<AuthenticationCodeType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "AuthorizationState",
+                size: size_of::<AuthorizationState>(),
+                estimated: 128,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputFile",
+                size: size_of::<InputFile>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MaskPoint",
+                size: size_of::<MaskPoint>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PollType",
+                size: size_of::<PollType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserType",
+                size: size_of::<UserType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatMemberStatus",
+                size: size_of::<ChatMemberStatus>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatMembersFilter",
+                size: size_of::<ChatMembersFilter>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "SupergroupMembersFilter",
+                size: size_of::<SupergroupMembersFilter>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "SecretChatState",
+                size: size_of::<SecretChatState>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(any(
+                feature = "bots",
+                feature = "calls",
+                feature = "chats",
+                feature = "messages"
+            ))]
+            VariantSize {
+                name: "MessageForwardOrigin",
+                size: size_of::<MessageForwardOrigin>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(any(
+                feature = "bots",
+                feature = "calls",
+                feature = "chats",
+                feature = "messages"
+            ))]
+            VariantSize {
+                name: "MessageSendingState",
+                size: size_of::<MessageSendingState>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NotificationSettingsScope",
+                size: size_of::<NotificationSettingsScope>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ChatType",
+                size: size_of::<ChatType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(any(feature = "chats", feature = "messages"))]
+            VariantSize {
+                name: "ChatList",
+                size: size_of::<ChatList>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "PublicChatType",
+                size: size_of::<PublicChatType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatActionBar",
+                size: size_of::<ChatActionBar>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(any(
+                feature = "bots",
+                feature = "calls",
+                feature = "chats",
+                feature = "messages"
+            ))]
+            VariantSize {
+                name: "KeyboardButtonType",
+                size: size_of::<KeyboardButtonType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(any(
+                feature = "bots",
+                feature = "calls",
+                feature = "chats",
+                feature = "messages"
+            ))]
+            VariantSize {
+                name: "InlineKeyboardButtonType",
+                size: size_of::<InlineKeyboardButtonType>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(any(
+                feature = "bots",
+                feature = "calls",
+                feature = "chats",
+                feature = "messages"
+            ))]
+            VariantSize {
+                name: "ReplyMarkup",
+                size: size_of::<ReplyMarkup>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "LoginUrlInfo",
+                size: size_of::<LoginUrlInfo>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "RichText",
+                size: size_of::<RichText>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "PageBlockHorizontalAlignment",
+                size: size_of::<PageBlockHorizontalAlignment>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "PageBlockVerticalAlignment",
+                size: size_of::<PageBlockVerticalAlignment>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "PageBlock",
+                size: size_of::<PageBlock>(),
+                estimated: 120,
+                boxed: false,
+            },
+            #[cfg(feature = "payments")]
+            VariantSize {
+                name: "InputCredentials",
+                size: size_of::<InputCredentials>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "PassportElementType",
+                size: size_of::<PassportElementType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "PassportElement",
+                size: size_of::<PassportElement>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "InputPassportElement",
+                size: size_of::<InputPassportElement>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "PassportElementErrorSource",
+                size: size_of::<PassportElementErrorSource>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "passport")]
+            VariantSize {
+                name: "InputPassportElementErrorSource",
+                size: size_of::<InputPassportElementErrorSource>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "MessageContent",
+                size: size_of::<MessageContent>(),
+                estimated: 120,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TextEntityType",
+                size: size_of::<TextEntityType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(any(
+                feature = "bots",
+                feature = "calls",
+                feature = "chats",
+                feature = "messages"
+            ))]
+            VariantSize {
+                name: "MessageSchedulingState",
+                size: size_of::<MessageSchedulingState>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(any(feature = "bots", feature = "chats", feature = "messages"))]
+            VariantSize {
+                name: "InputMessageContent",
+                size: size_of::<InputMessageContent>(),
+                estimated: 136,
+                boxed: true,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "SearchMessagesFilter",
+                size: size_of::<SearchMessagesFilter>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatAction",
+                size: size_of::<ChatAction>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserStatus",
+                size: size_of::<UserStatus>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CallDiscardReason",
+                size: size_of::<CallDiscardReason>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "calls")]
+            VariantSize {
+                name: "CallState",
+                size: size_of::<CallState>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "calls")]
+            VariantSize {
+                name: "CallProblem",
+                size: size_of::<CallProblem>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "InputInlineQueryResult",
+                size: size_of::<InputInlineQueryResult>(),
+                estimated: 96,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "InlineQueryResult",
+                size: size_of::<InlineQueryResult>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "bots")]
+            VariantSize {
+                name: "CallbackQueryPayload",
+                size: size_of::<CallbackQueryPayload>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatEventAction",
+                size: size_of::<ChatEventAction>(),
+                estimated: 128,
+                boxed: false,
+            },
+            VariantSize {
+                name: "LanguagePackStringValue",
+                size: size_of::<LanguagePackStringValue>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "DeviceToken",
+                size: size_of::<DeviceToken>(),
+                estimated: 72,
+                boxed: false,
+            },
+            VariantSize {
+                name: "BackgroundFill",
+                size: size_of::<BackgroundFill>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "BackgroundType",
+                size: size_of::<BackgroundType>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "InputBackground",
+                size: size_of::<InputBackground>(),
+                estimated: 56,
+                boxed: false,
+            },
+            VariantSize {
+                name: "CanTransferOwnershipResult",
+                size: size_of::<CanTransferOwnershipResult>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "CheckChatUsernameResult",
+                size: size_of::<CheckChatUsernameResult>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "PushMessageContent",
+                size: size_of::<PushMessageContent>(),
+                estimated: 96,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NotificationType",
+                size: size_of::<NotificationType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NotificationGroupType",
+                size: size_of::<NotificationGroupType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "OptionValue",
+                size: size_of::<OptionValue>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "JsonValue",
+                size: size_of::<JsonValue>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySettingRule",
+                size: size_of::<UserPrivacySettingRule>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "UserPrivacySetting",
+                size: size_of::<UserPrivacySetting>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "ChatReportReason",
+                size: size_of::<ChatReportReason>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "FileType",
+                size: size_of::<FileType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "NetworkType",
+                size: size_of::<NetworkType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "files")]
+            VariantSize {
+                name: "NetworkStatisticsEntry",
+                size: size_of::<NetworkStatisticsEntry>(),
+                estimated: 112,
+                boxed: false,
+            },
+            VariantSize {
+                name: "ConnectionState",
+                size: size_of::<ConnectionState>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "chats")]
+            VariantSize {
+                name: "TopChatCategory",
+                size: size_of::<TopChatCategory>(),
+                estimated: 48,
+                boxed: false,
+            },
+            VariantSize {
+                name: "TMeUrlType",
+                size: size_of::<TMeUrlType>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "messages")]
+            VariantSize {
+                name: "TextParseMode",
+                size: size_of::<TextParseMode>(),
+                estimated: 48,
+                boxed: false,
+            },
+            #[cfg(feature = "proxy")]
+            VariantSize {
+                name: "ProxyType",
+                size: size_of::<ProxyType>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "stickers")]
+            VariantSize {
+                name: "InputSticker",
+                size: size_of::<InputSticker>(),
+                estimated: 80,
+                boxed: false,
+            },
+            VariantSize {
+                name: "StatisticsGraph",
+                size: size_of::<StatisticsGraph>(),
+                estimated: 56,
+                boxed: false,
+            },
+            #[cfg(feature = "logging")]
+            VariantSize {
+                name: "LogStream",
+                size: size_of::<LogStream>(),
+                estimated: 48,
+                boxed: false,
+            },
+        ],
+    },
+];
@@ -0,0 +1,3120 @@
+//! The types of the `bots` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A simple button, with text that should be sent when the button is pressed"]
+pub struct KeyboardButtonTypeText {}
+impl TlObject for KeyboardButtonTypeText {
+    const CLASS: &'static str = "KeyboardButtonType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for KeyboardButtonTypeText {
+    const TYPE: &'static str = "keyboardButtonTypeText";
+}
+impl KeyboardButtonTypeText {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for KeyboardButtonTypeText {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A button that sends the user's phone number when pressed; available only in private chats"]
+pub struct KeyboardButtonTypeRequestPhoneNumber {}
+impl TlObject for KeyboardButtonTypeRequestPhoneNumber {
+    const CLASS: &'static str = "KeyboardButtonType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for KeyboardButtonTypeRequestPhoneNumber {
+    const TYPE: &'static str = "keyboardButtonTypeRequestPhoneNumber";
+}
+impl KeyboardButtonTypeRequestPhoneNumber {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for KeyboardButtonTypeRequestPhoneNumber {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A button that sends the user's location when pressed; available only in private chats"]
+pub struct KeyboardButtonTypeRequestLocation {}
+impl TlObject for KeyboardButtonTypeRequestLocation {
+    const CLASS: &'static str = "KeyboardButtonType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for KeyboardButtonTypeRequestLocation {
+    const TYPE: &'static str = "keyboardButtonTypeRequestLocation";
+}
+impl KeyboardButtonTypeRequestLocation {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for KeyboardButtonTypeRequestLocation {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A button that allows the user to create and send a poll when pressed; available only in private chats"]
+pub struct KeyboardButtonTypeRequestPoll {
+    #[doc = "If true, only regular polls must be allowed to create"]
+    pub force_regular: bool,
+    #[doc = "If true, only polls in quiz mode must be allowed to create"]
+    pub force_quiz: bool,
+}
+impl TlObject for KeyboardButtonTypeRequestPoll {
+    const CLASS: &'static str = "KeyboardButtonType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for KeyboardButtonTypeRequestPoll {
+    const TYPE: &'static str = "keyboardButtonTypeRequestPoll";
+}
+impl KeyboardButtonTypeRequestPoll {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "If true, only regular polls must be allowed to create"]
+    pub fn with_force_regular(mut self, value: impl Into<bool>) -> Self {
+        self.force_regular = value.into();
+        self
+    }
+    #[doc = "If true, only polls in quiz mode must be allowed to create"]
+    pub fn with_force_quiz(mut self, value: impl Into<bool>) -> Self {
+        self.force_quiz = value.into();
+        self
+    }
+}
+impl Validate for KeyboardButtonTypeRequestPoll {}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes a keyboard button type"]
+pub enum KeyboardButtonType {
+    KeyboardButtonTypeText(KeyboardButtonTypeText),
+    KeyboardButtonTypeRequestPhoneNumber(KeyboardButtonTypeRequestPhoneNumber),
+    KeyboardButtonTypeRequestLocation(KeyboardButtonTypeRequestLocation),
+    KeyboardButtonTypeRequestPoll(KeyboardButtonTypeRequestPoll),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for KeyboardButtonType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "keyboardButtonTypeText" => {
+                Deserialize::deserialize(value).map(KeyboardButtonType::KeyboardButtonTypeText)
+            }
+            "keyboardButtonTypeRequestPhoneNumber" => Deserialize::deserialize(value)
+                .map(KeyboardButtonType::KeyboardButtonTypeRequestPhoneNumber),
+            "keyboardButtonTypeRequestLocation" => Deserialize::deserialize(value)
+                .map(KeyboardButtonType::KeyboardButtonTypeRequestLocation),
+            "keyboardButtonTypeRequestPoll" => Deserialize::deserialize(value)
+                .map(KeyboardButtonType::KeyboardButtonTypeRequestPoll),
+            _ => Unknown::deserialize(value).map(KeyboardButtonType::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for KeyboardButtonType {
+    const CLASS: &'static str = "KeyboardButtonType";
+    fn type_name(&self) -> &str {
+        match self {
+            KeyboardButtonType::KeyboardButtonTypeText(_) => "keyboardButtonTypeText",
+            KeyboardButtonType::KeyboardButtonTypeRequestPhoneNumber(_) => {
+                "keyboardButtonTypeRequestPhoneNumber"
+            }
+            KeyboardButtonType::KeyboardButtonTypeRequestLocation(_) => {
+                "keyboardButtonTypeRequestLocation"
+            }
+            KeyboardButtonType::KeyboardButtonTypeRequestPoll(_) => "keyboardButtonTypeRequestPoll",
+            KeyboardButtonType::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for KeyboardButtonType {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            KeyboardButtonType::KeyboardButtonTypeText(value) => value.validate(),
+            KeyboardButtonType::KeyboardButtonTypeRequestPhoneNumber(value) => value.validate(),
+            KeyboardButtonType::KeyboardButtonTypeRequestLocation(value) => value.validate(),
+            KeyboardButtonType::KeyboardButtonTypeRequestPoll(value) => value.validate(),
+            KeyboardButtonType::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<KeyboardButtonTypeText> for KeyboardButtonType {
+    fn from(value: KeyboardButtonTypeText) -> Self {
+        KeyboardButtonType::KeyboardButtonTypeText(value)
+    }
+}
+impl From<KeyboardButtonTypeRequestPhoneNumber> for KeyboardButtonType {
+    fn from(value: KeyboardButtonTypeRequestPhoneNumber) -> Self {
+        KeyboardButtonType::KeyboardButtonTypeRequestPhoneNumber(value)
+    }
+}
+impl From<KeyboardButtonTypeRequestLocation> for KeyboardButtonType {
+    fn from(value: KeyboardButtonTypeRequestLocation) -> Self {
+        KeyboardButtonType::KeyboardButtonTypeRequestLocation(value)
+    }
+}
+impl From<KeyboardButtonTypeRequestPoll> for KeyboardButtonType {
+    fn from(value: KeyboardButtonTypeRequestPoll) -> Self {
+        KeyboardButtonType::KeyboardButtonTypeRequestPoll(value)
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a single button in a bot keyboard"]
+pub struct KeyboardButton {
+    #[doc = "Text of the button"]
+    pub text: String,
+    #[serde(rename = "type")]
+    #[doc = "Type of the button"]
+    pub type_: KeyboardButtonType,
+}
+impl TlObject for KeyboardButton {
+    const CLASS: &'static str = "KeyboardButton";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for KeyboardButton {
+    const TYPE: &'static str = "keyboardButton";
+}
+impl KeyboardButton {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(type_: impl Into<KeyboardButtonType>) -> Self {
+        Self {
+            text: Default::default(),
+            type_: type_.into(),
+        }
+    }
+    #[doc = "Text of the button"]
+    pub fn with_text(mut self, value: impl Into<String>) -> Self {
+        self.text = value.into();
+        self
+    }
+}
+impl Validate for KeyboardButton {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.type_
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "type"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A button that opens a specified URL"]
+pub struct InlineKeyboardButtonTypeUrl {
+    #[doc = "HTTP or tg:// URL to open"]
+    pub url: String,
+}
+impl TlObject for InlineKeyboardButtonTypeUrl {
+    const CLASS: &'static str = "InlineKeyboardButtonType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InlineKeyboardButtonTypeUrl {
+    const TYPE: &'static str = "inlineKeyboardButtonTypeUrl";
+}
+impl InlineKeyboardButtonTypeUrl {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "HTTP or tg:// URL to open"]
+    pub fn with_url(mut self, value: impl Into<String>) -> Self {
+        self.url = value.into();
+        self
+    }
+}
+impl Validate for InlineKeyboardButtonTypeUrl {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A button that opens a specified URL and automatically logs in in current user if they allowed to do that"]
+pub struct InlineKeyboardButtonTypeLoginUrl {
+    #[doc = "An HTTP URL to open"]
+    pub url: String,
+    #[doc = "Unique button identifier"]
+    pub id: i32,
+    #[doc = "If non-empty, new text of the button in forwarded messages"]
+    pub forward_text: String,
+}
+impl TlObject for InlineKeyboardButtonTypeLoginUrl {
+    const CLASS: &'static str = "InlineKeyboardButtonType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InlineKeyboardButtonTypeLoginUrl {
+    const TYPE: &'static str = "inlineKeyboardButtonTypeLoginUrl";
+}
+impl InlineKeyboardButtonTypeLoginUrl {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "An HTTP URL to open"]
+    pub fn with_url(mut self, value: impl Into<String>) -> Self {
+        self.url = value.into();
+        self
+    }
+    #[doc = "Unique button identifier"]
+    pub fn with_id(mut self, value: impl Into<i32>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "If non-empty, new text of the button in forwarded messages"]
+    pub fn with_forward_text(mut self, value: impl Into<String>) -> Self {
+        self.forward_text = value.into();
+        self
+    }
+}
+impl Validate for InlineKeyboardButtonTypeLoginUrl {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A button that sends a special callback query to a bot"]
+pub struct InlineKeyboardButtonTypeCallback {
+    #[doc = "Data to be sent to the bot via a callback query"]
+    pub data: Bytes,
+}
+impl TlObject for InlineKeyboardButtonTypeCallback {
+    const CLASS: &'static str = "InlineKeyboardButtonType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InlineKeyboardButtonTypeCallback {
+    const TYPE: &'static str = "inlineKeyboardButtonTypeCallback";
+}
+impl InlineKeyboardButtonTypeCallback {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Data to be sent to the bot via a callback query"]
+    pub fn with_data(mut self, value: impl Into<Bytes>) -> Self {
+        self.data = value.into();
+        self
+    }
+}
+impl Validate for InlineKeyboardButtonTypeCallback {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A button with a game that sends a special callback query to a bot. This button must be in the first column and row of the keyboard and can be attached only to a message with content of the type messageGame"]
+pub struct InlineKeyboardButtonTypeCallbackGame {}
+impl TlObject for InlineKeyboardButtonTypeCallbackGame {
+    const CLASS: &'static str = "InlineKeyboardButtonType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InlineKeyboardButtonTypeCallbackGame {
+    const TYPE: &'static str = "inlineKeyboardButtonTypeCallbackGame";
+}
+impl InlineKeyboardButtonTypeCallbackGame {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for InlineKeyboardButtonTypeCallbackGame {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A button that forces an inline query to the bot to be inserted in the input field"]
+pub struct InlineKeyboardButtonTypeSwitchInline {
+    #[doc = "Inline query to be sent to the bot"]
+    pub query: String,
+    #[doc = "True, if the inline query should be sent from the current chat"]
+    pub in_current_chat: bool,
+}
+impl TlObject for InlineKeyboardButtonTypeSwitchInline {
+    const CLASS: &'static str = "InlineKeyboardButtonType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InlineKeyboardButtonTypeSwitchInline {
+    const TYPE: &'static str = "inlineKeyboardButtonTypeSwitchInline";
+}
+impl InlineKeyboardButtonTypeSwitchInline {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Inline query to be sent to the bot"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+    #[doc = "True, if the inline query should be sent from the current chat"]
+    pub fn with_in_current_chat(mut self, value: impl Into<bool>) -> Self {
+        self.in_current_chat = value.into();
+        self
+    }
+}
+impl Validate for InlineKeyboardButtonTypeSwitchInline {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A button to buy something. This button must be in the first column and row of the keyboard and can be attached only to a message with content of the type messageInvoice"]
+pub struct InlineKeyboardButtonTypeBuy {}
+impl TlObject for InlineKeyboardButtonTypeBuy {
+    const CLASS: &'static str = "InlineKeyboardButtonType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InlineKeyboardButtonTypeBuy {
+    const TYPE: &'static str = "inlineKeyboardButtonTypeBuy";
+}
+impl InlineKeyboardButtonTypeBuy {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for InlineKeyboardButtonTypeBuy {}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes the type of an inline keyboard button"]
+pub enum InlineKeyboardButtonType {
+    InlineKeyboardButtonTypeUrl(InlineKeyboardButtonTypeUrl),
+    InlineKeyboardButtonTypeLoginUrl(InlineKeyboardButtonTypeLoginUrl),
+    InlineKeyboardButtonTypeCallback(InlineKeyboardButtonTypeCallback),
+    InlineKeyboardButtonTypeCallbackGame(InlineKeyboardButtonTypeCallbackGame),
+    InlineKeyboardButtonTypeSwitchInline(InlineKeyboardButtonTypeSwitchInline),
+    InlineKeyboardButtonTypeBuy(InlineKeyboardButtonTypeBuy),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for InlineKeyboardButtonType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "inlineKeyboardButtonTypeUrl" => Deserialize::deserialize(value)
+                .map(InlineKeyboardButtonType::InlineKeyboardButtonTypeUrl),
+            "inlineKeyboardButtonTypeLoginUrl" => Deserialize::deserialize(value)
+                .map(InlineKeyboardButtonType::InlineKeyboardButtonTypeLoginUrl),
+            "inlineKeyboardButtonTypeCallback" => Deserialize::deserialize(value)
+                .map(InlineKeyboardButtonType::InlineKeyboardButtonTypeCallback),
+            "inlineKeyboardButtonTypeCallbackGame" => Deserialize::deserialize(value)
+                .map(InlineKeyboardButtonType::InlineKeyboardButtonTypeCallbackGame),
+            "inlineKeyboardButtonTypeSwitchInline" => Deserialize::deserialize(value)
+                .map(InlineKeyboardButtonType::InlineKeyboardButtonTypeSwitchInline),
+            "inlineKeyboardButtonTypeBuy" => Deserialize::deserialize(value)
+                .map(InlineKeyboardButtonType::InlineKeyboardButtonTypeBuy),
+            _ => Unknown::deserialize(value).map(InlineKeyboardButtonType::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for InlineKeyboardButtonType {
+    const CLASS: &'static str = "InlineKeyboardButtonType";
+    fn type_name(&self) -> &str {
+        match self {
+            InlineKeyboardButtonType::InlineKeyboardButtonTypeUrl(_) => {
+                "inlineKeyboardButtonTypeUrl"
+            }
+            InlineKeyboardButtonType::InlineKeyboardButtonTypeLoginUrl(_) => {
+                "inlineKeyboardButtonTypeLoginUrl"
+            }
+            InlineKeyboardButtonType::InlineKeyboardButtonTypeCallback(_) => {
+                "inlineKeyboardButtonTypeCallback"
+            }
+            InlineKeyboardButtonType::InlineKeyboardButtonTypeCallbackGame(_) => {
+                "inlineKeyboardButtonTypeCallbackGame"
+            }
+            InlineKeyboardButtonType::InlineKeyboardButtonTypeSwitchInline(_) => {
+                "inlineKeyboardButtonTypeSwitchInline"
+            }
+            InlineKeyboardButtonType::InlineKeyboardButtonTypeBuy(_) => {
+                "inlineKeyboardButtonTypeBuy"
+            }
+            InlineKeyboardButtonType::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for InlineKeyboardButtonType {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            InlineKeyboardButtonType::InlineKeyboardButtonTypeUrl(value) => value.validate(),
+            InlineKeyboardButtonType::InlineKeyboardButtonTypeLoginUrl(value) => value.validate(),
+            InlineKeyboardButtonType::InlineKeyboardButtonTypeCallback(value) => value.validate(),
+            InlineKeyboardButtonType::InlineKeyboardButtonTypeCallbackGame(value) => {
+                value.validate()
+            }
+            InlineKeyboardButtonType::InlineKeyboardButtonTypeSwitchInline(value) => {
+                value.validate()
+            }
+            InlineKeyboardButtonType::InlineKeyboardButtonTypeBuy(value) => value.validate(),
+            InlineKeyboardButtonType::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<InlineKeyboardButtonTypeUrl> for InlineKeyboardButtonType {
+    fn from(value: InlineKeyboardButtonTypeUrl) -> Self {
+        InlineKeyboardButtonType::InlineKeyboardButtonTypeUrl(value)
+    }
+}
+impl From<InlineKeyboardButtonTypeLoginUrl> for InlineKeyboardButtonType {
+    fn from(value: InlineKeyboardButtonTypeLoginUrl) -> Self {
+        InlineKeyboardButtonType::InlineKeyboardButtonTypeLoginUrl(value)
+    }
+}
+impl From<InlineKeyboardButtonTypeCallback> for InlineKeyboardButtonType {
+    fn from(value: InlineKeyboardButtonTypeCallback) -> Self {
+        InlineKeyboardButtonType::InlineKeyboardButtonTypeCallback(value)
+    }
+}
+impl From<InlineKeyboardButtonTypeCallbackGame> for InlineKeyboardButtonType {
+    fn from(value: InlineKeyboardButtonTypeCallbackGame) -> Self {
+        InlineKeyboardButtonType::InlineKeyboardButtonTypeCallbackGame(value)
+    }
+}
+impl From<InlineKeyboardButtonTypeSwitchInline> for InlineKeyboardButtonType {
+    fn from(value: InlineKeyboardButtonTypeSwitchInline) -> Self {
+        InlineKeyboardButtonType::InlineKeyboardButtonTypeSwitchInline(value)
+    }
+}
+impl From<InlineKeyboardButtonTypeBuy> for InlineKeyboardButtonType {
+    fn from(value: InlineKeyboardButtonTypeBuy) -> Self {
+        InlineKeyboardButtonType::InlineKeyboardButtonTypeBuy(value)
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a single button in an inline keyboard"]
+pub struct InlineKeyboardButton {
+    #[doc = "Text of the button"]
+    pub text: String,
+    #[serde(rename = "type")]
+    #[doc = "Type of the button"]
+    pub type_: InlineKeyboardButtonType,
+}
+impl TlObject for InlineKeyboardButton {
+    const CLASS: &'static str = "InlineKeyboardButton";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InlineKeyboardButton {
+    const TYPE: &'static str = "inlineKeyboardButton";
+}
+impl InlineKeyboardButton {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(type_: impl Into<InlineKeyboardButtonType>) -> Self {
+        Self {
+            text: Default::default(),
+            type_: type_.into(),
+        }
+    }
+    #[doc = "Text of the button"]
+    pub fn with_text(mut self, value: impl Into<String>) -> Self {
+        self.text = value.into();
+        self
+    }
+}
+impl Validate for InlineKeyboardButton {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.type_
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "type"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Instructs clients to remove the keyboard once this message has been received. This kind of keyboard can't be received in an incoming message; instead, UpdateChatReplyMarkup with message_id == 0 will be sent"]
+pub struct ReplyMarkupRemoveKeyboard {
+    #[doc = "True, if the keyboard is removed only for the mentioned users or the target user of a reply"]
+    pub is_personal: bool,
+}
+impl TlObject for ReplyMarkupRemoveKeyboard {
+    const CLASS: &'static str = "ReplyMarkup";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for ReplyMarkupRemoveKeyboard {
+    const TYPE: &'static str = "replyMarkupRemoveKeyboard";
+}
+impl ReplyMarkupRemoveKeyboard {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "True, if the keyboard is removed only for the mentioned users or the target user of a reply"]
+    pub fn with_is_personal(mut self, value: impl Into<bool>) -> Self {
+        self.is_personal = value.into();
+        self
+    }
+}
+impl Validate for ReplyMarkupRemoveKeyboard {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Instructs clients to force a reply to this message"]
+pub struct ReplyMarkupForceReply {
+    #[doc = "True, if a forced reply must automatically be shown to the current user. For outgoing messages, specify true to show the forced reply only for the mentioned users and for the target user of a reply"]
+    pub is_personal: bool,
+}
+impl TlObject for ReplyMarkupForceReply {
+    const CLASS: &'static str = "ReplyMarkup";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for ReplyMarkupForceReply {
+    const TYPE: &'static str = "replyMarkupForceReply";
+}
+impl ReplyMarkupForceReply {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "True, if a forced reply must automatically be shown to the current user. For outgoing messages, specify true to show the forced reply only for the mentioned users and for the target user of a reply"]
+    pub fn with_is_personal(mut self, value: impl Into<bool>) -> Self {
+        self.is_personal = value.into();
+        self
+    }
+}
+impl Validate for ReplyMarkupForceReply {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Contains a custom keyboard layout to quickly reply to bots"]
+pub struct ReplyMarkupShowKeyboard {
+    #[doc = "A list of rows of bot keyboard buttons"]
+    pub rows: Vec<Vec<KeyboardButton>>,
+    #[doc = "True, if the client needs to resize the keyboard vertically"]
+    pub resize_keyboard: bool,
+    #[doc = "True, if the client needs to hide the keyboard after use"]
+    pub one_time: bool,
+    #[doc = "True, if the keyboard must automatically be shown to the current user. For outgoing messages, specify true to show the keyboard only for the mentioned users and for the target user of a reply"]
+    pub is_personal: bool,
+}
+impl TlObject for ReplyMarkupShowKeyboard {
+    const CLASS: &'static str = "ReplyMarkup";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for ReplyMarkupShowKeyboard {
+    const TYPE: &'static str = "replyMarkupShowKeyboard";
+}
+impl ReplyMarkupShowKeyboard {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "A list of rows of bot keyboard buttons"]
+    pub fn with_rows(mut self, value: impl Into<Vec<Vec<KeyboardButton>>>) -> Self {
+        self.rows = value.into();
+        self
+    }
+    #[doc = "True, if the client needs to resize the keyboard vertically"]
+    pub fn with_resize_keyboard(mut self, value: impl Into<bool>) -> Self {
+        self.resize_keyboard = value.into();
+        self
+    }
+    #[doc = "True, if the client needs to hide the keyboard after use"]
+    pub fn with_one_time(mut self, value: impl Into<bool>) -> Self {
+        self.one_time = value.into();
+        self
+    }
+    #[doc = "True, if the keyboard must automatically be shown to the current user. For outgoing messages, specify true to show the keyboard only for the mentioned users and for the target user of a reply"]
+    pub fn with_is_personal(mut self, value: impl Into<bool>) -> Self {
+        self.is_personal = value.into();
+        self
+    }
+}
+impl Validate for ReplyMarkupShowKeyboard {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, value) in self.rows.iter().enumerate() {
+            for (j, value) in value.iter().enumerate() {
+                value
+                    .validate()
+                    .map_err(|err| err.within(Self::TYPE, &format!("rows[{}][{}]", i, j)))?;
+            }
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Contains an inline keyboard layout"]
+pub struct ReplyMarkupInlineKeyboard {
+    #[doc = "A list of rows of inline keyboard buttons"]
+    pub rows: Vec<Vec<InlineKeyboardButton>>,
+}
+impl TlObject for ReplyMarkupInlineKeyboard {
+    const CLASS: &'static str = "ReplyMarkup";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for ReplyMarkupInlineKeyboard {
+    const TYPE: &'static str = "replyMarkupInlineKeyboard";
+}
+impl ReplyMarkupInlineKeyboard {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "A list of rows of inline keyboard buttons"]
+    pub fn with_rows(mut self, value: impl Into<Vec<Vec<InlineKeyboardButton>>>) -> Self {
+        self.rows = value.into();
+        self
+    }
+}
+impl Validate for ReplyMarkupInlineKeyboard {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, value) in self.rows.iter().enumerate() {
+            for (j, value) in value.iter().enumerate() {
+                value
+                    .validate()
+                    .map_err(|err| err.within(Self::TYPE, &format!("rows[{}][{}]", i, j)))?;
+            }
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Contains a description of a custom keyboard and actions that can be done with it to quickly reply to bots"]
+pub enum ReplyMarkup {
+    ReplyMarkupRemoveKeyboard(ReplyMarkupRemoveKeyboard),
+    ReplyMarkupForceReply(ReplyMarkupForceReply),
+    ReplyMarkupShowKeyboard(ReplyMarkupShowKeyboard),
+    ReplyMarkupInlineKeyboard(ReplyMarkupInlineKeyboard),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for ReplyMarkup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "replyMarkupRemoveKeyboard" => {
+                Deserialize::deserialize(value).map(ReplyMarkup::ReplyMarkupRemoveKeyboard)
+            }
+            "replyMarkupForceReply" => {
+                Deserialize::deserialize(value).map(ReplyMarkup::ReplyMarkupForceReply)
+            }
+            "replyMarkupShowKeyboard" => {
+                Deserialize::deserialize(value).map(ReplyMarkup::ReplyMarkupShowKeyboard)
+            }
+            "replyMarkupInlineKeyboard" => {
+                Deserialize::deserialize(value).map(ReplyMarkup::ReplyMarkupInlineKeyboard)
+            }
+            _ => Unknown::deserialize(value).map(ReplyMarkup::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for ReplyMarkup {
+    const CLASS: &'static str = "ReplyMarkup";
+    fn type_name(&self) -> &str {
+        match self {
+            ReplyMarkup::ReplyMarkupRemoveKeyboard(_) => "replyMarkupRemoveKeyboard",
+            ReplyMarkup::ReplyMarkupForceReply(_) => "replyMarkupForceReply",
+            ReplyMarkup::ReplyMarkupShowKeyboard(_) => "replyMarkupShowKeyboard",
+            ReplyMarkup::ReplyMarkupInlineKeyboard(_) => "replyMarkupInlineKeyboard",
+            ReplyMarkup::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for ReplyMarkup {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            ReplyMarkup::ReplyMarkupRemoveKeyboard(value) => value.validate(),
+            ReplyMarkup::ReplyMarkupForceReply(value) => value.validate(),
+            ReplyMarkup::ReplyMarkupShowKeyboard(value) => value.validate(),
+            ReplyMarkup::ReplyMarkupInlineKeyboard(value) => value.validate(),
+            ReplyMarkup::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<ReplyMarkupRemoveKeyboard> for ReplyMarkup {
+    fn from(value: ReplyMarkupRemoveKeyboard) -> Self {
+        ReplyMarkup::ReplyMarkupRemoveKeyboard(value)
+    }
+}
+impl From<ReplyMarkupForceReply> for ReplyMarkup {
+    fn from(value: ReplyMarkupForceReply) -> Self {
+        ReplyMarkup::ReplyMarkupForceReply(value)
+    }
+}
+impl From<ReplyMarkupShowKeyboard> for ReplyMarkup {
+    fn from(value: ReplyMarkupShowKeyboard) -> Self {
+        ReplyMarkup::ReplyMarkupShowKeyboard(value)
+    }
+}
+impl From<ReplyMarkupInlineKeyboard> for ReplyMarkup {
+    fn from(value: ReplyMarkupInlineKeyboard) -> Self {
+        ReplyMarkup::ReplyMarkupInlineKeyboard(value)
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An HTTP url needs to be open"]
+pub struct LoginUrlInfoOpen {
+    #[doc = "The URL to open"]
+    pub url: String,
+    #[doc = "True, if there is no need to show an ordinary open URL confirm"]
+    pub skip_confirm: bool,
+}
+#[cfg(feature = "bots")]
+impl TlObject for LoginUrlInfoOpen {
+    const CLASS: &'static str = "LoginUrlInfo";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for LoginUrlInfoOpen {
+    const TYPE: &'static str = "loginUrlInfoOpen";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An authorization confirmation dialog needs to be shown to the user"]
+pub struct LoginUrlInfoRequestConfirmation {
+    #[doc = "An HTTP URL to be opened"]
+    pub url: String,
+    #[doc = "A domain of the URL"]
+    pub domain: String,
+    #[doc = "User identifier of a bot linked with the website"]
+    pub bot_user_id: UserId,
+    #[doc = "True, if the user needs to be requested to give the permission to the bot to send them messages"]
+    pub request_write_access: bool,
+}
+#[cfg(feature = "bots")]
+impl TlObject for LoginUrlInfoRequestConfirmation {
+    const CLASS: &'static str = "LoginUrlInfo";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for LoginUrlInfoRequestConfirmation {
+    const TYPE: &'static str = "loginUrlInfoRequestConfirmation";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Contains information about an inline button of type inlineKeyboardButtonTypeLoginUrl"]
+pub enum LoginUrlInfo {
+    LoginUrlInfoOpen(LoginUrlInfoOpen),
+    LoginUrlInfoRequestConfirmation(LoginUrlInfoRequestConfirmation),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "bots")]
+impl<'de> Deserialize<'de> for LoginUrlInfo {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "loginUrlInfoOpen" => {
+                Deserialize::deserialize(value).map(LoginUrlInfo::LoginUrlInfoOpen)
+            }
+            "loginUrlInfoRequestConfirmation" => {
+                Deserialize::deserialize(value).map(LoginUrlInfo::LoginUrlInfoRequestConfirmation)
+            }
+            _ => Unknown::deserialize(value).map(LoginUrlInfo::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "bots")]
+impl TlObject for LoginUrlInfo {
+    const CLASS: &'static str = "LoginUrlInfo";
+    fn type_name(&self) -> &str {
+        match self {
+            LoginUrlInfo::LoginUrlInfoOpen(_) => "loginUrlInfoOpen",
+            LoginUrlInfo::LoginUrlInfoRequestConfirmation(_) => "loginUrlInfoRequestConfirmation",
+            LoginUrlInfo::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a link to an animated GIF"]
+pub struct InputInlineQueryResultAnimatedGif {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Title of the query result"]
+    pub title: String,
+    #[doc = "URL of the static result thumbnail (JPEG or GIF), if it exists"]
+    pub thumbnail_url: String,
+    #[doc = "The URL of the GIF-file (file size must not exceed 1MB)"]
+    pub gif_url: String,
+    #[doc = "Duration of the GIF, in seconds"]
+    pub gif_duration: i32,
+    #[doc = "Width of the GIF"]
+    pub gif_width: i32,
+    #[doc = "Height of the GIF"]
+    pub gif_height: i32,
+    #[serde(default)]
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageAnimation, InputMessageLocation, InputMessageVenue or InputMessageContact"]
+    pub input_message_content: InputMessageContent,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultAnimatedGif {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultAnimatedGif {
+    const TYPE: &'static str = "inputInlineQueryResultAnimatedGif";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultAnimatedGif {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_content: impl Into<InputMessageContent>) -> Self {
+        Self {
+            id: Default::default(),
+            title: Default::default(),
+            thumbnail_url: Default::default(),
+            gif_url: Default::default(),
+            gif_duration: Default::default(),
+            gif_width: Default::default(),
+            gif_height: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "Title of the query result"]
+    pub fn with_title(mut self, value: impl Into<String>) -> Self {
+        self.title = value.into();
+        self
+    }
+    #[doc = "URL of the static result thumbnail (JPEG or GIF), if it exists"]
+    pub fn with_thumbnail_url(mut self, value: impl Into<String>) -> Self {
+        self.thumbnail_url = value.into();
+        self
+    }
+    #[doc = "The URL of the GIF-file (file size must not exceed 1MB)"]
+    pub fn with_gif_url(mut self, value: impl Into<String>) -> Self {
+        self.gif_url = value.into();
+        self
+    }
+    #[doc = "Duration of the GIF, in seconds"]
+    pub fn with_gif_duration(mut self, value: impl Into<i32>) -> Self {
+        self.gif_duration = value.into();
+        self
+    }
+    #[doc = "Width of the GIF"]
+    pub fn with_gif_width(mut self, value: impl Into<i32>) -> Self {
+        self.gif_width = value.into();
+        self
+    }
+    #[doc = "Height of the GIF"]
+    pub fn with_gif_height(mut self, value: impl Into<i32>) -> Self {
+        self.gif_height = value.into();
+        self
+    }
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultAnimatedGif {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a link to an animated (i.e. without sound) H.264/MPEG-4 AVC video"]
+pub struct InputInlineQueryResultAnimatedMpeg4 {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Title of the result"]
+    pub title: String,
+    #[doc = "URL of the static result thumbnail (JPEG or GIF), if it exists"]
+    pub thumbnail_url: String,
+    #[doc = "The URL of the MPEG4-file (file size must not exceed 1MB)"]
+    pub mpeg4_url: String,
+    #[doc = "Duration of the video, in seconds"]
+    pub mpeg4_duration: i32,
+    #[doc = "Width of the video"]
+    pub mpeg4_width: i32,
+    #[doc = "Height of the video"]
+    pub mpeg4_height: i32,
+    #[serde(default)]
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageAnimation, InputMessageLocation, InputMessageVenue or InputMessageContact"]
+    pub input_message_content: InputMessageContent,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultAnimatedMpeg4 {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultAnimatedMpeg4 {
+    const TYPE: &'static str = "inputInlineQueryResultAnimatedMpeg4";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultAnimatedMpeg4 {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_content: impl Into<InputMessageContent>) -> Self {
+        Self {
+            id: Default::default(),
+            title: Default::default(),
+            thumbnail_url: Default::default(),
+            mpeg4_url: Default::default(),
+            mpeg4_duration: Default::default(),
+            mpeg4_width: Default::default(),
+            mpeg4_height: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "Title of the result"]
+    pub fn with_title(mut self, value: impl Into<String>) -> Self {
+        self.title = value.into();
+        self
+    }
+    #[doc = "URL of the static result thumbnail (JPEG or GIF), if it exists"]
+    pub fn with_thumbnail_url(mut self, value: impl Into<String>) -> Self {
+        self.thumbnail_url = value.into();
+        self
+    }
+    #[doc = "The URL of the MPEG4-file (file size must not exceed 1MB)"]
+    pub fn with_mpeg4_url(mut self, value: impl Into<String>) -> Self {
+        self.mpeg4_url = value.into();
+        self
+    }
+    #[doc = "Duration of the video, in seconds"]
+    pub fn with_mpeg4_duration(mut self, value: impl Into<i32>) -> Self {
+        self.mpeg4_duration = value.into();
+        self
+    }
+    #[doc = "Width of the video"]
+    pub fn with_mpeg4_width(mut self, value: impl Into<i32>) -> Self {
+        self.mpeg4_width = value.into();
+        self
+    }
+    #[doc = "Height of the video"]
+    pub fn with_mpeg4_height(mut self, value: impl Into<i32>) -> Self {
+        self.mpeg4_height = value.into();
+        self
+    }
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultAnimatedMpeg4 {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a link to an article or web page"]
+pub struct InputInlineQueryResultArticle {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "URL of the result, if it exists"]
+    pub url: String,
+    #[doc = "True, if the URL must be not shown"]
+    pub hide_url: bool,
+    #[doc = "Title of the result"]
+    pub title: String,
+    #[doc = "Represents a link to an article or web page"]
+    pub description: String,
+    #[doc = "URL of the result thumbnail, if it exists"]
+    pub thumbnail_url: String,
+    #[doc = "Thumbnail width, if known"]
+    pub thumbnail_width: i32,
+    #[doc = "Thumbnail height, if known"]
+    pub thumbnail_height: i32,
+    #[serde(default)]
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageLocation, InputMessageVenue or InputMessageContact"]
+    pub input_message_content: InputMessageContent,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultArticle {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultArticle {
+    const TYPE: &'static str = "inputInlineQueryResultArticle";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultArticle {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_content: impl Into<InputMessageContent>) -> Self {
+        Self {
+            id: Default::default(),
+            url: Default::default(),
+            hide_url: Default::default(),
+            title: Default::default(),
+            description: Default::default(),
+            thumbnail_url: Default::default(),
+            thumbnail_width: Default::default(),
+            thumbnail_height: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "URL of the result, if it exists"]
+    pub fn with_url(mut self, value: impl Into<String>) -> Self {
+        self.url = value.into();
+        self
+    }
+    #[doc = "True, if the URL must be not shown"]
+    pub fn with_hide_url(mut self, value: impl Into<bool>) -> Self {
+        self.hide_url = value.into();
+        self
+    }
+    #[doc = "Title of the result"]
+    pub fn with_title(mut self, value: impl Into<String>) -> Self {
+        self.title = value.into();
+        self
+    }
+    #[doc = "Represents a link to an article or web page"]
+    pub fn with_description(mut self, value: impl Into<String>) -> Self {
+        self.description = value.into();
+        self
+    }
+    #[doc = "URL of the result thumbnail, if it exists"]
+    pub fn with_thumbnail_url(mut self, value: impl Into<String>) -> Self {
+        self.thumbnail_url = value.into();
+        self
+    }
+    #[doc = "Thumbnail width, if known"]
+    pub fn with_thumbnail_width(mut self, value: impl Into<i32>) -> Self {
+        self.thumbnail_width = value.into();
+        self
+    }
+    #[doc = "Thumbnail height, if known"]
+    pub fn with_thumbnail_height(mut self, value: impl Into<i32>) -> Self {
+        self.thumbnail_height = value.into();
+        self
+    }
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultArticle {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a link to an MP3 audio file"]
+pub struct InputInlineQueryResultAudio {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Title of the audio file"]
+    pub title: String,
+    #[doc = "Performer of the audio file"]
+    pub performer: String,
+    #[doc = "The URL of the audio file"]
+    pub audio_url: String,
+    #[doc = "Audio file duration, in seconds"]
+    pub audio_duration: i32,
+    #[serde(default)]
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageAudio, InputMessageLocation, InputMessageVenue or InputMessageContact"]
+    pub input_message_content: InputMessageContent,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultAudio {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultAudio {
+    const TYPE: &'static str = "inputInlineQueryResultAudio";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultAudio {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_content: impl Into<InputMessageContent>) -> Self {
+        Self {
+            id: Default::default(),
+            title: Default::default(),
+            performer: Default::default(),
+            audio_url: Default::default(),
+            audio_duration: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "Title of the audio file"]
+    pub fn with_title(mut self, value: impl Into<String>) -> Self {
+        self.title = value.into();
+        self
+    }
+    #[doc = "Performer of the audio file"]
+    pub fn with_performer(mut self, value: impl Into<String>) -> Self {
+        self.performer = value.into();
+        self
+    }
+    #[doc = "The URL of the audio file"]
+    pub fn with_audio_url(mut self, value: impl Into<String>) -> Self {
+        self.audio_url = value.into();
+        self
+    }
+    #[doc = "Audio file duration, in seconds"]
+    pub fn with_audio_duration(mut self, value: impl Into<i32>) -> Self {
+        self.audio_duration = value.into();
+        self
+    }
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultAudio {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a user contact"]
+pub struct InputInlineQueryResultContact {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "User contact"]
+    pub contact: Contact,
+    #[doc = "URL of the result thumbnail, if it exists"]
+    pub thumbnail_url: String,
+    #[doc = "Thumbnail width, if known"]
+    pub thumbnail_width: i32,
+    #[doc = "Thumbnail height, if known"]
+    pub thumbnail_height: i32,
+    #[serde(default)]
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageLocation, InputMessageVenue or InputMessageContact"]
+    pub input_message_content: InputMessageContent,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultContact {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultContact {
+    const TYPE: &'static str = "inputInlineQueryResultContact";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultContact {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        contact: impl Into<Contact>,
+        input_message_content: impl Into<InputMessageContent>,
+    ) -> Self {
+        Self {
+            id: Default::default(),
+            contact: contact.into(),
+            thumbnail_url: Default::default(),
+            thumbnail_width: Default::default(),
+            thumbnail_height: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "URL of the result thumbnail, if it exists"]
+    pub fn with_thumbnail_url(mut self, value: impl Into<String>) -> Self {
+        self.thumbnail_url = value.into();
+        self
+    }
+    #[doc = "Thumbnail width, if known"]
+    pub fn with_thumbnail_width(mut self, value: impl Into<i32>) -> Self {
+        self.thumbnail_width = value.into();
+        self
+    }
+    #[doc = "Thumbnail height, if known"]
+    pub fn with_thumbnail_height(mut self, value: impl Into<i32>) -> Self {
+        self.thumbnail_height = value.into();
+        self
+    }
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultContact {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.contact
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "contact"))?;
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a link to a file"]
+pub struct InputInlineQueryResultDocument {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Title of the resulting file"]
+    pub title: String,
+    #[doc = "Represents a link to a file"]
+    pub description: String,
+    #[doc = "URL of the file"]
+    pub document_url: String,
+    #[doc = "MIME type of the file content; only \"application/pdf\" and \"application/zip\" are currently allowed"]
+    pub mime_type: String,
+    #[doc = "The URL of the file thumbnail, if it exists"]
+    pub thumbnail_url: String,
+    #[doc = "Width of the thumbnail"]
+    pub thumbnail_width: i32,
+    #[doc = "Height of the thumbnail"]
+    pub thumbnail_height: i32,
+    #[serde(default)]
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageDocument, InputMessageLocation, InputMessageVenue or InputMessageContact"]
+    pub input_message_content: InputMessageContent,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultDocument {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultDocument {
+    const TYPE: &'static str = "inputInlineQueryResultDocument";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultDocument {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_content: impl Into<InputMessageContent>) -> Self {
+        Self {
+            id: Default::default(),
+            title: Default::default(),
+            description: Default::default(),
+            document_url: Default::default(),
+            mime_type: Default::default(),
+            thumbnail_url: Default::default(),
+            thumbnail_width: Default::default(),
+            thumbnail_height: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "Title of the resulting file"]
+    pub fn with_title(mut self, value: impl Into<String>) -> Self {
+        self.title = value.into();
+        self
+    }
+    #[doc = "Represents a link to a file"]
+    pub fn with_description(mut self, value: impl Into<String>) -> Self {
+        self.description = value.into();
+        self
+    }
+    #[doc = "URL of the file"]
+    pub fn with_document_url(mut self, value: impl Into<String>) -> Self {
+        self.document_url = value.into();
+        self
+    }
+    #[doc = "MIME type of the file content; only \"application/pdf\" and \"application/zip\" are currently allowed"]
+    pub fn with_mime_type(mut self, value: impl Into<String>) -> Self {
+        self.mime_type = value.into();
+        self
+    }
+    #[doc = "The URL of the file thumbnail, if it exists"]
+    pub fn with_thumbnail_url(mut self, value: impl Into<String>) -> Self {
+        self.thumbnail_url = value.into();
+        self
+    }
+    #[doc = "Width of the thumbnail"]
+    pub fn with_thumbnail_width(mut self, value: impl Into<i32>) -> Self {
+        self.thumbnail_width = value.into();
+        self
+    }
+    #[doc = "Height of the thumbnail"]
+    pub fn with_thumbnail_height(mut self, value: impl Into<i32>) -> Self {
+        self.thumbnail_height = value.into();
+        self
+    }
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultDocument {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Represents a game"]
+pub struct InputInlineQueryResultGame {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Short name of the game"]
+    pub game_short_name: String,
+    #[serde(default)]
+    #[doc = "Message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultGame {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultGame {
+    const TYPE: &'static str = "inputInlineQueryResultGame";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultGame {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "Short name of the game"]
+    pub fn with_game_short_name(mut self, value: impl Into<String>) -> Self {
+        self.game_short_name = value.into();
+        self
+    }
+    #[doc = "Message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultGame {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a point on the map"]
+pub struct InputInlineQueryResultLocation {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Location result"]
+    pub location: Location,
+    #[doc = "Amount of time relative to the message sent time until the location can be updated, in seconds"]
+    pub live_period: i32,
+    #[doc = "Title of the result"]
+    pub title: String,
+    #[doc = "URL of the result thumbnail, if it exists"]
+    pub thumbnail_url: String,
+    #[doc = "Thumbnail width, if known"]
+    pub thumbnail_width: i32,
+    #[doc = "Thumbnail height, if known"]
+    pub thumbnail_height: i32,
+    #[serde(default)]
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageLocation, InputMessageVenue or InputMessageContact"]
+    pub input_message_content: InputMessageContent,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultLocation {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultLocation {
+    const TYPE: &'static str = "inputInlineQueryResultLocation";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultLocation {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_content: impl Into<InputMessageContent>) -> Self {
+        Self {
+            id: Default::default(),
+            location: Default::default(),
+            live_period: Default::default(),
+            title: Default::default(),
+            thumbnail_url: Default::default(),
+            thumbnail_width: Default::default(),
+            thumbnail_height: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "Location result"]
+    pub fn with_location(mut self, value: impl Into<Location>) -> Self {
+        self.location = value.into();
+        self
+    }
+    #[doc = "Amount of time relative to the message sent time until the location can be updated, in seconds"]
+    pub fn with_live_period(mut self, value: impl Into<i32>) -> Self {
+        self.live_period = value.into();
+        self
+    }
+    #[doc = "Title of the result"]
+    pub fn with_title(mut self, value: impl Into<String>) -> Self {
+        self.title = value.into();
+        self
+    }
+    #[doc = "URL of the result thumbnail, if it exists"]
+    pub fn with_thumbnail_url(mut self, value: impl Into<String>) -> Self {
+        self.thumbnail_url = value.into();
+        self
+    }
+    #[doc = "Thumbnail width, if known"]
+    pub fn with_thumbnail_width(mut self, value: impl Into<i32>) -> Self {
+        self.thumbnail_width = value.into();
+        self
+    }
+    #[doc = "Thumbnail height, if known"]
+    pub fn with_thumbnail_height(mut self, value: impl Into<i32>) -> Self {
+        self.thumbnail_height = value.into();
+        self
+    }
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultLocation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.location
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "location"))?;
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents link to a JPEG image"]
+pub struct InputInlineQueryResultPhoto {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Title of the result, if known"]
+    pub title: String,
+    #[doc = "Represents link to a JPEG image"]
+    pub description: String,
+    #[doc = "URL of the photo thumbnail, if it exists"]
+    pub thumbnail_url: String,
+    #[doc = "The URL of the JPEG photo (photo size must not exceed 5MB)"]
+    pub photo_url: String,
+    #[doc = "Width of the photo"]
+    pub photo_width: i32,
+    #[doc = "Height of the photo"]
+    pub photo_height: i32,
+    #[serde(default)]
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessagePhoto, InputMessageLocation, InputMessageVenue or InputMessageContact"]
+    pub input_message_content: InputMessageContent,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultPhoto {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultPhoto {
+    const TYPE: &'static str = "inputInlineQueryResultPhoto";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultPhoto {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_content: impl Into<InputMessageContent>) -> Self {
+        Self {
+            id: Default::default(),
+            title: Default::default(),
+            description: Default::default(),
+            thumbnail_url: Default::default(),
+            photo_url: Default::default(),
+            photo_width: Default::default(),
+            photo_height: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "Title of the result, if known"]
+    pub fn with_title(mut self, value: impl Into<String>) -> Self {
+        self.title = value.into();
+        self
+    }
+    #[doc = "Represents link to a JPEG image"]
+    pub fn with_description(mut self, value: impl Into<String>) -> Self {
+        self.description = value.into();
+        self
+    }
+    #[doc = "URL of the photo thumbnail, if it exists"]
+    pub fn with_thumbnail_url(mut self, value: impl Into<String>) -> Self {
+        self.thumbnail_url = value.into();
+        self
+    }
+    #[doc = "The URL of the JPEG photo (photo size must not exceed 5MB)"]
+    pub fn with_photo_url(mut self, value: impl Into<String>) -> Self {
+        self.photo_url = value.into();
+        self
+    }
+    #[doc = "Width of the photo"]
+    pub fn with_photo_width(mut self, value: impl Into<i32>) -> Self {
+        self.photo_width = value.into();
+        self
+    }
+    #[doc = "Height of the photo"]
+    pub fn with_photo_height(mut self, value: impl Into<i32>) -> Self {
+        self.photo_height = value.into();
+        self
+    }
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultPhoto {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a link to a WEBP or TGS sticker"]
+pub struct InputInlineQueryResultSticker {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "URL of the sticker thumbnail, if it exists"]
+    pub thumbnail_url: String,
+    #[doc = "The URL of the WEBP or TGS sticker (sticker file size must not exceed 5MB)"]
+    pub sticker_url: String,
+    #[doc = "Width of the sticker"]
+    pub sticker_width: i32,
+    #[doc = "Height of the sticker"]
+    pub sticker_height: i32,
+    #[serde(default)]
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent. Must be one of the following types: InputMessageText, inputMessageSticker, InputMessageLocation, InputMessageVenue or InputMessageContact"]
+    pub input_message_content: InputMessageContent,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultSticker {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultSticker {
+    const TYPE: &'static str = "inputInlineQueryResultSticker";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultSticker {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_content: impl Into<InputMessageContent>) -> Self {
+        Self {
+            id: Default::default(),
+            thumbnail_url: Default::default(),
+            sticker_url: Default::default(),
+            sticker_width: Default::default(),
+            sticker_height: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "URL of the sticker thumbnail, if it exists"]
+    pub fn with_thumbnail_url(mut self, value: impl Into<String>) -> Self {
+        self.thumbnail_url = value.into();
+        self
+    }
+    #[doc = "The URL of the WEBP or TGS sticker (sticker file size must not exceed 5MB)"]
+    pub fn with_sticker_url(mut self, value: impl Into<String>) -> Self {
+        self.sticker_url = value.into();
+        self
+    }
+    #[doc = "Width of the sticker"]
+    pub fn with_sticker_width(mut self, value: impl Into<i32>) -> Self {
+        self.sticker_width = value.into();
+        self
+    }
+    #[doc = "Height of the sticker"]
+    pub fn with_sticker_height(mut self, value: impl Into<i32>) -> Self {
+        self.sticker_height = value.into();
+        self
+    }
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultSticker {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents information about a venue"]
+pub struct InputInlineQueryResultVenue {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Venue result"]
+    pub venue: Venue,
+    #[doc = "URL of the result thumbnail, if it exists"]
+    pub thumbnail_url: String,
+    #[doc = "Thumbnail width, if known"]
+    pub thumbnail_width: i32,
+    #[doc = "Thumbnail height, if known"]
+    pub thumbnail_height: i32,
+    #[serde(default)]
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageLocation, InputMessageVenue or InputMessageContact"]
+    pub input_message_content: InputMessageContent,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultVenue {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultVenue {
+    const TYPE: &'static str = "inputInlineQueryResultVenue";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultVenue {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_content: impl Into<InputMessageContent>) -> Self {
+        Self {
+            id: Default::default(),
+            venue: Default::default(),
+            thumbnail_url: Default::default(),
+            thumbnail_width: Default::default(),
+            thumbnail_height: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "Venue result"]
+    pub fn with_venue(mut self, value: impl Into<Venue>) -> Self {
+        self.venue = value.into();
+        self
+    }
+    #[doc = "URL of the result thumbnail, if it exists"]
+    pub fn with_thumbnail_url(mut self, value: impl Into<String>) -> Self {
+        self.thumbnail_url = value.into();
+        self
+    }
+    #[doc = "Thumbnail width, if known"]
+    pub fn with_thumbnail_width(mut self, value: impl Into<i32>) -> Self {
+        self.thumbnail_width = value.into();
+        self
+    }
+    #[doc = "Thumbnail height, if known"]
+    pub fn with_thumbnail_height(mut self, value: impl Into<i32>) -> Self {
+        self.thumbnail_height = value.into();
+        self
+    }
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultVenue {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.venue
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "venue"))?;
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a link to a page containing an embedded video player or a video file"]
+pub struct InputInlineQueryResultVideo {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Title of the result"]
+    pub title: String,
+    #[doc = "Represents a link to a page containing an embedded video player or a video file"]
+    pub description: String,
+    #[doc = "The URL of the video thumbnail (JPEG), if it exists"]
+    pub thumbnail_url: String,
+    #[doc = "URL of the embedded video player or video file"]
+    pub video_url: String,
+    #[doc = "MIME type of the content of the video URL, only \"text/html\" or \"video/mp4\" are currently supported"]
+    pub mime_type: String,
+    #[doc = "Width of the video"]
+    pub video_width: i32,
+    #[doc = "Height of the video"]
+    pub video_height: i32,
+    #[doc = "Video duration, in seconds"]
+    pub video_duration: i32,
+    #[serde(default)]
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageVideo, InputMessageLocation, InputMessageVenue or InputMessageContact"]
+    pub input_message_content: InputMessageContent,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultVideo {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultVideo {
+    const TYPE: &'static str = "inputInlineQueryResultVideo";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultVideo {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_content: impl Into<InputMessageContent>) -> Self {
+        Self {
+            id: Default::default(),
+            title: Default::default(),
+            description: Default::default(),
+            thumbnail_url: Default::default(),
+            video_url: Default::default(),
+            mime_type: Default::default(),
+            video_width: Default::default(),
+            video_height: Default::default(),
+            video_duration: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "Title of the result"]
+    pub fn with_title(mut self, value: impl Into<String>) -> Self {
+        self.title = value.into();
+        self
+    }
+    #[doc = "Represents a link to a page containing an embedded video player or a video file"]
+    pub fn with_description(mut self, value: impl Into<String>) -> Self {
+        self.description = value.into();
+        self
+    }
+    #[doc = "The URL of the video thumbnail (JPEG), if it exists"]
+    pub fn with_thumbnail_url(mut self, value: impl Into<String>) -> Self {
+        self.thumbnail_url = value.into();
+        self
+    }
+    #[doc = "URL of the embedded video player or video file"]
+    pub fn with_video_url(mut self, value: impl Into<String>) -> Self {
+        self.video_url = value.into();
+        self
+    }
+    #[doc = "MIME type of the content of the video URL, only \"text/html\" or \"video/mp4\" are currently supported"]
+    pub fn with_mime_type(mut self, value: impl Into<String>) -> Self {
+        self.mime_type = value.into();
+        self
+    }
+    #[doc = "Width of the video"]
+    pub fn with_video_width(mut self, value: impl Into<i32>) -> Self {
+        self.video_width = value.into();
+        self
+    }
+    #[doc = "Height of the video"]
+    pub fn with_video_height(mut self, value: impl Into<i32>) -> Self {
+        self.video_height = value.into();
+        self
+    }
+    #[doc = "Video duration, in seconds"]
+    pub fn with_video_duration(mut self, value: impl Into<i32>) -> Self {
+        self.video_duration = value.into();
+        self
+    }
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultVideo {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a link to an opus-encoded audio file within an OGG container, single channel audio"]
+pub struct InputInlineQueryResultVoiceNote {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Title of the voice note"]
+    pub title: String,
+    #[doc = "The URL of the voice note file"]
+    pub voice_note_url: String,
+    #[doc = "Duration of the voice note, in seconds"]
+    pub voice_note_duration: i32,
+    #[serde(default)]
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub reply_markup: Option<ReplyMarkup>,
+    #[doc = "The content of the message to be sent. Must be one of the following types: InputMessageText, InputMessageVoiceNote, InputMessageLocation, InputMessageVenue or InputMessageContact"]
+    pub input_message_content: InputMessageContent,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResultVoiceNote {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InputInlineQueryResultVoiceNote {
+    const TYPE: &'static str = "inputInlineQueryResultVoiceNote";
+}
+#[cfg(feature = "bots")]
+impl InputInlineQueryResultVoiceNote {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(input_message_content: impl Into<InputMessageContent>) -> Self {
+        Self {
+            id: Default::default(),
+            title: Default::default(),
+            voice_note_url: Default::default(),
+            voice_note_duration: Default::default(),
+            reply_markup: Default::default(),
+            input_message_content: input_message_content.into(),
+        }
+    }
+    #[doc = "Unique identifier of the query result"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "Title of the voice note"]
+    pub fn with_title(mut self, value: impl Into<String>) -> Self {
+        self.title = value.into();
+        self
+    }
+    #[doc = "The URL of the voice note file"]
+    pub fn with_voice_note_url(mut self, value: impl Into<String>) -> Self {
+        self.voice_note_url = value.into();
+        self
+    }
+    #[doc = "Duration of the voice note, in seconds"]
+    pub fn with_voice_note_duration(mut self, value: impl Into<i32>) -> Self {
+        self.voice_note_duration = value.into();
+        self
+    }
+    #[doc = "The message reply markup. Must be of type replyMarkupInlineKeyboard or null"]
+    pub fn with_reply_markup(mut self, value: impl Into<ReplyMarkup>) -> Self {
+        self.reply_markup = Some(value.into());
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResultVoiceNote {
+    fn validate(&self) -> Result<(), ValidationError> {
+        if let Some(value) = &self.reply_markup {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "reply_markup"))?;
+        }
+        self.input_message_content
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "input_message_content"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Represents a single result of an inline query; for bots only"]
+pub enum InputInlineQueryResult {
+    InputInlineQueryResultAnimatedGif(Box<InputInlineQueryResultAnimatedGif>),
+    InputInlineQueryResultAnimatedMpeg4(Box<InputInlineQueryResultAnimatedMpeg4>),
+    InputInlineQueryResultArticle(Box<InputInlineQueryResultArticle>),
+    InputInlineQueryResultAudio(Box<InputInlineQueryResultAudio>),
+    InputInlineQueryResultContact(Box<InputInlineQueryResultContact>),
+    InputInlineQueryResultDocument(Box<InputInlineQueryResultDocument>),
+    InputInlineQueryResultGame(InputInlineQueryResultGame),
+    InputInlineQueryResultLocation(Box<InputInlineQueryResultLocation>),
+    InputInlineQueryResultPhoto(Box<InputInlineQueryResultPhoto>),
+    InputInlineQueryResultSticker(Box<InputInlineQueryResultSticker>),
+    InputInlineQueryResultVenue(Box<InputInlineQueryResultVenue>),
+    InputInlineQueryResultVideo(Box<InputInlineQueryResultVideo>),
+    InputInlineQueryResultVoiceNote(Box<InputInlineQueryResultVoiceNote>),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "bots")]
+impl<'de> Deserialize<'de> for InputInlineQueryResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "inputInlineQueryResultAnimatedGif" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultAnimatedGif),
+            "inputInlineQueryResultAnimatedMpeg4" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultAnimatedMpeg4),
+            "inputInlineQueryResultArticle" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultArticle),
+            "inputInlineQueryResultAudio" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultAudio),
+            "inputInlineQueryResultContact" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultContact),
+            "inputInlineQueryResultDocument" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultDocument),
+            "inputInlineQueryResultGame" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultGame),
+            "inputInlineQueryResultLocation" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultLocation),
+            "inputInlineQueryResultPhoto" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultPhoto),
+            "inputInlineQueryResultSticker" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultSticker),
+            "inputInlineQueryResultVenue" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultVenue),
+            "inputInlineQueryResultVideo" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultVideo),
+            "inputInlineQueryResultVoiceNote" => Deserialize::deserialize(value)
+                .map(InputInlineQueryResult::InputInlineQueryResultVoiceNote),
+            _ => Unknown::deserialize(value).map(InputInlineQueryResult::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "bots")]
+impl TlObject for InputInlineQueryResult {
+    const CLASS: &'static str = "InputInlineQueryResult";
+    fn type_name(&self) -> &str {
+        match self {
+            InputInlineQueryResult::InputInlineQueryResultAnimatedGif(_) => {
+                "inputInlineQueryResultAnimatedGif"
+            }
+            InputInlineQueryResult::InputInlineQueryResultAnimatedMpeg4(_) => {
+                "inputInlineQueryResultAnimatedMpeg4"
+            }
+            InputInlineQueryResult::InputInlineQueryResultArticle(_) => {
+                "inputInlineQueryResultArticle"
+            }
+            InputInlineQueryResult::InputInlineQueryResultAudio(_) => "inputInlineQueryResultAudio",
+            InputInlineQueryResult::InputInlineQueryResultContact(_) => {
+                "inputInlineQueryResultContact"
+            }
+            InputInlineQueryResult::InputInlineQueryResultDocument(_) => {
+                "inputInlineQueryResultDocument"
+            }
+            InputInlineQueryResult::InputInlineQueryResultGame(_) => "inputInlineQueryResultGame",
+            InputInlineQueryResult::InputInlineQueryResultLocation(_) => {
+                "inputInlineQueryResultLocation"
+            }
+            InputInlineQueryResult::InputInlineQueryResultPhoto(_) => "inputInlineQueryResultPhoto",
+            InputInlineQueryResult::InputInlineQueryResultSticker(_) => {
+                "inputInlineQueryResultSticker"
+            }
+            InputInlineQueryResult::InputInlineQueryResultVenue(_) => "inputInlineQueryResultVenue",
+            InputInlineQueryResult::InputInlineQueryResultVideo(_) => "inputInlineQueryResultVideo",
+            InputInlineQueryResult::InputInlineQueryResultVoiceNote(_) => {
+                "inputInlineQueryResultVoiceNote"
+            }
+            InputInlineQueryResult::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for InputInlineQueryResult {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            InputInlineQueryResult::InputInlineQueryResultAnimatedGif(value) => value.validate(),
+            InputInlineQueryResult::InputInlineQueryResultAnimatedMpeg4(value) => value.validate(),
+            InputInlineQueryResult::InputInlineQueryResultArticle(value) => value.validate(),
+            InputInlineQueryResult::InputInlineQueryResultAudio(value) => value.validate(),
+            InputInlineQueryResult::InputInlineQueryResultContact(value) => value.validate(),
+            InputInlineQueryResult::InputInlineQueryResultDocument(value) => value.validate(),
+            InputInlineQueryResult::InputInlineQueryResultGame(value) => value.validate(),
+            InputInlineQueryResult::InputInlineQueryResultLocation(value) => value.validate(),
+            InputInlineQueryResult::InputInlineQueryResultPhoto(value) => value.validate(),
+            InputInlineQueryResult::InputInlineQueryResultSticker(value) => value.validate(),
+            InputInlineQueryResult::InputInlineQueryResultVenue(value) => value.validate(),
+            InputInlineQueryResult::InputInlineQueryResultVideo(value) => value.validate(),
+            InputInlineQueryResult::InputInlineQueryResultVoiceNote(value) => value.validate(),
+            InputInlineQueryResult::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultAnimatedGif> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultAnimatedGif) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultAnimatedGif(Box::new(value))
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultAnimatedMpeg4> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultAnimatedMpeg4) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultAnimatedMpeg4(Box::new(value))
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultArticle> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultArticle) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultArticle(Box::new(value))
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultAudio> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultAudio) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultAudio(Box::new(value))
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultContact> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultContact) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultContact(Box::new(value))
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultDocument> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultDocument) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultDocument(Box::new(value))
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultGame> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultGame) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultGame(value)
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultLocation> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultLocation) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultLocation(Box::new(value))
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultPhoto> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultPhoto) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultPhoto(Box::new(value))
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultSticker> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultSticker) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultSticker(Box::new(value))
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultVenue> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultVenue) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultVenue(Box::new(value))
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultVideo> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultVideo) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultVideo(Box::new(value))
+    }
+}
+#[cfg(feature = "bots")]
+impl From<InputInlineQueryResultVoiceNote> for InputInlineQueryResult {
+    fn from(value: InputInlineQueryResultVoiceNote) -> Self {
+        InputInlineQueryResult::InputInlineQueryResultVoiceNote(Box::new(value))
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a link to an article or web page"]
+pub struct InlineQueryResultArticle {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "URL of the result, if it exists"]
+    pub url: String,
+    #[doc = "True, if the URL must be not shown"]
+    pub hide_url: bool,
+    #[doc = "Title of the result"]
+    pub title: String,
+    #[doc = "Represents a link to an article or web page"]
+    pub description: String,
+    #[serde(default)]
+    #[doc = "Result thumbnail; may be null"]
+    pub thumbnail: Option<PhotoSize>,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResultArticle {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResultArticle {
+    const TYPE: &'static str = "inlineQueryResultArticle";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a user contact"]
+pub struct InlineQueryResultContact {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "A user contact"]
+    pub contact: Contact,
+    #[serde(default)]
+    #[doc = "Result thumbnail; may be null"]
+    pub thumbnail: Option<PhotoSize>,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResultContact {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResultContact {
+    const TYPE: &'static str = "inlineQueryResultContact";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a point on the map"]
+pub struct InlineQueryResultLocation {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Location result"]
+    pub location: Location,
+    #[doc = "Title of the result"]
+    pub title: String,
+    #[serde(default)]
+    #[doc = "Result thumbnail; may be null"]
+    pub thumbnail: Option<PhotoSize>,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResultLocation {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResultLocation {
+    const TYPE: &'static str = "inlineQueryResultLocation";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents information about a venue"]
+pub struct InlineQueryResultVenue {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Venue result"]
+    pub venue: Venue,
+    #[serde(default)]
+    #[doc = "Result thumbnail; may be null"]
+    pub thumbnail: Option<PhotoSize>,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResultVenue {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResultVenue {
+    const TYPE: &'static str = "inlineQueryResultVenue";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents information about a game"]
+pub struct InlineQueryResultGame {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Game result"]
+    pub game: Game,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResultGame {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResultGame {
+    const TYPE: &'static str = "inlineQueryResultGame";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents an animation file"]
+pub struct InlineQueryResultAnimation {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Animation file"]
+    pub animation: Animation,
+    #[doc = "Animation title"]
+    pub title: String,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResultAnimation {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResultAnimation {
+    const TYPE: &'static str = "inlineQueryResultAnimation";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents an audio file"]
+pub struct InlineQueryResultAudio {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Audio file"]
+    pub audio: Audio,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResultAudio {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResultAudio {
+    const TYPE: &'static str = "inlineQueryResultAudio";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a document"]
+pub struct InlineQueryResultDocument {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Document"]
+    pub document: Document,
+    #[doc = "Document title"]
+    pub title: String,
+    #[doc = "Represents a document"]
+    pub description: String,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResultDocument {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResultDocument {
+    const TYPE: &'static str = "inlineQueryResultDocument";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a photo"]
+pub struct InlineQueryResultPhoto {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Photo"]
+    pub photo: Photo,
+    #[doc = "Title of the result, if known"]
+    pub title: String,
+    #[doc = "Represents a photo"]
+    pub description: String,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResultPhoto {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResultPhoto {
+    const TYPE: &'static str = "inlineQueryResultPhoto";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a sticker"]
+pub struct InlineQueryResultSticker {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Sticker"]
+    pub sticker: Sticker,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResultSticker {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResultSticker {
+    const TYPE: &'static str = "inlineQueryResultSticker";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a video"]
+pub struct InlineQueryResultVideo {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Video"]
+    pub video: Video,
+    #[doc = "Title of the video"]
+    pub title: String,
+    #[doc = "Represents a video"]
+    pub description: String,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResultVideo {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResultVideo {
+    const TYPE: &'static str = "inlineQueryResultVideo";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a voice note"]
+pub struct InlineQueryResultVoiceNote {
+    #[doc = "Unique identifier of the query result"]
+    pub id: String,
+    #[doc = "Voice note"]
+    pub voice_note: VoiceNote,
+    #[doc = "Title of the voice note"]
+    pub title: String,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResultVoiceNote {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResultVoiceNote {
+    const TYPE: &'static str = "inlineQueryResultVoiceNote";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Represents a single result of an inline query"]
+pub enum InlineQueryResult {
+    InlineQueryResultArticle(Box<InlineQueryResultArticle>),
+    InlineQueryResultContact(Box<InlineQueryResultContact>),
+    InlineQueryResultLocation(Box<InlineQueryResultLocation>),
+    InlineQueryResultVenue(Box<InlineQueryResultVenue>),
+    InlineQueryResultGame(Box<InlineQueryResultGame>),
+    InlineQueryResultAnimation(Box<InlineQueryResultAnimation>),
+    InlineQueryResultAudio(Box<InlineQueryResultAudio>),
+    InlineQueryResultDocument(Box<InlineQueryResultDocument>),
+    InlineQueryResultPhoto(Box<InlineQueryResultPhoto>),
+    InlineQueryResultSticker(Box<InlineQueryResultSticker>),
+    InlineQueryResultVideo(Box<InlineQueryResultVideo>),
+    InlineQueryResultVoiceNote(Box<InlineQueryResultVoiceNote>),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "bots")]
+impl<'de> Deserialize<'de> for InlineQueryResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "inlineQueryResultArticle" => {
+                Deserialize::deserialize(value).map(InlineQueryResult::InlineQueryResultArticle)
+            }
+            "inlineQueryResultContact" => {
+                Deserialize::deserialize(value).map(InlineQueryResult::InlineQueryResultContact)
+            }
+            "inlineQueryResultLocation" => {
+                Deserialize::deserialize(value).map(InlineQueryResult::InlineQueryResultLocation)
+            }
+            "inlineQueryResultVenue" => {
+                Deserialize::deserialize(value).map(InlineQueryResult::InlineQueryResultVenue)
+            }
+            "inlineQueryResultGame" => {
+                Deserialize::deserialize(value).map(InlineQueryResult::InlineQueryResultGame)
+            }
+            "inlineQueryResultAnimation" => {
+                Deserialize::deserialize(value).map(InlineQueryResult::InlineQueryResultAnimation)
+            }
+            "inlineQueryResultAudio" => {
+                Deserialize::deserialize(value).map(InlineQueryResult::InlineQueryResultAudio)
+            }
+            "inlineQueryResultDocument" => {
+                Deserialize::deserialize(value).map(InlineQueryResult::InlineQueryResultDocument)
+            }
+            "inlineQueryResultPhoto" => {
+                Deserialize::deserialize(value).map(InlineQueryResult::InlineQueryResultPhoto)
+            }
+            "inlineQueryResultSticker" => {
+                Deserialize::deserialize(value).map(InlineQueryResult::InlineQueryResultSticker)
+            }
+            "inlineQueryResultVideo" => {
+                Deserialize::deserialize(value).map(InlineQueryResult::InlineQueryResultVideo)
+            }
+            "inlineQueryResultVoiceNote" => {
+                Deserialize::deserialize(value).map(InlineQueryResult::InlineQueryResultVoiceNote)
+            }
+            _ => Unknown::deserialize(value).map(InlineQueryResult::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResult {
+    const CLASS: &'static str = "InlineQueryResult";
+    fn type_name(&self) -> &str {
+        match self {
+            InlineQueryResult::InlineQueryResultArticle(_) => "inlineQueryResultArticle",
+            InlineQueryResult::InlineQueryResultContact(_) => "inlineQueryResultContact",
+            InlineQueryResult::InlineQueryResultLocation(_) => "inlineQueryResultLocation",
+            InlineQueryResult::InlineQueryResultVenue(_) => "inlineQueryResultVenue",
+            InlineQueryResult::InlineQueryResultGame(_) => "inlineQueryResultGame",
+            InlineQueryResult::InlineQueryResultAnimation(_) => "inlineQueryResultAnimation",
+            InlineQueryResult::InlineQueryResultAudio(_) => "inlineQueryResultAudio",
+            InlineQueryResult::InlineQueryResultDocument(_) => "inlineQueryResultDocument",
+            InlineQueryResult::InlineQueryResultPhoto(_) => "inlineQueryResultPhoto",
+            InlineQueryResult::InlineQueryResultSticker(_) => "inlineQueryResultSticker",
+            InlineQueryResult::InlineQueryResultVideo(_) => "inlineQueryResultVideo",
+            InlineQueryResult::InlineQueryResultVoiceNote(_) => "inlineQueryResultVoiceNote",
+            InlineQueryResult::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents the results of the inline query. Use sendInlineQueryResultMessage to send the result of the query"]
+pub struct InlineQueryResults {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Unique identifier of the inline query"]
+    pub inline_query_id: i64,
+    #[doc = "The offset for the next request. If empty, there are no more results"]
+    pub next_offset: String,
+    #[doc = "Results of the query"]
+    pub results: Vec<InlineQueryResult>,
+    #[doc = "If non-empty, this text should be shown on the button, which opens a private chat with the bot and sends the bot a start message with the switch_pm_parameter"]
+    pub switch_pm_text: String,
+    #[doc = "Parameter for the bot start message"]
+    pub switch_pm_parameter: String,
+}
+#[cfg(feature = "bots")]
+impl TlObject for InlineQueryResults {
+    const CLASS: &'static str = "InlineQueryResults";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for InlineQueryResults {
+    const TYPE: &'static str = "inlineQueryResults";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The payload from a general callback button"]
+pub struct CallbackQueryPayloadData {
+    #[doc = "Data that was attached to the callback button"]
+    pub data: Bytes,
+}
+#[cfg(feature = "bots")]
+impl TlObject for CallbackQueryPayloadData {
+    const CLASS: &'static str = "CallbackQueryPayload";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for CallbackQueryPayloadData {
+    const TYPE: &'static str = "callbackQueryPayloadData";
+}
+#[cfg(feature = "bots")]
+impl CallbackQueryPayloadData {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Data that was attached to the callback button"]
+    pub fn with_data(mut self, value: impl Into<Bytes>) -> Self {
+        self.data = value.into();
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for CallbackQueryPayloadData {}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The payload from a game callback button"]
+pub struct CallbackQueryPayloadGame {
+    #[doc = "A short name of the game that was attached to the callback button"]
+    pub game_short_name: String,
+}
+#[cfg(feature = "bots")]
+impl TlObject for CallbackQueryPayloadGame {
+    const CLASS: &'static str = "CallbackQueryPayload";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for CallbackQueryPayloadGame {
+    const TYPE: &'static str = "callbackQueryPayloadGame";
+}
+#[cfg(feature = "bots")]
+impl CallbackQueryPayloadGame {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "A short name of the game that was attached to the callback button"]
+    pub fn with_game_short_name(mut self, value: impl Into<String>) -> Self {
+        self.game_short_name = value.into();
+        self
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for CallbackQueryPayloadGame {}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Represents a payload of a callback query"]
+pub enum CallbackQueryPayload {
+    CallbackQueryPayloadData(CallbackQueryPayloadData),
+    CallbackQueryPayloadGame(CallbackQueryPayloadGame),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "bots")]
+impl<'de> Deserialize<'de> for CallbackQueryPayload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "callbackQueryPayloadData" => {
+                Deserialize::deserialize(value).map(CallbackQueryPayload::CallbackQueryPayloadData)
+            }
+            "callbackQueryPayloadGame" => {
+                Deserialize::deserialize(value).map(CallbackQueryPayload::CallbackQueryPayloadGame)
+            }
+            _ => Unknown::deserialize(value).map(CallbackQueryPayload::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "bots")]
+impl TlObject for CallbackQueryPayload {
+    const CLASS: &'static str = "CallbackQueryPayload";
+    fn type_name(&self) -> &str {
+        match self {
+            CallbackQueryPayload::CallbackQueryPayloadData(_) => "callbackQueryPayloadData",
+            CallbackQueryPayload::CallbackQueryPayloadGame(_) => "callbackQueryPayloadGame",
+            CallbackQueryPayload::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "bots")]
+impl Validate for CallbackQueryPayload {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            CallbackQueryPayload::CallbackQueryPayloadData(value) => value.validate(),
+            CallbackQueryPayload::CallbackQueryPayloadGame(value) => value.validate(),
+            CallbackQueryPayload::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(feature = "bots")]
+impl From<CallbackQueryPayloadData> for CallbackQueryPayload {
+    fn from(value: CallbackQueryPayloadData) -> Self {
+        CallbackQueryPayload::CallbackQueryPayloadData(value)
+    }
+}
+#[cfg(feature = "bots")]
+impl From<CallbackQueryPayloadGame> for CallbackQueryPayload {
+    fn from(value: CallbackQueryPayloadGame) -> Self {
+        CallbackQueryPayload::CallbackQueryPayloadGame(value)
+    }
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a bot's answer to a callback query"]
+pub struct CallbackQueryAnswer {
+    #[doc = "Text of the answer"]
+    pub text: String,
+    #[doc = "True, if an alert should be shown to the user instead of a toast notification"]
+    pub show_alert: bool,
+    #[doc = "URL to be opened"]
+    pub url: String,
+}
+#[cfg(feature = "bots")]
+impl TlObject for CallbackQueryAnswer {
+    const CLASS: &'static str = "CallbackQueryAnswer";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for CallbackQueryAnswer {
+    const TYPE: &'static str = "callbackQueryAnswer";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains the result of a custom request"]
+pub struct CustomRequestResult {
+    #[doc = "A JSON-serialized result"]
+    pub result: String,
+}
+#[cfg(feature = "bots")]
+impl TlObject for CustomRequestResult {
+    const CLASS: &'static str = "CustomRequestResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for CustomRequestResult {
+    const TYPE: &'static str = "customRequestResult";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains one row of the game high score table"]
+pub struct GameHighScore {
+    #[doc = "Position in the high score table"]
+    pub position: i32,
+    #[doc = "User identifier"]
+    pub user_id: UserId,
+    #[doc = "User score"]
+    pub score: i32,
+}
+#[cfg(feature = "bots")]
+impl TlObject for GameHighScore {
+    const CLASS: &'static str = "GameHighScore";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for GameHighScore {
+    const TYPE: &'static str = "gameHighScore";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a list of game high scores"]
+pub struct GameHighScores {
+    #[doc = "A list of game high scores"]
+    pub scores: Vec<GameHighScore>,
+}
+#[cfg(feature = "bots")]
+impl TlObject for GameHighScores {
+    const CLASS: &'static str = "GameHighScores";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for GameHighScores {
+    const TYPE: &'static str = "gameHighScores";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The default chat reply markup was changed. Can occur because new messages with reply markup were received or because an old reply markup was hidden by the user"]
+pub struct UpdateChatReplyMarkup {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message from which reply markup needs to be used; 0 if there is no default custom reply markup in the chat"]
+    pub reply_markup_message_id: MessageId,
+}
+#[cfg(feature = "bots")]
+impl TlObject for UpdateChatReplyMarkup {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for UpdateChatReplyMarkup {
+    const TYPE: &'static str = "updateChatReplyMarkup";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new incoming inline query; for bots only"]
+pub struct UpdateNewInlineQuery {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Unique query identifier"]
+    pub id: i64,
+    #[doc = "Identifier of the user who sent the query"]
+    pub sender_user_id: UserId,
+    #[serde(default)]
+    #[doc = "User location, provided by the client; may be null"]
+    pub user_location: Option<Location>,
+    #[doc = "Text of the query"]
+    pub query: String,
+    #[doc = "Offset of the first entry to return"]
+    pub offset: String,
+}
+#[cfg(feature = "bots")]
+impl TlObject for UpdateNewInlineQuery {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for UpdateNewInlineQuery {
+    const TYPE: &'static str = "updateNewInlineQuery";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The user has chosen a result of an inline query; for bots only"]
+pub struct UpdateNewChosenInlineResult {
+    #[doc = "Identifier of the user who sent the query"]
+    pub sender_user_id: UserId,
+    #[serde(default)]
+    #[doc = "User location, provided by the client; may be null"]
+    pub user_location: Option<Location>,
+    #[doc = "Text of the query"]
+    pub query: String,
+    #[doc = "Identifier of the chosen result"]
+    pub result_id: String,
+    #[doc = "Identifier of the sent inline message, if known"]
+    pub inline_message_id: String,
+}
+#[cfg(feature = "bots")]
+impl TlObject for UpdateNewChosenInlineResult {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for UpdateNewChosenInlineResult {
+    const TYPE: &'static str = "updateNewChosenInlineResult";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new incoming callback query; for bots only"]
+pub struct UpdateNewCallbackQuery {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Unique query identifier"]
+    pub id: i64,
+    #[doc = "Identifier of the user who sent the query"]
+    pub sender_user_id: UserId,
+    #[doc = "Identifier of the chat where the query was sent"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the message, from which the query originated"]
+    pub message_id: MessageId,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier that uniquely corresponds to the chat to which the message was sent"]
+    pub chat_instance: i64,
+    #[doc = "Query payload"]
+    pub payload: CallbackQueryPayload,
+}
+#[cfg(feature = "bots")]
+impl TlObject for UpdateNewCallbackQuery {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for UpdateNewCallbackQuery {
+    const TYPE: &'static str = "updateNewCallbackQuery";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new incoming callback query from a message sent via a bot; for bots only"]
+pub struct UpdateNewInlineCallbackQuery {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Unique query identifier"]
+    pub id: i64,
+    #[doc = "Identifier of the user who sent the query"]
+    pub sender_user_id: UserId,
+    #[doc = "Identifier of the inline message, from which the query originated"]
+    pub inline_message_id: String,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "An identifier uniquely corresponding to the chat a message was sent to"]
+    pub chat_instance: i64,
+    #[doc = "Query payload"]
+    pub payload: CallbackQueryPayload,
+}
+#[cfg(feature = "bots")]
+impl TlObject for UpdateNewInlineCallbackQuery {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for UpdateNewInlineCallbackQuery {
+    const TYPE: &'static str = "updateNewInlineCallbackQuery";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new incoming event; for bots only"]
+pub struct UpdateNewCustomEvent {
+    #[doc = "A JSON-serialized event"]
+    pub event: String,
+}
+#[cfg(feature = "bots")]
+impl TlObject for UpdateNewCustomEvent {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for UpdateNewCustomEvent {
+    const TYPE: &'static str = "updateNewCustomEvent";
+}
+#[cfg(feature = "bots")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new incoming query; for bots only"]
+pub struct UpdateNewCustomQuery {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "The query identifier"]
+    pub id: i64,
+    #[doc = "JSON-serialized query data"]
+    pub data: String,
+    #[doc = "Query timeout"]
+    pub timeout: i32,
+}
+#[cfg(feature = "bots")]
+impl TlObject for UpdateNewCustomQuery {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "bots")]
+impl TlConstructor for UpdateNewCustomQuery {
+    const TYPE: &'static str = "updateNewCustomQuery";
+}
@@ -0,0 +1,546 @@
+//! The types of the `calls` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Specifies the supported call protocols"]
+pub struct CallProtocol {
+    #[doc = "True, if UDP peer-to-peer connections are supported"]
+    pub udp_p2p: bool,
+    #[doc = "True, if connection through UDP reflectors is supported"]
+    pub udp_reflector: bool,
+    #[doc = "The minimum supported API layer; use 65"]
+    pub min_layer: i32,
+    #[doc = "The maximum supported API layer; use 65"]
+    pub max_layer: i32,
+    #[doc = "List of supported libtgvoip versions"]
+    pub library_versions: Vec<String>,
+}
+impl TlObject for CallProtocol {
+    const CLASS: &'static str = "CallProtocol";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallProtocol {
+    const TYPE: &'static str = "callProtocol";
+}
+impl CallProtocol {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "True, if UDP peer-to-peer connections are supported"]
+    pub fn with_udp_p2p(mut self, value: impl Into<bool>) -> Self {
+        self.udp_p2p = value.into();
+        self
+    }
+    #[doc = "True, if connection through UDP reflectors is supported"]
+    pub fn with_udp_reflector(mut self, value: impl Into<bool>) -> Self {
+        self.udp_reflector = value.into();
+        self
+    }
+    #[doc = "The minimum supported API layer; use 65"]
+    pub fn with_min_layer(mut self, value: impl Into<i32>) -> Self {
+        self.min_layer = value.into();
+        self
+    }
+    #[doc = "The maximum supported API layer; use 65"]
+    pub fn with_max_layer(mut self, value: impl Into<i32>) -> Self {
+        self.max_layer = value.into();
+        self
+    }
+    #[doc = "List of supported libtgvoip versions"]
+    pub fn with_library_versions(mut self, value: impl Into<Vec<String>>) -> Self {
+        self.library_versions = value.into();
+        self
+    }
+}
+impl Validate for CallProtocol {}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Describes the address of UDP reflectors"]
+pub struct CallConnection {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Reflector identifier"]
+    pub id: i64,
+    #[doc = "IPv4 reflector address"]
+    pub ip: String,
+    #[doc = "IPv6 reflector address"]
+    pub ipv6: String,
+    #[doc = "Reflector port number"]
+    pub port: i32,
+    #[doc = "Connection peer tag"]
+    pub peer_tag: Bytes,
+}
+impl TlObject for CallConnection {
+    const CLASS: &'static str = "CallConnection";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallConnection {
+    const TYPE: &'static str = "callConnection";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains the call identifier"]
+pub struct CallId {
+    #[doc = "Call identifier"]
+    pub id: i32,
+}
+impl TlObject for CallId {
+    const CLASS: &'static str = "CallId";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallId {
+    const TYPE: &'static str = "callId";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The call is pending, waiting to be accepted by a user"]
+pub struct CallStatePending {
+    #[doc = "True, if the call has already been created by the server"]
+    pub is_created: bool,
+    #[doc = "True, if the call has already been received by the other party"]
+    pub is_received: bool,
+}
+impl TlObject for CallStatePending {
+    const CLASS: &'static str = "CallState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallStatePending {
+    const TYPE: &'static str = "callStatePending";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The call has been answered and encryption keys are being exchanged"]
+pub struct CallStateExchangingKeys {}
+impl TlObject for CallStateExchangingKeys {
+    const CLASS: &'static str = "CallState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallStateExchangingKeys {
+    const TYPE: &'static str = "callStateExchangingKeys";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The call is ready to use"]
+pub struct CallStateReady {
+    #[doc = "Call protocols supported by the peer"]
+    pub protocol: CallProtocol,
+    #[doc = "Available UDP reflectors"]
+    pub connections: Vec<CallConnection>,
+    #[doc = "A JSON-encoded call config"]
+    pub config: String,
+    #[doc = "Call encryption key"]
+    pub encryption_key: Bytes,
+    #[doc = "Encryption key emojis fingerprint"]
+    pub emojis: Vec<String>,
+    #[doc = "True, if peer-to-peer connection is allowed by users privacy settings"]
+    pub allow_p2p: bool,
+}
+impl TlObject for CallStateReady {
+    const CLASS: &'static str = "CallState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallStateReady {
+    const TYPE: &'static str = "callStateReady";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The call is hanging up after discardCall has been called"]
+pub struct CallStateHangingUp {}
+impl TlObject for CallStateHangingUp {
+    const CLASS: &'static str = "CallState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallStateHangingUp {
+    const TYPE: &'static str = "callStateHangingUp";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The call has ended successfully"]
+pub struct CallStateDiscarded {
+    #[doc = "The reason, why the call has ended"]
+    pub reason: CallDiscardReason,
+    #[doc = "True, if the call rating should be sent to the server"]
+    pub need_rating: bool,
+    #[doc = "True, if the call debug information should be sent to the server"]
+    pub need_debug_information: bool,
+}
+impl TlObject for CallStateDiscarded {
+    const CLASS: &'static str = "CallState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallStateDiscarded {
+    const TYPE: &'static str = "callStateDiscarded";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The call has ended with an error"]
+pub struct CallStateError {
+    #[doc = "Error. An error with the code 4005000 will be returned if an outgoing call is missed because of an expired timeout"]
+    pub error: Error,
+}
+impl TlObject for CallStateError {
+    const CLASS: &'static str = "CallState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallStateError {
+    const TYPE: &'static str = "callStateError";
+}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes the current call state"]
+pub enum CallState {
+    CallStatePending(CallStatePending),
+    CallStateExchangingKeys(CallStateExchangingKeys),
+    CallStateReady(Box<CallStateReady>),
+    CallStateHangingUp(CallStateHangingUp),
+    CallStateDiscarded(CallStateDiscarded),
+    CallStateError(CallStateError),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for CallState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "callStatePending" => Deserialize::deserialize(value).map(CallState::CallStatePending),
+            "callStateExchangingKeys" => {
+                Deserialize::deserialize(value).map(CallState::CallStateExchangingKeys)
+            }
+            "callStateReady" => Deserialize::deserialize(value).map(CallState::CallStateReady),
+            "callStateHangingUp" => {
+                Deserialize::deserialize(value).map(CallState::CallStateHangingUp)
+            }
+            "callStateDiscarded" => {
+                Deserialize::deserialize(value).map(CallState::CallStateDiscarded)
+            }
+            "callStateError" => Deserialize::deserialize(value).map(CallState::CallStateError),
+            _ => Unknown::deserialize(value).map(CallState::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for CallState {
+    const CLASS: &'static str = "CallState";
+    fn type_name(&self) -> &str {
+        match self {
+            CallState::CallStatePending(_) => "callStatePending",
+            CallState::CallStateExchangingKeys(_) => "callStateExchangingKeys",
+            CallState::CallStateReady(_) => "callStateReady",
+            CallState::CallStateHangingUp(_) => "callStateHangingUp",
+            CallState::CallStateDiscarded(_) => "callStateDiscarded",
+            CallState::CallStateError(_) => "callStateError",
+            CallState::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user heard their own voice"]
+pub struct CallProblemEcho {}
+impl TlObject for CallProblemEcho {
+    const CLASS: &'static str = "CallProblem";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallProblemEcho {
+    const TYPE: &'static str = "callProblemEcho";
+}
+impl CallProblemEcho {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for CallProblemEcho {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user heard background noise"]
+pub struct CallProblemNoise {}
+impl TlObject for CallProblemNoise {
+    const CLASS: &'static str = "CallProblem";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallProblemNoise {
+    const TYPE: &'static str = "callProblemNoise";
+}
+impl CallProblemNoise {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for CallProblemNoise {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The other side kept disappearing"]
+pub struct CallProblemInterruptions {}
+impl TlObject for CallProblemInterruptions {
+    const CLASS: &'static str = "CallProblem";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallProblemInterruptions {
+    const TYPE: &'static str = "callProblemInterruptions";
+}
+impl CallProblemInterruptions {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for CallProblemInterruptions {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The speech was distorted"]
+pub struct CallProblemDistortedSpeech {}
+impl TlObject for CallProblemDistortedSpeech {
+    const CLASS: &'static str = "CallProblem";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallProblemDistortedSpeech {
+    const TYPE: &'static str = "callProblemDistortedSpeech";
+}
+impl CallProblemDistortedSpeech {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for CallProblemDistortedSpeech {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user couldn't hear the other side"]
+pub struct CallProblemSilentLocal {}
+impl TlObject for CallProblemSilentLocal {
+    const CLASS: &'static str = "CallProblem";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallProblemSilentLocal {
+    const TYPE: &'static str = "callProblemSilentLocal";
+}
+impl CallProblemSilentLocal {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for CallProblemSilentLocal {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The other side couldn't hear the user"]
+pub struct CallProblemSilentRemote {}
+impl TlObject for CallProblemSilentRemote {
+    const CLASS: &'static str = "CallProblem";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallProblemSilentRemote {
+    const TYPE: &'static str = "callProblemSilentRemote";
+}
+impl CallProblemSilentRemote {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for CallProblemSilentRemote {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The call ended unexpectedly"]
+pub struct CallProblemDropped {}
+impl TlObject for CallProblemDropped {
+    const CLASS: &'static str = "CallProblem";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for CallProblemDropped {
+    const TYPE: &'static str = "callProblemDropped";
+}
+impl CallProblemDropped {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for CallProblemDropped {}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes the exact type of a problem with a call"]
+pub enum CallProblem {
+    CallProblemEcho(CallProblemEcho),
+    CallProblemNoise(CallProblemNoise),
+    CallProblemInterruptions(CallProblemInterruptions),
+    CallProblemDistortedSpeech(CallProblemDistortedSpeech),
+    CallProblemSilentLocal(CallProblemSilentLocal),
+    CallProblemSilentRemote(CallProblemSilentRemote),
+    CallProblemDropped(CallProblemDropped),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for CallProblem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "callProblemEcho" => Deserialize::deserialize(value).map(CallProblem::CallProblemEcho),
+            "callProblemNoise" => {
+                Deserialize::deserialize(value).map(CallProblem::CallProblemNoise)
+            }
+            "callProblemInterruptions" => {
+                Deserialize::deserialize(value).map(CallProblem::CallProblemInterruptions)
+            }
+            "callProblemDistortedSpeech" => {
+                Deserialize::deserialize(value).map(CallProblem::CallProblemDistortedSpeech)
+            }
+            "callProblemSilentLocal" => {
+                Deserialize::deserialize(value).map(CallProblem::CallProblemSilentLocal)
+            }
+            "callProblemSilentRemote" => {
+                Deserialize::deserialize(value).map(CallProblem::CallProblemSilentRemote)
+            }
+            "callProblemDropped" => {
+                Deserialize::deserialize(value).map(CallProblem::CallProblemDropped)
+            }
+            _ => Unknown::deserialize(value).map(CallProblem::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for CallProblem {
+    const CLASS: &'static str = "CallProblem";
+    fn type_name(&self) -> &str {
+        match self {
+            CallProblem::CallProblemEcho(_) => "callProblemEcho",
+            CallProblem::CallProblemNoise(_) => "callProblemNoise",
+            CallProblem::CallProblemInterruptions(_) => "callProblemInterruptions",
+            CallProblem::CallProblemDistortedSpeech(_) => "callProblemDistortedSpeech",
+            CallProblem::CallProblemSilentLocal(_) => "callProblemSilentLocal",
+            CallProblem::CallProblemSilentRemote(_) => "callProblemSilentRemote",
+            CallProblem::CallProblemDropped(_) => "callProblemDropped",
+            CallProblem::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for CallProblem {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            CallProblem::CallProblemEcho(value) => value.validate(),
+            CallProblem::CallProblemNoise(value) => value.validate(),
+            CallProblem::CallProblemInterruptions(value) => value.validate(),
+            CallProblem::CallProblemDistortedSpeech(value) => value.validate(),
+            CallProblem::CallProblemSilentLocal(value) => value.validate(),
+            CallProblem::CallProblemSilentRemote(value) => value.validate(),
+            CallProblem::CallProblemDropped(value) => value.validate(),
+            CallProblem::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<CallProblemEcho> for CallProblem {
+    fn from(value: CallProblemEcho) -> Self {
+        CallProblem::CallProblemEcho(value)
+    }
+}
+impl From<CallProblemNoise> for CallProblem {
+    fn from(value: CallProblemNoise) -> Self {
+        CallProblem::CallProblemNoise(value)
+    }
+}
+impl From<CallProblemInterruptions> for CallProblem {
+    fn from(value: CallProblemInterruptions) -> Self {
+        CallProblem::CallProblemInterruptions(value)
+    }
+}
+impl From<CallProblemDistortedSpeech> for CallProblem {
+    fn from(value: CallProblemDistortedSpeech) -> Self {
+        CallProblem::CallProblemDistortedSpeech(value)
+    }
+}
+impl From<CallProblemSilentLocal> for CallProblem {
+    fn from(value: CallProblemSilentLocal) -> Self {
+        CallProblem::CallProblemSilentLocal(value)
+    }
+}
+impl From<CallProblemSilentRemote> for CallProblem {
+    fn from(value: CallProblemSilentRemote) -> Self {
+        CallProblem::CallProblemSilentRemote(value)
+    }
+}
+impl From<CallProblemDropped> for CallProblem {
+    fn from(value: CallProblemDropped) -> Self {
+        CallProblem::CallProblemDropped(value)
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Describes a call"]
+pub struct Call {
+    #[doc = "Call identifier, not persistent"]
+    pub id: i32,
+    #[doc = "Peer user identifier"]
+    pub user_id: UserId,
+    #[doc = "True, if the call is outgoing"]
+    pub is_outgoing: bool,
+    #[doc = "Call state"]
+    pub state: CallState,
+}
+impl TlObject for Call {
+    const CLASS: &'static str = "Call";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for Call {
+    const TYPE: &'static str = "call";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "New call was received"]
+pub struct NotificationTypeNewCall {
+    #[doc = "Call identifier"]
+    pub call_id: i32,
+}
+impl TlObject for NotificationTypeNewCall {
+    const CLASS: &'static str = "NotificationType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for NotificationTypeNewCall {
+    const TYPE: &'static str = "notificationTypeNewCall";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "New call was created or information about a call was updated"]
+pub struct UpdateCall {
+    #[doc = "New data about a call"]
+    pub call: Call,
+}
+impl TlObject for UpdateCall {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for UpdateCall {
+    const TYPE: &'static str = "updateCall";
+}
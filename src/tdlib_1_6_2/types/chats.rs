@@ -0,0 +1,4578 @@
+//! The types of the `chats` feature.
+
+use super::*;
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a location to which a chat is connected"]
+pub struct ChatLocation {
+    #[doc = "The location"]
+    pub location: Location,
+    #[doc = "Location address; 1-64 characters, as defined by the chat owner"]
+    pub address: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatLocation {
+    const CLASS: &'static str = "ChatLocation";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatLocation {
+    const TYPE: &'static str = "chatLocation";
+}
+#[cfg(feature = "chats")]
+impl ChatLocation {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            location: Default::default(),
+            address: address.into(),
+        }
+    }
+    #[doc = "The location"]
+    pub fn with_location(mut self, value: impl Into<Location>) -> Self {
+        self.location = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatLocation {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.location
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "location"))?;
+        validate::check(
+            Self::TYPE,
+            "address",
+            Measure::Characters,
+            self.address.chars().count() as i64,
+            Some(1),
+            Some(64),
+        )?;
+        Ok(())
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about a chat administrator"]
+pub struct ChatAdministrator {
+    #[doc = "User identifier of the administrator"]
+    pub user_id: UserId,
+    #[doc = "Custom title of the administrator"]
+    pub custom_title: String,
+    #[doc = "True, if the user is the owner of the chat"]
+    pub is_owner: bool,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatAdministrator {
+    const CLASS: &'static str = "ChatAdministrator";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatAdministrator {
+    const TYPE: &'static str = "chatAdministrator";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a list of chat administrators"]
+pub struct ChatAdministrators {
+    #[doc = "A list of chat administrators"]
+    pub administrators: Vec<ChatAdministrator>,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatAdministrators {
+    const CLASS: &'static str = "ChatAdministrators";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatAdministrators {
+    const TYPE: &'static str = "chatAdministrators";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Describes actions that a user is allowed to take in a chat"]
+pub struct ChatPermissions {
+    #[doc = "True, if the user can send text messages, contacts, locations, and venues"]
+    pub can_send_messages: bool,
+    #[doc = "True, if the user can send audio files, documents, photos, videos, video notes, and voice notes. Implies can_send_messages permissions"]
+    pub can_send_media_messages: bool,
+    #[doc = "True, if the user can send polls. Implies can_send_messages permissions"]
+    pub can_send_polls: bool,
+    #[doc = "True, if the user can send animations, games, and stickers and use inline bots. Implies can_send_messages permissions"]
+    pub can_send_other_messages: bool,
+    #[doc = "True, if the user may add a web page preview to their messages. Implies can_send_messages permissions"]
+    pub can_add_web_page_previews: bool,
+    #[doc = "True, if the user can change the chat title, photo, and other settings"]
+    pub can_change_info: bool,
+    #[doc = "True, if the user can invite new users to the chat"]
+    pub can_invite_users: bool,
+    #[doc = "True, if the user can pin messages"]
+    pub can_pin_messages: bool,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatPermissions {
+    const CLASS: &'static str = "ChatPermissions";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatPermissions {
+    const TYPE: &'static str = "chatPermissions";
+}
+#[cfg(feature = "chats")]
+impl ChatPermissions {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "True, if the user can send text messages, contacts, locations, and venues"]
+    pub fn with_can_send_messages(mut self, value: impl Into<bool>) -> Self {
+        self.can_send_messages = value.into();
+        self
+    }
+    #[doc = "True, if the user can send audio files, documents, photos, videos, video notes, and voice notes. Implies can_send_messages permissions"]
+    pub fn with_can_send_media_messages(mut self, value: impl Into<bool>) -> Self {
+        self.can_send_media_messages = value.into();
+        self
+    }
+    #[doc = "True, if the user can send polls. Implies can_send_messages permissions"]
+    pub fn with_can_send_polls(mut self, value: impl Into<bool>) -> Self {
+        self.can_send_polls = value.into();
+        self
+    }
+    #[doc = "True, if the user can send animations, games, and stickers and use inline bots. Implies can_send_messages permissions"]
+    pub fn with_can_send_other_messages(mut self, value: impl Into<bool>) -> Self {
+        self.can_send_other_messages = value.into();
+        self
+    }
+    #[doc = "True, if the user may add a web page preview to their messages. Implies can_send_messages permissions"]
+    pub fn with_can_add_web_page_previews(mut self, value: impl Into<bool>) -> Self {
+        self.can_add_web_page_previews = value.into();
+        self
+    }
+    #[doc = "True, if the user can change the chat title, photo, and other settings"]
+    pub fn with_can_change_info(mut self, value: impl Into<bool>) -> Self {
+        self.can_change_info = value.into();
+        self
+    }
+    #[doc = "True, if the user can invite new users to the chat"]
+    pub fn with_can_invite_users(mut self, value: impl Into<bool>) -> Self {
+        self.can_invite_users = value.into();
+        self
+    }
+    #[doc = "True, if the user can pin messages"]
+    pub fn with_can_pin_messages(mut self, value: impl Into<bool>) -> Self {
+        self.can_pin_messages = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatPermissions {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is the owner of a chat and has all the administrator privileges"]
+pub struct ChatMemberStatusCreator {
+    #[doc = "A custom title of the owner; 0-16 characters without emojis; applicable to supergroups only"]
+    pub custom_title: String,
+    #[doc = "True, if the user is a member of the chat"]
+    pub is_member: bool,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMemberStatusCreator {
+    const CLASS: &'static str = "ChatMemberStatus";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMemberStatusCreator {
+    const TYPE: &'static str = "chatMemberStatusCreator";
+}
+#[cfg(feature = "chats")]
+impl ChatMemberStatusCreator {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "A custom title of the owner; 0-16 characters without emojis; applicable to supergroups only"]
+    pub fn with_custom_title(mut self, value: impl Into<String>) -> Self {
+        self.custom_title = value.into();
+        self
+    }
+    #[doc = "True, if the user is a member of the chat"]
+    pub fn with_is_member(mut self, value: impl Into<bool>) -> Self {
+        self.is_member = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMemberStatusCreator {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "custom_title",
+            Measure::Characters,
+            self.custom_title.chars().count() as i64,
+            Some(0),
+            Some(16),
+        )?;
+        Ok(())
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is a member of a chat and has some additional privileges. In basic groups, administrators can edit and delete messages sent by others, add new members, and ban unprivileged members. In supergroups and channels, there are more detailed options for administrator privileges"]
+pub struct ChatMemberStatusAdministrator {
+    #[doc = "A custom title of the administrator; 0-16 characters without emojis; applicable to supergroups only"]
+    pub custom_title: String,
+    #[doc = "True, if the current user can edit the administrator privileges for the called user"]
+    pub can_be_edited: bool,
+    #[doc = "True, if the administrator can change the chat title, photo, and other settings"]
+    pub can_change_info: bool,
+    #[doc = "True, if the administrator can create channel posts; applicable to channels only"]
+    pub can_post_messages: bool,
+    #[doc = "True, if the administrator can edit messages of other users and pin messages; applicable to channels only"]
+    pub can_edit_messages: bool,
+    #[doc = "True, if the administrator can delete messages of other users"]
+    pub can_delete_messages: bool,
+    #[doc = "True, if the administrator can invite new users to the chat"]
+    pub can_invite_users: bool,
+    #[doc = "True, if the administrator can restrict, ban, or unban chat members"]
+    pub can_restrict_members: bool,
+    #[doc = "True, if the administrator can pin messages; applicable to groups only"]
+    pub can_pin_messages: bool,
+    #[doc = "True, if the administrator can add new administrators with a subset of their own privileges or demote administrators that were directly or indirectly promoted by them"]
+    pub can_promote_members: bool,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMemberStatusAdministrator {
+    const CLASS: &'static str = "ChatMemberStatus";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMemberStatusAdministrator {
+    const TYPE: &'static str = "chatMemberStatusAdministrator";
+}
+#[cfg(feature = "chats")]
+impl ChatMemberStatusAdministrator {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "A custom title of the administrator; 0-16 characters without emojis; applicable to supergroups only"]
+    pub fn with_custom_title(mut self, value: impl Into<String>) -> Self {
+        self.custom_title = value.into();
+        self
+    }
+    #[doc = "True, if the current user can edit the administrator privileges for the called user"]
+    pub fn with_can_be_edited(mut self, value: impl Into<bool>) -> Self {
+        self.can_be_edited = value.into();
+        self
+    }
+    #[doc = "True, if the administrator can change the chat title, photo, and other settings"]
+    pub fn with_can_change_info(mut self, value: impl Into<bool>) -> Self {
+        self.can_change_info = value.into();
+        self
+    }
+    #[doc = "True, if the administrator can create channel posts; applicable to channels only"]
+    pub fn with_can_post_messages(mut self, value: impl Into<bool>) -> Self {
+        self.can_post_messages = value.into();
+        self
+    }
+    #[doc = "True, if the administrator can edit messages of other users and pin messages; applicable to channels only"]
+    pub fn with_can_edit_messages(mut self, value: impl Into<bool>) -> Self {
+        self.can_edit_messages = value.into();
+        self
+    }
+    #[doc = "True, if the administrator can delete messages of other users"]
+    pub fn with_can_delete_messages(mut self, value: impl Into<bool>) -> Self {
+        self.can_delete_messages = value.into();
+        self
+    }
+    #[doc = "True, if the administrator can invite new users to the chat"]
+    pub fn with_can_invite_users(mut self, value: impl Into<bool>) -> Self {
+        self.can_invite_users = value.into();
+        self
+    }
+    #[doc = "True, if the administrator can restrict, ban, or unban chat members"]
+    pub fn with_can_restrict_members(mut self, value: impl Into<bool>) -> Self {
+        self.can_restrict_members = value.into();
+        self
+    }
+    #[doc = "True, if the administrator can pin messages; applicable to groups only"]
+    pub fn with_can_pin_messages(mut self, value: impl Into<bool>) -> Self {
+        self.can_pin_messages = value.into();
+        self
+    }
+    #[doc = "True, if the administrator can add new administrators with a subset of their own privileges or demote administrators that were directly or indirectly promoted by them"]
+    pub fn with_can_promote_members(mut self, value: impl Into<bool>) -> Self {
+        self.can_promote_members = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMemberStatusAdministrator {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "custom_title",
+            Measure::Characters,
+            self.custom_title.chars().count() as i64,
+            Some(0),
+            Some(16),
+        )?;
+        Ok(())
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is a member of a chat, without any additional privileges or restrictions"]
+pub struct ChatMemberStatusMember {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMemberStatusMember {
+    const CLASS: &'static str = "ChatMemberStatus";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMemberStatusMember {
+    const TYPE: &'static str = "chatMemberStatusMember";
+}
+#[cfg(feature = "chats")]
+impl ChatMemberStatusMember {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMemberStatusMember {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is under certain restrictions in the chat. Not supported in basic groups and channels"]
+pub struct ChatMemberStatusRestricted {
+    #[doc = "True, if the user is a member of the chat"]
+    pub is_member: bool,
+    #[doc = "Point in time (Unix timestamp) when restrictions will be lifted from the user; 0 if never. If the user is restricted for more than 366 days or for less than 30 seconds from the current time, the user is considered to be restricted forever"]
+    pub restricted_until_date: i32,
+    #[doc = "User permissions in the chat"]
+    pub permissions: ChatPermissions,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMemberStatusRestricted {
+    const CLASS: &'static str = "ChatMemberStatus";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMemberStatusRestricted {
+    const TYPE: &'static str = "chatMemberStatusRestricted";
+}
+#[cfg(feature = "chats")]
+impl ChatMemberStatusRestricted {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "True, if the user is a member of the chat"]
+    pub fn with_is_member(mut self, value: impl Into<bool>) -> Self {
+        self.is_member = value.into();
+        self
+    }
+    #[doc = "Point in time (Unix timestamp) when restrictions will be lifted from the user; 0 if never. If the user is restricted for more than 366 days or for less than 30 seconds from the current time, the user is considered to be restricted forever"]
+    pub fn with_restricted_until_date(mut self, value: impl Into<i32>) -> Self {
+        self.restricted_until_date = value.into();
+        self
+    }
+    #[doc = "User permissions in the chat"]
+    pub fn with_permissions(mut self, value: impl Into<ChatPermissions>) -> Self {
+        self.permissions = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMemberStatusRestricted {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.permissions
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "permissions"))?;
+        Ok(())
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is not a chat member"]
+pub struct ChatMemberStatusLeft {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMemberStatusLeft {
+    const CLASS: &'static str = "ChatMemberStatus";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMemberStatusLeft {
+    const TYPE: &'static str = "chatMemberStatusLeft";
+}
+#[cfg(feature = "chats")]
+impl ChatMemberStatusLeft {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMemberStatusLeft {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user was banned (and hence is not a member of the chat). Implies the user can't return to the chat or view messages"]
+pub struct ChatMemberStatusBanned {
+    #[doc = "Point in time (Unix timestamp) when the user will be unbanned; 0 if never. If the user is banned for more than 366 days or for less than 30 seconds from the current time, the user is considered to be banned forever"]
+    pub banned_until_date: i32,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMemberStatusBanned {
+    const CLASS: &'static str = "ChatMemberStatus";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMemberStatusBanned {
+    const TYPE: &'static str = "chatMemberStatusBanned";
+}
+#[cfg(feature = "chats")]
+impl ChatMemberStatusBanned {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Point in time (Unix timestamp) when the user will be unbanned; 0 if never. If the user is banned for more than 366 days or for less than 30 seconds from the current time, the user is considered to be banned forever"]
+    pub fn with_banned_until_date(mut self, value: impl Into<i32>) -> Self {
+        self.banned_until_date = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMemberStatusBanned {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Provides information about the status of a member in a chat"]
+pub enum ChatMemberStatus {
+    ChatMemberStatusCreator(ChatMemberStatusCreator),
+    ChatMemberStatusAdministrator(ChatMemberStatusAdministrator),
+    ChatMemberStatusMember(ChatMemberStatusMember),
+    ChatMemberStatusRestricted(ChatMemberStatusRestricted),
+    ChatMemberStatusLeft(ChatMemberStatusLeft),
+    ChatMemberStatusBanned(ChatMemberStatusBanned),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "chats")]
+impl<'de> Deserialize<'de> for ChatMemberStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "chatMemberStatusCreator" => {
+                Deserialize::deserialize(value).map(ChatMemberStatus::ChatMemberStatusCreator)
+            }
+            "chatMemberStatusAdministrator" => {
+                Deserialize::deserialize(value).map(ChatMemberStatus::ChatMemberStatusAdministrator)
+            }
+            "chatMemberStatusMember" => {
+                Deserialize::deserialize(value).map(ChatMemberStatus::ChatMemberStatusMember)
+            }
+            "chatMemberStatusRestricted" => {
+                Deserialize::deserialize(value).map(ChatMemberStatus::ChatMemberStatusRestricted)
+            }
+            "chatMemberStatusLeft" => {
+                Deserialize::deserialize(value).map(ChatMemberStatus::ChatMemberStatusLeft)
+            }
+            "chatMemberStatusBanned" => {
+                Deserialize::deserialize(value).map(ChatMemberStatus::ChatMemberStatusBanned)
+            }
+            _ => Unknown::deserialize(value).map(ChatMemberStatus::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMemberStatus {
+    const CLASS: &'static str = "ChatMemberStatus";
+    fn type_name(&self) -> &str {
+        match self {
+            ChatMemberStatus::ChatMemberStatusCreator(_) => "chatMemberStatusCreator",
+            ChatMemberStatus::ChatMemberStatusAdministrator(_) => "chatMemberStatusAdministrator",
+            ChatMemberStatus::ChatMemberStatusMember(_) => "chatMemberStatusMember",
+            ChatMemberStatus::ChatMemberStatusRestricted(_) => "chatMemberStatusRestricted",
+            ChatMemberStatus::ChatMemberStatusLeft(_) => "chatMemberStatusLeft",
+            ChatMemberStatus::ChatMemberStatusBanned(_) => "chatMemberStatusBanned",
+            ChatMemberStatus::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMemberStatus {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            ChatMemberStatus::ChatMemberStatusCreator(value) => value.validate(),
+            ChatMemberStatus::ChatMemberStatusAdministrator(value) => value.validate(),
+            ChatMemberStatus::ChatMemberStatusMember(value) => value.validate(),
+            ChatMemberStatus::ChatMemberStatusRestricted(value) => value.validate(),
+            ChatMemberStatus::ChatMemberStatusLeft(value) => value.validate(),
+            ChatMemberStatus::ChatMemberStatusBanned(value) => value.validate(),
+            ChatMemberStatus::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatMemberStatusCreator> for ChatMemberStatus {
+    fn from(value: ChatMemberStatusCreator) -> Self {
+        ChatMemberStatus::ChatMemberStatusCreator(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatMemberStatusAdministrator> for ChatMemberStatus {
+    fn from(value: ChatMemberStatusAdministrator) -> Self {
+        ChatMemberStatus::ChatMemberStatusAdministrator(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatMemberStatusMember> for ChatMemberStatus {
+    fn from(value: ChatMemberStatusMember) -> Self {
+        ChatMemberStatus::ChatMemberStatusMember(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatMemberStatusRestricted> for ChatMemberStatus {
+    fn from(value: ChatMemberStatusRestricted) -> Self {
+        ChatMemberStatus::ChatMemberStatusRestricted(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatMemberStatusLeft> for ChatMemberStatus {
+    fn from(value: ChatMemberStatusLeft) -> Self {
+        ChatMemberStatus::ChatMemberStatusLeft(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatMemberStatusBanned> for ChatMemberStatus {
+    fn from(value: ChatMemberStatusBanned) -> Self {
+        ChatMemberStatus::ChatMemberStatusBanned(value)
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A user with information about joining/leaving a chat"]
+pub struct ChatMember {
+    #[doc = "User identifier of the chat member"]
+    pub user_id: UserId,
+    #[doc = "Identifier of a user that invited/promoted/banned this member in the chat; 0 if unknown"]
+    pub inviter_user_id: UserId,
+    #[doc = "Point in time (Unix timestamp) when the user joined a chat"]
+    pub joined_chat_date: i32,
+    #[doc = "Status of the member in the chat"]
+    pub status: ChatMemberStatus,
+    #[serde(default)]
+    #[doc = "If the user is a bot, information about the bot; may be null. Can be null even for a bot if the bot is not a chat member"]
+    pub bot_info: Option<BotInfo>,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMember {
+    const CLASS: &'static str = "ChatMember";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMember {
+    const TYPE: &'static str = "chatMember";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a list of chat members"]
+pub struct ChatMembers {
+    #[doc = "Approximate total count of chat members found"]
+    pub total_count: i32,
+    #[doc = "A list of chat members"]
+    pub members: Vec<ChatMember>,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMembers {
+    const CLASS: &'static str = "ChatMembers";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMembers {
+    const TYPE: &'static str = "chatMembers";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns contacts of the user"]
+pub struct ChatMembersFilterContacts {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMembersFilterContacts {
+    const CLASS: &'static str = "ChatMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMembersFilterContacts {
+    const TYPE: &'static str = "chatMembersFilterContacts";
+}
+#[cfg(feature = "chats")]
+impl ChatMembersFilterContacts {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMembersFilterContacts {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns the owner and administrators"]
+pub struct ChatMembersFilterAdministrators {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMembersFilterAdministrators {
+    const CLASS: &'static str = "ChatMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMembersFilterAdministrators {
+    const TYPE: &'static str = "chatMembersFilterAdministrators";
+}
+#[cfg(feature = "chats")]
+impl ChatMembersFilterAdministrators {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMembersFilterAdministrators {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns all chat members, including restricted chat members"]
+pub struct ChatMembersFilterMembers {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMembersFilterMembers {
+    const CLASS: &'static str = "ChatMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMembersFilterMembers {
+    const TYPE: &'static str = "chatMembersFilterMembers";
+}
+#[cfg(feature = "chats")]
+impl ChatMembersFilterMembers {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMembersFilterMembers {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns users under certain restrictions in the chat; can be used only by administrators in a supergroup"]
+pub struct ChatMembersFilterRestricted {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMembersFilterRestricted {
+    const CLASS: &'static str = "ChatMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMembersFilterRestricted {
+    const TYPE: &'static str = "chatMembersFilterRestricted";
+}
+#[cfg(feature = "chats")]
+impl ChatMembersFilterRestricted {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMembersFilterRestricted {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns users banned from the chat; can be used only by administrators in a supergroup or in a channel"]
+pub struct ChatMembersFilterBanned {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMembersFilterBanned {
+    const CLASS: &'static str = "ChatMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMembersFilterBanned {
+    const TYPE: &'static str = "chatMembersFilterBanned";
+}
+#[cfg(feature = "chats")]
+impl ChatMembersFilterBanned {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMembersFilterBanned {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns bot members of the chat"]
+pub struct ChatMembersFilterBots {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMembersFilterBots {
+    const CLASS: &'static str = "ChatMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatMembersFilterBots {
+    const TYPE: &'static str = "chatMembersFilterBots";
+}
+#[cfg(feature = "chats")]
+impl ChatMembersFilterBots {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMembersFilterBots {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Specifies the kind of chat members to return in searchChatMembers"]
+pub enum ChatMembersFilter {
+    ChatMembersFilterContacts(ChatMembersFilterContacts),
+    ChatMembersFilterAdministrators(ChatMembersFilterAdministrators),
+    ChatMembersFilterMembers(ChatMembersFilterMembers),
+    ChatMembersFilterRestricted(ChatMembersFilterRestricted),
+    ChatMembersFilterBanned(ChatMembersFilterBanned),
+    ChatMembersFilterBots(ChatMembersFilterBots),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "chats")]
+impl<'de> Deserialize<'de> for ChatMembersFilter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "chatMembersFilterContacts" => {
+                Deserialize::deserialize(value).map(ChatMembersFilter::ChatMembersFilterContacts)
+            }
+            "chatMembersFilterAdministrators" => Deserialize::deserialize(value)
+                .map(ChatMembersFilter::ChatMembersFilterAdministrators),
+            "chatMembersFilterMembers" => {
+                Deserialize::deserialize(value).map(ChatMembersFilter::ChatMembersFilterMembers)
+            }
+            "chatMembersFilterRestricted" => {
+                Deserialize::deserialize(value).map(ChatMembersFilter::ChatMembersFilterRestricted)
+            }
+            "chatMembersFilterBanned" => {
+                Deserialize::deserialize(value).map(ChatMembersFilter::ChatMembersFilterBanned)
+            }
+            "chatMembersFilterBots" => {
+                Deserialize::deserialize(value).map(ChatMembersFilter::ChatMembersFilterBots)
+            }
+            _ => Unknown::deserialize(value).map(ChatMembersFilter::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatMembersFilter {
+    const CLASS: &'static str = "ChatMembersFilter";
+    fn type_name(&self) -> &str {
+        match self {
+            ChatMembersFilter::ChatMembersFilterContacts(_) => "chatMembersFilterContacts",
+            ChatMembersFilter::ChatMembersFilterAdministrators(_) => {
+                "chatMembersFilterAdministrators"
+            }
+            ChatMembersFilter::ChatMembersFilterMembers(_) => "chatMembersFilterMembers",
+            ChatMembersFilter::ChatMembersFilterRestricted(_) => "chatMembersFilterRestricted",
+            ChatMembersFilter::ChatMembersFilterBanned(_) => "chatMembersFilterBanned",
+            ChatMembersFilter::ChatMembersFilterBots(_) => "chatMembersFilterBots",
+            ChatMembersFilter::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatMembersFilter {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            ChatMembersFilter::ChatMembersFilterContacts(value) => value.validate(),
+            ChatMembersFilter::ChatMembersFilterAdministrators(value) => value.validate(),
+            ChatMembersFilter::ChatMembersFilterMembers(value) => value.validate(),
+            ChatMembersFilter::ChatMembersFilterRestricted(value) => value.validate(),
+            ChatMembersFilter::ChatMembersFilterBanned(value) => value.validate(),
+            ChatMembersFilter::ChatMembersFilterBots(value) => value.validate(),
+            ChatMembersFilter::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatMembersFilterContacts> for ChatMembersFilter {
+    fn from(value: ChatMembersFilterContacts) -> Self {
+        ChatMembersFilter::ChatMembersFilterContacts(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatMembersFilterAdministrators> for ChatMembersFilter {
+    fn from(value: ChatMembersFilterAdministrators) -> Self {
+        ChatMembersFilter::ChatMembersFilterAdministrators(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatMembersFilterMembers> for ChatMembersFilter {
+    fn from(value: ChatMembersFilterMembers) -> Self {
+        ChatMembersFilter::ChatMembersFilterMembers(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatMembersFilterRestricted> for ChatMembersFilter {
+    fn from(value: ChatMembersFilterRestricted) -> Self {
+        ChatMembersFilter::ChatMembersFilterRestricted(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatMembersFilterBanned> for ChatMembersFilter {
+    fn from(value: ChatMembersFilterBanned) -> Self {
+        ChatMembersFilter::ChatMembersFilterBanned(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatMembersFilterBots> for ChatMembersFilter {
+    fn from(value: ChatMembersFilterBots) -> Self {
+        ChatMembersFilter::ChatMembersFilterBots(value)
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns recently active users in reverse chronological order"]
+pub struct SupergroupMembersFilterRecent {}
+#[cfg(feature = "chats")]
+impl TlObject for SupergroupMembersFilterRecent {
+    const CLASS: &'static str = "SupergroupMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for SupergroupMembersFilterRecent {
+    const TYPE: &'static str = "supergroupMembersFilterRecent";
+}
+#[cfg(feature = "chats")]
+impl SupergroupMembersFilterRecent {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for SupergroupMembersFilterRecent {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns contacts of the user, which are members of the supergroup or channel"]
+pub struct SupergroupMembersFilterContacts {
+    #[doc = "Query to search for"]
+    pub query: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for SupergroupMembersFilterContacts {
+    const CLASS: &'static str = "SupergroupMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for SupergroupMembersFilterContacts {
+    const TYPE: &'static str = "supergroupMembersFilterContacts";
+}
+#[cfg(feature = "chats")]
+impl SupergroupMembersFilterContacts {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Query to search for"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for SupergroupMembersFilterContacts {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns the owner and administrators"]
+pub struct SupergroupMembersFilterAdministrators {}
+#[cfg(feature = "chats")]
+impl TlObject for SupergroupMembersFilterAdministrators {
+    const CLASS: &'static str = "SupergroupMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for SupergroupMembersFilterAdministrators {
+    const TYPE: &'static str = "supergroupMembersFilterAdministrators";
+}
+#[cfg(feature = "chats")]
+impl SupergroupMembersFilterAdministrators {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for SupergroupMembersFilterAdministrators {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Used to search for supergroup or channel members via a (string) query"]
+pub struct SupergroupMembersFilterSearch {
+    #[doc = "Query to search for"]
+    pub query: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for SupergroupMembersFilterSearch {
+    const CLASS: &'static str = "SupergroupMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for SupergroupMembersFilterSearch {
+    const TYPE: &'static str = "supergroupMembersFilterSearch";
+}
+#[cfg(feature = "chats")]
+impl SupergroupMembersFilterSearch {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Query to search for"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for SupergroupMembersFilterSearch {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns restricted supergroup members; can be used only by administrators"]
+pub struct SupergroupMembersFilterRestricted {
+    #[doc = "Query to search for"]
+    pub query: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for SupergroupMembersFilterRestricted {
+    const CLASS: &'static str = "SupergroupMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for SupergroupMembersFilterRestricted {
+    const TYPE: &'static str = "supergroupMembersFilterRestricted";
+}
+#[cfg(feature = "chats")]
+impl SupergroupMembersFilterRestricted {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Query to search for"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for SupergroupMembersFilterRestricted {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns users banned from the supergroup or channel; can be used only by administrators"]
+pub struct SupergroupMembersFilterBanned {
+    #[doc = "Query to search for"]
+    pub query: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for SupergroupMembersFilterBanned {
+    const CLASS: &'static str = "SupergroupMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for SupergroupMembersFilterBanned {
+    const TYPE: &'static str = "supergroupMembersFilterBanned";
+}
+#[cfg(feature = "chats")]
+impl SupergroupMembersFilterBanned {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Query to search for"]
+    pub fn with_query(mut self, value: impl Into<String>) -> Self {
+        self.query = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for SupergroupMembersFilterBanned {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Returns bot members of the supergroup or channel"]
+pub struct SupergroupMembersFilterBots {}
+#[cfg(feature = "chats")]
+impl TlObject for SupergroupMembersFilterBots {
+    const CLASS: &'static str = "SupergroupMembersFilter";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for SupergroupMembersFilterBots {
+    const TYPE: &'static str = "supergroupMembersFilterBots";
+}
+#[cfg(feature = "chats")]
+impl SupergroupMembersFilterBots {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for SupergroupMembersFilterBots {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Specifies the kind of chat members to return in getSupergroupMembers"]
+pub enum SupergroupMembersFilter {
+    SupergroupMembersFilterRecent(SupergroupMembersFilterRecent),
+    SupergroupMembersFilterContacts(SupergroupMembersFilterContacts),
+    SupergroupMembersFilterAdministrators(SupergroupMembersFilterAdministrators),
+    SupergroupMembersFilterSearch(SupergroupMembersFilterSearch),
+    SupergroupMembersFilterRestricted(SupergroupMembersFilterRestricted),
+    SupergroupMembersFilterBanned(SupergroupMembersFilterBanned),
+    SupergroupMembersFilterBots(SupergroupMembersFilterBots),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "chats")]
+impl<'de> Deserialize<'de> for SupergroupMembersFilter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "supergroupMembersFilterRecent" => Deserialize::deserialize(value)
+                .map(SupergroupMembersFilter::SupergroupMembersFilterRecent),
+            "supergroupMembersFilterContacts" => Deserialize::deserialize(value)
+                .map(SupergroupMembersFilter::SupergroupMembersFilterContacts),
+            "supergroupMembersFilterAdministrators" => Deserialize::deserialize(value)
+                .map(SupergroupMembersFilter::SupergroupMembersFilterAdministrators),
+            "supergroupMembersFilterSearch" => Deserialize::deserialize(value)
+                .map(SupergroupMembersFilter::SupergroupMembersFilterSearch),
+            "supergroupMembersFilterRestricted" => Deserialize::deserialize(value)
+                .map(SupergroupMembersFilter::SupergroupMembersFilterRestricted),
+            "supergroupMembersFilterBanned" => Deserialize::deserialize(value)
+                .map(SupergroupMembersFilter::SupergroupMembersFilterBanned),
+            "supergroupMembersFilterBots" => Deserialize::deserialize(value)
+                .map(SupergroupMembersFilter::SupergroupMembersFilterBots),
+            _ => Unknown::deserialize(value).map(SupergroupMembersFilter::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "chats")]
+impl TlObject for SupergroupMembersFilter {
+    const CLASS: &'static str = "SupergroupMembersFilter";
+    fn type_name(&self) -> &str {
+        match self {
+            SupergroupMembersFilter::SupergroupMembersFilterRecent(_) => {
+                "supergroupMembersFilterRecent"
+            }
+            SupergroupMembersFilter::SupergroupMembersFilterContacts(_) => {
+                "supergroupMembersFilterContacts"
+            }
+            SupergroupMembersFilter::SupergroupMembersFilterAdministrators(_) => {
+                "supergroupMembersFilterAdministrators"
+            }
+            SupergroupMembersFilter::SupergroupMembersFilterSearch(_) => {
+                "supergroupMembersFilterSearch"
+            }
+            SupergroupMembersFilter::SupergroupMembersFilterRestricted(_) => {
+                "supergroupMembersFilterRestricted"
+            }
+            SupergroupMembersFilter::SupergroupMembersFilterBanned(_) => {
+                "supergroupMembersFilterBanned"
+            }
+            SupergroupMembersFilter::SupergroupMembersFilterBots(_) => {
+                "supergroupMembersFilterBots"
+            }
+            SupergroupMembersFilter::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for SupergroupMembersFilter {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            SupergroupMembersFilter::SupergroupMembersFilterRecent(value) => value.validate(),
+            SupergroupMembersFilter::SupergroupMembersFilterContacts(value) => value.validate(),
+            SupergroupMembersFilter::SupergroupMembersFilterAdministrators(value) => {
+                value.validate()
+            }
+            SupergroupMembersFilter::SupergroupMembersFilterSearch(value) => value.validate(),
+            SupergroupMembersFilter::SupergroupMembersFilterRestricted(value) => value.validate(),
+            SupergroupMembersFilter::SupergroupMembersFilterBanned(value) => value.validate(),
+            SupergroupMembersFilter::SupergroupMembersFilterBots(value) => value.validate(),
+            SupergroupMembersFilter::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl From<SupergroupMembersFilterRecent> for SupergroupMembersFilter {
+    fn from(value: SupergroupMembersFilterRecent) -> Self {
+        SupergroupMembersFilter::SupergroupMembersFilterRecent(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<SupergroupMembersFilterContacts> for SupergroupMembersFilter {
+    fn from(value: SupergroupMembersFilterContacts) -> Self {
+        SupergroupMembersFilter::SupergroupMembersFilterContacts(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<SupergroupMembersFilterAdministrators> for SupergroupMembersFilter {
+    fn from(value: SupergroupMembersFilterAdministrators) -> Self {
+        SupergroupMembersFilter::SupergroupMembersFilterAdministrators(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<SupergroupMembersFilterSearch> for SupergroupMembersFilter {
+    fn from(value: SupergroupMembersFilterSearch) -> Self {
+        SupergroupMembersFilter::SupergroupMembersFilterSearch(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<SupergroupMembersFilterRestricted> for SupergroupMembersFilter {
+    fn from(value: SupergroupMembersFilterRestricted) -> Self {
+        SupergroupMembersFilter::SupergroupMembersFilterRestricted(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<SupergroupMembersFilterBanned> for SupergroupMembersFilter {
+    fn from(value: SupergroupMembersFilterBanned) -> Self {
+        SupergroupMembersFilter::SupergroupMembersFilterBanned(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<SupergroupMembersFilterBots> for SupergroupMembersFilter {
+    fn from(value: SupergroupMembersFilterBots) -> Self {
+        SupergroupMembersFilter::SupergroupMembersFilterBots(value)
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a basic group of 0-200 users (must be upgraded to a supergroup to accommodate more than 200 users)"]
+pub struct BasicGroup {
+    #[doc = "Group identifier"]
+    pub id: BasicGroupId,
+    #[doc = "Number of members in the group"]
+    pub member_count: i32,
+    #[doc = "Status of the current user in the group"]
+    pub status: ChatMemberStatus,
+    #[doc = "True, if the group is active"]
+    pub is_active: bool,
+    #[doc = "Identifier of the supergroup to which this group was upgraded; 0 if none"]
+    pub upgraded_to_supergroup_id: SupergroupId,
+}
+#[cfg(feature = "chats")]
+impl TlObject for BasicGroup {
+    const CLASS: &'static str = "BasicGroup";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for BasicGroup {
+    const TYPE: &'static str = "basicGroup";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains full information about a basic group"]
+pub struct BasicGroupFullInfo {
+    #[doc = "Contains full information about a basic group"]
+    pub description: String,
+    #[doc = "User identifier of the creator of the group; 0 if unknown"]
+    pub creator_user_id: UserId,
+    #[doc = "Group members"]
+    pub members: Vec<ChatMember>,
+    #[doc = "Invite link for this group; available only after it has been generated at least once and only for the group creator"]
+    pub invite_link: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for BasicGroupFullInfo {
+    const CLASS: &'static str = "BasicGroupFullInfo";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for BasicGroupFullInfo {
+    const TYPE: &'static str = "basicGroupFullInfo";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a supergroup or channel with zero or more members (subscribers in the case of channels). From the point of view of the system, a channel is a special kind of a supergroup: only administrators can post and see the list of members, and posts from all administrators use the name and photo of the channel instead of individual names and profile photos. Unlike supergroups, channels can have an unlimited number of subscribers"]
+pub struct Supergroup {
+    #[doc = "Supergroup or channel identifier"]
+    pub id: SupergroupId,
+    #[doc = "Username of the supergroup or channel; empty for private supergroups or channels"]
+    pub username: String,
+    #[doc = "Point in time (Unix timestamp) when the current user joined, or the point in time when the supergroup or channel was created, in case the user is not a member"]
+    pub date: i32,
+    #[doc = "Status of the current user in the supergroup or channel; custom title will be always empty"]
+    pub status: ChatMemberStatus,
+    #[doc = "Number of members in the supergroup or channel; 0 if unknown. Currently it is guaranteed to be known only if the supergroup or channel was found through SearchPublicChats"]
+    pub member_count: i32,
+    #[doc = "True, if the channel has a discussion group, or the supergroup is the designated discussion group for a channel"]
+    pub has_linked_chat: bool,
+    #[doc = "True, if the supergroup is connected to a location, i.e. the supergroup is a location-based supergroup"]
+    pub has_location: bool,
+    #[doc = "True, if messages sent to the channel should contain information about the sender. This field is only applicable to channels"]
+    pub sign_messages: bool,
+    #[doc = "True, if the slow mode is enabled in the supergroup"]
+    pub is_slow_mode_enabled: bool,
+    #[doc = "True, if the supergroup is a channel"]
+    pub is_channel: bool,
+    #[doc = "True, if the supergroup or channel is verified"]
+    pub is_verified: bool,
+    #[doc = "If non-empty, contains a human-readable description of the reason why access to this supergroup or channel must be restricted"]
+    pub restriction_reason: String,
+    #[doc = "True, if many users reported this supergroup as a scam"]
+    pub is_scam: bool,
+}
+#[cfg(feature = "chats")]
+impl TlObject for Supergroup {
+    const CLASS: &'static str = "Supergroup";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for Supergroup {
+    const TYPE: &'static str = "supergroup";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains full information about a supergroup or channel"]
+pub struct SupergroupFullInfo {
+    #[doc = "Contains full information about a supergroup or channel"]
+    pub description: String,
+    #[doc = "Number of members in the supergroup or channel; 0 if unknown"]
+    pub member_count: i32,
+    #[doc = "Number of privileged users in the supergroup or channel; 0 if unknown"]
+    pub administrator_count: i32,
+    #[doc = "Number of restricted users in the supergroup; 0 if unknown"]
+    pub restricted_count: i32,
+    #[doc = "Number of users banned from chat; 0 if unknown"]
+    pub banned_count: i32,
+    #[doc = "Chat identifier of a discussion group for the channel, or a channel, for which the supergroup is the designated discussion group; 0 if none or unknown"]
+    pub linked_chat_id: ChatId,
+    #[doc = "Delay between consecutive sent messages for non-administrator supergroup members, in seconds"]
+    pub slow_mode_delay: i32,
+    #[doc = "Time left before next message can be sent in the supergroup, in seconds. An updateSupergroupFullInfo update is not triggered when value of this field changes, but both new and old values are non-zero"]
+    pub slow_mode_delay_expires_in: f64,
+    #[doc = "True, if members of the chat can be retrieved"]
+    pub can_get_members: bool,
+    #[doc = "True, if the chat username can be changed"]
+    pub can_set_username: bool,
+    #[doc = "True, if the supergroup sticker set can be changed"]
+    pub can_set_sticker_set: bool,
+    #[doc = "True, if the supergroup location can be changed"]
+    pub can_set_location: bool,
+    #[doc = "True, if the channel statistics is available"]
+    pub can_view_statistics: bool,
+    #[doc = "True, if new chat members will have access to old messages. In public or discussion groups and both public and private channels, old messages are always available, so this option affects only private supergroups without a linked chat. The value of this field is only available for chat administrators"]
+    pub is_all_history_available: bool,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of the supergroup sticker set; 0 if none"]
+    pub sticker_set_id: i64,
+    #[serde(default)]
+    #[doc = "Location to which the supergroup is connected; may be null"]
+    pub location: Option<ChatLocation>,
+    #[doc = "Invite link for this chat"]
+    pub invite_link: String,
+    #[doc = "Identifier of the basic group from which supergroup was upgraded; 0 if none"]
+    pub upgraded_from_basic_group_id: BasicGroupId,
+    #[doc = "Identifier of the last message in the basic group from which supergroup was upgraded; 0 if none"]
+    pub upgraded_from_max_message_id: MessageId,
+}
+#[cfg(feature = "chats")]
+impl TlObject for SupergroupFullInfo {
+    const CLASS: &'static str = "SupergroupFullInfo";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for SupergroupFullInfo {
+    const TYPE: &'static str = "supergroupFullInfo";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The secret chat is not yet created; waiting for the other user to get online"]
+pub struct SecretChatStatePending {}
+#[cfg(feature = "chats")]
+impl TlObject for SecretChatStatePending {
+    const CLASS: &'static str = "SecretChatState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for SecretChatStatePending {
+    const TYPE: &'static str = "secretChatStatePending";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The secret chat is ready to use"]
+pub struct SecretChatStateReady {}
+#[cfg(feature = "chats")]
+impl TlObject for SecretChatStateReady {
+    const CLASS: &'static str = "SecretChatState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for SecretChatStateReady {
+    const TYPE: &'static str = "secretChatStateReady";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The secret chat is closed"]
+pub struct SecretChatStateClosed {}
+#[cfg(feature = "chats")]
+impl TlObject for SecretChatStateClosed {
+    const CLASS: &'static str = "SecretChatState";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for SecretChatStateClosed {
+    const TYPE: &'static str = "secretChatStateClosed";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes the current secret chat state"]
+pub enum SecretChatState {
+    SecretChatStatePending(SecretChatStatePending),
+    SecretChatStateReady(SecretChatStateReady),
+    SecretChatStateClosed(SecretChatStateClosed),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "chats")]
+impl<'de> Deserialize<'de> for SecretChatState {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "secretChatStatePending" => {
+                Deserialize::deserialize(value).map(SecretChatState::SecretChatStatePending)
+            }
+            "secretChatStateReady" => {
+                Deserialize::deserialize(value).map(SecretChatState::SecretChatStateReady)
+            }
+            "secretChatStateClosed" => {
+                Deserialize::deserialize(value).map(SecretChatState::SecretChatStateClosed)
+            }
+            _ => Unknown::deserialize(value).map(SecretChatState::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "chats")]
+impl TlObject for SecretChatState {
+    const CLASS: &'static str = "SecretChatState";
+    fn type_name(&self) -> &str {
+        match self {
+            SecretChatState::SecretChatStatePending(_) => "secretChatStatePending",
+            SecretChatState::SecretChatStateReady(_) => "secretChatStateReady",
+            SecretChatState::SecretChatStateClosed(_) => "secretChatStateClosed",
+            SecretChatState::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a secret chat"]
+pub struct SecretChat {
+    #[doc = "Secret chat identifier"]
+    pub id: SecretChatId,
+    #[doc = "Identifier of the chat partner"]
+    pub user_id: UserId,
+    #[doc = "State of the secret chat"]
+    pub state: SecretChatState,
+    #[doc = "True, if the chat was created by the current user; otherwise false"]
+    pub is_outbound: bool,
+    #[doc = "Current message Time To Live setting (self-destruct timer) for the chat, in seconds"]
+    pub ttl: i32,
+    #[doc = "Hash of the currently used key for comparison with the hash of the chat partner's key. This is a string of 36 little-endian bytes, which must be split into groups of 2 bits, each denoting a pixel of one of 4 colors FFFFFF, D5E6F3, 2D5775, and 2F99C9. The pixels must be used to make a 12x12 square image filled from left to right, top to bottom. Alternatively, the first 32 bytes of the hash can be converted to the hexadecimal format and printed as 32 2-digit hex numbers"]
+    pub key_hash: Bytes,
+    #[doc = "Secret chat layer; determines features supported by the other client. Video notes are supported if the layer >= 66; nested text entities and underline and strikethrough entities are supported if the layer >= 101"]
+    pub layer: i32,
+}
+#[cfg(feature = "chats")]
+impl TlObject for SecretChat {
+    const CLASS: &'static str = "SecretChat";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for SecretChat {
+    const TYPE: &'static str = "secretChat";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Contains information about notification settings for a chat"]
+pub struct ChatNotificationSettings {
+    #[doc = "If true, mute_for is ignored and the value for the relevant type of chat is used instead"]
+    pub use_default_mute_for: bool,
+    #[doc = "Time left before notifications will be unmuted, in seconds"]
+    pub mute_for: i32,
+    #[doc = "If true, sound is ignored and the value for the relevant type of chat is used instead"]
+    pub use_default_sound: bool,
+    #[doc = "The name of an audio file to be used for notification sounds; only applies to iOS applications"]
+    pub sound: String,
+    #[doc = "If true, show_preview is ignored and the value for the relevant type of chat is used instead"]
+    pub use_default_show_preview: bool,
+    #[doc = "True, if message content should be displayed in notifications"]
+    pub show_preview: bool,
+    #[doc = "If true, disable_pinned_message_notifications is ignored and the value for the relevant type of chat is used instead"]
+    pub use_default_disable_pinned_message_notifications: bool,
+    #[doc = "If true, notifications for incoming pinned messages will be created as for an ordinary unread message"]
+    pub disable_pinned_message_notifications: bool,
+    #[doc = "If true, disable_mention_notifications is ignored and the value for the relevant type of chat is used instead"]
+    pub use_default_disable_mention_notifications: bool,
+    #[doc = "If true, notifications for messages with mentions will be created as for an ordinary unread message"]
+    pub disable_mention_notifications: bool,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatNotificationSettings {
+    const CLASS: &'static str = "ChatNotificationSettings";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatNotificationSettings {
+    const TYPE: &'static str = "chatNotificationSettings";
+}
+#[cfg(feature = "chats")]
+impl ChatNotificationSettings {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "If true, mute_for is ignored and the value for the relevant type of chat is used instead"]
+    pub fn with_use_default_mute_for(mut self, value: impl Into<bool>) -> Self {
+        self.use_default_mute_for = value.into();
+        self
+    }
+    #[doc = "Time left before notifications will be unmuted, in seconds"]
+    pub fn with_mute_for(mut self, value: impl Into<i32>) -> Self {
+        self.mute_for = value.into();
+        self
+    }
+    #[doc = "If true, sound is ignored and the value for the relevant type of chat is used instead"]
+    pub fn with_use_default_sound(mut self, value: impl Into<bool>) -> Self {
+        self.use_default_sound = value.into();
+        self
+    }
+    #[doc = "The name of an audio file to be used for notification sounds; only applies to iOS applications"]
+    pub fn with_sound(mut self, value: impl Into<String>) -> Self {
+        self.sound = value.into();
+        self
+    }
+    #[doc = "If true, show_preview is ignored and the value for the relevant type of chat is used instead"]
+    pub fn with_use_default_show_preview(mut self, value: impl Into<bool>) -> Self {
+        self.use_default_show_preview = value.into();
+        self
+    }
+    #[doc = "True, if message content should be displayed in notifications"]
+    pub fn with_show_preview(mut self, value: impl Into<bool>) -> Self {
+        self.show_preview = value.into();
+        self
+    }
+    #[doc = "If true, disable_pinned_message_notifications is ignored and the value for the relevant type of chat is used instead"]
+    pub fn with_use_default_disable_pinned_message_notifications(
+        mut self,
+        value: impl Into<bool>,
+    ) -> Self {
+        self.use_default_disable_pinned_message_notifications = value.into();
+        self
+    }
+    #[doc = "If true, notifications for incoming pinned messages will be created as for an ordinary unread message"]
+    pub fn with_disable_pinned_message_notifications(mut self, value: impl Into<bool>) -> Self {
+        self.disable_pinned_message_notifications = value.into();
+        self
+    }
+    #[doc = "If true, disable_mention_notifications is ignored and the value for the relevant type of chat is used instead"]
+    pub fn with_use_default_disable_mention_notifications(
+        mut self,
+        value: impl Into<bool>,
+    ) -> Self {
+        self.use_default_disable_mention_notifications = value.into();
+        self
+    }
+    #[doc = "If true, notifications for messages with mentions will be created as for an ordinary unread message"]
+    pub fn with_disable_mention_notifications(mut self, value: impl Into<bool>) -> Self {
+        self.disable_mention_notifications = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatNotificationSettings {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A main list of chats"]
+pub struct ChatListMain {}
+impl TlObject for ChatListMain {
+    const CLASS: &'static str = "ChatList";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for ChatListMain {
+    const TYPE: &'static str = "chatListMain";
+}
+impl ChatListMain {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for ChatListMain {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A list of chats usually located at the top of the main chat list. Unmuted chats are automatically moved from the Archive to the Main chat list when a new message arrives"]
+pub struct ChatListArchive {}
+impl TlObject for ChatListArchive {
+    const CLASS: &'static str = "ChatList";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for ChatListArchive {
+    const TYPE: &'static str = "chatListArchive";
+}
+impl ChatListArchive {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+impl Validate for ChatListArchive {}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes a list of chats"]
+pub enum ChatList {
+    ChatListMain(ChatListMain),
+    ChatListArchive(ChatListArchive),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for ChatList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "chatListMain" => Deserialize::deserialize(value).map(ChatList::ChatListMain),
+            "chatListArchive" => Deserialize::deserialize(value).map(ChatList::ChatListArchive),
+            _ => Unknown::deserialize(value).map(ChatList::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for ChatList {
+    const CLASS: &'static str = "ChatList";
+    fn type_name(&self) -> &str {
+        match self {
+            ChatList::ChatListMain(_) => "chatListMain",
+            ChatList::ChatListArchive(_) => "chatListArchive",
+            ChatList::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for ChatList {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            ChatList::ChatListMain(value) => value.validate(),
+            ChatList::ChatListArchive(value) => value.validate(),
+            ChatList::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<ChatListMain> for ChatList {
+    fn from(value: ChatListMain) -> Self {
+        ChatList::ChatListMain(value)
+    }
+}
+impl From<ChatListArchive> for ChatList {
+    fn from(value: ChatListArchive) -> Self {
+        ChatList::ChatListArchive(value)
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A chat. (Can be a private chat, basic group, supergroup, or secret chat)"]
+pub struct Chat {
+    #[doc = "Chat unique identifier"]
+    pub id: ChatId,
+    #[serde(rename = "type")]
+    #[doc = "Type of the chat"]
+    pub type_: ChatType,
+    #[serde(default)]
+    #[doc = "A chat list to which the chat belongs; may be null"]
+    pub chat_list: Option<ChatList>,
+    #[doc = "Chat title"]
+    pub title: String,
+    #[serde(default)]
+    #[doc = "Chat photo; may be null"]
+    pub photo: Option<ChatPhoto>,
+    #[doc = "Actions that non-administrator chat members are allowed to take in the chat"]
+    pub permissions: ChatPermissions,
+    #[serde(default)]
+    #[doc = "Last message in the chat; may be null"]
+    pub last_message: Option<Message>,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Descending parameter by which chats are sorted in the main chat list. If the order number of two chats is the same, they must be sorted in descending order by ID. If 0, the position of the chat in the list is undetermined"]
+    pub order: i64,
+    #[doc = "True, if the chat is pinned"]
+    pub is_pinned: bool,
+    #[doc = "True, if the chat is marked as unread"]
+    pub is_marked_as_unread: bool,
+    #[doc = "True, if the chat is sponsored by the user's MTProxy server"]
+    pub is_sponsored: bool,
+    #[doc = "True, if the chat has scheduled messages"]
+    pub has_scheduled_messages: bool,
+    #[doc = "True, if the chat messages can be deleted only for the current user while other users will continue to see the messages"]
+    pub can_be_deleted_only_for_self: bool,
+    #[doc = "True, if the chat messages can be deleted for all users"]
+    pub can_be_deleted_for_all_users: bool,
+    #[doc = "True, if the chat can be reported to Telegram moderators through reportChat"]
+    pub can_be_reported: bool,
+    #[doc = "Default value of the disable_notification parameter, used when a message is sent to the chat"]
+    pub default_disable_notification: bool,
+    #[doc = "Number of unread messages in the chat"]
+    pub unread_count: i32,
+    #[doc = "Identifier of the last read incoming message"]
+    pub last_read_inbox_message_id: MessageId,
+    #[doc = "Identifier of the last read outgoing message"]
+    pub last_read_outbox_message_id: MessageId,
+    #[doc = "Number of unread messages with a mention/reply in the chat"]
+    pub unread_mention_count: i32,
+    #[doc = "Notification settings for this chat"]
+    pub notification_settings: ChatNotificationSettings,
+    #[serde(default)]
+    #[doc = "Describes actions which should be possible to do through a chat action bar; may be null"]
+    pub action_bar: Option<ChatActionBar>,
+    #[doc = "Identifier of the pinned message in the chat; 0 if none"]
+    pub pinned_message_id: MessageId,
+    #[doc = "Identifier of the message from which reply markup needs to be used; 0 if there is no default custom reply markup in the chat"]
+    pub reply_markup_message_id: MessageId,
+    #[serde(default)]
+    #[doc = "A draft of a message in the chat; may be null"]
+    pub draft_message: Option<DraftMessage>,
+    #[doc = "Contains client-specific data associated with the chat. (For example, the chat position or local chat notification settings can be stored here.) Persistent if the message database is used"]
+    pub client_data: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for Chat {
+    const CLASS: &'static str = "Chat";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for Chat {
+    const TYPE: &'static str = "chat";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a list of chats"]
+pub struct Chats {
+    #[doc = "List of chat identifiers"]
+    pub chat_ids: Vec<ChatId>,
+}
+#[cfg(feature = "chats")]
+impl TlObject for Chats {
+    const CLASS: &'static str = "Chats";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for Chats {
+    const TYPE: &'static str = "chats";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a list of chats located nearby"]
+pub struct ChatsNearby {
+    #[doc = "List of users nearby"]
+    pub users_nearby: Vec<ChatNearby>,
+    #[doc = "List of location-based supergroups nearby"]
+    pub supergroups_nearby: Vec<ChatNearby>,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatsNearby {
+    const CLASS: &'static str = "ChatsNearby";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatsNearby {
+    const TYPE: &'static str = "chatsNearby";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a chat invite link"]
+pub struct ChatInviteLink {
+    #[doc = "Chat invite link"]
+    pub invite_link: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatInviteLink {
+    const CLASS: &'static str = "ChatInviteLink";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatInviteLink {
+    const TYPE: &'static str = "chatInviteLink";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The chat is public, because it has username"]
+pub struct PublicChatTypeHasUsername {}
+#[cfg(feature = "chats")]
+impl TlObject for PublicChatTypeHasUsername {
+    const CLASS: &'static str = "PublicChatType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for PublicChatTypeHasUsername {
+    const TYPE: &'static str = "publicChatTypeHasUsername";
+}
+#[cfg(feature = "chats")]
+impl PublicChatTypeHasUsername {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for PublicChatTypeHasUsername {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The chat is public, because it is a location-based supergroup"]
+pub struct PublicChatTypeIsLocationBased {}
+#[cfg(feature = "chats")]
+impl TlObject for PublicChatTypeIsLocationBased {
+    const CLASS: &'static str = "PublicChatType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for PublicChatTypeIsLocationBased {
+    const TYPE: &'static str = "publicChatTypeIsLocationBased";
+}
+#[cfg(feature = "chats")]
+impl PublicChatTypeIsLocationBased {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for PublicChatTypeIsLocationBased {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes a type of public chats"]
+pub enum PublicChatType {
+    PublicChatTypeHasUsername(PublicChatTypeHasUsername),
+    PublicChatTypeIsLocationBased(PublicChatTypeIsLocationBased),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "chats")]
+impl<'de> Deserialize<'de> for PublicChatType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "publicChatTypeHasUsername" => {
+                Deserialize::deserialize(value).map(PublicChatType::PublicChatTypeHasUsername)
+            }
+            "publicChatTypeIsLocationBased" => {
+                Deserialize::deserialize(value).map(PublicChatType::PublicChatTypeIsLocationBased)
+            }
+            _ => Unknown::deserialize(value).map(PublicChatType::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "chats")]
+impl TlObject for PublicChatType {
+    const CLASS: &'static str = "PublicChatType";
+    fn type_name(&self) -> &str {
+        match self {
+            PublicChatType::PublicChatTypeHasUsername(_) => "publicChatTypeHasUsername",
+            PublicChatType::PublicChatTypeIsLocationBased(_) => "publicChatTypeIsLocationBased",
+            PublicChatType::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for PublicChatType {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            PublicChatType::PublicChatTypeHasUsername(value) => value.validate(),
+            PublicChatType::PublicChatTypeIsLocationBased(value) => value.validate(),
+            PublicChatType::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl From<PublicChatTypeHasUsername> for PublicChatType {
+    fn from(value: PublicChatTypeHasUsername) -> Self {
+        PublicChatType::PublicChatTypeHasUsername(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<PublicChatTypeIsLocationBased> for PublicChatType {
+    fn from(value: PublicChatTypeIsLocationBased) -> Self {
+        PublicChatType::PublicChatTypeIsLocationBased(value)
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat can be reported as spam using the method reportChat with the reason chatReportReasonSpam"]
+pub struct ChatActionBarReportSpam {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionBarReportSpam {
+    const CLASS: &'static str = "ChatActionBar";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionBarReportSpam {
+    const TYPE: &'static str = "chatActionBarReportSpam";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat is a location-based supergroup, which can be reported as having unrelated location using the method reportChat with the reason chatReportReasonUnrelatedLocation"]
+pub struct ChatActionBarReportUnrelatedLocation {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionBarReportUnrelatedLocation {
+    const CLASS: &'static str = "ChatActionBar";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionBarReportUnrelatedLocation {
+    const TYPE: &'static str = "chatActionBarReportUnrelatedLocation";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat is a private or secret chat, which can be reported using the method reportChat, or the other user can be added to the contact list using the method addContact, or the other user can be blocked using the method blockUser"]
+pub struct ChatActionBarReportAddBlock {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionBarReportAddBlock {
+    const CLASS: &'static str = "ChatActionBar";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionBarReportAddBlock {
+    const TYPE: &'static str = "chatActionBarReportAddBlock";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat is a private or secret chat and the other user can be added to the contact list using the method addContact"]
+pub struct ChatActionBarAddContact {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionBarAddContact {
+    const CLASS: &'static str = "ChatActionBar";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionBarAddContact {
+    const TYPE: &'static str = "chatActionBarAddContact";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat is a private or secret chat with a mutual contact and the user's phone number can be shared with the other user using the method sharePhoneNumber"]
+pub struct ChatActionBarSharePhoneNumber {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionBarSharePhoneNumber {
+    const CLASS: &'static str = "ChatActionBar";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionBarSharePhoneNumber {
+    const TYPE: &'static str = "chatActionBarSharePhoneNumber";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes actions which should be possible to do through a chat action bar"]
+pub enum ChatActionBar {
+    ChatActionBarReportSpam(ChatActionBarReportSpam),
+    ChatActionBarReportUnrelatedLocation(ChatActionBarReportUnrelatedLocation),
+    ChatActionBarReportAddBlock(ChatActionBarReportAddBlock),
+    ChatActionBarAddContact(ChatActionBarAddContact),
+    ChatActionBarSharePhoneNumber(ChatActionBarSharePhoneNumber),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "chats")]
+impl<'de> Deserialize<'de> for ChatActionBar {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "chatActionBarReportSpam" => {
+                Deserialize::deserialize(value).map(ChatActionBar::ChatActionBarReportSpam)
+            }
+            "chatActionBarReportUnrelatedLocation" => Deserialize::deserialize(value)
+                .map(ChatActionBar::ChatActionBarReportUnrelatedLocation),
+            "chatActionBarReportAddBlock" => {
+                Deserialize::deserialize(value).map(ChatActionBar::ChatActionBarReportAddBlock)
+            }
+            "chatActionBarAddContact" => {
+                Deserialize::deserialize(value).map(ChatActionBar::ChatActionBarAddContact)
+            }
+            "chatActionBarSharePhoneNumber" => {
+                Deserialize::deserialize(value).map(ChatActionBar::ChatActionBarSharePhoneNumber)
+            }
+            _ => Unknown::deserialize(value).map(ChatActionBar::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionBar {
+    const CLASS: &'static str = "ChatActionBar";
+    fn type_name(&self) -> &str {
+        match self {
+            ChatActionBar::ChatActionBarReportSpam(_) => "chatActionBarReportSpam",
+            ChatActionBar::ChatActionBarReportUnrelatedLocation(_) => {
+                "chatActionBarReportUnrelatedLocation"
+            }
+            ChatActionBar::ChatActionBarReportAddBlock(_) => "chatActionBarReportAddBlock",
+            ChatActionBar::ChatActionBarAddContact(_) => "chatActionBarAddContact",
+            ChatActionBar::ChatActionBarSharePhoneNumber(_) => "chatActionBarSharePhoneNumber",
+            ChatActionBar::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is typing a message"]
+pub struct ChatActionTyping {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionTyping {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionTyping {
+    const TYPE: &'static str = "chatActionTyping";
+}
+#[cfg(feature = "chats")]
+impl ChatActionTyping {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionTyping {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is recording a video"]
+pub struct ChatActionRecordingVideo {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionRecordingVideo {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionRecordingVideo {
+    const TYPE: &'static str = "chatActionRecordingVideo";
+}
+#[cfg(feature = "chats")]
+impl ChatActionRecordingVideo {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionRecordingVideo {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is uploading a video"]
+pub struct ChatActionUploadingVideo {
+    #[doc = "Upload progress, as a percentage"]
+    pub progress: i32,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionUploadingVideo {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionUploadingVideo {
+    const TYPE: &'static str = "chatActionUploadingVideo";
+}
+#[cfg(feature = "chats")]
+impl ChatActionUploadingVideo {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Upload progress, as a percentage"]
+    pub fn with_progress(mut self, value: impl Into<i32>) -> Self {
+        self.progress = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionUploadingVideo {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is recording a voice note"]
+pub struct ChatActionRecordingVoiceNote {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionRecordingVoiceNote {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionRecordingVoiceNote {
+    const TYPE: &'static str = "chatActionRecordingVoiceNote";
+}
+#[cfg(feature = "chats")]
+impl ChatActionRecordingVoiceNote {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionRecordingVoiceNote {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is uploading a voice note"]
+pub struct ChatActionUploadingVoiceNote {
+    #[doc = "Upload progress, as a percentage"]
+    pub progress: i32,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionUploadingVoiceNote {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionUploadingVoiceNote {
+    const TYPE: &'static str = "chatActionUploadingVoiceNote";
+}
+#[cfg(feature = "chats")]
+impl ChatActionUploadingVoiceNote {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Upload progress, as a percentage"]
+    pub fn with_progress(mut self, value: impl Into<i32>) -> Self {
+        self.progress = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionUploadingVoiceNote {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is uploading a photo"]
+pub struct ChatActionUploadingPhoto {
+    #[doc = "Upload progress, as a percentage"]
+    pub progress: i32,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionUploadingPhoto {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionUploadingPhoto {
+    const TYPE: &'static str = "chatActionUploadingPhoto";
+}
+#[cfg(feature = "chats")]
+impl ChatActionUploadingPhoto {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Upload progress, as a percentage"]
+    pub fn with_progress(mut self, value: impl Into<i32>) -> Self {
+        self.progress = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionUploadingPhoto {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is uploading a document"]
+pub struct ChatActionUploadingDocument {
+    #[doc = "Upload progress, as a percentage"]
+    pub progress: i32,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionUploadingDocument {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionUploadingDocument {
+    const TYPE: &'static str = "chatActionUploadingDocument";
+}
+#[cfg(feature = "chats")]
+impl ChatActionUploadingDocument {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Upload progress, as a percentage"]
+    pub fn with_progress(mut self, value: impl Into<i32>) -> Self {
+        self.progress = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionUploadingDocument {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is picking a location or venue to send"]
+pub struct ChatActionChoosingLocation {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionChoosingLocation {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionChoosingLocation {
+    const TYPE: &'static str = "chatActionChoosingLocation";
+}
+#[cfg(feature = "chats")]
+impl ChatActionChoosingLocation {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionChoosingLocation {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is picking a contact to send"]
+pub struct ChatActionChoosingContact {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionChoosingContact {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionChoosingContact {
+    const TYPE: &'static str = "chatActionChoosingContact";
+}
+#[cfg(feature = "chats")]
+impl ChatActionChoosingContact {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionChoosingContact {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user has started to play a game"]
+pub struct ChatActionStartPlayingGame {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionStartPlayingGame {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionStartPlayingGame {
+    const TYPE: &'static str = "chatActionStartPlayingGame";
+}
+#[cfg(feature = "chats")]
+impl ChatActionStartPlayingGame {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionStartPlayingGame {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is recording a video note"]
+pub struct ChatActionRecordingVideoNote {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionRecordingVideoNote {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionRecordingVideoNote {
+    const TYPE: &'static str = "chatActionRecordingVideoNote";
+}
+#[cfg(feature = "chats")]
+impl ChatActionRecordingVideoNote {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionRecordingVideoNote {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user is uploading a video note"]
+pub struct ChatActionUploadingVideoNote {
+    #[doc = "Upload progress, as a percentage"]
+    pub progress: i32,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionUploadingVideoNote {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionUploadingVideoNote {
+    const TYPE: &'static str = "chatActionUploadingVideoNote";
+}
+#[cfg(feature = "chats")]
+impl ChatActionUploadingVideoNote {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Upload progress, as a percentage"]
+    pub fn with_progress(mut self, value: impl Into<i32>) -> Self {
+        self.progress = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionUploadingVideoNote {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The user has cancelled the previous action"]
+pub struct ChatActionCancel {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatActionCancel {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatActionCancel {
+    const TYPE: &'static str = "chatActionCancel";
+}
+#[cfg(feature = "chats")]
+impl ChatActionCancel {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatActionCancel {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes the different types of activity in a chat"]
+pub enum ChatAction {
+    ChatActionTyping(ChatActionTyping),
+    ChatActionRecordingVideo(ChatActionRecordingVideo),
+    ChatActionUploadingVideo(ChatActionUploadingVideo),
+    ChatActionRecordingVoiceNote(ChatActionRecordingVoiceNote),
+    ChatActionUploadingVoiceNote(ChatActionUploadingVoiceNote),
+    ChatActionUploadingPhoto(ChatActionUploadingPhoto),
+    ChatActionUploadingDocument(ChatActionUploadingDocument),
+    ChatActionChoosingLocation(ChatActionChoosingLocation),
+    ChatActionChoosingContact(ChatActionChoosingContact),
+    ChatActionStartPlayingGame(ChatActionStartPlayingGame),
+    ChatActionRecordingVideoNote(ChatActionRecordingVideoNote),
+    ChatActionUploadingVideoNote(ChatActionUploadingVideoNote),
+    ChatActionCancel(ChatActionCancel),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "chats")]
+impl<'de> Deserialize<'de> for ChatAction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "chatActionTyping" => Deserialize::deserialize(value).map(ChatAction::ChatActionTyping),
+            "chatActionRecordingVideo" => {
+                Deserialize::deserialize(value).map(ChatAction::ChatActionRecordingVideo)
+            }
+            "chatActionUploadingVideo" => {
+                Deserialize::deserialize(value).map(ChatAction::ChatActionUploadingVideo)
+            }
+            "chatActionRecordingVoiceNote" => {
+                Deserialize::deserialize(value).map(ChatAction::ChatActionRecordingVoiceNote)
+            }
+            "chatActionUploadingVoiceNote" => {
+                Deserialize::deserialize(value).map(ChatAction::ChatActionUploadingVoiceNote)
+            }
+            "chatActionUploadingPhoto" => {
+                Deserialize::deserialize(value).map(ChatAction::ChatActionUploadingPhoto)
+            }
+            "chatActionUploadingDocument" => {
+                Deserialize::deserialize(value).map(ChatAction::ChatActionUploadingDocument)
+            }
+            "chatActionChoosingLocation" => {
+                Deserialize::deserialize(value).map(ChatAction::ChatActionChoosingLocation)
+            }
+            "chatActionChoosingContact" => {
+                Deserialize::deserialize(value).map(ChatAction::ChatActionChoosingContact)
+            }
+            "chatActionStartPlayingGame" => {
+                Deserialize::deserialize(value).map(ChatAction::ChatActionStartPlayingGame)
+            }
+            "chatActionRecordingVideoNote" => {
+                Deserialize::deserialize(value).map(ChatAction::ChatActionRecordingVideoNote)
+            }
+            "chatActionUploadingVideoNote" => {
+                Deserialize::deserialize(value).map(ChatAction::ChatActionUploadingVideoNote)
+            }
+            "chatActionCancel" => Deserialize::deserialize(value).map(ChatAction::ChatActionCancel),
+            _ => Unknown::deserialize(value).map(ChatAction::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatAction {
+    const CLASS: &'static str = "ChatAction";
+    fn type_name(&self) -> &str {
+        match self {
+            ChatAction::ChatActionTyping(_) => "chatActionTyping",
+            ChatAction::ChatActionRecordingVideo(_) => "chatActionRecordingVideo",
+            ChatAction::ChatActionUploadingVideo(_) => "chatActionUploadingVideo",
+            ChatAction::ChatActionRecordingVoiceNote(_) => "chatActionRecordingVoiceNote",
+            ChatAction::ChatActionUploadingVoiceNote(_) => "chatActionUploadingVoiceNote",
+            ChatAction::ChatActionUploadingPhoto(_) => "chatActionUploadingPhoto",
+            ChatAction::ChatActionUploadingDocument(_) => "chatActionUploadingDocument",
+            ChatAction::ChatActionChoosingLocation(_) => "chatActionChoosingLocation",
+            ChatAction::ChatActionChoosingContact(_) => "chatActionChoosingContact",
+            ChatAction::ChatActionStartPlayingGame(_) => "chatActionStartPlayingGame",
+            ChatAction::ChatActionRecordingVideoNote(_) => "chatActionRecordingVideoNote",
+            ChatAction::ChatActionUploadingVideoNote(_) => "chatActionUploadingVideoNote",
+            ChatAction::ChatActionCancel(_) => "chatActionCancel",
+            ChatAction::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatAction {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            ChatAction::ChatActionTyping(value) => value.validate(),
+            ChatAction::ChatActionRecordingVideo(value) => value.validate(),
+            ChatAction::ChatActionUploadingVideo(value) => value.validate(),
+            ChatAction::ChatActionRecordingVoiceNote(value) => value.validate(),
+            ChatAction::ChatActionUploadingVoiceNote(value) => value.validate(),
+            ChatAction::ChatActionUploadingPhoto(value) => value.validate(),
+            ChatAction::ChatActionUploadingDocument(value) => value.validate(),
+            ChatAction::ChatActionChoosingLocation(value) => value.validate(),
+            ChatAction::ChatActionChoosingContact(value) => value.validate(),
+            ChatAction::ChatActionStartPlayingGame(value) => value.validate(),
+            ChatAction::ChatActionRecordingVideoNote(value) => value.validate(),
+            ChatAction::ChatActionUploadingVideoNote(value) => value.validate(),
+            ChatAction::ChatActionCancel(value) => value.validate(),
+            ChatAction::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionTyping> for ChatAction {
+    fn from(value: ChatActionTyping) -> Self {
+        ChatAction::ChatActionTyping(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionRecordingVideo> for ChatAction {
+    fn from(value: ChatActionRecordingVideo) -> Self {
+        ChatAction::ChatActionRecordingVideo(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionUploadingVideo> for ChatAction {
+    fn from(value: ChatActionUploadingVideo) -> Self {
+        ChatAction::ChatActionUploadingVideo(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionRecordingVoiceNote> for ChatAction {
+    fn from(value: ChatActionRecordingVoiceNote) -> Self {
+        ChatAction::ChatActionRecordingVoiceNote(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionUploadingVoiceNote> for ChatAction {
+    fn from(value: ChatActionUploadingVoiceNote) -> Self {
+        ChatAction::ChatActionUploadingVoiceNote(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionUploadingPhoto> for ChatAction {
+    fn from(value: ChatActionUploadingPhoto) -> Self {
+        ChatAction::ChatActionUploadingPhoto(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionUploadingDocument> for ChatAction {
+    fn from(value: ChatActionUploadingDocument) -> Self {
+        ChatAction::ChatActionUploadingDocument(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionChoosingLocation> for ChatAction {
+    fn from(value: ChatActionChoosingLocation) -> Self {
+        ChatAction::ChatActionChoosingLocation(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionChoosingContact> for ChatAction {
+    fn from(value: ChatActionChoosingContact) -> Self {
+        ChatAction::ChatActionChoosingContact(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionStartPlayingGame> for ChatAction {
+    fn from(value: ChatActionStartPlayingGame) -> Self {
+        ChatAction::ChatActionStartPlayingGame(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionRecordingVideoNote> for ChatAction {
+    fn from(value: ChatActionRecordingVideoNote) -> Self {
+        ChatAction::ChatActionRecordingVideoNote(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionUploadingVideoNote> for ChatAction {
+    fn from(value: ChatActionUploadingVideoNote) -> Self {
+        ChatAction::ChatActionUploadingVideoNote(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatActionCancel> for ChatAction {
+    fn from(value: ChatActionCancel) -> Self {
+        ChatAction::ChatActionCancel(value)
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message was edited"]
+pub struct ChatEventMessageEdited {
+    #[doc = "The original message before the edit"]
+    pub old_message: Message,
+    #[doc = "The message after it was edited"]
+    pub new_message: Message,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventMessageEdited {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventMessageEdited {
+    const TYPE: &'static str = "chatEventMessageEdited";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message was deleted"]
+pub struct ChatEventMessageDeleted {
+    #[doc = "Deleted message"]
+    pub message: Message,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventMessageDeleted {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventMessageDeleted {
+    const TYPE: &'static str = "chatEventMessageDeleted";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A poll in a message was stopped"]
+pub struct ChatEventPollStopped {
+    #[doc = "The message with the poll"]
+    pub message: Message,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventPollStopped {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventPollStopped {
+    const TYPE: &'static str = "chatEventPollStopped";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message was pinned"]
+pub struct ChatEventMessagePinned {
+    #[doc = "Pinned message"]
+    pub message: Message,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventMessagePinned {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventMessagePinned {
+    const TYPE: &'static str = "chatEventMessagePinned";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A message was unpinned"]
+pub struct ChatEventMessageUnpinned {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventMessageUnpinned {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventMessageUnpinned {
+    const TYPE: &'static str = "chatEventMessageUnpinned";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new member joined the chat"]
+pub struct ChatEventMemberJoined {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventMemberJoined {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventMemberJoined {
+    const TYPE: &'static str = "chatEventMemberJoined";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A member left the chat"]
+pub struct ChatEventMemberLeft {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventMemberLeft {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventMemberLeft {
+    const TYPE: &'static str = "chatEventMemberLeft";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new chat member was invited"]
+pub struct ChatEventMemberInvited {
+    #[doc = "New member user identifier"]
+    pub user_id: UserId,
+    #[doc = "New member status"]
+    pub status: ChatMemberStatus,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventMemberInvited {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventMemberInvited {
+    const TYPE: &'static str = "chatEventMemberInvited";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A chat member has gained/lost administrator status, or the list of their administrator privileges has changed"]
+pub struct ChatEventMemberPromoted {
+    #[doc = "Chat member user identifier"]
+    pub user_id: UserId,
+    #[doc = "Previous status of the chat member"]
+    pub old_status: ChatMemberStatus,
+    #[doc = "New status of the chat member"]
+    pub new_status: ChatMemberStatus,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventMemberPromoted {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventMemberPromoted {
+    const TYPE: &'static str = "chatEventMemberPromoted";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A chat member was restricted/unrestricted or banned/unbanned, or the list of their restrictions has changed"]
+pub struct ChatEventMemberRestricted {
+    #[doc = "Chat member user identifier"]
+    pub user_id: UserId,
+    #[doc = "Previous status of the chat member"]
+    pub old_status: ChatMemberStatus,
+    #[doc = "New status of the chat member"]
+    pub new_status: ChatMemberStatus,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventMemberRestricted {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventMemberRestricted {
+    const TYPE: &'static str = "chatEventMemberRestricted";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat title was changed"]
+pub struct ChatEventTitleChanged {
+    #[doc = "Previous chat title"]
+    pub old_title: String,
+    #[doc = "New chat title"]
+    pub new_title: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventTitleChanged {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventTitleChanged {
+    const TYPE: &'static str = "chatEventTitleChanged";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat permissions was changed"]
+pub struct ChatEventPermissionsChanged {
+    #[doc = "Previous chat permissions"]
+    pub old_permissions: ChatPermissions,
+    #[doc = "New chat permissions"]
+    pub new_permissions: ChatPermissions,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventPermissionsChanged {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventPermissionsChanged {
+    const TYPE: &'static str = "chatEventPermissionsChanged";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat description was changed"]
+pub struct ChatEventDescriptionChanged {
+    #[doc = "Previous chat description"]
+    pub old_description: String,
+    #[doc = "New chat description"]
+    pub new_description: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventDescriptionChanged {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventDescriptionChanged {
+    const TYPE: &'static str = "chatEventDescriptionChanged";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat username was changed"]
+pub struct ChatEventUsernameChanged {
+    #[doc = "Previous chat username"]
+    pub old_username: String,
+    #[doc = "New chat username"]
+    pub new_username: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventUsernameChanged {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventUsernameChanged {
+    const TYPE: &'static str = "chatEventUsernameChanged";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat photo was changed"]
+pub struct ChatEventPhotoChanged {
+    #[serde(default)]
+    #[doc = "Previous chat photo value; may be null"]
+    pub old_photo: Option<Photo>,
+    #[serde(default)]
+    #[doc = "New chat photo value; may be null"]
+    pub new_photo: Option<Photo>,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventPhotoChanged {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventPhotoChanged {
+    const TYPE: &'static str = "chatEventPhotoChanged";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The can_invite_users permission of a supergroup chat was toggled"]
+pub struct ChatEventInvitesToggled {
+    #[doc = "New value of can_invite_users permission"]
+    pub can_invite_users: bool,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventInvitesToggled {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventInvitesToggled {
+    const TYPE: &'static str = "chatEventInvitesToggled";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The linked chat of a supergroup was changed"]
+pub struct ChatEventLinkedChatChanged {
+    #[doc = "Previous supergroup linked chat identifier"]
+    pub old_linked_chat_id: ChatId,
+    #[doc = "New supergroup linked chat identifier"]
+    pub new_linked_chat_id: ChatId,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventLinkedChatChanged {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventLinkedChatChanged {
+    const TYPE: &'static str = "chatEventLinkedChatChanged";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The slow_mode_delay setting of a supergroup was changed"]
+pub struct ChatEventSlowModeDelayChanged {
+    #[doc = "Previous value of slow_mode_delay"]
+    pub old_slow_mode_delay: i32,
+    #[doc = "New value of slow_mode_delay"]
+    pub new_slow_mode_delay: i32,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventSlowModeDelayChanged {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventSlowModeDelayChanged {
+    const TYPE: &'static str = "chatEventSlowModeDelayChanged";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The sign_messages setting of a channel was toggled"]
+pub struct ChatEventSignMessagesToggled {
+    #[doc = "New value of sign_messages"]
+    pub sign_messages: bool,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventSignMessagesToggled {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventSignMessagesToggled {
+    const TYPE: &'static str = "chatEventSignMessagesToggled";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The supergroup sticker set was changed"]
+pub struct ChatEventStickerSetChanged {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Previous identifier of the chat sticker set; 0 if none"]
+    pub old_sticker_set_id: i64,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "New identifier of the chat sticker set; 0 if none"]
+    pub new_sticker_set_id: i64,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventStickerSetChanged {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventStickerSetChanged {
+    const TYPE: &'static str = "chatEventStickerSetChanged";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The supergroup location was changed"]
+pub struct ChatEventLocationChanged {
+    #[serde(default)]
+    #[doc = "Previous location; may be null"]
+    pub old_location: Option<ChatLocation>,
+    #[serde(default)]
+    #[doc = "New location; may be null"]
+    pub new_location: Option<ChatLocation>,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventLocationChanged {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventLocationChanged {
+    const TYPE: &'static str = "chatEventLocationChanged";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The is_all_history_available setting of a supergroup was toggled"]
+pub struct ChatEventIsAllHistoryAvailableToggled {
+    #[doc = "New value of is_all_history_available"]
+    pub is_all_history_available: bool,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventIsAllHistoryAvailableToggled {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventIsAllHistoryAvailableToggled {
+    const TYPE: &'static str = "chatEventIsAllHistoryAvailableToggled";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Represents a chat event"]
+pub enum ChatEventAction {
+    ChatEventMessageEdited(Box<ChatEventMessageEdited>),
+    ChatEventMessageDeleted(Box<ChatEventMessageDeleted>),
+    ChatEventPollStopped(Box<ChatEventPollStopped>),
+    ChatEventMessagePinned(Box<ChatEventMessagePinned>),
+    ChatEventMessageUnpinned(ChatEventMessageUnpinned),
+    ChatEventMemberJoined(ChatEventMemberJoined),
+    ChatEventMemberLeft(ChatEventMemberLeft),
+    ChatEventMemberInvited(ChatEventMemberInvited),
+    ChatEventMemberPromoted(ChatEventMemberPromoted),
+    ChatEventMemberRestricted(ChatEventMemberRestricted),
+    ChatEventTitleChanged(ChatEventTitleChanged),
+    ChatEventPermissionsChanged(ChatEventPermissionsChanged),
+    ChatEventDescriptionChanged(ChatEventDescriptionChanged),
+    ChatEventUsernameChanged(ChatEventUsernameChanged),
+    ChatEventPhotoChanged(ChatEventPhotoChanged),
+    ChatEventInvitesToggled(ChatEventInvitesToggled),
+    ChatEventLinkedChatChanged(ChatEventLinkedChatChanged),
+    ChatEventSlowModeDelayChanged(ChatEventSlowModeDelayChanged),
+    ChatEventSignMessagesToggled(ChatEventSignMessagesToggled),
+    ChatEventStickerSetChanged(ChatEventStickerSetChanged),
+    ChatEventLocationChanged(ChatEventLocationChanged),
+    ChatEventIsAllHistoryAvailableToggled(ChatEventIsAllHistoryAvailableToggled),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "chats")]
+impl<'de> Deserialize<'de> for ChatEventAction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "chatEventMessageEdited" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventMessageEdited)
+            }
+            "chatEventMessageDeleted" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventMessageDeleted)
+            }
+            "chatEventPollStopped" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventPollStopped)
+            }
+            "chatEventMessagePinned" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventMessagePinned)
+            }
+            "chatEventMessageUnpinned" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventMessageUnpinned)
+            }
+            "chatEventMemberJoined" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventMemberJoined)
+            }
+            "chatEventMemberLeft" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventMemberLeft)
+            }
+            "chatEventMemberInvited" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventMemberInvited)
+            }
+            "chatEventMemberPromoted" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventMemberPromoted)
+            }
+            "chatEventMemberRestricted" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventMemberRestricted)
+            }
+            "chatEventTitleChanged" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventTitleChanged)
+            }
+            "chatEventPermissionsChanged" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventPermissionsChanged)
+            }
+            "chatEventDescriptionChanged" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventDescriptionChanged)
+            }
+            "chatEventUsernameChanged" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventUsernameChanged)
+            }
+            "chatEventPhotoChanged" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventPhotoChanged)
+            }
+            "chatEventInvitesToggled" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventInvitesToggled)
+            }
+            "chatEventLinkedChatChanged" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventLinkedChatChanged)
+            }
+            "chatEventSlowModeDelayChanged" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventSlowModeDelayChanged)
+            }
+            "chatEventSignMessagesToggled" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventSignMessagesToggled)
+            }
+            "chatEventStickerSetChanged" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventStickerSetChanged)
+            }
+            "chatEventLocationChanged" => {
+                Deserialize::deserialize(value).map(ChatEventAction::ChatEventLocationChanged)
+            }
+            "chatEventIsAllHistoryAvailableToggled" => Deserialize::deserialize(value)
+                .map(ChatEventAction::ChatEventIsAllHistoryAvailableToggled),
+            _ => Unknown::deserialize(value).map(ChatEventAction::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventAction {
+    const CLASS: &'static str = "ChatEventAction";
+    fn type_name(&self) -> &str {
+        match self {
+            ChatEventAction::ChatEventMessageEdited(_) => "chatEventMessageEdited",
+            ChatEventAction::ChatEventMessageDeleted(_) => "chatEventMessageDeleted",
+            ChatEventAction::ChatEventPollStopped(_) => "chatEventPollStopped",
+            ChatEventAction::ChatEventMessagePinned(_) => "chatEventMessagePinned",
+            ChatEventAction::ChatEventMessageUnpinned(_) => "chatEventMessageUnpinned",
+            ChatEventAction::ChatEventMemberJoined(_) => "chatEventMemberJoined",
+            ChatEventAction::ChatEventMemberLeft(_) => "chatEventMemberLeft",
+            ChatEventAction::ChatEventMemberInvited(_) => "chatEventMemberInvited",
+            ChatEventAction::ChatEventMemberPromoted(_) => "chatEventMemberPromoted",
+            ChatEventAction::ChatEventMemberRestricted(_) => "chatEventMemberRestricted",
+            ChatEventAction::ChatEventTitleChanged(_) => "chatEventTitleChanged",
+            ChatEventAction::ChatEventPermissionsChanged(_) => "chatEventPermissionsChanged",
+            ChatEventAction::ChatEventDescriptionChanged(_) => "chatEventDescriptionChanged",
+            ChatEventAction::ChatEventUsernameChanged(_) => "chatEventUsernameChanged",
+            ChatEventAction::ChatEventPhotoChanged(_) => "chatEventPhotoChanged",
+            ChatEventAction::ChatEventInvitesToggled(_) => "chatEventInvitesToggled",
+            ChatEventAction::ChatEventLinkedChatChanged(_) => "chatEventLinkedChatChanged",
+            ChatEventAction::ChatEventSlowModeDelayChanged(_) => "chatEventSlowModeDelayChanged",
+            ChatEventAction::ChatEventSignMessagesToggled(_) => "chatEventSignMessagesToggled",
+            ChatEventAction::ChatEventStickerSetChanged(_) => "chatEventStickerSetChanged",
+            ChatEventAction::ChatEventLocationChanged(_) => "chatEventLocationChanged",
+            ChatEventAction::ChatEventIsAllHistoryAvailableToggled(_) => {
+                "chatEventIsAllHistoryAvailableToggled"
+            }
+            ChatEventAction::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a chat event"]
+pub struct ChatEvent {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Chat event identifier"]
+    pub id: i64,
+    #[doc = "Point in time (Unix timestamp) when the event happened"]
+    pub date: i32,
+    #[doc = "Identifier of the user who performed the action that triggered the event"]
+    pub user_id: UserId,
+    #[doc = "Action performed by the user"]
+    pub action: ChatEventAction,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEvent {
+    const CLASS: &'static str = "ChatEvent";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEvent {
+    const TYPE: &'static str = "chatEvent";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a list of chat events"]
+pub struct ChatEvents {
+    #[doc = "List of events"]
+    pub events: Vec<ChatEvent>,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEvents {
+    const CLASS: &'static str = "ChatEvents";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEvents {
+    const TYPE: &'static str = "chatEvents";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Represents a set of filters used to obtain a chat event log"]
+pub struct ChatEventLogFilters {
+    #[doc = "True, if message edits should be returned"]
+    pub message_edits: bool,
+    #[doc = "True, if message deletions should be returned"]
+    pub message_deletions: bool,
+    #[doc = "True, if pin/unpin events should be returned"]
+    pub message_pins: bool,
+    #[doc = "True, if members joining events should be returned"]
+    pub member_joins: bool,
+    #[doc = "True, if members leaving events should be returned"]
+    pub member_leaves: bool,
+    #[doc = "True, if invited member events should be returned"]
+    pub member_invites: bool,
+    #[doc = "True, if member promotion/demotion events should be returned"]
+    pub member_promotions: bool,
+    #[doc = "True, if member restricted/unrestricted/banned/unbanned events should be returned"]
+    pub member_restrictions: bool,
+    #[doc = "True, if changes in chat information should be returned"]
+    pub info_changes: bool,
+    #[doc = "True, if changes in chat settings should be returned"]
+    pub setting_changes: bool,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatEventLogFilters {
+    const CLASS: &'static str = "ChatEventLogFilters";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatEventLogFilters {
+    const TYPE: &'static str = "chatEventLogFilters";
+}
+#[cfg(feature = "chats")]
+impl ChatEventLogFilters {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "True, if message edits should be returned"]
+    pub fn with_message_edits(mut self, value: impl Into<bool>) -> Self {
+        self.message_edits = value.into();
+        self
+    }
+    #[doc = "True, if message deletions should be returned"]
+    pub fn with_message_deletions(mut self, value: impl Into<bool>) -> Self {
+        self.message_deletions = value.into();
+        self
+    }
+    #[doc = "True, if pin/unpin events should be returned"]
+    pub fn with_message_pins(mut self, value: impl Into<bool>) -> Self {
+        self.message_pins = value.into();
+        self
+    }
+    #[doc = "True, if members joining events should be returned"]
+    pub fn with_member_joins(mut self, value: impl Into<bool>) -> Self {
+        self.member_joins = value.into();
+        self
+    }
+    #[doc = "True, if members leaving events should be returned"]
+    pub fn with_member_leaves(mut self, value: impl Into<bool>) -> Self {
+        self.member_leaves = value.into();
+        self
+    }
+    #[doc = "True, if invited member events should be returned"]
+    pub fn with_member_invites(mut self, value: impl Into<bool>) -> Self {
+        self.member_invites = value.into();
+        self
+    }
+    #[doc = "True, if member promotion/demotion events should be returned"]
+    pub fn with_member_promotions(mut self, value: impl Into<bool>) -> Self {
+        self.member_promotions = value.into();
+        self
+    }
+    #[doc = "True, if member restricted/unrestricted/banned/unbanned events should be returned"]
+    pub fn with_member_restrictions(mut self, value: impl Into<bool>) -> Self {
+        self.member_restrictions = value.into();
+        self
+    }
+    #[doc = "True, if changes in chat information should be returned"]
+    pub fn with_info_changes(mut self, value: impl Into<bool>) -> Self {
+        self.info_changes = value.into();
+        self
+    }
+    #[doc = "True, if changes in chat settings should be returned"]
+    pub fn with_setting_changes(mut self, value: impl Into<bool>) -> Self {
+        self.setting_changes = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatEventLogFilters {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The username can be set"]
+pub struct CheckChatUsernameResultOk {}
+#[cfg(feature = "chats")]
+impl TlObject for CheckChatUsernameResultOk {
+    const CLASS: &'static str = "CheckChatUsernameResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for CheckChatUsernameResultOk {
+    const TYPE: &'static str = "checkChatUsernameResultOk";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The username is invalid"]
+pub struct CheckChatUsernameResultUsernameInvalid {}
+#[cfg(feature = "chats")]
+impl TlObject for CheckChatUsernameResultUsernameInvalid {
+    const CLASS: &'static str = "CheckChatUsernameResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for CheckChatUsernameResultUsernameInvalid {
+    const TYPE: &'static str = "checkChatUsernameResultUsernameInvalid";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The username is occupied"]
+pub struct CheckChatUsernameResultUsernameOccupied {}
+#[cfg(feature = "chats")]
+impl TlObject for CheckChatUsernameResultUsernameOccupied {
+    const CLASS: &'static str = "CheckChatUsernameResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for CheckChatUsernameResultUsernameOccupied {
+    const TYPE: &'static str = "checkChatUsernameResultUsernameOccupied";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The user has too much chats with username, one of them should be made private first"]
+pub struct CheckChatUsernameResultPublicChatsTooMuch {}
+#[cfg(feature = "chats")]
+impl TlObject for CheckChatUsernameResultPublicChatsTooMuch {
+    const CLASS: &'static str = "CheckChatUsernameResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for CheckChatUsernameResultPublicChatsTooMuch {
+    const TYPE: &'static str = "checkChatUsernameResultPublicChatsTooMuch";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The user can't be a member of a public supergroup"]
+pub struct CheckChatUsernameResultPublicGroupsUnavailable {}
+#[cfg(feature = "chats")]
+impl TlObject for CheckChatUsernameResultPublicGroupsUnavailable {
+    const CLASS: &'static str = "CheckChatUsernameResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for CheckChatUsernameResultPublicGroupsUnavailable {
+    const TYPE: &'static str = "checkChatUsernameResultPublicGroupsUnavailable";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Represents result of checking whether a username can be set for a chat"]
+pub enum CheckChatUsernameResult {
+    CheckChatUsernameResultOk(CheckChatUsernameResultOk),
+    CheckChatUsernameResultUsernameInvalid(CheckChatUsernameResultUsernameInvalid),
+    CheckChatUsernameResultUsernameOccupied(CheckChatUsernameResultUsernameOccupied),
+    CheckChatUsernameResultPublicChatsTooMuch(CheckChatUsernameResultPublicChatsTooMuch),
+    CheckChatUsernameResultPublicGroupsUnavailable(CheckChatUsernameResultPublicGroupsUnavailable),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "chats")]
+impl<'de> Deserialize<'de> for CheckChatUsernameResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "checkChatUsernameResultOk" => Deserialize::deserialize(value)
+                .map(CheckChatUsernameResult::CheckChatUsernameResultOk),
+            "checkChatUsernameResultUsernameInvalid" => Deserialize::deserialize(value)
+                .map(CheckChatUsernameResult::CheckChatUsernameResultUsernameInvalid),
+            "checkChatUsernameResultUsernameOccupied" => Deserialize::deserialize(value)
+                .map(CheckChatUsernameResult::CheckChatUsernameResultUsernameOccupied),
+            "checkChatUsernameResultPublicChatsTooMuch" => Deserialize::deserialize(value)
+                .map(CheckChatUsernameResult::CheckChatUsernameResultPublicChatsTooMuch),
+            "checkChatUsernameResultPublicGroupsUnavailable" => Deserialize::deserialize(value)
+                .map(CheckChatUsernameResult::CheckChatUsernameResultPublicGroupsUnavailable),
+            _ => Unknown::deserialize(value).map(CheckChatUsernameResult::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "chats")]
+impl TlObject for CheckChatUsernameResult {
+    const CLASS: &'static str = "CheckChatUsernameResult";
+    fn type_name(&self) -> &str {
+        match self {
+            CheckChatUsernameResult::CheckChatUsernameResultOk(_) => "checkChatUsernameResultOk",
+            CheckChatUsernameResult::CheckChatUsernameResultUsernameInvalid(_) => {
+                "checkChatUsernameResultUsernameInvalid"
+            }
+            CheckChatUsernameResult::CheckChatUsernameResultUsernameOccupied(_) => {
+                "checkChatUsernameResultUsernameOccupied"
+            }
+            CheckChatUsernameResult::CheckChatUsernameResultPublicChatsTooMuch(_) => {
+                "checkChatUsernameResultPublicChatsTooMuch"
+            }
+            CheckChatUsernameResult::CheckChatUsernameResultPublicGroupsUnavailable(_) => {
+                "checkChatUsernameResultPublicGroupsUnavailable"
+            }
+            CheckChatUsernameResult::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "New secret chat was created"]
+pub struct NotificationTypeNewSecretChat {}
+#[cfg(feature = "chats")]
+impl TlObject for NotificationTypeNewSecretChat {
+    const CLASS: &'static str = "NotificationType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for NotificationTypeNewSecretChat {
+    const TYPE: &'static str = "notificationTypeNewSecretChat";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The chat contains spam messages"]
+pub struct ChatReportReasonSpam {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatReportReasonSpam {
+    const CLASS: &'static str = "ChatReportReason";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatReportReasonSpam {
+    const TYPE: &'static str = "chatReportReasonSpam";
+}
+#[cfg(feature = "chats")]
+impl ChatReportReasonSpam {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatReportReasonSpam {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The chat promotes violence"]
+pub struct ChatReportReasonViolence {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatReportReasonViolence {
+    const CLASS: &'static str = "ChatReportReason";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatReportReasonViolence {
+    const TYPE: &'static str = "chatReportReasonViolence";
+}
+#[cfg(feature = "chats")]
+impl ChatReportReasonViolence {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatReportReasonViolence {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The chat contains pornographic messages"]
+pub struct ChatReportReasonPornography {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatReportReasonPornography {
+    const CLASS: &'static str = "ChatReportReason";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatReportReasonPornography {
+    const TYPE: &'static str = "chatReportReasonPornography";
+}
+#[cfg(feature = "chats")]
+impl ChatReportReasonPornography {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatReportReasonPornography {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The chat has child abuse related content"]
+pub struct ChatReportReasonChildAbuse {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatReportReasonChildAbuse {
+    const CLASS: &'static str = "ChatReportReason";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatReportReasonChildAbuse {
+    const TYPE: &'static str = "chatReportReasonChildAbuse";
+}
+#[cfg(feature = "chats")]
+impl ChatReportReasonChildAbuse {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatReportReasonChildAbuse {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The chat contains copyrighted content"]
+pub struct ChatReportReasonCopyright {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatReportReasonCopyright {
+    const CLASS: &'static str = "ChatReportReason";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatReportReasonCopyright {
+    const TYPE: &'static str = "chatReportReasonCopyright";
+}
+#[cfg(feature = "chats")]
+impl ChatReportReasonCopyright {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatReportReasonCopyright {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The location-based chat is unrelated to its stated location"]
+pub struct ChatReportReasonUnrelatedLocation {}
+#[cfg(feature = "chats")]
+impl TlObject for ChatReportReasonUnrelatedLocation {
+    const CLASS: &'static str = "ChatReportReason";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatReportReasonUnrelatedLocation {
+    const TYPE: &'static str = "chatReportReasonUnrelatedLocation";
+}
+#[cfg(feature = "chats")]
+impl ChatReportReasonUnrelatedLocation {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatReportReasonUnrelatedLocation {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A custom reason provided by the user"]
+pub struct ChatReportReasonCustom {
+    #[doc = "Report text"]
+    pub text: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatReportReasonCustom {
+    const CLASS: &'static str = "ChatReportReason";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatReportReasonCustom {
+    const TYPE: &'static str = "chatReportReasonCustom";
+}
+#[cfg(feature = "chats")]
+impl ChatReportReasonCustom {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Report text"]
+    pub fn with_text(mut self, value: impl Into<String>) -> Self {
+        self.text = value.into();
+        self
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatReportReasonCustom {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes the reason why a chat is reported"]
+pub enum ChatReportReason {
+    ChatReportReasonSpam(ChatReportReasonSpam),
+    ChatReportReasonViolence(ChatReportReasonViolence),
+    ChatReportReasonPornography(ChatReportReasonPornography),
+    ChatReportReasonChildAbuse(ChatReportReasonChildAbuse),
+    ChatReportReasonCopyright(ChatReportReasonCopyright),
+    ChatReportReasonUnrelatedLocation(ChatReportReasonUnrelatedLocation),
+    ChatReportReasonCustom(ChatReportReasonCustom),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "chats")]
+impl<'de> Deserialize<'de> for ChatReportReason {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "chatReportReasonSpam" => {
+                Deserialize::deserialize(value).map(ChatReportReason::ChatReportReasonSpam)
+            }
+            "chatReportReasonViolence" => {
+                Deserialize::deserialize(value).map(ChatReportReason::ChatReportReasonViolence)
+            }
+            "chatReportReasonPornography" => {
+                Deserialize::deserialize(value).map(ChatReportReason::ChatReportReasonPornography)
+            }
+            "chatReportReasonChildAbuse" => {
+                Deserialize::deserialize(value).map(ChatReportReason::ChatReportReasonChildAbuse)
+            }
+            "chatReportReasonCopyright" => {
+                Deserialize::deserialize(value).map(ChatReportReason::ChatReportReasonCopyright)
+            }
+            "chatReportReasonUnrelatedLocation" => Deserialize::deserialize(value)
+                .map(ChatReportReason::ChatReportReasonUnrelatedLocation),
+            "chatReportReasonCustom" => {
+                Deserialize::deserialize(value).map(ChatReportReason::ChatReportReasonCustom)
+            }
+            _ => Unknown::deserialize(value).map(ChatReportReason::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatReportReason {
+    const CLASS: &'static str = "ChatReportReason";
+    fn type_name(&self) -> &str {
+        match self {
+            ChatReportReason::ChatReportReasonSpam(_) => "chatReportReasonSpam",
+            ChatReportReason::ChatReportReasonViolence(_) => "chatReportReasonViolence",
+            ChatReportReason::ChatReportReasonPornography(_) => "chatReportReasonPornography",
+            ChatReportReason::ChatReportReasonChildAbuse(_) => "chatReportReasonChildAbuse",
+            ChatReportReason::ChatReportReasonCopyright(_) => "chatReportReasonCopyright",
+            ChatReportReason::ChatReportReasonUnrelatedLocation(_) => {
+                "chatReportReasonUnrelatedLocation"
+            }
+            ChatReportReason::ChatReportReasonCustom(_) => "chatReportReasonCustom",
+            ChatReportReason::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for ChatReportReason {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            ChatReportReason::ChatReportReasonSpam(value) => value.validate(),
+            ChatReportReason::ChatReportReasonViolence(value) => value.validate(),
+            ChatReportReason::ChatReportReasonPornography(value) => value.validate(),
+            ChatReportReason::ChatReportReasonChildAbuse(value) => value.validate(),
+            ChatReportReason::ChatReportReasonCopyright(value) => value.validate(),
+            ChatReportReason::ChatReportReasonUnrelatedLocation(value) => value.validate(),
+            ChatReportReason::ChatReportReasonCustom(value) => value.validate(),
+            ChatReportReason::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatReportReasonSpam> for ChatReportReason {
+    fn from(value: ChatReportReasonSpam) -> Self {
+        ChatReportReason::ChatReportReasonSpam(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatReportReasonViolence> for ChatReportReason {
+    fn from(value: ChatReportReasonViolence) -> Self {
+        ChatReportReason::ChatReportReasonViolence(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatReportReasonPornography> for ChatReportReason {
+    fn from(value: ChatReportReasonPornography) -> Self {
+        ChatReportReason::ChatReportReasonPornography(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatReportReasonChildAbuse> for ChatReportReason {
+    fn from(value: ChatReportReasonChildAbuse) -> Self {
+        ChatReportReason::ChatReportReasonChildAbuse(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatReportReasonCopyright> for ChatReportReason {
+    fn from(value: ChatReportReasonCopyright) -> Self {
+        ChatReportReason::ChatReportReasonCopyright(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatReportReasonUnrelatedLocation> for ChatReportReason {
+    fn from(value: ChatReportReasonUnrelatedLocation) -> Self {
+        ChatReportReason::ChatReportReasonUnrelatedLocation(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<ChatReportReasonCustom> for ChatReportReason {
+    fn from(value: ChatReportReasonCustom) -> Self {
+        ChatReportReason::ChatReportReasonCustom(value)
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A category containing frequently used private chats with non-bot users"]
+pub struct TopChatCategoryUsers {}
+#[cfg(feature = "chats")]
+impl TlObject for TopChatCategoryUsers {
+    const CLASS: &'static str = "TopChatCategory";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for TopChatCategoryUsers {
+    const TYPE: &'static str = "topChatCategoryUsers";
+}
+#[cfg(feature = "chats")]
+impl TopChatCategoryUsers {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for TopChatCategoryUsers {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A category containing frequently used private chats with bot users"]
+pub struct TopChatCategoryBots {}
+#[cfg(feature = "chats")]
+impl TlObject for TopChatCategoryBots {
+    const CLASS: &'static str = "TopChatCategory";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for TopChatCategoryBots {
+    const TYPE: &'static str = "topChatCategoryBots";
+}
+#[cfg(feature = "chats")]
+impl TopChatCategoryBots {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for TopChatCategoryBots {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A category containing frequently used basic groups and supergroups"]
+pub struct TopChatCategoryGroups {}
+#[cfg(feature = "chats")]
+impl TlObject for TopChatCategoryGroups {
+    const CLASS: &'static str = "TopChatCategory";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for TopChatCategoryGroups {
+    const TYPE: &'static str = "topChatCategoryGroups";
+}
+#[cfg(feature = "chats")]
+impl TopChatCategoryGroups {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for TopChatCategoryGroups {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A category containing frequently used channels"]
+pub struct TopChatCategoryChannels {}
+#[cfg(feature = "chats")]
+impl TlObject for TopChatCategoryChannels {
+    const CLASS: &'static str = "TopChatCategory";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for TopChatCategoryChannels {
+    const TYPE: &'static str = "topChatCategoryChannels";
+}
+#[cfg(feature = "chats")]
+impl TopChatCategoryChannels {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for TopChatCategoryChannels {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A category containing frequently used chats with inline bots sorted by their usage in inline mode"]
+pub struct TopChatCategoryInlineBots {}
+#[cfg(feature = "chats")]
+impl TlObject for TopChatCategoryInlineBots {
+    const CLASS: &'static str = "TopChatCategory";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for TopChatCategoryInlineBots {
+    const TYPE: &'static str = "topChatCategoryInlineBots";
+}
+#[cfg(feature = "chats")]
+impl TopChatCategoryInlineBots {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for TopChatCategoryInlineBots {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A category containing frequently used chats used for calls"]
+pub struct TopChatCategoryCalls {}
+#[cfg(feature = "chats")]
+impl TlObject for TopChatCategoryCalls {
+    const CLASS: &'static str = "TopChatCategory";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for TopChatCategoryCalls {
+    const TYPE: &'static str = "topChatCategoryCalls";
+}
+#[cfg(feature = "chats")]
+impl TopChatCategoryCalls {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for TopChatCategoryCalls {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A category containing frequently used chats used to forward messages"]
+pub struct TopChatCategoryForwardChats {}
+#[cfg(feature = "chats")]
+impl TlObject for TopChatCategoryForwardChats {
+    const CLASS: &'static str = "TopChatCategory";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for TopChatCategoryForwardChats {
+    const TYPE: &'static str = "topChatCategoryForwardChats";
+}
+#[cfg(feature = "chats")]
+impl TopChatCategoryForwardChats {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for TopChatCategoryForwardChats {}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Represents the categories of chats for which a list of frequently used chats can be retrieved"]
+pub enum TopChatCategory {
+    TopChatCategoryUsers(TopChatCategoryUsers),
+    TopChatCategoryBots(TopChatCategoryBots),
+    TopChatCategoryGroups(TopChatCategoryGroups),
+    TopChatCategoryChannels(TopChatCategoryChannels),
+    TopChatCategoryInlineBots(TopChatCategoryInlineBots),
+    TopChatCategoryCalls(TopChatCategoryCalls),
+    TopChatCategoryForwardChats(TopChatCategoryForwardChats),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "chats")]
+impl<'de> Deserialize<'de> for TopChatCategory {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "topChatCategoryUsers" => {
+                Deserialize::deserialize(value).map(TopChatCategory::TopChatCategoryUsers)
+            }
+            "topChatCategoryBots" => {
+                Deserialize::deserialize(value).map(TopChatCategory::TopChatCategoryBots)
+            }
+            "topChatCategoryGroups" => {
+                Deserialize::deserialize(value).map(TopChatCategory::TopChatCategoryGroups)
+            }
+            "topChatCategoryChannels" => {
+                Deserialize::deserialize(value).map(TopChatCategory::TopChatCategoryChannels)
+            }
+            "topChatCategoryInlineBots" => {
+                Deserialize::deserialize(value).map(TopChatCategory::TopChatCategoryInlineBots)
+            }
+            "topChatCategoryCalls" => {
+                Deserialize::deserialize(value).map(TopChatCategory::TopChatCategoryCalls)
+            }
+            "topChatCategoryForwardChats" => {
+                Deserialize::deserialize(value).map(TopChatCategory::TopChatCategoryForwardChats)
+            }
+            _ => Unknown::deserialize(value).map(TopChatCategory::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "chats")]
+impl TlObject for TopChatCategory {
+    const CLASS: &'static str = "TopChatCategory";
+    fn type_name(&self) -> &str {
+        match self {
+            TopChatCategory::TopChatCategoryUsers(_) => "topChatCategoryUsers",
+            TopChatCategory::TopChatCategoryBots(_) => "topChatCategoryBots",
+            TopChatCategory::TopChatCategoryGroups(_) => "topChatCategoryGroups",
+            TopChatCategory::TopChatCategoryChannels(_) => "topChatCategoryChannels",
+            TopChatCategory::TopChatCategoryInlineBots(_) => "topChatCategoryInlineBots",
+            TopChatCategory::TopChatCategoryCalls(_) => "topChatCategoryCalls",
+            TopChatCategory::TopChatCategoryForwardChats(_) => "topChatCategoryForwardChats",
+            TopChatCategory::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl Validate for TopChatCategory {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            TopChatCategory::TopChatCategoryUsers(value) => value.validate(),
+            TopChatCategory::TopChatCategoryBots(value) => value.validate(),
+            TopChatCategory::TopChatCategoryGroups(value) => value.validate(),
+            TopChatCategory::TopChatCategoryChannels(value) => value.validate(),
+            TopChatCategory::TopChatCategoryInlineBots(value) => value.validate(),
+            TopChatCategory::TopChatCategoryCalls(value) => value.validate(),
+            TopChatCategory::TopChatCategoryForwardChats(value) => value.validate(),
+            TopChatCategory::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(feature = "chats")]
+impl From<TopChatCategoryUsers> for TopChatCategory {
+    fn from(value: TopChatCategoryUsers) -> Self {
+        TopChatCategory::TopChatCategoryUsers(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<TopChatCategoryBots> for TopChatCategory {
+    fn from(value: TopChatCategoryBots) -> Self {
+        TopChatCategory::TopChatCategoryBots(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<TopChatCategoryGroups> for TopChatCategory {
+    fn from(value: TopChatCategoryGroups) -> Self {
+        TopChatCategory::TopChatCategoryGroups(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<TopChatCategoryChannels> for TopChatCategory {
+    fn from(value: TopChatCategoryChannels) -> Self {
+        TopChatCategory::TopChatCategoryChannels(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<TopChatCategoryInlineBots> for TopChatCategory {
+    fn from(value: TopChatCategoryInlineBots) -> Self {
+        TopChatCategory::TopChatCategoryInlineBots(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<TopChatCategoryCalls> for TopChatCategory {
+    fn from(value: TopChatCategoryCalls) -> Self {
+        TopChatCategory::TopChatCategoryCalls(value)
+    }
+}
+#[cfg(feature = "chats")]
+impl From<TopChatCategoryForwardChats> for TopChatCategory {
+    fn from(value: TopChatCategoryForwardChats) -> Self {
+        TopChatCategory::TopChatCategoryForwardChats(value)
+    }
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A detailed statistics about a chat"]
+pub struct ChatStatistics {
+    #[doc = "A period to which the statistics applies"]
+    pub period: DateRange,
+    #[doc = "Number of members in the chat"]
+    pub member_count: StatisticsValue,
+    #[doc = "Mean number of times the recently sent messages was viewed"]
+    pub mean_view_count: StatisticsValue,
+    #[doc = "Mean number of times the recently sent messages was shared"]
+    pub mean_share_count: StatisticsValue,
+    #[doc = "A percentage of users with enabled notifications for the chat"]
+    pub enabled_notifications_percentage: f64,
+    #[doc = "A graph containing number of members in the chat"]
+    pub member_count_graph: StatisticsGraph,
+    #[doc = "A graph containing number of members joined and left the chat"]
+    pub join_graph: StatisticsGraph,
+    #[doc = "A graph containing number of members muted and unmuted the chat"]
+    pub mute_graph: StatisticsGraph,
+    #[doc = "A graph containing number of message views in a given hour in the last two weeks"]
+    pub view_count_by_hour_graph: StatisticsGraph,
+    #[doc = "A graph containing number of message views per source"]
+    pub view_count_by_source_graph: StatisticsGraph,
+    #[doc = "A graph containing number of new member joins per source"]
+    pub join_by_source_graph: StatisticsGraph,
+    #[doc = "A graph containing number of users viewed chat messages per language"]
+    pub language_graph: StatisticsGraph,
+    #[doc = "A graph containing number of chat message views and shares"]
+    pub message_interaction_graph: StatisticsGraph,
+    #[doc = "A graph containing number of views of associated with the chat instant views"]
+    pub instant_view_interaction_graph: StatisticsGraph,
+    #[doc = "Detailed statistics about number of views and shares of recently sent messages"]
+    pub recent_message_interactions: Vec<ChatStatisticsMessageInteractionCounters>,
+}
+#[cfg(feature = "chats")]
+impl TlObject for ChatStatistics {
+    const CLASS: &'static str = "ChatStatistics";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for ChatStatistics {
+    const TYPE: &'static str = "chatStatistics";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new chat has been loaded/created. This update is guaranteed to come before the chat identifier is returned to the client. The chat field changes will be reported through separate updates"]
+pub struct UpdateNewChat {
+    #[doc = "The chat"]
+    pub chat: Chat,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateNewChat {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateNewChat {
+    const TYPE: &'static str = "updateNewChat";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The list to which the chat belongs was changed. This update is guaranteed to be sent only when chat.order == 0 and the current or the new chat list is null"]
+pub struct UpdateChatChatList {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[serde(default)]
+    #[doc = "The new chat's chat list; may be null"]
+    pub chat_list: Option<ChatList>,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatChatList {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatChatList {
+    const TYPE: &'static str = "updateChatChatList";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The title of a chat was changed"]
+pub struct UpdateChatTitle {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "The new chat title"]
+    pub title: String,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatTitle {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatTitle {
+    const TYPE: &'static str = "updateChatTitle";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A chat photo was changed"]
+pub struct UpdateChatPhoto {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[serde(default)]
+    #[doc = "The new chat photo; may be null"]
+    pub photo: Option<ChatPhoto>,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatPhoto {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatPhoto {
+    const TYPE: &'static str = "updateChatPhoto";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Chat permissions was changed"]
+pub struct UpdateChatPermissions {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "The new chat permissions"]
+    pub permissions: ChatPermissions,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatPermissions {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatPermissions {
+    const TYPE: &'static str = "updateChatPermissions";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The order of the chat in the chat list has changed. Instead of this update updateChatLastMessage, updateChatIsPinned, updateChatDraftMessage, or updateChatIsSponsored might be sent"]
+pub struct UpdateChatOrder {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "New value of the order"]
+    pub order: i64,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatOrder {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatOrder {
+    const TYPE: &'static str = "updateChatOrder";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A chat was pinned or unpinned"]
+pub struct UpdateChatIsPinned {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New value of is_pinned"]
+    pub is_pinned: bool,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "New value of the chat order"]
+    pub order: i64,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatIsPinned {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatIsPinned {
+    const TYPE: &'static str = "updateChatIsPinned";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A chat was marked as unread or was read"]
+pub struct UpdateChatIsMarkedAsUnread {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New value of is_marked_as_unread"]
+    pub is_marked_as_unread: bool,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatIsMarkedAsUnread {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatIsMarkedAsUnread {
+    const TYPE: &'static str = "updateChatIsMarkedAsUnread";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A chat's is_sponsored field has changed"]
+pub struct UpdateChatIsSponsored {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "New value of is_sponsored"]
+    pub is_sponsored: bool,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "New value of chat order"]
+    pub order: i64,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatIsSponsored {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatIsSponsored {
+    const TYPE: &'static str = "updateChatIsSponsored";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The value of the default disable_notification parameter, used when a message is sent to the chat, was changed"]
+pub struct UpdateChatDefaultDisableNotification {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "The new default_disable_notification value"]
+    pub default_disable_notification: bool,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatDefaultDisableNotification {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatDefaultDisableNotification {
+    const TYPE: &'static str = "updateChatDefaultDisableNotification";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Incoming messages were read or number of unread messages has been changed"]
+pub struct UpdateChatReadInbox {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of the last read incoming message"]
+    pub last_read_inbox_message_id: MessageId,
+    #[doc = "The number of unread messages left in the chat"]
+    pub unread_count: i32,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatReadInbox {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatReadInbox {
+    const TYPE: &'static str = "updateChatReadInbox";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Outgoing messages were read"]
+pub struct UpdateChatReadOutbox {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of last read outgoing message"]
+    pub last_read_outbox_message_id: MessageId,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatReadOutbox {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatReadOutbox {
+    const TYPE: &'static str = "updateChatReadOutbox";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat unread_mention_count has changed"]
+pub struct UpdateChatUnreadMentionCount {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "The number of unread mention messages left in the chat"]
+    pub unread_mention_count: i32,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatUnreadMentionCount {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatUnreadMentionCount {
+    const TYPE: &'static str = "updateChatUnreadMentionCount";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Notification settings for a chat were changed"]
+pub struct UpdateChatNotificationSettings {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "The new notification settings"]
+    pub notification_settings: ChatNotificationSettings,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatNotificationSettings {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatNotificationSettings {
+    const TYPE: &'static str = "updateChatNotificationSettings";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The chat action bar was changed"]
+pub struct UpdateChatActionBar {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[serde(default)]
+    #[doc = "The new value of the action bar; may be null"]
+    pub action_bar: Option<ChatActionBar>,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatActionBar {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatActionBar {
+    const TYPE: &'static str = "updateChatActionBar";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The number of online group members has changed. This update with non-zero count is sent only for currently opened chats. There is no guarantee that it will be sent just after the count has changed"]
+pub struct UpdateChatOnlineMemberCount {
+    #[doc = "Identifier of the chat"]
+    pub chat_id: ChatId,
+    #[doc = "New number of online members in the chat, or 0 if unknown"]
+    pub online_member_count: i32,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateChatOnlineMemberCount {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateChatOnlineMemberCount {
+    const TYPE: &'static str = "updateChatOnlineMemberCount";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "User activity in the chat has changed"]
+pub struct UpdateUserChatAction {
+    #[doc = "Chat identifier"]
+    pub chat_id: ChatId,
+    #[doc = "Identifier of a user performing an action"]
+    pub user_id: UserId,
+    #[doc = "The action description"]
+    pub action: ChatAction,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateUserChatAction {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateUserChatAction {
+    const TYPE: &'static str = "updateUserChatAction";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Some data of a basic group has changed. This update is guaranteed to come before the basic group identifier is returned to the client"]
+pub struct UpdateBasicGroup {
+    #[doc = "New data about the group"]
+    pub basic_group: BasicGroup,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateBasicGroup {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateBasicGroup {
+    const TYPE: &'static str = "updateBasicGroup";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Some data of a supergroup or a channel has changed. This update is guaranteed to come before the supergroup identifier is returned to the client"]
+pub struct UpdateSupergroup {
+    #[doc = "New data about the supergroup"]
+    pub supergroup: Supergroup,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateSupergroup {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateSupergroup {
+    const TYPE: &'static str = "updateSupergroup";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Some data of a secret chat has changed. This update is guaranteed to come before the secret chat identifier is returned to the client"]
+pub struct UpdateSecretChat {
+    #[doc = "New data about the secret chat"]
+    pub secret_chat: SecretChat,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateSecretChat {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateSecretChat {
+    const TYPE: &'static str = "updateSecretChat";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Some data from basicGroupFullInfo has been changed"]
+pub struct UpdateBasicGroupFullInfo {
+    #[doc = "Identifier of a basic group"]
+    pub basic_group_id: BasicGroupId,
+    #[doc = "New full information about the group"]
+    pub basic_group_full_info: BasicGroupFullInfo,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateBasicGroupFullInfo {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateBasicGroupFullInfo {
+    const TYPE: &'static str = "updateBasicGroupFullInfo";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Some data from supergroupFullInfo has been changed"]
+pub struct UpdateSupergroupFullInfo {
+    #[doc = "Identifier of the supergroup or channel"]
+    pub supergroup_id: SupergroupId,
+    #[doc = "New full information about the supergroup"]
+    pub supergroup_full_info: SupergroupFullInfo,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateSupergroupFullInfo {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateSupergroupFullInfo {
+    const TYPE: &'static str = "updateSupergroupFullInfo";
+}
+#[cfg(feature = "chats")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Number of unread chats, i.e. with unread messages or marked as unread, has changed. This update is sent only if the message database is used"]
+pub struct UpdateUnreadChatCount {
+    #[doc = "The chat list with changed number of unread messages"]
+    pub chat_list: ChatList,
+    #[doc = "Approximate total number of chats in the chat list"]
+    pub total_count: i32,
+    #[doc = "Total number of unread chats"]
+    pub unread_count: i32,
+    #[doc = "Total number of unread unmuted chats"]
+    pub unread_unmuted_count: i32,
+    #[doc = "Total number of chats marked as unread"]
+    pub marked_as_unread_count: i32,
+    #[doc = "Total number of unmuted chats marked as unread"]
+    pub marked_as_unread_unmuted_count: i32,
+}
+#[cfg(feature = "chats")]
+impl TlObject for UpdateUnreadChatCount {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "chats")]
+impl TlConstructor for UpdateUnreadChatCount {
+    const TYPE: &'static str = "updateUnreadChatCount";
+}
@@ -0,0 +1,2037 @@
+//! The types of the `passport` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a date according to the Gregorian calendar"]
+pub struct Date {
+    #[doc = "Day of the month, 1-31"]
+    pub day: i32,
+    #[doc = "Month, 1-12"]
+    pub month: i32,
+    #[doc = "Year, 1-9999"]
+    pub year: i32,
+}
+impl TlObject for Date {
+    const CLASS: &'static str = "Date";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for Date {
+    const TYPE: &'static str = "date";
+}
+impl Date {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(day: impl Into<i32>, month: impl Into<i32>, year: impl Into<i32>) -> Self {
+        Self {
+            day: day.into(),
+            month: month.into(),
+            year: year.into(),
+        }
+    }
+}
+impl Validate for Date {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "day",
+            Measure::Value,
+            i64::from(self.day),
+            Some(1),
+            Some(31),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "month",
+            Measure::Value,
+            i64::from(self.month),
+            Some(1),
+            Some(12),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "year",
+            Measure::Value,
+            i64::from(self.year),
+            Some(1),
+            Some(9999),
+        )?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains the user's personal details"]
+pub struct PersonalDetails {
+    #[doc = "First name of the user written in English; 1-255 characters"]
+    pub first_name: String,
+    #[doc = "Middle name of the user written in English; 0-255 characters"]
+    pub middle_name: String,
+    #[doc = "Last name of the user written in English; 1-255 characters"]
+    pub last_name: String,
+    #[doc = "Native first name of the user; 1-255 characters"]
+    pub native_first_name: String,
+    #[doc = "Native middle name of the user; 0-255 characters"]
+    pub native_middle_name: String,
+    #[doc = "Native last name of the user; 1-255 characters"]
+    pub native_last_name: String,
+    #[doc = "Birthdate of the user"]
+    pub birthdate: Date,
+    #[doc = "Gender of the user, \"male\" or \"female\""]
+    pub gender: String,
+    #[doc = "A two-letter ISO 3166-1 alpha-2 country code of the user's country"]
+    pub country_code: String,
+    #[doc = "A two-letter ISO 3166-1 alpha-2 country code of the user's residence country"]
+    pub residence_country_code: String,
+}
+impl TlObject for PersonalDetails {
+    const CLASS: &'static str = "PersonalDetails";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PersonalDetails {
+    const TYPE: &'static str = "personalDetails";
+}
+impl PersonalDetails {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        first_name: impl Into<String>,
+        last_name: impl Into<String>,
+        native_first_name: impl Into<String>,
+        native_last_name: impl Into<String>,
+        birthdate: impl Into<Date>,
+    ) -> Self {
+        Self {
+            first_name: first_name.into(),
+            middle_name: Default::default(),
+            last_name: last_name.into(),
+            native_first_name: native_first_name.into(),
+            native_middle_name: Default::default(),
+            native_last_name: native_last_name.into(),
+            birthdate: birthdate.into(),
+            gender: Default::default(),
+            country_code: Default::default(),
+            residence_country_code: Default::default(),
+        }
+    }
+    #[doc = "Middle name of the user written in English; 0-255 characters"]
+    pub fn with_middle_name(mut self, value: impl Into<String>) -> Self {
+        self.middle_name = value.into();
+        self
+    }
+    #[doc = "Native middle name of the user; 0-255 characters"]
+    pub fn with_native_middle_name(mut self, value: impl Into<String>) -> Self {
+        self.native_middle_name = value.into();
+        self
+    }
+    #[doc = "Gender of the user, \"male\" or \"female\""]
+    pub fn with_gender(mut self, value: impl Into<String>) -> Self {
+        self.gender = value.into();
+        self
+    }
+    #[doc = "A two-letter ISO 3166-1 alpha-2 country code of the user's country"]
+    pub fn with_country_code(mut self, value: impl Into<String>) -> Self {
+        self.country_code = value.into();
+        self
+    }
+    #[doc = "A two-letter ISO 3166-1 alpha-2 country code of the user's residence country"]
+    pub fn with_residence_country_code(mut self, value: impl Into<String>) -> Self {
+        self.residence_country_code = value.into();
+        self
+    }
+}
+impl Validate for PersonalDetails {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "first_name",
+            Measure::Characters,
+            self.first_name.chars().count() as i64,
+            Some(1),
+            Some(255),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "middle_name",
+            Measure::Characters,
+            self.middle_name.chars().count() as i64,
+            Some(0),
+            Some(255),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "last_name",
+            Measure::Characters,
+            self.last_name.chars().count() as i64,
+            Some(1),
+            Some(255),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "native_first_name",
+            Measure::Characters,
+            self.native_first_name.chars().count() as i64,
+            Some(1),
+            Some(255),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "native_middle_name",
+            Measure::Characters,
+            self.native_middle_name.chars().count() as i64,
+            Some(0),
+            Some(255),
+        )?;
+        validate::check(
+            Self::TYPE,
+            "native_last_name",
+            Measure::Characters,
+            self.native_last_name.chars().count() as i64,
+            Some(1),
+            Some(255),
+        )?;
+        self.birthdate
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "birthdate"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An identity document"]
+pub struct IdentityDocument {
+    #[doc = "Document number; 1-24 characters"]
+    pub number: String,
+    #[serde(default)]
+    #[doc = "Document expiry date; may be null"]
+    pub expiry_date: Option<Date>,
+    #[doc = "Front side of the document"]
+    pub front_side: DatedFile,
+    #[doc = "Reverse side of the document; only for driver license and identity card"]
+    pub reverse_side: DatedFile,
+    #[serde(default)]
+    #[doc = "Selfie with the document; may be null"]
+    pub selfie: Option<DatedFile>,
+    #[doc = "List of files containing a certified English translation of the document"]
+    pub translation: Vec<DatedFile>,
+}
+impl TlObject for IdentityDocument {
+    const CLASS: &'static str = "IdentityDocument";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for IdentityDocument {
+    const TYPE: &'static str = "identityDocument";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An identity document to be saved to Telegram Passport"]
+pub struct InputIdentityDocument {
+    #[doc = "Document number; 1-24 characters"]
+    pub number: String,
+    #[doc = "Document expiry date, if available"]
+    pub expiry_date: Date,
+    #[doc = "Front side of the document"]
+    pub front_side: InputFile,
+    #[doc = "Reverse side of the document; only for driver license and identity card"]
+    pub reverse_side: InputFile,
+    #[doc = "Selfie with the document, if available"]
+    pub selfie: InputFile,
+    #[doc = "List of files containing a certified English translation of the document"]
+    pub translation: Vec<InputFile>,
+}
+impl TlObject for InputIdentityDocument {
+    const CLASS: &'static str = "InputIdentityDocument";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputIdentityDocument {
+    const TYPE: &'static str = "inputIdentityDocument";
+}
+impl InputIdentityDocument {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        number: impl Into<String>,
+        expiry_date: impl Into<Date>,
+        front_side: impl Into<InputFile>,
+        reverse_side: impl Into<InputFile>,
+        selfie: impl Into<InputFile>,
+    ) -> Self {
+        Self {
+            number: number.into(),
+            expiry_date: expiry_date.into(),
+            front_side: front_side.into(),
+            reverse_side: reverse_side.into(),
+            selfie: selfie.into(),
+            translation: Default::default(),
+        }
+    }
+    #[doc = "List of files containing a certified English translation of the document"]
+    pub fn with_translation(mut self, value: impl Into<Vec<InputFile>>) -> Self {
+        self.translation = value.into();
+        self
+    }
+}
+impl Validate for InputIdentityDocument {
+    fn validate(&self) -> Result<(), ValidationError> {
+        validate::check(
+            Self::TYPE,
+            "number",
+            Measure::Characters,
+            self.number.chars().count() as i64,
+            Some(1),
+            Some(24),
+        )?;
+        self.expiry_date
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "expiry_date"))?;
+        self.front_side
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "front_side"))?;
+        self.reverse_side
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "reverse_side"))?;
+        self.selfie
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "selfie"))?;
+        for (i, value) in self.translation.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("translation[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A personal document, containing some information about a user"]
+pub struct PersonalDocument {
+    #[doc = "List of files containing the pages of the document"]
+    pub files: Vec<DatedFile>,
+    #[doc = "List of files containing a certified English translation of the document"]
+    pub translation: Vec<DatedFile>,
+}
+impl TlObject for PersonalDocument {
+    const CLASS: &'static str = "PersonalDocument";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PersonalDocument {
+    const TYPE: &'static str = "personalDocument";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A personal document to be saved to Telegram Passport"]
+pub struct InputPersonalDocument {
+    #[doc = "List of files containing the pages of the document"]
+    pub files: Vec<InputFile>,
+    #[doc = "List of files containing a certified English translation of the document"]
+    pub translation: Vec<InputFile>,
+}
+impl TlObject for InputPersonalDocument {
+    const CLASS: &'static str = "InputPersonalDocument";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPersonalDocument {
+    const TYPE: &'static str = "inputPersonalDocument";
+}
+impl InputPersonalDocument {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "List of files containing the pages of the document"]
+    pub fn with_files(mut self, value: impl Into<Vec<InputFile>>) -> Self {
+        self.files = value.into();
+        self
+    }
+    #[doc = "List of files containing a certified English translation of the document"]
+    pub fn with_translation(mut self, value: impl Into<Vec<InputFile>>) -> Self {
+        self.translation = value.into();
+        self
+    }
+}
+impl Validate for InputPersonalDocument {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, value) in self.files.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("files[{}]", i)))?;
+        }
+        for (i, value) in self.translation.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("translation[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's personal details"]
+pub struct PassportElementPersonalDetails {
+    #[doc = "Personal details of the user"]
+    pub personal_details: PersonalDetails,
+}
+impl TlObject for PassportElementPersonalDetails {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementPersonalDetails {
+    const TYPE: &'static str = "passportElementPersonalDetails";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's passport"]
+pub struct PassportElementPassport {
+    #[doc = "Passport"]
+    pub passport: IdentityDocument,
+}
+impl TlObject for PassportElementPassport {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementPassport {
+    const TYPE: &'static str = "passportElementPassport";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's driver license"]
+pub struct PassportElementDriverLicense {
+    #[doc = "Driver license"]
+    pub driver_license: IdentityDocument,
+}
+impl TlObject for PassportElementDriverLicense {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementDriverLicense {
+    const TYPE: &'static str = "passportElementDriverLicense";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's identity card"]
+pub struct PassportElementIdentityCard {
+    #[doc = "Identity card"]
+    pub identity_card: IdentityDocument,
+}
+impl TlObject for PassportElementIdentityCard {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementIdentityCard {
+    const TYPE: &'static str = "passportElementIdentityCard";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's internal passport"]
+pub struct PassportElementInternalPassport {
+    #[doc = "Internal passport"]
+    pub internal_passport: IdentityDocument,
+}
+impl TlObject for PassportElementInternalPassport {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementInternalPassport {
+    const TYPE: &'static str = "passportElementInternalPassport";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's address"]
+pub struct PassportElementAddress {
+    #[doc = "Address"]
+    pub address: Address,
+}
+impl TlObject for PassportElementAddress {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementAddress {
+    const TYPE: &'static str = "passportElementAddress";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's utility bill"]
+pub struct PassportElementUtilityBill {
+    #[doc = "Utility bill"]
+    pub utility_bill: PersonalDocument,
+}
+impl TlObject for PassportElementUtilityBill {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementUtilityBill {
+    const TYPE: &'static str = "passportElementUtilityBill";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's bank statement"]
+pub struct PassportElementBankStatement {
+    #[doc = "Bank statement"]
+    pub bank_statement: PersonalDocument,
+}
+impl TlObject for PassportElementBankStatement {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementBankStatement {
+    const TYPE: &'static str = "passportElementBankStatement";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's rental agreement"]
+pub struct PassportElementRentalAgreement {
+    #[doc = "Rental agreement"]
+    pub rental_agreement: PersonalDocument,
+}
+impl TlObject for PassportElementRentalAgreement {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementRentalAgreement {
+    const TYPE: &'static str = "passportElementRentalAgreement";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's passport registration pages"]
+pub struct PassportElementPassportRegistration {
+    #[doc = "Passport registration pages"]
+    pub passport_registration: PersonalDocument,
+}
+impl TlObject for PassportElementPassportRegistration {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementPassportRegistration {
+    const TYPE: &'static str = "passportElementPassportRegistration";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's temporary registration"]
+pub struct PassportElementTemporaryRegistration {
+    #[doc = "Temporary registration"]
+    pub temporary_registration: PersonalDocument,
+}
+impl TlObject for PassportElementTemporaryRegistration {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementTemporaryRegistration {
+    const TYPE: &'static str = "passportElementTemporaryRegistration";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's phone number"]
+pub struct PassportElementPhoneNumber {
+    #[doc = "Phone number"]
+    pub phone_number: String,
+}
+impl TlObject for PassportElementPhoneNumber {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementPhoneNumber {
+    const TYPE: &'static str = "passportElementPhoneNumber";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element containing the user's email address"]
+pub struct PassportElementEmailAddress {
+    #[doc = "Email address"]
+    pub email_address: String,
+}
+impl TlObject for PassportElementEmailAddress {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementEmailAddress {
+    const TYPE: &'static str = "passportElementEmailAddress";
+}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Contains information about a Telegram Passport element"]
+pub enum PassportElement {
+    PassportElementPersonalDetails(Box<PassportElementPersonalDetails>),
+    PassportElementPassport(Box<PassportElementPassport>),
+    PassportElementDriverLicense(Box<PassportElementDriverLicense>),
+    PassportElementIdentityCard(Box<PassportElementIdentityCard>),
+    PassportElementInternalPassport(Box<PassportElementInternalPassport>),
+    PassportElementAddress(Box<PassportElementAddress>),
+    PassportElementUtilityBill(PassportElementUtilityBill),
+    PassportElementBankStatement(PassportElementBankStatement),
+    PassportElementRentalAgreement(PassportElementRentalAgreement),
+    PassportElementPassportRegistration(PassportElementPassportRegistration),
+    PassportElementTemporaryRegistration(PassportElementTemporaryRegistration),
+    PassportElementPhoneNumber(PassportElementPhoneNumber),
+    PassportElementEmailAddress(PassportElementEmailAddress),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for PassportElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "passportElementPersonalDetails" => {
+                Deserialize::deserialize(value).map(PassportElement::PassportElementPersonalDetails)
+            }
+            "passportElementPassport" => {
+                Deserialize::deserialize(value).map(PassportElement::PassportElementPassport)
+            }
+            "passportElementDriverLicense" => {
+                Deserialize::deserialize(value).map(PassportElement::PassportElementDriverLicense)
+            }
+            "passportElementIdentityCard" => {
+                Deserialize::deserialize(value).map(PassportElement::PassportElementIdentityCard)
+            }
+            "passportElementInternalPassport" => Deserialize::deserialize(value)
+                .map(PassportElement::PassportElementInternalPassport),
+            "passportElementAddress" => {
+                Deserialize::deserialize(value).map(PassportElement::PassportElementAddress)
+            }
+            "passportElementUtilityBill" => {
+                Deserialize::deserialize(value).map(PassportElement::PassportElementUtilityBill)
+            }
+            "passportElementBankStatement" => {
+                Deserialize::deserialize(value).map(PassportElement::PassportElementBankStatement)
+            }
+            "passportElementRentalAgreement" => {
+                Deserialize::deserialize(value).map(PassportElement::PassportElementRentalAgreement)
+            }
+            "passportElementPassportRegistration" => Deserialize::deserialize(value)
+                .map(PassportElement::PassportElementPassportRegistration),
+            "passportElementTemporaryRegistration" => Deserialize::deserialize(value)
+                .map(PassportElement::PassportElementTemporaryRegistration),
+            "passportElementPhoneNumber" => {
+                Deserialize::deserialize(value).map(PassportElement::PassportElementPhoneNumber)
+            }
+            "passportElementEmailAddress" => {
+                Deserialize::deserialize(value).map(PassportElement::PassportElementEmailAddress)
+            }
+            _ => Unknown::deserialize(value).map(PassportElement::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for PassportElement {
+    const CLASS: &'static str = "PassportElement";
+    fn type_name(&self) -> &str {
+        match self {
+            PassportElement::PassportElementPersonalDetails(_) => "passportElementPersonalDetails",
+            PassportElement::PassportElementPassport(_) => "passportElementPassport",
+            PassportElement::PassportElementDriverLicense(_) => "passportElementDriverLicense",
+            PassportElement::PassportElementIdentityCard(_) => "passportElementIdentityCard",
+            PassportElement::PassportElementInternalPassport(_) => {
+                "passportElementInternalPassport"
+            }
+            PassportElement::PassportElementAddress(_) => "passportElementAddress",
+            PassportElement::PassportElementUtilityBill(_) => "passportElementUtilityBill",
+            PassportElement::PassportElementBankStatement(_) => "passportElementBankStatement",
+            PassportElement::PassportElementRentalAgreement(_) => "passportElementRentalAgreement",
+            PassportElement::PassportElementPassportRegistration(_) => {
+                "passportElementPassportRegistration"
+            }
+            PassportElement::PassportElementTemporaryRegistration(_) => {
+                "passportElementTemporaryRegistration"
+            }
+            PassportElement::PassportElementPhoneNumber(_) => "passportElementPhoneNumber",
+            PassportElement::PassportElementEmailAddress(_) => "passportElementEmailAddress",
+            PassportElement::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's personal details"]
+pub struct InputPassportElementPersonalDetails {
+    #[doc = "Personal details of the user"]
+    pub personal_details: PersonalDetails,
+}
+impl TlObject for InputPassportElementPersonalDetails {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementPersonalDetails {
+    const TYPE: &'static str = "inputPassportElementPersonalDetails";
+}
+impl InputPassportElementPersonalDetails {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(personal_details: impl Into<PersonalDetails>) -> Self {
+        Self {
+            personal_details: personal_details.into(),
+        }
+    }
+}
+impl Validate for InputPassportElementPersonalDetails {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.personal_details
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "personal_details"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's passport"]
+pub struct InputPassportElementPassport {
+    #[doc = "The passport to be saved"]
+    pub passport: InputIdentityDocument,
+}
+impl TlObject for InputPassportElementPassport {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementPassport {
+    const TYPE: &'static str = "inputPassportElementPassport";
+}
+impl InputPassportElementPassport {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(passport: impl Into<InputIdentityDocument>) -> Self {
+        Self {
+            passport: passport.into(),
+        }
+    }
+}
+impl Validate for InputPassportElementPassport {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.passport
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "passport"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's driver license"]
+pub struct InputPassportElementDriverLicense {
+    #[doc = "The driver license to be saved"]
+    pub driver_license: InputIdentityDocument,
+}
+impl TlObject for InputPassportElementDriverLicense {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementDriverLicense {
+    const TYPE: &'static str = "inputPassportElementDriverLicense";
+}
+impl InputPassportElementDriverLicense {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(driver_license: impl Into<InputIdentityDocument>) -> Self {
+        Self {
+            driver_license: driver_license.into(),
+        }
+    }
+}
+impl Validate for InputPassportElementDriverLicense {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.driver_license
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "driver_license"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's identity card"]
+pub struct InputPassportElementIdentityCard {
+    #[doc = "The identity card to be saved"]
+    pub identity_card: InputIdentityDocument,
+}
+impl TlObject for InputPassportElementIdentityCard {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementIdentityCard {
+    const TYPE: &'static str = "inputPassportElementIdentityCard";
+}
+impl InputPassportElementIdentityCard {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(identity_card: impl Into<InputIdentityDocument>) -> Self {
+        Self {
+            identity_card: identity_card.into(),
+        }
+    }
+}
+impl Validate for InputPassportElementIdentityCard {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.identity_card
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "identity_card"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's internal passport"]
+pub struct InputPassportElementInternalPassport {
+    #[doc = "The internal passport to be saved"]
+    pub internal_passport: InputIdentityDocument,
+}
+impl TlObject for InputPassportElementInternalPassport {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementInternalPassport {
+    const TYPE: &'static str = "inputPassportElementInternalPassport";
+}
+impl InputPassportElementInternalPassport {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(internal_passport: impl Into<InputIdentityDocument>) -> Self {
+        Self {
+            internal_passport: internal_passport.into(),
+        }
+    }
+}
+impl Validate for InputPassportElementInternalPassport {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.internal_passport
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "internal_passport"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's address"]
+pub struct InputPassportElementAddress {
+    #[doc = "The address to be saved"]
+    pub address: Address,
+}
+impl TlObject for InputPassportElementAddress {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementAddress {
+    const TYPE: &'static str = "inputPassportElementAddress";
+}
+impl InputPassportElementAddress {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The address to be saved"]
+    pub fn with_address(mut self, value: impl Into<Address>) -> Self {
+        self.address = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementAddress {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.address
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "address"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's utility bill"]
+pub struct InputPassportElementUtilityBill {
+    #[doc = "The utility bill to be saved"]
+    pub utility_bill: InputPersonalDocument,
+}
+impl TlObject for InputPassportElementUtilityBill {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementUtilityBill {
+    const TYPE: &'static str = "inputPassportElementUtilityBill";
+}
+impl InputPassportElementUtilityBill {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The utility bill to be saved"]
+    pub fn with_utility_bill(mut self, value: impl Into<InputPersonalDocument>) -> Self {
+        self.utility_bill = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementUtilityBill {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.utility_bill
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "utility_bill"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's bank statement"]
+pub struct InputPassportElementBankStatement {
+    #[doc = "The bank statement to be saved"]
+    pub bank_statement: InputPersonalDocument,
+}
+impl TlObject for InputPassportElementBankStatement {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementBankStatement {
+    const TYPE: &'static str = "inputPassportElementBankStatement";
+}
+impl InputPassportElementBankStatement {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The bank statement to be saved"]
+    pub fn with_bank_statement(mut self, value: impl Into<InputPersonalDocument>) -> Self {
+        self.bank_statement = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementBankStatement {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.bank_statement
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "bank_statement"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's rental agreement"]
+pub struct InputPassportElementRentalAgreement {
+    #[doc = "The rental agreement to be saved"]
+    pub rental_agreement: InputPersonalDocument,
+}
+impl TlObject for InputPassportElementRentalAgreement {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementRentalAgreement {
+    const TYPE: &'static str = "inputPassportElementRentalAgreement";
+}
+impl InputPassportElementRentalAgreement {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The rental agreement to be saved"]
+    pub fn with_rental_agreement(mut self, value: impl Into<InputPersonalDocument>) -> Self {
+        self.rental_agreement = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementRentalAgreement {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.rental_agreement
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "rental_agreement"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's passport registration"]
+pub struct InputPassportElementPassportRegistration {
+    #[doc = "The passport registration page to be saved"]
+    pub passport_registration: InputPersonalDocument,
+}
+impl TlObject for InputPassportElementPassportRegistration {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementPassportRegistration {
+    const TYPE: &'static str = "inputPassportElementPassportRegistration";
+}
+impl InputPassportElementPassportRegistration {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The passport registration page to be saved"]
+    pub fn with_passport_registration(mut self, value: impl Into<InputPersonalDocument>) -> Self {
+        self.passport_registration = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementPassportRegistration {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.passport_registration
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "passport_registration"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's temporary registration"]
+pub struct InputPassportElementTemporaryRegistration {
+    #[doc = "The temporary registration document to be saved"]
+    pub temporary_registration: InputPersonalDocument,
+}
+impl TlObject for InputPassportElementTemporaryRegistration {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementTemporaryRegistration {
+    const TYPE: &'static str = "inputPassportElementTemporaryRegistration";
+}
+impl InputPassportElementTemporaryRegistration {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The temporary registration document to be saved"]
+    pub fn with_temporary_registration(mut self, value: impl Into<InputPersonalDocument>) -> Self {
+        self.temporary_registration = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementTemporaryRegistration {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.temporary_registration
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "temporary_registration"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's phone number"]
+pub struct InputPassportElementPhoneNumber {
+    #[doc = "The phone number to be saved"]
+    pub phone_number: String,
+}
+impl TlObject for InputPassportElementPhoneNumber {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementPhoneNumber {
+    const TYPE: &'static str = "inputPassportElementPhoneNumber";
+}
+impl InputPassportElementPhoneNumber {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The phone number to be saved"]
+    pub fn with_phone_number(mut self, value: impl Into<String>) -> Self {
+        self.phone_number = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementPhoneNumber {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A Telegram Passport element to be saved containing the user's email address"]
+pub struct InputPassportElementEmailAddress {
+    #[doc = "The email address to be saved"]
+    pub email_address: String,
+}
+impl TlObject for InputPassportElementEmailAddress {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementEmailAddress {
+    const TYPE: &'static str = "inputPassportElementEmailAddress";
+}
+impl InputPassportElementEmailAddress {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The email address to be saved"]
+    pub fn with_email_address(mut self, value: impl Into<String>) -> Self {
+        self.email_address = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementEmailAddress {}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Contains information about a Telegram Passport element to be saved"]
+pub enum InputPassportElement {
+    InputPassportElementPersonalDetails(Box<InputPassportElementPersonalDetails>),
+    InputPassportElementPassport(Box<InputPassportElementPassport>),
+    InputPassportElementDriverLicense(Box<InputPassportElementDriverLicense>),
+    InputPassportElementIdentityCard(Box<InputPassportElementIdentityCard>),
+    InputPassportElementInternalPassport(Box<InputPassportElementInternalPassport>),
+    InputPassportElementAddress(Box<InputPassportElementAddress>),
+    InputPassportElementUtilityBill(InputPassportElementUtilityBill),
+    InputPassportElementBankStatement(InputPassportElementBankStatement),
+    InputPassportElementRentalAgreement(InputPassportElementRentalAgreement),
+    InputPassportElementPassportRegistration(InputPassportElementPassportRegistration),
+    InputPassportElementTemporaryRegistration(InputPassportElementTemporaryRegistration),
+    InputPassportElementPhoneNumber(InputPassportElementPhoneNumber),
+    InputPassportElementEmailAddress(InputPassportElementEmailAddress),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for InputPassportElement {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "inputPassportElementPersonalDetails" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementPersonalDetails),
+            "inputPassportElementPassport" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementPassport),
+            "inputPassportElementDriverLicense" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementDriverLicense),
+            "inputPassportElementIdentityCard" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementIdentityCard),
+            "inputPassportElementInternalPassport" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementInternalPassport),
+            "inputPassportElementAddress" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementAddress),
+            "inputPassportElementUtilityBill" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementUtilityBill),
+            "inputPassportElementBankStatement" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementBankStatement),
+            "inputPassportElementRentalAgreement" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementRentalAgreement),
+            "inputPassportElementPassportRegistration" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementPassportRegistration),
+            "inputPassportElementTemporaryRegistration" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementTemporaryRegistration),
+            "inputPassportElementPhoneNumber" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementPhoneNumber),
+            "inputPassportElementEmailAddress" => Deserialize::deserialize(value)
+                .map(InputPassportElement::InputPassportElementEmailAddress),
+            _ => Unknown::deserialize(value).map(InputPassportElement::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for InputPassportElement {
+    const CLASS: &'static str = "InputPassportElement";
+    fn type_name(&self) -> &str {
+        match self {
+            InputPassportElement::InputPassportElementPersonalDetails(_) => {
+                "inputPassportElementPersonalDetails"
+            }
+            InputPassportElement::InputPassportElementPassport(_) => "inputPassportElementPassport",
+            InputPassportElement::InputPassportElementDriverLicense(_) => {
+                "inputPassportElementDriverLicense"
+            }
+            InputPassportElement::InputPassportElementIdentityCard(_) => {
+                "inputPassportElementIdentityCard"
+            }
+            InputPassportElement::InputPassportElementInternalPassport(_) => {
+                "inputPassportElementInternalPassport"
+            }
+            InputPassportElement::InputPassportElementAddress(_) => "inputPassportElementAddress",
+            InputPassportElement::InputPassportElementUtilityBill(_) => {
+                "inputPassportElementUtilityBill"
+            }
+            InputPassportElement::InputPassportElementBankStatement(_) => {
+                "inputPassportElementBankStatement"
+            }
+            InputPassportElement::InputPassportElementRentalAgreement(_) => {
+                "inputPassportElementRentalAgreement"
+            }
+            InputPassportElement::InputPassportElementPassportRegistration(_) => {
+                "inputPassportElementPassportRegistration"
+            }
+            InputPassportElement::InputPassportElementTemporaryRegistration(_) => {
+                "inputPassportElementTemporaryRegistration"
+            }
+            InputPassportElement::InputPassportElementPhoneNumber(_) => {
+                "inputPassportElementPhoneNumber"
+            }
+            InputPassportElement::InputPassportElementEmailAddress(_) => {
+                "inputPassportElementEmailAddress"
+            }
+            InputPassportElement::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for InputPassportElement {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            InputPassportElement::InputPassportElementPersonalDetails(value) => value.validate(),
+            InputPassportElement::InputPassportElementPassport(value) => value.validate(),
+            InputPassportElement::InputPassportElementDriverLicense(value) => value.validate(),
+            InputPassportElement::InputPassportElementIdentityCard(value) => value.validate(),
+            InputPassportElement::InputPassportElementInternalPassport(value) => value.validate(),
+            InputPassportElement::InputPassportElementAddress(value) => value.validate(),
+            InputPassportElement::InputPassportElementUtilityBill(value) => value.validate(),
+            InputPassportElement::InputPassportElementBankStatement(value) => value.validate(),
+            InputPassportElement::InputPassportElementRentalAgreement(value) => value.validate(),
+            InputPassportElement::InputPassportElementPassportRegistration(value) => {
+                value.validate()
+            }
+            InputPassportElement::InputPassportElementTemporaryRegistration(value) => {
+                value.validate()
+            }
+            InputPassportElement::InputPassportElementPhoneNumber(value) => value.validate(),
+            InputPassportElement::InputPassportElementEmailAddress(value) => value.validate(),
+            InputPassportElement::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<InputPassportElementPersonalDetails> for InputPassportElement {
+    fn from(value: InputPassportElementPersonalDetails) -> Self {
+        InputPassportElement::InputPassportElementPersonalDetails(Box::new(value))
+    }
+}
+impl From<InputPassportElementPassport> for InputPassportElement {
+    fn from(value: InputPassportElementPassport) -> Self {
+        InputPassportElement::InputPassportElementPassport(Box::new(value))
+    }
+}
+impl From<InputPassportElementDriverLicense> for InputPassportElement {
+    fn from(value: InputPassportElementDriverLicense) -> Self {
+        InputPassportElement::InputPassportElementDriverLicense(Box::new(value))
+    }
+}
+impl From<InputPassportElementIdentityCard> for InputPassportElement {
+    fn from(value: InputPassportElementIdentityCard) -> Self {
+        InputPassportElement::InputPassportElementIdentityCard(Box::new(value))
+    }
+}
+impl From<InputPassportElementInternalPassport> for InputPassportElement {
+    fn from(value: InputPassportElementInternalPassport) -> Self {
+        InputPassportElement::InputPassportElementInternalPassport(Box::new(value))
+    }
+}
+impl From<InputPassportElementAddress> for InputPassportElement {
+    fn from(value: InputPassportElementAddress) -> Self {
+        InputPassportElement::InputPassportElementAddress(Box::new(value))
+    }
+}
+impl From<InputPassportElementUtilityBill> for InputPassportElement {
+    fn from(value: InputPassportElementUtilityBill) -> Self {
+        InputPassportElement::InputPassportElementUtilityBill(value)
+    }
+}
+impl From<InputPassportElementBankStatement> for InputPassportElement {
+    fn from(value: InputPassportElementBankStatement) -> Self {
+        InputPassportElement::InputPassportElementBankStatement(value)
+    }
+}
+impl From<InputPassportElementRentalAgreement> for InputPassportElement {
+    fn from(value: InputPassportElementRentalAgreement) -> Self {
+        InputPassportElement::InputPassportElementRentalAgreement(value)
+    }
+}
+impl From<InputPassportElementPassportRegistration> for InputPassportElement {
+    fn from(value: InputPassportElementPassportRegistration) -> Self {
+        InputPassportElement::InputPassportElementPassportRegistration(value)
+    }
+}
+impl From<InputPassportElementTemporaryRegistration> for InputPassportElement {
+    fn from(value: InputPassportElementTemporaryRegistration) -> Self {
+        InputPassportElement::InputPassportElementTemporaryRegistration(value)
+    }
+}
+impl From<InputPassportElementPhoneNumber> for InputPassportElement {
+    fn from(value: InputPassportElementPhoneNumber) -> Self {
+        InputPassportElement::InputPassportElementPhoneNumber(value)
+    }
+}
+impl From<InputPassportElementEmailAddress> for InputPassportElement {
+    fn from(value: InputPassportElementEmailAddress) -> Self {
+        InputPassportElement::InputPassportElementEmailAddress(value)
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about saved Telegram Passport elements"]
+pub struct PassportElements {
+    #[doc = "Telegram Passport elements"]
+    pub elements: Vec<PassportElement>,
+}
+impl TlObject for PassportElements {
+    const CLASS: &'static str = "PassportElements";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElements {
+    const TYPE: &'static str = "passportElements";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The element contains an error in an unspecified place. The error will be considered resolved when new data is added"]
+pub struct PassportElementErrorSourceUnspecified {}
+impl TlObject for PassportElementErrorSourceUnspecified {
+    const CLASS: &'static str = "PassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementErrorSourceUnspecified {
+    const TYPE: &'static str = "passportElementErrorSourceUnspecified";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "One of the data fields contains an error. The error will be considered resolved when the value of the field changes"]
+pub struct PassportElementErrorSourceDataField {
+    #[doc = "Field name"]
+    pub field_name: String,
+}
+impl TlObject for PassportElementErrorSourceDataField {
+    const CLASS: &'static str = "PassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementErrorSourceDataField {
+    const TYPE: &'static str = "passportElementErrorSourceDataField";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The front side of the document contains an error. The error will be considered resolved when the file with the front side changes"]
+pub struct PassportElementErrorSourceFrontSide {}
+impl TlObject for PassportElementErrorSourceFrontSide {
+    const CLASS: &'static str = "PassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementErrorSourceFrontSide {
+    const TYPE: &'static str = "passportElementErrorSourceFrontSide";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The reverse side of the document contains an error. The error will be considered resolved when the file with the reverse side changes"]
+pub struct PassportElementErrorSourceReverseSide {}
+impl TlObject for PassportElementErrorSourceReverseSide {
+    const CLASS: &'static str = "PassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementErrorSourceReverseSide {
+    const TYPE: &'static str = "passportElementErrorSourceReverseSide";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The selfie with the document contains an error. The error will be considered resolved when the file with the selfie changes"]
+pub struct PassportElementErrorSourceSelfie {}
+impl TlObject for PassportElementErrorSourceSelfie {
+    const CLASS: &'static str = "PassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementErrorSourceSelfie {
+    const TYPE: &'static str = "passportElementErrorSourceSelfie";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "One of files with the translation of the document contains an error. The error will be considered resolved when the file changes"]
+pub struct PassportElementErrorSourceTranslationFile {
+    #[doc = "Index of a file with the error"]
+    pub file_index: i32,
+}
+impl TlObject for PassportElementErrorSourceTranslationFile {
+    const CLASS: &'static str = "PassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementErrorSourceTranslationFile {
+    const TYPE: &'static str = "passportElementErrorSourceTranslationFile";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The translation of the document contains an error. The error will be considered resolved when the list of translation files changes"]
+pub struct PassportElementErrorSourceTranslationFiles {}
+impl TlObject for PassportElementErrorSourceTranslationFiles {
+    const CLASS: &'static str = "PassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementErrorSourceTranslationFiles {
+    const TYPE: &'static str = "passportElementErrorSourceTranslationFiles";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The file contains an error. The error will be considered resolved when the file changes"]
+pub struct PassportElementErrorSourceFile {
+    #[doc = "Index of a file with the error"]
+    pub file_index: i32,
+}
+impl TlObject for PassportElementErrorSourceFile {
+    const CLASS: &'static str = "PassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementErrorSourceFile {
+    const TYPE: &'static str = "passportElementErrorSourceFile";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The list of attached files contains an error. The error will be considered resolved when the list of files changes"]
+pub struct PassportElementErrorSourceFiles {}
+impl TlObject for PassportElementErrorSourceFiles {
+    const CLASS: &'static str = "PassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementErrorSourceFiles {
+    const TYPE: &'static str = "passportElementErrorSourceFiles";
+}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Contains the description of an error in a Telegram Passport element"]
+pub enum PassportElementErrorSource {
+    PassportElementErrorSourceUnspecified(PassportElementErrorSourceUnspecified),
+    PassportElementErrorSourceDataField(PassportElementErrorSourceDataField),
+    PassportElementErrorSourceFrontSide(PassportElementErrorSourceFrontSide),
+    PassportElementErrorSourceReverseSide(PassportElementErrorSourceReverseSide),
+    PassportElementErrorSourceSelfie(PassportElementErrorSourceSelfie),
+    PassportElementErrorSourceTranslationFile(PassportElementErrorSourceTranslationFile),
+    PassportElementErrorSourceTranslationFiles(PassportElementErrorSourceTranslationFiles),
+    PassportElementErrorSourceFile(PassportElementErrorSourceFile),
+    PassportElementErrorSourceFiles(PassportElementErrorSourceFiles),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for PassportElementErrorSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "passportElementErrorSourceUnspecified" => Deserialize::deserialize(value)
+                .map(PassportElementErrorSource::PassportElementErrorSourceUnspecified),
+            "passportElementErrorSourceDataField" => Deserialize::deserialize(value)
+                .map(PassportElementErrorSource::PassportElementErrorSourceDataField),
+            "passportElementErrorSourceFrontSide" => Deserialize::deserialize(value)
+                .map(PassportElementErrorSource::PassportElementErrorSourceFrontSide),
+            "passportElementErrorSourceReverseSide" => Deserialize::deserialize(value)
+                .map(PassportElementErrorSource::PassportElementErrorSourceReverseSide),
+            "passportElementErrorSourceSelfie" => Deserialize::deserialize(value)
+                .map(PassportElementErrorSource::PassportElementErrorSourceSelfie),
+            "passportElementErrorSourceTranslationFile" => Deserialize::deserialize(value)
+                .map(PassportElementErrorSource::PassportElementErrorSourceTranslationFile),
+            "passportElementErrorSourceTranslationFiles" => Deserialize::deserialize(value)
+                .map(PassportElementErrorSource::PassportElementErrorSourceTranslationFiles),
+            "passportElementErrorSourceFile" => Deserialize::deserialize(value)
+                .map(PassportElementErrorSource::PassportElementErrorSourceFile),
+            "passportElementErrorSourceFiles" => Deserialize::deserialize(value)
+                .map(PassportElementErrorSource::PassportElementErrorSourceFiles),
+            _ => Unknown::deserialize(value).map(PassportElementErrorSource::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for PassportElementErrorSource {
+    const CLASS: &'static str = "PassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        match self {
+            PassportElementErrorSource::PassportElementErrorSourceUnspecified(_) => {
+                "passportElementErrorSourceUnspecified"
+            }
+            PassportElementErrorSource::PassportElementErrorSourceDataField(_) => {
+                "passportElementErrorSourceDataField"
+            }
+            PassportElementErrorSource::PassportElementErrorSourceFrontSide(_) => {
+                "passportElementErrorSourceFrontSide"
+            }
+            PassportElementErrorSource::PassportElementErrorSourceReverseSide(_) => {
+                "passportElementErrorSourceReverseSide"
+            }
+            PassportElementErrorSource::PassportElementErrorSourceSelfie(_) => {
+                "passportElementErrorSourceSelfie"
+            }
+            PassportElementErrorSource::PassportElementErrorSourceTranslationFile(_) => {
+                "passportElementErrorSourceTranslationFile"
+            }
+            PassportElementErrorSource::PassportElementErrorSourceTranslationFiles(_) => {
+                "passportElementErrorSourceTranslationFiles"
+            }
+            PassportElementErrorSource::PassportElementErrorSourceFile(_) => {
+                "passportElementErrorSourceFile"
+            }
+            PassportElementErrorSource::PassportElementErrorSourceFiles(_) => {
+                "passportElementErrorSourceFiles"
+            }
+            PassportElementErrorSource::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains the description of an error in a Telegram Passport element"]
+pub struct PassportElementError {
+    #[serde(rename = "type")]
+    #[doc = "Type of the Telegram Passport element which has the error"]
+    pub type_: PassportElementType,
+    #[doc = "Error message"]
+    pub message: String,
+    #[doc = "Error source"]
+    pub source: PassportElementErrorSource,
+}
+impl TlObject for PassportElementError {
+    const CLASS: &'static str = "PassportElementError";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementError {
+    const TYPE: &'static str = "passportElementError";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about a Telegram Passport element that was requested by a service"]
+pub struct PassportSuitableElement {
+    #[serde(rename = "type")]
+    #[doc = "Type of the element"]
+    pub type_: PassportElementType,
+    #[doc = "True, if a selfie is required with the identity document"]
+    pub is_selfie_required: bool,
+    #[doc = "True, if a certified English translation is required with the document"]
+    pub is_translation_required: bool,
+    #[doc = "True, if personal details must include the user's name in the language of their country of residence"]
+    pub is_native_name_required: bool,
+}
+impl TlObject for PassportSuitableElement {
+    const CLASS: &'static str = "PassportSuitableElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportSuitableElement {
+    const TYPE: &'static str = "passportSuitableElement";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a description of the required Telegram Passport element that was requested by a service"]
+pub struct PassportRequiredElement {
+    #[doc = "List of Telegram Passport elements any of which is enough to provide"]
+    pub suitable_elements: Vec<PassportSuitableElement>,
+}
+impl TlObject for PassportRequiredElement {
+    const CLASS: &'static str = "PassportRequiredElement";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportRequiredElement {
+    const TYPE: &'static str = "passportRequiredElement";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about a Telegram Passport authorization form that was requested"]
+pub struct PassportAuthorizationForm {
+    #[doc = "Unique identifier of the authorization form"]
+    pub id: i32,
+    #[doc = "Information about the Telegram Passport elements that need to be provided to complete the form"]
+    pub required_elements: Vec<PassportRequiredElement>,
+    #[doc = "URL for the privacy policy of the service; may be empty"]
+    pub privacy_policy_url: String,
+}
+impl TlObject for PassportAuthorizationForm {
+    const CLASS: &'static str = "PassportAuthorizationForm";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportAuthorizationForm {
+    const TYPE: &'static str = "passportAuthorizationForm";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about a Telegram Passport elements and corresponding errors"]
+pub struct PassportElementsWithErrors {
+    #[doc = "Telegram Passport elements"]
+    pub elements: Vec<PassportElement>,
+    #[doc = "Errors in the elements that are already available"]
+    pub errors: Vec<PassportElementError>,
+}
+impl TlObject for PassportElementsWithErrors {
+    const CLASS: &'static str = "PassportElementsWithErrors";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for PassportElementsWithErrors {
+    const TYPE: &'static str = "passportElementsWithErrors";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The element contains an error in an unspecified place. The error will be considered resolved when new data is added"]
+pub struct InputPassportElementErrorSourceUnspecified {
+    #[doc = "Current hash of the entire element"]
+    pub element_hash: Bytes,
+}
+impl TlObject for InputPassportElementErrorSourceUnspecified {
+    const CLASS: &'static str = "InputPassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementErrorSourceUnspecified {
+    const TYPE: &'static str = "inputPassportElementErrorSourceUnspecified";
+}
+impl InputPassportElementErrorSourceUnspecified {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Current hash of the entire element"]
+    pub fn with_element_hash(mut self, value: impl Into<Bytes>) -> Self {
+        self.element_hash = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementErrorSourceUnspecified {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A data field contains an error. The error is considered resolved when the field's value changes"]
+pub struct InputPassportElementErrorSourceDataField {
+    #[doc = "Field name"]
+    pub field_name: String,
+    #[doc = "Current data hash"]
+    pub data_hash: Bytes,
+}
+impl TlObject for InputPassportElementErrorSourceDataField {
+    const CLASS: &'static str = "InputPassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementErrorSourceDataField {
+    const TYPE: &'static str = "inputPassportElementErrorSourceDataField";
+}
+impl InputPassportElementErrorSourceDataField {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Field name"]
+    pub fn with_field_name(mut self, value: impl Into<String>) -> Self {
+        self.field_name = value.into();
+        self
+    }
+    #[doc = "Current data hash"]
+    pub fn with_data_hash(mut self, value: impl Into<Bytes>) -> Self {
+        self.data_hash = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementErrorSourceDataField {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The front side of the document contains an error. The error is considered resolved when the file with the front side of the document changes"]
+pub struct InputPassportElementErrorSourceFrontSide {
+    #[doc = "Current hash of the file containing the front side"]
+    pub file_hash: Bytes,
+}
+impl TlObject for InputPassportElementErrorSourceFrontSide {
+    const CLASS: &'static str = "InputPassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementErrorSourceFrontSide {
+    const TYPE: &'static str = "inputPassportElementErrorSourceFrontSide";
+}
+impl InputPassportElementErrorSourceFrontSide {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Current hash of the file containing the front side"]
+    pub fn with_file_hash(mut self, value: impl Into<Bytes>) -> Self {
+        self.file_hash = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementErrorSourceFrontSide {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The reverse side of the document contains an error. The error is considered resolved when the file with the reverse side of the document changes"]
+pub struct InputPassportElementErrorSourceReverseSide {
+    #[doc = "Current hash of the file containing the reverse side"]
+    pub file_hash: Bytes,
+}
+impl TlObject for InputPassportElementErrorSourceReverseSide {
+    const CLASS: &'static str = "InputPassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementErrorSourceReverseSide {
+    const TYPE: &'static str = "inputPassportElementErrorSourceReverseSide";
+}
+impl InputPassportElementErrorSourceReverseSide {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Current hash of the file containing the reverse side"]
+    pub fn with_file_hash(mut self, value: impl Into<Bytes>) -> Self {
+        self.file_hash = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementErrorSourceReverseSide {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The selfie contains an error. The error is considered resolved when the file with the selfie changes"]
+pub struct InputPassportElementErrorSourceSelfie {
+    #[doc = "Current hash of the file containing the selfie"]
+    pub file_hash: Bytes,
+}
+impl TlObject for InputPassportElementErrorSourceSelfie {
+    const CLASS: &'static str = "InputPassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementErrorSourceSelfie {
+    const TYPE: &'static str = "inputPassportElementErrorSourceSelfie";
+}
+impl InputPassportElementErrorSourceSelfie {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Current hash of the file containing the selfie"]
+    pub fn with_file_hash(mut self, value: impl Into<Bytes>) -> Self {
+        self.file_hash = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementErrorSourceSelfie {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "One of the files containing the translation of the document contains an error. The error is considered resolved when the file with the translation changes"]
+pub struct InputPassportElementErrorSourceTranslationFile {
+    #[doc = "Current hash of the file containing the translation"]
+    pub file_hash: Bytes,
+}
+impl TlObject for InputPassportElementErrorSourceTranslationFile {
+    const CLASS: &'static str = "InputPassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementErrorSourceTranslationFile {
+    const TYPE: &'static str = "inputPassportElementErrorSourceTranslationFile";
+}
+impl InputPassportElementErrorSourceTranslationFile {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Current hash of the file containing the translation"]
+    pub fn with_file_hash(mut self, value: impl Into<Bytes>) -> Self {
+        self.file_hash = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementErrorSourceTranslationFile {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The translation of the document contains an error. The error is considered resolved when the list of files changes"]
+pub struct InputPassportElementErrorSourceTranslationFiles {
+    #[doc = "Current hashes of all files with the translation"]
+    pub file_hashes: Vec<Bytes>,
+}
+impl TlObject for InputPassportElementErrorSourceTranslationFiles {
+    const CLASS: &'static str = "InputPassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementErrorSourceTranslationFiles {
+    const TYPE: &'static str = "inputPassportElementErrorSourceTranslationFiles";
+}
+impl InputPassportElementErrorSourceTranslationFiles {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Current hashes of all files with the translation"]
+    pub fn with_file_hashes(mut self, value: impl Into<Vec<Bytes>>) -> Self {
+        self.file_hashes = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementErrorSourceTranslationFiles {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The file contains an error. The error is considered resolved when the file changes"]
+pub struct InputPassportElementErrorSourceFile {
+    #[doc = "Current hash of the file which has the error"]
+    pub file_hash: Bytes,
+}
+impl TlObject for InputPassportElementErrorSourceFile {
+    const CLASS: &'static str = "InputPassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementErrorSourceFile {
+    const TYPE: &'static str = "inputPassportElementErrorSourceFile";
+}
+impl InputPassportElementErrorSourceFile {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Current hash of the file which has the error"]
+    pub fn with_file_hash(mut self, value: impl Into<Bytes>) -> Self {
+        self.file_hash = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementErrorSourceFile {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "The list of attached files contains an error. The error is considered resolved when the file list changes"]
+pub struct InputPassportElementErrorSourceFiles {
+    #[doc = "Current hashes of all attached files"]
+    pub file_hashes: Vec<Bytes>,
+}
+impl TlObject for InputPassportElementErrorSourceFiles {
+    const CLASS: &'static str = "InputPassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementErrorSourceFiles {
+    const TYPE: &'static str = "inputPassportElementErrorSourceFiles";
+}
+impl InputPassportElementErrorSourceFiles {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Current hashes of all attached files"]
+    pub fn with_file_hashes(mut self, value: impl Into<Vec<Bytes>>) -> Self {
+        self.file_hashes = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementErrorSourceFiles {}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Contains the description of an error in a Telegram Passport element; for bots only"]
+pub enum InputPassportElementErrorSource {
+    InputPassportElementErrorSourceUnspecified(InputPassportElementErrorSourceUnspecified),
+    InputPassportElementErrorSourceDataField(InputPassportElementErrorSourceDataField),
+    InputPassportElementErrorSourceFrontSide(InputPassportElementErrorSourceFrontSide),
+    InputPassportElementErrorSourceReverseSide(InputPassportElementErrorSourceReverseSide),
+    InputPassportElementErrorSourceSelfie(InputPassportElementErrorSourceSelfie),
+    InputPassportElementErrorSourceTranslationFile(InputPassportElementErrorSourceTranslationFile),
+    InputPassportElementErrorSourceTranslationFiles(
+        InputPassportElementErrorSourceTranslationFiles,
+    ),
+    InputPassportElementErrorSourceFile(InputPassportElementErrorSourceFile),
+    InputPassportElementErrorSourceFiles(InputPassportElementErrorSourceFiles),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for InputPassportElementErrorSource {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "inputPassportElementErrorSourceUnspecified" => Deserialize::deserialize(value)
+                .map(InputPassportElementErrorSource::InputPassportElementErrorSourceUnspecified),
+            "inputPassportElementErrorSourceDataField" => Deserialize::deserialize(value)
+                .map(InputPassportElementErrorSource::InputPassportElementErrorSourceDataField),
+            "inputPassportElementErrorSourceFrontSide" => Deserialize::deserialize(value)
+                .map(InputPassportElementErrorSource::InputPassportElementErrorSourceFrontSide),
+            "inputPassportElementErrorSourceReverseSide" => Deserialize::deserialize(value)
+                .map(InputPassportElementErrorSource::InputPassportElementErrorSourceReverseSide),
+            "inputPassportElementErrorSourceSelfie" => Deserialize::deserialize(value)
+                .map(InputPassportElementErrorSource::InputPassportElementErrorSourceSelfie),
+            "inputPassportElementErrorSourceTranslationFile" => Deserialize::deserialize(value)
+                .map(
+                    InputPassportElementErrorSource::InputPassportElementErrorSourceTranslationFile,
+                ),
+            "inputPassportElementErrorSourceTranslationFiles" => Deserialize::deserialize(value)
+                .map(
+                InputPassportElementErrorSource::InputPassportElementErrorSourceTranslationFiles,
+            ),
+            "inputPassportElementErrorSourceFile" => Deserialize::deserialize(value)
+                .map(InputPassportElementErrorSource::InputPassportElementErrorSourceFile),
+            "inputPassportElementErrorSourceFiles" => Deserialize::deserialize(value)
+                .map(InputPassportElementErrorSource::InputPassportElementErrorSourceFiles),
+            _ => Unknown::deserialize(value).map(InputPassportElementErrorSource::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for InputPassportElementErrorSource {
+    const CLASS: &'static str = "InputPassportElementErrorSource";
+    fn type_name(&self) -> &str {
+        match self {
+            InputPassportElementErrorSource::InputPassportElementErrorSourceUnspecified(_) => {
+                "inputPassportElementErrorSourceUnspecified"
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceDataField(_) => {
+                "inputPassportElementErrorSourceDataField"
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceFrontSide(_) => {
+                "inputPassportElementErrorSourceFrontSide"
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceReverseSide(_) => {
+                "inputPassportElementErrorSourceReverseSide"
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceSelfie(_) => {
+                "inputPassportElementErrorSourceSelfie"
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceTranslationFile(_) => {
+                "inputPassportElementErrorSourceTranslationFile"
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceTranslationFiles(_) => {
+                "inputPassportElementErrorSourceTranslationFiles"
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceFile(_) => {
+                "inputPassportElementErrorSourceFile"
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceFiles(_) => {
+                "inputPassportElementErrorSourceFiles"
+            }
+            InputPassportElementErrorSource::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for InputPassportElementErrorSource {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            InputPassportElementErrorSource::InputPassportElementErrorSourceUnspecified(value) => {
+                value.validate()
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceDataField(value) => {
+                value.validate()
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceFrontSide(value) => {
+                value.validate()
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceReverseSide(value) => {
+                value.validate()
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceSelfie(value) => {
+                value.validate()
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceTranslationFile(
+                value,
+            ) => value.validate(),
+            InputPassportElementErrorSource::InputPassportElementErrorSourceTranslationFiles(
+                value,
+            ) => value.validate(),
+            InputPassportElementErrorSource::InputPassportElementErrorSourceFile(value) => {
+                value.validate()
+            }
+            InputPassportElementErrorSource::InputPassportElementErrorSourceFiles(value) => {
+                value.validate()
+            }
+            InputPassportElementErrorSource::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<InputPassportElementErrorSourceUnspecified> for InputPassportElementErrorSource {
+    fn from(value: InputPassportElementErrorSourceUnspecified) -> Self {
+        InputPassportElementErrorSource::InputPassportElementErrorSourceUnspecified(value)
+    }
+}
+impl From<InputPassportElementErrorSourceDataField> for InputPassportElementErrorSource {
+    fn from(value: InputPassportElementErrorSourceDataField) -> Self {
+        InputPassportElementErrorSource::InputPassportElementErrorSourceDataField(value)
+    }
+}
+impl From<InputPassportElementErrorSourceFrontSide> for InputPassportElementErrorSource {
+    fn from(value: InputPassportElementErrorSourceFrontSide) -> Self {
+        InputPassportElementErrorSource::InputPassportElementErrorSourceFrontSide(value)
+    }
+}
+impl From<InputPassportElementErrorSourceReverseSide> for InputPassportElementErrorSource {
+    fn from(value: InputPassportElementErrorSourceReverseSide) -> Self {
+        InputPassportElementErrorSource::InputPassportElementErrorSourceReverseSide(value)
+    }
+}
+impl From<InputPassportElementErrorSourceSelfie> for InputPassportElementErrorSource {
+    fn from(value: InputPassportElementErrorSourceSelfie) -> Self {
+        InputPassportElementErrorSource::InputPassportElementErrorSourceSelfie(value)
+    }
+}
+impl From<InputPassportElementErrorSourceTranslationFile> for InputPassportElementErrorSource {
+    fn from(value: InputPassportElementErrorSourceTranslationFile) -> Self {
+        InputPassportElementErrorSource::InputPassportElementErrorSourceTranslationFile(value)
+    }
+}
+impl From<InputPassportElementErrorSourceTranslationFiles> for InputPassportElementErrorSource {
+    fn from(value: InputPassportElementErrorSourceTranslationFiles) -> Self {
+        InputPassportElementErrorSource::InputPassportElementErrorSourceTranslationFiles(value)
+    }
+}
+impl From<InputPassportElementErrorSourceFile> for InputPassportElementErrorSource {
+    fn from(value: InputPassportElementErrorSourceFile) -> Self {
+        InputPassportElementErrorSource::InputPassportElementErrorSourceFile(value)
+    }
+}
+impl From<InputPassportElementErrorSourceFiles> for InputPassportElementErrorSource {
+    fn from(value: InputPassportElementErrorSourceFiles) -> Self {
+        InputPassportElementErrorSource::InputPassportElementErrorSourceFiles(value)
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains the description of an error in a Telegram Passport element; for bots only"]
+pub struct InputPassportElementError {
+    #[serde(rename = "type")]
+    #[doc = "Type of Telegram Passport element that has the error"]
+    pub type_: PassportElementType,
+    #[doc = "Error message"]
+    pub message: String,
+    #[doc = "Error source"]
+    pub source: InputPassportElementErrorSource,
+}
+impl TlObject for InputPassportElementError {
+    const CLASS: &'static str = "InputPassportElementError";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputPassportElementError {
+    const TYPE: &'static str = "inputPassportElementError";
+}
+impl InputPassportElementError {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(
+        type_: impl Into<PassportElementType>,
+        source: impl Into<InputPassportElementErrorSource>,
+    ) -> Self {
+        Self {
+            type_: type_.into(),
+            message: Default::default(),
+            source: source.into(),
+        }
+    }
+    #[doc = "Error message"]
+    pub fn with_message(mut self, value: impl Into<String>) -> Self {
+        self.message = value.into();
+        self
+    }
+}
+impl Validate for InputPassportElementError {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.type_
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "type"))?;
+        self.source
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "source"))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,663 @@
+//! The types of the `payments` feature.
+
+use super::*;
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Describes an action associated with a bank card number"]
+pub struct BankCardActionOpenUrl {
+    #[doc = "Action text"]
+    pub text: String,
+    #[doc = "The URL to be opened"]
+    pub url: String,
+}
+#[cfg(feature = "payments")]
+impl TlObject for BankCardActionOpenUrl {
+    const CLASS: &'static str = "BankCardActionOpenUrl";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for BankCardActionOpenUrl {
+    const TYPE: &'static str = "bankCardActionOpenUrl";
+}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Information about a bank card"]
+pub struct BankCardInfo {
+    #[doc = "Title of the bank card description"]
+    pub title: String,
+    #[doc = "Actions that can be done with the bank card number"]
+    pub actions: Vec<BankCardActionOpenUrl>,
+}
+#[cfg(feature = "payments")]
+impl TlObject for BankCardInfo {
+    const CLASS: &'static str = "BankCardInfo";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for BankCardInfo {
+    const TYPE: &'static str = "bankCardInfo";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Portion of the price of a product (e.g., \"delivery cost\", \"tax amount\")"]
+pub struct LabeledPricePart {
+    #[doc = "Label for this portion of the product price"]
+    pub label: String,
+    #[doc = "Currency amount in minimal quantity of the currency"]
+    pub amount: i64,
+}
+impl TlObject for LabeledPricePart {
+    const CLASS: &'static str = "LabeledPricePart";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for LabeledPricePart {
+    const TYPE: &'static str = "labeledPricePart";
+}
+impl LabeledPricePart {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Label for this portion of the product price"]
+    pub fn with_label(mut self, value: impl Into<String>) -> Self {
+        self.label = value.into();
+        self
+    }
+    #[doc = "Currency amount in minimal quantity of the currency"]
+    pub fn with_amount(mut self, value: impl Into<i64>) -> Self {
+        self.amount = value.into();
+        self
+    }
+}
+impl Validate for LabeledPricePart {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Product invoice"]
+pub struct Invoice {
+    #[doc = "ISO 4217 currency code"]
+    pub currency: String,
+    #[doc = "A list of objects used to calculate the total price of the product"]
+    pub price_parts: Vec<LabeledPricePart>,
+    #[doc = "True, if the payment is a test payment"]
+    pub is_test: bool,
+    #[doc = "True, if the user's name is needed for payment"]
+    pub need_name: bool,
+    #[doc = "True, if the user's phone number is needed for payment"]
+    pub need_phone_number: bool,
+    #[doc = "True, if the user's email address is needed for payment"]
+    pub need_email_address: bool,
+    #[doc = "True, if the user's shipping address is needed for payment"]
+    pub need_shipping_address: bool,
+    #[doc = "True, if the user's phone number will be sent to the provider"]
+    pub send_phone_number_to_provider: bool,
+    #[doc = "True, if the user's email address will be sent to the provider"]
+    pub send_email_address_to_provider: bool,
+    #[doc = "True, if the total price depends on the shipping method"]
+    pub is_flexible: bool,
+}
+impl TlObject for Invoice {
+    const CLASS: &'static str = "Invoice";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for Invoice {
+    const TYPE: &'static str = "invoice";
+}
+impl Invoice {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "ISO 4217 currency code"]
+    pub fn with_currency(mut self, value: impl Into<String>) -> Self {
+        self.currency = value.into();
+        self
+    }
+    #[doc = "A list of objects used to calculate the total price of the product"]
+    pub fn with_price_parts(mut self, value: impl Into<Vec<LabeledPricePart>>) -> Self {
+        self.price_parts = value.into();
+        self
+    }
+    #[doc = "True, if the payment is a test payment"]
+    pub fn with_is_test(mut self, value: impl Into<bool>) -> Self {
+        self.is_test = value.into();
+        self
+    }
+    #[doc = "True, if the user's name is needed for payment"]
+    pub fn with_need_name(mut self, value: impl Into<bool>) -> Self {
+        self.need_name = value.into();
+        self
+    }
+    #[doc = "True, if the user's phone number is needed for payment"]
+    pub fn with_need_phone_number(mut self, value: impl Into<bool>) -> Self {
+        self.need_phone_number = value.into();
+        self
+    }
+    #[doc = "True, if the user's email address is needed for payment"]
+    pub fn with_need_email_address(mut self, value: impl Into<bool>) -> Self {
+        self.need_email_address = value.into();
+        self
+    }
+    #[doc = "True, if the user's shipping address is needed for payment"]
+    pub fn with_need_shipping_address(mut self, value: impl Into<bool>) -> Self {
+        self.need_shipping_address = value.into();
+        self
+    }
+    #[doc = "True, if the user's phone number will be sent to the provider"]
+    pub fn with_send_phone_number_to_provider(mut self, value: impl Into<bool>) -> Self {
+        self.send_phone_number_to_provider = value.into();
+        self
+    }
+    #[doc = "True, if the user's email address will be sent to the provider"]
+    pub fn with_send_email_address_to_provider(mut self, value: impl Into<bool>) -> Self {
+        self.send_email_address_to_provider = value.into();
+        self
+    }
+    #[doc = "True, if the total price depends on the shipping method"]
+    pub fn with_is_flexible(mut self, value: impl Into<bool>) -> Self {
+        self.is_flexible = value.into();
+        self
+    }
+}
+impl Validate for Invoice {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, value) in self.price_parts.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("price_parts[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "One shipping option"]
+pub struct ShippingOption {
+    #[doc = "Shipping option identifier"]
+    pub id: String,
+    #[doc = "Option title"]
+    pub title: String,
+    #[doc = "A list of objects used to calculate the total shipping costs"]
+    pub price_parts: Vec<LabeledPricePart>,
+}
+#[cfg(feature = "payments")]
+impl TlObject for ShippingOption {
+    const CLASS: &'static str = "ShippingOption";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for ShippingOption {
+    const TYPE: &'static str = "shippingOption";
+}
+#[cfg(feature = "payments")]
+impl ShippingOption {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Shipping option identifier"]
+    pub fn with_id(mut self, value: impl Into<String>) -> Self {
+        self.id = value.into();
+        self
+    }
+    #[doc = "Option title"]
+    pub fn with_title(mut self, value: impl Into<String>) -> Self {
+        self.title = value.into();
+        self
+    }
+    #[doc = "A list of objects used to calculate the total shipping costs"]
+    pub fn with_price_parts(mut self, value: impl Into<Vec<LabeledPricePart>>) -> Self {
+        self.price_parts = value.into();
+        self
+    }
+}
+#[cfg(feature = "payments")]
+impl Validate for ShippingOption {
+    fn validate(&self) -> Result<(), ValidationError> {
+        for (i, value) in self.price_parts.iter().enumerate() {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, &format!("price_parts[{}]", i)))?;
+        }
+        Ok(())
+    }
+}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about saved card credentials"]
+pub struct SavedCredentials {
+    #[doc = "Unique identifier of the saved credentials"]
+    pub id: String,
+    #[doc = "Title of the saved credentials"]
+    pub title: String,
+}
+#[cfg(feature = "payments")]
+impl TlObject for SavedCredentials {
+    const CLASS: &'static str = "SavedCredentials";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for SavedCredentials {
+    const TYPE: &'static str = "savedCredentials";
+}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Applies if a user chooses some previously saved payment credentials. To use their previously saved credentials, the user must have a valid temporary password"]
+pub struct InputCredentialsSaved {
+    #[doc = "Identifier of the saved credentials"]
+    pub saved_credentials_id: String,
+}
+#[cfg(feature = "payments")]
+impl TlObject for InputCredentialsSaved {
+    const CLASS: &'static str = "InputCredentials";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for InputCredentialsSaved {
+    const TYPE: &'static str = "inputCredentialsSaved";
+}
+#[cfg(feature = "payments")]
+impl InputCredentialsSaved {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Identifier of the saved credentials"]
+    pub fn with_saved_credentials_id(mut self, value: impl Into<String>) -> Self {
+        self.saved_credentials_id = value.into();
+        self
+    }
+}
+#[cfg(feature = "payments")]
+impl Validate for InputCredentialsSaved {}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Applies if a user enters new credentials on a payment provider website"]
+pub struct InputCredentialsNew {
+    #[doc = "Contains JSON-encoded data with a credential identifier from the payment provider"]
+    pub data: String,
+    #[doc = "True, if the credential identifier can be saved on the server side"]
+    pub allow_save: bool,
+}
+#[cfg(feature = "payments")]
+impl TlObject for InputCredentialsNew {
+    const CLASS: &'static str = "InputCredentials";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for InputCredentialsNew {
+    const TYPE: &'static str = "inputCredentialsNew";
+}
+#[cfg(feature = "payments")]
+impl InputCredentialsNew {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Contains JSON-encoded data with a credential identifier from the payment provider"]
+    pub fn with_data(mut self, value: impl Into<String>) -> Self {
+        self.data = value.into();
+        self
+    }
+    #[doc = "True, if the credential identifier can be saved on the server side"]
+    pub fn with_allow_save(mut self, value: impl Into<bool>) -> Self {
+        self.allow_save = value.into();
+        self
+    }
+}
+#[cfg(feature = "payments")]
+impl Validate for InputCredentialsNew {}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Applies if a user enters new credentials using Android Pay"]
+pub struct InputCredentialsAndroidPay {
+    #[doc = "JSON-encoded data with the credential identifier"]
+    pub data: String,
+}
+#[cfg(feature = "payments")]
+impl TlObject for InputCredentialsAndroidPay {
+    const CLASS: &'static str = "InputCredentials";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for InputCredentialsAndroidPay {
+    const TYPE: &'static str = "inputCredentialsAndroidPay";
+}
+#[cfg(feature = "payments")]
+impl InputCredentialsAndroidPay {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "JSON-encoded data with the credential identifier"]
+    pub fn with_data(mut self, value: impl Into<String>) -> Self {
+        self.data = value.into();
+        self
+    }
+}
+#[cfg(feature = "payments")]
+impl Validate for InputCredentialsAndroidPay {}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "Applies if a user enters new credentials using Apple Pay"]
+pub struct InputCredentialsApplePay {
+    #[doc = "JSON-encoded data with the credential identifier"]
+    pub data: String,
+}
+#[cfg(feature = "payments")]
+impl TlObject for InputCredentialsApplePay {
+    const CLASS: &'static str = "InputCredentials";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for InputCredentialsApplePay {
+    const TYPE: &'static str = "inputCredentialsApplePay";
+}
+#[cfg(feature = "payments")]
+impl InputCredentialsApplePay {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "JSON-encoded data with the credential identifier"]
+    pub fn with_data(mut self, value: impl Into<String>) -> Self {
+        self.data = value.into();
+        self
+    }
+}
+#[cfg(feature = "payments")]
+impl Validate for InputCredentialsApplePay {}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Contains information about the payment method chosen by the user"]
+pub enum InputCredentials {
+    InputCredentialsSaved(InputCredentialsSaved),
+    InputCredentialsNew(InputCredentialsNew),
+    InputCredentialsAndroidPay(InputCredentialsAndroidPay),
+    InputCredentialsApplePay(InputCredentialsApplePay),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+#[cfg(feature = "payments")]
+impl<'de> Deserialize<'de> for InputCredentials {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "inputCredentialsSaved" => {
+                Deserialize::deserialize(value).map(InputCredentials::InputCredentialsSaved)
+            }
+            "inputCredentialsNew" => {
+                Deserialize::deserialize(value).map(InputCredentials::InputCredentialsNew)
+            }
+            "inputCredentialsAndroidPay" => {
+                Deserialize::deserialize(value).map(InputCredentials::InputCredentialsAndroidPay)
+            }
+            "inputCredentialsApplePay" => {
+                Deserialize::deserialize(value).map(InputCredentials::InputCredentialsApplePay)
+            }
+            _ => Unknown::deserialize(value).map(InputCredentials::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+#[cfg(feature = "payments")]
+impl TlObject for InputCredentials {
+    const CLASS: &'static str = "InputCredentials";
+    fn type_name(&self) -> &str {
+        match self {
+            InputCredentials::InputCredentialsSaved(_) => "inputCredentialsSaved",
+            InputCredentials::InputCredentialsNew(_) => "inputCredentialsNew",
+            InputCredentials::InputCredentialsAndroidPay(_) => "inputCredentialsAndroidPay",
+            InputCredentials::InputCredentialsApplePay(_) => "inputCredentialsApplePay",
+            InputCredentials::Unknown(object) => &object.type_,
+        }
+    }
+}
+#[cfg(feature = "payments")]
+impl Validate for InputCredentials {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            InputCredentials::InputCredentialsSaved(value) => value.validate(),
+            InputCredentials::InputCredentialsNew(value) => value.validate(),
+            InputCredentials::InputCredentialsAndroidPay(value) => value.validate(),
+            InputCredentials::InputCredentialsApplePay(value) => value.validate(),
+            InputCredentials::Unknown(_) => Ok(()),
+        }
+    }
+}
+#[cfg(feature = "payments")]
+impl From<InputCredentialsSaved> for InputCredentials {
+    fn from(value: InputCredentialsSaved) -> Self {
+        InputCredentials::InputCredentialsSaved(value)
+    }
+}
+#[cfg(feature = "payments")]
+impl From<InputCredentialsNew> for InputCredentials {
+    fn from(value: InputCredentialsNew) -> Self {
+        InputCredentials::InputCredentialsNew(value)
+    }
+}
+#[cfg(feature = "payments")]
+impl From<InputCredentialsAndroidPay> for InputCredentials {
+    fn from(value: InputCredentialsAndroidPay) -> Self {
+        InputCredentials::InputCredentialsAndroidPay(value)
+    }
+}
+#[cfg(feature = "payments")]
+impl From<InputCredentialsApplePay> for InputCredentials {
+    fn from(value: InputCredentialsApplePay) -> Self {
+        InputCredentials::InputCredentialsApplePay(value)
+    }
+}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Stripe payment provider"]
+pub struct PaymentsProviderStripe {
+    #[doc = "Stripe API publishable key"]
+    pub publishable_key: String,
+    #[doc = "True, if the user country must be provided"]
+    pub need_country: bool,
+    #[doc = "True, if the user ZIP/postal code must be provided"]
+    pub need_postal_code: bool,
+    #[doc = "True, if the cardholder name must be provided"]
+    pub need_cardholder_name: bool,
+}
+#[cfg(feature = "payments")]
+impl TlObject for PaymentsProviderStripe {
+    const CLASS: &'static str = "PaymentsProviderStripe";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for PaymentsProviderStripe {
+    const TYPE: &'static str = "paymentsProviderStripe";
+}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about an invoice payment form"]
+pub struct PaymentForm {
+    #[doc = "Full information of the invoice"]
+    pub invoice: Invoice,
+    #[doc = "Payment form URL"]
+    pub url: String,
+    #[serde(default)]
+    #[doc = "Contains information about the payment provider, if available, to support it natively without the need for opening the URL; may be null"]
+    pub payments_provider: Option<PaymentsProviderStripe>,
+    #[serde(default)]
+    #[doc = "Saved server-side order information; may be null"]
+    pub saved_order_info: Option<OrderInfo>,
+    #[serde(default)]
+    #[doc = "Contains information about saved card credentials; may be null"]
+    pub saved_credentials: Option<SavedCredentials>,
+    #[doc = "True, if the user can choose to save credentials"]
+    pub can_save_credentials: bool,
+    #[doc = "True, if the user will be able to save credentials protected by a password they set up"]
+    pub need_password: bool,
+}
+#[cfg(feature = "payments")]
+impl TlObject for PaymentForm {
+    const CLASS: &'static str = "PaymentForm";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for PaymentForm {
+    const TYPE: &'static str = "paymentForm";
+}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains a temporary identifier of validated order information, which is stored for one hour. Also contains the available shipping options"]
+pub struct ValidatedOrderInfo {
+    #[doc = "Temporary identifier of the order information"]
+    pub order_info_id: String,
+    #[doc = "Available shipping options"]
+    pub shipping_options: Vec<ShippingOption>,
+}
+#[cfg(feature = "payments")]
+impl TlObject for ValidatedOrderInfo {
+    const CLASS: &'static str = "ValidatedOrderInfo";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for ValidatedOrderInfo {
+    const TYPE: &'static str = "validatedOrderInfo";
+}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains the result of a payment request"]
+pub struct PaymentResult {
+    #[doc = "True, if the payment request was successful; otherwise the verification_url will be not empty"]
+    pub success: bool,
+    #[doc = "URL for additional payment credentials verification"]
+    pub verification_url: String,
+}
+#[cfg(feature = "payments")]
+impl TlObject for PaymentResult {
+    const CLASS: &'static str = "PaymentResult";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for PaymentResult {
+    const TYPE: &'static str = "paymentResult";
+}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about a successful payment"]
+pub struct PaymentReceipt {
+    #[doc = "Point in time (Unix timestamp) when the payment was made"]
+    pub date: i32,
+    #[doc = "User identifier of the payment provider bot"]
+    pub payments_provider_user_id: UserId,
+    #[doc = "Contains information about the invoice"]
+    pub invoice: Invoice,
+    #[serde(default)]
+    #[doc = "Contains order information; may be null"]
+    pub order_info: Option<OrderInfo>,
+    #[serde(default)]
+    #[doc = "Chosen shipping option; may be null"]
+    pub shipping_option: Option<ShippingOption>,
+    #[doc = "Title of the saved credentials"]
+    pub credentials_title: String,
+}
+#[cfg(feature = "payments")]
+impl TlObject for PaymentReceipt {
+    const CLASS: &'static str = "PaymentReceipt";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for PaymentReceipt {
+    const TYPE: &'static str = "paymentReceipt";
+}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new incoming shipping query; for bots only. Only for invoices with flexible price"]
+pub struct UpdateNewShippingQuery {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Unique query identifier"]
+    pub id: i64,
+    #[doc = "Identifier of the user who sent the query"]
+    pub sender_user_id: UserId,
+    #[doc = "Invoice payload"]
+    pub invoice_payload: String,
+    #[doc = "User shipping address"]
+    pub shipping_address: Address,
+}
+#[cfg(feature = "payments")]
+impl TlObject for UpdateNewShippingQuery {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for UpdateNewShippingQuery {
+    const TYPE: &'static str = "updateNewShippingQuery";
+}
+#[cfg(feature = "payments")]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A new incoming pre-checkout query; for bots only. Contains full information about a checkout"]
+pub struct UpdateNewPreCheckoutQuery {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Unique query identifier"]
+    pub id: i64,
+    #[doc = "Identifier of the user who sent the query"]
+    pub sender_user_id: UserId,
+    #[doc = "Currency for the product price"]
+    pub currency: String,
+    #[doc = "Total price for the product, in the minimal quantity of the currency"]
+    pub total_amount: i64,
+    #[doc = "Invoice payload"]
+    pub invoice_payload: Bytes,
+    #[doc = "Identifier of a shipping option chosen by the user; may be empty if not applicable"]
+    pub shipping_option_id: String,
+    #[serde(default)]
+    #[doc = "Information about the order; may be null"]
+    pub order_info: Option<OrderInfo>,
+}
+#[cfg(feature = "payments")]
+impl TlObject for UpdateNewPreCheckoutQuery {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+#[cfg(feature = "payments")]
+impl TlConstructor for UpdateNewPreCheckoutQuery {
+    const TYPE: &'static str = "updateNewPreCheckoutQuery";
+}
@@ -0,0 +1,208 @@
+//! The types of the `proxy` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A SOCKS5 proxy server"]
+pub struct ProxyTypeSocks5 {
+    #[doc = "Username for logging in; may be empty"]
+    pub username: String,
+    #[doc = "Password for logging in; may be empty"]
+    pub password: String,
+}
+impl TlObject for ProxyTypeSocks5 {
+    const CLASS: &'static str = "ProxyType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for ProxyTypeSocks5 {
+    const TYPE: &'static str = "proxyTypeSocks5";
+}
+impl ProxyTypeSocks5 {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Username for logging in; may be empty"]
+    pub fn with_username(mut self, value: impl Into<String>) -> Self {
+        self.username = value.into();
+        self
+    }
+    #[doc = "Password for logging in; may be empty"]
+    pub fn with_password(mut self, value: impl Into<String>) -> Self {
+        self.password = value.into();
+        self
+    }
+}
+impl Validate for ProxyTypeSocks5 {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A HTTP transparent proxy server"]
+pub struct ProxyTypeHttp {
+    #[doc = "Username for logging in; may be empty"]
+    pub username: String,
+    #[doc = "Password for logging in; may be empty"]
+    pub password: String,
+    #[doc = "Pass true if the proxy supports only HTTP requests and doesn't support transparent TCP connections via HTTP CONNECT method"]
+    pub http_only: bool,
+}
+impl TlObject for ProxyTypeHttp {
+    const CLASS: &'static str = "ProxyType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for ProxyTypeHttp {
+    const TYPE: &'static str = "proxyTypeHttp";
+}
+impl ProxyTypeHttp {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Username for logging in; may be empty"]
+    pub fn with_username(mut self, value: impl Into<String>) -> Self {
+        self.username = value.into();
+        self
+    }
+    #[doc = "Password for logging in; may be empty"]
+    pub fn with_password(mut self, value: impl Into<String>) -> Self {
+        self.password = value.into();
+        self
+    }
+    #[doc = "Pass true if the proxy supports only HTTP requests and doesn't support transparent TCP connections via HTTP CONNECT method"]
+    pub fn with_http_only(mut self, value: impl Into<bool>) -> Self {
+        self.http_only = value.into();
+        self
+    }
+}
+impl Validate for ProxyTypeHttp {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "An MTProto proxy server"]
+pub struct ProxyTypeMtproto {
+    #[doc = "The proxy's secret in hexadecimal encoding"]
+    pub secret: String,
+}
+impl TlObject for ProxyTypeMtproto {
+    const CLASS: &'static str = "ProxyType";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for ProxyTypeMtproto {
+    const TYPE: &'static str = "proxyTypeMtproto";
+}
+impl ProxyTypeMtproto {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "The proxy's secret in hexadecimal encoding"]
+    pub fn with_secret(mut self, value: impl Into<String>) -> Self {
+        self.secret = value.into();
+        self
+    }
+}
+impl Validate for ProxyTypeMtproto {}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes the type of a proxy server"]
+pub enum ProxyType {
+    ProxyTypeSocks5(ProxyTypeSocks5),
+    ProxyTypeHttp(ProxyTypeHttp),
+    ProxyTypeMtproto(ProxyTypeMtproto),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for ProxyType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "proxyTypeSocks5" => Deserialize::deserialize(value).map(ProxyType::ProxyTypeSocks5),
+            "proxyTypeHttp" => Deserialize::deserialize(value).map(ProxyType::ProxyTypeHttp),
+            "proxyTypeMtproto" => Deserialize::deserialize(value).map(ProxyType::ProxyTypeMtproto),
+            _ => Unknown::deserialize(value).map(ProxyType::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for ProxyType {
+    const CLASS: &'static str = "ProxyType";
+    fn type_name(&self) -> &str {
+        match self {
+            ProxyType::ProxyTypeSocks5(_) => "proxyTypeSocks5",
+            ProxyType::ProxyTypeHttp(_) => "proxyTypeHttp",
+            ProxyType::ProxyTypeMtproto(_) => "proxyTypeMtproto",
+            ProxyType::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for ProxyType {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            ProxyType::ProxyTypeSocks5(value) => value.validate(),
+            ProxyType::ProxyTypeHttp(value) => value.validate(),
+            ProxyType::ProxyTypeMtproto(value) => value.validate(),
+            ProxyType::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<ProxyTypeSocks5> for ProxyType {
+    fn from(value: ProxyTypeSocks5) -> Self {
+        ProxyType::ProxyTypeSocks5(value)
+    }
+}
+impl From<ProxyTypeHttp> for ProxyType {
+    fn from(value: ProxyTypeHttp) -> Self {
+        ProxyType::ProxyTypeHttp(value)
+    }
+}
+impl From<ProxyTypeMtproto> for ProxyType {
+    fn from(value: ProxyTypeMtproto) -> Self {
+        ProxyType::ProxyTypeMtproto(value)
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Contains information about a proxy server"]
+pub struct Proxy {
+    #[doc = "Unique identifier of the proxy"]
+    pub id: i32,
+    #[doc = "Proxy server IP address"]
+    pub server: String,
+    #[doc = "Proxy server port"]
+    pub port: i32,
+    #[doc = "Point in time (Unix timestamp) when the proxy was last used; 0 if never"]
+    pub last_used_date: i32,
+    #[doc = "True, if the proxy is enabled now"]
+    pub is_enabled: bool,
+    #[serde(rename = "type")]
+    #[doc = "Type of the proxy"]
+    pub type_: ProxyType,
+}
+impl TlObject for Proxy {
+    const CLASS: &'static str = "Proxy";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for Proxy {
+    const TYPE: &'static str = "proxy";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a list of proxy servers"]
+pub struct Proxies {
+    #[doc = "List of proxy servers"]
+    pub proxies: Vec<Proxy>,
+}
+impl TlObject for Proxies {
+    const CLASS: &'static str = "Proxies";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for Proxies {
+    const TYPE: &'static str = "proxies";
+}
@@ -0,0 +1,366 @@
+//! The types of the `stickers` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a list of stickers"]
+pub struct Stickers {
+    #[doc = "List of stickers"]
+    pub stickers: Vec<Sticker>,
+}
+impl TlObject for Stickers {
+    const CLASS: &'static str = "Stickers";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for Stickers {
+    const TYPE: &'static str = "stickers";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a list of emoji"]
+pub struct Emojis {
+    #[doc = "List of emojis"]
+    pub emojis: Vec<String>,
+}
+impl TlObject for Emojis {
+    const CLASS: &'static str = "Emojis";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for Emojis {
+    const TYPE: &'static str = "emojis";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a sticker set"]
+pub struct StickerSet {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of the sticker set"]
+    pub id: i64,
+    #[doc = "Title of the sticker set"]
+    pub title: String,
+    #[doc = "Name of the sticker set"]
+    pub name: String,
+    #[serde(default)]
+    #[doc = "Sticker set thumbnail in WEBP format with width and height 100; may be null. The file can be downloaded only before the thumbnail is changed"]
+    pub thumbnail: Option<PhotoSize>,
+    #[doc = "True, if the sticker set has been installed by the current user"]
+    pub is_installed: bool,
+    #[doc = "True, if the sticker set has been archived. A sticker set can't be installed and archived simultaneously"]
+    pub is_archived: bool,
+    #[doc = "True, if the sticker set is official"]
+    pub is_official: bool,
+    #[doc = "True, is the stickers in the set are animated"]
+    pub is_animated: bool,
+    #[doc = "True, if the stickers in the set are masks"]
+    pub is_masks: bool,
+    #[doc = "True for already viewed trending sticker sets"]
+    pub is_viewed: bool,
+    #[doc = "List of stickers in this set"]
+    pub stickers: Vec<Sticker>,
+    #[doc = "A list of emoji corresponding to the stickers in the same order. The list is only for informational purposes, because a sticker is always sent with a fixed emoji from the corresponding Sticker object"]
+    pub emojis: Vec<Emojis>,
+}
+impl TlObject for StickerSet {
+    const CLASS: &'static str = "StickerSet";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for StickerSet {
+    const TYPE: &'static str = "stickerSet";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents short information about a sticker set"]
+pub struct StickerSetInfo {
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_t",
+        serialize_with = "crate::utils::t_to_str"
+    )]
+    #[doc = "Identifier of the sticker set"]
+    pub id: i64,
+    #[doc = "Title of the sticker set"]
+    pub title: String,
+    #[doc = "Name of the sticker set"]
+    pub name: String,
+    #[serde(default)]
+    #[doc = "Sticker set thumbnail in WEBP format with width and height 100; may be null"]
+    pub thumbnail: Option<PhotoSize>,
+    #[doc = "True, if the sticker set has been installed by current user"]
+    pub is_installed: bool,
+    #[doc = "True, if the sticker set has been archived. A sticker set can't be installed and archived simultaneously"]
+    pub is_archived: bool,
+    #[doc = "True, if the sticker set is official"]
+    pub is_official: bool,
+    #[doc = "True, is the stickers in the set are animated"]
+    pub is_animated: bool,
+    #[doc = "True, if the stickers in the set are masks"]
+    pub is_masks: bool,
+    #[doc = "True for already viewed trending sticker sets"]
+    pub is_viewed: bool,
+    #[doc = "Total number of stickers in the set"]
+    pub size: i32,
+    #[doc = "Contains up to the first 5 stickers from the set, depending on the context. If the client needs more stickers the full set should be requested"]
+    pub covers: Vec<Sticker>,
+}
+impl TlObject for StickerSetInfo {
+    const CLASS: &'static str = "StickerSetInfo";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for StickerSetInfo {
+    const TYPE: &'static str = "stickerSetInfo";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "Represents a list of sticker sets"]
+pub struct StickerSets {
+    #[doc = "Approximate total number of sticker sets found"]
+    pub total_count: i32,
+    #[doc = "List of sticker sets"]
+    pub sets: Vec<StickerSetInfo>,
+}
+impl TlObject for StickerSets {
+    const CLASS: &'static str = "StickerSets";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for StickerSets {
+    const TYPE: &'static str = "stickerSets";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A static sticker in PNG format, which will be converted to WEBP server-side"]
+pub struct InputStickerStatic {
+    #[doc = "PNG image with the sticker; must be up to 512 KB in size and fit in a 512x512 square"]
+    pub sticker: InputFile,
+    #[doc = "Emojis corresponding to the sticker"]
+    pub emojis: String,
+    #[serde(default)]
+    #[doc = "For masks, position where the mask should be placed; may be null"]
+    pub mask_position: Option<MaskPosition>,
+}
+impl TlObject for InputStickerStatic {
+    const CLASS: &'static str = "InputSticker";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputStickerStatic {
+    const TYPE: &'static str = "inputStickerStatic";
+}
+impl InputStickerStatic {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(sticker: impl Into<InputFile>) -> Self {
+        Self {
+            sticker: sticker.into(),
+            emojis: Default::default(),
+            mask_position: Default::default(),
+        }
+    }
+    #[doc = "Emojis corresponding to the sticker"]
+    pub fn with_emojis(mut self, value: impl Into<String>) -> Self {
+        self.emojis = value.into();
+        self
+    }
+    #[doc = "For masks, position where the mask should be placed; may be null"]
+    pub fn with_mask_position(mut self, value: impl Into<MaskPosition>) -> Self {
+        self.mask_position = Some(value.into());
+        self
+    }
+}
+impl Validate for InputStickerStatic {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.sticker
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "sticker"))?;
+        if let Some(value) = &self.mask_position {
+            value
+                .validate()
+                .map_err(|err| err.within(Self::TYPE, "mask_position"))?;
+        }
+        Ok(())
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "An animated sticker in TGS format"]
+pub struct InputStickerAnimated {
+    #[doc = "File with the animated sticker. Only local or uploaded within a week files are supported. See https://core.telegram.org/animated_stickers#technical-requirements for technical requirements"]
+    pub sticker: InputFile,
+    #[doc = "Emojis corresponding to the sticker"]
+    pub emojis: String,
+}
+impl TlObject for InputStickerAnimated {
+    const CLASS: &'static str = "InputSticker";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for InputStickerAnimated {
+    const TYPE: &'static str = "inputStickerAnimated";
+}
+impl InputStickerAnimated {
+    #[doc = "Returns the object with the given fields, that have no default value, and the others set to their default value"]
+    pub fn new(sticker: impl Into<InputFile>) -> Self {
+        Self {
+            sticker: sticker.into(),
+            emojis: Default::default(),
+        }
+    }
+    #[doc = "Emojis corresponding to the sticker"]
+    pub fn with_emojis(mut self, value: impl Into<String>) -> Self {
+        self.emojis = value.into();
+        self
+    }
+}
+impl Validate for InputStickerAnimated {
+    fn validate(&self) -> Result<(), ValidationError> {
+        self.sticker
+            .validate()
+            .map_err(|err| err.within(Self::TYPE, "sticker"))?;
+        Ok(())
+    }
+}
+#[derive(Serialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "@type")]
+#[doc = "Describes a sticker that needs to be added to a sticker set"]
+pub enum InputSticker {
+    InputStickerStatic(Box<InputStickerStatic>),
+    InputStickerAnimated(InputStickerAnimated),
+    #[serde(untagged)]
+    Unknown(Unknown),
+}
+impl<'de> Deserialize<'de> for InputSticker {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (type_, value) = crate::utils::tagged(deserializer)?;
+        match type_.as_str() {
+            "inputStickerStatic" => {
+                Deserialize::deserialize(value).map(InputSticker::InputStickerStatic)
+            }
+            "inputStickerAnimated" => {
+                Deserialize::deserialize(value).map(InputSticker::InputStickerAnimated)
+            }
+            _ => Unknown::deserialize(value).map(InputSticker::Unknown),
+        }
+        .map_err(serde::de::Error::custom)
+    }
+}
+impl TlObject for InputSticker {
+    const CLASS: &'static str = "InputSticker";
+    fn type_name(&self) -> &str {
+        match self {
+            InputSticker::InputStickerStatic(_) => "inputStickerStatic",
+            InputSticker::InputStickerAnimated(_) => "inputStickerAnimated",
+            InputSticker::Unknown(object) => &object.type_,
+        }
+    }
+}
+impl Validate for InputSticker {
+    fn validate(&self) -> Result<(), ValidationError> {
+        match self {
+            InputSticker::InputStickerStatic(value) => value.validate(),
+            InputSticker::InputStickerAnimated(value) => value.validate(),
+            InputSticker::Unknown(_) => Ok(()),
+        }
+    }
+}
+impl From<InputStickerStatic> for InputSticker {
+    fn from(value: InputStickerStatic) -> Self {
+        InputSticker::InputStickerStatic(Box::new(value))
+    }
+}
+impl From<InputStickerAnimated> for InputSticker {
+    fn from(value: InputStickerAnimated) -> Self {
+        InputSticker::InputStickerAnimated(value)
+    }
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The list of installed sticker sets was updated"]
+pub struct UpdateInstalledStickerSets {
+    #[doc = "True, if the list of installed mask sticker sets was updated"]
+    pub is_masks: bool,
+    #[serde(
+        deserialize_with = "crate::utils::from_str_to_vec_t",
+        serialize_with = "crate::utils::vec_t_to_str"
+    )]
+    #[doc = "The new list of installed ordinary sticker sets"]
+    pub sticker_set_ids: Vec<i64>,
+}
+impl TlObject for UpdateInstalledStickerSets {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for UpdateInstalledStickerSets {
+    const TYPE: &'static str = "updateInstalledStickerSets";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The list of trending sticker sets was updated or some of them were viewed"]
+pub struct UpdateTrendingStickerSets {
+    #[doc = "The new list of trending sticker sets"]
+    pub sticker_sets: StickerSets,
+}
+impl TlObject for UpdateTrendingStickerSets {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for UpdateTrendingStickerSets {
+    const TYPE: &'static str = "updateTrendingStickerSets";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The list of recently used stickers was updated"]
+pub struct UpdateRecentStickers {
+    #[doc = "True, if the list of stickers attached to photo or video files was updated, otherwise the list of sent stickers is updated"]
+    pub is_attached: bool,
+    #[doc = "The new list of file identifiers of recently used stickers"]
+    pub sticker_ids: Vec<FileId>,
+}
+impl TlObject for UpdateRecentStickers {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for UpdateRecentStickers {
+    const TYPE: &'static str = "updateRecentStickers";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The list of favorite stickers was updated"]
+pub struct UpdateFavoriteStickers {
+    #[doc = "The new list of file identifiers of favorite stickers"]
+    pub sticker_ids: Vec<FileId>,
+}
+impl TlObject for UpdateFavoriteStickers {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for UpdateFavoriteStickers {
+    const TYPE: &'static str = "updateFavoriteStickers";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "The list of saved animations was updated"]
+pub struct UpdateSavedAnimations {
+    #[doc = "The new list of file identifiers of saved animations"]
+    pub animation_ids: Vec<FileId>,
+}
+impl TlObject for UpdateSavedAnimations {
+    const CLASS: &'static str = "Update";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for UpdateSavedAnimations {
+    const TYPE: &'static str = "updateSavedAnimations";
+}
@@ -0,0 +1,132 @@
+//! The types of the `tests` feature.
+
+use super::*;
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A simple object containing a number; for testing only"]
+pub struct TestInt {
+    #[doc = "Number"]
+    pub value: i32,
+}
+impl TlObject for TestInt {
+    const CLASS: &'static str = "TestInt";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for TestInt {
+    const TYPE: &'static str = "testInt";
+}
+impl TestInt {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "Number"]
+    pub fn with_value(mut self, value: impl Into<i32>) -> Self {
+        self.value = value.into();
+        self
+    }
+}
+impl Validate for TestInt {}
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[doc = "A simple object containing a string; for testing only"]
+pub struct TestString {
+    #[doc = "String"]
+    pub value: String,
+}
+impl TlObject for TestString {
+    const CLASS: &'static str = "TestString";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for TestString {
+    const TYPE: &'static str = "testString";
+}
+impl TestString {
+    #[doc = "Returns the object with every field set to its default value"]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    #[doc = "String"]
+    pub fn with_value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+}
+impl Validate for TestString {}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A simple object containing a sequence of bytes; for testing only"]
+pub struct TestBytes {
+    #[doc = "Bytes"]
+    pub value: Bytes,
+}
+impl TlObject for TestBytes {
+    const CLASS: &'static str = "TestBytes";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for TestBytes {
+    const TYPE: &'static str = "testBytes";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A simple object containing a vector of numbers; for testing only"]
+pub struct TestVectorInt {
+    #[doc = "Vector of numbers"]
+    pub value: Vec<i32>,
+}
+impl TlObject for TestVectorInt {
+    const CLASS: &'static str = "TestVectorInt";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for TestVectorInt {
+    const TYPE: &'static str = "testVectorInt";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A simple object containing a vector of objects that hold a number; for testing only"]
+pub struct TestVectorIntObject {
+    #[doc = "Vector of objects"]
+    pub value: Vec<TestInt>,
+}
+impl TlObject for TestVectorIntObject {
+    const CLASS: &'static str = "TestVectorIntObject";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for TestVectorIntObject {
+    const TYPE: &'static str = "testVectorIntObject";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A simple object containing a vector of strings; for testing only"]
+pub struct TestVectorString {
+    #[doc = "Vector of strings"]
+    pub value: Vec<String>,
+}
+impl TlObject for TestVectorString {
+    const CLASS: &'static str = "TestVectorString";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for TestVectorString {
+    const TYPE: &'static str = "testVectorString";
+}
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[doc = "A simple object containing a vector of objects that hold a string; for testing only"]
+pub struct TestVectorStringObject {
+    #[doc = "Vector of objects"]
+    pub value: Vec<TestString>,
+}
+impl TlObject for TestVectorStringObject {
+    const CLASS: &'static str = "TestVectorStringObject";
+    fn type_name(&self) -> &str {
+        Self::TYPE
+    }
+}
+impl TlConstructor for TestVectorStringObject {
+    const TYPE: &'static str = "testVectorStringObject";
+}
@@ -0,0 +1,175 @@
+//! Checks of the limits documented on the arguments of methods, so that
+//! requests TDLib would reject can be caught before they are sent.
+//!
+//! The limits are read from `td_api.tl` when the types are generated, e.g.
+//! "1-128 characters", "must be positive and can't be greater than 100" or
+//! "must be non-empty", as well as the limits tied to an offset, as in "the
+//! limit must be greater or equal to -offset". Limits that depend on options,
+//! such as the maximum length of a message, are left to TDLib, and limits on
+//! the total over several lists are checked on each list alone.
+//!
+//! ```
+//! # #[cfg(feature = "messages")] {
+//! use tdlib_types::methods::GetChatHistory;
+//! use tdlib_types::types::ChatId;
+//! use tdlib_types::validate::Validate;
+//!
+//! let request = GetChatHistory::new(ChatId(-100), 500);
+//! let err = request.validate().unwrap_err();
+//! assert_eq!(err.to_string(), "getChatHistory.limit must be 1-100, not 500");
+//! # }
+//! ```
+
+use std::fmt;
+
+/// An object whose fields can be checked against their documented limits.
+///
+/// Implemented by every method and by the types of their arguments; the
+/// fields holding other objects are checked as well.
+pub trait Validate {
+    /// Returns the first field whose value is out of its documented limits.
+    /// The default, kept by objects without any, accepts every value.
+    fn validate(&self) -> Result<(), ValidationError> {
+        Ok(())
+    }
+}
+
+/// A field whose value is out of its documented limits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The @type of the validated object.
+    pub type_: &'static str,
+    /// The path of the field from the validated object, e.g.
+    /// `input_message_content.question` or `options[2]`.
+    pub field: String,
+    pub violation: Violation,
+}
+
+/// What is measured of a field to check it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Measure {
+    /// The value of an integer.
+    Value,
+    /// The number of characters of a string.
+    Characters,
+    /// The length in bytes of a string.
+    Bytes,
+    /// The number of elements of a list.
+    Elements,
+}
+
+/// How a field is out of its limits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// The measure of the field is out of the inclusive bounds; `None` when
+    /// unbounded on that side.
+    OutOfRange {
+        measure: Measure,
+        min: Option<i64>,
+        max: Option<i64>,
+        actual: i64,
+    },
+    /// The value of the field is none of the allowed ones.
+    NotOneOf {
+        allowed: &'static [i64],
+        actual: i64,
+    },
+}
+
+impl ValidationError {
+    /// Makes the error of a field of an object held in the field `field` of
+    /// an object of type `type_` an error of the latter.
+    pub fn within(mut self, type_: &'static str, field: &str) -> Self {
+        self.type_ = type_;
+        self.field = format!("{}.{}", field, self.field);
+        self
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{} ", self.type_, self.field)?;
+        match &self.violation {
+            Violation::OutOfRange {
+                measure,
+                min,
+                max,
+                actual,
+            } => {
+                let (verb, unit) = match measure {
+                    Measure::Value => ("be", ""),
+                    Measure::Characters => ("have", " characters"),
+                    Measure::Bytes => ("have", " bytes"),
+                    Measure::Elements => ("have", " elements"),
+                };
+                match (min, max) {
+                    (Some(min), Some(max)) if *min < 0 => {
+                        write!(f, "must {} between {} and {}{}", verb, min, max, unit)?
+                    }
+                    (Some(min), Some(max)) => write!(f, "must {} {}-{}{}", verb, min, max, unit)?,
+                    (Some(min), None) => write!(f, "must {} at least {}{}", verb, min, unit)?,
+                    (None, Some(max)) => write!(f, "must {} at most {}{}", verb, max, unit)?,
+                    (None, None) => write!(f, "is out of range")?,
+                }
+                write!(f, ", not {}", actual)
+            }
+            Violation::NotOneOf { allowed, actual } => {
+                f.write_str("must be one of ")?;
+                for (i, value) in allowed.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, ", not {}", actual)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Checks that `actual`, the measure of `field` of an object of type
+/// `type_`, is within `min..=max`.
+#[allow(dead_code)]
+pub(crate) fn check(
+    type_: &'static str,
+    field: &str,
+    measure: Measure,
+    actual: i64,
+    min: Option<i64>,
+    max: Option<i64>,
+) -> Result<(), ValidationError> {
+    if min.is_some_and(|min| actual < min) || max.is_some_and(|max| actual > max) {
+        return Err(ValidationError {
+            type_,
+            field: field.to_string(),
+            violation: Violation::OutOfRange {
+                measure,
+                min,
+                max,
+                actual,
+            },
+        });
+    }
+    Ok(())
+}
+
+/// Checks that `actual`, the value of `field` of an object of type `type_`,
+/// is one of `allowed`.
+#[allow(dead_code)]
+pub(crate) fn check_one_of(
+    type_: &'static str,
+    field: &str,
+    actual: i64,
+    allowed: &'static [i64],
+) -> Result<(), ValidationError> {
+    if !allowed.contains(&actual) {
+        return Err(ValidationError {
+            type_,
+            field: field.to_string(),
+            violation: Violation::NotOneOf { allowed, actual },
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,146 @@
+//! The TDLib version the crate was generated for, and a check against the
+//! version of the TDLib actually linked.
+//!
+//! TDLib reports its version through the `version` option, sent in an
+//! `updateOption` right after the client is created and available through
+//! `getOption`.
+
+use crate::types::{OptionValue, UpdateOption};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// The version of TDLib whose `td_api.tl` the types in this crate were
+/// generated from, as selected by the `tdlib-*` feature.
+pub const TDLIB_VERSION: Version = crate::release::VERSION;
+
+/// The name of the option TDLib reports its version in.
+pub const VERSION_OPTION: &str = "version";
+
+/// A TDLib version, as in `1.6.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl Version {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Compares the version of the linked TDLib with [`TDLIB_VERSION`].
+    pub fn compatibility(self) -> Compatibility {
+        match self.cmp(&TDLIB_VERSION) {
+            Ordering::Equal => Compatibility::Exact,
+            Ordering::Greater => Compatibility::Newer(self),
+            Ordering::Less => Compatibility::Older(self),
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for Version {
+    type Err = ParseVersionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParseVersionError(s.to_string());
+        let mut parts = s
+            .trim()
+            .split('.')
+            .map(|part| part.parse().map_err(|_| error()));
+        let major = parts.next().ok_or_else(error)??;
+        let minor = parts.next().ok_or_else(error)??;
+        let patch = parts.next().unwrap_or(Ok(0))?;
+        if parts.next().is_some() {
+            return Err(error());
+        }
+        Ok(Version::new(major, minor, patch))
+    }
+}
+
+/// A `version` that is not of the `major.minor[.patch]` form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVersionError(pub String);
+
+impl fmt::Display for ParseVersionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid TDLib version {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseVersionError {}
+
+/// How the linked TDLib relates to the schema of this crate.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Compatibility {
+    /// The linked TDLib is the version this crate was generated for.
+    Exact,
+    /// The linked TDLib is newer: it may send objects this crate does not
+    /// know, which end up in the `Unknown` variants, or whose fields changed.
+    Newer(Version),
+    /// The linked TDLib is older: some methods and fields of this crate do
+    /// not exist in it.
+    Older(Version),
+    /// The `version` option is not a string of the expected form.
+    Unrecognized(OptionValue),
+}
+
+impl Compatibility {
+    /// Checks the value of the `version` option.
+    pub fn check(value: &OptionValue) -> Self {
+        match value {
+            OptionValue::OptionValueString(version) => match version.value.parse::<Version>() {
+                Ok(version) => version.compatibility(),
+                Err(_) => Compatibility::Unrecognized(value.clone()),
+            },
+            _ => Compatibility::Unrecognized(value.clone()),
+        }
+    }
+
+    /// Checks an `updateOption`, if it is the one carrying the `version`.
+    pub fn from_update(update: &UpdateOption) -> Option<Self> {
+        if update.name == VERSION_OPTION {
+            Some(Self::check(&update.value))
+        } else {
+            None
+        }
+    }
+
+    /// Whether the linked TDLib is exactly the version this crate was
+    /// generated for.
+    pub fn is_exact(&self) -> bool {
+        matches!(self, Compatibility::Exact)
+    }
+}
+
+impl fmt::Display for Compatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Compatibility::Exact => write!(f, "TDLib {} matches the schema", TDLIB_VERSION),
+            Compatibility::Newer(version) => write!(
+                f,
+                "TDLib {} is newer than the schema of TDLib {}: new objects will be decoded as unknown and changed ones may fail to decode",
+                version, TDLIB_VERSION
+            ),
+            Compatibility::Older(version) => write!(
+                f,
+                "TDLib {} is older than the schema of TDLib {}: some methods and fields are not supported",
+                version, TDLIB_VERSION
+            ),
+            Compatibility::Unrecognized(value) => {
+                write!(f, "unrecognized TDLib version {:?}", value)
+            }
+        }
+    }
+}
@@ -0,0 +1,364 @@
+#![cfg(feature = "auth")]
+
+use serde_json::{json, Value};
+use tdlib_types::authorization::{
+    self, Authenticator, AuthorizationFlow, AuthorizationRequest, Step, WrongState,
+};
+use tdlib_types::methods::*;
+use tdlib_types::types::*;
+
+/// The update announcing the state with the given @type.
+fn update(state: &str) -> UpdateAuthorizationState {
+    update_to(json!({ "@type": state }))
+}
+
+fn update_to(state: Value) -> UpdateAuthorizationState {
+    serde_json::from_value(json!({
+        "@type": "updateAuthorizationState",
+        "authorization_state": state,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn most_methods_need_authorization() {
+    assert_eq!(
+        GetMe::AUTHORIZATION_STATES,
+        Some(&["authorizationStateReady"][..])
+    );
+    let ready = update("authorizationStateReady");
+    assert_eq!(authorization::check(&GetMe::new(), &ready), Ok(()));
+
+    let waiting = update("authorizationStateWaitPhoneNumber");
+    assert_eq!(
+        authorization::check(&GetMe::new(), &waiting),
+        Err(WrongState {
+            method: "getMe",
+            state: "authorizationStateWaitPhoneNumber".to_string(),
+            expected: &["authorizationStateReady"],
+        })
+    );
+}
+
+#[test]
+fn authentication_methods_work_in_their_state() {
+    let request = CheckAuthenticationCode::new("12345");
+    let err = authorization::check(&request, &update("authorizationStateReady")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "checkAuthenticationCode doesn't work in authorizationStateReady, only in authorizationStateWaitCode"
+    );
+    assert!(CheckAuthenticationCode::works_in(
+        "authorizationStateWaitCode"
+    ));
+    assert!(RegisterUser::works_in("authorizationStateWaitRegistration"));
+    assert!(SetTdlibParameters::works_in(
+        "authorizationStateWaitTdlibParameters"
+    ));
+    assert!(!SetTdlibParameters::works_in("authorizationStateReady"));
+
+    // Also while another authentication step is pending.
+    for state in &[
+        "authorizationStateWaitPhoneNumber",
+        "authorizationStateWaitCode",
+        "authorizationStateWaitRegistration",
+        "authorizationStateWaitPassword",
+    ] {
+        assert!(SetAuthenticationPhoneNumber::works_in(state));
+    }
+    assert!(!SetAuthenticationPhoneNumber::works_in(
+        "authorizationStateReady"
+    ));
+}
+
+#[test]
+fn methods_before_authorization_work_in_every_state() {
+    assert_eq!(GetOption::AUTHORIZATION_STATES, None);
+    assert_eq!(Close::AUTHORIZATION_STATES, None);
+    assert_eq!(GetAuthorizationState::AUTHORIZATION_STATES, None);
+    let waiting = update("authorizationStateWaitTdlibParameters");
+    assert_eq!(
+        authorization::check(&GetOption::new().with_name("version"), &waiting),
+        Ok(())
+    );
+
+    // Before authorization only while waiting for the password.
+    assert!(DeleteAccount::works_in("authorizationStateReady"));
+    assert!(DeleteAccount::works_in("authorizationStateWaitPassword"));
+    assert!(!DeleteAccount::works_in("authorizationStateWaitCode"));
+}
+
+#[test]
+fn unknown_states() {
+    let unknown = update("authorizationStateSomethingNew");
+    assert!(matches!(
+        unknown.authorization_state,
+        AuthorizationState::Unknown(_)
+    ));
+    assert_eq!(authorization::check(&Close::new(), &unknown), Ok(()));
+    assert_eq!(authorization::check(&Destroy::new(), &unknown), Ok(()));
+    assert_eq!(
+        authorization::check(&GetMe::new(), &unknown)
+            .unwrap_err()
+            .state,
+        "authorizationStateSomethingNew"
+    );
+}
+
+/// Answers with fixed values, and records what it was asked.
+#[derive(Default)]
+struct User {
+    encryption_key: Vec<u8>,
+    bot_token: Option<String>,
+    accepts_terms: bool,
+    asked: Vec<String>,
+}
+
+impl Authenticator for User {
+    fn tdlib_parameters(&mut self) -> TdlibParameters {
+        TdlibParameters::new(
+            94575,
+            "0123456789abcdef0123456789abcdef",
+            "en",
+            "Desktop",
+            "Linux",
+            "1.0",
+        )
+        .with_database_directory("tdlib")
+    }
+
+    fn encryption_key(&mut self, is_encrypted: bool) -> Bytes {
+        self.asked.push(format!("key, encrypted {}", is_encrypted));
+        self.encryption_key.clone().into()
+    }
+
+    fn bot_token(&mut self) -> Option<String> {
+        self.bot_token.clone()
+    }
+
+    fn phone_number(&mut self) -> String {
+        "+391234567890".to_string()
+    }
+
+    fn code(&mut self, info: &AuthenticationCodeInfo) -> String {
+        self.asked.push(format!("code for {}", info.phone_number));
+        "12345".to_string()
+    }
+
+    fn password(&mut self, state: &AuthorizationStateWaitPassword) -> String {
+        self.asked
+            .push(format!("password, hint {}", state.password_hint));
+        "hunter2".to_string()
+    }
+
+    fn accept_terms_of_service(&mut self, terms_of_service: &TermsOfService) -> bool {
+        self.asked
+            .push(format!("terms, {}", terms_of_service.text.text));
+        self.accepts_terms
+    }
+
+    fn name(&mut self) -> (String, String) {
+        ("Ada".to_string(), "Lovelace".to_string())
+    }
+}
+
+fn sent(step: Step) -> Value {
+    match step {
+        Step::Send(request) => serde_json::to_value(request.payload).unwrap(),
+        step => panic!("expected a request, got {:?}", step),
+    }
+}
+
+#[test]
+fn user_login() {
+    let mut flow = AuthorizationFlow::new(User::default());
+    assert!(matches!(flow.retry(), Step::Wait));
+
+    let request = sent(flow.next(&update("authorizationStateWaitTdlibParameters")));
+    assert_eq!(request["@type"], "setTdlibParameters");
+    assert_eq!(request["parameters"]["api_id"], 94575);
+
+    let request = sent(flow.next(&update_to(json!({
+        "@type": "authorizationStateWaitEncryptionKey",
+        "is_encrypted": false,
+    }))));
+    assert_eq!(
+        request,
+        json!({ "@type": "checkDatabaseEncryptionKey", "encryption_key": "" })
+    );
+
+    let request = sent(flow.next(&update("authorizationStateWaitPhoneNumber")));
+    assert_eq!(request["@type"], "setAuthenticationPhoneNumber");
+    assert_eq!(request["phone_number"], "+391234567890");
+
+    let wait_code = update_to(json!({
+        "@type": "authorizationStateWaitCode",
+        "code_info": {
+            "phone_number": "+391234567890",
+            "type": { "@type": "authenticationCodeTypeSms", "length": 5 },
+            "next_type": null,
+            "timeout": 60,
+        },
+    }));
+    assert_eq!(
+        sent(flow.next(&wait_code)),
+        json!({ "@type": "checkAuthenticationCode", "code": "12345" })
+    );
+    // A wrong code leaves the state as it is.
+    assert_eq!(sent(flow.retry())["@type"], "checkAuthenticationCode");
+
+    let request = sent(flow.next(&update_to(json!({
+        "@type": "authorizationStateWaitPassword",
+        "password_hint": "the usual",
+        "has_recovery_email_address": false,
+        "recovery_email_address_pattern": "",
+    }))));
+    assert_eq!(
+        request,
+        json!({ "@type": "checkAuthenticationPassword", "password": "hunter2" })
+    );
+
+    assert!(matches!(
+        flow.next(&update("authorizationStateReady")),
+        Step::Ready
+    ));
+    assert_eq!(
+        flow.authenticator().asked,
+        [
+            "key, encrypted false",
+            "code for +391234567890",
+            "code for +391234567890",
+            "password, hint the usual"
+        ]
+    );
+}
+
+#[test]
+fn bot_login() {
+    let mut flow = AuthorizationFlow::new(User {
+        bot_token: Some("123:abc".to_string()),
+        ..User::default()
+    });
+    assert_eq!(
+        sent(flow.next(&update("authorizationStateWaitPhoneNumber"))),
+        json!({ "@type": "checkAuthenticationBotToken", "token": "123:abc" })
+    );
+}
+
+#[test]
+fn encrypted_database() {
+    let mut flow = AuthorizationFlow::new(User {
+        encryption_key: b"secret".to_vec(),
+        ..User::default()
+    });
+    let request = sent(flow.next(&update_to(json!({
+        "@type": "authorizationStateWaitEncryptionKey",
+        "is_encrypted": true,
+    }))));
+    assert_eq!(
+        request,
+        json!({ "@type": "checkDatabaseEncryptionKey", "encryption_key": "c2VjcmV0" })
+    );
+    assert_eq!(flow.authenticator().asked, ["key, encrypted true"]);
+}
+
+fn wait_registration() -> UpdateAuthorizationState {
+    update_to(json!({
+        "@type": "authorizationStateWaitRegistration",
+        "terms_of_service": {
+            "text": { "text": "Be nice", "entities": [] },
+            "min_user_age": 0,
+            "show_popup": true,
+        },
+    }))
+}
+
+#[test]
+fn registration() {
+    let mut flow = AuthorizationFlow::new(User {
+        accepts_terms: true,
+        ..User::default()
+    });
+    assert_eq!(
+        sent(flow.next(&wait_registration())),
+        json!({ "@type": "registerUser", "first_name": "Ada", "last_name": "Lovelace" })
+    );
+
+    let mut flow = AuthorizationFlow::new(User::default());
+    match flow.next(&wait_registration()) {
+        Step::Send(Envelope {
+            payload: AuthorizationRequest::LogOut(_),
+            ..
+        }) => {}
+        step => panic!("expected logOut, got {:?}", step),
+    }
+    assert_eq!(flow.authenticator().asked, ["terms, Be nice"]);
+}
+
+#[test]
+fn states_without_requests() {
+    let mut flow = AuthorizationFlow::new(User::default());
+    for state in &["authorizationStateLoggingOut", "authorizationStateClosing"] {
+        assert!(matches!(flow.next(&update(state)), Step::Wait));
+    }
+    assert!(matches!(
+        flow.next(&update("authorizationStateClosed")),
+        Step::Closed
+    ));
+    assert!(matches!(
+        flow.next(&update("authorizationStateFuture")),
+        Step::Wait
+    ));
+}
+
+fn answer(step: &Step, json: Value) -> Envelope<Response> {
+    let extra = match step {
+        Step::Send(request) => request.extra.clone(),
+        step => panic!("expected a request, got {:?}", step),
+    };
+    Envelope {
+        extra,
+        client_id: None,
+        payload: serde_json::from_value(json).unwrap(),
+    }
+}
+
+#[test]
+fn replies() {
+    let mut flow = AuthorizationFlow::new(User::default());
+    let wait_password = update_to(json!({
+        "@type": "authorizationStateWaitPassword",
+        "password_hint": "",
+        "has_recovery_email_address": false,
+        "recovery_email_address_pattern": "",
+    }));
+    let first = flow.next(&wait_password);
+    if let Step::Send(request) = &first {
+        let json = serde_json::to_value(request).unwrap();
+        assert_eq!(json["@type"], "checkAuthenticationPassword");
+        assert_eq!(Some(&json["@extra"]), request.extra.as_ref());
+    }
+    let wrong_password =
+        json!({ "@type": "error", "code": 400, "message": "PASSWORD_HASH_INVALID" });
+    let error = answer(&first, wrong_password.clone());
+
+    // Each request has its own @extra, and only the answers to the last one
+    // are the flow's.
+    let second = flow.reply(&error).unwrap();
+    assert_ne!(answer(&second, json!({ "@type": "ok" })).extra, error.extra);
+    assert!(flow.reply(&error).is_none());
+    assert_eq!(
+        sent(flow.reply(&answer(&second, wrong_password)).unwrap())["@type"],
+        "checkAuthenticationPassword"
+    );
+    let third = flow.retry();
+    assert!(matches!(
+        flow.reply(&answer(&third, json!({ "@type": "ok" }))),
+        Some(Step::Wait)
+    ));
+
+    // Nor are answers without @extra.
+    let ok: Envelope<Response> = serde_json::from_value(json!({ "@type": "ok" })).unwrap();
+    assert!(flow.reply(&ok).is_none());
+    assert_eq!(flow.authenticator().asked.len(), 4);
+}
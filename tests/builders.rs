@@ -0,0 +1,113 @@
+#![cfg(all(feature = "auth", feature = "messages"))]
+
+use serde_json::json;
+use tdlib_types::methods::*;
+use tdlib_types::types::*;
+use tdlib_types::validate::Validate;
+
+#[test]
+fn methods_without_required_fields_are_default() {
+    let search = SearchMessages::new().with_query("cats").with_limit(50);
+    assert_eq!(
+        serde_json::to_value(&search).unwrap(),
+        json!({
+            "chat_list": null,
+            "query": "cats",
+            "offset_date": 0,
+            "offset_chat_id": 0,
+            "offset_message_id": 0,
+            "limit": 50,
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(SearchMessages::default()).unwrap(),
+        serde_json::to_value(SearchMessages::new()).unwrap()
+    );
+}
+
+#[test]
+fn required_fields_are_passed_to_new() {
+    // "must be positive and can't be greater than 100".
+    let history = GetChatHistory::new(ChatId(-100), 10).with_from_message_id(MessageId(42));
+    assert_eq!(history.chat_id, ChatId(-100));
+    assert_eq!(history.from_message_id, MessageId(42));
+    assert_eq!(history.offset, 0);
+    assert_eq!(history.limit, 10);
+    assert!(!history.only_local);
+}
+
+#[test]
+fn variants_convert_into_their_enum() {
+    let text = FormattedText::new().with_text("hello");
+    let send = SendMessage::new(ChatId(1), InputMessageText::new().with_text(text))
+        .with_reply_to_message_id(MessageId(7));
+    assert!(send.reply_markup.is_none());
+    assert_eq!(send.reply_to_message_id, MessageId(7));
+    match &send.input_message_content {
+        InputMessageContent::InputMessageText(content) => {
+            assert_eq!(content.text.text, "hello");
+            assert!(!content.clear_draft);
+        }
+        content => panic!("unexpected content {:?}", content),
+    }
+    // Sent immediately, unless scheduled.
+    assert_eq!(send.options, SendMessageOptions::default());
+    assert_eq!(
+        serde_json::to_value(&send.options).unwrap(),
+        json!({
+            "disable_notification": false,
+            "from_background": false,
+            "scheduling_state": null,
+        })
+    );
+    let scheduled = SendMessageOptions::new()
+        .with_scheduling_state(MessageSchedulingStateSendAtDate::new().with_send_date(1600000000));
+    assert!(matches!(
+        scheduled.scheduling_state,
+        Some(MessageSchedulingState::MessageSchedulingStateSendAtDate(_))
+    ));
+}
+
+#[test]
+fn input_types_are_default() {
+    let settings = PhoneNumberAuthenticationSettings::new()
+        .with_allow_flash_call(true)
+        .with_is_current_phone_number(true);
+    assert!(settings.allow_flash_call);
+    assert!(!settings.allow_sms_retriever_api);
+    assert_eq!(
+        settings,
+        PhoneNumberAuthenticationSettings {
+            allow_flash_call: true,
+            is_current_phone_number: true,
+            ..PhoneNumberAuthenticationSettings::default()
+        }
+    );
+}
+
+#[test]
+fn fields_whose_limits_reject_the_default_are_required() {
+    // The credentials of the application, and "must be non-empty".
+    let parameters = TdlibParameters::new(
+        94575,
+        "0123456789abcdef0123456789abcdef",
+        "en",
+        "Desktop",
+        "Linux",
+        "1.0",
+    )
+    .with_use_message_database(true);
+    assert_eq!(parameters.api_id, 94575);
+    assert_eq!(parameters.system_language_code, "en");
+    assert_eq!(parameters.device_model, "Desktop");
+    assert_eq!(parameters.system_version, "Linux");
+    assert_eq!(parameters.application_version, "1.0");
+    assert!(!parameters.use_test_dc);
+    let request = SetTdlibParameters::new(parameters);
+    assert_eq!(request.validate(), Ok(()));
+    // "1-64 characters".
+    assert_eq!(RegisterUser::new("Ada").first_name, "Ada");
+    // Without them the request would be pointless.
+    assert_eq!(CheckAuthenticationCode::new("12345").code, "12345");
+    assert_eq!(CheckAuthenticationBotToken::new("123:abc").token, "123:abc");
+}
@@ -0,0 +1,98 @@
+use tdlib_types::types::Bytes;
+
+fn encode(bytes: &[u8]) -> String {
+    serde_json::to_string(&Bytes::from(bytes)).unwrap()
+}
+
+fn decode(json: &str) -> Result<Bytes, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+#[test]
+fn empty() {
+    assert_eq!(encode(b""), r#""""#);
+    assert_eq!(decode(r#""""#).unwrap(), Bytes::new());
+    assert!(Bytes::default().is_empty());
+}
+
+#[test]
+fn padding() {
+    // Every length modulo 3, so with two, one and no padding characters.
+    let cases: &[(&[u8], &str)] = &[
+        (b"\x01", "AQ=="),
+        (b"\x01\x02", "AQI="),
+        (b"\x01\x02\x03", "AQID"),
+        (b"\xff\xfe\xfd\xfc", "//79/A=="),
+    ];
+    for (bytes, base64) in cases {
+        let json = format!("\"{}\"", base64);
+        assert_eq!(encode(bytes), json);
+        assert_eq!(decode(&json).unwrap().as_ref(), *bytes);
+    }
+}
+
+#[test]
+fn round_trip() {
+    let bytes: Bytes = (0..=255).collect::<Vec<u8>>().into();
+    let json = serde_json::to_string(&bytes).unwrap();
+    assert_eq!(decode(&json).unwrap(), bytes);
+    assert_eq!(Vec::from(bytes.clone()), bytes.into_vec());
+}
+
+#[test]
+fn missing_padding_is_accepted() {
+    assert_eq!(decode(r#""AQ""#).unwrap().as_ref(), b"\x01");
+    assert_eq!(decode(r#""AQ=""#).unwrap().as_ref(), b"\x01");
+    assert_eq!(decode(r#""aGVsbG8""#).unwrap().as_ref(), b"hello");
+}
+
+#[test]
+fn invalid_base64() {
+    for json in &[
+        // A single character can't encode a byte.
+        r#""A""#,
+        r#""AQ===""#,
+        r#""!!!!""#,
+        // Padding in the middle.
+        r#""AQ==AQ==""#,
+        // The URL-safe alphabet.
+        r#""AQ-_""#,
+        // Bits set after the last byte.
+        r#""AR==""#,
+    ] {
+        assert!(decode(json).is_err(), "{} decoded", json);
+    }
+    assert!(decode("[1, 2]").is_err());
+    assert!(decode("null").is_err());
+}
+
+#[test]
+#[cfg(feature = "files")]
+fn bytes_fields() {
+    use serde_json::json;
+    use tdlib_types::methods::WriteGeneratedFilePart;
+    use tdlib_types::types::FilePart;
+
+    let part: FilePart = serde_json::from_value(json!({ "data": "aGVsbG8=" })).unwrap();
+    assert_eq!(part.data.as_ref(), b"hello");
+    let request = WriteGeneratedFilePart::new().with_data(&b"hello"[..]);
+    assert_eq!(serde_json::to_value(&request).unwrap()["data"], "aGVsbG8=");
+    assert!(serde_json::from_value::<FilePart>(json!({ "data": "hello" })).is_err());
+}
+
+#[test]
+#[cfg(feature = "auth")]
+fn encryption_keys() {
+    use tdlib_types::methods::{CheckDatabaseEncryptionKey, SetDatabaseEncryptionKey};
+
+    let key = vec![0u8, 159, 146, 150];
+    let request = CheckDatabaseEncryptionKey::new().with_encryption_key(key.clone());
+    assert_eq!(
+        serde_json::to_string(&request).unwrap(),
+        r#"{"encryption_key":"AJ+Slg=="}"#
+    );
+    let request = SetDatabaseEncryptionKey::new().with_new_encryption_key(key);
+    let json = serde_json::to_string(&request).unwrap();
+    let back: SetDatabaseEncryptionKey = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.new_encryption_key.as_ref(), [0, 159, 146, 150]);
+}
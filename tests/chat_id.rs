@@ -0,0 +1,81 @@
+use tdlib_types::types::*;
+
+#[test]
+fn private_chats() {
+    assert_eq!(ChatId(1).peer(), Some(Peer::Private(UserId(1))));
+    assert_eq!(
+        ChatId(2147483647).peer(),
+        Some(Peer::Private(UserId(2147483647)))
+    );
+    assert_eq!(ChatId::private(UserId(777000)), ChatId(777000));
+    assert!(ChatId(777000).is_private());
+}
+
+#[test]
+fn basic_groups() {
+    assert_eq!(ChatId(-1).peer(), Some(Peer::BasicGroup(BasicGroupId(1))));
+    assert_eq!(
+        ChatId(-123456789).peer(),
+        Some(Peer::BasicGroup(BasicGroupId(123456789)))
+    );
+    assert_eq!(ChatId::basic_group(BasicGroupId(42)), ChatId(-42));
+    assert!(ChatId(-42).is_basic_group());
+}
+
+#[test]
+fn supergroups() {
+    assert_eq!(
+        ChatId(-1001234567890).peer(),
+        Some(Peer::Supergroup(SupergroupId(1234567890)))
+    );
+    assert_eq!(
+        ChatId(-1000000000001).peer(),
+        Some(Peer::Supergroup(SupergroupId(1)))
+    );
+    assert_eq!(
+        ChatId::supergroup(SupergroupId(1234567890)),
+        ChatId(-1001234567890)
+    );
+    assert!(ChatId(-1001234567890).is_supergroup());
+}
+
+#[test]
+fn secret_chats() {
+    assert_eq!(
+        ChatId(-1999999999999).peer(),
+        Some(Peer::Secret(SecretChatId(1)))
+    );
+    assert_eq!(
+        ChatId(-2000000000001).peer(),
+        Some(Peer::Secret(SecretChatId(-1)))
+    );
+    assert_eq!(ChatId::secret(SecretChatId(5)), ChatId(-1999999999995));
+    assert!(ChatId(-1999999999995).is_secret());
+}
+
+#[test]
+fn invalid_ids() {
+    for id in &[
+        0,
+        2147483648,
+        -1000000000000,
+        -2000000000000,
+        -4000000000000,
+        i64::MIN,
+        i64::MAX,
+    ] {
+        assert_eq!(ChatId(*id).peer(), None, "{}", id);
+    }
+}
+
+#[test]
+fn round_trip() {
+    for peer in &[
+        Peer::Private(UserId(93372553)),
+        Peer::BasicGroup(BasicGroupId(371212)),
+        Peer::Supergroup(SupergroupId(1101170442)),
+        Peer::Secret(SecretChatId(-1234)),
+    ] {
+        assert_eq!(ChatId::from(*peer).peer(), Some(*peer));
+    }
+}
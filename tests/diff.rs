@@ -0,0 +1,321 @@
+use serde_json::json;
+use tdlib_types::schema::diff::{Change, Diff, Nullability};
+use tdlib_types::schema::Schema;
+
+const OLD: &str = "
+//@description An object of this type is returned on a successful function call for certain functions
+ok = Ok;
+
+//@class ChatType @description Describes the type of a chat
+
+//@description An ordinary chat with a user @user_id User identifier
+chatTypePrivate user_id:int32 = ChatType;
+
+//@description A secret chat with a user @secret_chat_id Secret chat identifier @user_id User identifier of the secret chat peer
+chatTypeSecret secret_chat_id:int32 user_id:int32 = ChatType;
+
+//@description A chat @id Chat unique identifier @title Chat title @photo Chat photo; may be null @type Type of the chat
+chat id:int53 title:string photo:string type:ChatType = Chat;
+
+//@description Represents a list of chats @chat_ids List of chat identifiers
+chats chat_ids:vector<int53> = Chats;
+
+//@description Represents a part of the text that needs to be formatted @offset Offset of the entity in UTF-16 code units
+textEntity offset:int32 = TextEntity;
+
+//@description Describes a sticker @emoji Emoji corresponding to the sticker
+sticker emoji:string = Sticker;
+
+---functions---
+
+//@description Returns information about a chat @chat_id Chat identifier
+getChat chat_id:int53 = Chat;
+
+//@description Returns an ordered list of chats @limit The maximum number of chats to be returned
+getChats limit:int32 = Chats;
+
+//@description Returns a sticker @emoji String representation of emoji
+getSticker emoji:string = Sticker;
+
+//@description Closes the TDLib instance
+close = Ok;
+";
+
+const NEW: &str = "
+//@description An object of this type is returned on a successful function call for certain functions
+ok = Ok;
+
+//@class ChatType @description Describes the type of a chat
+
+//@description An ordinary chat with a user @user_id User identifier
+chatTypePrivate user_id:int32 = ChatType;
+
+//@description A basic group @basic_group_id Basic group identifier
+chatTypeBasicGroup basic_group_id:int32 = ChatType;
+
+//@description A chat @id Chat unique identifier @name Chat name @photo Chat photo @type Type of the chat
+//-@permissions Permissions of the chat members; permissions may be null
+chat id:int64 name:string photo:string type:ChatType permissions:vector<string> = Chat;
+
+//@description Represents a list of chats @chat_ids List of chat identifiers @total_count Approximate total count of chats
+chats chat_ids:vector<int53> total_count:int32 = Chats;
+
+//@description Represents a part of the text that needs to be formatted @offset Offset of the entity in UTF-16 code units
+textEntity offset:int32 = Entity;
+
+//@description Represents a file @id Unique file identifier
+file id:int32 = File;
+
+---functions---
+
+//@description Returns information about a chat @chat_id Chat identifier
+getChat chat_id:int53 = Chat;
+
+//@description Returns an ordered list of chats @limit The maximum number of chats to be returned
+//-@offset_order Chat order to return chats from
+getChats limit:int32 offset_order:int64 = Chats;
+
+//@description Returns information about a file @file_id Identifier of the file
+getFile file_id:int32 = File;
+
+//@description Closes the TDLib instance
+close = File;
+";
+
+fn diff(old: &str, new: &str) -> Diff {
+    Diff::new(&Schema::parse(old).unwrap(), &Schema::parse(new).unwrap())
+}
+
+fn s(s: &str) -> String {
+    s.to_string()
+}
+
+#[test]
+fn identical_schemas() {
+    for source in &[OLD, NEW] {
+        let diff = diff(source, source);
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+        assert_eq!(
+            serde_json::to_value(&diff).unwrap(),
+            json!({ "changes": [] })
+        );
+    }
+}
+
+#[test]
+fn changes() {
+    let diff = diff(OLD, NEW);
+    assert!(!diff.is_empty());
+    assert_eq!(
+        diff.changes,
+        [
+            Change::ClassRemoved {
+                class: s("TextEntity")
+            },
+            Change::ClassRemoved {
+                class: s("Sticker")
+            },
+            Change::ClassAdded { class: s("Entity") },
+            Change::ClassAdded { class: s("File") },
+            // Constructors, in the order of the old schema.
+            Change::ConstructorRemoved {
+                constructor: s("chatTypeSecret"),
+                class: s("ChatType"),
+            },
+            Change::FieldTypeChanged {
+                definition: s("chat"),
+                field: s("id"),
+                old: s("int53"),
+                new: s("int64"),
+            },
+            // A renamed field is removed and added.
+            Change::FieldRemoved {
+                definition: s("chat"),
+                field: s("title"),
+                type_: s("string"),
+            },
+            Change::NullabilityChanged {
+                definition: s("chat"),
+                field: s("photo"),
+                old: Nullability::Nullable,
+                new: Nullability::NotNull,
+            },
+            Change::FieldAdded {
+                definition: s("chat"),
+                field: s("name"),
+                type_: s("string"),
+                nullability: Nullability::NotNull,
+            },
+            Change::FieldAdded {
+                definition: s("chat"),
+                field: s("permissions"),
+                type_: s("vector<string>"),
+                nullability: Nullability::NullableElements,
+            },
+            Change::FieldAdded {
+                definition: s("chats"),
+                field: s("total_count"),
+                type_: s("int32"),
+                nullability: Nullability::NotNull,
+            },
+            Change::ClassChanged {
+                constructor: s("textEntity"),
+                old: s("TextEntity"),
+                new: s("Entity"),
+            },
+            Change::ConstructorRemoved {
+                constructor: s("sticker"),
+                class: s("Sticker"),
+            },
+            // Then the new ones, in their order.
+            Change::ConstructorAdded {
+                constructor: s("chatTypeBasicGroup"),
+                class: s("ChatType"),
+            },
+            Change::ConstructorAdded {
+                constructor: s("file"),
+                class: s("File"),
+            },
+            // Functions.
+            Change::FieldAdded {
+                definition: s("getChats"),
+                field: s("offset_order"),
+                type_: s("int64"),
+                nullability: Nullability::NotNull,
+            },
+            Change::FunctionRemoved {
+                function: s("getSticker"),
+                result: s("Sticker"),
+            },
+            Change::ResultChanged {
+                function: s("close"),
+                old: s("Ok"),
+                new: s("File"),
+            },
+            Change::FunctionAdded {
+                function: s("getFile"),
+                result: s("File"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn changes_the_other_way() {
+    let diff = diff(NEW, OLD);
+    for change in &[
+        Change::ConstructorAdded {
+            constructor: s("chatTypeSecret"),
+            class: s("ChatType"),
+        },
+        Change::ConstructorRemoved {
+            constructor: s("file"),
+            class: s("File"),
+        },
+        Change::FieldRemoved {
+            definition: s("chat"),
+            field: s("permissions"),
+            type_: s("vector<string>"),
+        },
+        Change::ClassChanged {
+            constructor: s("textEntity"),
+            old: s("Entity"),
+            new: s("TextEntity"),
+        },
+    ] {
+        assert!(diff.changes.contains(change), "{}", change);
+    }
+    assert!(diff.changes.contains(&Change::NullabilityChanged {
+        definition: s("chat"),
+        field: s("photo"),
+        old: Nullability::NotNull,
+        new: Nullability::Nullable,
+    }));
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        diff(OLD, NEW).to_string(),
+        "\
+- class TextEntity
+- class Sticker
++ class Entity
++ class File
+- constructor chatTypeSecret = ChatType
+~ field chat.id:int64 (was int53)
+- field chat.title:string
+~ field chat.photo is not null (was nullable)
++ field chat.name:string (not null)
++ field chat.permissions:vector<string> (nullable elements)
++ field chats.total_count:int32 (not null)
+~ constructor textEntity = Entity (was TextEntity)
+- constructor sticker = Sticker
++ constructor chatTypeBasicGroup = ChatType
++ constructor file = File
++ field getChats.offset_order:int64 (not null)
+- function getSticker = Sticker
+~ function close = File (was Ok)
++ function getFile = File
+"
+    );
+}
+
+#[test]
+fn json() {
+    let json = serde_json::to_value(diff(OLD, NEW)).unwrap();
+    let changes = json["changes"].as_array().unwrap();
+    let kinds: Vec<_> = changes
+        .iter()
+        .map(|change| change["kind"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            "class_removed",
+            "class_removed",
+            "class_added",
+            "class_added",
+            "constructor_removed",
+            "field_type_changed",
+            "field_removed",
+            "nullability_changed",
+            "field_added",
+            "field_added",
+            "field_added",
+            "class_changed",
+            "constructor_removed",
+            "constructor_added",
+            "constructor_added",
+            "field_added",
+            "function_removed",
+            "result_changed",
+            "function_added",
+        ]
+    );
+    assert_eq!(
+        changes[7],
+        json!({
+            "kind": "nullability_changed",
+            "definition": "chat",
+            "field": "photo",
+            "old": "nullable",
+            "new": "not_null",
+        })
+    );
+    assert_eq!(
+        changes[9],
+        json!({
+            "kind": "field_added",
+            "definition": "chat",
+            "field": "permissions",
+            "type": "vector<string>",
+            "nullability": "nullable_elements",
+        })
+    );
+    assert_eq!(
+        changes[17],
+        json!({ "kind": "result_changed", "function": "close", "old": "Ok", "new": "File" })
+    );
+}
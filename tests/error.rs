@@ -0,0 +1,203 @@
+use std::error::Error as _;
+use std::time::Duration;
+use tdlib_types::error::{ErrorKind, TdError, KNOWN_MESSAGES};
+use tdlib_types::methods::*;
+use tdlib_types::types::*;
+
+#[test]
+fn results_decode() {
+    let value =
+        GetOption::decode_response(r#"{"@type":"optionValueString","value":"1.6.0"}"#).unwrap();
+    assert_eq!(
+        value,
+        OptionValue::OptionValueString(OptionValueString {
+            value: "1.6.0".to_string()
+        })
+    );
+    let ok = SetOption::decode_response(r#"{"@type":"ok","@extra":7}"#).unwrap();
+    assert_eq!(ok, Ok {});
+}
+
+#[test]
+fn td_errors() {
+    let err = GetMe::decode_response(r#"{"@type":"error","code":401,"message":"Unauthorized"}"#)
+        .unwrap_err();
+    match &err {
+        TdError::Td(error) => {
+            assert_eq!(error.code, 401);
+            assert_eq!(error.message, "Unauthorized");
+        }
+        err => panic!("expected a TDLib error, got {:?}", err),
+    }
+    assert_eq!(err.to_string(), "TDLib error 401: Unauthorized");
+    assert!(err.source().is_none());
+}
+
+#[test]
+fn json_errors() {
+    for json in &["", "{", r#"{"@type":"ok""#, r#"{"code":400}"#, "[]", "null"] {
+        match GetMe::decode_response(json) {
+            Err(err @ TdError::Json(_)) => {
+                assert!(err.to_string().starts_with("invalid TDLib answer: "));
+                assert!(err.source().is_some());
+            }
+            result => panic!("{:?} decoded as {:?}", json, result),
+        }
+    }
+}
+
+#[test]
+fn malformed_objects() {
+    for json in &[
+        // A field of the wrong type.
+        r#"{"@type":"error","code":"400","message":""}"#,
+        // A missing field of a constructor of an abstract class.
+        r#"{"@type":"optionValueInteger"}"#,
+        // A malformed object in a field.
+        r#"{"@type":"updateUserStatus","user_id":1,"status":{"@type":"userStatusOnline"}}"#,
+    ] {
+        match GetOption::decode_response(json) {
+            Err(TdError::Malformed(object)) => {
+                assert_eq!(object, serde_json::from_str(json).unwrap());
+            }
+            result => panic!("{:?} decoded as {:?}", json, result),
+        }
+    }
+    let err =
+        GetOption::decode_response(r#"{"@type":"optionValueInteger","value":"1"}"#).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "fields of optionValueInteger do not match its definition"
+    );
+}
+
+#[test]
+fn unexpected_types() {
+    match GetMe::decode_response(r#"{"@type":"ok"}"#) {
+        Err(TdError::UnexpectedType(response)) => assert_eq!(*response, Response::Ok(Ok {})),
+        result => panic!("expected an unexpected type, got {:?}", result),
+    }
+    // Objects this crate doesn't know.
+    match GetOption::decode_response(r#"{"@type":"optionValueSomethingNew"}"#) {
+        Err(TdError::UnexpectedType(response)) => {
+            assert!(matches!(*response, Response::Unknown(_)));
+            assert_eq!(response.type_name(), "optionValueSomethingNew");
+        }
+        result => panic!("expected an unexpected type, got {:?}", result),
+    }
+}
+
+fn error(code: i32, message: &str) -> Error {
+    Error {
+        code,
+        message: message.to_string(),
+    }
+}
+
+struct Case {
+    code: i32,
+    message: &'static str,
+    kind: ErrorKind,
+    retry_after: Option<u64>,
+    transient: bool,
+    requires_auth: bool,
+    hidden: bool,
+}
+
+/// An error as TDLib sends it, with its kind, wait time and whether it is
+/// transient, requires logging in again or must not be shown.
+const fn case(
+    code: i32,
+    message: &'static str,
+    kind: ErrorKind,
+    retry_after: Option<u64>,
+    (transient, requires_auth, hidden): (bool, bool, bool),
+) -> Case {
+    Case {
+        code,
+        message,
+        kind,
+        retry_after,
+        transient,
+        requires_auth,
+        hidden,
+    }
+}
+
+#[test]
+fn classification() {
+    #[rustfmt::skip]
+    let cases = [
+        // code, message, kind, retry after, (transient, requires auth, hidden)
+        case(429, "Too Many Requests: retry after 17", ErrorKind::FloodWait, Some(17), (true, false, false)),
+        case(420, "FLOOD_WAIT_3600", ErrorKind::FloodWait, Some(3600), (true, false, false)),
+        case(420, "FLOOD_WAIT_0", ErrorKind::FloodWait, Some(0), (true, false, false)),
+        case(420, "SLOWMODE_WAIT_30", ErrorKind::SlowModeWait, Some(30), (true, false, false)),
+        case(420, "TAKEOUT_INIT_DELAY_86400", ErrorKind::TakeoutInitDelay, Some(86400), (true, false, false)),
+        case(400, "PHONE_CODE_INVALID", ErrorKind::PhoneCodeInvalid, None, (false, false, false)),
+        case(400, "PHONE_CODE_EXPIRED", ErrorKind::PhoneCodeExpired, None, (false, false, false)),
+        case(400, "PHONE_NUMBER_INVALID", ErrorKind::PhoneNumberInvalid, None, (false, false, false)),
+        case(401, "SESSION_PASSWORD_NEEDED", ErrorKind::SessionPasswordNeeded, None, (false, true, false)),
+        case(401, "Unauthorized", ErrorKind::Other, None, (false, true, false)),
+        case(401, "AUTH_KEY_UNREGISTERED", ErrorKind::AuthKeyUnregistered, None, (false, true, false)),
+        case(401, "SESSION_REVOKED", ErrorKind::SessionRevoked, None, (false, true, false)),
+        case(401, "USER_DEACTIVATED_BAN", ErrorKind::UserDeactivated, None, (false, true, false)),
+        // Classified by message whatever the code.
+        case(400, "AUTH_KEY_DUPLICATED", ErrorKind::AuthKeyDuplicated, None, (false, true, false)),
+        case(400, "Chat not found", ErrorKind::ChatNotFound, None, (false, false, false)),
+        case(403, "CHAT_WRITE_FORBIDDEN", ErrorKind::ChatWriteForbidden, None, (false, false, false)),
+        case(400, "Have no write access to the chat", ErrorKind::ChatWriteForbidden, None, (false, false, false)),
+        case(400, "MESSAGE_NOT_MODIFIED", ErrorKind::MessageNotModified, None, (false, false, false)),
+        case(406, "UPDATE_APP_TO_LOGIN", ErrorKind::Other, None, (false, false, true)),
+        case(406, "PHONE_NUMBER_INVALID", ErrorKind::PhoneNumberInvalid, None, (false, false, true)),
+        case(500, "Internal Server Error", ErrorKind::Other, None, (true, false, false)),
+        case(500, "Request aborted", ErrorKind::RequestAborted, None, (false, false, false)),
+        case(400, "Bad Request: chat not found", ErrorKind::Other, None, (false, false, false)),
+        case(404, "Not Found", ErrorKind::Other, None, (false, false, false)),
+    ];
+    for case in &cases {
+        let err = error(case.code, case.message);
+        let name = format!("{} {}", case.code, case.message);
+        assert_eq!(err.kind(), case.kind, "{}", name);
+        assert_eq!(
+            err.retry_after(),
+            case.retry_after.map(Duration::from_secs),
+            "{}",
+            name
+        );
+        assert_eq!(err.is_transient(), case.transient, "{}", name);
+        assert_eq!(err.is_permanent(), !case.transient, "{}", name);
+        assert_eq!(err.requires_auth(), case.requires_auth, "{}", name);
+        assert_eq!(err.must_not_be_shown(), case.hidden, "{}", name);
+    }
+}
+
+#[test]
+fn malformed_wait_times() {
+    for message in &[
+        "FLOOD_WAIT_",
+        "FLOOD_WAIT_X",
+        "FLOOD_WAIT_-1",
+        "FLOOD_WAIT_1.5",
+        "FLOOD_WAIT_99999999999999999999",
+        "flood_wait_5",
+        "Too Many Requests: retry after",
+        "Too Many Requests: retry after 5 seconds",
+    ] {
+        let err = error(420, message);
+        assert_eq!(err.kind(), ErrorKind::Other, "{}", message);
+        assert_eq!(err.retry_after(), None, "{}", message);
+        // Still transient by its code.
+        assert!(err.is_transient(), "{}", message);
+    }
+    let err = error(400, "FLOOD_WAIT_X");
+    assert!(err.is_permanent());
+}
+
+#[test]
+fn known_messages_are_classified() {
+    for &(message, kind) in KNOWN_MESSAGES {
+        assert_eq!(error(400, message).kind(), kind, "{}", message);
+        assert_eq!(error(400, message).retry_after(), None, "{}", message);
+    }
+}
@@ -0,0 +1,118 @@
+use serde_json::{json, Value};
+use tdlib_types::methods::*;
+use tdlib_types::types::*;
+
+#[test]
+fn requests_carry_extra() {
+    let request = GetOption::new().with_name("version").tag_with_extra(7);
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({ "@type": "getOption", "@extra": 7, "name": "version" })
+    );
+    let request = GetOption::new()
+        .with_name("version")
+        .tag()
+        .with_extra(json!({ "id": "a1", "attempt": 2 }));
+    assert_eq!(request.extra, Some(json!({ "id": "a1", "attempt": 2 })));
+    assert_eq!(
+        serde_json::to_value(&request).unwrap()["@extra"],
+        json!({ "id": "a1", "attempt": 2 })
+    );
+}
+
+#[test]
+fn requests_without_extra_omit_it() {
+    let request = GetMe::new().tag();
+    assert_eq!(request.extra, None);
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({ "@type": "getMe" })
+    );
+}
+
+#[test]
+fn extra_comes_back_with_the_response() {
+    // Every kind of value TDLib can echo back.
+    for extra in &[
+        json!(7),
+        json!(-1),
+        json!(u64::MAX),
+        json!("request 1"),
+        json!(1.5),
+        json!(true),
+        json!([1, "two"]),
+        json!({ "id": 1, "nested": { "x": null } }),
+    ] {
+        let request = GetOption::new()
+            .with_name("version")
+            .tag_with_extra(extra.clone());
+        let sent = serde_json::to_value(&request).unwrap();
+        // TDLib answers with the @extra of the request.
+        let answer = json!({
+            "@type": "optionValueString",
+            "value": "1.6.0",
+            "@extra": sent["@extra"],
+        });
+        let envelope: Envelope<Response> = serde_json::from_value(answer.clone()).unwrap();
+        assert_eq!(envelope.extra.as_ref(), Some(extra));
+        assert_eq!(envelope.client_id, None);
+        assert_eq!(
+            envelope.payload,
+            Response::OptionValue(OptionValue::OptionValueString(OptionValueString {
+                value: "1.6.0".to_string()
+            }))
+        );
+        // And it serializes back as it came.
+        assert_eq!(serde_json::to_value(&envelope).unwrap(), answer);
+    }
+}
+
+#[test]
+fn objects_without_extra() {
+    let envelope: Envelope<Update> = serde_json::from_value(json!({
+        "@type": "updateOption",
+        "name": "version",
+        "value": { "@type": "optionValueString", "value": "1.6.0" },
+    }))
+    .unwrap();
+    assert_eq!(envelope.extra, None);
+    assert!(matches!(envelope.payload, Update::UpdateOption(_)));
+    let json = serde_json::to_value(&envelope).unwrap();
+    assert!(json.get("@extra").is_none());
+    assert!(json.get("@client_id").is_none());
+
+    // A null @extra is as good as none.
+    let envelope: Envelope<Response> =
+        serde_json::from_value(json!({ "@type": "ok", "@extra": null })).unwrap();
+    assert_eq!(envelope.extra, None);
+    assert_eq!(envelope.payload, Response::Ok(Ok {}));
+}
+
+#[test]
+fn extra_with_errors_and_unknown_objects() {
+    let envelope: Envelope<Response> = serde_json::from_value(json!({
+        "@type": "error",
+        "code": 400,
+        "message": "Chat not found",
+        "@extra": "get chat 1",
+    }))
+    .unwrap();
+    assert_eq!(envelope.extra, Some(Value::from("get chat 1")));
+    assert!(matches!(envelope.payload, Response::Error(ref error) if error.code == 400));
+
+    let envelope: Envelope<Response> = serde_json::from_value(json!({
+        "@type": "somethingNew",
+        "@extra": 3,
+        "value": 1,
+    }))
+    .unwrap();
+    assert_eq!(envelope.extra, Some(Value::from(3)));
+    match &envelope.payload {
+        Response::Unknown(object) => {
+            assert_eq!(object.type_, "somethingNew");
+            // @extra belongs to the envelope, not to the object.
+            assert!(object.fields.get("@extra").is_none());
+        }
+        payload => panic!("expected Unknown, got {:?}", payload),
+    }
+}
@@ -0,0 +1,98 @@
+/// Runs under `with_passport_only`, with `passport` and none of the other
+/// groups: `AuthenticationCodeInfo` is compiled for the methods of
+/// `passport`, and so must be the constructors of its `type_`, although they
+/// belong to `auth`.
+#[cfg(all(feature = "passport", not(feature = "auth")))]
+#[test]
+fn constructors_of_compiled_classes() {
+    use tdlib_types::types::*;
+
+    let info: AuthenticationCodeInfo = serde_json::from_value(serde_json::json!({
+        "@type": "authenticationCodeInfo",
+        "phone_number": "+391234567890",
+        "type": { "@type": "authenticationCodeTypeSms", "length": 5 },
+        "timeout": 60,
+    }))
+    .unwrap();
+    assert!(matches!(
+        info.type_,
+        AuthenticationCodeType::AuthenticationCodeTypeSms(AuthenticationCodeTypeSms { length: 5 })
+    ));
+}
+
+/// Runs under `with_chats_and_messages`, the configuration of the README:
+/// starting TDLib, following its authorization state and stopping it don't
+/// need `auth`.
+#[cfg(all(feature = "chats", feature = "messages", not(feature = "auth")))]
+#[test]
+fn lifecycle_in_the_core() {
+    use tdlib_types::methods::*;
+    use tdlib_types::types::*;
+
+    let _: Option<(
+        SetTdlibParameters,
+        CheckDatabaseEncryptionKey,
+        GetAuthorizationState,
+        Close,
+        Destroy,
+        LogOut,
+    )> = None;
+    let update: Update = serde_json::from_value(serde_json::json!({
+        "@type": "updateAuthorizationState",
+        "authorization_state": { "@type": "authorizationStateWaitTdlibParameters" },
+    }))
+    .unwrap();
+    assert!(matches!(
+        update,
+        Update::UpdateAuthorizationState(UpdateAuthorizationState {
+            authorization_state: AuthorizationState::AuthorizationStateWaitTdlibParameters(_),
+        })
+    ));
+}
+
+/// Runs the tests of this file with only the release and `features`, and
+/// checks that exactly one of them was compiled.
+#[cfg(feature = "full")]
+fn run_with(features: &str) {
+    use std::path::Path;
+    use std::process::Command;
+
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(cargo)
+        .current_dir(crate_dir)
+        .args([
+            "test",
+            "--quiet",
+            "--test",
+            "features",
+            "--no-default-features",
+        ])
+        .args(["--features", &format!("tdlib-1-6-0 {}", features)])
+        .env(
+            "CARGO_TARGET_DIR",
+            crate_dir.join("target").join("releases"),
+        )
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "{}\n{}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("1 passed"), "{}", stdout);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn with_passport_only() {
+    run_with("passport");
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn with_chats_and_messages() {
+    run_with("chats messages");
+}
@@ -0,0 +1,55 @@
+use serde_json::json;
+use tdlib_types::methods::*;
+
+/// TDLib sends and expects int64 values as JSON strings.
+#[test]
+fn int64_fields_round_trip_as_strings() {
+    for &id in &[i64::MIN, -1, 0, 1, 9007199254740993, i64::MAX] {
+        let json = json!({ "profile_photo_id": id.to_string() });
+        let request: DeleteProfilePhoto = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(request.profile_photo_id, id);
+        assert_eq!(serde_json::to_value(&request).unwrap(), json);
+    }
+}
+
+#[test]
+fn int64_fields_are_exact() {
+    // Above 2^53, where a JSON number would lose precision in JavaScript.
+    let json = r#"{"profile_photo_id":"9223372036854775807"}"#;
+    let request: DeleteProfilePhoto = serde_json::from_str(json).unwrap();
+    assert_eq!(serde_json::to_string(&request).unwrap(), json);
+}
+
+#[test]
+fn int64_fields_must_be_strings() {
+    for json in &[
+        json!({ "profile_photo_id": 1 }),
+        json!({ "profile_photo_id": "" }),
+        json!({ "profile_photo_id": "1.5" }),
+        json!({ "profile_photo_id": "9223372036854775808" }),
+    ] {
+        assert!(
+            serde_json::from_value::<DeleteProfilePhoto>(json.clone()).is_err(),
+            "{} decoded",
+            json
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "stickers")]
+fn int64_vectors_round_trip_as_strings() {
+    let ids = vec![i64::MIN, 0, i64::MAX];
+    let request = ViewTrendingStickerSets::new().with_sticker_set_ids(ids.clone());
+    let json = serde_json::to_value(&request).unwrap();
+    assert_eq!(
+        json,
+        json!({ "sticker_set_ids": ["-9223372036854775808", "0", "9223372036854775807"] })
+    );
+    let back: ViewTrendingStickerSets = serde_json::from_value(json).unwrap();
+    assert_eq!(back.sticker_set_ids, ids);
+    assert_eq!(
+        serde_json::to_value(ViewTrendingStickerSets::new()).unwrap()["sticker_set_ids"],
+        serde_json::Value::Array(Vec::new())
+    );
+}
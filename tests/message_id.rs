@@ -0,0 +1,148 @@
+use tdlib_types::types::*;
+
+#[test]
+fn server_ids_are_shifted_by_20_bits() {
+    assert_eq!(MessageId::from_server_id(1), MessageId(1 << 20));
+    assert_eq!(MessageId::from_server_id(1), MessageId(1048576));
+    assert_eq!(MessageId::from_server_id(12345), MessageId(12944670720));
+    for &server_id in &[1, 2, 100, 12345, 1 << 20, i32::MAX] {
+        let id = MessageId::from_server_id(server_id);
+        assert!(id.is_server());
+        assert!(!id.is_scheduled() && !id.is_yet_unsent() && !id.is_local());
+        assert_eq!(id.server_id(), Some(server_id));
+        assert_eq!(id.scheduled_server_id(), None);
+        assert_eq!(id.scheduled_send_date(), None);
+    }
+    assert_eq!(
+        MessageId::from_server_id(i32::MAX),
+        MessageId(2251799812636672)
+    );
+}
+
+#[test]
+fn server_ids_out_of_range() {
+    // Server ids above i32::MAX can't be returned, rather than being
+    // truncated.
+    let id = MessageId(1 << 52);
+    assert!(id.is_server());
+    assert_eq!(id.server_id(), None);
+    assert_eq!(id.public_link("durov"), None);
+    assert_eq!(MessageId((i64::from(i32::MAX) + 1) << 20).server_id(), None);
+}
+
+#[test]
+fn scheduled_ids() {
+    let id = MessageId::from_scheduled_server_id(5, 1600000000).unwrap();
+    // The date above 2^30 from bit 21, the server id from bit 3 and the
+    // scheduled flag in bit 2.
+    assert_eq!(id, MessageId(1103643386314796));
+    assert!(id.is_scheduled());
+    assert!(!id.is_server() && !id.is_yet_unsent() && !id.is_local());
+    assert_eq!(id.server_id(), None);
+    assert_eq!(id.scheduled_server_id(), Some(5));
+    assert_eq!(id.scheduled_send_date(), Some(1600000000));
+
+    // The smallest and largest values that fit.
+    let first = MessageId::from_scheduled_server_id(1, (1 << 30) + 1).unwrap();
+    assert_eq!(first, MessageId(2097164));
+    assert_eq!(first.scheduled_server_id(), Some(1));
+    assert_eq!(first.scheduled_send_date(), Some((1 << 30) + 1));
+    let last = MessageId::from_scheduled_server_id((1 << 18) - 1, i32::MAX).unwrap();
+    assert_eq!(last, MessageId(2251799813685244));
+    assert_eq!(last.scheduled_server_id(), Some((1 << 18) - 1));
+    assert_eq!(last.scheduled_send_date(), Some(i32::MAX));
+}
+
+#[test]
+fn scheduled_ids_out_of_range() {
+    assert_eq!(MessageId::from_scheduled_server_id(0, 1600000000), None);
+    assert_eq!(MessageId::from_scheduled_server_id(-1, 1600000000), None);
+    assert_eq!(
+        MessageId::from_scheduled_server_id(1 << 18, 1600000000),
+        None
+    );
+    assert_eq!(MessageId::from_scheduled_server_id(1, 1 << 30), None);
+    assert_eq!(MessageId::from_scheduled_server_id(1, 0), None);
+}
+
+#[test]
+fn scheduled_dates_out_of_range() {
+    // A date offset of 2^31 added to the 2^30 the dates start from doesn't
+    // fit in an i32, rather than being truncated.
+    let id = MessageId((1 << 31 << 21) | (5 << 3) | 4);
+    assert!(id.is_scheduled());
+    assert_eq!(id.scheduled_server_id(), Some(5));
+    assert_eq!(id.scheduled_send_date(), None);
+}
+
+#[test]
+fn scheduled_ids_not_on_the_server_yet() {
+    // A scheduled message being sent has the yet-unsent type in its low bits.
+    let id = MessageId(
+        MessageId::from_scheduled_server_id(5, 1600000000)
+            .unwrap()
+            .0
+            | 1,
+    );
+    assert!(id.is_scheduled());
+    assert!(!id.is_yet_unsent());
+    assert_eq!(id.scheduled_server_id(), None);
+    assert_eq!(id.scheduled_send_date(), Some(1600000000));
+}
+
+#[test]
+fn yet_unsent_and_local_ids() {
+    let last_sent = MessageId::from_server_id(42).0;
+    let yet_unsent = MessageId(last_sent + 1);
+    assert!(yet_unsent.is_yet_unsent());
+    assert!(!yet_unsent.is_server() && !yet_unsent.is_local() && !yet_unsent.is_scheduled());
+    assert_eq!(yet_unsent.server_id(), None);
+
+    let local = MessageId(last_sent + 2);
+    assert!(local.is_local());
+    assert!(!local.is_server() && !local.is_yet_unsent() && !local.is_scheduled());
+    assert_eq!(local.server_id(), None);
+    // Local messages before any message was received.
+    assert!(MessageId(2).is_local());
+    assert!(MessageId(1).is_yet_unsent());
+}
+
+#[test]
+fn invalid_ids() {
+    for &id in &[0, -1, -(1 << 20), i64::MIN] {
+        let id = MessageId(id);
+        assert!(!id.is_server() && !id.is_scheduled());
+        assert!(!id.is_yet_unsent() && !id.is_local());
+        assert_eq!(id.server_id(), None);
+        assert_eq!(id.scheduled_send_date(), None);
+        assert_eq!(id.public_link("durov"), None);
+    }
+    assert_eq!(MessageId::from_server_id(0).server_id(), None);
+}
+
+#[test]
+fn links() {
+    let id = MessageId::from_server_id(123);
+    assert_eq!(
+        id.public_link("telegram").as_deref(),
+        Some("https://t.me/telegram/123")
+    );
+    assert_eq!(
+        id.private_link(SupergroupId(1234567890)).as_deref(),
+        Some("https://t.me/c/1234567890/123")
+    );
+    // Only sent messages have a link.
+    let scheduled = MessageId::from_scheduled_server_id(123, 1600000000).unwrap();
+    assert_eq!(scheduled.public_link("telegram"), None);
+    assert_eq!(scheduled.private_link(SupergroupId(1234567890)), None);
+    assert_eq!(MessageId(id.0 + 1).public_link("telegram"), None);
+    assert_eq!(MessageId(id.0 + 2).private_link(SupergroupId(1)), None);
+}
+
+#[test]
+fn ids_from_json() {
+    // As TDLib sends them, e.g. in message.id.
+    let id: MessageId = serde_json::from_str("12944670720").unwrap();
+    assert_eq!(id.server_id(), Some(12345));
+    assert_eq!(serde_json::to_string(&id).unwrap(), "12944670720");
+}
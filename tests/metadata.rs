@@ -0,0 +1,83 @@
+#![cfg(all(
+    feature = "auth",
+    feature = "chats",
+    feature = "messages",
+    feature = "bots"
+))]
+
+use tdlib_types::methods::*;
+use tdlib_types::types::*;
+
+/// Stands for `td_execute`, which only accepts synchronous methods.
+fn execute<M: SyncMethod>(_request: M) -> &'static str {
+    M::TYPE
+}
+
+/// The names of the constants of `M` that are true.
+fn flags<M: Method>() -> Vec<&'static str> {
+    let flags = [
+        ("OFFLINE", M::OFFLINE),
+        ("SYNCHRONOUS", M::SYNCHRONOUS),
+        ("BEFORE_AUTHORIZATION", M::BEFORE_AUTHORIZATION),
+        ("BOTS_ONLY", M::BOTS_ONLY),
+        ("USERS_ONLY", M::USERS_ONLY),
+    ];
+    flags
+        .iter()
+        .filter(|(_, set)| *set)
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+#[test]
+fn synchronous_methods() {
+    assert_eq!(
+        execute(GetTextEntities::new().with_text("@telegram")),
+        "getTextEntities"
+    );
+    assert_eq!(
+        execute(ParseTextEntities::new(TextParseModeHTML::new())),
+        "parseTextEntities"
+    );
+    assert_eq!(execute(GetJsonValue::new()), "getJsonValue");
+    assert_eq!(
+        flags::<GetTextEntities>(),
+        ["OFFLINE", "SYNCHRONOUS", "BEFORE_AUTHORIZATION"]
+    );
+    // "Asynchronously uploads a file" doesn't make a synchronous method.
+    assert!(flags::<UploadFile>().is_empty());
+}
+
+#[test]
+fn offline_methods() {
+    assert_eq!(
+        flags::<GetAuthorizationState>(),
+        ["OFFLINE", "BEFORE_AUTHORIZATION"]
+    );
+    assert_eq!(flags::<GetSecretChat>(), ["OFFLINE"]);
+    assert!(flags::<SendMessage>().is_empty());
+    // Offline only under a condition.
+    assert!(flags::<GetChat>().is_empty());
+    assert!(flags::<GetChatHistory>().is_empty());
+}
+
+#[test]
+fn methods_before_authorization() {
+    assert_eq!(flags::<GetOption>(), ["BEFORE_AUTHORIZATION"]);
+    assert_eq!(flags::<AddProxy>(), ["BEFORE_AUTHORIZATION"]);
+    // Methods of the authorization flow, that work only in some of the
+    // states before it's complete.
+    assert_eq!(flags::<SetTdlibParameters>(), ["BEFORE_AUTHORIZATION"]);
+    assert_eq!(flags::<CheckAuthenticationCode>(), ["BEFORE_AUTHORIZATION"]);
+    // And the ones that work in every state.
+    assert_eq!(flags::<Close>(), ["BEFORE_AUTHORIZATION"]);
+    assert_eq!(flags::<LogOut>(), ["BEFORE_AUTHORIZATION"]);
+    assert!(flags::<GetChats>().is_empty());
+}
+
+#[test]
+fn methods_for_bots() {
+    assert_eq!(flags::<AnswerInlineQuery>(), ["BOTS_ONLY"]);
+    assert_eq!(flags::<AnswerCallbackQuery>(), ["BOTS_ONLY"]);
+    assert!(flags::<GetInlineQueryResults>().is_empty());
+}
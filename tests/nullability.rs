@@ -0,0 +1,43 @@
+#![cfg(feature = "messages")]
+
+use tdlib_types::methods::{GetMessages, Method};
+use tdlib_types::types::*;
+
+const GET_MESSAGES: &str = include_str!("fixtures/get_messages.json");
+
+#[test]
+fn get_messages_with_deleted_ids() {
+    let messages = GetMessages::decode_response(GET_MESSAGES).unwrap();
+    assert_eq!(messages.total_count, 3);
+    let ids: Vec<_> = messages
+        .messages
+        .iter()
+        .map(|message| message.as_ref().map(|message| message.id))
+        .collect();
+    assert_eq!(
+        ids,
+        vec![Some(MessageId(1048576)), None, Some(MessageId(3145728))]
+    );
+}
+
+#[test]
+fn null_elements_are_serialized_as_null() {
+    let messages = Messages {
+        total_count: 1,
+        messages: vec![None],
+    };
+    assert_eq!(
+        serde_json::to_string(&messages).unwrap(),
+        r#"{"total_count":1,"messages":[null]}"#
+    );
+}
+
+#[test]
+fn nullable_fields_accept_null_and_missing_values() {
+    let messages: Messages = serde_json::from_str(GET_MESSAGES).unwrap();
+    let first = messages.messages[0].as_ref().unwrap();
+    let last = messages.messages[2].as_ref().unwrap();
+    assert_eq!(first.forward_info, None);
+    assert_eq!(last.forward_info, None);
+    assert_eq!(last.reply_markup, None);
+}
@@ -0,0 +1,132 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+fn crate_dir() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// The versions with a `schema/<version>/td_api.tl`, as features.
+fn release_features() -> Vec<String> {
+    let mut features: Vec<String> = fs::read_dir(crate_dir().join("schema"))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.join("td_api.tl").is_file())
+        .map(|path| {
+            let version = path.file_name().unwrap().to_str().unwrap();
+            format!("tdlib-{}", version.replace('.', "-"))
+        })
+        .collect();
+    features.sort();
+    features
+}
+
+/// `cargo check` of the library with the given features only.
+fn check(features: &[&str]) -> (bool, String) {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    // A target directory of its own, not to wait for the lock of the one
+    // the tests run from.
+    let target: PathBuf = crate_dir().join("target").join("releases");
+    let output = Command::new(cargo)
+        .current_dir(crate_dir())
+        .args(["check", "--quiet", "--lib", "--no-default-features"])
+        .arg("--features")
+        .arg(features.join(" "))
+        .env("CARGO_TARGET_DIR", target)
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+#[test]
+fn every_release_has_a_feature_and_a_module() {
+    let manifest = fs::read_to_string(crate_dir().join("Cargo.toml")).unwrap();
+    let mut features: Vec<&str> = manifest
+        .lines()
+        .filter_map(|line| line.strip_suffix(" = []"))
+        .filter(|feature| feature.starts_with("tdlib-"))
+        .collect();
+    features.sort_unstable();
+    assert_eq!(features, release_features());
+    for feature in features {
+        let module = feature.replace('-', "_");
+        assert!(crate_dir()
+            .join("src")
+            .join(module)
+            .join("mod.rs")
+            .is_file());
+    }
+}
+
+#[test]
+fn each_release_builds() {
+    for feature in release_features() {
+        let (ok, stderr) = check(&[&feature, "full"]);
+        assert!(ok, "{} doesn't build:\n{}", feature, stderr);
+    }
+}
+
+#[test]
+fn exactly_one_release_must_be_enabled() {
+    let (ok, stderr) = check(&["full"]);
+    assert!(!ok);
+    assert!(stderr.contains("enable the feature of the TDLib release to build for"));
+
+    let features = release_features();
+    if let [a, b, ..] = features.as_slice() {
+        let (ok, stderr) = check(&[a, b]);
+        assert!(!ok);
+        assert!(stderr.contains("enable the feature of one TDLib release only"));
+    }
+}
+
+/// Runs under `the_release_decides_the_types`, with the feature of 1.6.2.
+#[cfg(feature = "tdlib-1-6-2")]
+#[test]
+fn release_1_6_2() {
+    use tdlib_types::types::*;
+    use tdlib_types::version::{Version, TDLIB_VERSION};
+
+    assert_eq!(TDLIB_VERSION, Version::new(1, 6, 2));
+    let schema = tdlib_types::schema::td_api();
+    assert!(schema.function("getChatStatistics").is_some());
+    let dice: MessageContent =
+        serde_json::from_str(r#"{"@type":"messageDice","value":6}"#).unwrap();
+    assert!(matches!(
+        dice,
+        MessageContent::MessageDice(MessageDice { value: 6 })
+    ));
+}
+
+#[test]
+fn the_release_decides_the_types() {
+    assert_eq!(release_features(), ["tdlib-1-6-0", "tdlib-1-6-2"]);
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .current_dir(crate_dir())
+        .args([
+            "test",
+            "--quiet",
+            "--test",
+            "releases",
+            "--no-default-features",
+        ])
+        .args(["--features", "tdlib-1-6-2 full", "release_1_6_2"])
+        .env(
+            "CARGO_TARGET_DIR",
+            crate_dir().join("target").join("releases"),
+        )
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "{}\n{}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("1 passed"), "{}", stdout);
+}
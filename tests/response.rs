@@ -0,0 +1,129 @@
+#![cfg(all(feature = "auth", feature = "chats"))]
+
+use tdlib_types::types::*;
+
+#[test]
+#[cfg(feature = "full")]
+fn every_method_response_is_reachable() {
+    let schema = tdlib_types::schema::td_api();
+    let mut checked = 0;
+    for function in schema.functions() {
+        let name = function.result.as_str();
+        let class = schema.class(name).unwrap();
+        assert!(
+            !class.constructors.is_empty(),
+            "{} has no constructors",
+            name
+        );
+        for constructor in &class.constructors {
+            // Updates have a variant each.
+            let variant = match name {
+                "Update" => format!("U{}", &constructor[1..]),
+                _ => name.to_string(),
+            };
+            assert_eq!(
+                Response::variant_of(constructor),
+                Some(variant.as_str()),
+                "{} does not decode as Response::{}",
+                constructor,
+                variant
+            );
+            checked += 1;
+        }
+    }
+    assert!(checked > 100);
+}
+
+#[test]
+fn abstract_class_results_deserialize() {
+    let response: Response =
+        serde_json::from_str(r#"{"@type":"authorizationStateReady"}"#).unwrap();
+    assert_eq!(
+        response,
+        Response::AuthorizationState(AuthorizationState::AuthorizationStateReady(
+            AuthorizationStateReady {}
+        ))
+    );
+    let response: Response = serde_json::from_str(
+        r#"{"@type":"chatMemberStatusAdministrator","can_be_edited":true,"can_change_info":false,"can_post_messages":false,"can_edit_messages":false,"can_delete_messages":true,"can_invite_users":true,"can_restrict_members":false,"can_pin_messages":true,"can_promote_members":false,"custom_title":""}"#,
+    )
+    .unwrap();
+    assert!(matches!(
+        response,
+        Response::ChatMemberStatus(ChatMemberStatus::ChatMemberStatusAdministrator(_))
+    ));
+}
+
+#[test]
+fn concrete_results_deserialize() {
+    let response: Response = serde_json::from_str(r#"{"@type":"ok"}"#).unwrap();
+    assert_eq!(response, Response::Ok(Ok {}));
+    let response: Response =
+        serde_json::from_str(r#"{"@type":"error","code":400,"message":"CHAT_NOT_FOUND"}"#).unwrap();
+    assert_eq!(
+        response,
+        Response::Error(Error {
+            code: 400,
+            message: "CHAT_NOT_FOUND".to_string(),
+        })
+    );
+    let response: Response =
+        serde_json::from_str(r#"{"@type":"updateChatTitle","chat_id":1,"title":"t"}"#).unwrap();
+    assert!(matches!(response, Response::UpdateChatTitle(_)));
+}
+
+#[test]
+fn updates_convert_to_and_from_response() {
+    use std::convert::TryFrom;
+
+    let json = r#"{"@type":"updateChatTitle","chat_id":1,"title":"t"}"#;
+    let update: Update = serde_json::from_str(json).unwrap();
+    let response = Response::from(update.clone());
+    assert!(matches!(response, Response::UpdateChatTitle(_)));
+    assert_eq!(Update::try_from(response), Ok(update));
+    // Boxed in both enums.
+    let update: Update = serde_json::from_str(
+        r#"{"@type":"updateUser","user":{"id":1,"first_name":"","last_name":"","username":"","phone_number":"","status":{"@type":"userStatusEmpty"},"is_contact":false,"is_mutual_contact":false,"is_verified":false,"is_support":false,"restriction_reason":"","is_scam":false,"have_access":true,"type":{"@type":"userTypeRegular"},"language_code":""}}"#,
+    )
+    .unwrap();
+    assert_eq!(Update::try_from(Response::from(update.clone())), Ok(update));
+    assert!(Update::try_from(Response::Ok(Ok {})).is_err());
+}
+
+#[test]
+fn malformed_results_are_errors() {
+    for json in &[
+        // A known concrete type with a field of the wrong type.
+        r#"{"@type":"error","code":"400","message":"CHAT_NOT_FOUND"}"#,
+        // A known constructor of an abstract class.
+        r#"{"@type":"authorizationStateWaitPassword","password_hint":1}"#,
+        // An update with a missing field.
+        r#"{"@type":"updateChatTitle","chat_id":1}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<Response>(json).is_err(),
+            "{} decoded",
+            json
+        );
+    }
+}
+
+#[test]
+fn unknown_results_are_kept() {
+    let json = r#"{"@type":"somethingNew","value":1}"#;
+    let response: Response = serde_json::from_str(json).unwrap();
+    assert!(matches!(&response, Response::Unknown(u) if u.type_ == "somethingNew"));
+    assert_eq!(serde_json::to_string(&response).unwrap(), json);
+}
+
+#[test]
+fn responses_serialize_with_a_single_type() {
+    for json in &[
+        r#"{"@type":"ok"}"#,
+        r#"{"@type":"authorizationStateWaitPassword","password_hint":"","has_recovery_email_address":false,"recovery_email_address_pattern":""}"#,
+        r#"{"@type":"updateChatTitle","chat_id":1,"title":"t"}"#,
+    ] {
+        let response: Response = serde_json::from_str(json).unwrap();
+        assert_eq!(&serde_json::to_string(&response).unwrap(), json);
+    }
+}
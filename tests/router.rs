@@ -0,0 +1,146 @@
+use serde_json::json;
+use tdlib_types::router::{RouteError, Router};
+use tdlib_types::types::*;
+
+fn ok(client_id: i32) -> String {
+    json!({ "@type": "ok", "@client_id": client_id }).to_string()
+}
+
+#[test]
+fn objects_are_routed_by_client_id() {
+    let mut router: Router<Response> = Router::new();
+    let first = router.register(1);
+    let second = router.register(2);
+    assert!(router.is_registered(1) && router.is_registered(2));
+    assert!(!router.is_registered(3));
+
+    assert_eq!(router.route_str(&ok(2)).unwrap(), 2);
+    let update = json!({
+        "@type": "updateOption",
+        "@client_id": 1,
+        "name": "version",
+        "value": { "@type": "optionValueString", "value": "1.6.0" },
+    });
+    assert_eq!(router.route_str(&update.to_string()).unwrap(), 1);
+    let answer = json!({ "@type": "ok", "@client_id": 1, "@extra": "request 7" });
+    assert_eq!(router.route_str(&answer.to_string()).unwrap(), 1);
+
+    let envelope = first.try_recv().unwrap();
+    assert_eq!(envelope.client_id, Some(1));
+    assert!(matches!(envelope.payload, Response::UpdateOption(_)));
+    let envelope = first.try_recv().unwrap();
+    assert_eq!(envelope.extra, Some(json!("request 7")));
+    assert_eq!(envelope.payload, Response::Ok(Ok {}));
+    assert!(first.try_recv().is_err());
+
+    let envelope = second.try_recv().unwrap();
+    assert_eq!(envelope.client_id, Some(2));
+    assert_eq!(envelope.payload, Response::Ok(Ok {}));
+    assert!(second.try_recv().is_err());
+}
+
+#[test]
+fn parsed_objects_are_routed_too() {
+    let mut router: Router<Update> = Router::new();
+    let rx = router.register(5);
+    let envelope = serde_json::from_value(json!({
+        "@type": "updateSomethingNew",
+        "@client_id": 5,
+    }))
+    .unwrap();
+    assert_eq!(router.route(envelope).unwrap(), 5);
+    let envelope = rx.try_recv().unwrap();
+    assert_eq!(envelope.payload.type_name(), "updateSomethingNew");
+}
+
+#[test]
+fn objects_without_client_id() {
+    let mut router: Router<Response> = Router::new();
+    let rx = router.register(1);
+    match router.route_str(r#"{"@type":"ok"}"#) {
+        Err(err @ RouteError::MissingClientId(_)) => {
+            assert_eq!(err.to_string(), "incoming object has no @client_id");
+            if let RouteError::MissingClientId(envelope) = err {
+                assert_eq!(envelope.payload, Response::Ok(Ok {}));
+            }
+        }
+        result => panic!("expected MissingClientId, got {:?}", result),
+    }
+    assert!(rx.try_recv().is_err());
+}
+
+#[test]
+fn unknown_clients() {
+    let mut router: Router<Response> = Router::new();
+    let rx = router.register(1);
+    match router.route_str(&ok(2)) {
+        Err(RouteError::UnknownClient(envelope)) => {
+            assert_eq!(envelope.client_id, Some(2));
+            assert_eq!(
+                RouteError::UnknownClient(envelope).to_string(),
+                "no stream registered for client 2"
+            );
+        }
+        result => panic!("expected UnknownClient, got {:?}", result),
+    }
+    assert!(rx.try_recv().is_err());
+
+    // Unregistered clients are unknown again, and their stream ends.
+    router.unregister(1);
+    assert!(!router.is_registered(1));
+    assert!(matches!(
+        router.route_str(&ok(1)),
+        Err(RouteError::UnknownClient(_))
+    ));
+    assert!(rx.recv().is_err());
+}
+
+#[test]
+fn dropped_streams() {
+    let mut router: Router<Response> = Router::new();
+    drop(router.register(3));
+    match router.route_str(&ok(3)) {
+        Err(err @ RouteError::Disconnected(_)) => {
+            assert_eq!(err.to_string(), "stream for client 3 was dropped");
+        }
+        result => panic!("expected Disconnected, got {:?}", result),
+    }
+    // The client is unregistered.
+    assert!(!router.is_registered(3));
+    assert!(matches!(
+        router.route_str(&ok(3)),
+        Err(RouteError::UnknownClient(_))
+    ));
+}
+
+#[test]
+fn registering_again_replaces_the_stream() {
+    let mut router: Router<Response> = Router::new();
+    let old = router.register(1);
+    let new = router.register(1);
+    router.route_str(&ok(1)).unwrap();
+    assert!(new.try_recv().is_ok());
+    assert!(old.recv().is_err());
+}
+
+#[test]
+fn invalid_json() {
+    let mut router: Router<Response> = Router::new();
+    let rx = router.register(1);
+    for json in &[
+        "",
+        "{",
+        "[]",
+        r#"{"@client_id":1}"#,
+        r#"{"@type":"ok","@client_id":"1"}"#,
+        r#"{"@type":"error","@client_id":1,"code":"400","message":""}"#,
+    ] {
+        match router.route_str(json) {
+            Err(err @ RouteError::Json(_)) => {
+                assert!(err.to_string().starts_with("invalid incoming object: "));
+            }
+            result => panic!("{:?} routed as {:?}", json, result),
+        }
+    }
+    assert!(rx.try_recv().is_err());
+}
@@ -0,0 +1,16 @@
+use tdlib_types::schema::{self, TlType};
+
+#[test]
+fn embedded_schema() {
+    let schema = schema::td_api();
+    assert!(schema.constructors().len() > 700);
+    assert!(schema.functions().len() > 300);
+    let key = &schema
+        .function("checkDatabaseEncryptionKey")
+        .unwrap()
+        .fields[0];
+    assert_eq!(
+        (key.name.as_str(), &key.type_),
+        ("encryption_key", &TlType::Bytes)
+    );
+}
@@ -0,0 +1,124 @@
+#![cfg(all(feature = "full", target_pointer_width = "64"))]
+
+#[cfg(feature = "sizes")]
+use tdlib_types::sizes::{ENUMS, MAX_VARIANT_SIZE};
+
+#[cfg(feature = "sizes")]
+#[test]
+fn large_variants_are_boxed() {
+    for enum_ in ENUMS {
+        for variant in enum_.variants.iter().filter(|variant| !variant.boxed) {
+            assert!(
+                variant.size <= MAX_VARIANT_SIZE,
+                "{}::{} is {} bytes and not boxed",
+                enum_.name,
+                variant.name,
+                variant.size
+            );
+        }
+    }
+}
+
+/// tl-codegen boxes variants by the sizes it estimates, which must be the
+/// ones rustc picks.
+#[cfg(feature = "sizes")]
+#[test]
+fn estimated_sizes_are_exact() {
+    for enum_ in ENUMS {
+        assert_eq!(
+            enum_.estimated, enum_.size,
+            "estimated size of {}",
+            enum_.name
+        );
+        for variant in enum_.variants {
+            assert_eq!(
+                variant.estimated, variant.size,
+                "estimated size of {}::{}",
+                enum_.name, variant.name
+            );
+            assert_eq!(
+                variant.boxed,
+                variant.size > MAX_VARIANT_SIZE,
+                "{}::{} is {} bytes",
+                enum_.name,
+                variant.name,
+                variant.size
+            );
+        }
+    }
+}
+
+#[cfg(feature = "sizes")]
+#[test]
+fn enums_are_at_most_a_tag_larger_than_their_variants() {
+    for enum_ in ENUMS {
+        assert!(
+            enum_.size <= MAX_VARIANT_SIZE + 8,
+            "{} is {} bytes",
+            enum_.name,
+            enum_.size
+        );
+    }
+}
+
+/// The sizes of TDLib 1.6.0, to notice when a change of the generator makes
+/// the enums larger.
+#[cfg(feature = "tdlib-1-6-0")]
+#[test]
+fn enum_sizes() {
+    use std::mem::size_of;
+    use tdlib_types::types::*;
+
+    assert_eq!(size_of::<Response>(), 136);
+    assert_eq!(size_of::<Update>(), 136);
+    assert_eq!(size_of::<MessageContent>(), 120);
+    assert_eq!(size_of::<ChatEventAction>(), 128);
+    assert_eq!(size_of::<InputMessageContent>(), 136);
+    assert_eq!(size_of::<NotificationType>(), 112);
+    assert_eq!(size_of::<PageBlock>(), 128);
+    assert_eq!(size_of::<PushMessageContent>(), 96);
+    assert_eq!(size_of::<InlineQueryResult>(), 48);
+    assert_eq!(size_of::<RichText>(), 48);
+}
+
+/// Runs the tests of the `sizes` module, which is only compiled with the
+/// `sizes` feature.
+#[cfg(not(feature = "sizes"))]
+#[test]
+fn with_sizes() {
+    use std::path::Path;
+    use std::process::Command;
+
+    // `enum_sizes` only runs on 1.6.0.
+    let (release, passed) = if cfg!(feature = "tdlib-1-6-0") {
+        ("tdlib-1-6-0", "4 passed")
+    } else {
+        ("tdlib-1-6-2", "3 passed")
+    };
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let output = Command::new(cargo)
+        .current_dir(crate_dir)
+        .args([
+            "test",
+            "--quiet",
+            "--test",
+            "sizes",
+            "--no-default-features",
+        ])
+        .args(["--features", &format!("{} full sizes", release)])
+        .env(
+            "CARGO_TARGET_DIR",
+            crate_dir.join("target").join("releases"),
+        )
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "{}\n{}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains(passed), "{}", stdout);
+}
@@ -0,0 +1,166 @@
+#![cfg(feature = "full")]
+
+use serde_json::{json, Map, Value};
+use tdlib_types::schema::{self, Constructor, Schema, TlType};
+use tdlib_types::types::*;
+
+/// A value of `type_` that TDLib could send, with every field set, or None
+/// if all the objects it could hold contain one of `stack`.
+fn value(schema: &Schema, type_: &TlType, stack: &mut Vec<String>) -> Option<Value> {
+    Some(match type_ {
+        TlType::Bool => json!(false),
+        TlType::Int32 | TlType::Int53 => json!(0),
+        TlType::Int64 => json!("0"),
+        TlType::Double => json!(0.0),
+        TlType::String | TlType::Bytes => json!(""),
+        TlType::Vector(_) => json!([]),
+        TlType::Object(name) => match schema.constructor(name) {
+            Some(constructor) => object(schema, constructor, stack)?,
+            None => schema
+                .class(name)
+                .unwrap()
+                .constructors
+                .iter()
+                .find_map(|name| object(schema, schema.constructor(name).unwrap(), stack))?,
+        },
+    })
+}
+
+fn object(schema: &Schema, constructor: &Constructor, stack: &mut Vec<String>) -> Option<Value> {
+    if stack.contains(&constructor.name) {
+        return None;
+    }
+    stack.push(constructor.name.clone());
+    let mut fields = Map::new();
+    fields.insert("@type".to_string(), json!(constructor.name));
+    let mut complete = true;
+    for field in &constructor.fields {
+        let value = if field.nullable {
+            Some(Value::Null)
+        } else {
+            value(schema, &field.type_, stack)
+        };
+        match value {
+            Some(value) => {
+                fields.insert(field.name.clone(), value);
+            }
+            None => complete = false,
+        }
+    }
+    stack.pop();
+    if complete {
+        Some(Value::Object(fields))
+    } else {
+        None
+    }
+}
+
+#[test]
+fn every_constructor_has_a_variant() {
+    let schema = schema::td_api();
+    for constructor in schema.constructors() {
+        let expected = if constructor.class == "Update" {
+            // Updates are variants of their own, not to box every response.
+            TlType::Object(constructor.name.clone()).class().unwrap()
+        } else {
+            constructor.class.clone()
+        };
+        assert_eq!(
+            Response::variant_of(&constructor.name),
+            Some(expected.as_str()),
+            "{}",
+            constructor.name
+        );
+    }
+    assert_eq!(Response::variant_of("somethingNew"), None);
+}
+
+#[test]
+fn type_names_match_the_schema() {
+    let schema = schema::td_api();
+    for constructor in schema.constructors() {
+        let json = object(schema, constructor, &mut Vec::new()).unwrap();
+        let response: Response = serde_json::from_value(json.clone())
+            .unwrap_or_else(|err| panic!("{}: {}\n{}", constructor.name, err, json));
+        assert_eq!(response.type_name(), constructor.name);
+        // Known constructors are never kept as Unknown, but in the variant
+        // of their class.
+        let variant = Response::variant_of(&constructor.name).unwrap();
+        let debug = format!("{:?}", response);
+        assert!(
+            debug.starts_with(&format!("{}(", variant)),
+            "{} is read as {}",
+            constructor.name,
+            debug
+        );
+        // And they are written back with their @type, to be read again.
+        let written = serde_json::to_value(&response).unwrap();
+        assert_eq!(written["@type"], json["@type"]);
+        assert_eq!(
+            serde_json::from_value::<Response>(written).unwrap(),
+            response
+        );
+    }
+}
+
+/// Checks `TYPE` and `CLASS` of constructors against the schema.
+macro_rules! constructors {
+    ($schema:expr, $($type:ty),* $(,)?) => {$(
+        let constructor = $schema
+            .constructor(<$type as TlConstructor>::TYPE)
+            .unwrap_or_else(|| panic!("{} is not in the schema", stringify!($type)));
+        assert!(constructor.name.eq_ignore_ascii_case(stringify!($type)));
+        assert_eq!(<$type as TlObject>::CLASS, constructor.class);
+    )*};
+}
+
+/// Checks `CLASS` of enums against the schema.
+macro_rules! classes {
+    ($schema:expr, $($type:ty),* $(,)?) => {$(
+        assert_eq!(<$type as TlObject>::CLASS, stringify!($type));
+        assert!($schema.class(stringify!($type)).is_some());
+    )*};
+}
+
+#[test]
+fn constants_match_the_schema() {
+    let schema = schema::td_api();
+    constructors!(
+        schema,
+        Ok,
+        Error,
+        User,
+        Chat,
+        Message,
+        FormattedText,
+        OptionValueString,
+        UpdateOption,
+        UpdateNewMessage,
+        MessageText,
+        InputMessageText,
+        ChatTypePrivate,
+        AuthorizationStateReady,
+        TdlibParameters,
+        TextEntityTypeBold,
+        PageBlockParagraph,
+        RichTextPlain,
+        JsonValueNull,
+    );
+    classes!(
+        schema,
+        Update,
+        OptionValue,
+        MessageContent,
+        InputMessageContent,
+        ChatType,
+        AuthorizationState,
+        TextEntityType,
+        PageBlock,
+        RichText,
+        JsonValue,
+    );
+    // Enums report the @type of the object they hold.
+    let value = OptionValue::OptionValueString(OptionValueString::new());
+    assert_eq!(value.type_name(), OptionValueString::TYPE);
+    assert_eq!(Ok {}.type_name(), "ok");
+}
@@ -0,0 +1,71 @@
+use serde_json::json;
+use tdlib_types::types::*;
+
+#[test]
+fn unknown_constructors_are_kept() {
+    let json = r#"{"@type":"updateSomethingNew","value":1,"nested":{"@type":"x"}}"#;
+    let update: Update = serde_json::from_str(json).unwrap();
+    match &update {
+        Update::Unknown(object) => {
+            assert_eq!(object.type_, "updateSomethingNew");
+            assert_eq!(object.fields["value"], 1);
+        }
+        update => panic!("expected Unknown, got {:?}", update),
+    }
+    assert_eq!(update.type_name(), "updateSomethingNew");
+    assert_eq!(
+        serde_json::to_value(&update).unwrap(),
+        serde_json::from_str::<serde_json::Value>(json).unwrap()
+    );
+}
+
+#[test]
+fn unknown_constructors_in_fields() {
+    let update: UpdateUserStatus = serde_json::from_value(json!({
+        "@type": "updateUserStatus",
+        "user_id": 1,
+        "status": { "@type": "userStatusInvisible" },
+    }))
+    .unwrap();
+    assert!(matches!(&update.status, UserStatus::Unknown(u) if u.type_ == "userStatusInvisible"));
+}
+
+#[test]
+fn known_constructors_are_decoded() {
+    let status: UserStatus =
+        serde_json::from_value(json!({ "@type": "userStatusOnline", "expires": 1600000000 }))
+            .unwrap();
+    assert_eq!(
+        status,
+        UserStatus::UserStatusOnline(UserStatusOnline {
+            expires: 1600000000
+        })
+    );
+}
+
+#[test]
+fn malformed_known_constructors_fail() {
+    // A field of the wrong type.
+    let err = serde_json::from_value::<UserStatus>(
+        json!({ "@type": "userStatusOnline", "expires": "soon" }),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("invalid type"), "{}", err);
+    // A missing field.
+    assert!(serde_json::from_value::<UserStatus>(json!({ "@type": "userStatusOnline" })).is_err());
+    // Deeper in an object.
+    let err = serde_json::from_value::<Update>(json!({
+        "@type": "updateUserStatus",
+        "user_id": 1,
+        "status": { "@type": "userStatusOnline", "expires": "soon" },
+    }))
+    .unwrap_err();
+    assert!(err.to_string().contains("invalid type"), "{}", err);
+}
+
+#[test]
+fn objects_without_a_type_fail() {
+    let err = serde_json::from_value::<UserStatus>(json!({ "expires": 1 })).unwrap_err();
+    assert_eq!(err.to_string(), "missing field `@type`");
+    assert!(serde_json::from_value::<Update>(json!(null)).is_err());
+}
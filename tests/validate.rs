@@ -0,0 +1,176 @@
+#![cfg(all(feature = "chats", feature = "messages"))]
+
+use tdlib_types::methods::*;
+use tdlib_types::types::*;
+use tdlib_types::validate::{Measure, Validate, ValidationError, Violation};
+
+fn poll(question: &str, options: &[&str]) -> SendMessage {
+    let options: Vec<String> = options.iter().map(|option| option.to_string()).collect();
+    SendMessage::new(
+        ChatId(-100),
+        InputMessagePoll::new(question, options, PollTypeRegular::new()),
+    )
+}
+
+#[test]
+fn valid_requests_pass() {
+    assert_eq!(GetChatHistory::new(ChatId(-100), 100).validate(), Ok(()));
+    assert_eq!(SetChatTitle::new(ChatId(-100), "Cats").validate(), Ok(()));
+    assert_eq!(poll("Cats or dogs?", &["Cats", "Dogs"]).validate(), Ok(()));
+    // Methods without documented limits always pass.
+    assert_eq!(GetMe::new().validate(), Ok(()));
+}
+
+#[test]
+fn limits_tied_to_offset() {
+    let history = |offset: i32, limit: i32| {
+        GetChatHistory::new(ChatId(-100), limit)
+            .with_offset(offset)
+            .validate()
+    };
+    assert_eq!(history(0, 1), Ok(()));
+    assert_eq!(history(-10, 10), Ok(()));
+    assert_eq!(history(-99, 100), Ok(()));
+    assert_eq!(
+        history(-10, 5).unwrap_err().to_string(),
+        "getChatHistory.limit must be at least 10, not 5"
+    );
+    assert_eq!(
+        history(1, 10).unwrap_err().to_string(),
+        "getChatHistory.offset must be between -99 and 0, not 1"
+    );
+    assert_eq!(
+        history(-100, 100).unwrap_err().to_string(),
+        "getChatHistory.offset must be between -99 and 0, not -100"
+    );
+
+    // Strictly greater than -offset here.
+    let search = |offset: i32, limit: i32| {
+        SearchChatMessages::new(ChatId(-100), limit, SearchMessagesFilterEmpty::new())
+            .with_offset(offset)
+            .validate()
+    };
+    assert_eq!(search(-10, 11), Ok(()));
+    assert_eq!(
+        search(-10, 10).unwrap_err().to_string(),
+        "searchChatMessages.limit must be at least 11, not 10"
+    );
+    assert_eq!(
+        search(5, 10).unwrap_err().to_string(),
+        "searchChatMessages.offset must be at most 0, not 5"
+    );
+}
+
+#[test]
+fn value_ranges() {
+    // Required in new, but a value can still be out of range.
+    let err = GetChatHistory::new(ChatId(-100), 0).validate().unwrap_err();
+    assert_eq!(
+        err,
+        ValidationError {
+            type_: "getChatHistory",
+            field: "limit".to_string(),
+            violation: Violation::OutOfRange {
+                measure: Measure::Value,
+                min: Some(1),
+                max: Some(100),
+                actual: 0,
+            },
+        }
+    );
+    assert_eq!(err.to_string(), "getChatHistory.limit must be 1-100, not 0");
+
+    let err = GetSupergroupMembers::new(SupergroupId(1), SupergroupMembersFilterRecent::new())
+        .with_limit(201)
+        .validate()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "getSupergroupMembers.limit must be at most 200, not 201"
+    );
+}
+
+#[test]
+fn allowed_values() {
+    let err = SetChatSlowModeDelay::new(ChatId(-100))
+        .with_slow_mode_delay(5)
+        .validate()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "setChatSlowModeDelay.slow_mode_delay must be one of 0, 10, 30, 60, 300, 900, 3600, not 5"
+    );
+    assert_eq!(
+        SetChatSlowModeDelay::new(ChatId(-100))
+            .with_slow_mode_delay(30)
+            .validate(),
+        Ok(())
+    );
+}
+
+#[test]
+fn string_lengths_are_counted_in_characters() {
+    assert_eq!(
+        SetChatTitle::new(ChatId(-100), "")
+            .validate()
+            .unwrap_err()
+            .to_string(),
+        "setChatTitle.title must have 1-128 characters, not 0"
+    );
+    // 128 characters, but more bytes.
+    let title = "é".repeat(128);
+    assert_eq!(SetChatTitle::new(ChatId(-100), title).validate(), Ok(()));
+    let err = SetChatTitle::new(ChatId(-100), "é".repeat(129))
+        .validate()
+        .unwrap_err();
+    assert_eq!(err.field, "title");
+}
+
+#[test]
+fn list_lengths() {
+    let err = ReportSupergroupSpam::new(SupergroupId(1), UserId(1), Vec::new())
+        .validate()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "reportSupergroupSpam.message_ids must have at least 1 elements, not 0"
+    );
+
+    let rules = UserPrivacySettingRules::new().with_rules(vec![UserPrivacySettingRule::from(
+        UserPrivacySettingRuleAllowUsers::new().with_user_ids(vec![UserId(1); 1001]),
+    )]);
+    let err = SetUserPrivacySettingRules::new(UserPrivacySettingShowStatus::new())
+        .with_rules(rules)
+        .validate()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "setUserPrivacySettingRules.rules.rules[0].user_ids must have at most 1000 elements, not 1001"
+    );
+}
+
+#[test]
+fn nested_objects_are_validated() {
+    let err = poll("", &["Cats", "Dogs"]).validate().unwrap_err();
+    assert_eq!(err.type_, "sendMessage");
+    assert_eq!(err.field, "input_message_content.question");
+
+    let err = poll("Cats or dogs?", &["Cats"]).validate().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "sendMessage.input_message_content.options must have 2-10 elements, not 1"
+    );
+
+    let err = poll("Cats or dogs?", &["Cats", ""]).validate().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "sendMessage.input_message_content.options[1] must have 1-100 characters, not 0"
+    );
+}
+
+#[test]
+fn unknown_objects_pass() {
+    let content: InputMessageContent =
+        serde_json::from_str(r#"{"@type":"inputMessageFuture"}"#).unwrap();
+    assert_eq!(content.validate(), Ok(()));
+}
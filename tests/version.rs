@@ -0,0 +1,137 @@
+#![cfg(feature = "tdlib-1-6-0")]
+
+use tdlib_types::types::*;
+use tdlib_types::version::{Compatibility, Version, TDLIB_VERSION, VERSION_OPTION};
+
+fn string(value: &str) -> OptionValue {
+    OptionValue::OptionValueString(OptionValueString::new().with_value(value))
+}
+
+#[test]
+fn the_version_of_the_release() {
+    assert_eq!(TDLIB_VERSION, Version::new(1, 6, 0));
+    assert_eq!(TDLIB_VERSION.to_string(), "1.6.0");
+}
+
+#[test]
+fn versions() {
+    assert_eq!("1.6.0".parse(), Ok(Version::new(1, 6, 0)));
+    assert_eq!("1.7".parse(), Ok(Version::new(1, 7, 0)));
+    assert_eq!(" 1.10.2\n".parse(), Ok(Version::new(1, 10, 2)));
+    assert!(Version::new(1, 10, 0) > Version::new(1, 6, 9));
+    assert!(Version::new(2, 0, 0) > Version::new(1, 99, 99));
+    for version in &["", "1", "1.", "1.6.0.1", "v1.6.0", "1.6.x", "-1.6.0"] {
+        let err = version.parse::<Version>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("invalid TDLib version {:?}", version)
+        );
+    }
+}
+
+#[test]
+fn equal_versions() {
+    assert_eq!(Compatibility::check(&string("1.6.0")), Compatibility::Exact);
+    // The patch defaults to 0.
+    assert_eq!(Compatibility::check(&string("1.6")), Compatibility::Exact);
+    assert!(Compatibility::check(&string("1.6.0")).is_exact());
+    assert_eq!(
+        Compatibility::Exact.to_string(),
+        "TDLib 1.6.0 matches the schema"
+    );
+}
+
+#[test]
+fn older_versions() {
+    for (value, version) in &[
+        ("1.5.0", Version::new(1, 5, 0)),
+        ("1.5.9", Version::new(1, 5, 9)),
+        ("0.9.0", Version::new(0, 9, 0)),
+    ] {
+        let compatibility = Compatibility::check(&string(value));
+        assert_eq!(compatibility, Compatibility::Older(*version));
+        assert!(!compatibility.is_exact());
+    }
+    assert_eq!(
+        Compatibility::Older(Version::new(1, 5, 0)).to_string(),
+        "TDLib 1.5.0 is older than the schema of TDLib 1.6.0: some methods and fields are not supported"
+    );
+}
+
+#[test]
+fn newer_versions() {
+    for (value, version) in &[
+        ("1.6.1", Version::new(1, 6, 1)),
+        ("1.7.0", Version::new(1, 7, 0)),
+        ("1.10.0", Version::new(1, 10, 0)),
+        ("2.0.0", Version::new(2, 0, 0)),
+    ] {
+        let compatibility = Compatibility::check(&string(value));
+        assert_eq!(compatibility, Compatibility::Newer(*version));
+        assert!(!compatibility.is_exact());
+    }
+    assert!(Compatibility::Newer(Version::new(1, 7, 0))
+        .to_string()
+        .starts_with("TDLib 1.7.0 is newer than the schema of TDLib 1.6.0"));
+}
+
+#[test]
+fn malformed_versions() {
+    for value in &[
+        string(""),
+        string("1.6.0-beta"),
+        string("unknown"),
+        OptionValue::OptionValueInteger(OptionValueInteger::new().with_value(160)),
+        OptionValue::OptionValueBoolean(OptionValueBoolean::new()),
+        OptionValue::OptionValueEmpty(OptionValueEmpty::new()),
+    ] {
+        let compatibility = Compatibility::check(value);
+        assert_eq!(compatibility, Compatibility::Unrecognized(value.clone()));
+        assert!(!compatibility.is_exact());
+        assert!(compatibility
+            .to_string()
+            .starts_with("unrecognized TDLib version "));
+    }
+}
+
+#[test]
+fn version_updates() {
+    let update = UpdateOption {
+        name: VERSION_OPTION.to_string(),
+        value: string("1.7.0"),
+    };
+    assert_eq!(
+        Compatibility::from_update(&update),
+        Some(Compatibility::Newer(Version::new(1, 7, 0)))
+    );
+    let update = UpdateOption {
+        name: "version".to_string(),
+        value: OptionValue::OptionValueEmpty(OptionValueEmpty::new()),
+    };
+    assert!(matches!(
+        Compatibility::from_update(&update),
+        Some(Compatibility::Unrecognized(_))
+    ));
+
+    // Other options are not about the version.
+    let update = UpdateOption {
+        name: "my_id".to_string(),
+        value: string("1.6.0"),
+    };
+    assert_eq!(Compatibility::from_update(&update), None);
+
+    // As TDLib sends it.
+    let update: Update = serde_json::from_str(
+        r#"{"@type":"updateOption","name":"version","value":{"@type":"optionValueString","value":"1.6.0"}}"#,
+    )
+    .unwrap();
+    match update {
+        Update::UpdateOption(update) => {
+            assert_eq!(
+                Compatibility::from_update(&update),
+                Some(Compatibility::Exact)
+            );
+        }
+        update => panic!("expected updateOption, got {:?}", update),
+    }
+}